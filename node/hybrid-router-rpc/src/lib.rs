@@ -0,0 +1,137 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+//! JSON-RPC interface for simulating hybrid router trades through `HybridRouterApi`.
+
+use codec::{Codec, MaxEncodedLen};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use pallet_pm_hybrid_router_runtime_api::HybridRouterApi as HybridRouterRuntimeApi;
+use prediction_market_primitives::{
+    hybrid_router_api_types::{Strategy, TradeSimulation},
+    orderbook::OrderId,
+    types::Asset,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use std::{marker::PhantomData, sync::Arc};
+
+#[rpc(server, namespace = "hybridRouter")]
+pub trait HybridRouterRpc<BlockHash, AccountId, MarketId, Balance> {
+    #[method(name = "simulateBuy", blocking)]
+    #[allow(clippy::too_many_arguments)]
+    fn simulate_buy(
+        &self,
+        who: AccountId,
+        market_id: MarketId,
+        asset: Asset<MarketId>,
+        amount_in: Balance,
+        max_price: Balance,
+        orders: Vec<OrderId>,
+        strategy: Strategy,
+        at: Option<BlockHash>,
+    ) -> RpcResult<TradeSimulation<AccountId, Balance, MarketId>>;
+
+    #[method(name = "simulateSell", blocking)]
+    #[allow(clippy::too_many_arguments)]
+    fn simulate_sell(
+        &self,
+        who: AccountId,
+        market_id: MarketId,
+        asset: Asset<MarketId>,
+        amount_in: Balance,
+        min_price: Balance,
+        orders: Vec<OrderId>,
+        strategy: Strategy,
+        at: Option<BlockHash>,
+    ) -> RpcResult<TradeSimulation<AccountId, Balance, MarketId>>;
+}
+
+pub struct HybridRouterProvider<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> HybridRouterProvider<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+fn runtime_error(method: &str, e: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+    jsonrpsee::core::Error::Custom(format!("Error calling runtime api {}: {:?}", method, e))
+}
+
+fn dispatch_error(method: &str, e: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+    jsonrpsee::core::Error::Custom(format!("Unable to compute {}: {:?}", method, e))
+}
+
+impl<C, Block, AccountId, MarketId, Balance>
+    HybridRouterRpcServer<<Block as BlockT>::Hash, AccountId, MarketId, Balance>
+    for HybridRouterProvider<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: HybridRouterRuntimeApi<Block, AccountId, MarketId, Balance>,
+    AccountId: Codec + Send + Sync + 'static + serde::Serialize + serde::de::DeserializeOwned,
+    MarketId: Codec
+        + MaxEncodedLen
+        + Send
+        + Sync
+        + 'static
+        + serde::Serialize
+        + serde::de::DeserializeOwned,
+    Balance: Codec + Send + Sync + 'static + serde::Serialize + serde::de::DeserializeOwned,
+{
+    #[allow(clippy::too_many_arguments)]
+    fn simulate_buy(
+        &self,
+        who: AccountId,
+        market_id: MarketId,
+        asset: Asset<MarketId>,
+        amount_in: Balance,
+        max_price: Balance,
+        orders: Vec<OrderId>,
+        strategy: Strategy,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<TradeSimulation<AccountId, Balance, MarketId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.simulate_buy(at, who, market_id, asset, amount_in, max_price, orders, strategy)
+            .map_err(|e| runtime_error("simulate_buy", e))?
+            .map_err(|e| dispatch_error("simulate_buy", e))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn simulate_sell(
+        &self,
+        who: AccountId,
+        market_id: MarketId,
+        asset: Asset<MarketId>,
+        amount_in: Balance,
+        min_price: Balance,
+        orders: Vec<OrderId>,
+        strategy: Strategy,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<TradeSimulation<AccountId, Balance, MarketId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.simulate_sell(at, who, market_id, asset, amount_in, min_price, orders, strategy)
+            .map_err(|e| runtime_error("simulate_sell", e))?
+            .map_err(|e| dispatch_error("simulate_sell", e))
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+//! JSON-RPC interface for quoting trades against neo-swaps pools through `NeoSwapsApi`.
+
+use codec::{Codec, MaxEncodedLen};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use pallet_pm_neo_swaps_runtime_api::NeoSwapsApi as NeoSwapsRuntimeApi;
+use prediction_market_primitives::{neo_swaps_api_types::SwapQuote, types::Asset};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use std::{marker::PhantomData, sync::Arc};
+
+#[rpc(server, namespace = "neoSwaps")]
+pub trait NeoSwapsRpc<BlockHash, AccountId, MarketId, Balance, BlockNumber> {
+    #[method(name = "quoteBuy", blocking)]
+    fn quote_buy(
+        &self,
+        who: AccountId,
+        market_id: MarketId,
+        asset_out: Asset<MarketId>,
+        amount_in: Balance,
+        at: Option<BlockHash>,
+    ) -> RpcResult<SwapQuote<Balance>>;
+
+    #[method(name = "quoteSell", blocking)]
+    fn quote_sell(
+        &self,
+        who: AccountId,
+        market_id: MarketId,
+        asset_in: Asset<MarketId>,
+        amount_in: Balance,
+        at: Option<BlockHash>,
+    ) -> RpcResult<SwapQuote<Balance>>;
+
+    #[method(name = "spotPrices", blocking)]
+    fn spot_prices(
+        &self,
+        market_id: MarketId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(Asset<MarketId>, Balance)>>;
+
+    #[method(name = "twap", blocking)]
+    fn twap(
+        &self,
+        market_id: MarketId,
+        period: BlockNumber,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(Asset<MarketId>, Balance)>>;
+}
+
+pub struct NeoSwapsProvider<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> NeoSwapsProvider<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+fn runtime_error(method: &str, e: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+    jsonrpsee::core::Error::Custom(format!("Error calling runtime api {}: {:?}", method, e))
+}
+
+fn dispatch_error(method: &str, e: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+    jsonrpsee::core::Error::Custom(format!("Unable to compute {}: {:?}", method, e))
+}
+
+impl<C, Block, AccountId, MarketId, Balance, BlockNumber>
+    NeoSwapsRpcServer<<Block as BlockT>::Hash, AccountId, MarketId, Balance, BlockNumber>
+    for NeoSwapsProvider<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: NeoSwapsRuntimeApi<Block, AccountId, MarketId, Balance, BlockNumber>,
+    AccountId: Codec + Send + Sync + 'static + serde::de::DeserializeOwned,
+    MarketId: Codec
+        + MaxEncodedLen
+        + Send
+        + Sync
+        + 'static
+        + serde::Serialize
+        + serde::de::DeserializeOwned,
+    Balance: Codec + Send + Sync + 'static + serde::Serialize + serde::de::DeserializeOwned,
+    BlockNumber: Codec + Send + Sync + 'static + serde::de::DeserializeOwned,
+{
+    fn quote_buy(
+        &self,
+        who: AccountId,
+        market_id: MarketId,
+        asset_out: Asset<MarketId>,
+        amount_in: Balance,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<SwapQuote<Balance>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.quote_buy(at, who, market_id, asset_out, amount_in)
+            .map_err(|e| runtime_error("quote_buy", e))?
+            .map_err(|e| dispatch_error("quote_buy", e))
+    }
+
+    fn quote_sell(
+        &self,
+        who: AccountId,
+        market_id: MarketId,
+        asset_in: Asset<MarketId>,
+        amount_in: Balance,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<SwapQuote<Balance>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.quote_sell(at, who, market_id, asset_in, amount_in)
+            .map_err(|e| runtime_error("quote_sell", e))?
+            .map_err(|e| dispatch_error("quote_sell", e))
+    }
+
+    fn spot_prices(
+        &self,
+        market_id: MarketId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(Asset<MarketId>, Balance)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.spot_prices(at, market_id)
+            .map_err(|e| runtime_error("spot_prices", e))?
+            .map_err(|e| dispatch_error("spot_prices", e))
+    }
+
+    fn twap(
+        &self,
+        market_id: MarketId,
+        period: BlockNumber,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(Asset<MarketId>, Balance)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.twap(at, market_id, period)
+            .map_err(|e| runtime_error("twap", e))?
+            .map_err(|e| dispatch_error("twap", e))
+    }
+}
@@ -0,0 +1,169 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+//! JSON-RPC interface for querying prediction markets through `PredictionMarketsApi`.
+
+use codec::{Codec, MaxEncodedLen};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use pallet_prediction_markets_runtime_api::PredictionMarketsApi as PredictionMarketsRuntimeApi;
+use prediction_market_primitives::types::{Asset, Market, MarketStatus, OutcomeReport};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use std::{marker::PhantomData, sync::Arc};
+
+#[rpc(server, namespace = "predictionMarkets")]
+pub trait PredictionMarketsRpc<BlockHash, AccountId, Balance, BlockNumber, MarketId, Moment> {
+    #[method(name = "marketOutcomeShareId", blocking)]
+    fn market_outcome_share_id(
+        &self,
+        market_id: MarketId,
+        outcome: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Asset<MarketId>>>;
+
+    #[method(name = "market", blocking)]
+    fn market(
+        &self,
+        market_id: MarketId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Market<AccountId, Balance, BlockNumber, Moment, MarketId>>>;
+
+    #[method(name = "marketOutcomeAssets", blocking)]
+    fn market_outcome_assets(
+        &self,
+        market_id: MarketId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Asset<MarketId>>>;
+
+    #[method(name = "marketStatus", blocking)]
+    fn market_status(
+        &self,
+        market_id: MarketId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<MarketStatus>>;
+
+    #[method(name = "marketResolvedOutcome", blocking)]
+    fn market_resolved_outcome(
+        &self,
+        market_id: MarketId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<OutcomeReport>>;
+}
+
+pub struct PredictionMarketsProvider<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> PredictionMarketsProvider<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+fn runtime_error(method: &str, e: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+    jsonrpsee::core::Error::Custom(format!("Error calling runtime api {}: {:?}", method, e))
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber, MarketId, Moment>
+    PredictionMarketsRpcServer<
+        <Block as BlockT>::Hash,
+        AccountId,
+        Balance,
+        BlockNumber,
+        MarketId,
+        Moment,
+    > for PredictionMarketsProvider<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: PredictionMarketsRuntimeApi<
+        Block,
+        AccountId,
+        Balance,
+        BlockNumber,
+        MarketId,
+        Moment,
+        <Block as BlockT>::Hash,
+    >,
+    AccountId: Clone + Codec + Send + Sync + 'static + serde::Serialize,
+    Balance: Codec + Send + Sync + 'static + serde::Serialize,
+    BlockNumber: Codec + Send + Sync + 'static + serde::Serialize,
+    MarketId: Codec
+        + MaxEncodedLen
+        + Send
+        + Sync
+        + 'static
+        + serde::Serialize
+        + serde::de::DeserializeOwned,
+    Moment: Codec + Send + Sync + 'static + serde::Serialize,
+{
+    fn market_outcome_share_id(
+        &self,
+        market_id: MarketId,
+        outcome: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Asset<MarketId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.market_outcome_share_id(at, market_id, outcome)
+            .map_err(|e| runtime_error("market_outcome_share_id", e))
+    }
+
+    fn market(
+        &self,
+        market_id: MarketId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Market<AccountId, Balance, BlockNumber, Moment, MarketId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.market(at, market_id).map_err(|e| runtime_error("market", e))
+    }
+
+    fn market_outcome_assets(
+        &self,
+        market_id: MarketId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Asset<MarketId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.market_outcome_assets(at, market_id)
+            .map_err(|e| runtime_error("market_outcome_assets", e))
+    }
+
+    fn market_status(
+        &self,
+        market_id: MarketId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<MarketStatus>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.market_status(at, market_id).map_err(|e| runtime_error("market_status", e))
+    }
+
+    fn market_resolved_outcome(
+        &self,
+        market_id: MarketId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<OutcomeReport>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        api.market_resolved_outcome(at, market_id)
+            .map_err(|e| runtime_error("market_resolved_outcome", e))
+    }
+}
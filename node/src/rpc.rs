@@ -13,7 +13,9 @@ use sc_transaction_pool_api::TransactionPool;
 use sp_api::{offchain::OffchainStorage, ProvideRuntimeApi};
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
-use tnf_node_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use tnf_node_runtime::{
+    opaque::Block, AccountId, Balance, BlockNumber, Hash, MarketId, Moment, Nonce,
+};
 
 pub use sc_rpc_api::DenyUnsafe;
 
@@ -40,11 +42,32 @@ where
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: BlockBuilder<Block>,
+    C::Api: pallet_prediction_markets_runtime_api::PredictionMarketsApi<
+        Block,
+        AccountId,
+        Balance,
+        BlockNumber,
+        MarketId,
+        Moment,
+        Hash,
+    >,
+    C::Api: pallet_pm_neo_swaps_runtime_api::NeoSwapsApi<
+        Block,
+        AccountId,
+        MarketId,
+        Balance,
+        BlockNumber,
+    >,
+    C::Api:
+        pallet_pm_hybrid_router_runtime_api::HybridRouterApi<Block, AccountId, MarketId, Balance>,
     P: TransactionPool + 'static,
     O: OffchainStorage + Clone + Send + Sync + 'static,
     C: BlockBackend<Block> + UsageProvider<Block>,
 {
+    use hybrid_router_rpc::{HybridRouterProvider, HybridRouterRpcServer};
+    use neo_swaps_rpc::{NeoSwapsProvider, NeoSwapsRpcServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+    use prediction_markets_rpc::{PredictionMarketsProvider, PredictionMarketsRpcServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
     use summary_calculation_rpc::{
         SummaryCalculationProvider, SummaryCalculationProviderRpcServer,
@@ -61,6 +84,9 @@ where
     // to call into the runtime.
     // `module.merge(YourRpcTrait::into_rpc(YourRpcStruct::new(ReferenceToClient, ...)))?;`
     module.merge(SummaryCalculationProvider::new(client.clone(), offchain_storage).into_rpc())?;
+    module.merge(PredictionMarketsProvider::new(client.clone()).into_rpc())?;
+    module.merge(NeoSwapsProvider::new(client.clone()).into_rpc())?;
+    module.merge(HybridRouterProvider::new(client.clone()).into_rpc())?;
 
     Ok(module)
 }
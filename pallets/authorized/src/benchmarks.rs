@@ -23,11 +23,14 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use crate::{
-    market_mock, AuthorizedOutcomeReports, Call, Config, NegativeImbalanceOf, Pallet as Authorized,
-    Pallet,
+    market_mock, AuthorizedOutcomeReports, Call, Config, CreatorPanels, MarketPanels,
+    NegativeImbalanceOf, Pallet as Authorized, Pallet, Panel, PanelMembersOf, PanelReport,
+    PanelRound, PanelRoundOf, PanelRounds,
 };
-use frame_benchmarking::benchmarks;
+use alloc::vec::Vec;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_support::traits::{EnsureOrigin, Get, Imbalance, UnfilteredDispatchable};
+use frame_system::RawOrigin;
 use pallet_pm_market_commons::MarketCommonsPalletApi;
 use prediction_market_primitives::{
     traits::{DisputeApi, DisputeResolutionApi},
@@ -35,6 +38,30 @@ use prediction_market_primitives::{
 };
 use sp_runtime::traits::Saturating;
 
+fn panel_members<T: Config>(m: u32) -> PanelMembersOf<T> {
+    (0..m).map(|i| account("member", i, 0)).collect::<Vec<_>>().try_into().unwrap()
+}
+
+/// Returns a round of a panel with `T::MaxPanelMembers` members in which all but one member
+/// submitted a different outcome.
+fn split_panel_round<T: Config>() -> PanelRoundOf<T> {
+    let m = T::MaxPanelMembers::get();
+    let members = panel_members::<T>(m);
+    let reports = members
+        .iter()
+        .take(m.saturating_sub(1) as usize)
+        .enumerate()
+        .map(|(i, member)| PanelReport {
+            member: member.clone(),
+            outcome: OutcomeReport::Scalar(i as u128),
+            justification: Default::default(),
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap();
+    PanelRound { panel: Panel { members, quorum: m }, deadline: 0u32.into(), reports }
+}
+
 benchmarks! {
     authorize_market_outcome_first_report {
         let m in 1..63;
@@ -94,10 +121,82 @@ benchmarks! {
         assert_eq!(AuthorizedOutcomeReports::<T>::get(market_id).unwrap(), report);
     }
 
+    set_market_panel {
+        let m in 1..T::MaxPanelMembers::get();
+
+        let origin = T::AuthorizedDisputeResolutionOrigin::try_successful_origin().unwrap();
+        let market_id = T::MarketCommons::push_market(market_mock::<T>()).unwrap();
+        let members = panel_members::<T>(m);
+        let call = Call::<T>::set_market_panel { market_id, members, quorum: m };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    } verify {
+        assert!(MarketPanels::<T>::contains_key(market_id));
+    }
+
+    set_creator_panel {
+        let m in 1..T::MaxPanelMembers::get();
+
+        let origin = T::AuthorizedDisputeResolutionOrigin::try_successful_origin().unwrap();
+        let creator: T::AccountId = whitelisted_caller();
+        let members = panel_members::<T>(m);
+        let call = Call::<T>::set_creator_panel { creator: creator.clone(), members, quorum: m };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    } verify {
+        assert!(CreatorPanels::<T>::contains_key(&creator));
+    }
+
+    submit_panel_report {
+        let r in 1..63;
+        let m in 1..T::MaxPanelMembers::get();
+
+        let market_id = T::MarketCommons::push_market(market_mock::<T>()).unwrap();
+        frame_system::Pallet::<T>::set_block_number(42u32.into());
+        let now = frame_system::Pallet::<T>::block_number();
+        let resolve_at = now.saturating_add(1u32.into());
+        for _ in 1..=r {
+            let id = T::MarketCommons::push_market(market_mock::<T>()).unwrap();
+            T::DisputeResolution::add_auto_resolve(&id, resolve_at).unwrap();
+        }
+
+        // All members agree, so the last report reaches the quorum.
+        let members = panel_members::<T>(m);
+        let last = members[members.len() - 1].clone();
+        let reports = members
+            .iter()
+            .take(members.len() - 1)
+            .map(|member| PanelReport {
+                member: member.clone(),
+                outcome: OutcomeReport::Scalar(1),
+                justification: Default::default(),
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+        let deadline = now.saturating_add(T::PanelReportPeriod::get());
+        let round = PanelRound { panel: Panel { members, quorum: m }, deadline, reports };
+        PanelRounds::<T>::insert(market_id, round);
+
+        let call = Call::<T>::submit_panel_report {
+            market_id,
+            outcome: OutcomeReport::Scalar(1),
+            justification: Default::default(),
+        };
+    }: {
+        call.dispatch_bypass_filter(RawOrigin::Signed(last).into())?
+    } verify {
+        let report = AuthorityReport { resolve_at, outcome: OutcomeReport::Scalar(1) };
+        assert_eq!(AuthorizedOutcomeReports::<T>::get(market_id).unwrap(), report);
+    }
+
     on_dispute_weight {
         let market_id = 0u32.into();
         let market = market_mock::<T>();
         T::MarketCommons::push_market(market.clone()).unwrap();
+        let members = panel_members::<T>(T::MaxPanelMembers::get());
+        let panel = Panel { members, quorum: T::MaxPanelMembers::get() };
+        CreatorPanels::<T>::insert(&market.creator, panel);
     }: {
         Authorized::<T>::on_dispute(&market_id, &market).unwrap();
     }
@@ -138,6 +237,7 @@ benchmarks! {
         let market_id = 0u32.into();
         let market = market_mock::<T>();
         T::MarketCommons::push_market(market.clone()).unwrap();
+        PanelRounds::<T>::insert(market_id, split_panel_round::<T>());
     }: {
         Authorized::<T>::has_failed(&market_id, &market).unwrap();
     }
@@ -146,6 +246,7 @@ benchmarks! {
         let market_id = 0u32.into();
         let market = market_mock::<T>();
         T::MarketCommons::push_market(market.clone()).unwrap();
+        PanelRounds::<T>::insert(market_id, split_panel_round::<T>());
     }: {
         Authorized::<T>::on_global_dispute(&market_id, &market).unwrap();
     }
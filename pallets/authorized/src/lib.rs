@@ -27,14 +27,16 @@ pub mod migrations;
 mod mock;
 mod mock_storage;
 mod tests;
+mod types;
 pub mod weights;
 
 pub use authorized_pallet_api::AuthorizedPalletApi;
 pub use pallet::*;
+pub use types::*;
 
 #[frame_support::pallet]
 mod pallet {
-    use crate::{weights::WeightInfoZeitgeist, AuthorizedPalletApi};
+    use crate::{types::*, weights::WeightInfoZeitgeist, AuthorizedPalletApi};
     use alloc::vec::Vec;
     use core::marker::PhantomData;
     use frame_support::{
@@ -42,9 +44,12 @@ mod pallet {
         ensure,
         pallet_prelude::{ConstU32, EnsureOrigin, OptionQuery, StorageMap, Weight},
         traits::{Currency, Get, Hooks, IsType, StorageVersion},
-        PalletId, Twox64Concat,
+        BoundedVec, PalletId, Twox64Concat,
+    };
+    use frame_system::{
+        ensure_signed,
+        pallet_prelude::{BlockNumberFor, OriginFor},
     };
-    use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
     use pallet_pm_market_commons::MarketCommonsPalletApi;
     use prediction_market_primitives::{
         traits::{DisputeApi, DisputeMaxWeightApi, DisputeResolutionApi},
@@ -53,7 +58,10 @@ mod pallet {
             OutcomeReport, ResultWithWeightInfo,
         },
     };
-    use sp_runtime::{traits::Saturating, DispatchError, DispatchResult};
+    use sp_runtime::{
+        traits::{Saturating, Zero},
+        DispatchError, DispatchResult,
+    };
 
     /// The current storage version.
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
@@ -66,6 +74,16 @@ mod pallet {
     pub(crate) type MarketIdOf<T> =
         <<T as Config>::MarketCommons as MarketCommonsPalletApi>::MarketId;
     pub(crate) type MomentOf<T> = <<T as Config>::MarketCommons as MarketCommonsPalletApi>::Moment;
+    pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+    pub(crate) type PanelMembersOf<T> = BoundedVec<AccountIdOf<T>, <T as Config>::MaxPanelMembers>;
+    pub(crate) type PanelOf<T> = Panel<PanelMembersOf<T>>;
+    pub(crate) type PanelReportOf<T> =
+        PanelReport<AccountIdOf<T>, <T as frame_system::Config>::Hash>;
+    pub(crate) type PanelRoundOf<T> = PanelRound<
+        BlockNumberFor<T>,
+        PanelMembersOf<T>,
+        BoundedVec<PanelReportOf<T>, <T as Config>::MaxPanelMembers>,
+    >;
     pub type CacheSize = ConstU32<64>;
     pub(crate) type MarketOf<T> = Market<
         <T as frame_system::Config>::AccountId,
@@ -95,6 +113,7 @@ mod pallet {
             ensure!(market.status == MarketStatus::Disputed, Error::<T>::MarketIsNotDisputed);
             ensure!(market.matches_outcome_report(&outcome), Error::<T>::OutcomeMismatch);
             Self::ensure_dispute_mechanism(&market)?;
+            ensure!(!Self::has_panel(&market_id, &market), Error::<T>::MarketHasPanel);
 
             let now = frame_system::Pallet::<T>::block_number();
 
@@ -121,6 +140,151 @@ mod pallet {
                 Ok(Some(T::WeightInfo::authorize_market_outcome_existing_report()).into())
             }
         }
+
+        /// Sets the panel which resolves the dispute of the market, replacing the panel of the
+        /// market creator. An empty set of `members` removes the panel.
+        ///
+        /// The panel is fixed as soon as the market is disputed, so later changes only affect
+        /// markets which aren't disputed yet.
+        ///
+        /// # Arguments
+        ///
+        /// - `market_id`: The identifier of the market.
+        /// - `members`: The accounts which are allowed to submit an outcome.
+        /// - `quorum`: The number of members which need to agree on an outcome. Must be a
+        ///   majority of the members.
+        ///
+        /// Complexity: `O(m^2)`, where `m` is the number of members.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_market_panel(members.len() as u32))]
+        #[frame_support::transactional]
+        pub fn set_market_panel(
+            origin: OriginFor<T>,
+            market_id: MarketIdOf<T>,
+            members: PanelMembersOf<T>,
+            quorum: u32,
+        ) -> DispatchResult {
+            T::AuthorizedDisputeResolutionOrigin::ensure_origin(origin)?;
+            let market = T::MarketCommons::market(&market_id)?;
+            Self::ensure_dispute_mechanism(&market)?;
+            let panel = Self::new_panel(members, quorum)?;
+
+            MarketPanels::<T>::set(market_id, panel.clone());
+
+            Self::deposit_event(Event::MarketPanelSet { market_id, panel });
+
+            Ok(())
+        }
+
+        /// Sets the panel which resolves the disputes of all markets of `creator` which don't
+        /// have a panel of their own. An empty set of `members` removes the panel.
+        ///
+        /// # Arguments
+        ///
+        /// - `creator`: The market creator.
+        /// - `members`: The accounts which are allowed to submit an outcome.
+        /// - `quorum`: The number of members which need to agree on an outcome. Must be a
+        ///   majority of the members.
+        ///
+        /// Complexity: `O(m^2)`, where `m` is the number of members.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::set_creator_panel(members.len() as u32))]
+        #[frame_support::transactional]
+        pub fn set_creator_panel(
+            origin: OriginFor<T>,
+            creator: AccountIdOf<T>,
+            members: PanelMembersOf<T>,
+            quorum: u32,
+        ) -> DispatchResult {
+            T::AuthorizedDisputeResolutionOrigin::ensure_origin(origin)?;
+            let panel = Self::new_panel(members, quorum)?;
+
+            CreatorPanels::<T>::set(&creator, panel.clone());
+
+            Self::deposit_event(Event::CreatorPanelSet { creator, panel });
+
+            Ok(())
+        }
+
+        /// Submits the outcome of a panel member for a disputed market. Each member submits
+        /// exactly once. As soon as the quorum agrees on an outcome, the market is scheduled to
+        /// resolve to it in the next block.
+        ///
+        /// If the panel can no longer reach a quorum, or if the `PanelReportPeriod` ends without
+        /// one, the dispute mechanism has failed and a global dispute can be started.
+        ///
+        /// # Arguments
+        ///
+        /// - `market_id`: The identifier of the market.
+        /// - `outcome`: The outcome the member considers correct.
+        /// - `justification`: The hash of the member's reasoning, which is published off-chain.
+        ///
+        /// Complexity: `O(m^2)`, where `m` is the number of panel members.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::submit_panel_report(
+            CacheSize::get(),
+            T::MaxPanelMembers::get()
+        ))]
+        #[frame_support::transactional]
+        pub fn submit_panel_report(
+            origin: OriginFor<T>,
+            market_id: MarketIdOf<T>,
+            outcome: OutcomeReport,
+            justification: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(market.status == MarketStatus::Disputed, Error::<T>::MarketIsNotDisputed);
+            ensure!(market.matches_outcome_report(&outcome), Error::<T>::OutcomeMismatch);
+            Self::ensure_dispute_mechanism(&market)?;
+            ensure!(
+                !AuthorizedOutcomeReports::<T>::contains_key(market_id),
+                Error::<T>::QuorumReachedAlready
+            );
+
+            // Markets which were disputed before their panel was set open the round lazily.
+            let mut round = match PanelRounds::<T>::get(market_id) {
+                Some(round) => round,
+                None => Self::open_panel_round(&market_id, &market)
+                    .ok_or(Error::<T>::MarketHasNoPanel)?,
+            };
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now <= round.deadline, Error::<T>::PanelReportPeriodOver);
+            ensure!(round.panel.members.contains(&who), Error::<T>::NotAPanelMember);
+            ensure!(
+                !round.reports.iter().any(|report| report.member == who),
+                Error::<T>::PanelReportSubmittedAlready
+            );
+
+            let report =
+                PanelReport { member: who.clone(), outcome: outcome.clone(), justification };
+            // Can't fail, since there is at most one report per member.
+            round.reports.try_push(report).map_err(|_| Error::<T>::NotAPanelMember)?;
+
+            Self::deposit_event(Event::PanelReportSubmitted {
+                market_id,
+                member: who,
+                outcome: outcome.clone(),
+                justification,
+            });
+
+            let members_len = round.panel.members.len() as u32;
+            let agreeing = round.reports.iter().filter(|report| report.outcome == outcome).count();
+            let mut ids_len = 0u32;
+            if agreeing as u32 >= round.panel.quorum {
+                let resolve_at = now.saturating_add(1u32.into());
+                ids_len = T::DisputeResolution::add_auto_resolve(&market_id, resolve_at)?;
+                let report = AuthorityReport { resolve_at, outcome: outcome.clone() };
+                AuthorizedOutcomeReports::<T>::insert(market_id, report);
+                Self::deposit_event(Event::PanelQuorumReached { market_id, outcome });
+            } else if Self::is_split(&round) {
+                Self::deposit_event(Event::PanelSplit { market_id });
+            }
+
+            PanelRounds::<T>::insert(market_id, round);
+
+            Ok(Some(T::WeightInfo::submit_panel_report(ids_len, members_len)).into())
+        }
     }
 
     #[pallet::config]
@@ -151,6 +315,14 @@ mod pallet {
         /// The origin that is allowed to resolved disupute in Authorized dispute mechanism.
         type AuthorizedDisputeResolutionOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+        /// The maximum number of members of a panel.
+        #[pallet::constant]
+        type MaxPanelMembers: Get<u32>;
+
+        /// The period after the dispute in which the panel members can submit their outcomes.
+        #[pallet::constant]
+        type PanelReportPeriod: Get<BlockNumberFor<Self>>;
+
         /// Identifier of this pallet
         #[pallet::constant]
         type PalletId: Get<PalletId>;
@@ -167,6 +339,20 @@ mod pallet {
         MarketIsNotDisputed,
         /// The report does not match the market's type.
         OutcomeMismatch,
+        /// The market is resolved by a panel, so the authority can't report on its own.
+        MarketHasPanel,
+        /// Neither the market nor its creator has a panel.
+        MarketHasNoPanel,
+        /// The members contain duplicates or the quorum isn't a majority of the members.
+        InvalidPanel,
+        /// The sender is not a member of the market's panel.
+        NotAPanelMember,
+        /// The sender already submitted an outcome for this market.
+        PanelReportSubmittedAlready,
+        /// The period in which the panel members can submit their outcomes is over.
+        PanelReportPeriodOver,
+        /// The panel already agreed on an outcome.
+        QuorumReachedAlready,
     }
 
     #[pallet::event]
@@ -177,6 +363,21 @@ mod pallet {
     {
         /// The Authority reported.
         AuthorityReported { market_id: MarketIdOf<T>, outcome: OutcomeReport },
+        /// The panel of a market was set or removed.
+        MarketPanelSet { market_id: MarketIdOf<T>, panel: Option<PanelOf<T>> },
+        /// The panel of a market creator was set or removed.
+        CreatorPanelSet { creator: AccountIdOf<T>, panel: Option<PanelOf<T>> },
+        /// A panel member submitted an outcome.
+        PanelReportSubmitted {
+            market_id: MarketIdOf<T>,
+            member: AccountIdOf<T>,
+            outcome: OutcomeReport,
+            justification: T::Hash,
+        },
+        /// The panel agreed on an outcome, which the market resolves to.
+        PanelQuorumReached { market_id: MarketIdOf<T>, outcome: OutcomeReport },
+        /// The panel can no longer reach a quorum, so a global dispute can be started.
+        PanelSplit { market_id: MarketIdOf<T> },
     }
 
     #[pallet::hooks]
@@ -203,6 +404,58 @@ mod pallet {
             );
             Ok(())
         }
+
+        /// Returns `None` if `members` is empty and the panel otherwise.
+        fn new_panel(
+            members: PanelMembersOf<T>,
+            quorum: u32,
+        ) -> Result<Option<PanelOf<T>>, DispatchError> {
+            if members.is_empty() {
+                return Ok(None);
+            }
+            let has_duplicates =
+                members.iter().enumerate().any(|(i, member)| members[..i].contains(member));
+            let members_len = members.len() as u32;
+            ensure!(
+                !has_duplicates && quorum <= members_len && quorum.saturating_mul(2) > members_len,
+                Error::<T>::InvalidPanel
+            );
+            Ok(Some(Panel { members, quorum }))
+        }
+
+        fn has_panel(market_id: &MarketIdOf<T>, market: &MarketOf<T>) -> bool {
+            PanelRounds::<T>::contains_key(market_id)
+                || MarketPanels::<T>::contains_key(market_id)
+                || CreatorPanels::<T>::contains_key(&market.creator)
+        }
+
+        /// Returns a new round for the panel of the market or, if it has none, of its creator.
+        fn open_panel_round(
+            market_id: &MarketIdOf<T>,
+            market: &MarketOf<T>,
+        ) -> Option<PanelRoundOf<T>> {
+            let panel = MarketPanels::<T>::get(market_id)
+                .or_else(|| CreatorPanels::<T>::get(&market.creator))?;
+            let now = frame_system::Pallet::<T>::block_number();
+            Some(PanelRound {
+                panel,
+                deadline: now.saturating_add(T::PanelReportPeriod::get()),
+                reports: Default::default(),
+            })
+        }
+
+        /// Returns `true` if no outcome can reach the quorum, even if all outstanding members
+        /// agree with the leading outcome.
+        pub(crate) fn is_split(round: &PanelRoundOf<T>) -> bool {
+            let outstanding = round.panel.members.len().saturating_sub(round.reports.len());
+            let leading = round
+                .reports
+                .iter()
+                .map(|report| round.reports.iter().filter(|r| r.outcome == report.outcome).count())
+                .max()
+                .unwrap_or(0);
+            (leading.saturating_add(outstanding) as u32) < round.panel.quorum
+        }
     }
 
     impl<T> DisputeMaxWeightApi for Pallet<T>
@@ -251,11 +504,15 @@ mod pallet {
         type Origin = T::RuntimeOrigin;
 
         fn on_dispute(
-            _: &Self::MarketId,
+            market_id: &Self::MarketId,
             market: &MarketOf<T>,
         ) -> Result<ResultWithWeightInfo<()>, DispatchError> {
             Self::ensure_dispute_mechanism(market)?;
 
+            if let Some(round) = Self::open_panel_round(market_id, market) {
+                PanelRounds::<T>::insert(market_id, round);
+            }
+
             let res =
                 ResultWithWeightInfo { result: (), weight: T::WeightInfo::on_dispute_weight() };
 
@@ -268,6 +525,7 @@ mod pallet {
         ) -> Result<ResultWithWeightInfo<Option<OutcomeReport>>, DispatchError> {
             Self::ensure_dispute_mechanism(market)?;
             let report = AuthorizedOutcomeReports::<T>::take(market_id);
+            PanelRounds::<T>::remove(market_id);
 
             let res = ResultWithWeightInfo {
                 result: report.map(|r| r.outcome),
@@ -312,19 +570,30 @@ mod pallet {
         }
 
         fn has_failed(
-            _: &Self::MarketId,
+            market_id: &Self::MarketId,
             market: &MarketOf<T>,
         ) -> Result<ResultWithWeightInfo<bool>, DispatchError> {
             Self::ensure_dispute_mechanism(market)?;
 
-            let res =
-                ResultWithWeightInfo { result: false, weight: T::WeightInfo::has_failed_weight() };
+            // Only a panel can fail. A single authority can always correct its report.
+            let has_failed = match PanelRounds::<T>::get(market_id) {
+                Some(round) if !AuthorizedOutcomeReports::<T>::contains_key(market_id) => {
+                    let now = frame_system::Pallet::<T>::block_number();
+                    round.deadline < now || Self::is_split(&round)
+                },
+                _ => false,
+            };
+
+            let res = ResultWithWeightInfo {
+                result: has_failed,
+                weight: T::WeightInfo::has_failed_weight(),
+            };
 
             Ok(res)
         }
 
         fn on_global_dispute(
-            _: &Self::MarketId,
+            market_id: &Self::MarketId,
             market: &MarketOf<T>,
         ) -> Result<
             ResultWithWeightInfo<Vec<GlobalDisputeItem<Self::AccountId, Self::Balance>>>,
@@ -332,8 +601,23 @@ mod pallet {
         > {
             Self::ensure_dispute_mechanism(market)?;
 
+            // The outcomes of the panel members are added to the global dispute.
+            let gd_items = PanelRounds::<T>::take(market_id)
+                .map(|round| {
+                    round
+                        .reports
+                        .into_iter()
+                        .map(|report| GlobalDisputeItem {
+                            outcome: report.outcome,
+                            owner: report.member,
+                            initial_vote_amount: Zero::zero(),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
             let res = ResultWithWeightInfo {
-                result: Vec::new(),
+                result: gd_items,
                 weight: T::WeightInfo::on_global_dispute_weight(),
             };
 
@@ -347,6 +631,7 @@ mod pallet {
             Self::ensure_dispute_mechanism(market)?;
 
             AuthorizedOutcomeReports::<T>::remove(market_id);
+            PanelRounds::<T>::remove(market_id);
 
             let res = ResultWithWeightInfo { result: (), weight: T::WeightInfo::clear_weight() };
 
@@ -361,6 +646,21 @@ mod pallet {
     #[pallet::getter(fn outcomes)]
     pub type AuthorizedOutcomeReports<T: Config> =
         StorageMap<_, Twox64Concat, MarketIdOf<T>, AuthorityReport<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Maps the market id to the panel which resolves its dispute.
+    #[pallet::storage]
+    pub type MarketPanels<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, PanelOf<T>, OptionQuery>;
+
+    /// Maps the market creator to the panel which resolves the disputes of their markets.
+    #[pallet::storage]
+    pub type CreatorPanels<T: Config> =
+        StorageMap<_, Twox64Concat, AccountIdOf<T>, PanelOf<T>, OptionQuery>;
+
+    /// Maps the market id to the panel round of its dispute.
+    #[pallet::storage]
+    pub type PanelRounds<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, PanelRoundOf<T>, OptionQuery>;
 }
 
 #[cfg(any(feature = "runtime-benchmarks", test))]
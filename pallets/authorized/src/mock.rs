@@ -28,7 +28,7 @@ use frame_system::{mocking::MockBlockU32, EnsureSignedBy};
 use prediction_market_primitives::{
     constants::mock::{
         AuthorizedPalletId, BlockHashCount, CorrectionPeriod, ExistentialDeposit, MaxLocks,
-        MaxReserves, MinimumPeriod, BASE,
+        MaxPanelMembers, MaxReserves, MinimumPeriod, PanelReportPeriod, BASE,
     },
     traits::{DisputeResolutionApi, MarketOfDisputeResolutionApi},
     types::{AccountIdTest, MarketId},
@@ -41,6 +41,7 @@ use sp_runtime::{
 pub const ALICE: AccountIdTest = 0;
 pub const BOB: AccountIdTest = 1;
 pub const CHARLIE: AccountIdTest = 2;
+pub const DAVE: AccountIdTest = 3;
 
 construct_runtime!(
     pub enum Runtime {
@@ -107,7 +108,9 @@ impl crate::Config for Runtime {
     type CorrectionPeriod = CorrectionPeriod;
     type DisputeResolution = MockResolution;
     type MarketCommons = MarketCommons;
+    type MaxPanelMembers = MaxPanelMembers;
     type PalletId = AuthorizedPalletId;
+    type PanelReportPeriod = PanelReportPeriod;
     type AuthorizedDisputeResolutionOrigin =
         EnsureSignedBy<AuthorizedDisputeResolutionUser, AccountIdTest>;
     type WeightInfo = crate::weights::WeightInfo<Runtime>;
@@ -20,18 +20,43 @@
 
 use crate::{
     market_mock,
-    mock::{Authorized, AuthorizedDisputeResolutionUser, ExtBuilder, Runtime, RuntimeOrigin, BOB},
+    mock::{
+        Authorized, AuthorizedDisputeResolutionUser, ExtBuilder, Runtime, RuntimeEvent,
+        RuntimeOrigin, System, ALICE, BOB, CHARLIE, DAVE,
+    },
     mock_storage::pallet as mock_storage,
-    AuthorizedOutcomeReports, Error,
+    AuthorizedOutcomeReports, CreatorPanels, Error, Event, MarketPanels, Panel, PanelRounds,
 };
-use frame_support::{assert_noop, assert_ok};
+use common_primitives::types::Hash;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo};
 use pallet_pm_market_commons::Markets;
 use prediction_market_primitives::{
     traits::DisputeApi,
-    types::{AuthorityReport, MarketDisputeMechanism, MarketStatus, OutcomeReport},
+    types::{
+        AccountIdTest, AuthorityReport, GlobalDisputeItem, MarketDisputeMechanism, MarketStatus,
+        OutcomeReport,
+    },
 };
 use sp_runtime::DispatchError;
 
+fn set_market_panel(members: Vec<AccountIdTest>, quorum: u32) {
+    assert_ok!(Authorized::set_market_panel(
+        RuntimeOrigin::signed(AuthorizedDisputeResolutionUser::get()),
+        0,
+        members.try_into().unwrap(),
+        quorum,
+    ));
+}
+
+fn submit_panel_report(who: AccountIdTest, outcome: u128) -> DispatchResultWithPostInfo {
+    Authorized::submit_panel_report(
+        RuntimeOrigin::signed(who),
+        0,
+        OutcomeReport::Scalar(outcome),
+        Hash::repeat_byte(who as u8),
+    )
+}
+
 #[test]
 fn authorize_market_outcome_inserts_a_new_outcome() {
     ExtBuilder::default().build().execute_with(|| {
@@ -260,3 +285,237 @@ fn get_auto_resolve_returns_none_without_market_storage() {
         assert_eq!(Authorized::get_auto_resolve(&0, &market).result, None,);
     });
 }
+
+#[test]
+fn set_market_panel_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        set_market_panel(vec![BOB, CHARLIE, DAVE], 2);
+        let panel = Panel { members: vec![BOB, CHARLIE, DAVE].try_into().unwrap(), quorum: 2 };
+        assert_eq!(MarketPanels::<Runtime>::get(0), Some(panel.clone()));
+        System::assert_last_event(
+            Event::<Runtime>::MarketPanelSet { market_id: 0, panel: Some(panel) }.into(),
+        );
+
+        set_market_panel(vec![], 0);
+        assert_eq!(MarketPanels::<Runtime>::get(0), None);
+    });
+}
+
+#[test]
+fn set_market_panel_fails_on_invalid_panel() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        for (members, quorum) in
+            [(vec![BOB, CHARLIE, DAVE], 1), (vec![BOB, CHARLIE], 3), (vec![BOB, BOB, CHARLIE], 2)]
+        {
+            assert_noop!(
+                Authorized::set_market_panel(
+                    RuntimeOrigin::signed(AuthorizedDisputeResolutionUser::get()),
+                    0,
+                    members.try_into().unwrap(),
+                    quorum,
+                ),
+                Error::<Runtime>::InvalidPanel
+            );
+        }
+    });
+}
+
+#[test]
+fn set_creator_panel_fails_on_unauthorized_account() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Authorized::set_creator_panel(
+                RuntimeOrigin::signed(BOB),
+                ALICE,
+                vec![BOB].try_into().unwrap(),
+                1,
+            ),
+            DispatchError::BadOrigin,
+        );
+    });
+}
+
+#[test]
+fn on_dispute_prefers_the_market_panel_over_the_creator_panel() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(0, &market);
+        assert_ok!(Authorized::set_creator_panel(
+            RuntimeOrigin::signed(AuthorizedDisputeResolutionUser::get()),
+            market.creator,
+            vec![BOB].try_into().unwrap(),
+            1,
+        ));
+        assert!(CreatorPanels::<Runtime>::contains_key(market.creator));
+        set_market_panel(vec![CHARLIE, DAVE], 2);
+
+        assert_ok!(Authorized::on_dispute(&0, &market));
+        let round = PanelRounds::<Runtime>::get(0).unwrap();
+        assert_eq!(round.panel.members.into_inner(), vec![CHARLIE, DAVE]);
+        let now = System::block_number();
+        assert_eq!(round.deadline, now + <Runtime as crate::Config>::PanelReportPeriod::get());
+    });
+}
+
+#[test]
+fn submit_panel_report_resolves_market_once_quorum_is_reached() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(0, &market);
+        set_market_panel(vec![BOB, CHARLIE, DAVE], 2);
+        assert_ok!(Authorized::on_dispute(&0, &market));
+
+        assert_ok!(submit_panel_report(BOB, 1));
+        assert_ok!(submit_panel_report(CHARLIE, 2));
+        assert_eq!(AuthorizedOutcomeReports::<Runtime>::get(0), None);
+        let report = PanelRounds::<Runtime>::get(0).unwrap().reports[0].clone();
+        assert_eq!(report.justification, Hash::repeat_byte(BOB as u8));
+
+        assert_ok!(submit_panel_report(DAVE, 2));
+        let resolve_at = System::block_number() + 1;
+        assert_eq!(
+            AuthorizedOutcomeReports::<Runtime>::get(0).unwrap(),
+            AuthorityReport { outcome: OutcomeReport::Scalar(2), resolve_at }
+        );
+        assert_eq!(mock_storage::MarketIdsPerDisputeBlock::<Runtime>::get(resolve_at), vec![0]);
+        System::assert_last_event(
+            Event::<Runtime>::PanelQuorumReached {
+                market_id: 0,
+                outcome: OutcomeReport::Scalar(2),
+            }
+            .into(),
+        );
+        assert!(!Authorized::has_failed(&0, &market).unwrap().result);
+        assert_eq!(
+            Authorized::on_resolution(&0, &market).unwrap().result,
+            Some(OutcomeReport::Scalar(2))
+        );
+        assert!(!PanelRounds::<Runtime>::contains_key(0));
+    });
+}
+
+#[test]
+fn submit_panel_report_uses_creator_panel_if_market_was_disputed_before() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(0, &market);
+        assert_ok!(Authorized::on_dispute(&0, &market));
+        assert!(!PanelRounds::<Runtime>::contains_key(0));
+
+        assert_ok!(Authorized::set_creator_panel(
+            RuntimeOrigin::signed(AuthorizedDisputeResolutionUser::get()),
+            market.creator,
+            vec![BOB].try_into().unwrap(),
+            1,
+        ));
+        assert_ok!(submit_panel_report(BOB, 3));
+        assert_eq!(
+            AuthorizedOutcomeReports::<Runtime>::get(0).unwrap().outcome,
+            OutcomeReport::Scalar(3)
+        );
+    });
+}
+
+#[test]
+fn submit_panel_report_fails_on_market_without_panel() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        assert_noop!(submit_panel_report(BOB, 1), Error::<Runtime>::MarketHasNoPanel);
+    });
+}
+
+#[test]
+fn submit_panel_report_fails_on_non_member() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        set_market_panel(vec![BOB, CHARLIE], 2);
+        assert_noop!(submit_panel_report(DAVE, 1), Error::<Runtime>::NotAPanelMember);
+    });
+}
+
+#[test]
+fn submit_panel_report_fails_on_second_report() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        set_market_panel(vec![BOB, CHARLIE], 2);
+        assert_ok!(submit_panel_report(BOB, 1));
+        assert_noop!(submit_panel_report(BOB, 2), Error::<Runtime>::PanelReportSubmittedAlready);
+    });
+}
+
+#[test]
+fn submit_panel_report_fails_after_report_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(0, &market);
+        set_market_panel(vec![BOB, CHARLIE], 2);
+        assert_ok!(Authorized::on_dispute(&0, &market));
+        let deadline = PanelRounds::<Runtime>::get(0).unwrap().deadline;
+        System::set_block_number(deadline + 1);
+        assert_noop!(submit_panel_report(BOB, 1), Error::<Runtime>::PanelReportPeriodOver);
+        assert!(Authorized::has_failed(&0, &market).unwrap().result);
+    });
+}
+
+#[test]
+fn submit_panel_report_fails_if_quorum_was_reached() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        set_market_panel(vec![BOB, CHARLIE, DAVE], 2);
+        assert_ok!(submit_panel_report(BOB, 1));
+        assert_ok!(submit_panel_report(CHARLIE, 1));
+        assert_noop!(submit_panel_report(DAVE, 2), Error::<Runtime>::QuorumReachedAlready);
+    });
+}
+
+#[test]
+fn authorize_market_outcome_fails_on_market_with_panel() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        set_market_panel(vec![BOB], 1);
+        assert_noop!(
+            Authorized::authorize_market_outcome(
+                RuntimeOrigin::signed(AuthorizedDisputeResolutionUser::get()),
+                0,
+                OutcomeReport::Scalar(1)
+            ),
+            Error::<Runtime>::MarketHasPanel
+        );
+    });
+}
+
+#[test]
+fn split_panel_fails_and_hands_its_outcomes_to_the_global_dispute() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(0, &market);
+        set_market_panel(vec![BOB, CHARLIE, DAVE], 2);
+        assert_ok!(Authorized::on_dispute(&0, &market));
+
+        assert_ok!(submit_panel_report(BOB, 1));
+        assert_ok!(submit_panel_report(CHARLIE, 2));
+        assert!(!Authorized::has_failed(&0, &market).unwrap().result);
+        assert_ok!(submit_panel_report(DAVE, 3));
+        assert!(System::events()
+            .iter()
+            .any(|r| r.event == RuntimeEvent::Authorized(Event::PanelSplit { market_id: 0 })));
+        assert!(Authorized::has_failed(&0, &market).unwrap().result);
+
+        let items = Authorized::on_global_dispute(&0, &market).unwrap().result;
+        let expected = [(BOB, 1), (CHARLIE, 2), (DAVE, 3)]
+            .into_iter()
+            .map(|(owner, outcome)| GlobalDisputeItem {
+                outcome: OutcomeReport::Scalar(outcome),
+                owner,
+                initial_vote_amount: 0,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(items, expected);
+        assert!(!PanelRounds::<Runtime>::contains_key(0));
+    });
+}
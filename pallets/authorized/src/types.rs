@@ -0,0 +1,47 @@
+// Copyright 2022-2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::pallet_prelude::{Decode, Encode, MaxEncodedLen, TypeInfo};
+use prediction_market_primitives::types::OutcomeReport;
+
+/// A set of authorities which resolve a dispute together.
+#[derive(Debug, TypeInfo, Decode, Encode, MaxEncodedLen, Clone, PartialEq, Eq)]
+pub struct Panel<Members> {
+    /// The accounts which are allowed to submit an outcome.
+    pub members: Members,
+    /// The number of members which need to agree on an outcome.
+    pub quorum: u32,
+}
+
+/// The outcome submitted by a panel member.
+#[derive(Debug, TypeInfo, Decode, Encode, MaxEncodedLen, Clone, PartialEq, Eq)]
+pub struct PanelReport<AccountId, Hash> {
+    pub member: AccountId,
+    pub outcome: OutcomeReport,
+    /// The hash of the reasoning behind the outcome, which is published off-chain.
+    pub justification: Hash,
+}
+
+/// The panel of a disputed market and the outcomes its members submitted so far.
+#[derive(Debug, TypeInfo, Decode, Encode, MaxEncodedLen, Clone, PartialEq, Eq)]
+pub struct PanelRound<BlockNumber, Members, Reports> {
+    /// The panel at the time the market was disputed.
+    pub panel: Panel<Members>,
+    /// The block after which no more outcomes can be submitted.
+    pub deadline: BlockNumber,
+    pub reports: Reports,
+}
@@ -51,6 +51,9 @@ use frame_support::{traits::Get, weights::Weight};
 pub trait WeightInfoZeitgeist {
     fn authorize_market_outcome_first_report(m: u32) -> Weight;
     fn authorize_market_outcome_existing_report() -> Weight;
+    fn set_market_panel(m: u32) -> Weight;
+    fn set_creator_panel(m: u32) -> Weight;
+    fn submit_panel_report(r: u32, m: u32) -> Weight;
     fn on_dispute_weight() -> Weight;
     fn on_resolution_weight() -> Weight;
     fn exchange_weight() -> Weight;
@@ -96,24 +99,92 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(1))
     }
-    fn on_dispute_weight() -> Weight {
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
+    /// mode: `MaxEncodedLen`) Storage: `Authorized::MarketPanels` (r:0 w:1)
+    /// Proof: `Authorized::MarketPanels` (`max_values`: None, `max_size`: Some(529), added: 3004,
+    /// mode: `MaxEncodedLen`) The range of component `m` is `[1, 16]`.
+    fn set_market_panel(m: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `561`
+        //  Estimated: `4159`
+        // Minimum execution time: 17_520 nanoseconds.
+        Weight::from_parts(18_246_811, 4159)
+            // Standard Error: 1_907
+            .saturating_add(Weight::from_parts(181_420, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Authorized::CreatorPanels` (r:0 w:1)
+    /// Proof: `Authorized::CreatorPanels` (`max_values`: None, `max_size`: Some(553), added:
+    /// 3028, mode: `MaxEncodedLen`) The range of component `m` is `[1, 16]`.
+    fn set_creator_panel(m: u32) -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `0`
         //  Estimated: `0`
-        // Minimum execution time: 280 nanoseconds.
-        Weight::from_parts(320_000, 0)
+        // Minimum execution time: 9_810 nanoseconds.
+        Weight::from_parts(10_372_405, 0)
+            // Standard Error: 1_544
+            .saturating_add(Weight::from_parts(178_093, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
+    /// mode: `MaxEncodedLen`) Storage: `Authorized::AuthorizedOutcomeReports` (r:1 w:1)
+    /// Proof: `Authorized::AuthorizedOutcomeReports` (`max_values`: None, `max_size`: Some(49),
+    /// added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::PanelRounds` (r:1 w:1)
+    /// Proof: `Authorized::PanelRounds` (`max_values`: None, `max_size`: Some(1877), added: 4352,
+    /// mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::MarketIdsPerDisputeBlock` (r:1 w:1)
+    /// Proof: `PredictionMarkets::MarketIdsPerDisputeBlock` (`max_values`: None, `max_size`:
+    /// Some(1042), added: 3517, mode: `MaxEncodedLen`) The range of component `r` is `[1, 63]`.
+    /// The range of component `m` is `[1, 16]`.
+    fn submit_panel_report(r: u32, m: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1021 + r * (22 ±0) + m * (118 ±0)`
+        //  Estimated: `5342`
+        // Minimum execution time: 41_230 nanoseconds.
+        Weight::from_parts(38_807_112, 5342)
+            // Standard Error: 3_412
+            .saturating_add(Weight::from_parts(96_125, 0).saturating_mul(r.into()))
+            // Standard Error: 13_371
+            .saturating_add(Weight::from_parts(1_204_507, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: `Authorized::MarketPanels` (r:1 w:0)
+    /// Proof: `Authorized::MarketPanels` (`max_values`: None, `max_size`: Some(529), added: 3004,
+    /// mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::CreatorPanels` (r:1 w:0)
+    /// Proof: `Authorized::CreatorPanels` (`max_values`: None, `max_size`: Some(553), added:
+    /// 3028, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::PanelRounds` (r:0 w:1)
+    /// Proof: `Authorized::PanelRounds` (`max_values`: None, `max_size`: Some(1877), added: 4352,
+    /// mode: `MaxEncodedLen`)
+    fn on_dispute_weight() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `737`
+        //  Estimated: `4018`
+        // Minimum execution time: 14_930 nanoseconds.
+        Weight::from_parts(15_460_000, 4018)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
     }
     /// Storage: `Authorized::AuthorizedOutcomeReports` (r:1 w:1)
     /// Proof: `Authorized::AuthorizedOutcomeReports` (`max_values`: None, `max_size`: Some(49),
     /// added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::PanelRounds` (r:0 w:1)
+    /// Proof: `Authorized::PanelRounds` (`max_values`: None, `max_size`: Some(1877), added: 4352,
+    /// mode: `MaxEncodedLen`)
     fn on_resolution_weight() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `217`
         //  Estimated: `3514`
-        // Minimum execution time: 7_181 nanoseconds.
-        Weight::from_parts(7_350_000, 3514)
+        // Minimum execution time: 8_431 nanoseconds.
+        Weight::from_parts(8_690_000, 3514)
             .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes(2))
     }
     fn exchange_weight() -> Weight {
         // Proof Size summary in bytes:
@@ -132,28 +203,42 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
         // Minimum execution time: 6_580 nanoseconds.
         Weight::from_parts(6_870_000, 3514).saturating_add(T::DbWeight::get().reads(1))
     }
+    /// Storage: `Authorized::PanelRounds` (r:1 w:0)
+    /// Proof: `Authorized::PanelRounds` (`max_values`: None, `max_size`: Some(1877), added: 4352,
+    /// mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::AuthorizedOutcomeReports` (r:1 w:0)
+    /// Proof: `Authorized::AuthorizedOutcomeReports` (`max_values`: None, `max_size`: Some(49),
+    /// added: 2524, mode: `MaxEncodedLen`)
     fn has_failed_weight() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 250 nanoseconds.
-        Weight::from_parts(320_000, 0)
+        //  Measured:  `2099`
+        //  Estimated: `5342`
+        // Minimum execution time: 19_440 nanoseconds.
+        Weight::from_parts(20_110_000, 5342).saturating_add(T::DbWeight::get().reads(2))
     }
+    /// Storage: `Authorized::PanelRounds` (r:1 w:1)
+    /// Proof: `Authorized::PanelRounds` (`max_values`: None, `max_size`: Some(1877), added: 4352,
+    /// mode: `MaxEncodedLen`)
     fn on_global_dispute_weight() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 260 nanoseconds.
-        Weight::from_parts(320_000, 0)
+        //  Measured:  `2099`
+        //  Estimated: `5342`
+        // Minimum execution time: 17_010 nanoseconds.
+        Weight::from_parts(17_720_000, 5342)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
     }
     /// Storage: `Authorized::AuthorizedOutcomeReports` (r:0 w:1)
     /// Proof: `Authorized::AuthorizedOutcomeReports` (`max_values`: None, `max_size`: Some(49),
     /// added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::PanelRounds` (r:0 w:1)
+    /// Proof: `Authorized::PanelRounds` (`max_values`: None, `max_size`: Some(1877), added: 4352,
+    /// mode: `MaxEncodedLen`)
     fn clear_weight() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `0`
         //  Estimated: `0`
-        // Minimum execution time: 1_780 nanoseconds.
-        Weight::from_parts(2_120_000, 0).saturating_add(T::DbWeight::get().writes(1))
+        // Minimum execution time: 2_540 nanoseconds.
+        Weight::from_parts(2_910_000, 0).saturating_add(T::DbWeight::get().writes(2))
     }
 }
@@ -0,0 +1,36 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use prediction_market_primitives::court_api_types::JurorReputation;
+use sp_runtime::Perbill;
+
+sp_api::decl_runtime_apis! {
+    pub trait CourtApi<AccountId> where
+        AccountId: Codec,
+    {
+        /// Returns the track record of `court_participant` in the courts which were reassigned.
+        fn reputation(court_participant: AccountId) -> JurorReputation;
+
+        /// Returns the reputation score of `court_participant`, which scales their weight in the
+        /// juror selection.
+        fn reputation_score(court_participant: AccountId) -> Perbill;
+    }
+}
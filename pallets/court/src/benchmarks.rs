@@ -26,14 +26,15 @@ extern crate alloc;
 use crate::{
     types::{CourtParticipantInfo, CourtPoolItem, CourtStatus, Draw, Vote},
     AppealInfo, BalanceOf, Call, Config, CourtId, CourtPool, Courts, DelegatedStakesOf,
-    MarketIdToCourtId, MarketOf, Pallet as Court, Pallet, Participants, RequestBlock,
-    SelectedDraws, VoteItem, YearlyInflation,
+    MarketIdToCourtId, MarketOf, Pallet as Court, Pallet, Participants, PendingDraws, RequestBlock,
+    SelectedDraws, UserNonces, VoteItem, YearlyInflation,
 };
 use alloc::{vec, vec::Vec};
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_support::traits::{Currency, Get, Imbalance, NamedReservableCurrency};
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use pallet_pm_market_commons::MarketCommonsPalletApi;
+use parity_scale_codec::{Decode, Encode};
 use prediction_market_primitives::{
     traits::{DisputeApi, DisputeResolutionApi},
     types::{
@@ -42,9 +43,11 @@ use prediction_market_primitives::{
     },
 };
 use sp_arithmetic::Perbill;
+use sp_avn_common::Proof;
+use sp_core::{crypto::DEV_PHRASE, H256};
 use sp_runtime::{
     traits::{Bounded, Hash, Saturating, StaticLookup, Zero},
-    SaturatedConversion,
+    RuntimeAppPublic, SaturatedConversion,
 };
 
 const ORACLE_REPORT: OutcomeReport = OutcomeReport::Scalar(u128::MAX);
@@ -87,6 +90,7 @@ where
             dispute: None,
             close_dispute: None,
             close_request: None,
+            oracles: Default::default(),
         },
         early_close: None,
     }
@@ -246,7 +250,228 @@ where
     <SelectedDraws<T>>::insert(court_id, draws);
 }
 
+fn setup_vote<T>(caller: &T::AccountId, d: u32) -> Result<CourtId, &'static str>
+where
+    T: Config,
+{
+    fill_pool::<T>(T::MaxCourtParticipants::get() - 1)?;
+
+    let (_, court_id) = setup_court::<T>()?;
+
+    let court = <Courts<T>>::get(court_id).unwrap();
+    let pre_vote = court.round_ends.pre_vote;
+
+    fill_draws::<T>(court_id, d)?;
+
+    let mut draws = <SelectedDraws<T>>::get(court_id);
+    draws.remove(0);
+    let draw = Draw {
+        court_participant: caller.clone(),
+        vote: Vote::Drawn,
+        weight: 1u32,
+        slashable: BalanceOf::<T>::zero(),
+    };
+    let index = draws
+        .binary_search_by_key(caller, |draw| draw.court_participant.clone())
+        .unwrap_or_else(|j| j);
+    draws.try_insert(index, draw).unwrap();
+    <SelectedDraws<T>>::insert(court_id, draws);
+
+    <frame_system::Pallet<T>>::set_block_number(
+        pre_vote + 1u64.saturated_into::<BlockNumberFor<T>>(),
+    );
+
+    Ok(court_id)
+}
+
+fn setup_reveal_vote<T>(
+    caller: &T::AccountId,
+    d: u32,
+) -> Result<(CourtId, VoteItem, T::Hash, T::Hash), &'static str>
+where
+    T: Config,
+{
+    fill_pool::<T>(T::MaxCourtParticipants::get() - 1)?;
+
+    let (_, court_id) = setup_court::<T>()?;
+
+    let court = <Courts<T>>::get(court_id).unwrap();
+    let vote_end = court.round_ends.vote;
+
+    fill_draws::<T>(court_id, d)?;
+
+    let salt = Default::default();
+    let outcome = OutcomeReport::Scalar(0u128);
+    let vote_item = VoteItem::Outcome(outcome);
+    join_with_min_stake::<T>(caller)?;
+    <Participants<T>>::insert(
+        caller,
+        CourtParticipantInfo {
+            stake: T::MinJurorStake::get(),
+            active_lock: T::MinJurorStake::get(),
+            prepare_exit_at: None,
+            delegations: Default::default(),
+        },
+    );
+    let commitment = T::Hashing::hash_of(&(caller.clone(), vote_item.clone(), salt));
+
+    let mut draws = <SelectedDraws<T>>::get(court_id);
+    draws.remove(0);
+    let index = draws
+        .binary_search_by_key(caller, |draw| draw.court_participant.clone())
+        .unwrap_or_else(|j| j);
+    draws
+        .try_insert(
+            index,
+            Draw {
+                court_participant: caller.clone(),
+                vote: Vote::Secret { commitment },
+                weight: 1u32,
+                slashable: T::MinJurorStake::get(),
+            },
+        )
+        .unwrap();
+    <SelectedDraws<T>>::insert(court_id, draws);
+
+    <frame_system::Pallet<T>>::set_block_number(
+        vote_end + 1u64.saturated_into::<BlockNumberFor<T>>(),
+    );
+
+    Ok((court_id, vote_item, salt, commitment))
+}
+
+#[allow(clippy::type_complexity)]
+fn setup_appeal<T>(
+    caller: &T::AccountId,
+    j: u32,
+    a: u32,
+    r: u32,
+    f: u32,
+) -> Result<(crate::MarketIdOf<T>, CourtId, BlockNumberFor<T>, BlockNumberFor<T>), &'static str>
+where
+    T: Config,
+{
+    let necessary_draws_weight =
+        Court::<T>::necessary_draws_weight((T::MaxAppeals::get() - 1) as usize);
+    debug_assert!(necessary_draws_weight == 255usize);
+    fill_pool::<T>(j)?;
+    fill_delegations::<T>();
+
+    deposit::<T>(caller);
+    // adds resolve_at for the created market
+    let (market_id, court_id) = setup_court::<T>()?;
+
+    let mut court = <Courts<T>>::get(court_id).unwrap();
+    let appeal_end = court.round_ends.appeal;
+    for i in 0..r {
+        let market_id_i = (i + 100).saturated_into::<crate::MarketIdOf<T>>();
+        T::DisputeResolution::add_auto_resolve(&market_id_i, appeal_end).unwrap();
+    }
+
+    let aggregation = court.round_ends.aggregation;
+    for i in 0..a {
+        let appeal_info = AppealInfo {
+            backer: account("backer", i, 0),
+            bond: crate::get_appeal_bond::<T>(i as usize),
+            appealed_vote_item: VoteItem::Outcome(OutcomeReport::Scalar(0u128)),
+        };
+        court.appeals.try_push(appeal_info).unwrap();
+    }
+    <Courts<T>>::insert(court_id, court);
+
+    let salt = Default::default();
+    // remove last random selections of on_dispute
+    <SelectedDraws<T>>::remove(court_id);
+    let mut draws = <SelectedDraws<T>>::get(court_id);
+    let draws_len = Court::<T>::necessary_draws_weight(a as usize) as u32;
+    for i in 0..draws_len {
+        let juror: T::AccountId = account("juror", i, 0);
+        <Participants<T>>::insert(
+            &juror,
+            CourtParticipantInfo {
+                stake: T::MinJurorStake::get(),
+                active_lock: T::MinJurorStake::get(),
+                prepare_exit_at: None,
+                delegations: Default::default(),
+            },
+        );
+        let vote_item: VoteItem = VoteItem::Outcome(OutcomeReport::Scalar(i as u128));
+        let commitment = T::Hashing::hash_of(&(juror.clone(), vote_item.clone(), salt));
+        let draw = Draw {
+            court_participant: juror,
+            vote: Vote::Revealed { commitment, vote_item, salt },
+            weight: 1u32,
+            slashable: BalanceOf::<T>::zero(),
+        };
+        draws.try_push(draw).unwrap();
+    }
+    <SelectedDraws<T>>::insert(court_id, draws);
+
+    <frame_system::Pallet<T>>::set_block_number(
+        aggregation + 1u64.saturated_into::<BlockNumberFor<T>>(),
+    );
+    let now = <frame_system::Pallet<T>>::block_number();
+    <RequestBlock<T>>::put(now + 1u64.saturated_into::<BlockNumberFor<T>>());
+
+    let new_resolve_at = <RequestBlock<T>>::get() +
+        T::VotePeriod::get() +
+        T::AggregationPeriod::get() +
+        T::AppealPeriod::get();
+    for i in 0..f {
+        let market_id_i = (i + 100).saturated_into::<crate::MarketIdOf<T>>();
+        T::DisputeResolution::add_auto_resolve(&market_id_i, new_resolve_at).unwrap();
+    }
+
+    Ok((market_id, court_id, appeal_end, new_resolve_at))
+}
+
+fn into_bytes<T: Config>(account: &<T as pallet_avn::Config>::AuthorityId) -> [u8; 32]
+where
+    T: Config + pallet_avn::Config,
+{
+    let bytes = account.encode();
+    let mut vector: [u8; 32] = Default::default();
+    vector.copy_from_slice(&bytes[0..32]);
+    vector
+}
+
+fn get_user_account<T: Config>() -> (<T as pallet_avn::Config>::AuthorityId, T::AccountId)
+where
+    T: Config + pallet_avn::Config,
+{
+    let mnemonic: &str = DEV_PHRASE;
+    let key_pair =
+        <T as pallet_avn::Config>::AuthorityId::generate_pair(Some(mnemonic.as_bytes().to_vec()));
+    let account_bytes = into_bytes::<T>(&key_pair);
+    let account_id = T::AccountId::decode(&mut &account_bytes.encode()[..]).unwrap();
+    (key_pair, account_id)
+}
+
+fn get_relayer<T: Config>() -> T::AccountId {
+    let relayer_account: H256 = H256::repeat_byte(1);
+    T::AccountId::decode(&mut relayer_account.as_bytes()).expect("valid relayer account id")
+}
+
+fn get_proof<T>(
+    key_pair: &<T as pallet_avn::Config>::AuthorityId,
+    signer: T::AccountId,
+    relayer: T::AccountId,
+    signed_payload: &[u8],
+) -> Proof<T::Signature, T::AccountId>
+where
+    T: Config + pallet_avn::Config,
+{
+    let signature = key_pair.sign(&signed_payload).unwrap().encode();
+    Proof {
+        signer,
+        relayer,
+        signature: sp_core::sr25519::Signature::from_slice(&signature).unwrap().into(),
+    }
+}
+
 benchmarks! {
+    where_clause { where T: pallet_avn::Config }
+
     join_court {
         let j in 0..(T::MaxCourtParticipants::get() - 1);
 
@@ -386,34 +611,8 @@ benchmarks! {
     vote {
         let d in 1..T::MaxSelectedDraws::get();
 
-        fill_pool::<T>(T::MaxCourtParticipants::get() - 1)?;
-
         let caller: T::AccountId = whitelisted_caller();
-        let (market_id, court_id) = setup_court::<T>()?;
-
-        let court = <Courts<T>>::get(court_id).unwrap();
-        let pre_vote = court.round_ends.pre_vote;
-
-        fill_draws::<T>(court_id, d)?;
-
-        let mut draws = <SelectedDraws<T>>::get(court_id);
-        let draws_len = draws.len();
-        draws.remove(0);
-        let draw = Draw {
-            court_participant: caller.clone(),
-            vote: Vote::Drawn,
-            weight: 1u32,
-            slashable: BalanceOf::<T>::zero(),
-        };
-        let index = draws
-                .binary_search_by_key(&caller, |draw| draw.court_participant.clone())
-                .unwrap_or_else(|j| j);
-        draws.try_insert(index, draw).unwrap();
-        <SelectedDraws<T>>::insert(court_id, draws);
-
-        <frame_system::Pallet<T>>::set_block_number(
-            pre_vote + 1u64.saturated_into::<BlockNumberFor<T>>(),
-        );
+        let court_id = setup_vote::<T>(&caller, d)?;
 
         let commitment_vote = Default::default();
     }: _(RawOrigin::Signed(caller.clone()), court_id, commitment_vote)
@@ -479,45 +678,8 @@ benchmarks! {
     reveal_vote {
         let d in 1..T::MaxSelectedDraws::get();
 
-        fill_pool::<T>(T::MaxCourtParticipants::get() - 1)?;
-
         let caller: T::AccountId = whitelisted_caller();
-        let (market_id, court_id) = setup_court::<T>()?;
-
-        let court = <Courts<T>>::get(court_id).unwrap();
-        let vote_end = court.round_ends.vote;
-
-        fill_draws::<T>(court_id, d)?;
-
-        let salt = Default::default();
-        let outcome = OutcomeReport::Scalar(0u128);
-        let vote_item = VoteItem::Outcome(outcome);
-        join_with_min_stake::<T>(&caller)?;
-        <Participants<T>>::insert(&caller, CourtParticipantInfo {
-            stake: T::MinJurorStake::get(),
-            active_lock: T::MinJurorStake::get(),
-            prepare_exit_at: None,
-            delegations: Default::default(),
-        });
-        let commitment = T::Hashing::hash_of(&(caller.clone(), vote_item.clone(), salt));
-
-        let mut draws = <SelectedDraws<T>>::get(court_id);
-        let draws_len = draws.len();
-        draws.remove(0);
-        let index = draws
-                .binary_search_by_key(&caller, |draw| draw.court_participant.clone())
-                .unwrap_or_else(|j| j);
-        draws.try_insert(index, Draw {
-            court_participant: caller.clone(),
-            vote: Vote::Secret { commitment },
-            weight: 1u32,
-            slashable: T::MinJurorStake::get(),
-        }).unwrap();
-        <SelectedDraws<T>>::insert(court_id, draws);
-
-        <frame_system::Pallet<T>>::set_block_number(
-            vote_end + 1u64.saturated_into::<BlockNumberFor<T>>()
-        );
+        let (court_id, vote_item, salt, commitment) = setup_reveal_vote::<T>(&caller, d)?;
     }: _(RawOrigin::Signed(caller.clone()), court_id, vote_item.clone(), salt)
     verify {
         let draws = <SelectedDraws<T>>::get(court_id);
@@ -534,72 +696,9 @@ benchmarks! {
         // the number of market ids inside MarketIdsPerCloseBlock at the new appeal end block
         let f in 0..62;
 
-        let necessary_draws_weight = Court::<T>::necessary_draws_weight((T::MaxAppeals::get() - 1) as usize);
-        debug_assert!(necessary_draws_weight == 255usize);
-        fill_pool::<T>(j)?;
-        fill_delegations::<T>();
-
         let caller: T::AccountId = whitelisted_caller();
-        deposit::<T>(&caller);
-        // adds resolve_at for the created market
-        let (market_id, court_id) = setup_court::<T>()?;
-
-        let mut court = <Courts<T>>::get(court_id).unwrap();
-        let appeal_end = court.round_ends.appeal;
-        for i in 0..r {
-            let market_id_i = (i + 100).saturated_into::<crate::MarketIdOf<T>>();
-            T::DisputeResolution::add_auto_resolve(&market_id_i, appeal_end).unwrap();
-        }
-
-        let aggregation = court.round_ends.aggregation;
-        for i in 0..a {
-            let appeal_info = AppealInfo {
-                backer: account("backer", i, 0),
-                bond: crate::get_appeal_bond::<T>(i as usize),
-                appealed_vote_item: VoteItem::Outcome(OutcomeReport::Scalar(0u128)),
-            };
-            court.appeals.try_push(appeal_info).unwrap();
-        }
-        <Courts<T>>::insert(court_id, court);
-
-        let salt = Default::default();
-        // remove last random selections of on_dispute
-        <SelectedDraws<T>>::remove(court_id);
-        let mut draws = <SelectedDraws<T>>::get(court_id);
-        let draws_len = Court::<T>::necessary_draws_weight(a as usize) as u32;
-        for i in 0..draws_len {
-            let juror: T::AccountId = account("juror", i, 0);
-            <Participants<T>>::insert(&juror, CourtParticipantInfo {
-                stake: T::MinJurorStake::get(),
-                active_lock: T::MinJurorStake::get(),
-                prepare_exit_at: None,
-                delegations: Default::default(),
-            });
-            let vote_item: VoteItem = VoteItem::Outcome(OutcomeReport::Scalar(i as u128));
-            let commitment = T::Hashing::hash_of(&(juror.clone(), vote_item.clone(), salt));
-            let draw =
-                Draw {
-                    court_participant: juror,
-                    vote: Vote::Revealed { commitment, vote_item, salt },
-                    weight: 1u32,
-                    slashable: BalanceOf::<T>::zero()
-                };
-            draws.try_push(draw).unwrap();
-        }
-        <SelectedDraws<T>>::insert(court_id, draws);
-
-        <frame_system::Pallet<T>>::set_block_number(aggregation + 1u64.saturated_into::<BlockNumberFor<T>>());
-        let now = <frame_system::Pallet<T>>::block_number();
-        <RequestBlock<T>>::put(now + 1u64.saturated_into::<BlockNumberFor<T>>());
-
-        let new_resolve_at = <RequestBlock<T>>::get()
-            + T::VotePeriod::get()
-            + T::AggregationPeriod::get()
-            + T::AppealPeriod::get();
-        for i in 0..f {
-            let market_id_i = (i + 100).saturated_into::<crate::MarketIdOf<T>>();
-            T::DisputeResolution::add_auto_resolve(&market_id_i, new_resolve_at).unwrap();
-        }
+        let (market_id, court_id, appeal_end, new_resolve_at) =
+            setup_appeal::<T>(&caller, j, a, r, f)?;
     }: _(RawOrigin::Signed(caller), court_id)
     verify {
         let court = <Courts<T>>::get(court_id).unwrap();
@@ -690,8 +789,14 @@ benchmarks! {
         fill_pool::<T>(T::MaxCourtParticipants::get())?;
 
         fill_delegations::<T>();
+
+        // the randomness has to become known after the court round was opened
+        let opened_at = <frame_system::Pallet<T>>::block_number();
+        <frame_system::Pallet<T>>::set_block_number(
+            opened_at + 1u64.saturated_into::<BlockNumberFor<T>>(),
+        );
     }: {
-        let _ = Court::<T>::select_participants(a as usize).unwrap();
+        let _ = Court::<T>::select_participants(a as usize, opened_at).unwrap();
     }
 
     on_dispute {
@@ -845,6 +950,216 @@ benchmarks! {
         Court::<T>::clear(&market_id, &market).unwrap();
     }
 
+    signed_join_court {
+        let j in 0..(T::MaxCourtParticipants::get() - 1);
+
+        fill_pool::<T>(j)?;
+
+        let relayer = get_relayer::<T>();
+        let (key_pair, caller) = get_user_account::<T>();
+        join_with_min_stake::<T>(&caller)?;
+
+        let joined_at_before = <frame_system::Pallet<T>>::block_number();
+        <frame_system::Pallet<T>>::set_block_number(
+            joined_at_before + 1u64.saturated_into::<BlockNumberFor<T>>(),
+        );
+
+        let new_stake = T::MinJurorStake::get()
+            .saturating_add(1u128.saturated_into::<BalanceOf<T>>());
+        let signed_payload = Court::<T>::encode_signed_join_court_params(&relayer, 0, &new_stake);
+        let proof = get_proof::<T>(&key_pair, caller.clone(), relayer, &signed_payload);
+    }: _(RawOrigin::Signed(caller.clone()), proof, new_stake)
+    verify {
+        assert_eq!(<Participants<T>>::get(&caller).unwrap().stake, new_stake);
+        assert_eq!(<UserNonces<T>>::get(&caller), 1);
+    }
+
+    signed_delegate {
+        let j in 5..(T::MaxCourtParticipants::get() - 1);
+        let d in 1..T::MaxDelegations::get();
+
+        fill_pool::<T>(j)?;
+
+        let relayer = get_relayer::<T>();
+        let (key_pair, caller) = get_user_account::<T>();
+        join_with_min_stake::<T>(&caller)?;
+
+        let joined_at_before = <frame_system::Pallet<T>>::block_number();
+        <frame_system::Pallet<T>>::set_block_number(
+            joined_at_before + 1u64.saturated_into::<BlockNumberFor<T>>(),
+        );
+
+        let juror_pool = <CourtPool<T>>::get();
+        let mut delegations = Vec::<T::AccountId>::new();
+        juror_pool.iter()
+            .filter(|pool_item| pool_item.court_participant != caller).take(d as usize)
+            .for_each(|pool_item| delegations.push(pool_item.court_participant.clone()));
+
+        let new_stake = T::MinJurorStake::get()
+            .saturating_add(1u128.saturated_into::<BalanceOf<T>>());
+        let signed_payload =
+            Court::<T>::encode_signed_delegate_params(&relayer, 0, &new_stake, &delegations);
+        let proof = get_proof::<T>(&key_pair, caller.clone(), relayer, &signed_payload);
+    }: _(RawOrigin::Signed(caller.clone()), proof, new_stake, delegations)
+    verify {
+        assert_eq!(<UserNonces<T>>::get(&caller), 1);
+    }
+
+    signed_prepare_exit_court {
+        let j in 0..(T::MaxCourtParticipants::get() - 1);
+
+        fill_pool::<T>(j)?;
+
+        let relayer = get_relayer::<T>();
+        let (key_pair, caller) = get_user_account::<T>();
+        join_with_min_stake::<T>(&caller)?;
+
+        let signed_payload = Court::<T>::encode_signed_prepare_exit_court_params(&relayer, 0);
+        let proof = get_proof::<T>(&key_pair, caller.clone(), relayer, &signed_payload);
+    }: _(RawOrigin::Signed(caller.clone()), proof)
+    verify {
+        assert!(
+            !<CourtPool<T>>::get()
+                .into_inner()
+                .iter()
+                .any(|pool_item| pool_item.court_participant == caller)
+        );
+    }
+
+    signed_exit_court {
+        let relayer = get_relayer::<T>();
+        let (key_pair, caller) = get_user_account::<T>();
+        join_with_min_stake::<T>(&caller)?;
+
+        Court::<T>::prepare_exit_court(RawOrigin::Signed(caller.clone()).into())?;
+        let now = <frame_system::Pallet<T>>::block_number();
+        <frame_system::Pallet<T>>::set_block_number(now + T::InflationPeriod::get());
+
+        <Participants<T>>::mutate(caller.clone(), |prev_p_info| {
+            prev_p_info.as_mut().unwrap().active_lock = BalanceOf::<T>::zero();
+        });
+
+        let signed_payload = Court::<T>::encode_signed_exit_court_params(&relayer, 0);
+        let proof = get_proof::<T>(&key_pair, caller.clone(), relayer, &signed_payload);
+    }: _(RawOrigin::Signed(caller.clone()), proof)
+    verify {
+        assert!(!<Participants<T>>::contains_key(caller));
+    }
+
+    signed_vote {
+        let d in 1..T::MaxSelectedDraws::get();
+
+        let relayer = get_relayer::<T>();
+        let (key_pair, caller) = get_user_account::<T>();
+        let court_id = setup_vote::<T>(&caller, d)?;
+
+        let commitment_vote = Default::default();
+        let signed_payload =
+            Court::<T>::encode_signed_vote_params(&relayer, 0, &court_id, &commitment_vote);
+        let proof = get_proof::<T>(&key_pair, caller.clone(), relayer, &signed_payload);
+    }: _(RawOrigin::Signed(caller.clone()), proof, court_id, commitment_vote)
+    verify {
+        let draws = <SelectedDraws<T>>::get(court_id);
+        let draw = draws.iter().find(|draw| draw.court_participant == caller).unwrap();
+        assert_eq!(draw.vote, Vote::Secret { commitment: commitment_vote });
+    }
+
+    signed_reveal_vote {
+        let d in 1..T::MaxSelectedDraws::get();
+
+        let relayer = get_relayer::<T>();
+        let (key_pair, caller) = get_user_account::<T>();
+        let (court_id, vote_item, salt, commitment) = setup_reveal_vote::<T>(&caller, d)?;
+
+        let signed_payload = Court::<T>::encode_signed_reveal_vote_params(
+            &relayer,
+            0,
+            &court_id,
+            &vote_item,
+            &salt,
+        );
+        let proof = get_proof::<T>(&key_pair, caller.clone(), relayer, &signed_payload);
+    }: _(RawOrigin::Signed(caller.clone()), proof, court_id, vote_item.clone(), salt)
+    verify {
+        let draws = <SelectedDraws<T>>::get(court_id);
+        let draw = draws.iter().find(|draw| draw.court_participant == caller).unwrap();
+        assert_eq!(draw.vote, Vote::Revealed { commitment, vote_item, salt });
+    }
+
+    signed_appeal {
+        let j in 255..T::MaxCourtParticipants::get();
+        let a in 0..(T::MaxAppeals::get() - 2);
+        let r in 0..62;
+        let f in 0..62;
+
+        let relayer = get_relayer::<T>();
+        let (key_pair, caller) = get_user_account::<T>();
+        let (market_id, court_id, appeal_end, new_resolve_at) =
+            setup_appeal::<T>(&caller, j, a, r, f)?;
+
+        let signed_payload = Court::<T>::encode_signed_appeal_params(&relayer, 0, &court_id);
+        let proof = get_proof::<T>(&key_pair, caller.clone(), relayer, &signed_payload);
+    }: _(RawOrigin::Signed(caller), proof, court_id)
+    verify {
+        let court = <Courts<T>>::get(court_id).unwrap();
+        assert_eq!(court.round_ends.appeal, new_resolve_at);
+        assert!(T::DisputeResolution::auto_resolve_exists(&market_id, new_resolve_at));
+        assert!(!T::DisputeResolution::auto_resolve_exists(&market_id, appeal_end));
+    }
+
+    draw_participants {
+        // from 255 because in the last appeal round we need at least 255 jurors
+        let j in 255..T::MaxCourtParticipants::get();
+        let a in 0..(T::MaxAppeals::get() - 1);
+        // the number of market ids inside MarketIdsPerCloseBlock at the old appeal end block
+        let r in 0..62;
+        // the number of market ids inside MarketIdsPerCloseBlock at the new appeal end block
+        let f in 0..62;
+
+        fill_pool::<T>(j)?;
+        fill_delegations::<T>();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let (market_id, court_id) = setup_court::<T>()?;
+
+        let mut court = <Courts<T>>::get(court_id).unwrap();
+        for i in 0..a {
+            let appeal_info = AppealInfo {
+                backer: account("backer", i, 0),
+                bond: crate::get_appeal_bond::<T>(i as usize),
+                appealed_vote_item: VoteItem::Outcome(OutcomeReport::Scalar(0u128)),
+            };
+            court.appeals.try_push(appeal_info).unwrap();
+        }
+        <Courts<T>>::insert(court_id, court.clone());
+
+        let appeal_end = court.round_ends.appeal;
+        for i in 0..r {
+            let market_id_i = (i + 100).saturated_into::<crate::MarketIdOf<T>>();
+            T::DisputeResolution::add_auto_resolve(&market_id_i, appeal_end).unwrap();
+        }
+
+        // worst case: the pre-vote period passed without draws, so the round is postponed
+        let now = court.round_ends.pre_vote + 1u64.saturated_into::<BlockNumberFor<T>>();
+        <frame_system::Pallet<T>>::set_block_number(now);
+        <RequestBlock<T>>::put(now + 1u64.saturated_into::<BlockNumberFor<T>>());
+
+        let new_resolve_at = <RequestBlock<T>>::get() +
+            T::VotePeriod::get() +
+            T::AggregationPeriod::get() +
+            T::AppealPeriod::get();
+        for i in 0..f {
+            let market_id_i = (i + 100).saturated_into::<crate::MarketIdOf<T>>();
+            T::DisputeResolution::add_auto_resolve(&market_id_i, new_resolve_at).unwrap();
+        }
+    }: _(RawOrigin::Signed(caller), court_id)
+    verify {
+        assert!(!<PendingDraws<T>>::contains_key(court_id));
+        assert!(!<SelectedDraws<T>>::get(court_id).is_empty());
+        assert!(T::DisputeResolution::auto_resolve_exists(&market_id, new_resolve_at));
+        assert!(!T::DisputeResolution::auto_resolve_exists(&market_id, appeal_end));
+    }
+
     impl_benchmark_test_suite!(
         Court,
         crate::mock::ExtBuilder::default().build(),
@@ -28,20 +28,22 @@ use crate::{
     SelectionAdd, SelectionError, SelectionValue, SelfInfo, Vote, VoteItem, VoteItemType,
 };
 use alloc::{
+    boxed::Box,
     collections::{BTreeMap, BTreeSet},
     format,
     vec::Vec,
 };
 use core::marker::PhantomData;
 use frame_support::{
-    dispatch::DispatchResult,
+    dispatch::{DispatchResult, GetDispatchInfo},
     ensure,
     pallet_prelude::{
-        ConstU32, Decode, DispatchResultWithPostInfo, Encode, EnsureOrigin, Hooks, OptionQuery,
-        StorageMap, StorageValue, TypeInfo, ValueQuery,
+        ConstU32, Decode, DispatchResultWithPostInfo, Encode, EnsureOrigin, Hooks, Member,
+        OptionQuery, Parameter, StorageMap, StorageValue, TypeInfo, ValueQuery,
     },
+    storage::with_storage_layer,
     traits::{
-        Currency, Get, Imbalance, IsType, LockIdentifier, LockableCurrency,
+        Currency, Get, Imbalance, IsSubType, IsType, LockIdentifier, LockableCurrency,
         NamedReservableCurrency, OnUnbalanced, Randomness, ReservableCurrency, StorageVersion,
         WithdrawReasons,
     },
@@ -56,6 +58,7 @@ use frame_system::{
 use node_macros::unreachable_non_terminating;
 use pallet_pm_market_commons::MarketCommonsPalletApi;
 use prediction_market_primitives::{
+    court_api_types::JurorReputation,
     math::checked_ops_res::{CheckedAddRes, CheckedRemRes, CheckedSubRes},
     traits::{DisputeApi, DisputeMaxWeightApi, DisputeResolutionApi},
     types::{
@@ -69,11 +72,23 @@ use sp_arithmetic::{
     per_things::Perquintill,
     traits::{CheckedRem, One},
 };
+use sp_avn_common::{verify_signature, InnerCallValidator, Proof};
 use sp_runtime::{
-    traits::{AccountIdConversion, CheckedDiv, Hash, Saturating, StaticLookup, Zero},
+    traits::{
+        AccountIdConversion, CheckedDiv, Dispatchable, Hash, IdentifyAccount, Saturating,
+        StaticLookup, Verify, Zero,
+    },
     DispatchError, Perbill, RuntimeDebug, SaturatedConversion,
 };
 
+pub const JOIN_COURT_CONTEXT: &[u8] = b"court::join_court_context";
+pub const DELEGATE_CONTEXT: &[u8] = b"court::delegate_context";
+pub const PREPARE_EXIT_COURT_CONTEXT: &[u8] = b"court::prepare_exit_court_context";
+pub const EXIT_COURT_CONTEXT: &[u8] = b"court::exit_court_context";
+pub const VOTE_CONTEXT: &[u8] = b"court::vote_context";
+pub const REVEAL_VOTE_CONTEXT: &[u8] = b"court::reveal_vote_context";
+pub const APPEAL_CONTEXT: &[u8] = b"court::appeal_context";
+
 mod benchmarks;
 mod court_pallet_api;
 pub mod migrations;
@@ -186,9 +201,18 @@ mod pallet {
         /// The origin for monetary governance to control the court inflation.
         type MonetaryGovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
-        /// Randomness source
+        /// The randomness source of the juror selection. The participants of a court round are
+        /// only drawn from values which became known after the round was opened, so the source
+        /// should report the block in which its output became known truthfully, like
+        /// `pallet-pm-randomness-beacon` does.
         type Random: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
+        /// The share of the selection weight of a court participant which depends on their
+        /// reputation. The rest is determined by their stake alone. Zero disables the influence of
+        /// the reputation on the juror selection.
+        #[pallet::constant]
+        type ReputationInfluence: Get<Perbill>;
+
         /// The global interval which schedules the start of new court vote periods.
         #[pallet::constant]
         type RequestInterval: Get<BlockNumberFor<Self>>;
@@ -202,6 +226,24 @@ mod pallet {
 
         /// Weights generated by benchmarks
         type WeightInfo: WeightInfoZeitgeist;
+
+        /// The overarching call type.
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = <Self as frame_system::Config>::RuntimeOrigin>
+            + IsSubType<Call<Self>>
+            + From<Call<Self>>
+            + GetDispatchInfo
+            + From<frame_system::Call<Self>>;
+
+        type Public: IdentifyAccount<AccountId = Self::AccountId>;
+
+        /// The signature type used by accounts/transactions.
+        type Signature: Verify<Signer = Self::Public>
+            + Member
+            + Decode
+            + Encode
+            + From<sp_core::sr25519::Signature>
+            + TypeInfo;
     }
 
     /// Number of draws for the initial court round.
@@ -272,6 +314,27 @@ mod pallet {
     pub type SelectedDraws<T: Config> =
         StorageMap<_, Blake2_128Concat, CourtId, SelectedDrawsOf<T>, ValueQuery>;
 
+    /// The draws of the appealed rounds of each court by appeal index. They are kept until the
+    /// stakes of the court are reassigned, so that the votes are scored against the final
+    /// winner.
+    #[pallet::storage]
+    pub type AppealedDraws<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CourtId,
+        Twox64Concat,
+        u32,
+        SelectedDrawsOf<T>,
+        ValueQuery,
+    >;
+
+    /// The courts whose participants for the current round are not drawn yet, mapped to the
+    /// block in which the round was opened. The draws only use randomness which became known
+    /// after this block.
+    #[pallet::storage]
+    pub type PendingDraws<T: Config> =
+        StorageMap<_, Blake2_128Concat, CourtId, BlockNumberFor<T>, OptionQuery>;
+
     /// The general information about each court.
     #[pallet::storage]
     pub type Courts<T: Config> = StorageMap<_, Blake2_128Concat, CourtId, CourtOf<T>, OptionQuery>;
@@ -305,6 +368,15 @@ mod pallet {
     pub type YearlyInflation<T: Config> =
         StorageValue<_, Perbill, ValueQuery, DefaultYearlyInflation<T>>;
 
+    /// The track record of each juror in the courts which were reassigned.
+    #[pallet::storage]
+    pub type Reputations<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, JurorReputation, ValueQuery>;
+
+    /// The nonce of each signer's next signed call.
+    #[pallet::storage]
+    pub type UserNonces<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(fn deposit_event)]
     pub enum Event<T>
@@ -363,6 +435,8 @@ mod pallet {
         StakesReassigned { court_id: CourtId },
         /// The yearly inflation rate has been set.
         InflationSet { inflation: Perbill },
+        /// The participants of the current round of a court have been drawn.
+        ParticipantsDrawn { court_id: CourtId, round_ends: RoundTimingOf<T> },
     }
 
     #[pallet::error]
@@ -474,6 +548,15 @@ mod pallet {
         Unexpected(UnexpectedError),
         /// The inflation rate is too high.
         InflationExceedsMaxYearlyInflation,
+        /// The sender is not the signer of the transaction.
+        SenderIsNotSigner,
+        /// Signed transaction has failed validation.
+        UnauthorizedSignedTransaction,
+        /// The participants of the current round of the court are already drawn.
+        ParticipantsAlreadyDrawn,
+        /// The randomness source has no output yet which became known after the current round
+        /// of the court was opened.
+        RandomnessNotAvailable,
     }
 
     // NOTE: these errors should never happen.
@@ -499,6 +582,10 @@ mod pallet {
             total_weight
         }
 
+        fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::draw_pending_participants(remaining_weight)
+        }
+
         fn integrity_test() {
             assert!(!T::BlocksPerYear::get().is_zero(), "Blocks per year musn't be zero!");
         }
@@ -574,37 +661,7 @@ mod pallet {
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
-            ensure!(!delegations.is_empty(), Error::<T>::NoDelegations);
-            let delegations_len = delegations.len() as u32;
-            let mut sorted_delegations: DelegationsOf<T> =
-                delegations.clone().try_into().map_err(|_| Error::<T>::MaxDelegationsReached)?;
-
-            let pool = CourtPool::<T>::get();
-            let is_valid_set = sorted_delegations.iter().all(|pretended_juror| {
-                <Participants<T>>::get(pretended_juror).map_or(false, |pretended_juror_info| {
-                    match Self::get_pool_item(&pool, pretended_juror_info.stake, pretended_juror) {
-                        Ok(Some(_)) => pretended_juror_info.delegations.is_none(),
-                        _ => false,
-                    }
-                })
-            });
-            ensure!(is_valid_set, Error::<T>::DelegatedToInvalidJuror);
-            // ensure all elements are different
-            sorted_delegations.sort();
-            let has_duplicates = sorted_delegations
-                .iter()
-                .zip(sorted_delegations.iter().skip(1))
-                .any(|(x, y)| x == y);
-            ensure!(!has_duplicates, Error::<T>::IdenticalDelegationsNotAllowed);
-            ensure!(!sorted_delegations.contains(&who), Error::<T>::SelfDelegationNotAllowed);
-
-            let pool_len = Self::do_join_court(&who, amount, Some(sorted_delegations))?;
-
-            Self::deposit_event(Event::DelegatorJoined {
-                delegator: who,
-                stake: amount,
-                delegated_jurors: delegations,
-            });
+            let (pool_len, delegations_len) = Self::do_delegate(who, amount, delegations)?;
 
             Ok(Some(T::WeightInfo::delegate(pool_len, delegations_len)).into())
         }
@@ -623,25 +680,7 @@ mod pallet {
         pub fn prepare_exit_court(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
-            let mut prev_p_info =
-                <Participants<T>>::get(&who).ok_or(Error::<T>::JurorDoesNotExist)?;
-            ensure!(prev_p_info.prepare_exit_at.is_none(), Error::<T>::AlreadyPreparedExit);
-
-            let mut pool = CourtPool::<T>::get();
-            let pool_len = pool.len() as u32;
-
-            // do not error in the else case
-            // because the juror might have been already removed from the pool
-            if let Some((index, _)) = Self::get_pool_item(&pool, prev_p_info.stake, &who)? {
-                pool.remove(index);
-                <CourtPool<T>>::put(pool);
-            }
-
-            let now = <frame_system::Pallet<T>>::block_number();
-            prev_p_info.prepare_exit_at = Some(now);
-            <Participants<T>>::insert(&who, prev_p_info);
-
-            Self::deposit_event(Event::ExitPrepared { court_participant: who });
+            let pool_len = Self::do_prepare_exit_court(who)?;
 
             Ok(Some(T::WeightInfo::prepare_exit_court(pool_len)).into())
         }
@@ -670,38 +709,12 @@ mod pallet {
 
             let who = T::Lookup::lookup(court_participant)?;
 
-            let mut prev_p_info =
-                <Participants<T>>::get(&who).ok_or(Error::<T>::JurorDoesNotExist)?;
-
-            let now = <frame_system::Pallet<T>>::block_number();
-            let prepare_exit_at =
-                prev_p_info.prepare_exit_at.ok_or(Error::<T>::PrepareExitAtNotPresent)?;
-            ensure!(
-                now.saturating_sub(prepare_exit_at) >= T::InflationPeriod::get(),
-                Error::<T>::PrematureExit
-            );
-
-            let (exit_amount, active_lock, weight) = if prev_p_info.active_lock.is_zero() {
-                T::Currency::remove_lock(T::LockId::get(), &who);
-                Participants::<T>::remove(&who);
-                (prev_p_info.stake, BalanceOf::<T>::zero(), T::WeightInfo::exit_court_remove())
+            let weight = if Self::do_exit_court(who)? {
+                T::WeightInfo::exit_court_remove()
             } else {
-                let active_lock = prev_p_info.active_lock;
-                let exit_amount = prev_p_info.stake.saturating_sub(active_lock);
-                T::Currency::set_lock(T::LockId::get(), &who, active_lock, WithdrawReasons::all());
-
-                prev_p_info.stake = active_lock;
-                Participants::<T>::insert(&who, prev_p_info);
-
-                (exit_amount, active_lock, T::WeightInfo::exit_court_set())
+                T::WeightInfo::exit_court_set()
             };
 
-            Self::deposit_event(Event::ExitedCourt {
-                court_participant: who,
-                exit_amount,
-                active_lock,
-            });
-
             Ok(Some(weight).into())
         }
 
@@ -726,41 +739,7 @@ mod pallet {
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
-            let court = <Courts<T>>::get(court_id).ok_or(Error::<T>::CourtNotFound)?;
-            let now = <frame_system::Pallet<T>>::block_number();
-            ensure!(
-                court.round_ends.pre_vote < now && now <= court.round_ends.vote,
-                Error::<T>::NotInVotingPeriod
-            );
-
-            let mut draws = <SelectedDraws<T>>::get(court_id);
-
-            match draws.binary_search_by_key(&who, |draw| draw.court_participant.clone()) {
-                Ok(index) => {
-                    let draw = draws
-                        .get_mut(index)
-                        .ok_or(Error::<T>::Unexpected(UnexpectedError::BinarySearchByKeyFailed))?;
-                    // allow to override last vote
-                    ensure!(
-                        matches!(draw.vote, Vote::Drawn | Vote::Secret { commitment: _ }),
-                        Error::<T>::InvalidVoteState
-                    );
-
-                    let vote = Vote::Secret { commitment: commitment_vote };
-                    draw.vote = vote;
-                },
-                Err(_) => return Err(Error::<T>::CallerNotInSelectedDraws.into()),
-            }
-
-            let draws_len = draws.len() as u32;
-
-            <SelectedDraws<T>>::insert(court_id, draws);
-
-            Self::deposit_event(Event::JurorVoted {
-                juror: who,
-                court_id,
-                commitment: commitment_vote,
-            });
+            let draws_len = Self::do_vote(who, court_id, commitment_vote)?;
 
             Ok(Some(T::WeightInfo::vote(draws_len)).into())
         }
@@ -850,34 +829,947 @@ mod pallet {
                 salt,
             });
 
-            Ok(Some(T::WeightInfo::denounce_vote(draws_len)).into())
+            Ok(Some(T::WeightInfo::denounce_vote(draws_len)).into())
+        }
+
+        /// Reveal the commitment vote of the caller, who is a selected juror.
+        /// A check of `commitment_hash == hash(juror ++ vote_item ++ salt)`
+        /// is performed for validation.
+        ///
+        /// # Arguments
+        ///
+        /// - `court_id`: The identifier of the court.
+        /// - `vote_item`: The raw vote item which should match with the commitment of the juror.
+        /// - `salt`: The hash which is used for the validation.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(log(n))`, where `n` is the number of selected draws
+        /// in the specified court.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::reveal_vote(T::MaxSelectedDraws::get()))]
+        #[transactional]
+        pub fn reveal_vote(
+            origin: OriginFor<T>,
+            #[pallet::compact] court_id: CourtId,
+            vote_item: VoteItem,
+            salt: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let draws_len = Self::do_reveal_vote(who, court_id, vote_item, salt)?;
+
+            Ok(Some(T::WeightInfo::reveal_vote(draws_len)).into())
+        }
+
+        /// Initiate an appeal for a court
+        /// if the presumptive winner of the last vote round is believed to be incorrect.
+        /// The last appeal does not trigger a new court round
+        /// but instead it marks the court mechanism for this market as failed.
+        /// If the court failed, the prediction markets pallet takes over the dispute resolution.
+        /// The prediction markets pallet might allow to trigger a global token holder vote.
+        /// The participants of the new court round are drawn by `draw_participants`.
+        ///
+        /// # Arguments
+        ///
+        /// - `court_id`: The identifier of the court.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(N)`, with `N` being the number of participants in the court pool.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::appeal(
+            T::MaxCourtParticipants::get(),
+            T::MaxAppeals::get(),
+            CacheSize::get(),
+            CacheSize::get(),
+        ))]
+        #[transactional]
+        pub fn appeal(
+            origin: OriginFor<T>,
+            #[pallet::compact] court_id: CourtId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let (pool_len, appeal_number, ids_len_0, ids_len_1) = Self::do_appeal(who, court_id)?;
+
+            Ok(Some(T::WeightInfo::appeal(pool_len, appeal_number, ids_len_0, ids_len_1)).into())
+        }
+
+        /// Reassign the stakes of the jurors and delegators
+        /// for the selected draws of the specified court.
+        /// The losing jurors and delegators get slashed and
+        /// pay for the winning jurors and delegators.
+        /// The tardy (juror did not reveal or did not vote) or denounced jurors
+        /// and associated delegators get slashed and reward the winners.
+        ///
+        /// # Arguments
+        ///
+        /// - `court_id`: The identifier of the court.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: O(N + M), with `N` being the number of draws of all rounds and `M` being the
+        /// total number of valid winners and losers. The draws of the appealed rounds add up to
+        /// less than `MaxSelectedDraws`.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::reassign_court_stakes(
+            T::MaxSelectedDraws::get().saturating_mul(2)
+        ))]
+        #[transactional]
+        pub fn reassign_court_stakes(
+            origin: OriginFor<T>,
+            court_id: CourtId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let mut court = <Courts<T>>::get(court_id).ok_or(Error::<T>::CourtNotFound)?;
+            let winner = match court.status {
+                CourtStatus::Closed { winner } => winner,
+                CourtStatus::Reassigned => return Err(Error::<T>::CourtAlreadyReassigned.into()),
+                CourtStatus::Open => return Err(Error::<T>::CourtNotClosed.into()),
+            };
+
+            let draws = SelectedDraws::<T>::get(court_id);
+            let mut draws_len = draws.len() as u32;
+
+            // the appealed rounds are scored against the final winner, because later rounds may
+            // have overturned the vote item which was appealed
+            for (_, appealed_draws) in <AppealedDraws<T>>::drain_prefix(court_id) {
+                draws_len = draws_len.saturating_add(appealed_draws.len() as u32);
+                for draw in appealed_draws.iter() {
+                    Self::update_reputation(draw, &winner);
+                }
+            }
+
+            let reward_pot = Self::reward_pot(court_id);
+            let slash_juror = |ai: &T::AccountId, slashable: BalanceOf<T>| {
+                let (imbalance, missing) = T::Currency::slash(ai, slashable);
+                debug_assert!(
+                    missing.is_zero(),
+                    "Could not slash all of the amount for juror {:?}.",
+                    ai
+                );
+                T::Currency::resolve_creating(&reward_pot, imbalance);
+            };
+
+            // map delegated jurors to own_slashable, vote item and Vec<(delegator,
+            // delegator_stake)>
+            let mut jurors_to_stakes = BTreeMap::<T::AccountId, JurorVoteWithStakesOf<T>>::new();
+
+            let mut handle_vote = |draw: DrawOf<T>| -> DispatchResult {
+                match draw.vote {
+                    Vote::Drawn |
+                    Vote::Secret { commitment: _ } |
+                    Vote::Denounced { commitment: _, vote_item: _, salt: _ } => {
+                        slash_juror(&draw.court_participant, draw.slashable);
+                    },
+                    Vote::Revealed { commitment: _, vote_item, salt: _ } => {
+                        jurors_to_stakes.entry(draw.court_participant).or_default().self_info =
+                            Some(SelfInfo { slashable: draw.slashable, vote_item });
+                    },
+                    Vote::Delegated { delegated_stakes } => {
+                        let delegator = draw.court_participant;
+                        for (j, delegated_stake) in delegated_stakes {
+                            // fill the delegations for each juror
+                            // [(juror_0, [(delegator_0, delegator_stake_0), ...]),
+                            // (juror_1, [(delegator_42, delegator_stake_42), ...]), ...]
+                            let jurors_to_stakes_entry = jurors_to_stakes.entry(j);
+                            let juror_vote_with_stakes = jurors_to_stakes_entry.or_default();
+
+                            // future-proof binary search by key
+                            // because many delegators can back one juror
+                            // we might want to fastly find elements later on
+                            match juror_vote_with_stakes
+                                .delegations
+                                .binary_search_by_key(&delegator, |(d, _)| d.clone())
+                            {
+                                Ok(i) => {
+                                    let delegations = juror_vote_with_stakes
+                                        .delegations
+                                        .get_mut(i)
+                                        .ok_or(Error::<T>::Unexpected(
+                                            UnexpectedError::BinarySearchByKeyFailed,
+                                        ))?;
+                                    delegations.1 = delegations.1.saturating_add(delegated_stake);
+                                },
+                                Err(i) => {
+                                    juror_vote_with_stakes
+                                        .delegations
+                                        .insert(i, (delegator.clone(), delegated_stake));
+                                },
+                            }
+                        }
+                    },
+                }
+                Ok(())
+            };
+
+            for draw in draws {
+                if let Some(mut p_info) = <Participants<T>>::get(&draw.court_participant) {
+                    p_info.active_lock = p_info.active_lock.saturating_sub(draw.slashable);
+                    <Participants<T>>::insert(&draw.court_participant, p_info);
+                } else {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "Participant {:?} not found in Participants storage \
+                         (reassign_court_stakes). Court id {:?}.",
+                        draw.court_participant,
+                        court_id
+                    );
+                    debug_assert!(false);
+                }
+
+                Self::update_reputation(&draw, &winner);
+                handle_vote(draw)?;
+            }
+
+            Self::slash_losers_to_award_winners(court_id, jurors_to_stakes, &winner);
+
+            court.status = CourtStatus::Reassigned;
+            <Courts<T>>::insert(court_id, court);
+
+            <SelectedDraws<T>>::remove(court_id);
+
+            Self::deposit_event(Event::StakesReassigned { court_id });
+
+            Ok(Some(T::WeightInfo::reassign_court_stakes(draws_len)).into())
+        }
+
+        /// Set the yearly inflation rate of the court system.
+        /// This is only allowed to be called by the `MonetaryGovernanceOrigin`.
+        ///
+        /// # Arguments
+        ///
+        /// - `inflation`: The desired yearly inflation rate.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_inflation())]
+        #[transactional]
+        pub fn set_inflation(origin: OriginFor<T>, inflation: Perbill) -> DispatchResult {
+            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                inflation <= T::MaxYearlyInflation::get(),
+                Error::<T>::InflationExceedsMaxYearlyInflation
+            );
+
+            <YearlyInflation<T>>::put(inflation);
+
+            Self::deposit_event(Event::InflationSet { inflation });
+
+            Ok(())
+        }
+
+        /// Joins the court as juror on behalf of `proof.signer`. The relayer pays the transaction
+        /// fee.
+        ///
+        /// See `join_court` for the meaning of `amount`.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(log(n))`, where `n` is the number of jurors in the stake-weighted pool.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::signed_join_court(T::MaxCourtParticipants::get()))]
+        #[transactional]
+        pub fn signed_join_court(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(who == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let nonce = <UserNonces<T>>::get(&who);
+            let signed_payload =
+                Self::encode_signed_join_court_params(&proof.relayer, nonce, &amount);
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedTransaction
+            );
+
+            let jurors_len = Self::do_join_court(&who, amount, None)?;
+
+            <UserNonces<T>>::mutate(&who, |nonce| *nonce += 1);
+            Self::deposit_event(Event::JurorJoined { juror: who, stake: amount });
+
+            Ok(Some(T::WeightInfo::signed_join_court(jurors_len)).into())
+        }
+
+        /// Joins the court as delegator on behalf of `proof.signer`. The relayer pays the
+        /// transaction fee.
+        ///
+        /// See `delegate` for the meaning of `amount` and `delegations`.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(log(n))`, where `n` is the number of jurors in the stake-weighted pool.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::signed_delegate(
+            T::MaxCourtParticipants::get(),
+            delegations.len() as u32,
+        ))]
+        #[transactional]
+        pub fn signed_delegate(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            amount: BalanceOf<T>,
+            delegations: Vec<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(who == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let nonce = <UserNonces<T>>::get(&who);
+            let signed_payload =
+                Self::encode_signed_delegate_params(&proof.relayer, nonce, &amount, &delegations);
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedTransaction
+            );
+
+            let (pool_len, delegations_len) = Self::do_delegate(who.clone(), amount, delegations)?;
+
+            <UserNonces<T>>::mutate(&who, |nonce| *nonce += 1);
+
+            Ok(Some(T::WeightInfo::signed_delegate(pool_len, delegations_len)).into())
+        }
+
+        /// Prepares the exit of `proof.signer` from the court. The relayer pays the transaction
+        /// fee.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(log(n))`, where `n` is the number of jurors in the stake-weighted pool.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::signed_prepare_exit_court(T::MaxCourtParticipants::get()))]
+        #[transactional]
+        pub fn signed_prepare_exit_court(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(who == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let nonce = <UserNonces<T>>::get(&who);
+            let signed_payload =
+                Self::encode_signed_prepare_exit_court_params(&proof.relayer, nonce);
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedTransaction
+            );
+
+            let pool_len = Self::do_prepare_exit_court(who.clone())?;
+
+            <UserNonces<T>>::mutate(&who, |nonce| *nonce += 1);
+
+            Ok(Some(T::WeightInfo::signed_prepare_exit_court(pool_len)).into())
+        }
+
+        /// Exits `proof.signer` from the court. The relayer pays the transaction fee.
+        ///
+        /// See `exit_court` for the requirements.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::signed_exit_court())]
+        #[transactional]
+        pub fn signed_exit_court(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(who == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let nonce = <UserNonces<T>>::get(&who);
+            let signed_payload = Self::encode_signed_exit_court_params(&proof.relayer, nonce);
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedTransaction
+            );
+
+            let _ = Self::do_exit_court(who.clone())?;
+
+            <UserNonces<T>>::mutate(&who, |nonce| *nonce += 1);
+            Ok(())
+        }
+
+        /// Votes on behalf of `proof.signer`, who is a selected juror. The relayer pays the
+        /// transaction fee.
+        ///
+        /// See `vote` for the meaning of `court_id` and `commitment_vote`.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(log(n))`, where `n` is the number of participants
+        /// in the list of random selections (draws).
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::signed_vote(T::MaxSelectedDraws::get()))]
+        #[transactional]
+        pub fn signed_vote(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            #[pallet::compact] court_id: CourtId,
+            commitment_vote: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(who == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let nonce = <UserNonces<T>>::get(&who);
+            let signed_payload =
+                Self::encode_signed_vote_params(&proof.relayer, nonce, &court_id, &commitment_vote);
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedTransaction
+            );
+
+            let draws_len = Self::do_vote(who.clone(), court_id, commitment_vote)?;
+
+            <UserNonces<T>>::mutate(&who, |nonce| *nonce += 1);
+
+            Ok(Some(T::WeightInfo::signed_vote(draws_len)).into())
+        }
+
+        /// Reveals the commitment vote of `proof.signer`, who is a selected juror. The relayer
+        /// pays the transaction fee.
+        ///
+        /// See `reveal_vote` for the meaning of `court_id`, `vote_item` and `salt`.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(log(n))`, where `n` is the number of selected draws
+        /// in the specified court.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::signed_reveal_vote(T::MaxSelectedDraws::get()))]
+        #[transactional]
+        pub fn signed_reveal_vote(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            #[pallet::compact] court_id: CourtId,
+            vote_item: VoteItem,
+            salt: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(who == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let nonce = <UserNonces<T>>::get(&who);
+            let signed_payload = Self::encode_signed_reveal_vote_params(
+                &proof.relayer,
+                nonce,
+                &court_id,
+                &vote_item,
+                &salt,
+            );
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedTransaction
+            );
+
+            let draws_len = Self::do_reveal_vote(who.clone(), court_id, vote_item, salt)?;
+
+            <UserNonces<T>>::mutate(&who, |nonce| *nonce += 1);
+
+            Ok(Some(T::WeightInfo::signed_reveal_vote(draws_len)).into())
+        }
+
+        /// Appeals a court on behalf of `proof.signer`, who backs the appeal with their bond. The
+        /// relayer pays the transaction fee.
+        ///
+        /// See `appeal` for the meaning of `court_id`.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(N)`, with `N` being the number of participants in the court pool.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::signed_appeal(
+            T::MaxCourtParticipants::get(),
+            T::MaxAppeals::get(),
+            CacheSize::get(),
+            CacheSize::get(),
+        ))]
+        #[transactional]
+        pub fn signed_appeal(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            #[pallet::compact] court_id: CourtId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(who == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let nonce = <UserNonces<T>>::get(&who);
+            let signed_payload =
+                Self::encode_signed_appeal_params(&proof.relayer, nonce, &court_id);
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedTransaction
+            );
+
+            let (pool_len, appeal_number, ids_len_0, ids_len_1) =
+                Self::do_appeal(who.clone(), court_id)?;
+
+            <UserNonces<T>>::mutate(&who, |nonce| *nonce += 1);
+
+            Ok(Some(T::WeightInfo::signed_appeal(pool_len, appeal_number, ids_len_0, ids_len_1))
+                .into())
+        }
+
+        /// Draw the jurors and delegators for the current round of a court.
+        /// The draws only use randomness which became known after the round was opened, so that
+        /// nobody can compute them when disputing or appealing a market.
+        /// If the pre-vote period of the round is already over, the round is postponed
+        /// so that the drawn jurors get the full vote period.
+        /// This can be called by anyone. Pending draws are also made in `on_idle` if the block has
+        /// enough weight left.
+        ///
+        /// # Arguments
+        ///
+        /// - `court_id`: The identifier of the court.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: It depends heavily on the complexity of `select_participants`.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::draw_participants(
+            T::MaxCourtParticipants::get(),
+            T::MaxAppeals::get(),
+            CacheSize::get(),
+            CacheSize::get(),
+        ))]
+        #[transactional]
+        pub fn draw_participants(
+            origin: OriginFor<T>,
+            #[pallet::compact] court_id: CourtId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let (pool_len, appeal_number, ids_len_0, ids_len_1) =
+                Self::do_draw_participants(court_id)?;
+
+            Ok(Some(T::WeightInfo::draw_participants(
+                pool_len,
+                appeal_number,
+                ids_len_0,
+                ids_len_1,
+            ))
+            .into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Draws the participants of the current round of a court and returns the components of
+        /// the `draw_participants` weight.
+        pub(crate) fn do_draw_participants(
+            court_id: CourtId,
+        ) -> Result<(u32, u32, u32, u32), DispatchError> {
+            let opened_at =
+                <PendingDraws<T>>::get(court_id).ok_or(Error::<T>::ParticipantsAlreadyDrawn)?;
+            let mut court = <Courts<T>>::get(court_id).ok_or(Error::<T>::CourtNotFound)?;
+            let appeal_number = court.appeals.len();
+
+            // used for benchmarking, juror pool is queried inside `select_participants`
+            let pool_len = <CourtPool<T>>::decode_len().unwrap_or(0) as u32;
+            let new_draws = Self::select_participants(appeal_number, opened_at)?;
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            let mut ids_len_0 = 0u32;
+            let mut ids_len_1 = 0u32;
+            if court.round_ends.pre_vote < now {
+                let last_resolve_at = court.round_ends.appeal;
+                let request_block = <RequestBlock<T>>::get();
+                debug_assert!(request_block >= now, "Request block must be greater than now.");
+                let round_timing = RoundTiming {
+                    pre_vote: request_block,
+                    vote: T::VotePeriod::get(),
+                    aggregation: T::AggregationPeriod::get(),
+                    appeal: T::AppealPeriod::get(),
+                };
+                court.update_round(round_timing);
+                if let Some(market_id) = <CourtIdToMarketId<T>>::get(court_id) {
+                    ids_len_0 =
+                        T::DisputeResolution::remove_auto_resolve(&market_id, last_resolve_at);
+                    ids_len_1 = T::DisputeResolution::add_auto_resolve(
+                        &market_id,
+                        court.round_ends.appeal,
+                    )?;
+                }
+                <Courts<T>>::insert(court_id, court.clone());
+            }
+
+            <SelectedDraws<T>>::insert(court_id, new_draws);
+            <PendingDraws<T>>::remove(court_id);
+
+            Self::deposit_event(Event::ParticipantsDrawn {
+                court_id,
+                round_ends: court.round_ends,
+            });
+
+            Ok((pool_len, appeal_number as u32, ids_len_0, ids_len_1))
+        }
+
+        /// Draws the participants of the courts in `PendingDraws` for which the randomness source
+        /// has an output which became known after the round was opened, as long as
+        /// `remaining_weight` covers the worst case of another draw. Courts whose draw fails, e.g.
+        /// because the court pool shrank in the meantime, are left to `draw_participants`.
+        pub(crate) fn draw_pending_participants(remaining_weight: Weight) -> Weight {
+            let draw_weight = T::WeightInfo::draw_participants(
+                T::MaxCourtParticipants::get(),
+                T::MaxAppeals::get(),
+                CacheSize::get(),
+                CacheSize::get(),
+            );
+            let read_weight = T::DbWeight::get().reads(1);
+            // Reading the randomness and the first pending court must leave room for a draw.
+            if read_weight
+                .saturating_mul(2)
+                .saturating_add(draw_weight)
+                .any_gt(remaining_weight)
+            {
+                return Weight::zero();
+            }
+
+            // The block since which the output of the randomness source is known doesn't depend on
+            // the subject.
+            let (_, known_since) = T::Random::random(&[]);
+            let mut total_weight = read_weight;
+            let mut court_ids = Vec::new();
+            let mut pending_draws = <PendingDraws<T>>::iter();
+            loop {
+                let weight_with_draw =
+                    total_weight.saturating_add(read_weight).saturating_add(draw_weight);
+                if weight_with_draw.any_gt(remaining_weight) {
+                    break;
+                }
+                let Some((court_id, opened_at)) = pending_draws.next() else {
+                    break;
+                };
+                total_weight = total_weight.saturating_add(read_weight);
+                if known_since > opened_at {
+                    court_ids.push(court_id);
+                    total_weight = weight_with_draw;
+                }
+            }
+
+            for court_id in court_ids {
+                let result = with_storage_layer(|| Self::do_draw_participants(court_id));
+                if let Err(e) = result {
+                    log::debug!(
+                        target: LOG_TARGET,
+                        "Failed to draw the participants of court {:?}: {:?}",
+                        court_id,
+                        e
+                    );
+                }
+            }
+
+            total_weight
+        }
+
+        pub fn encode_signed_join_court_params(
+            relayer: &T::AccountId,
+            nonce: u64,
+            amount: &BalanceOf<T>,
+        ) -> Vec<u8> {
+            (JOIN_COURT_CONTEXT, relayer, nonce, amount).encode()
+        }
+
+        pub fn encode_signed_delegate_params(
+            relayer: &T::AccountId,
+            nonce: u64,
+            amount: &BalanceOf<T>,
+            delegations: &Vec<T::AccountId>,
+        ) -> Vec<u8> {
+            (DELEGATE_CONTEXT, relayer, nonce, amount, delegations).encode()
+        }
+
+        pub fn encode_signed_prepare_exit_court_params(
+            relayer: &T::AccountId,
+            nonce: u64,
+        ) -> Vec<u8> {
+            (PREPARE_EXIT_COURT_CONTEXT, relayer, nonce).encode()
+        }
+
+        pub fn encode_signed_exit_court_params(relayer: &T::AccountId, nonce: u64) -> Vec<u8> {
+            (EXIT_COURT_CONTEXT, relayer, nonce).encode()
+        }
+
+        pub fn encode_signed_vote_params(
+            relayer: &T::AccountId,
+            nonce: u64,
+            court_id: &CourtId,
+            commitment_vote: &T::Hash,
+        ) -> Vec<u8> {
+            (VOTE_CONTEXT, relayer, nonce, court_id, commitment_vote).encode()
+        }
+
+        pub fn encode_signed_reveal_vote_params(
+            relayer: &T::AccountId,
+            nonce: u64,
+            court_id: &CourtId,
+            vote_item: &VoteItem,
+            salt: &T::Hash,
+        ) -> Vec<u8> {
+            (REVEAL_VOTE_CONTEXT, relayer, nonce, court_id, vote_item, salt).encode()
+        }
+
+        pub fn encode_signed_appeal_params(
+            relayer: &T::AccountId,
+            nonce: u64,
+            court_id: &CourtId,
+        ) -> Vec<u8> {
+            (APPEAL_CONTEXT, relayer, nonce, court_id).encode()
+        }
+
+        pub fn get_encoded_call_param(
+            call: &<T as Config>::RuntimeCall,
+        ) -> Option<(&Proof<T::Signature, T::AccountId>, Vec<u8>)> {
+            let call = match call.is_sub_type() {
+                Some(call) => call,
+                None => return None,
+            };
+
+            match call {
+                Call::signed_join_court { ref proof, ref amount } => {
+                    let nonce = <UserNonces<T>>::get(&proof.signer);
+                    let encoded_data =
+                        Self::encode_signed_join_court_params(&proof.relayer, nonce, amount);
+
+                    Some((proof, encoded_data))
+                },
+                Call::signed_delegate { ref proof, ref amount, ref delegations } => {
+                    let nonce = <UserNonces<T>>::get(&proof.signer);
+                    let encoded_data = Self::encode_signed_delegate_params(
+                        &proof.relayer,
+                        nonce,
+                        amount,
+                        delegations,
+                    );
+
+                    Some((proof, encoded_data))
+                },
+                Call::signed_prepare_exit_court { ref proof } => {
+                    let nonce = <UserNonces<T>>::get(&proof.signer);
+                    let encoded_data =
+                        Self::encode_signed_prepare_exit_court_params(&proof.relayer, nonce);
+
+                    Some((proof, encoded_data))
+                },
+                Call::signed_exit_court { ref proof } => {
+                    let nonce = <UserNonces<T>>::get(&proof.signer);
+                    let encoded_data = Self::encode_signed_exit_court_params(&proof.relayer, nonce);
+
+                    Some((proof, encoded_data))
+                },
+                Call::signed_vote { ref proof, ref court_id, ref commitment_vote } => {
+                    let nonce = <UserNonces<T>>::get(&proof.signer);
+                    let encoded_data = Self::encode_signed_vote_params(
+                        &proof.relayer,
+                        nonce,
+                        court_id,
+                        commitment_vote,
+                    );
+
+                    Some((proof, encoded_data))
+                },
+                Call::signed_reveal_vote { ref proof, ref court_id, ref vote_item, ref salt } => {
+                    let nonce = <UserNonces<T>>::get(&proof.signer);
+                    let encoded_data = Self::encode_signed_reveal_vote_params(
+                        &proof.relayer,
+                        nonce,
+                        court_id,
+                        vote_item,
+                        salt,
+                    );
+
+                    Some((proof, encoded_data))
+                },
+                Call::signed_appeal { ref proof, ref court_id } => {
+                    let nonce = <UserNonces<T>>::get(&proof.signer);
+                    let encoded_data =
+                        Self::encode_signed_appeal_params(&proof.relayer, nonce, court_id);
+
+                    Some((proof, encoded_data))
+                },
+                _ => None,
+            }
+        }
+    }
+
+    impl<T: Config> InnerCallValidator for Pallet<T> {
+        type Call = <T as Config>::RuntimeCall;
+
+        fn signature_is_valid(call: &Box<Self::Call>) -> bool {
+            if let Some((proof, signed_payload)) = Self::get_encoded_call_param(call) {
+                return verify_signature::<T::Signature, T::AccountId>(
+                    proof,
+                    signed_payload.as_slice(),
+                )
+                .is_ok();
+            }
+
+            false
+        }
+    }
+
+    impl<T> Pallet<T>
+    where
+        T: Config,
+    {
+        fn do_delegate(
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+            delegations: Vec<T::AccountId>,
+        ) -> Result<(u32, u32), DispatchError> {
+            ensure!(!delegations.is_empty(), Error::<T>::NoDelegations);
+            let delegations_len = delegations.len() as u32;
+            let mut sorted_delegations: DelegationsOf<T> =
+                delegations.clone().try_into().map_err(|_| Error::<T>::MaxDelegationsReached)?;
+
+            let pool = CourtPool::<T>::get();
+            let is_valid_set = sorted_delegations.iter().all(|pretended_juror| {
+                <Participants<T>>::get(pretended_juror).map_or(false, |pretended_juror_info| {
+                    match Self::get_pool_item(&pool, pretended_juror_info.stake, pretended_juror) {
+                        Ok(Some(_)) => pretended_juror_info.delegations.is_none(),
+                        _ => false,
+                    }
+                })
+            });
+            ensure!(is_valid_set, Error::<T>::DelegatedToInvalidJuror);
+            // ensure all elements are different
+            sorted_delegations.sort();
+            let has_duplicates = sorted_delegations
+                .iter()
+                .zip(sorted_delegations.iter().skip(1))
+                .any(|(x, y)| x == y);
+            ensure!(!has_duplicates, Error::<T>::IdenticalDelegationsNotAllowed);
+            ensure!(!sorted_delegations.contains(&who), Error::<T>::SelfDelegationNotAllowed);
+
+            let pool_len = Self::do_join_court(&who, amount, Some(sorted_delegations))?;
+
+            Self::deposit_event(Event::DelegatorJoined {
+                delegator: who,
+                stake: amount,
+                delegated_jurors: delegations,
+            });
+
+            Ok((pool_len, delegations_len))
+        }
+
+        fn do_prepare_exit_court(who: T::AccountId) -> Result<u32, DispatchError> {
+            let mut prev_p_info =
+                <Participants<T>>::get(&who).ok_or(Error::<T>::JurorDoesNotExist)?;
+            ensure!(prev_p_info.prepare_exit_at.is_none(), Error::<T>::AlreadyPreparedExit);
+
+            let mut pool = CourtPool::<T>::get();
+            let pool_len = pool.len() as u32;
+
+            // do not error in the else case
+            // because the juror might have been already removed from the pool
+            if let Some((index, _)) = Self::get_pool_item(&pool, prev_p_info.stake, &who)? {
+                pool.remove(index);
+                <CourtPool<T>>::put(pool);
+            }
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            prev_p_info.prepare_exit_at = Some(now);
+            <Participants<T>>::insert(&who, prev_p_info);
+
+            Self::deposit_event(Event::ExitPrepared { court_participant: who });
+
+            Ok(pool_len)
+        }
+
+        // Returns `true` if the court participant was removed entirely, and `false` if a part of
+        // their stake stays locked by court cases.
+        fn do_exit_court(who: T::AccountId) -> Result<bool, DispatchError> {
+            let mut prev_p_info =
+                <Participants<T>>::get(&who).ok_or(Error::<T>::JurorDoesNotExist)?;
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            let prepare_exit_at =
+                prev_p_info.prepare_exit_at.ok_or(Error::<T>::PrepareExitAtNotPresent)?;
+            ensure!(
+                now.saturating_sub(prepare_exit_at) >= T::InflationPeriod::get(),
+                Error::<T>::PrematureExit
+            );
+
+            let (exit_amount, active_lock, removed) = if prev_p_info.active_lock.is_zero() {
+                T::Currency::remove_lock(T::LockId::get(), &who);
+                Participants::<T>::remove(&who);
+                (prev_p_info.stake, BalanceOf::<T>::zero(), true)
+            } else {
+                let active_lock = prev_p_info.active_lock;
+                let exit_amount = prev_p_info.stake.saturating_sub(active_lock);
+                T::Currency::set_lock(T::LockId::get(), &who, active_lock, WithdrawReasons::all());
+
+                prev_p_info.stake = active_lock;
+                Participants::<T>::insert(&who, prev_p_info);
+
+                (exit_amount, active_lock, false)
+            };
+
+            Self::deposit_event(Event::ExitedCourt {
+                court_participant: who,
+                exit_amount,
+                active_lock,
+            });
+
+            Ok(removed)
+        }
+
+        fn do_vote(
+            who: T::AccountId,
+            court_id: CourtId,
+            commitment_vote: T::Hash,
+        ) -> Result<u32, DispatchError> {
+            let court = <Courts<T>>::get(court_id).ok_or(Error::<T>::CourtNotFound)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                court.round_ends.pre_vote < now && now <= court.round_ends.vote,
+                Error::<T>::NotInVotingPeriod
+            );
+
+            let mut draws = <SelectedDraws<T>>::get(court_id);
+
+            match draws.binary_search_by_key(&who, |draw| draw.court_participant.clone()) {
+                Ok(index) => {
+                    let draw = draws
+                        .get_mut(index)
+                        .ok_or(Error::<T>::Unexpected(UnexpectedError::BinarySearchByKeyFailed))?;
+                    // allow to override last vote
+                    ensure!(
+                        matches!(draw.vote, Vote::Drawn | Vote::Secret { commitment: _ }),
+                        Error::<T>::InvalidVoteState
+                    );
+
+                    let vote = Vote::Secret { commitment: commitment_vote };
+                    draw.vote = vote;
+                },
+                Err(_) => return Err(Error::<T>::CallerNotInSelectedDraws.into()),
+            }
+
+            let draws_len = draws.len() as u32;
+
+            <SelectedDraws<T>>::insert(court_id, draws);
+
+            Self::deposit_event(Event::JurorVoted {
+                juror: who,
+                court_id,
+                commitment: commitment_vote,
+            });
+
+            Ok(draws_len)
         }
 
-        /// Reveal the commitment vote of the caller, who is a selected juror.
-        /// A check of `commitment_hash == hash(juror ++ vote_item ++ salt)`
-        /// is performed for validation.
-        ///
-        /// # Arguments
-        ///
-        /// - `court_id`: The identifier of the court.
-        /// - `vote_item`: The raw vote item which should match with the commitment of the juror.
-        /// - `salt`: The hash which is used for the validation.
-        ///
-        /// # Weight
-        ///
-        /// Complexity: `O(log(n))`, where `n` is the number of selected draws
-        /// in the specified court.
-        #[pallet::call_index(6)]
-        #[pallet::weight(T::WeightInfo::reveal_vote(T::MaxSelectedDraws::get()))]
-        #[transactional]
-        pub fn reveal_vote(
-            origin: OriginFor<T>,
-            #[pallet::compact] court_id: CourtId,
+        fn do_reveal_vote(
+            who: T::AccountId,
+            court_id: CourtId,
             vote_item: VoteItem,
             salt: T::Hash,
-        ) -> DispatchResultWithPostInfo {
-            let who = ensure_signed(origin)?;
-
+        ) -> Result<u32, DispatchError> {
             if let Some(market_id) = <CourtIdToMarketId<T>>::get(court_id) {
                 let market = T::MarketCommons::market(&market_id)?;
                 let outcome =
@@ -934,37 +1826,13 @@ mod pallet {
                 draw_weight,
             });
 
-            Ok(Some(T::WeightInfo::reveal_vote(draws_len)).into())
+            Ok(draws_len)
         }
 
-        /// Initiate an appeal for a court
-        /// if the presumptive winner of the last vote round is believed to be incorrect.
-        /// The last appeal does not trigger a new court round
-        /// but instead it marks the court mechanism for this market as failed.
-        /// If the court failed, the prediction markets pallet takes over the dispute resolution.
-        /// The prediction markets pallet might allow to trigger a global token holder vote.
-        ///
-        /// # Arguments
-        ///
-        /// - `court_id`: The identifier of the court.
-        ///
-        /// # Weight
-        ///
-        /// Complexity: It depends heavily on the complexity of `select_participants`.
-        #[pallet::call_index(7)]
-        #[pallet::weight(T::WeightInfo::appeal(
-            T::MaxCourtParticipants::get(),
-            T::MaxAppeals::get(),
-            CacheSize::get(),
-            CacheSize::get(),
-        ))]
-        #[transactional]
-        pub fn appeal(
-            origin: OriginFor<T>,
-            #[pallet::compact] court_id: CourtId,
-        ) -> DispatchResultWithPostInfo {
-            let who = ensure_signed(origin)?;
-
+        fn do_appeal(
+            who: T::AccountId,
+            court_id: CourtId,
+        ) -> Result<(u32, u32, u32, u32), DispatchError> {
             let mut court = <Courts<T>>::get(court_id).ok_or(Error::<T>::CourtNotFound)?;
             let appeal_number = court.appeals.len().saturating_add(1);
             ensure!(appeal_number <= T::MaxAppeals::get() as usize, Error::<T>::MaxAppealsReached);
@@ -985,12 +1853,12 @@ mod pallet {
 
             let last_resolve_at = court.round_ends.appeal;
 
-            // used for benchmarking, juror pool is queried inside `select_participants`
+            // used for benchmarking, juror pool is queried inside `ensure_enough_stake_for_draws`
             let pool_len = <CourtPool<T>>::decode_len().unwrap_or(0) as u32;
 
             // if appeal_number == MaxAppeals, then don't start a new appeal round
             let (new_round_ends, ids_len_1) = if appeal_number < T::MaxAppeals::get() as usize {
-                let new_draws = Self::select_participants(appeal_number)?;
+                Self::ensure_enough_stake_for_draws(appeal_number)?;
                 let request_block = <RequestBlock<T>>::get();
                 debug_assert!(request_block >= now, "Request block must be greater than now.");
                 let round_timing = RoundTiming {
@@ -1009,7 +1877,11 @@ mod pallet {
                 } else {
                     0u32
                 };
-                <SelectedDraws<T>>::insert(court_id, new_draws);
+                // the participants of the new round are drawn once the randomness of a later
+                // block is known
+                <SelectedDraws<T>>::remove(court_id);
+                <PendingDraws<T>>::insert(court_id, now);
+                <AppealedDraws<T>>::insert(court_id, appeal_number as u32, old_draws.clone());
                 Self::unlock_participants_from_last_draw(court_id, old_draws);
                 (new_round_ends, ids_len_1)
             } else {
@@ -1028,169 +1900,9 @@ mod pallet {
             let appeal_number = appeal_number as u32;
             Self::deposit_event(Event::CourtAppealed { court_id, appeal_info, new_round_ends });
 
-            Ok(Some(T::WeightInfo::appeal(pool_len, appeal_number, ids_len_0, ids_len_1)).into())
-        }
-
-        /// Reassign the stakes of the jurors and delegators
-        /// for the selected draws of the specified court.
-        /// The losing jurors and delegators get slashed and
-        /// pay for the winning jurors and delegators.
-        /// The tardy (juror did not reveal or did not vote) or denounced jurors
-        /// and associated delegators get slashed and reward the winners.
-        ///
-        /// # Arguments
-        ///
-        /// - `court_id`: The identifier of the court.
-        ///
-        /// # Weight
-        ///
-        /// Complexity: O(N + M), with `N` being the number of draws and `M` being the total number
-        /// of valid winners and losers.
-        #[pallet::call_index(8)]
-        #[pallet::weight(T::WeightInfo::reassign_court_stakes(T::MaxSelectedDraws::get()))]
-        #[transactional]
-        pub fn reassign_court_stakes(
-            origin: OriginFor<T>,
-            court_id: CourtId,
-        ) -> DispatchResultWithPostInfo {
-            ensure_signed(origin)?;
-
-            let mut court = <Courts<T>>::get(court_id).ok_or(Error::<T>::CourtNotFound)?;
-            let winner = match court.status {
-                CourtStatus::Closed { winner } => winner,
-                CourtStatus::Reassigned => return Err(Error::<T>::CourtAlreadyReassigned.into()),
-                CourtStatus::Open => return Err(Error::<T>::CourtNotClosed.into()),
-            };
-
-            let draws = SelectedDraws::<T>::get(court_id);
-            let draws_len = draws.len() as u32;
-
-            let reward_pot = Self::reward_pot(court_id);
-            let slash_juror = |ai: &T::AccountId, slashable: BalanceOf<T>| {
-                let (imbalance, missing) = T::Currency::slash(ai, slashable);
-                debug_assert!(
-                    missing.is_zero(),
-                    "Could not slash all of the amount for juror {:?}.",
-                    ai
-                );
-                T::Currency::resolve_creating(&reward_pot, imbalance);
-            };
-
-            // map delegated jurors to own_slashable, vote item and Vec<(delegator,
-            // delegator_stake)>
-            let mut jurors_to_stakes = BTreeMap::<T::AccountId, JurorVoteWithStakesOf<T>>::new();
-
-            let mut handle_vote = |draw: DrawOf<T>| -> DispatchResult {
-                match draw.vote {
-                    Vote::Drawn |
-                    Vote::Secret { commitment: _ } |
-                    Vote::Denounced { commitment: _, vote_item: _, salt: _ } => {
-                        slash_juror(&draw.court_participant, draw.slashable);
-                    },
-                    Vote::Revealed { commitment: _, vote_item, salt: _ } => {
-                        jurors_to_stakes.entry(draw.court_participant).or_default().self_info =
-                            Some(SelfInfo { slashable: draw.slashable, vote_item });
-                    },
-                    Vote::Delegated { delegated_stakes } => {
-                        let delegator = draw.court_participant;
-                        for (j, delegated_stake) in delegated_stakes {
-                            // fill the delegations for each juror
-                            // [(juror_0, [(delegator_0, delegator_stake_0), ...]),
-                            // (juror_1, [(delegator_42, delegator_stake_42), ...]), ...]
-                            let jurors_to_stakes_entry = jurors_to_stakes.entry(j);
-                            let juror_vote_with_stakes = jurors_to_stakes_entry.or_default();
-
-                            // future-proof binary search by key
-                            // because many delegators can back one juror
-                            // we might want to fastly find elements later on
-                            match juror_vote_with_stakes
-                                .delegations
-                                .binary_search_by_key(&delegator, |(d, _)| d.clone())
-                            {
-                                Ok(i) => {
-                                    let delegations = juror_vote_with_stakes
-                                        .delegations
-                                        .get_mut(i)
-                                        .ok_or(Error::<T>::Unexpected(
-                                            UnexpectedError::BinarySearchByKeyFailed,
-                                        ))?;
-                                    delegations.1 = delegations.1.saturating_add(delegated_stake);
-                                },
-                                Err(i) => {
-                                    juror_vote_with_stakes
-                                        .delegations
-                                        .insert(i, (delegator.clone(), delegated_stake));
-                                },
-                            }
-                        }
-                    },
-                }
-                Ok(())
-            };
-
-            for draw in draws {
-                if let Some(mut p_info) = <Participants<T>>::get(&draw.court_participant) {
-                    p_info.active_lock = p_info.active_lock.saturating_sub(draw.slashable);
-                    <Participants<T>>::insert(&draw.court_participant, p_info);
-                } else {
-                    log::warn!(
-                        target: LOG_TARGET,
-                        "Participant {:?} not found in Participants storage \
-                         (reassign_court_stakes). Court id {:?}.",
-                        draw.court_participant,
-                        court_id
-                    );
-                    debug_assert!(false);
-                }
-
-                handle_vote(draw)?;
-            }
-
-            Self::slash_losers_to_award_winners(court_id, jurors_to_stakes, &winner);
-
-            court.status = CourtStatus::Reassigned;
-            <Courts<T>>::insert(court_id, court);
-
-            <SelectedDraws<T>>::remove(court_id);
-
-            Self::deposit_event(Event::StakesReassigned { court_id });
-
-            Ok(Some(T::WeightInfo::reassign_court_stakes(draws_len)).into())
-        }
-
-        /// Set the yearly inflation rate of the court system.
-        /// This is only allowed to be called by the `MonetaryGovernanceOrigin`.
-        ///
-        /// # Arguments
-        ///
-        /// - `inflation`: The desired yearly inflation rate.
-        ///
-        /// # Weight
-        ///
-        /// Complexity: `O(1)`
-        #[pallet::call_index(9)]
-        #[pallet::weight(T::WeightInfo::set_inflation())]
-        #[transactional]
-        pub fn set_inflation(origin: OriginFor<T>, inflation: Perbill) -> DispatchResult {
-            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
-
-            ensure!(
-                inflation <= T::MaxYearlyInflation::get(),
-                Error::<T>::InflationExceedsMaxYearlyInflation
-            );
-
-            <YearlyInflation<T>>::put(inflation);
-
-            Self::deposit_event(Event::InflationSet { inflation });
-
-            Ok(())
+            Ok((pool_len, appeal_number, ids_len_0, ids_len_1))
         }
-    }
 
-    impl<T> Pallet<T>
-    where
-        T: Config,
-    {
         fn get_uneligible_stake(
             pool_item_opt: Option<&CourtPoolItemOf<T>>,
             amount: BalanceOf<T>,
@@ -1477,8 +2189,12 @@ mod pallet {
         // Uses Partial Fisher Yates shuffle and drawing without replacement.
         // The time complexity is O(n).
         // Return a vector of n unique random numbers between ´MinJurorStake´ and ´max´ (inclusive).
-        pub(crate) fn get_n_random_section_ends(n: usize, max: u128) -> BTreeSet<u128> {
-            let mut rng = Self::rng();
+        pub(crate) fn get_n_random_section_ends(
+            n: usize,
+            max: u128,
+            opened_at: BlockNumberFor<T>,
+        ) -> Result<BTreeSet<u128>, DispatchError> {
+            let mut rng = Self::rng(opened_at)?;
 
             let min_juror_stake = T::MinJurorStake::get().saturated_into::<u128>();
             debug_assert!((max % min_juror_stake).is_zero(), "This is ensured by the caller.");
@@ -1509,7 +2225,7 @@ mod pallet {
 
             debug_assert!(random_section_ends.len() == n);
 
-            random_section_ends
+            Ok(random_section_ends)
         }
 
         // Adds active lock amount.
@@ -1624,8 +2340,10 @@ mod pallet {
         /// Return one delegated juror out of the delegations randomly.
         fn get_valid_delegated_juror(
             delegations: &[T::AccountId],
+            opened_at: BlockNumberFor<T>,
         ) -> Result<Option<T::AccountId>, SelectionError> {
-            let mut rng = Self::rng();
+            let mut rng =
+                Self::rng(opened_at).map_err(|_| SelectionError::RandomnessNotAvailable)?;
             let pool: CourtPoolOf<T> = CourtPool::<T>::get();
             let mut valid_delegated_jurors = Vec::new();
 
@@ -1663,13 +2381,15 @@ mod pallet {
             selections: &mut BTreeMap<T::AccountId, SelectionValueOf<T>>,
             court_participant: &T::AccountId,
             lock_added: BalanceOf<T>,
+            opened_at: BlockNumberFor<T>,
         ) -> Result<(), SelectionError> {
             let delegations_opt = <Participants<T>>::get(court_participant.clone())
                 .and_then(|p_info| p_info.delegations);
             match delegations_opt {
                 Some(delegations) => {
-                    let delegated_juror = Self::get_valid_delegated_juror(delegations.as_slice())?
-                        .ok_or(SelectionError::NoValidDelegatedJuror)?;
+                    let delegated_juror =
+                        Self::get_valid_delegated_juror(delegations.as_slice(), opened_at)?
+                            .ok_or(SelectionError::NoValidDelegatedJuror)?;
                     // delegated juror gets the vote weight
                     let sel_add = SelectionAdd::DelegationWeight;
                     Self::update_selections(selections, &delegated_juror, sel_add).map_err(
@@ -1731,6 +2451,7 @@ mod pallet {
             pool: &mut CourtPoolOf<T>,
             random_section_ends: BTreeSet<u128>,
             cumulative_section_ends: Vec<(u128, bool)>,
+            opened_at: BlockNumberFor<T>,
         ) -> Result<BTreeMap<T::AccountId, SelectionValueOf<T>>, DispatchError> {
             debug_assert!(pool.len() == cumulative_section_ends.len());
             debug_assert!({
@@ -1762,6 +2483,7 @@ mod pallet {
                         &mut selections,
                         &pool_item.court_participant,
                         lock_added,
+                        opened_at,
                     ) {
                         Ok(()) => {},
                         Err(SelectionError::NoValidDelegatedJuror) => {
@@ -1777,6 +2499,9 @@ mod pallet {
                             )
                             .into());
                         },
+                        Err(SelectionError::RandomnessNotAvailable) => {
+                            return Err(Error::<T>::RandomnessNotAvailable.into());
+                        },
                     }
 
                     Self::add_active_lock(&pool_item.court_participant, lock_added);
@@ -1829,26 +2554,24 @@ mod pallet {
                 .collect()
         }
 
-        // Choose `draw_weight` (multiple) of `MinJurorStake` from the pool randomly
-        // according to the weighted stake of all jurors and delegators.
+        // Returns the total selectable stake of the pool and the cumulative section ends of its
+        // jurors and delegators.
         // NOTE: The jurors and delegators are being cut by the remainder
         // if the stake is not a multiple of `MinJurorStake`.
-        // Return the random draws.
-        pub(crate) fn choose_multiple_weighted(
-            draw_weight: usize,
-        ) -> Result<Vec<DrawOf<T>>, DispatchError> {
-            let mut pool = <CourtPool<T>>::get();
-
+        fn cumulative_section_ends(
+            pool: &CourtPoolOf<T>,
+        ) -> Result<(u128, Vec<(u128, bool)>), DispatchError> {
             let min_juror_stake = T::MinJurorStake::get().saturated_into::<u128>();
 
             let mut total_unconsumed = 0u128;
             let mut cumulative_section_ends = Vec::new();
             let mut running_total = 0u128;
-            for pool_item in &pool {
+            for pool_item in pool {
                 let unconsumed = pool_item
                     .stake
                     .saturating_sub(pool_item.consumed_stake)
                     .saturated_into::<u128>();
+                let unconsumed = Self::selection_weight(&pool_item.court_participant, unconsumed);
                 let remainder = unconsumed.checked_rem_res(&min_juror_stake)?;
                 let unconsumed = unconsumed.saturating_sub(remainder);
                 total_unconsumed = total_unconsumed.saturating_add(unconsumed);
@@ -1865,20 +2588,90 @@ mod pallet {
                 "Remainders are being cut in the above for loop."
             );
 
+            Ok((total_unconsumed, cumulative_section_ends))
+        }
+
+        // Ensures that the pool holds enough stake to draw the participants of a court round
+        // with the specified appeal number.
+        pub(crate) fn ensure_enough_stake_for_draws(appeal_number: usize) -> DispatchResult {
+            let (total_unconsumed, _) = Self::cumulative_section_ends(&<CourtPool<T>>::get())?;
+            let draw_weight = Self::necessary_draws_weight(appeal_number);
+            Self::ensure_enough_stake(draw_weight, total_unconsumed)
+        }
+
+        fn ensure_enough_stake(draw_weight: usize, total_unconsumed: u128) -> DispatchResult {
+            let min_juror_stake = T::MinJurorStake::get().saturated_into::<u128>();
             let required_stake = (draw_weight as u128).saturating_mul(min_juror_stake);
             ensure!(
                 total_unconsumed >= required_stake,
                 Error::<T>::NotEnoughJurorsAndDelegatorsStake
             );
+            Ok(())
+        }
+
+        // Choose `draw_weight` (multiple) of `MinJurorStake` from the pool randomly
+        // according to the weighted stake of all jurors and delegators.
+        // Only randomness which became known after `opened_at` is used.
+        // Return the random draws.
+        pub(crate) fn choose_multiple_weighted(
+            draw_weight: usize,
+            opened_at: BlockNumberFor<T>,
+        ) -> Result<Vec<DrawOf<T>>, DispatchError> {
+            let mut pool = <CourtPool<T>>::get();
+            let (total_unconsumed, cumulative_section_ends) = Self::cumulative_section_ends(&pool)?;
+            Self::ensure_enough_stake(draw_weight, total_unconsumed)?;
+
             let random_section_ends =
-                Self::get_n_random_section_ends(draw_weight, total_unconsumed);
-            let selections =
-                Self::get_selections(&mut pool, random_section_ends, cumulative_section_ends)?;
+                Self::get_n_random_section_ends(draw_weight, total_unconsumed, opened_at)?;
+            let selections = Self::get_selections(
+                &mut pool,
+                random_section_ends,
+                cumulative_section_ends,
+                opened_at,
+            )?;
             <CourtPool<T>>::put(pool);
 
             Ok(Self::convert_selections_to_draws(selections))
         }
 
+        // Scales the unconsumed stake of a court participant by their reputation, so that
+        // careless jurors get fewer sections in the juror selection. The result is at most
+        // `unconsumed`, so a participant is never drawn for more than their stake.
+        pub(crate) fn selection_weight(court_participant: &T::AccountId, unconsumed: u128) -> u128 {
+            let influence = T::ReputationInfluence::get();
+            if influence.is_zero() {
+                return unconsumed;
+            }
+            let score = <Reputations<T>>::get(court_participant).score();
+            let factor = influence.left_from_one().saturating_add(influence.saturating_mul(score));
+            // keep one section of `MinJurorStake` for everyone who can afford it,
+            // so that fresh jurors are still drawn and can build up a track record
+            let min_juror_stake = T::MinJurorStake::get().saturated_into::<u128>();
+            factor.mul_floor(unconsumed).max(unconsumed.min(min_juror_stake))
+        }
+
+        // Adds the vote of a draw of a reassigned court to the track record of the juror.
+        // Delegators don't vote themselves, so their track record is left untouched.
+        fn update_reputation(draw: &DrawOf<T>, winner: &VoteItem) {
+            let mut reputation = <Reputations<T>>::get(&draw.court_participant);
+            match &draw.vote {
+                Vote::Drawn | Vote::Secret { .. } => {
+                    reputation.missed_votes = reputation.missed_votes.saturating_add(1);
+                },
+                Vote::Denounced { .. } => {
+                    reputation.denounced_votes = reputation.denounced_votes.saturating_add(1);
+                },
+                Vote::Revealed { vote_item, .. } =>
+                    if vote_item == winner {
+                        reputation.majority_votes = reputation.majority_votes.saturating_add(1);
+                    } else {
+                        reputation.minority_votes = reputation.minority_votes.saturating_add(1);
+                    },
+                Vote::Delegated { .. } => return,
+            }
+            <Reputations<T>>::insert(&draw.court_participant, reputation);
+        }
+
         // Reduce the active lock of the jurors from the last draws.
         // This is useful so that the jurors can thaw their non-locked stake.
         fn unlock_participants_from_last_draw(court_id: CourtId, last_draws: SelectedDrawsOf<T>) {
@@ -1909,9 +2702,10 @@ mod pallet {
         // Returns the new draws.
         pub(crate) fn select_participants(
             appeal_number: usize,
+            opened_at: BlockNumberFor<T>,
         ) -> Result<SelectedDrawsOf<T>, DispatchError> {
             let necessary_draws_weight = Self::necessary_draws_weight(appeal_number);
-            let random_jurors = Self::choose_multiple_weighted(necessary_draws_weight)?;
+            let random_jurors = Self::choose_multiple_weighted(necessary_draws_weight, opened_at)?;
 
             // keep in mind that the number of draws is at maximum necessary_draws_weight * 2
             // because with delegations each juror draw weight
@@ -2034,30 +2828,36 @@ mod pallet {
             Ok(())
         }
 
-        // Get a random seed based on a nonce.
-        pub(crate) fn get_random_seed(nonce: u64) -> [u8; 32] {
+        // Get a random seed based on a nonce. Fails if the randomness source has no output which
+        // became known after `opened_at`, because anyone could have computed the seed when the
+        // court round was opened otherwise.
+        pub(crate) fn get_random_seed(
+            nonce: u64,
+            opened_at: BlockNumberFor<T>,
+        ) -> Result<[u8; 32], DispatchError> {
             debug_assert!(
                 !<frame_system::Pallet<T>>::block_number().is_zero(),
                 "When testing with the randomness of the collective flip pallet it produces a \
                  underflow (block number substraction by one) panic if the block number is zero."
             );
             let mut seed = [0; 32];
-            let (random_hash, _) = T::Random::random(&nonce.to_le_bytes());
+            let (random_hash, known_since) = T::Random::random(&nonce.to_le_bytes());
+            ensure!(known_since > opened_at, Error::<T>::RandomnessNotAvailable);
             seed.copy_from_slice(&random_hash.as_ref()[..32]);
-            seed
+            Ok(seed)
         }
 
         // Returns a cryptographically secure random number generator
         // implementation based on the seed provided by the `Config::Random` type
         // and the `SelectionNonce` storage.
-        pub(crate) fn rng() -> impl RngCore {
+        pub(crate) fn rng(opened_at: BlockNumberFor<T>) -> Result<impl RngCore, DispatchError> {
             let nonce = <SelectionNonce<T>>::mutate(|n| {
                 let rslt = *n;
                 *n = n.wrapping_add(1);
                 rslt
             });
-            let random_seed = Self::get_random_seed(nonce);
-            ChaCha20Rng::from_seed(random_seed)
+            let random_seed = Self::get_random_seed(nonce, opened_at)?;
+            Ok(ChaCha20Rng::from_seed(random_seed))
         }
 
         // Calculates the necessary number of draws depending on the number of market appeals.
@@ -2321,7 +3121,7 @@ mod pallet {
 
             let appeal_number = 0usize;
             let pool_len = <CourtPool<T>>::decode_len().unwrap_or(0) as u32;
-            let new_draws = Self::select_participants(appeal_number)?;
+            Self::ensure_enough_stake_for_draws(appeal_number)?;
 
             let now = <frame_system::Pallet<T>>::block_number();
             let request_block = <RequestBlock<T>>::get();
@@ -2340,7 +3140,8 @@ mod pallet {
             let ids_len =
                 T::DisputeResolution::add_auto_resolve(market_id, court.round_ends.appeal)?;
 
-            <SelectedDraws<T>>::insert(court_id, new_draws);
+            // the participants are drawn once the randomness of a later block is known
+            <PendingDraws<T>>::insert(court_id, now);
             <Courts<T>>::insert(court_id, court.clone());
             <MarketIdToCourtId<T>>::insert(market_id, court_id);
             <CourtIdToMarketId<T>>::insert(court_id, market_id);
@@ -2369,6 +3170,7 @@ mod pallet {
             let draws_len = draws.len() as u32;
             let winner_vote_item = Self::get_latest_winner_vote_item(court_id, draws.as_slice())?;
             Self::unlock_participants_from_last_draw(court_id, draws);
+            <PendingDraws<T>>::remove(court_id);
             court.status = CourtStatus::Closed { winner: winner_vote_item.clone() };
             <Courts<T>>::insert(court_id, court);
 
@@ -2560,6 +3362,8 @@ mod pallet {
                 draws_len = old_draws.len() as u32;
                 Self::unlock_participants_from_last_draw(court_id, old_draws);
                 <SelectedDraws<T>>::remove(court_id);
+                <PendingDraws<T>>::remove(court_id);
+                let _ = <AppealedDraws<T>>::clear_prefix(court_id, T::MaxAppeals::get(), None);
                 <Courts<T>>::remove(court_id);
             }
 
@@ -2584,6 +3388,8 @@ mod pallet {
             let draws_len = old_draws.len() as u32;
             Self::unlock_participants_from_last_draw(court_id, old_draws);
             <SelectedDraws<T>>::remove(court_id);
+            <PendingDraws<T>>::remove(court_id);
+            let _ = <AppealedDraws<T>>::clear_prefix(court_id, T::MaxAppeals::get(), None);
             <Courts<T>>::remove(court_id);
 
             let res = ResultWithWeightInfo { result: (), weight: T::WeightInfo::clear(draws_len) };
@@ -28,32 +28,50 @@ use frame_support::{
     PalletId,
 };
 use frame_system::{mocking::MockBlockU32, EnsureRoot, EnsureSignedBy};
+use parity_scale_codec::alloc::sync::Arc;
 use prediction_market_primitives::{
     constants::mock::{
-        AggregationPeriod, AppealBond, AppealPeriod, BlockHashCount, BlocksPerYear, CourtPalletId,
-        ExistentialDeposit, InflationPeriod, LockId, MaxAppeals, MaxApprovals,
+        AggregationPeriod, AppealBond, AppealPeriod, BeaconCommitBond, BeaconCommitPeriod,
+        BeaconRevealPeriod, BlockHashCount, BlocksPerYear, CourtPalletId, ExistentialDeposit,
+        InflationPeriod, LockId, MaxAppeals, MaxApprovals, MaxBeaconParticipants,
         MaxCourtParticipants, MaxDelegations, MaxLocks, MaxReserves, MaxSelectedDraws,
-        MaxYearlyInflation, MinJurorStake, MinimumPeriod, RequestInterval, VotePeriod, BASE,
+        MaxYearlyInflation, MinJurorStake, MinimumPeriod, ReputationInfluence, RequestInterval,
+        VotePeriod, BASE,
     },
     traits::{DisputeResolutionApi, MarketOfDisputeResolutionApi},
-    types::{AccountIdTest, MarketId},
+    types::{MarketId, SignatureTest, TestAccountIdPK},
 };
+use sp_core::sr25519;
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
 };
 
-pub const ALICE: AccountIdTest = 0;
-pub const BOB: AccountIdTest = 1;
-pub const CHARLIE: AccountIdTest = 2;
-pub const DAVE: AccountIdTest = 3;
-pub const EVE: AccountIdTest = 4;
-pub const POOR_PAUL: AccountIdTest = 9;
+pub const ALICE: TestAccountIdPK = account(0);
+pub const BOB: TestAccountIdPK = account(1);
+pub const CHARLIE: TestAccountIdPK = account(2);
+pub const DAVE: TestAccountIdPK = account(3);
+pub const EVE: TestAccountIdPK = account(4);
+pub const POOR_PAUL: TestAccountIdPK = account(9);
 pub const INITIAL_BALANCE: u128 = 1000 * BASE;
-pub const SUDO: AccountIdTest = 69;
+pub const SUDO: TestAccountIdPK = account(69);
+
+/// Returns a test account whose ordering matches the ordering of `index`. The accounts aren't
+/// backed by a key pair, so signed calls need a `TestAccount` instead.
+pub const fn account(index: u128) -> TestAccountIdPK {
+    let bytes = index.to_be_bytes();
+    let mut raw = [0u8; 32];
+    let mut i = 0;
+    while i < bytes.len() {
+        raw[16 + i] = bytes[i];
+        i += 1;
+    }
+    sr25519::Public::from_raw(raw)
+}
 
 ord_parameter_types! {
-    pub const Sudo: AccountIdTest = SUDO;
+    pub const Sudo: TestAccountIdPK = SUDO;
 }
 
 parameter_types! {
@@ -62,6 +80,7 @@ parameter_types! {
 
 construct_runtime!(
     pub enum Runtime {
+        AVN: pallet_avn,
         Balances: pallet_balances,
         Court: pallet_pm_court,
         MarketCommons: pallet_pm_market_commons,
@@ -70,6 +89,7 @@ construct_runtime!(
         Treasury: pallet_treasury,
         // Just a mock storage for testing.
         MockStorage: mock_storage,
+        RandomnessBeacon: pallet_pm_randomness_beacon,
     }
 );
 
@@ -78,10 +98,11 @@ pub struct MockResolution;
 
 impl mock_storage::Config for Runtime {
     type MarketCommons = MarketCommons;
+    type Beacon = RandomnessBeacon;
 }
 
 impl DisputeResolutionApi for MockResolution {
-    type AccountId = AccountIdTest;
+    type AccountId = TestAccountIdPK;
     type Balance = Balance;
     type BlockNumber = BlockNumber;
     type MarketId = MarketId;
@@ -141,10 +162,14 @@ impl crate::Config for Runtime {
     type MaxCourtParticipants = MaxCourtParticipants;
     type MaxYearlyInflation = MaxYearlyInflation;
     type MinJurorStake = MinJurorStake;
-    type MonetaryGovernanceOrigin = EnsureRoot<AccountIdTest>;
+    type MonetaryGovernanceOrigin = EnsureRoot<TestAccountIdPK>;
     type PalletId = CourtPalletId;
+    type Public = TestAccountIdPK;
     type Random = MockStorage;
+    type ReputationInfluence = ReputationInfluence;
     type RequestInterval = RequestInterval;
+    type RuntimeCall = RuntimeCall;
+    type Signature = SignatureTest;
     type Slash = Treasury;
     type TreasuryPalletId = TreasuryPalletId;
     type WeightInfo = crate::weights::WeightInfo<Runtime>;
@@ -152,7 +177,7 @@ impl crate::Config for Runtime {
 
 impl frame_system::Config for Runtime {
     type AccountData = pallet_balances::AccountData<Balance>;
-    type AccountId = AccountIdTest;
+    type AccountId = TestAccountIdPK;
     type BaseCallFilter = Everything;
     type Block = MockBlockU32<Runtime>;
     type BlockHashCount = BlockHashCount;
@@ -176,6 +201,18 @@ impl frame_system::Config for Runtime {
     type OnSetCode = ();
 }
 
+impl pallet_pm_randomness_beacon::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Participants = Everything;
+    type Currency = Balances;
+    type CommitBond = BeaconCommitBond;
+    type Slash = ();
+    type CommitPeriod = BeaconCommitPeriod;
+    type RevealPeriod = BeaconRevealPeriod;
+    type MaxParticipants = MaxBeaconParticipants;
+    type WeightInfo = pallet_pm_randomness_beacon::weights::WeightInfo<Runtime>;
+}
+
 impl pallet_balances::Config for Runtime {
     type AccountStore = System;
     type Balance = Balance;
@@ -192,6 +229,15 @@ impl pallet_balances::Config for Runtime {
     type WeightInfo = ();
 }
 
+impl pallet_avn::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AuthorityId = pallet_avn::sr25519::AuthorityId;
+    type EthereumPublicKeyChecker = ();
+    type NewSessionHandler = ();
+    type DisabledValidatorChecker = ();
+    type WeightInfo = ();
+}
+
 impl pallet_pm_market_commons::Config for Runtime {
     type Balance = Balance;
     type MarketId = MarketId;
@@ -206,7 +252,7 @@ impl pallet_timestamp::Config for Runtime {
 }
 
 impl pallet_treasury::Config for Runtime {
-    type ApproveOrigin = EnsureSignedBy<Sudo, AccountIdTest>;
+    type ApproveOrigin = EnsureSignedBy<Sudo, TestAccountIdPK>;
     type Burn = ();
     type BurnDestination = ();
     type Currency = Balances;
@@ -217,7 +263,7 @@ impl pallet_treasury::Config for Runtime {
     type ProposalBond = ();
     type ProposalBondMinimum = ();
     type ProposalBondMaximum = ();
-    type RejectOrigin = EnsureSignedBy<Sudo, AccountIdTest>;
+    type RejectOrigin = EnsureSignedBy<Sudo, TestAccountIdPK>;
     type SpendFunds = ();
     type SpendOrigin = NeverEnsureOrigin<Balance>;
     type SpendPeriod = ();
@@ -225,7 +271,7 @@ impl pallet_treasury::Config for Runtime {
 }
 
 pub struct ExtBuilder {
-    balances: Vec<(AccountIdTest, Balance)>,
+    balances: Vec<(TestAccountIdPK, Balance)>,
 }
 
 impl Default for ExtBuilder {
@@ -255,6 +301,7 @@ impl ExtBuilder {
             .unwrap();
 
         let mut t: sp_io::TestExternalities = t.into();
+        t.register_extension(KeystoreExt(Arc::new(MemoryKeystore::new())));
         // required to assert for events
         t.execute_with(|| System::set_block_number(1));
         t
@@ -275,6 +322,7 @@ pub fn run_to_block(n: BlockNumber) {
         System::on_initialize(System::block_number());
         Court::on_initialize(System::block_number());
         Balances::on_initialize(System::block_number());
+        RandomnessBeacon::on_initialize(System::block_number());
     }
 }
 
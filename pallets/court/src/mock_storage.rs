@@ -19,15 +19,16 @@
 #![allow(dead_code)]
 #![allow(unused_imports)]
 
+use frame_support::traits::Randomness;
 use frame_system::pallet_prelude::BlockNumberFor;
 pub use pallet::*;
 use parity_scale_codec::Encode;
-use sp_runtime::traits::Hash;
+use sp_runtime::traits::{Hash, Zero};
 
 #[frame_support::pallet]
 pub(crate) mod pallet {
     use core::marker::PhantomData;
-    use frame_support::pallet_prelude::*;
+    use frame_support::{pallet_prelude::*, traits::Randomness};
     use frame_system::pallet_prelude::BlockNumberFor;
     use pallet_pm_market_commons::MarketCommonsPalletApi;
 
@@ -42,6 +43,9 @@ pub(crate) mod pallet {
             AccountId = Self::AccountId,
             BlockNumber = BlockNumberFor<Self>,
         >;
+
+        /// The randomness beacon whose output is used once it produced a seed.
+        type Beacon: Randomness<Self::Hash, BlockNumberFor<Self>>;
     }
 
     #[pallet::pallet]
@@ -58,11 +62,16 @@ pub(crate) mod pallet {
     >;
 }
 
-impl<T: Config> frame_support::traits::Randomness<T::Hash, BlockNumberFor<T>> for Pallet<T> {
+/// Returns the output of the randomness beacon once it produced a seed. Before that, returns a
+/// value which is purely based on `subject` and known since the current block, so that tests
+/// which don't run the beacon can draw jurors in any block after the court round was opened.
+impl<T: Config> Randomness<T::Hash, BlockNumberFor<T>> for Pallet<T> {
     fn random(subject: &[u8]) -> (T::Hash, BlockNumberFor<T>) {
+        let (beacon_random, known_since) = T::Beacon::random(subject);
+        if !known_since.is_zero() {
+            return (beacon_random, known_since);
+        }
         let block_number = <frame_system::Pallet<T>>::block_number();
-        let seed = subject.using_encoded(T::Hashing::hash);
-
-        (seed, block_number)
+        (subject.using_encoded(T::Hashing::hash), block_number)
     }
 }
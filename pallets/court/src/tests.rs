@@ -21,106 +21,149 @@
 extern crate alloc;
 use crate::{
     mock::{
-        run_blocks, run_to_block, Balances, Court, ExtBuilder, MarketCommons, Runtime,
-        RuntimeOrigin, System, ALICE, BOB, CHARLIE, DAVE, EVE, INITIAL_BALANCE, POOR_PAUL,
+        account, run_blocks, run_to_block, Balances, Court, ExtBuilder, MarketCommons,
+        RandomnessBeacon, Runtime, RuntimeOrigin, System, ALICE, BOB, CHARLIE, DAVE, EVE,
+        INITIAL_BALANCE, POOR_PAUL,
     },
     mock_storage::pallet::MarketIdsPerDisputeBlock,
     types::{CourtStatus, Draw, Vote, VoteItem},
-    AppealInfo, BalanceOf, CourtId, CourtIdToMarketId, CourtParticipantInfo,
+    AppealInfo, AppealedDraws, BalanceOf, CourtId, CourtIdToMarketId, CourtParticipantInfo,
     CourtParticipantInfoOf, CourtPool, CourtPoolItem, CourtPoolOf, Courts, Error, Event,
-    MarketIdToCourtId, MarketOf, NegativeImbalanceOf, Participants, RequestBlock, SelectedDraws,
-    YearlyInflation,
+    MarketIdToCourtId, MarketOf, NegativeImbalanceOf, Participants, PendingDraws, Reputations,
+    RequestBlock, SelectedDraws, UserNonces, YearlyInflation,
 };
 use alloc::collections::BTreeMap;
 use common_primitives::constants::currency::BASE;
 use frame_support::{
     assert_noop, assert_ok,
-    storage::child::StateVersion,
+    storage::{child::StateVersion, with_transaction, TransactionOutcome},
     traits::{
         fungible::Balanced,
         tokens::{imbalance::Imbalance, Precision},
-        Currency, NamedReservableCurrency,
+        Currency, Hooks, NamedReservableCurrency,
     },
+    weights::Weight,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_balances::{BalanceLock, NegativeImbalance};
 use pallet_pm_market_commons::{Error as MError, MarketCommonsPalletApi};
+use pallet_pm_randomness_beacon::CurrentRound;
 use prediction_market_primitives::{
     constants::mock::{
         AggregationPeriod, AppealBond, AppealPeriod, InflationPeriod, LockId, MaxAppeals,
-        MaxCourtParticipants, MaxYearlyInflation, MinJurorStake, RequestInterval, VotePeriod,
+        MaxCourtParticipants, MaxYearlyInflation, MinJurorStake, ReputationInfluence,
+        RequestInterval, VotePeriod,
     },
+    court_api_types::JurorReputation,
+    test_helper::TestAccount,
     traits::DisputeApi,
     types::{
-        AccountIdTest, Asset, Deadlines, GlobalDisputeItem, Market, MarketBonds, MarketCreation,
+        Asset, Deadlines, GlobalDisputeItem, Market, MarketBonds, MarketCreation,
         MarketDisputeMechanism, MarketPeriod, MarketStatus, MarketType, OutcomeReport, Report,
-        ScoringRule,
+        ScoringRule, SignatureTest, TestAccountIdPK,
     },
 };
 use rand::seq::SliceRandom;
+use sp_avn_common::Proof;
+use sp_core::Pair;
 use sp_io::storage::root as storage_root;
 use sp_runtime::{
     traits::{BlakeTwo256, Hash, Zero},
-    Perbill, Perquintill,
+    DispatchError, Perbill, Perquintill,
 };
 use test_case::test_case;
 
 const ORACLE_REPORT: OutcomeReport = OutcomeReport::Scalar(u128::MAX);
 
-const DEFAULT_MARKET: MarketOf<Runtime> = Market {
-    market_id: 0,
-    base_asset: Asset::Tru,
-    creation: MarketCreation::Permissionless,
-    creator_fee: sp_runtime::Perbill::zero(),
-    creator: 0,
-    market_type: MarketType::Scalar(0..=100),
-    dispute_mechanism: Some(MarketDisputeMechanism::Court),
-    metadata: vec![],
-    oracle: 0,
-    period: MarketPeriod::Block(0..100),
-    deadlines: Deadlines { grace_period: 1_u32, oracle_duration: 1_u32, dispute_duration: 1_u32 },
-    report: None,
-    resolved_outcome: None,
-    status: MarketStatus::Disputed,
-    scoring_rule: ScoringRule::AmmCdaHybrid,
-    bonds: MarketBonds {
-        creation: None,
-        oracle: None,
-        outsider: None,
-        dispute: None,
-        close_dispute: None,
-        close_request: None,
-    },
-    early_close: None,
-};
+fn default_market() -> MarketOf<Runtime> {
+    Market {
+        market_id: 0,
+        base_asset: Asset::Tru,
+        creation: MarketCreation::Permissionless,
+        creator_fee: sp_runtime::Perbill::zero(),
+        creator: 0,
+        market_type: MarketType::Scalar(0..=100),
+        dispute_mechanism: Some(MarketDisputeMechanism::Court),
+        metadata: vec![],
+        oracle: 0,
+        period: MarketPeriod::Block(0..100),
+        deadlines: Deadlines {
+            grace_period: 1_u32,
+            oracle_duration: 1_u32,
+            dispute_duration: 1_u32,
+        },
+        report: None,
+        resolved_outcome: None,
+        status: MarketStatus::Disputed,
+        scoring_rule: ScoringRule::AmmCdaHybrid,
+        bonds: MarketBonds {
+            creation: None,
+            oracle: None,
+            outsider: None,
+            dispute: None,
+            close_dispute: None,
+            close_request: None,
+            oracles: Default::default(),
+        },
+        early_close: None,
+    }
+}
 
-fn initialize_court() -> CourtId {
+fn initialize_court_without_draws() -> CourtId {
     let now = <frame_system::Pallet<Runtime>>::block_number();
     <RequestBlock<Runtime>>::put(now + RequestInterval::get());
+    // fresh jurors are selected with three quarters of their stake, so that these amounts
+    // provide exactly the 31 sections of `MinJurorStake` which the first round needs
     let amount_alice = 2 * BASE;
-    let amount_bob = 3 * BASE;
+    let amount_bob = 3 * BASE + BASE / 2;
     let amount_charlie = 4 * BASE;
     let amount_dave = 5 * BASE;
-    let amount_eve = 6 * BASE;
+    let amount_eve = 7 * BASE;
     Court::join_court(RuntimeOrigin::signed(ALICE), amount_alice).unwrap();
     Court::join_court(RuntimeOrigin::signed(BOB), amount_bob).unwrap();
     Court::join_court(RuntimeOrigin::signed(CHARLIE), amount_charlie).unwrap();
     Court::join_court(RuntimeOrigin::signed(DAVE), amount_dave).unwrap();
     Court::join_court(RuntimeOrigin::signed(EVE), amount_eve).unwrap();
-    let market_id = MarketCommons::push_market(DEFAULT_MARKET).unwrap();
+    let market_id = MarketCommons::push_market(default_market()).unwrap();
     MarketCommons::mutate_market(&market_id, |market| {
         market.report = Some(Report { at: 1, by: BOB, outcome: ORACLE_REPORT });
         Ok(())
     })
     .unwrap();
-    Court::on_dispute(&market_id, &DEFAULT_MARKET).unwrap();
+    Court::on_dispute(&market_id, &default_market()).unwrap();
     <MarketIdToCourtId<Runtime>>::get(market_id).unwrap()
 }
 
+fn initialize_court() -> CourtId {
+    let court_id = initialize_court_without_draws();
+    draw_participants(court_id);
+    court_id
+}
+
+// Draws the participants of the current court round in the next block, so that the randomness
+// is known after the round was opened.
+fn draw_participants(court_id: CourtId) {
+    run_blocks(1);
+    assert_ok!(Court::draw_participants(RuntimeOrigin::signed(EVE), court_id));
+}
+
+// Runs a round of the randomness beacon in which `who` reveals `secret`.
+fn run_beacon_round(who: TestAccountIdPK, secret: <Runtime as frame_system::Config>::Hash) {
+    if System::block_number() >= CurrentRound::<Runtime>::get().commit_end {
+        run_to_block(CurrentRound::<Runtime>::get().reveal_end);
+    }
+    let round = CurrentRound::<Runtime>::get();
+    let commitment = RandomnessBeacon::commitment_of(&who, &secret);
+    assert_ok!(RandomnessBeacon::commit(RuntimeOrigin::signed(who), commitment));
+    run_to_block(round.commit_end);
+    assert_ok!(RandomnessBeacon::reveal(RuntimeOrigin::signed(who), secret));
+    run_to_block(round.reveal_end);
+}
+
 fn fill_juror_pool(jurors_len: u32) {
     for i in 0..jurors_len {
         let amount = MinJurorStake::get() + i as u128;
-        let juror = (i + 1000) as u128;
+        let juror = account((i + 1000) as u128);
         let _ = Balances::deposit(&juror, amount, Precision::Exact).unwrap();
         assert_ok!(Court::join_court(RuntimeOrigin::signed(juror), amount));
     }
@@ -135,7 +178,7 @@ fn fill_appeals(court_id: CourtId, appeal_number: usize) {
         court
             .appeals
             .try_push(AppealInfo {
-                backer: number,
+                backer: account(number),
                 bond: crate::get_appeal_bond::<Runtime>(court.appeals.len()),
                 appealed_vote_item,
             })
@@ -259,7 +302,7 @@ fn join_court_works_multiple_joins() {
         );
         assert_eq!(
             Participants::<Runtime>::iter()
-                .collect::<Vec<(AccountIdTest, CourtParticipantInfoOf<Runtime>)>>(),
+                .collect::<Vec<(TestAccountIdPK, CourtParticipantInfoOf<Runtime>)>>(),
             vec![(
                 ALICE,
                 CourtParticipantInfo {
@@ -469,14 +512,14 @@ fn join_court_fails_amount_below_lowest_juror() {
         let max_amount = min_amount + max_accounts as u128;
         for i in 1..=max_accounts {
             let amount = max_amount - i as u128;
-            let _ = Balances::deposit(&(i as u128), amount, Precision::Exact).unwrap();
-            assert_ok!(Court::join_court(RuntimeOrigin::signed(i as u128), amount));
+            let _ = Balances::deposit(&account(i as u128), amount, Precision::Exact).unwrap();
+            assert_ok!(Court::join_court(RuntimeOrigin::signed(account(i as u128)), amount));
         }
 
         assert!(CourtPool::<Runtime>::get().is_full());
 
         assert_noop!(
-            Court::join_court(RuntimeOrigin::signed(0u128), min_amount - 1),
+            Court::join_court(RuntimeOrigin::signed(account(0)), min_amount - 1),
             Error::<Runtime>::AmountBelowLowestJuror
         );
     });
@@ -514,7 +557,7 @@ fn prepare_exit_court_removes_lowest_staked_juror() {
 
         for i in 0..CourtPoolOf::<Runtime>::bound() {
             let amount = min_amount + i as u128;
-            let juror = i as u128;
+            let juror = account(i as u128);
             let _ = Balances::deposit(&juror, amount, Precision::Exact).unwrap();
             assert_ok!(Court::join_court(RuntimeOrigin::signed(juror), amount));
         }
@@ -523,11 +566,11 @@ fn prepare_exit_court_removes_lowest_staked_juror() {
         assert!(CourtPool::<Runtime>::get()
             .into_inner()
             .iter()
-            .any(|item| item.court_participant == 0u128));
-        assert_ok!(Court::prepare_exit_court(RuntimeOrigin::signed(0u128)));
+            .any(|item| item.court_participant == account(0)));
+        assert_ok!(Court::prepare_exit_court(RuntimeOrigin::signed(account(0))));
         assert_eq!(CourtPool::<Runtime>::get().into_inner().len(), len - 1);
         CourtPool::<Runtime>::get().into_inner().iter().for_each(|item| {
-            assert_ne!(item.court_participant, 0u128);
+            assert_ne!(item.court_participant, account(0));
         });
     });
 }
@@ -540,12 +583,12 @@ fn prepare_exit_court_removes_middle_staked_juror() {
 
         for i in 0..CourtPoolOf::<Runtime>::bound() {
             let amount = min_amount + i as u128;
-            let juror = i as u128;
+            let juror = account(i as u128);
             let _ = Balances::deposit(&juror, amount, Precision::Exact).unwrap();
             assert_ok!(Court::join_court(RuntimeOrigin::signed(juror), amount));
         }
 
-        let middle_index = (CourtPoolOf::<Runtime>::bound() / 2) as u128;
+        let middle_index = account((CourtPoolOf::<Runtime>::bound() / 2) as u128);
 
         let len = CourtPool::<Runtime>::get().into_inner().len();
         assert!(CourtPool::<Runtime>::get()
@@ -568,12 +611,12 @@ fn prepare_exit_court_removes_highest_staked_juror() {
 
         for i in 0..CourtPoolOf::<Runtime>::bound() {
             let amount = min_amount + i as u128;
-            let juror = i as u128;
+            let juror = account(i as u128);
             let _ = Balances::deposit(&juror, amount, Precision::Exact).unwrap();
             assert_ok!(Court::join_court(RuntimeOrigin::signed(juror), amount));
         }
 
-        let last_index = (CourtPoolOf::<Runtime>::bound() - 1) as u128;
+        let last_index = account((CourtPoolOf::<Runtime>::bound() - 1) as u128);
 
         let len = CourtPool::<Runtime>::get().into_inner().len();
         assert!(CourtPool::<Runtime>::get()
@@ -601,7 +644,7 @@ fn join_court_binary_search_sorted_insert_works() {
         let max_amount = min_amount + max_accounts as u128;
         for i in random_numbers {
             let amount = max_amount - i as u128;
-            let juror = i as u128;
+            let juror = account(i as u128);
             let _ = Balances::deposit(&juror, amount, Precision::Exact).unwrap();
             assert_ok!(Court::join_court(RuntimeOrigin::signed(juror), amount));
         }
@@ -1578,6 +1621,8 @@ fn appeal_overrides_last_draws() {
         run_blocks(VotePeriod::get() + AggregationPeriod::get() + 1);
 
         assert_ok!(Court::appeal(RuntimeOrigin::signed(CHARLIE), court_id));
+        assert!(<SelectedDraws<Runtime>>::get(court_id).is_empty());
+        draw_participants(court_id);
 
         let draws = <SelectedDraws<Runtime>>::get(court_id);
         assert_ne!(draws, last_draws);
@@ -1597,6 +1642,7 @@ fn appeal_draws_total_weight_is_correct() {
         run_blocks(VotePeriod::get() + AggregationPeriod::get() + 1);
 
         assert_ok!(Court::appeal(RuntimeOrigin::signed(CHARLIE), court_id));
+        draw_participants(court_id);
 
         let neccessary_juror_weight = Court::necessary_draws_weight(1usize) as u32;
         let draws = <SelectedDraws<Runtime>>::get(court_id);
@@ -1614,6 +1660,7 @@ fn appeal_get_latest_resolved_outcome_changes() {
         run_blocks(VotePeriod::get() + AggregationPeriod::get() + 1);
 
         assert_ok!(Court::appeal(RuntimeOrigin::signed(CHARLIE), court_id));
+        draw_participants(court_id);
 
         let last_appealed_vote_item = <Courts<Runtime>>::get(court_id)
             .unwrap()
@@ -1888,26 +1935,180 @@ fn reassign_court_stakes_slashes_tardy_jurors_and_rewards_winners() {
 
         let free_alice_after = Balances::free_balance(ALICE);
         assert_ne!(free_alice_after, free_alice_before);
-        assert_eq!(free_alice_after, free_alice_before - old_draws[ALICE as usize].slashable);
+        assert_eq!(free_alice_after, free_alice_before - old_draws[0].slashable);
 
         let free_bob_after = Balances::free_balance(BOB);
         assert_ne!(free_bob_after, free_bob_before);
-        assert_eq!(free_bob_after, free_bob_before - old_draws[BOB as usize].slashable);
+        assert_eq!(free_bob_after, free_bob_before - old_draws[1].slashable);
 
         let free_charlie_after = Balances::free_balance(CHARLIE);
-        let full_slashes = old_draws[ALICE as usize].slashable +
-            old_draws[BOB as usize].slashable +
-            old_draws[DAVE as usize].slashable +
-            old_draws[EVE as usize].slashable;
+        let full_slashes = old_draws[0].slashable +
+            old_draws[1].slashable +
+            old_draws[3].slashable +
+            old_draws[4].slashable;
         assert_eq!(free_charlie_after, free_charlie_before + full_slashes);
 
         let free_dave_after = Balances::free_balance(DAVE);
         assert_ne!(free_dave_after, free_dave_before);
-        assert_eq!(free_dave_after, free_dave_before - old_draws[DAVE as usize].slashable);
+        assert_eq!(free_dave_after, free_dave_before - old_draws[3].slashable);
 
         let free_eve_after = Balances::free_balance(EVE);
         assert_ne!(free_eve_after, free_eve_before);
-        assert_eq!(free_eve_after, free_eve_before - old_draws[EVE as usize].slashable);
+        assert_eq!(free_eve_after, free_eve_before - old_draws[4].slashable);
+    });
+}
+
+#[test]
+fn reassign_court_stakes_updates_reputations() {
+    ExtBuilder::default().build().execute_with(|| {
+        fill_juror_pool(MaxCourtParticipants::get());
+        let court_id = initialize_court();
+
+        let outcome = OutcomeReport::Scalar(42u128);
+        let salt = <Runtime as frame_system::Config>::Hash::default();
+        let commitment = BlakeTwo256::hash_of(&(ALICE, outcome.clone(), salt));
+
+        let vote_item = VoteItem::Outcome(outcome);
+        let other_vote_item = VoteItem::Outcome(OutcomeReport::Scalar(43u128));
+
+        let draws: crate::SelectedDrawsOf<Runtime> = vec![
+            Draw {
+                court_participant: ALICE,
+                weight: 1,
+                vote: Vote::Drawn,
+                slashable: MinJurorStake::get(),
+            },
+            Draw {
+                court_participant: BOB,
+                weight: 1,
+                vote: Vote::Secret { commitment },
+                slashable: MinJurorStake::get(),
+            },
+            Draw {
+                court_participant: CHARLIE,
+                weight: 2,
+                vote: Vote::Revealed { commitment, vote_item: vote_item.clone(), salt },
+                slashable: 2 * MinJurorStake::get(),
+            },
+            Draw {
+                court_participant: DAVE,
+                weight: 1,
+                vote: Vote::Revealed { commitment, vote_item: other_vote_item, salt },
+                slashable: MinJurorStake::get(),
+            },
+            Draw {
+                court_participant: EVE,
+                weight: 1,
+                vote: Vote::Denounced { commitment, vote_item, salt },
+                slashable: MinJurorStake::get(),
+            },
+        ]
+        .try_into()
+        .unwrap();
+        <SelectedDraws<Runtime>>::insert(court_id, draws);
+
+        run_to_block(<RequestBlock<Runtime>>::get() + 1);
+
+        run_blocks(VotePeriod::get() + AggregationPeriod::get() + AppealPeriod::get() + 1);
+
+        let market_id = <CourtIdToMarketId<Runtime>>::get(court_id).unwrap();
+        let market = MarketCommons::market(&market_id).unwrap();
+        let _ = Court::on_resolution(&market_id, &market).unwrap();
+
+        assert_ok!(Court::reassign_court_stakes(RuntimeOrigin::signed(EVE), court_id));
+
+        let reputation = |missed_votes, denounced_votes, majority_votes, minority_votes| {
+            JurorReputation { majority_votes, minority_votes, missed_votes, denounced_votes }
+        };
+        assert_eq!(<Reputations<Runtime>>::get(ALICE), reputation(1, 0, 0, 0));
+        assert_eq!(<Reputations<Runtime>>::get(BOB), reputation(1, 0, 0, 0));
+        assert_eq!(<Reputations<Runtime>>::get(CHARLIE), reputation(0, 0, 1, 0));
+        assert_eq!(<Reputations<Runtime>>::get(DAVE), reputation(0, 0, 0, 1));
+        assert_eq!(<Reputations<Runtime>>::get(EVE), reputation(0, 1, 0, 0));
+    });
+}
+
+#[test_case(0, 0, Perbill::from_percent(50); "no_track_record")]
+#[test_case(3, 0, Perbill::from_percent(80); "always_with_the_majority")]
+#[test_case(0, 3, Perbill::from_percent(20); "never_with_the_majority")]
+#[test_case(2, 3, Perbill::from_percent(50); "mixed")]
+fn juror_reputation_score_works(majority_votes: u32, missed_votes: u32, expected: Perbill) {
+    let reputation = JurorReputation { majority_votes, missed_votes, ..Default::default() };
+    assert_eq!(reputation.score(), expected);
+}
+
+#[test]
+fn selection_weight_is_scaled_by_reputation() {
+    ExtBuilder::default().build().execute_with(|| {
+        let unconsumed = 20 * MinJurorStake::get();
+        assert_eq!(ReputationInfluence::get(), Perbill::from_percent(50));
+        // Participants without track record have a score of 50%.
+        assert_eq!(Court::selection_weight(&ALICE, unconsumed), 15 * MinJurorStake::get());
+
+        // A score of 10% reduces the reputation dependent half of the weight to a tenth.
+        <Reputations<Runtime>>::insert(
+            ALICE,
+            JurorReputation { missed_votes: 8, ..Default::default() },
+        );
+        assert_eq!(Court::selection_weight(&ALICE, unconsumed), 11 * MinJurorStake::get());
+    });
+}
+
+#[test]
+fn selection_weight_keeps_one_section_of_min_juror_stake() {
+    ExtBuilder::default().build().execute_with(|| {
+        let unconsumed = MinJurorStake::get();
+        assert_eq!(Court::selection_weight(&ALICE, unconsumed), unconsumed);
+        assert_eq!(Court::selection_weight(&ALICE, unconsumed - 1), unconsumed - 1);
+    });
+}
+
+#[test]
+fn fresh_accounts_do_not_outrank_proven_jurors() {
+    ExtBuilder::default().build().execute_with(|| {
+        let unconsumed = 20 * MinJurorStake::get();
+        <Reputations<Runtime>>::insert(
+            BOB,
+            JurorReputation { majority_votes: 1, ..Default::default() },
+        );
+        assert!(
+            Court::selection_weight(&ALICE, unconsumed) < Court::selection_weight(&BOB, unconsumed)
+        );
+    });
+}
+
+#[test]
+fn reassign_court_stakes_scores_appealed_rounds_against_the_final_winner() {
+    ExtBuilder::default().build().execute_with(|| {
+        let outcome = OutcomeReport::Scalar(42u128);
+        let (court_id, _, salt) = set_alice_after_vote(outcome.clone());
+        let draws = <SelectedDraws<Runtime>>::get(court_id);
+
+        run_blocks(VotePeriod::get() + 1);
+        let vote_item = VoteItem::Outcome(outcome);
+        assert_ok!(Court::reveal_vote(RuntimeOrigin::signed(ALICE), court_id, vote_item, salt));
+        run_blocks(AggregationPeriod::get() + 1);
+
+        assert_ok!(Court::appeal(RuntimeOrigin::signed(CHARLIE), court_id));
+        draw_participants(court_id);
+        // the appealed round isn't scored before the final winner is known
+        assert_eq!(<Reputations<Runtime>>::get(ALICE), Default::default());
+        assert_eq!(<AppealedDraws<Runtime>>::get(court_id, 1).len(), draws.len());
+
+        // a later round overturns the vote item which alice backed
+        let mut court = <Courts<Runtime>>::get(court_id).unwrap();
+        let winner = VoteItem::Outcome(OutcomeReport::Scalar(0u128));
+        court.status = CourtStatus::Closed { winner };
+        <Courts<Runtime>>::insert(court_id, court);
+        assert_ok!(Court::reassign_court_stakes(RuntimeOrigin::signed(EVE), court_id));
+
+        let reputation = <Reputations<Runtime>>::get(ALICE);
+        assert_eq!(reputation.majority_votes, 0);
+        assert_eq!(reputation.minority_votes, 1);
+        for draw in draws.iter().filter(|draw| draw.court_participant != ALICE) {
+            assert!(<Reputations<Runtime>>::get(draw.court_participant).missed_votes >= 1);
+        }
+        assert!(!<AppealedDraws<Runtime>>::contains_key(court_id, 1));
     });
 }
 
@@ -2181,8 +2382,8 @@ fn reassign_court_stakes_slashes_loosers_and_awards_winners() {
 
         assert_ok!(Court::reassign_court_stakes(RuntimeOrigin::signed(EVE), court_id));
 
-        let bob_slashed = last_draws[BOB as usize].slashable;
-        let dave_slashed = last_draws[DAVE as usize].slashable;
+        let bob_slashed = last_draws[1].slashable;
+        let dave_slashed = last_draws[3].slashable;
         let slashed = bob_slashed + dave_slashed + tardy_or_denounced_value;
 
         let winners_risked_amount = charlie_slashable + alice_slashable;
@@ -2421,10 +2622,10 @@ fn reassign_court_stakes_rewards_treasury_if_no_winner() {
 
         assert_ok!(Court::reassign_court_stakes(RuntimeOrigin::signed(EVE), court_id));
 
-        let alice_slashed = last_draws[ALICE as usize].slashable;
-        let bob_slashed = last_draws[BOB as usize].slashable;
-        let charlie_slashed = last_draws[CHARLIE as usize].slashable;
-        let dave_slashed = last_draws[DAVE as usize].slashable;
+        let alice_slashed = last_draws[0].slashable;
+        let bob_slashed = last_draws[1].slashable;
+        let charlie_slashed = last_draws[2].slashable;
+        let dave_slashed = last_draws[3].slashable;
 
         let slashed = bob_slashed + dave_slashed + alice_slashed + charlie_slashed;
 
@@ -2447,7 +2648,7 @@ fn reassign_court_stakes_rewards_treasury_if_no_winner() {
 #[test]
 fn on_dispute_denies_non_court_markets() {
     ExtBuilder::default().build().execute_with(|| {
-        let mut market = DEFAULT_MARKET;
+        let mut market = default_market();
         market.dispute_mechanism = Some(MarketDisputeMechanism::Authorized);
         assert_noop!(
             Court::on_dispute(&0, &market),
@@ -2473,7 +2674,7 @@ fn on_resolution_sets_court_status() {
 #[test]
 fn on_resolution_fails_if_court_not_found() {
     ExtBuilder::default().build().execute_with(|| {
-        let market_id = MarketCommons::push_market(DEFAULT_MARKET).unwrap();
+        let market_id = MarketCommons::push_market(default_market()).unwrap();
         let market = MarketCommons::market(&market_id).unwrap();
 
         <MarketIdToCourtId<Runtime>>::insert(market_id, 0);
@@ -2484,7 +2685,7 @@ fn on_resolution_fails_if_court_not_found() {
 #[test]
 fn on_resolution_denies_non_court_markets() {
     ExtBuilder::default().build().execute_with(|| {
-        let mut market = DEFAULT_MARKET;
+        let mut market = default_market();
         market.dispute_mechanism = Some(MarketDisputeMechanism::Authorized);
         assert_noop!(
             Court::on_resolution(&0, &market),
@@ -2496,7 +2697,7 @@ fn on_resolution_denies_non_court_markets() {
 #[test]
 fn exchange_fails_if_non_court_markets() {
     ExtBuilder::default().build().execute_with(|| {
-        let mut market = DEFAULT_MARKET;
+        let mut market = default_market();
         market.dispute_mechanism = Some(MarketDisputeMechanism::Authorized);
         assert_noop!(
             Court::exchange(&0, &market, &ORACLE_REPORT, NegativeImbalance::<Runtime>::zero()),
@@ -2531,7 +2732,7 @@ fn exchange_slashes_unjustified_and_unreserves_justified_appealers() {
                 VoteItem::Outcome(other_outcome.clone())
             };
 
-            let backer = number;
+            let backer = account(number);
             let _ = Balances::deposit(&backer, bond, Precision::Exact).unwrap();
             assert_ok!(Balances::reserve_named(&Court::reserve_id(), &backer, bond));
             let free_balance = Balances::free_balance(backer);
@@ -2607,7 +2808,7 @@ fn on_global_dispute_removes_draws() {
 #[test]
 fn on_global_dispute_fails_if_wrong_dispute_mechanism() {
     ExtBuilder::default().build().execute_with(|| {
-        let mut market = DEFAULT_MARKET;
+        let mut market = default_market();
         market.dispute_mechanism = Some(MarketDisputeMechanism::Authorized);
         assert_noop!(
             Court::on_global_dispute(&0, &market),
@@ -2620,7 +2821,7 @@ fn on_global_dispute_fails_if_wrong_dispute_mechanism() {
 fn on_global_dispute_fails_if_court_not_found() {
     ExtBuilder::default().build().execute_with(|| {
         <MarketIdToCourtId<Runtime>>::insert(0, 0);
-        let market = DEFAULT_MARKET;
+        let market = default_market();
         assert_noop!(Court::on_global_dispute(&0, &market), Error::<Runtime>::CourtNotFound);
     });
 }
@@ -2657,7 +2858,7 @@ fn on_global_dispute_returns_appealed_outcomes() {
         for number in 0..MaxAppeals::get() {
             let appealed_vote_item: VoteItem =
                 VoteItem::Outcome(OutcomeReport::Scalar(number as u128));
-            let backer = number as u128;
+            let backer = account(number as u128);
             let bond = crate::get_appeal_bond::<Runtime>(court.appeals.len());
             gd_outcomes.push(GlobalDisputeItem {
                 outcome: appealed_vote_item.clone().into_outcome().unwrap(),
@@ -2677,11 +2878,11 @@ fn choose_multiple_weighted_works() {
         let necessary_draws_weight = Court::necessary_draws_weight(0usize);
         for i in 0..necessary_draws_weight {
             let amount = MinJurorStake::get() + i as u128;
-            let juror = i as u128;
+            let juror = account(i as u128);
             let _ = Balances::deposit(&juror, amount, Precision::Exact).unwrap();
             assert_ok!(Court::join_court(RuntimeOrigin::signed(juror), amount));
         }
-        let random_jurors = Court::choose_multiple_weighted(necessary_draws_weight).unwrap();
+        let random_jurors = Court::choose_multiple_weighted(necessary_draws_weight, 0).unwrap();
         assert_eq!(
             random_jurors.iter().map(|draw| draw.weight).sum::<u32>() as usize,
             necessary_draws_weight
@@ -2701,7 +2902,7 @@ fn select_participants_updates_juror_consumed_stake() {
         let jurors = CourtPool::<Runtime>::get();
         let consumed_stake_before = jurors.iter().map(|juror| juror.consumed_stake).sum::<u128>();
 
-        let new_draws = Court::select_participants(appeal_number).unwrap();
+        let new_draws = Court::select_participants(appeal_number, 0).unwrap();
 
         let total_draw_slashable = new_draws.iter().map(|draw| draw.slashable).sum::<u128>();
         let jurors = CourtPool::<Runtime>::get();
@@ -2720,13 +2921,13 @@ fn select_participants_fails_if_not_enough_jurors(appeal_number: usize) {
         let necessary_draws_weight = Court::necessary_draws_weight(appeal_number);
         for i in 0..(necessary_draws_weight - 1usize) {
             let amount = MinJurorStake::get() + i as u128;
-            let juror = (i + 1000) as u128;
+            let juror = account((i + 1000) as u128);
             let _ = Balances::deposit(&juror, amount, Precision::Exact).unwrap();
             assert_ok!(Court::join_court(RuntimeOrigin::signed(juror), amount));
         }
 
         assert_noop!(
-            Court::select_participants(appeal_number),
+            Court::select_participants(appeal_number, 0),
             Error::<Runtime>::NotEnoughJurorsAndDelegatorsStake
         );
     });
@@ -2750,6 +2951,7 @@ fn appeal_reduces_active_lock_from_old_draws() {
         run_blocks(VotePeriod::get() + AggregationPeriod::get() + 1);
 
         assert_ok!(Court::appeal(RuntimeOrigin::signed(CHARLIE), court_id));
+        draw_participants(court_id);
 
         let new_draws = <SelectedDraws<Runtime>>::get(court_id);
         old_draws.iter().for_each(|draw| {
@@ -2880,7 +3082,7 @@ fn has_failed_returns_true_for_uninitialized_court() {
     ExtBuilder::default().build().execute_with(|| {
         // force empty jurors pool
         <CourtPool<Runtime>>::kill();
-        let market_id = MarketCommons::push_market(DEFAULT_MARKET).unwrap();
+        let market_id = MarketCommons::push_market(default_market()).unwrap();
         let report_block = 42;
         MarketCommons::mutate_market(&market_id, |market| {
             market.report = Some(Report { at: report_block, by: BOB, outcome: ORACLE_REPORT });
@@ -2921,7 +3123,7 @@ fn prepare_draws(court_id: CourtId, outcomes_with_weights: Vec<(u128, u32)>) {
     for (i, (outcome_index, weight)) in outcomes_with_weights.iter().enumerate() {
         // offset to not conflict with other jurors
         let offset_i = (i + 1000) as u128;
-        let juror = offset_i;
+        let juror = account(offset_i);
         let salt = BlakeTwo256::hash_of(&offset_i);
         let vote_item: VoteItem = VoteItem::Outcome(OutcomeReport::Scalar(*outcome_index));
         let commitment = BlakeTwo256::hash_of(&(juror, vote_item.clone(), salt));
@@ -3024,17 +3226,17 @@ fn choose_multiple_weighted_returns_different_jurors_with_other_seed() {
         <crate::SelectionNonce<Runtime>>::put(nonce_0);
         // randomness is mocked and purely based on the nonce
         // thus a different nonce will result in a different seed (disregarding hash collisions)
-        let first_random_seed = Court::get_random_seed(nonce_0);
-        let first_random_list = Court::choose_multiple_weighted(3).unwrap();
+        let first_random_seed = Court::get_random_seed(nonce_0, 0).unwrap();
+        let first_random_list = Court::choose_multiple_weighted(3, 0).unwrap();
 
         run_blocks(1);
 
         let nonce_1 = 69u64;
         <crate::SelectionNonce<Runtime>>::put(nonce_1);
-        let second_random_seed = Court::get_random_seed(nonce_1);
+        let second_random_seed = Court::get_random_seed(nonce_1, 0).unwrap();
 
         assert_ne!(first_random_seed, second_random_seed);
-        let second_random_list = Court::choose_multiple_weighted(3).unwrap();
+        let second_random_list = Court::choose_multiple_weighted(3, 0).unwrap();
 
         // the two lists contain different jurors
         for juror in &first_random_list {
@@ -3052,17 +3254,167 @@ fn get_random_seed_returns_equal_seeds_with_equal_nonce() {
         // the same nonce always results in the same seed for testing deterministic
         let nonce = 42u64;
         <crate::SelectionNonce<Runtime>>::put(nonce);
-        let first_random_seed = Court::get_random_seed(nonce);
+        let first_random_seed = Court::get_random_seed(nonce, 0).unwrap();
 
         run_blocks(1);
 
         <crate::SelectionNonce<Runtime>>::put(nonce);
-        let second_random_seed = Court::get_random_seed(nonce);
+        let second_random_seed = Court::get_random_seed(nonce, 0).unwrap();
 
         assert_eq!(first_random_seed, second_random_seed);
     });
 }
 
+#[test]
+fn juror_draws_cannot_be_predicted_before_the_randomness_is_revealed() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_block(123);
+        fill_juror_pool(MaxCourtParticipants::get());
+        // Everyone knows the latest output of the beacon when the market is disputed.
+        run_beacon_round(ALICE, BlakeTwo256::hash(b"known secret"));
+        let now = <frame_system::Pallet<Runtime>>::block_number();
+        <RequestBlock<Runtime>>::put(now + RequestInterval::get());
+        let market_id = MarketCommons::push_market(default_market()).unwrap();
+        Court::on_dispute(&market_id, &default_market()).unwrap();
+        let court_id = <MarketIdToCourtId<Runtime>>::get(market_id).unwrap();
+        assert!(<SelectedDraws<Runtime>>::get(court_id).is_empty());
+
+        // So the jurors can't be drawn from it.
+        run_blocks(1);
+        assert_noop!(
+            Court::draw_participants(RuntimeOrigin::signed(EVE), court_id),
+            Error::<Runtime>::RandomnessNotAvailable
+        );
+
+        // The draws depend on the secrets which are revealed after the dispute.
+        let draws_after_reveal_of = |secret: &[u8]| {
+            with_transaction(|| {
+                run_beacon_round(BOB, BlakeTwo256::hash(secret));
+                assert_ok!(Court::draw_participants(RuntimeOrigin::signed(EVE), court_id));
+                let draws = <SelectedDraws<Runtime>>::get(court_id).into_inner();
+                TransactionOutcome::Rollback(Ok::<_, DispatchError>(draws))
+            })
+            .unwrap()
+        };
+        let draws = draws_after_reveal_of(b"first secret");
+        assert!(!draws.is_empty());
+        assert_ne!(draws_after_reveal_of(b"second secret"), draws);
+    });
+}
+
+#[test]
+fn on_dispute_defers_draws() {
+    ExtBuilder::default().build().execute_with(|| {
+        let now = <frame_system::Pallet<Runtime>>::block_number();
+        let court_id = initialize_court_without_draws();
+        assert!(<SelectedDraws<Runtime>>::get(court_id).is_empty());
+        assert_eq!(<PendingDraws<Runtime>>::get(court_id), Some(now));
+    });
+}
+
+#[test]
+fn draw_participants_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let court_id = initialize_court_without_draws();
+        let round_ends = <Courts<Runtime>>::get(court_id).unwrap().round_ends;
+        draw_participants(court_id);
+
+        let draws = <SelectedDraws<Runtime>>::get(court_id);
+        let draws_total_weight = draws.iter().map(|draw| draw.weight).sum::<u32>();
+        assert_eq!(draws_total_weight, Court::necessary_draws_weight(0usize) as u32);
+        assert_eq!(<PendingDraws<Runtime>>::get(court_id), None);
+        assert_eq!(<Courts<Runtime>>::get(court_id).unwrap().round_ends, round_ends);
+        System::assert_last_event(Event::ParticipantsDrawn { court_id, round_ends }.into());
+    });
+}
+
+#[test]
+fn draw_participants_fails_if_participants_already_drawn() {
+    ExtBuilder::default().build().execute_with(|| {
+        let court_id = initialize_court();
+        assert_noop!(
+            Court::draw_participants(RuntimeOrigin::signed(EVE), court_id),
+            Error::<Runtime>::ParticipantsAlreadyDrawn
+        );
+    });
+}
+
+#[test]
+fn draw_participants_fails_in_the_block_the_round_was_opened() {
+    ExtBuilder::default().build().execute_with(|| {
+        let court_id = initialize_court_without_draws();
+        assert_noop!(
+            Court::draw_participants(RuntimeOrigin::signed(EVE), court_id),
+            Error::<Runtime>::RandomnessNotAvailable
+        );
+    });
+}
+
+#[test]
+fn draw_participants_postpones_the_round_if_the_pre_vote_period_is_over() {
+    ExtBuilder::default().build().execute_with(|| {
+        let court_id = initialize_court_without_draws();
+        let market_id = <CourtIdToMarketId<Runtime>>::get(court_id).unwrap();
+        let old_round_ends = <Courts<Runtime>>::get(court_id).unwrap().round_ends;
+        run_to_block(old_round_ends.pre_vote + 1);
+
+        assert_ok!(Court::draw_participants(RuntimeOrigin::signed(EVE), court_id));
+
+        let round_ends = <Courts<Runtime>>::get(court_id).unwrap().round_ends;
+        let request_block = <RequestBlock<Runtime>>::get();
+        assert!(old_round_ends.pre_vote < request_block);
+        assert_eq!(round_ends.pre_vote, request_block);
+        assert_eq!(round_ends.vote, request_block + VotePeriod::get());
+        assert_eq!(MarketIdsPerDisputeBlock::<Runtime>::get(old_round_ends.appeal), vec![]);
+        assert_eq!(MarketIdsPerDisputeBlock::<Runtime>::get(round_ends.appeal), vec![market_id]);
+    });
+}
+
+#[test]
+fn on_idle_draws_pending_participants() {
+    ExtBuilder::default().build().execute_with(|| {
+        let court_id = initialize_court_without_draws();
+        let round_ends = <Courts<Runtime>>::get(court_id).unwrap().round_ends;
+        run_blocks(1);
+
+        Court::on_idle(System::block_number(), Weight::MAX);
+
+        let draws = <SelectedDraws<Runtime>>::get(court_id);
+        let draws_total_weight = draws.iter().map(|draw| draw.weight).sum::<u32>();
+        assert_eq!(draws_total_weight, Court::necessary_draws_weight(0usize) as u32);
+        assert_eq!(<PendingDraws<Runtime>>::get(court_id), None);
+        System::assert_last_event(Event::ParticipantsDrawn { court_id, round_ends }.into());
+    });
+}
+
+#[test]
+fn on_idle_skips_draws_in_the_block_the_round_was_opened() {
+    ExtBuilder::default().build().execute_with(|| {
+        let now = <frame_system::Pallet<Runtime>>::block_number();
+        let court_id = initialize_court_without_draws();
+
+        Court::on_idle(now, Weight::MAX);
+
+        assert!(<SelectedDraws<Runtime>>::get(court_id).is_empty());
+        assert_eq!(<PendingDraws<Runtime>>::get(court_id), Some(now));
+    });
+}
+
+#[test]
+fn on_idle_skips_draws_without_enough_weight() {
+    ExtBuilder::default().build().execute_with(|| {
+        let now = <frame_system::Pallet<Runtime>>::block_number();
+        let court_id = initialize_court_without_draws();
+        run_blocks(1);
+
+        let weight = Court::on_idle(System::block_number(), Weight::zero());
+
+        assert!(<SelectedDraws<Runtime>>::get(court_id).is_empty());
+        assert_eq!(<PendingDraws<Runtime>>::get(court_id), Some(now));
+        assert_eq!(weight, Weight::zero());
+    });
+}
+
 #[test]
 fn random_jurors_returns_a_subset_of_jurors() {
     ExtBuilder::default().build().execute_with(|| {
@@ -3071,7 +3423,7 @@ fn random_jurors_returns_a_subset_of_jurors() {
 
         let jurors = <CourtPool<Runtime>>::get();
 
-        let random_jurors = Court::choose_multiple_weighted(2).unwrap();
+        let random_jurors = Court::choose_multiple_weighted(2, 0).unwrap();
         for draw in random_jurors {
             assert!(jurors.iter().any(|el| el.court_participant == draw.court_participant));
         }
@@ -3103,9 +3455,9 @@ fn handle_inflation_works() {
         let joined_at = <frame_system::Pallet<Runtime>>::block_number();
         for number in jurors_list.iter() {
             let stake = *number;
-            let juror = *number;
+            let juror = account(*number);
             let _ = Balances::deposit(&juror, stake, Precision::Exact).unwrap();
-            free_balances_before.insert(juror, stake);
+            free_balances_before.insert(*number, stake);
             jurors
                 .try_push(CourtPoolItem {
                     stake,
@@ -3122,19 +3474,19 @@ fn handle_inflation_works() {
         let inflation_period = InflationPeriod::get();
         run_blocks(inflation_period + 1);
 
-        let free_balance_after_0 = Balances::free_balance(jurors_list[0]);
+        let free_balance_after_0 = Balances::free_balance(account(jurors_list[0]));
         assert_eq!(free_balance_after_0 - free_balances_before[&jurors_list[0]], 216_002);
 
-        let free_balance_after_1 = Balances::free_balance(jurors_list[1]);
+        let free_balance_after_1 = Balances::free_balance(account(jurors_list[1]));
         assert_eq!(free_balance_after_1 - free_balances_before[&jurors_list[1]], 2_160_021);
 
-        let free_balance_after_2 = Balances::free_balance(jurors_list[2]);
+        let free_balance_after_2 = Balances::free_balance(account(jurors_list[2]));
         assert_eq!(free_balance_after_2 - free_balances_before[&jurors_list[2]], 21_600_219);
 
-        let free_balance_after_3 = Balances::free_balance(jurors_list[3]);
+        let free_balance_after_3 = Balances::free_balance(account(jurors_list[3]));
         assert_eq!(free_balance_after_3 - free_balances_before[&jurors_list[3]], 216_002_199);
 
-        let free_balance_after_4 = Balances::free_balance(jurors_list[4]);
+        let free_balance_after_4 = Balances::free_balance(account(jurors_list[4]));
         assert_eq!(free_balance_after_4 - free_balances_before[&jurors_list[4]], 2_160_021_996);
     });
 }
@@ -3198,11 +3550,11 @@ fn block_inflation_reward_after_higher_rejoin() {
 }
 
 fn gain_equal(
-    account: AccountIdTest,
+    who: TestAccountIdPK,
     free_before: BalanceOf<Runtime>,
     gain: BalanceOf<Runtime>,
 ) -> BalanceOf<Runtime> {
-    let free_after = Balances::free_balance(account);
+    let free_after = Balances::free_balance(who);
     assert_eq!(free_after - free_before, gain);
     gain
 }
@@ -3421,9 +3773,9 @@ fn handle_inflation_without_waiting_one_inflation_period() {
         let joined_at = <frame_system::Pallet<Runtime>>::block_number();
         for number in jurors_list.iter() {
             let stake = *number;
-            let juror = *number;
+            let juror = account(*number);
             let _ = Balances::deposit(&juror, stake, Precision::Exact).unwrap();
-            free_balances_before.insert(juror, stake);
+            free_balances_before.insert(*number, stake);
             jurors
                 .try_push(CourtPoolItem {
                     stake,
@@ -3442,19 +3794,151 @@ fn handle_inflation_without_waiting_one_inflation_period() {
         let now = <frame_system::Pallet<Runtime>>::block_number();
         Court::handle_inflation(now);
 
-        let free_balance_after_0 = Balances::free_balance(jurors_list[0]);
+        let free_balance_after_0 = Balances::free_balance(account(jurors_list[0]));
         assert_eq!(free_balance_after_0 - free_balances_before[&jurors_list[0]], 0);
 
-        let free_balance_after_1 = Balances::free_balance(jurors_list[1]);
+        let free_balance_after_1 = Balances::free_balance(account(jurors_list[1]));
         assert_eq!(free_balance_after_1 - free_balances_before[&jurors_list[1]], 0);
 
-        let free_balance_after_2 = Balances::free_balance(jurors_list[2]);
+        let free_balance_after_2 = Balances::free_balance(account(jurors_list[2]));
         assert_eq!(free_balance_after_2 - free_balances_before[&jurors_list[2]], 0);
 
-        let free_balance_after_3 = Balances::free_balance(jurors_list[3]);
+        let free_balance_after_3 = Balances::free_balance(account(jurors_list[3]));
         assert_eq!(free_balance_after_3 - free_balances_before[&jurors_list[3]], 0);
 
-        let free_balance_after_4 = Balances::free_balance(jurors_list[4]);
+        let free_balance_after_4 = Balances::free_balance(account(jurors_list[4]));
         assert_eq!(free_balance_after_4 - free_balances_before[&jurors_list[4]], 0);
     });
 }
+
+fn signer() -> TestAccount {
+    TestAccount::new([0; 32])
+}
+
+fn fund_signer(amount: BalanceOf<Runtime>) -> TestAccountIdPK {
+    let who = signer().account_id();
+    let _ = Balances::deposit(&who, amount, Precision::Exact).unwrap();
+    who
+}
+
+fn sign(who: &TestAccount, payload: Vec<u8>) -> Proof<SignatureTest, TestAccountIdPK> {
+    let signature = SignatureTest::from(who.key_pair().sign(&payload));
+    Proof { signer: who.account_id(), relayer: EVE, signature }
+}
+
+fn signed_join_court_proof(
+    who: &TestAccount,
+    amount: BalanceOf<Runtime>,
+) -> Proof<SignatureTest, TestAccountIdPK> {
+    let nonce = UserNonces::<Runtime>::get(who.account_id());
+    sign(who, Court::encode_signed_join_court_params(&EVE, nonce, &amount))
+}
+
+#[test]
+fn signed_join_court_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let amount = 2 * BASE;
+        let who = fund_signer(10 * BASE);
+        let proof = signed_join_court_proof(&signer(), amount);
+
+        assert_ok!(Court::signed_join_court(RuntimeOrigin::signed(who), proof, amount));
+
+        System::assert_last_event(Event::JurorJoined { juror: who, stake: amount }.into());
+        assert_eq!(Participants::<Runtime>::get(who).unwrap().stake, amount);
+        assert_eq!(Balances::locks(who), vec![the_lock(amount)]);
+        assert_eq!(UserNonces::<Runtime>::get(who), 1);
+    });
+}
+
+#[test]
+fn signed_join_court_fails_on_replayed_proof() {
+    ExtBuilder::default().build().execute_with(|| {
+        let amount = 2 * BASE;
+        let who = fund_signer(10 * BASE);
+        let proof = signed_join_court_proof(&signer(), amount);
+
+        assert_ok!(Court::signed_join_court(RuntimeOrigin::signed(who), proof.clone(), amount));
+        assert_noop!(
+            Court::signed_join_court(RuntimeOrigin::signed(who), proof, amount),
+            Error::<Runtime>::UnauthorizedSignedTransaction
+        );
+    });
+}
+
+#[test]
+fn signed_join_court_fails_if_sender_is_not_signer() {
+    ExtBuilder::default().build().execute_with(|| {
+        let amount = 2 * BASE;
+        let _ = fund_signer(10 * BASE);
+        let proof = signed_join_court_proof(&signer(), amount);
+
+        assert_noop!(
+            Court::signed_join_court(RuntimeOrigin::signed(BOB), proof, amount),
+            Error::<Runtime>::SenderIsNotSigner
+        );
+    });
+}
+
+#[test]
+fn signed_join_court_fails_on_wrong_relayer() {
+    ExtBuilder::default().build().execute_with(|| {
+        let amount = 2 * BASE;
+        let who = fund_signer(10 * BASE);
+        let proof = Proof { relayer: DAVE, ..signed_join_court_proof(&signer(), amount) };
+
+        assert_noop!(
+            Court::signed_join_court(RuntimeOrigin::signed(who), proof, amount),
+            Error::<Runtime>::UnauthorizedSignedTransaction
+        );
+    });
+}
+
+#[test]
+fn signed_delegate_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let amount = 2 * BASE;
+        assert_ok!(Court::join_court(RuntimeOrigin::signed(ALICE), amount));
+        let who = fund_signer(10 * BASE);
+        let delegations = vec![ALICE];
+        let nonce = UserNonces::<Runtime>::get(who);
+        let payload = Court::encode_signed_delegate_params(&EVE, nonce, &amount, &delegations);
+        let proof = sign(&signer(), payload);
+
+        assert_ok!(Court::signed_delegate(
+            RuntimeOrigin::signed(who),
+            proof,
+            amount,
+            delegations.clone()
+        ));
+
+        System::assert_last_event(
+            Event::DelegatorJoined { delegator: who, stake: amount, delegated_jurors: delegations }
+                .into(),
+        );
+        assert_eq!(UserNonces::<Runtime>::get(who), 1);
+    });
+}
+
+#[test]
+fn signed_prepare_exit_court_and_signed_exit_court_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let amount = 2 * BASE;
+        let who = fund_signer(10 * BASE);
+        let proof = signed_join_court_proof(&signer(), amount);
+        assert_ok!(Court::signed_join_court(RuntimeOrigin::signed(who), proof, amount));
+
+        let payload = Court::encode_signed_prepare_exit_court_params(&EVE, 1);
+        let proof = sign(&signer(), payload);
+        assert_ok!(Court::signed_prepare_exit_court(RuntimeOrigin::signed(who), proof));
+        System::assert_last_event(Event::ExitPrepared { court_participant: who }.into());
+
+        run_blocks(InflationPeriod::get());
+
+        let payload = Court::encode_signed_exit_court_params(&EVE, 2);
+        let proof = sign(&signer(), payload);
+        assert_ok!(Court::signed_exit_court(RuntimeOrigin::signed(who), proof));
+        assert!(Participants::<Runtime>::get(who).is_none());
+        assert!(Balances::locks(who).is_empty());
+        assert_eq!(UserNonces::<Runtime>::get(who), 3);
+    });
+}
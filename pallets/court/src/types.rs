@@ -364,4 +364,5 @@ impl<AccountId, Balance> Default for JurorVoteWithStakes<AccountId, Balance> {
 pub(crate) enum SelectionError {
     NoValidDelegatedJuror,
     BinarySearchByKeyFailed,
+    RandomnessNotAvailable,
 }
@@ -69,6 +69,14 @@ pub trait WeightInfoZeitgeist {
     fn has_failed() -> Weight;
     fn on_global_dispute(a: u32, d: u32) -> Weight;
     fn clear(d: u32) -> Weight;
+    fn signed_join_court(j: u32) -> Weight;
+    fn signed_delegate(j: u32, d: u32) -> Weight;
+    fn signed_prepare_exit_court(j: u32) -> Weight;
+    fn signed_exit_court() -> Weight;
+    fn signed_vote(d: u32) -> Weight;
+    fn signed_reveal_vote(d: u32) -> Weight;
+    fn signed_appeal(j: u32, a: u32, r: u32, f: u32) -> Weight;
+    fn draw_participants(j: u32, a: u32, r: u32, f: u32) -> Weight;
 }
 
 /// Weight functions for pallet_pm_court (automatically generated)
@@ -249,6 +257,8 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `PredictionMarkets::MarketIdsPerDisputeBlock` (`max_values`: None, `max_size`:
     /// Some(1042), added: 3517, mode: `MaxEncodedLen`) Storage: `Balances::Reserves` (r:1 w:1)
     /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode:
+    /// `MaxEncodedLen`) Storage: `Court::Reputations` (r:1000 w:343)
+    /// Proof: `Court::Reputations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode:
     /// `MaxEncodedLen`) The range of component `j` is `[255, 1000]`.
     /// The range of component `a` is `[0, 2]`.
     /// The range of component `r` is `[0, 62]`.
@@ -264,11 +274,12 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             // Standard Error: 21_635_039
             .saturating_add(Weight::from_parts(3_898_041_505, 0).saturating_mul(a.into()))
             .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(j.into())))
             .saturating_add(T::DbWeight::get().reads((127_u64).saturating_mul(a.into())))
             .saturating_add(T::DbWeight::get().writes(100))
-            .saturating_add(T::DbWeight::get().writes((117_u64).saturating_mul(a.into())))
+            .saturating_add(T::DbWeight::get().writes((234_u64).saturating_mul(a.into())))
             .saturating_add(Weight::from_parts(0, 318078).saturating_mul(a.into()))
-            .saturating_add(Weight::from_parts(0, 194).saturating_mul(j.into()))
+            .saturating_add(Weight::from_parts(0, 2539).saturating_mul(j.into()))
     }
     /// Storage: `Court::Courts` (r:1 w:1)
     /// Proof: `Court::Courts` (`max_values`: None, `max_size`: Some(349), added: 2824, mode:
@@ -278,19 +289,21 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `Court::Participants` (`max_values`: None, `max_size`: Some(251), added: 2726, mode:
     /// `MaxEncodedLen`) Storage: `System::Account` (r:511 w:510)
     /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode:
+    /// `MaxEncodedLen`) Storage: `Court::Reputations` (r:510 w:510)
+    /// Proof: `Court::Reputations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode:
     /// `MaxEncodedLen`) The range of component `d` is `[5, 510]`.
     fn reassign_court_stakes(d: u32) -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `947 + d * (523 ±0)`
+        //  Measured:  `947 + d * (539 ±0)`
         //  Estimated: `153439 + d * (2726 ±0)`
-        // Minimum execution time: 151_234 nanoseconds.
-        Weight::from_parts(152_663_000, 153439)
-            // Standard Error: 89_829
-            .saturating_add(Weight::from_parts(79_360_224, 0).saturating_mul(d.into()))
+        // Minimum execution time: 158_471 nanoseconds.
+        Weight::from_parts(159_902_000, 153439)
+            // Standard Error: 91_204
+            .saturating_add(Weight::from_parts(86_113_517, 0).saturating_mul(d.into()))
             .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(d.into())))
             .saturating_add(T::DbWeight::get().writes(2))
-            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(d.into())))
             .saturating_add(Weight::from_parts(0, 2726).saturating_mul(d.into()))
     }
     /// Storage: `Court::YearlyInflation` (r:0 w:1)
@@ -368,6 +381,8 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `Court::MarketIdToCourtId` (`max_values`: None, `max_size`: Some(40), added: 2515,
     /// mode: `MaxEncodedLen`) Storage: `Court::Courts` (r:0 w:1)
     /// Proof: `Court::Courts` (`max_values`: None, `max_size`: Some(349), added: 2824, mode:
+    /// `MaxEncodedLen`) Storage: `Court::Reputations` (r:1000 w:0)
+    /// Proof: `Court::Reputations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode:
     /// `MaxEncodedLen`) The range of component `j` is `[31, 1000]`.
     /// The range of component `r` is `[0, 62]`.
     fn on_dispute(j: u32, r: u32) -> Weight {
@@ -381,8 +396,9 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             // Standard Error: 34_279
             .saturating_add(Weight::from_parts(220_032, 0).saturating_mul(r.into()))
             .saturating_add(T::DbWeight::get().reads(34))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(j.into())))
             .saturating_add(T::DbWeight::get().writes(36))
-            .saturating_add(Weight::from_parts(0, 8).saturating_mul(j.into()))
+            .saturating_add(Weight::from_parts(0, 2539).saturating_mul(j.into()))
             .saturating_add(Weight::from_parts(0, 25).saturating_mul(r.into()))
     }
     /// Storage: `Court::MarketIdToCourtId` (r:1 w:0)
@@ -511,4 +527,207 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
             .saturating_add(Weight::from_parts(0, 2726).saturating_mul(d.into()))
     }
+    /// Storage: `Court::UserNonces` (r:1 w:1)
+    /// Proof: `Court::UserNonces` (`max_values`: None, `max_size`: Some(56), added: 2531,
+    /// mode: `MaxEncodedLen`)
+    /// Storage: `Court::CourtPool` (r:1 w:1)
+    /// Proof: `Court::CourtPool` (`max_values`: Some(1), `max_size`: Some(96002), added: 96497,
+    /// mode: `MaxEncodedLen`) Storage: `Court::Participants` (r:1 w:1)
+    /// Proof: `Court::Participants` (`max_values`: None, `max_size`: Some(251), added: 2726, mode:
+    /// `MaxEncodedLen`) Storage: `Balances::Locks` (r:1 w:1)
+    /// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode:
+    /// `MaxEncodedLen`) Storage: `Balances::Freezes` (r:1 w:0)
+    /// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode:
+    /// `MaxEncodedLen`) The range of component `j` is `[0, 999]`.
+    fn signed_join_court(j: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1082 + j * (96 ±0)`
+        //  Estimated: `97487`
+        // Minimum execution time: 97_531 nanoseconds.
+        Weight::from_parts(107_818_024, 97487)
+            // Standard Error: 429
+            .saturating_add(Weight::from_parts(87_585, 0).saturating_mul(j.into()))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    /// Storage: `Court::UserNonces` (r:1 w:1)
+    /// Proof: `Court::UserNonces` (`max_values`: None, `max_size`: Some(56), added: 2531,
+    /// mode: `MaxEncodedLen`)
+    /// Storage: `Court::CourtPool` (r:1 w:1)
+    /// Proof: `Court::CourtPool` (`max_values`: Some(1), `max_size`: Some(96002), added: 96497,
+    /// mode: `MaxEncodedLen`) Storage: `Court::Participants` (r:6 w:1)
+    /// Proof: `Court::Participants` (`max_values`: None, `max_size`: Some(251), added: 2726, mode:
+    /// `MaxEncodedLen`) Storage: `Balances::Locks` (r:1 w:1)
+    /// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode:
+    /// `MaxEncodedLen`) Storage: `Balances::Freezes` (r:1 w:0)
+    /// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode:
+    /// `MaxEncodedLen`) The range of component `j` is `[5, 999]`.
+    /// The range of component `d` is `[1, 5]`.
+    fn signed_delegate(j: u32, d: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0 + d * (651 ±0) + j * (98 ±0)`
+        //  Estimated: `97487 + d * (2726 ±0)`
+        // Minimum execution time: 122_523 nanoseconds.
+        Weight::from_parts(103_742_170, 97487)
+            // Standard Error: 637
+            .saturating_add(Weight::from_parts(118_140, 0).saturating_mul(j.into()))
+            // Standard Error: 138_375
+            .saturating_add(Weight::from_parts(6_736_864, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(Weight::from_parts(0, 2726).saturating_mul(d.into()))
+    }
+    /// Storage: `Court::UserNonces` (r:1 w:1)
+    /// Proof: `Court::UserNonces` (`max_values`: None, `max_size`: Some(56), added: 2531,
+    /// mode: `MaxEncodedLen`)
+    /// Storage: `Court::Participants` (r:1 w:1)
+    /// Proof: `Court::Participants` (`max_values`: None, `max_size`: Some(251), added: 2726, mode:
+    /// `MaxEncodedLen`) Storage: `Court::CourtPool` (r:1 w:1)
+    /// Proof: `Court::CourtPool` (`max_values`: Some(1), `max_size`: Some(96002), added: 96497,
+    /// mode: `MaxEncodedLen`) The range of component `j` is `[0, 999]`.
+    fn signed_prepare_exit_court(j: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1020 + j * (96 ±0)`
+        //  Estimated: `97487`
+        // Minimum execution time: 75_170 nanoseconds.
+        Weight::from_parts(83_695_050, 97487)
+            // Standard Error: 292
+            .saturating_add(Weight::from_parts(73_562, 0).saturating_mul(j.into()))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: `Court::UserNonces` (r:1 w:1)
+    /// Proof: `Court::UserNonces` (`max_values`: None, `max_size`: Some(56), added: 2531,
+    /// mode: `MaxEncodedLen`)
+    /// Storage: `Court::Participants` (r:1 w:1)
+    /// Proof: `Court::Participants` (`max_values`: None, `max_size`: Some(251), added: 2726, mode:
+    /// `MaxEncodedLen`) Storage: `Balances::Locks` (r:1 w:1)
+    /// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode:
+    /// `MaxEncodedLen`) Storage: `Balances::Freezes` (r:1 w:0)
+    /// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode:
+    /// `MaxEncodedLen`)
+    fn signed_exit_court() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `276`
+        //  Estimated: `4764`
+        // Minimum execution time: 95_861 nanoseconds.
+        Weight::from_parts(97_190_000, 4764)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: `Court::UserNonces` (r:1 w:1)
+    /// Proof: `Court::UserNonces` (`max_values`: None, `max_size`: Some(56), added: 2531,
+    /// mode: `MaxEncodedLen`)
+    /// Storage: `Court::Courts` (r:1 w:0)
+    /// Proof: `Court::Courts` (`max_values`: None, `max_size`: Some(349), added: 2824, mode:
+    /// `MaxEncodedLen`) Storage: `Court::SelectedDraws` (r:1 w:1)
+    /// Proof: `Court::SelectedDraws` (`max_values`: None, `max_size`: Some(149974), added: 152449,
+    /// mode: `MaxEncodedLen`) The range of component `d` is `[1, 510]`.
+    fn signed_vote(d: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `387 + d * (53 ±0)`
+        //  Estimated: `153439`
+        // Minimum execution time: 86_871 nanoseconds.
+        Weight::from_parts(89_428_708, 153439)
+            // Standard Error: 381
+            .saturating_add(Weight::from_parts(103_937, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `Court::UserNonces` (r:1 w:1)
+    /// Proof: `Court::UserNonces` (`max_values`: None, `max_size`: Some(56), added: 2531,
+    /// mode: `MaxEncodedLen`)
+    /// Storage: `Court::CourtIdToMarketId` (r:1 w:0)
+    /// Proof: `Court::CourtIdToMarketId` (`max_values`: None, `max_size`: Some(40), added: 2515,
+    /// mode: `MaxEncodedLen`) Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
+    /// mode: `MaxEncodedLen`) Storage: `Court::Participants` (r:1 w:0)
+    /// Proof: `Court::Participants` (`max_values`: None, `max_size`: Some(251), added: 2726, mode:
+    /// `MaxEncodedLen`) Storage: `Court::Courts` (r:1 w:0)
+    /// Proof: `Court::Courts` (`max_values`: None, `max_size`: Some(349), added: 2824, mode:
+    /// `MaxEncodedLen`) Storage: `Court::SelectedDraws` (r:1 w:1)
+    /// Proof: `Court::SelectedDraws` (`max_values`: None, `max_size`: Some(149974), added: 152449,
+    /// mode: `MaxEncodedLen`) The range of component `d` is `[1, 510]`.
+    fn signed_reveal_vote(d: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `2034 + d * (53 ±0)`
+        //  Estimated: `153439`
+        // Minimum execution time: 113_301 nanoseconds.
+        Weight::from_parts(115_924_015, 153439)
+            // Standard Error: 321
+            .saturating_add(Weight::from_parts(103_527, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `Court::UserNonces` (r:1 w:1)
+    /// Proof: `Court::UserNonces` (`max_values`: None, `max_size`: Some(56), added: 2531,
+    /// mode: `MaxEncodedLen`)
+    /// Storage: `Court::Courts` (r:1 w:1)
+    /// Proof: `Court::Courts` (`max_values`: None, `max_size`: Some(349), added: 2824, mode:
+    /// `MaxEncodedLen`) Storage: `Court::CourtIdToMarketId` (r:1 w:0)
+    /// Proof: `Court::CourtIdToMarketId` (`max_values`: None, `max_size`: Some(40), added: 2515,
+    /// mode: `MaxEncodedLen`) Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
+    /// mode: `MaxEncodedLen`) Storage: `Court::SelectedDraws` (r:1 w:1)
+    /// Proof: `Court::SelectedDraws` (`max_values`: None, `max_size`: Some(149974), added: 152449,
+    /// mode: `MaxEncodedLen`) Storage: `Court::CourtPool` (r:1 w:1)
+    /// Proof: `Court::CourtPool` (`max_values`: Some(1), `max_size`: Some(96002), added: 96497,
+    /// mode: `MaxEncodedLen`) Storage: `Court::SelectionNonce` (r:1 w:1)
+    /// Proof: `Court::SelectionNonce` (`max_values`: Some(1), `max_size`: Some(8), added: 503,
+    /// mode: `MaxEncodedLen`) Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+    /// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`:
+    /// Some(2594), added: 3089, mode: `MaxEncodedLen`) Storage: `Court::Participants` (r:347
+    /// w:343) Proof: `Court::Participants` (`max_values`: None, `max_size`: Some(251), added:
+    /// 2726, mode: `MaxEncodedLen`) Storage: `Court::RequestBlock` (r:1 w:0)
+    /// Proof: `Court::RequestBlock` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode:
+    /// `MaxEncodedLen`) Storage: `PredictionMarkets::MarketIdsPerDisputeBlock` (r:2 w:2)
+    /// Proof: `PredictionMarkets::MarketIdsPerDisputeBlock` (`max_values`: None, `max_size`:
+    /// Some(1042), added: 3517, mode: `MaxEncodedLen`) Storage: `Balances::Reserves` (r:1 w:1)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode:
+    /// `MaxEncodedLen`) Storage: `Court::Reputations` (r:1000 w:343)
+    /// Proof: `Court::Reputations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode:
+    /// `MaxEncodedLen`) The range of component `j` is `[255, 1000]`.
+    /// The range of component `a` is `[0, 2]`.
+    /// The range of component `r` is `[0, 62]`.
+    /// The range of component `f` is `[0, 62]`.
+    fn signed_appeal(j: u32, a: u32, _r: u32, _f: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0 + a * (24688 ±0) + f * (16 ±0) + j * (149 ±0) + r * (16 ±0)`
+        //  Estimated: `268138 + a * (318078 ±1_049) + j * (194 ±3)`
+        // Minimum execution time: 3_384_141 nanoseconds.
+        Weight::from_parts(3_494_003_000, 268138)
+            // Standard Error: 62_139
+            .saturating_add(Weight::from_parts(5_788_506, 0).saturating_mul(j.into()))
+            // Standard Error: 21_635_039
+            .saturating_add(Weight::from_parts(3_898_041_505, 0).saturating_mul(a.into()))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(j.into())))
+            .saturating_add(T::DbWeight::get().reads((127_u64).saturating_mul(a.into())))
+            .saturating_add(T::DbWeight::get().writes(101))
+            .saturating_add(T::DbWeight::get().writes((234_u64).saturating_mul(a.into())))
+            .saturating_add(Weight::from_parts(0, 318078).saturating_mul(a.into()))
+            .saturating_add(Weight::from_parts(0, 2539).saturating_mul(j.into()))
+    }
+    /// Not benchmarked yet. Estimated as the participant selection part of `appeal` plus the reads
+    /// of the pending draw, the court and the randomness source. Storage: `Court::PendingDraws`
+    /// (r:1 w:1) Storage: `Court::Courts` (r:1 w:1) Storage: `Court::CourtPool` (r:1 w:1) Storage:
+    /// `Court::SelectionNonce` (r:1 w:1) Storage: `RandomnessBeacon::Output` (r:1 w:0) Storage:
+    /// `Court::Participants` (r:347 w:343) Storage: `Court::RequestBlock` (r:1 w:0) Storage:
+    /// `Court::CourtIdToMarketId` (r:1 w:0) Storage: `PredictionMarkets::MarketIdsPerDisputeBlock`
+    /// (r:2 w:2) Storage: `Court::Reputations` (r:1000 w:0) Storage: `Court::SelectedDraws` (r:0
+    /// w:1) The range of component `j` is `[255, 1000]`. The range of component `a` is `[0, 3]`.
+    /// The range of component `r` is `[0, 62]`. The range of component `f` is `[0, 62]`.
+    fn draw_participants(j: u32, a: u32, _r: u32, _f: u32) -> Weight {
+        Weight::from_parts(3_385_790_000, 97487)
+            .saturating_add(Weight::from_parts(5_788_506, 0).saturating_mul(j.into()))
+            .saturating_add(Weight::from_parts(3_898_041_505, 0).saturating_mul(a.into()))
+            .saturating_add(T::DbWeight::get().reads(9))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(j.into())))
+            .saturating_add(T::DbWeight::get().reads((127_u64).saturating_mul(a.into())))
+            .saturating_add(T::DbWeight::get().writes(100))
+            .saturating_add(T::DbWeight::get().writes((117_u64).saturating_mul(a.into())))
+            .saturating_add(Weight::from_parts(0, 318078).saturating_mul(a.into()))
+            .saturating_add(Weight::from_parts(0, 2539).saturating_mul(j.into()))
+    }
 }
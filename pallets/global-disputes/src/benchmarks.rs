@@ -94,14 +94,12 @@ benchmarks! {
         )
         .unwrap();
 
-        let mut vote_locks: BoundedVec<(
-            MarketIdOf<T>,
-            BalanceOf<T>
-        ), T::MaxGlobalDisputeVotes> = Default::default();
+        let mut vote_locks: LockInfoOf<T> = Default::default();
         for i in 0..v {
             let market_id: MarketIdOf<T> = i.saturated_into();
             let locked_balance: BalanceOf<T> = T::MinOutcomeVoteAmount::get().saturated_into();
-            vote_locks.try_push((market_id, locked_balance)).unwrap();
+            let vote_lock = VoteLock { market_id, amount: locked_balance, unlock_at: None };
+            vote_locks.try_push(vote_lock).unwrap();
         }
         <Locks<T>>::insert(caller.clone(), vote_locks);
 
@@ -161,12 +159,12 @@ benchmarks! {
         let caller: T::AccountId = whitelisted_caller();
         let voter: T::AccountId = account("voter", 0, 0);
         let voter_lookup = T::Lookup::unlookup(voter.clone());
-        let mut vote_locks: BoundedVec<(MarketIdOf<T>, BalanceOf<T>), T::MaxGlobalDisputeVotes> =
-            Default::default();
+        let mut vote_locks: LockInfoOf<T> = Default::default();
         for i in 0..l {
             let market_id: MarketIdOf<T> = i.saturated_into();
             let locked_balance: BalanceOf<T> = i.saturated_into();
-            vote_locks.try_push((market_id, locked_balance)).unwrap();
+            let vote_lock = VoteLock { market_id, amount: locked_balance, unlock_at: None };
+            vote_locks.try_push(vote_lock).unwrap();
             <GlobalDisputesInfo<T>>::insert(market_id, gd_info.clone());
         }
         <Locks<T>>::insert(voter.clone(), vote_locks.clone());
@@ -202,14 +200,12 @@ benchmarks! {
         let caller: T::AccountId = whitelisted_caller();
         let voter: T::AccountId = account("voter", 0, 0);
         let voter_lookup = T::Lookup::unlookup(voter.clone());
-        let mut vote_locks: BoundedVec<(
-            MarketIdOf<T>,
-            BalanceOf<T>
-        ), T::MaxGlobalDisputeVotes> = Default::default();
+        let mut vote_locks: LockInfoOf<T> = Default::default();
         for i in 0..l {
             let market_id: MarketIdOf<T> = i.saturated_into();
             let locked_balance: BalanceOf<T> = 1u128.saturated_into();
-            vote_locks.try_push((market_id, locked_balance)).unwrap();
+            let vote_lock = VoteLock { market_id, amount: locked_balance, unlock_at: None };
+            vote_locks.try_push(vote_lock).unwrap();
             <GlobalDisputesInfo<T>>::insert(market_id, gd_info.clone());
         }
         <Locks<T>>::insert(voter.clone(), vote_locks);
@@ -503,6 +499,118 @@ benchmarks! {
         assert_last_event::<T>(Event::OutcomesFullyCleaned::<T> { market_id }.into());
     }
 
+    delegate_gd_votes {
+        // the worst case evicts the smallest delegation of a full list
+        let d in 0..T::MaxDelegators::get();
+
+        let amount: BalanceOf<T> = T::MinOutcomeVoteAmount::get();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+        for i in 0..d {
+            let delegator: T::AccountId = account("delegator", i, 0);
+            deposit::<T>(&delegator);
+            GlobalDisputes::<T>::delegate_gd_votes(
+                RawOrigin::Signed(delegator).into(),
+                T::Lookup::unlookup(delegate.clone()),
+                amount,
+                DelegationScope::All,
+            )
+            .unwrap();
+        }
+
+        let caller: T::AccountId = whitelisted_caller();
+        deposit::<T>(&caller);
+        // the worst case replaces an existing delegation
+        let previous_delegate: T::AccountId = account("previous_delegate", 0, 0);
+        GlobalDisputes::<T>::delegate_gd_votes(
+            RawOrigin::Signed(caller.clone()).into(),
+            T::Lookup::unlookup(previous_delegate),
+            amount,
+            DelegationScope::All,
+        )
+        .unwrap();
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        T::Lookup::unlookup(delegate.clone()),
+        amount.saturating_add(1u8.into()),
+        DelegationScope::All
+    )
+    verify {
+        assert_eq!(<Delegations<T>>::get(&caller).unwrap().delegate, delegate);
+    }
+
+    undelegate {
+        let d in 1..T::MaxDelegators::get();
+
+        let amount: BalanceOf<T> = T::MinOutcomeVoteAmount::get();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+        for i in 1..d {
+            let delegator: T::AccountId = account("delegator", i, 0);
+            deposit::<T>(&delegator);
+            GlobalDisputes::<T>::delegate_gd_votes(
+                RawOrigin::Signed(delegator).into(),
+                T::Lookup::unlookup(delegate.clone()),
+                amount,
+                DelegationScope::All,
+            )
+            .unwrap();
+        }
+
+        let caller: T::AccountId = whitelisted_caller();
+        deposit::<T>(&caller);
+        GlobalDisputes::<T>::delegate_gd_votes(
+            RawOrigin::Signed(caller.clone()).into(),
+            T::Lookup::unlookup(delegate.clone()),
+            amount,
+            DelegationScope::All,
+        )
+        .unwrap();
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(<Delegations<T>>::get(&caller).is_none());
+        assert!(!<Delegators<T>>::get(&delegate).contains(&caller));
+    }
+
+    count_delegated_votes {
+        let d in 0..T::MaxDelegators::get();
+
+        let market_id: MarketIdOf<T> = 0u128.saturated_into();
+        let market = market_mock::<T>();
+        T::MarketCommons::push_market(market).unwrap();
+
+        let outcome = OutcomeReport::Scalar(0);
+        let delegate: T::AccountId = account("delegate", 0, 0);
+        let initial_items = vec![InitialItem {
+            outcome: outcome.clone(),
+            owner: delegate.clone(),
+            amount: 1_000_000_000u128.saturated_into(),
+        }];
+        GlobalDisputes::<T>::start_global_dispute(
+            &market_id,
+            initial_items.as_slice(),
+        )
+        .unwrap();
+
+        let amount: BalanceOf<T> = T::MinOutcomeVoteAmount::get();
+        for i in 0..d {
+            let delegator: T::AccountId = account("delegator", i, 0);
+            deposit::<T>(&delegator);
+            GlobalDisputes::<T>::delegate_gd_votes(
+                RawOrigin::Signed(delegator).into(),
+                T::Lookup::unlookup(delegate.clone()),
+                amount,
+                DelegationScope::All,
+            )
+            .unwrap();
+        }
+    }: {
+        GlobalDisputes::<T>::count_delegated_votes(&delegate, market_id, &outcome).unwrap();
+    } verify {
+        for i in 0..d {
+            let delegator: T::AccountId = account("delegator", i, 0);
+            assert_eq!(<Locks<T>>::get(&delegator).len(), 1);
+        }
+    }
+
     impl_benchmark_test_suite!(
         GlobalDisputes,
         crate::mock::ExtBuilder::default().build(),
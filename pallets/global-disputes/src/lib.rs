@@ -56,7 +56,10 @@ mod pallet {
         pallet_prelude::{BlockNumberFor, OriginFor},
     };
     use pallet_pm_market_commons::MarketCommonsPalletApi;
-    use prediction_market_primitives::{traits::DisputeResolutionApi, types::OutcomeReport};
+    use prediction_market_primitives::{
+        traits::DisputeResolutionApi,
+        types::{Asset, OutcomeReport},
+    };
     use sp_runtime::{
         traits::{AccountIdConversion, CheckedDiv, Saturating, Zero},
         DispatchError, DispatchResult,
@@ -76,11 +79,14 @@ mod pallet {
         GlobalDisputeInfo<AccountIdOf<T>, BalanceOf<T>, OwnerInfoOf<T>, BlockNumberFor<T>>;
 
     type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
-    pub type LockInfoOf<T> =
-        BoundedVec<(MarketIdOf<T>, BalanceOf<T>), <T as Config>::MaxGlobalDisputeVotes>;
+    pub type VoteLockOf<T> = VoteLock<MarketIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+    pub type LockInfoOf<T> = BoundedVec<VoteLockOf<T>, <T as Config>::MaxGlobalDisputeVotes>;
+    pub type DelegationScopeOf<T> = DelegationScope<AccountIdOf<T>, Asset<MarketIdOf<T>>>;
+    pub type DelegationOf<T> = Delegation<AccountIdOf<T>, BalanceOf<T>, Asset<MarketIdOf<T>>>;
+    pub(crate) type DelegatorsOf<T> = BoundedVec<AccountIdOf<T>, <T as Config>::MaxDelegators>;
 
     /// The current storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
     const LOG_TARGET: &str = "runtime::pallet-pm-global-disputes";
 
     #[pallet::config]
@@ -89,6 +95,11 @@ mod pallet {
         #[pallet::constant]
         type AddOutcomePeriod: Get<BlockNumberFor<Self>>;
 
+        /// The number of blocks a vote stays locked after the end of the voting period per lock
+        /// period of its conviction.
+        #[pallet::constant]
+        type ConvictionLockPeriod: Get<BlockNumberFor<Self>>;
+
         /// The currency implementation used to lock tokens for voting.
         type Currency: LockableCurrency<Self::AccountId, Moment = BlockNumberFor<Self>>;
 
@@ -116,6 +127,10 @@ mod pallet {
             BlockNumber = BlockNumberFor<Self>,
         >;
 
+        /// The maximum number of accounts which can delegate their votes to one account.
+        #[pallet::constant]
+        type MaxDelegators: Get<u32>;
+
         /// The maximum numbers of distinct markets
         /// on which one account can simultaneously vote on outcomes.
         /// When the user unlocks, the user has again `MaxGlobalDisputeVotes` number of votes.
@@ -144,6 +159,10 @@ mod pallet {
         #[pallet::constant]
         type VotingOutcomeFee: Get<BalanceOf<Self>>;
 
+        /// The way the locked amounts are weighted when summing up the votes on an outcome.
+        #[pallet::constant]
+        type VotingMode: Get<VotingMode>;
+
         type WeightInfo: WeightInfoZeitgeist;
     }
 
@@ -151,7 +170,7 @@ mod pallet {
     #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(PhantomData<T>);
 
-    /// All highest lock information (vote id, outcome index and locked balance)
+    /// All lock information (market id, locked balance and unlock block)
     /// for a particular voter.
     ///
     /// TWOX-NOTE: SAFE as `AccountId`s are crypto hashes anyway.
@@ -159,6 +178,20 @@ mod pallet {
     pub type Locks<T: Config> =
         StorageMap<_, Twox64Concat, T::AccountId, LockInfoOf<T>, ValueQuery>;
 
+    /// The delegation of the votes of a delegator.
+    ///
+    /// TWOX-NOTE: SAFE as `AccountId`s are crypto hashes anyway.
+    #[pallet::storage]
+    pub type Delegations<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, DelegationOf<T>, OptionQuery>;
+
+    /// The accounts which delegated their votes to a delegate.
+    ///
+    /// TWOX-NOTE: SAFE as `AccountId`s are crypto hashes anyway.
+    #[pallet::storage]
+    pub type Delegators<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, DelegatorsOf<T>, ValueQuery>;
+
     /// Maps the market id to the outcome and providing information about the outcome.
     #[pallet::storage]
     pub type Outcomes<T: Config> = StorageDoubleMap<
@@ -206,6 +239,26 @@ mod pallet {
             outcome: OutcomeReport,
             vote_amount: BalanceOf<T>,
         },
+        /// An account delegated its votes to another account.
+        VotesDelegated {
+            delegator: AccountIdOf<T>,
+            delegate: AccountIdOf<T>,
+            amount: BalanceOf<T>,
+            scope: DelegationScopeOf<T>,
+        },
+        /// An account revoked the delegation of its votes.
+        VotesUndelegated { delegator: AccountIdOf<T>, delegate: AccountIdOf<T> },
+        /// The smallest delegation of a delegate with the maximum number of delegators was
+        /// removed to make room for a larger one.
+        DelegatorEvicted { delegator: AccountIdOf<T>, delegate: AccountIdOf<T> },
+        /// The votes delegated to a delegate were counted towards the outcome the delegate voted
+        /// on.
+        DelegatedVotesCounted {
+            market_id: MarketIdOf<T>,
+            delegate: AccountIdOf<T>,
+            outcome: OutcomeReport,
+            amount: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -244,6 +297,15 @@ mod pallet {
         GlobalDisputeNotDestroyed,
         /// The global dispute was already started.
         GlobalDisputeAlreadyExists,
+        /// Votes with a conviction are only allowed in the conviction voting mode.
+        ConvictionNotSupported,
+        /// An account can't delegate its votes to itself.
+        SelfDelegation,
+        /// The maximum number of delegators of the delegate is reached and the amount doesn't
+        /// exceed the smallest delegation.
+        TooManyDelegators,
+        /// The sender hasn't delegated its votes.
+        DelegationNotFound,
     }
 
     #[pallet::call]
@@ -300,7 +362,8 @@ mod pallet {
             )?;
 
             let possession = Possession::Paid { owner: owner.clone(), fee: voting_outcome_fee };
-            let outcome_info = OutcomeInfo { outcome_sum: voting_outcome_fee, possession };
+            let outcome_sum = Self::vote_weight(voting_outcome_fee, Conviction::None);
+            let outcome_info = OutcomeInfo { outcome_sum, possession };
             Self::update_winner(&market_id, &outcome, outcome_info.clone());
             <Outcomes<T>>::insert(market_id, outcome.clone(), outcome_info);
 
@@ -481,6 +544,8 @@ mod pallet {
 
         /// Vote on existing voting outcomes by locking native tokens.
         /// Fails if the global dispute has not started or has already finished.
+        /// On the first vote of the sender on the global dispute, the votes delegated to the
+        /// sender are counted towards the outcome as well.
         ///
         /// # Arguments
         ///
@@ -490,13 +555,14 @@ mod pallet {
         ///
         /// # Weight
         ///
-        /// Complexity: `O(n + m)`, where `n` is the number of all current votes on global disputes,
-        /// and `m` is the number of owners for the specified outcome.
+        /// Complexity: `O(n + m + d)`, where `n` is the number of all current votes on global
+        /// disputes, `m` is the number of owners for the specified outcome and `d` is the number of
+        /// delegators of the sender.
         #[pallet::call_index(3)]
-        #[pallet::weight(T::WeightInfo::vote_on_outcome(
-            T::MaxOwners::get(),
-            T::MaxGlobalDisputeVotes::get(),
-        ))]
+        #[pallet::weight(
+            T::WeightInfo::vote_on_outcome(T::MaxOwners::get(), T::MaxGlobalDisputeVotes::get())
+                .saturating_add(T::WeightInfo::count_delegated_votes(T::MaxDelegators::get()))
+        )]
         #[frame_support::transactional]
         pub fn vote_on_outcome(
             origin: OriginFor<T>,
@@ -505,75 +571,143 @@ mod pallet {
             #[pallet::compact] amount: BalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
             let voter = ensure_signed(origin)?;
-            let voter_free_balance = T::Currency::free_balance(&voter);
-            ensure!(amount <= voter_free_balance, Error::<T>::InsufficientAmount);
-            ensure!(amount >= T::MinOutcomeVoteAmount::get(), Error::<T>::AmountTooLow);
-
-            let gd_info =
-                <GlobalDisputesInfo<T>>::get(market_id).ok_or(Error::<T>::GlobalDisputeNotFound)?;
-            let now = <frame_system::Pallet<T>>::block_number();
-            if let GdStatus::Active { add_outcome_end, vote_end } = gd_info.status {
-                ensure!(add_outcome_end < now && now <= vote_end, Error::<T>::NotInGdVotingPeriod);
-            } else {
-                return Err(Error::<T>::InvalidGlobalDisputeStatus.into());
-            }
-
-            let mut outcome_info =
-                <Outcomes<T>>::get(market_id, &outcome).ok_or(Error::<T>::OutcomeDoesNotExist)?;
-            let outcome_owners_len = match outcome_info.possession {
-                Possession::Shared { ref owners } => owners.len() as u32,
-                Possession::Paid { .. } => 1u32,
-            };
-
-            // The `outcome_sum` never decreases (only increases) to allow
-            // caching the outcome with the highest `outcome_sum`.
-            // If the `outcome_sum` decreases, it would lead to more storage,
-            // because the winning outcome could have a smaller `outcome_sum`
-            // than the second highest `outcome_sum`.
-            let add_to_outcome_sum = |a| {
-                outcome_info.outcome_sum = outcome_info.outcome_sum.saturating_add(a);
-                Self::update_winner(&market_id, &outcome, outcome_info.clone());
-                <Outcomes<T>>::insert(market_id, &outcome, outcome_info);
-            };
+            Self::do_vote_on_outcome(voter, market_id, outcome, amount, Conviction::None)
+        }
 
-            let mut lock_info = <Locks<T>>::get(&voter);
+        /// Vote on existing voting outcomes by locking native tokens with a conviction.
+        /// The higher the conviction, the longer the tokens stay locked after the end of the
+        /// voting period and the higher the weight of the vote.
+        /// Fails if the global dispute has not started or has already finished or if the voting
+        /// mode isn't `VotingMode::Conviction` and `conviction` isn't `Conviction::None`.
+        ///
+        /// # Arguments
+        ///
+        /// - `market_id`: The id of the market.
+        /// - `outcome`: The existing outcome report to vote on.
+        /// - `amount`: The amount to vote with.
+        /// - `conviction`: The conviction to vote with.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n + m + d)`, where `n` is the number of all current votes on global
+        /// disputes, `m` is the number of owners for the specified outcome and `d` is the number of
+        /// delegators of the sender.
+        #[pallet::call_index(6)]
+        #[pallet::weight(
+            T::WeightInfo::vote_on_outcome(T::MaxOwners::get(), T::MaxGlobalDisputeVotes::get())
+                .saturating_add(T::WeightInfo::count_delegated_votes(T::MaxDelegators::get()))
+        )]
+        #[frame_support::transactional]
+        pub fn vote_with_conviction(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            outcome: OutcomeReport,
+            #[pallet::compact] amount: BalanceOf<T>,
+            conviction: Conviction,
+        ) -> DispatchResultWithPostInfo {
+            let voter = ensure_signed(origin)?;
+            ensure!(
+                conviction == Conviction::None || T::VotingMode::get() == VotingMode::Conviction,
+                Error::<T>::ConvictionNotSupported
+            );
+            Self::do_vote_on_outcome(voter, market_id, outcome, amount, conviction)
+        }
 
-            let vote_lock_counter = lock_info.len() as u32;
+        /// Delegate votes on global disputes to another account. Whenever the delegate votes on a
+        /// global dispute of a market in `scope` for the first time, `amount` of the sender's
+        /// native tokens are locked and counted towards the outcome the delegate voted on.
+        /// Replaces an existing delegation of the sender. If the delegate has the maximum number
+        /// of delegators, the smallest delegation is removed to make room, provided that `amount`
+        /// exceeds it. Delegations are compared by the amount which their delegators can still
+        /// lock.
+        ///
+        /// # Arguments
+        ///
+        /// - `delegate`: The account to delegate the votes to.
+        /// - `amount`: The amount to vote with on each global dispute.
+        /// - `scope`: The markets on whose global disputes the votes are delegated.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(d)`, where `d` is the number of delegators of the delegate.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::delegate_gd_votes(T::MaxDelegators::get()))]
+        #[frame_support::transactional]
+        pub fn delegate_gd_votes(
+            origin: OriginFor<T>,
+            delegate: AccountIdLookupOf<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+            scope: DelegationScopeOf<T>,
+        ) -> DispatchResult {
+            let delegator = ensure_signed(origin)?;
+            let delegate = T::Lookup::lookup(delegate)?;
+            ensure!(delegator != delegate, Error::<T>::SelfDelegation);
+            ensure!(amount >= T::MinOutcomeVoteAmount::get(), Error::<T>::AmountTooLow);
+            ensure!(
+                amount <= T::Currency::free_balance(&delegator),
+                Error::<T>::InsufficientAmount
+            );
 
-            let lock_amount = match lock_info.binary_search_by_key(&market_id, |i| i.0) {
-                Ok(i) => {
-                    let prev_amount_acc: BalanceOf<T> = lock_info[i].1;
-                    let new_amount_acc = amount.saturating_add(prev_amount_acc);
-                    ensure!(new_amount_acc <= voter_free_balance, Error::<T>::InsufficientAmount);
-                    add_to_outcome_sum(amount);
-                    lock_info[i].1 = new_amount_acc;
-                    new_amount_acc
-                },
-                Err(i) => {
-                    lock_info
-                        .try_insert(i, (market_id, amount))
-                        .map_err(|_| Error::<T>::MaxVotesReached)?;
-                    add_to_outcome_sum(amount);
-                    amount
+            if let Some(delegation) = <Delegations<T>>::get(&delegator) {
+                Self::remove_delegator(&delegation.delegate, &delegator);
+            }
+            let evicted = <Delegators<T>>::try_mutate(
+                &delegate,
+                |delegators| -> Result<Option<AccountIdOf<T>>, DispatchError> {
+                    if delegators.try_push(delegator.clone()).is_ok() {
+                        return Ok(None);
+                    }
+                    let (index, smallest_amount) = delegators
+                        .iter()
+                        .map(Self::lockable_delegation_amount)
+                        .enumerate()
+                        .min_by_key(|(_, lockable)| *lockable)
+                        .ok_or(Error::<T>::TooManyDelegators)?;
+                    ensure!(amount > smallest_amount, Error::<T>::TooManyDelegators);
+                    let evicted = delegators.remove(index);
+                    delegators
+                        .try_push(delegator.clone())
+                        .map_err(|_| Error::<T>::TooManyDelegators)?;
+                    Ok(Some(evicted))
                 },
-            };
+            )?;
+            if let Some(evicted) = evicted {
+                <Delegations<T>>::remove(&evicted);
+                Self::deposit_event(Event::DelegatorEvicted {
+                    delegator: evicted,
+                    delegate: delegate.clone(),
+                });
+            }
+            let delegation =
+                Delegation { delegate: delegate.clone(), amount, scope: scope.clone() };
+            <Delegations<T>>::insert(&delegator, delegation);
 
-            T::Currency::extend_lock(
-                T::GlobalDisputeLockId::get(),
-                &voter,
-                lock_amount,
-                WithdrawReasons::TRANSFER,
-            );
+            Self::deposit_event(Event::VotesDelegated { delegator, delegate, amount, scope });
 
-            <Locks<T>>::insert(&voter, lock_info);
+            Ok(())
+        }
 
-            Self::deposit_event(Event::VotedOnOutcome {
-                market_id,
-                voter,
-                outcome,
-                vote_amount: amount,
+        /// Revoke the delegation of the sender's votes on global disputes. Delegated votes which
+        /// were already counted stay locked until the global dispute is concluded.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(d)`, where `d` is the number of delegators of the delegate.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::undelegate(T::MaxDelegators::get()))]
+        #[frame_support::transactional]
+        pub fn undelegate(origin: OriginFor<T>) -> DispatchResult {
+            let delegator = ensure_signed(origin)?;
+            let delegation =
+                <Delegations<T>>::take(&delegator).ok_or(Error::<T>::DelegationNotFound)?;
+            Self::remove_delegator(&delegation.delegate, &delegator);
+
+            Self::deposit_event(Event::VotesUndelegated {
+                delegator,
+                delegate: delegation.delegate,
             });
-            Ok(Some(T::WeightInfo::vote_on_outcome(outcome_owners_len, vote_lock_counter)).into())
+
+            Ok(())
         }
 
         /// Return all locked native tokens from a finished or destroyed global dispute.
@@ -609,17 +743,26 @@ mod pallet {
             let mut lock_needed: BalanceOf<T> = Zero::zero();
             let mut lock_info = <Locks<T>>::get(&voter);
             let vote_lock_counter = lock_info.len() as u32;
+            let now = <frame_system::Pallet<T>>::block_number();
             // Inside retain we follow these rules:
-            // 1. Remove all locks from resolved (/ finished / destroyed) global disputes.
-            // 2. Then find the maximum lock from all unresolved global disputes.
-            lock_info.retain(|&(market_id, locked_balance)| {
+            // 1. Remove all locks from destroyed global disputes and from finished global
+            //    disputes whose unlock block is reached.
+            // 2. Then find the maximum lock from all remaining global disputes.
+            lock_info.retain(|vote_lock| {
                 // weight component MaxOwners comes from querying the winner information
-                match <GlobalDisputesInfo<T>>::get(market_id) {
+                match <GlobalDisputesInfo<T>>::get(vote_lock.market_id) {
                     Some(gd_info) => {
-                        if matches!(gd_info.status, GdStatus::Finished | GdStatus::Destroyed) {
+                        let unlockable = match gd_info.status {
+                            GdStatus::Active { .. } => false,
+                            GdStatus::Finished => {
+                                vote_lock.unlock_at.map_or(true, |unlock_at| unlock_at <= now)
+                            },
+                            GdStatus::Destroyed => true,
+                        };
+                        if unlockable {
                             false
                         } else {
-                            lock_needed = lock_needed.max(locked_balance);
+                            lock_needed = lock_needed.max(vote_lock.amount);
                             true
                         }
                     },
@@ -627,7 +770,7 @@ mod pallet {
                         log::warn!(
                             target: LOG_TARGET,
                             "Winner info is not found for market with id {:?}.",
-                            market_id
+                            vote_lock.market_id
                         );
                         debug_assert!(false);
                         // unlock these funds
@@ -669,6 +812,212 @@ mod pallet {
             T::GlobalDisputesPalletId::get().into_sub_account_truncating(market_id)
         }
 
+        fn do_vote_on_outcome(
+            voter: AccountIdOf<T>,
+            market_id: MarketIdOf<T>,
+            outcome: OutcomeReport,
+            amount: BalanceOf<T>,
+            conviction: Conviction,
+        ) -> DispatchResultWithPostInfo {
+            let voter_free_balance = T::Currency::free_balance(&voter);
+            ensure!(amount <= voter_free_balance, Error::<T>::InsufficientAmount);
+            ensure!(amount >= T::MinOutcomeVoteAmount::get(), Error::<T>::AmountTooLow);
+
+            let gd_info =
+                <GlobalDisputesInfo<T>>::get(market_id).ok_or(Error::<T>::GlobalDisputeNotFound)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            let vote_end = if let GdStatus::Active { add_outcome_end, vote_end } = gd_info.status {
+                ensure!(add_outcome_end < now && now <= vote_end, Error::<T>::NotInGdVotingPeriod);
+                vote_end
+            } else {
+                return Err(Error::<T>::InvalidGlobalDisputeStatus.into());
+            };
+            let unlock_at = Self::unlock_block(vote_end, conviction);
+
+            let mut outcome_info =
+                <Outcomes<T>>::get(market_id, &outcome).ok_or(Error::<T>::OutcomeDoesNotExist)?;
+            let outcome_owners_len = match outcome_info.possession {
+                Possession::Shared { ref owners } => owners.len() as u32,
+                Possession::Paid { .. } => 1u32,
+            };
+
+            // The `outcome_sum` never decreases (only increases) to allow
+            // caching the outcome with the highest `outcome_sum`.
+            // If the `outcome_sum` decreases, it would lead to more storage,
+            // because the winning outcome could have a smaller `outcome_sum`
+            // than the second highest `outcome_sum`.
+            // The weight is added based on the total amount the voter locked for this global
+            // dispute, so that splitting a vote doesn't increase its quadratic weight.
+            let add_to_outcome_sum = |prev_amount, new_amount| {
+                let weight = Self::vote_weight(new_amount, conviction)
+                    .saturating_sub(Self::vote_weight(prev_amount, conviction));
+                outcome_info.outcome_sum = outcome_info.outcome_sum.saturating_add(weight);
+                Self::update_winner(&market_id, &outcome, outcome_info.clone());
+                <Outcomes<T>>::insert(market_id, &outcome, outcome_info);
+            };
+
+            let mut lock_info = <Locks<T>>::get(&voter);
+
+            let vote_lock_counter = lock_info.len() as u32;
+
+            let (lock_amount, is_first_vote) = match lock_info
+                .binary_search_by_key(&market_id, |i| i.market_id)
+            {
+                Ok(i) => {
+                    let prev_amount_acc: BalanceOf<T> = lock_info[i].amount;
+                    let new_amount_acc = amount.saturating_add(prev_amount_acc);
+                    ensure!(new_amount_acc <= voter_free_balance, Error::<T>::InsufficientAmount);
+                    add_to_outcome_sum(prev_amount_acc, new_amount_acc);
+                    lock_info[i].amount = new_amount_acc;
+                    lock_info[i].unlock_at = lock_info[i].unlock_at.max(unlock_at);
+                    (new_amount_acc, false)
+                },
+                Err(i) => {
+                    let vote_lock = VoteLock { market_id, amount, unlock_at };
+                    lock_info.try_insert(i, vote_lock).map_err(|_| Error::<T>::MaxVotesReached)?;
+                    add_to_outcome_sum(Zero::zero(), amount);
+                    (amount, true)
+                },
+            };
+
+            T::Currency::extend_lock(
+                T::GlobalDisputeLockId::get(),
+                &voter,
+                lock_amount,
+                WithdrawReasons::TRANSFER,
+            );
+
+            <Locks<T>>::insert(&voter, lock_info);
+
+            let delegators_len = if is_first_vote {
+                Self::count_delegated_votes(&voter, market_id, &outcome)?
+            } else {
+                0u32
+            };
+
+            Self::deposit_event(Event::VotedOnOutcome {
+                market_id,
+                voter,
+                outcome,
+                vote_amount: amount,
+            });
+            Ok(Some(
+                T::WeightInfo::vote_on_outcome(outcome_owners_len, vote_lock_counter)
+                    .saturating_add(T::WeightInfo::count_delegated_votes(delegators_len)),
+            )
+            .into())
+        }
+
+        /// Locks the delegated amount of each delegator of `delegate` whose delegation covers the
+        /// market and counts it towards `outcome`. Delegators who already voted on the global
+        /// dispute themselves are skipped. Returns the number of delegators of `delegate`.
+        pub(crate) fn count_delegated_votes(
+            delegate: &AccountIdOf<T>,
+            market_id: MarketIdOf<T>,
+            outcome: &OutcomeReport,
+        ) -> Result<u32, DispatchError> {
+            let delegators = <Delegators<T>>::get(delegate);
+            if delegators.is_empty() {
+                return Ok(0u32);
+            }
+            let market = T::MarketCommons::market(&market_id)?;
+            let mut outcome_info =
+                <Outcomes<T>>::get(market_id, outcome).ok_or(Error::<T>::OutcomeDoesNotExist)?;
+
+            let mut delegated_amount: BalanceOf<T> = Zero::zero();
+            for delegator in delegators.iter() {
+                let delegation = match <Delegations<T>>::get(delegator) {
+                    Some(delegation) => delegation,
+                    None => {
+                        log::warn!(
+                            target: LOG_TARGET,
+                            "Delegation of delegator {:?} is not found.",
+                            delegator
+                        );
+                        debug_assert!(false);
+                        continue;
+                    },
+                };
+                if !delegation.scope.contains(&market.creator, &market.base_asset) {
+                    continue;
+                }
+                let amount = delegation.amount.min(T::Currency::free_balance(delegator));
+                if amount < T::MinOutcomeVoteAmount::get() {
+                    continue;
+                }
+                let mut lock_info = <Locks<T>>::get(delegator);
+                let i = match lock_info.binary_search_by_key(&market_id, |i| i.market_id) {
+                    Ok(_) => continue,
+                    Err(i) => i,
+                };
+                let vote_lock = VoteLock { market_id, amount, unlock_at: None };
+                if lock_info.try_insert(i, vote_lock).is_err() {
+                    continue;
+                }
+                T::Currency::extend_lock(
+                    T::GlobalDisputeLockId::get(),
+                    delegator,
+                    amount,
+                    WithdrawReasons::TRANSFER,
+                );
+                <Locks<T>>::insert(delegator, lock_info);
+
+                let weight = Self::vote_weight(amount, Conviction::None);
+                outcome_info.outcome_sum = outcome_info.outcome_sum.saturating_add(weight);
+                delegated_amount = delegated_amount.saturating_add(amount);
+            }
+
+            if !delegated_amount.is_zero() {
+                Self::update_winner(&market_id, outcome, outcome_info.clone());
+                <Outcomes<T>>::insert(market_id, outcome, outcome_info);
+                Self::deposit_event(Event::DelegatedVotesCounted {
+                    market_id,
+                    delegate: delegate.clone(),
+                    outcome: outcome.clone(),
+                    amount: delegated_amount,
+                });
+            }
+
+            Ok(delegators.len() as u32)
+        }
+
+        /// The amount which the delegation of `delegator` can still lock.
+        fn lockable_delegation_amount(delegator: &AccountIdOf<T>) -> BalanceOf<T> {
+            <Delegations<T>>::get(delegator)
+                .map(|delegation| delegation.amount.min(T::Currency::free_balance(delegator)))
+                .unwrap_or_else(Zero::zero)
+        }
+
+        fn remove_delegator(delegate: &AccountIdOf<T>, delegator: &AccountIdOf<T>) {
+            <Delegators<T>>::mutate_exists(delegate, |delegators| {
+                if let Some(list) = delegators {
+                    list.retain(|d| d != delegator);
+                    if list.is_empty() {
+                        *delegators = None;
+                    }
+                }
+            });
+        }
+
+        /// The weight of `amount` locked with `conviction` in the configured voting mode.
+        pub(crate) fn vote_weight(amount: BalanceOf<T>, conviction: Conviction) -> BalanceOf<T> {
+            T::VotingMode::get().weigh(amount, conviction)
+        }
+
+        /// The block from which on a vote with `conviction` on a global dispute which ends at
+        /// `vote_end` can be unlocked. `None` if the vote isn't locked beyond the global dispute.
+        fn unlock_block(
+            vote_end: BlockNumberFor<T>,
+            conviction: Conviction,
+        ) -> Option<BlockNumberFor<T>> {
+            let lock_periods = conviction.lock_periods();
+            if lock_periods.is_zero() {
+                return None;
+            }
+            let lock_duration = T::ConvictionLockPeriod::get().saturating_mul(lock_periods.into());
+            Some(vote_end.saturating_add(lock_duration))
+        }
+
         fn update_winner(
             market_id: &MarketIdOf<T>,
             outcome: &OutcomeReport,
@@ -812,7 +1161,8 @@ mod pallet {
 
                 match <Outcomes<T>>::get(market_id, outcome) {
                     Some(mut outcome_info) => {
-                        let outcome_sum = outcome_info.outcome_sum.saturating_add(*amount);
+                        let weight = Self::vote_weight(*amount, Conviction::None);
+                        let outcome_sum = outcome_info.outcome_sum.saturating_add(weight);
                         outcome_info.outcome_sum = outcome_sum;
                         let mut owners = outcome_info
                             .possession
@@ -827,7 +1177,8 @@ mod pallet {
                         // adding one item to BoundedVec can not fail
                         if let Ok(owners) = BoundedVec::try_from(vec![owner.clone()]) {
                             let possession = Possession::Shared { owners };
-                            let outcome_info = OutcomeInfo { outcome_sum: *amount, possession };
+                            let outcome_sum = Self::vote_weight(*amount, Conviction::None);
+                            let outcome_info = OutcomeInfo { outcome_sum, possession };
                             Self::update_winner(market_id, outcome, outcome_info.clone());
                             <Outcomes<T>>::insert(market_id, outcome, outcome_info);
                         } else {
@@ -15,3 +15,80 @@
 //
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{types::VoteLock, *};
+use frame_support::{
+    pallet_prelude::BoundedVec,
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+#[cfg(feature = "try-runtime")]
+use {
+    frame_support::ensure,
+    parity_scale_codec::{Decode, Encode},
+    sp_runtime::TryRuntimeError,
+};
+
+const LOG_TARGET: &str = "runtime::pallet-pm-global-disputes";
+
+/// The layout of the vote locks of an account before the unlock block was added.
+type OldLockInfoOf<T> =
+    BoundedVec<(MarketIdOf<T>, BalanceOf<T>), <T as Config>::MaxGlobalDisputeVotes>;
+
+/// Turns the `(market_id, amount)` tuples of `Locks` into `VoteLock`s without an unlock block,
+/// so that they're unlocked as soon as their global dispute is concluded, like before.
+pub struct MigrateVoteLocks<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateVoteLocks<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let onchain = Pallet::<T>::on_chain_storage_version();
+        if onchain != 1 {
+            log::info!(
+                target: LOG_TARGET,
+                "Skipping MigrateVoteLocks, on-chain storage version is {:?}",
+                onchain
+            );
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut voter_count: u64 = 0;
+        <Locks<T>>::translate_values::<OldLockInfoOf<T>, _>(|old_lock_info| {
+            voter_count = voter_count.saturating_add(1);
+            let vote_locks = old_lock_info
+                .into_iter()
+                .map(|(market_id, amount)| VoteLock { market_id, amount, unlock_at: None })
+                .collect::<Vec<_>>();
+            // The number of entries doesn't change, so this can't fail.
+            Some(BoundedVec::truncate_from(vote_locks))
+        });
+
+        StorageVersion::new(2).put::<Pallet<T>>();
+        log::info!(target: LOG_TARGET, "Migrated the vote locks of {} voters", voter_count);
+
+        T::DbWeight::get()
+            .reads_writes(voter_count.saturating_add(1), voter_count.saturating_add(1))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let voter_count = <Locks<T>>::iter_keys().count() as u64;
+        Ok(voter_count.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(input: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let old_voter_count: u64 =
+            Decode::decode(&mut &input[..]).map_err(|_| "failed to decode the voter count")?;
+        let voter_count = <Locks<T>>::iter().count() as u64;
+        ensure!(voter_count == old_voter_count, "vote locks failed to decode after the migration");
+        ensure!(
+            <Locks<T>>::iter_values()
+                .flatten()
+                .all(|vote_lock| vote_lock.unlock_at.is_none()),
+            "migrated vote lock has an unlock block"
+        );
+        ensure!(Pallet::<T>::on_chain_storage_version() == 2, "storage version was not updated");
+        Ok(())
+    }
+}
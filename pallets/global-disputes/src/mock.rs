@@ -17,7 +17,7 @@
 
 #![cfg(test)]
 
-use crate::{self as pallet_pm_global_disputes};
+use crate::{self as pallet_pm_global_disputes, types::VotingMode};
 use common_primitives::types::{Balance, BlockNumber, Hash, Moment};
 use frame_support::{
     construct_runtime,
@@ -28,9 +28,9 @@ use frame_support::{
 use frame_system::mocking::MockBlockU32;
 use prediction_market_primitives::{
     constants::mock::{
-        AddOutcomePeriod, BlockHashCount, GdVotingPeriod, GlobalDisputeLockId,
-        GlobalDisputesPalletId, MaxLocks, MaxReserves, MinOutcomeVoteAmount, MinimumPeriod,
-        RemoveKeysLimit, VotingOutcomeFee, BASE,
+        AddOutcomePeriod, BlockHashCount, ConvictionLockPeriod, GdVotingPeriod,
+        GlobalDisputeLockId, GlobalDisputesPalletId, MaxLocks, MaxReserves, MinOutcomeVoteAmount,
+        MinimumPeriod, RemoveKeysLimit, VotingOutcomeFee, BASE,
     },
     traits::{DisputeResolutionApi, MarketOfDisputeResolutionApi},
     types::{AccountIdTest, MarketId},
@@ -93,22 +93,26 @@ impl DisputeResolutionApi for NoopResolution {
 parameter_types! {
     pub const MaxGlobalDisputeVotes: u32 = 50;
     pub const MaxOwners: u32 = 10;
+    pub storage GdVotingMode: VotingMode = VotingMode::Linear;
 }
 
 impl crate::Config for Runtime {
     type AddOutcomePeriod = AddOutcomePeriod;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
     type Currency = Balances;
     type DisputeResolution = NoopResolution;
     type RuntimeEvent = RuntimeEvent;
     type GlobalDisputeLockId = GlobalDisputeLockId;
     type GlobalDisputesPalletId = GlobalDisputesPalletId;
     type MarketCommons = MarketCommons;
+    type MaxDelegators = MaxGdDelegators;
     type MaxGlobalDisputeVotes = MaxGlobalDisputeVotes;
     type MaxOwners = MaxOwners;
     type MinOutcomeVoteAmount = MinOutcomeVoteAmount;
     type RemoveKeysLimit = RemoveKeysLimit;
     type GdVotingPeriod = GdVotingPeriod;
     type VotingOutcomeFee = VotingOutcomeFee;
+    type VotingMode = GdVotingMode;
     type WeightInfo = crate::weights::WeightInfo<Runtime>;
 }
 
@@ -20,9 +20,13 @@
 use crate::{
     global_disputes_pallet_api::GlobalDisputesPalletApi,
     mock::*,
-    types::{GdStatus, GlobalDisputeInfo, InitialItem, OutcomeInfo, Possession},
+    types::{
+        Conviction, Delegation, DelegationScope, GdStatus, GlobalDisputeInfo, InitialItem,
+        OutcomeInfo, Possession, VoteLock, VotingMode,
+    },
     utils::market_mock,
-    BalanceOf, Error, Event, GlobalDisputesInfo, InitialItemOf, Locks, MarketIdOf, Outcomes,
+    BalanceOf, Delegations, Delegators, Error, Event, GlobalDisputesInfo, InitialItemOf, Locks,
+    MarketIdOf, Outcomes, VoteLockOf,
 };
 use common_primitives::types::BlockNumber;
 use frame_support::{
@@ -34,9 +38,10 @@ use pallet_balances::BalanceLock;
 use pallet_pm_market_commons::{Error as MarketError, Markets};
 use prediction_market_primitives::{
     constants::mock::{
-        GlobalDisputeLockId, MinOutcomeVoteAmount, RemoveKeysLimit, VotingOutcomeFee, BASE,
+        ConvictionLockPeriod, GlobalDisputeLockId, MaxGdDelegators, MinOutcomeVoteAmount,
+        RemoveKeysLimit, VotingOutcomeFee, BASE,
     },
-    types::OutcomeReport,
+    types::{Asset, OutcomeReport},
 };
 use sp_runtime::{traits::Zero, DispatchError, SaturatedConversion, TokenError};
 use test_case::test_case;
@@ -47,6 +52,10 @@ fn the_lock(amount: u128) -> BalanceLock<u128> {
     BalanceLock { id: GlobalDisputeLockId::get(), amount, reasons: pallet_balances::Reasons::Misc }
 }
 
+fn vote_lock(market_id: MarketIdOf<Runtime>, amount: u128) -> VoteLockOf<Runtime> {
+    VoteLock { market_id, amount, unlock_at: None }
+}
+
 fn get_initial_items() -> Vec<InitialItemOf<Runtime>> {
     vec![
         InitialItem { outcome: OutcomeReport::Scalar(0), owner: ALICE, amount: SETUP_AMOUNT },
@@ -1265,7 +1274,7 @@ fn unlock_clears_lock_info() {
 
         assert!(GlobalDisputes::determine_voting_winner(&market_id).is_some());
 
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id, 50 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![vote_lock(market_id, 50 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
 
@@ -1349,43 +1358,43 @@ fn locking_works_for_one_market() {
             20 * BASE
         ));
 
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id, 50 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![vote_lock(market_id, 50 * BASE)]);
         assert_eq!(Balances::locks(ALICE), vec![the_lock(50 * BASE)]);
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![(market_id, 40 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(BOB), vec![vote_lock(market_id, 40 * BASE)]);
         assert_eq!(Balances::locks(BOB), vec![the_lock(40 * BASE)]);
-        assert_eq!(<Locks<Runtime>>::get(CHARLIE), vec![(market_id, 30 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(CHARLIE), vec![vote_lock(market_id, 30 * BASE)]);
         assert_eq!(Balances::locks(CHARLIE), vec![the_lock(30 * BASE)]);
-        assert_eq!(<Locks<Runtime>>::get(EVE), vec![(market_id, 20 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(EVE), vec![vote_lock(market_id, 20 * BASE)]);
         assert_eq!(Balances::locks(EVE), vec![the_lock(20 * BASE)]);
 
         assert!(GlobalDisputes::determine_voting_winner(&market_id).is_some());
 
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id, 50 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![vote_lock(market_id, 50 * BASE)]);
         assert_eq!(Balances::locks(ALICE), vec![the_lock(50 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
         assert_eq!(<Locks<Runtime>>::get(ALICE), vec![]);
         assert!(Balances::locks(ALICE).is_empty());
 
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![(market_id, 40 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(BOB), vec![vote_lock(market_id, 40 * BASE)]);
         assert_eq!(Balances::locks(BOB), vec![the_lock(40 * BASE)]);
-        assert_eq!(<Locks<Runtime>>::get(CHARLIE), vec![(market_id, 30 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(CHARLIE), vec![vote_lock(market_id, 30 * BASE)]);
         assert_eq!(Balances::locks(CHARLIE), vec![the_lock(30 * BASE)]);
-        assert_eq!(<Locks<Runtime>>::get(EVE), vec![(market_id, 20 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(EVE), vec![vote_lock(market_id, 20 * BASE)]);
         assert_eq!(Balances::locks(EVE), vec![the_lock(20 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(BOB), BOB));
         assert_eq!(<Locks<Runtime>>::get(BOB), vec![]);
         assert!(Balances::locks(BOB).is_empty());
-        assert_eq!(<Locks<Runtime>>::get(CHARLIE), vec![(market_id, 30 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(CHARLIE), vec![vote_lock(market_id, 30 * BASE)]);
         assert_eq!(Balances::locks(CHARLIE), vec![the_lock(30 * BASE)]);
-        assert_eq!(<Locks<Runtime>>::get(EVE), vec![(market_id, 20 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(EVE), vec![vote_lock(market_id, 20 * BASE)]);
         assert_eq!(Balances::locks(EVE), vec![the_lock(20 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(CHARLIE), CHARLIE));
         assert_eq!(<Locks<Runtime>>::get(CHARLIE), vec![]);
         assert!(Balances::locks(CHARLIE).is_empty());
-        assert_eq!(<Locks<Runtime>>::get(EVE), vec![(market_id, 20 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(EVE), vec![vote_lock(market_id, 20 * BASE)]);
         assert_eq!(Balances::locks(EVE), vec![the_lock(20 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(EVE), EVE));
@@ -1445,12 +1454,12 @@ fn locking_works_for_two_markets_with_stronger_first_unlock() {
 
         assert_eq!(
             <Locks<Runtime>>::get(ALICE),
-            vec![(market_id_1, 50 * BASE), (market_id_2, 30 * BASE)]
+            vec![vote_lock(market_id_1, 50 * BASE), vote_lock(market_id_2, 30 * BASE)]
         );
         assert_eq!(Balances::locks(ALICE), vec![the_lock(50 * BASE)]);
         assert_eq!(
             <Locks<Runtime>>::get(BOB),
-            vec![(market_id_1, 40 * BASE), (market_id_2, 20 * BASE)]
+            vec![vote_lock(market_id_1, 40 * BASE), vote_lock(market_id_2, 20 * BASE)]
         );
         assert_eq!(Balances::locks(BOB), vec![the_lock(40 * BASE)]);
 
@@ -1459,32 +1468,32 @@ fn locking_works_for_two_markets_with_stronger_first_unlock() {
 
         assert_eq!(
             <Locks<Runtime>>::get(ALICE),
-            vec![(market_id_1, 50 * BASE), (market_id_2, 30 * BASE)]
+            vec![vote_lock(market_id_1, 50 * BASE), vote_lock(market_id_2, 30 * BASE)]
         );
         assert_eq!(Balances::locks(ALICE), vec![the_lock(50 * BASE)]);
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id_2, 30 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![vote_lock(market_id_2, 30 * BASE)]);
         assert_eq!(Balances::locks(ALICE), vec![the_lock(30 * BASE)]);
         assert_eq!(
             <Locks<Runtime>>::get(BOB),
-            vec![(market_id_1, 40 * BASE), (market_id_2, 20 * BASE)]
+            vec![vote_lock(market_id_1, 40 * BASE), vote_lock(market_id_2, 20 * BASE)]
         );
         assert_eq!(Balances::locks(BOB), vec![the_lock(40 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(BOB), BOB));
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![(market_id_2, 20 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(BOB), vec![vote_lock(market_id_2, 20 * BASE)]);
         assert_eq!(Balances::locks(BOB), vec![the_lock(20 * BASE)]);
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id_2, 30 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![vote_lock(market_id_2, 30 * BASE)]);
         assert_eq!(Balances::locks(ALICE), vec![the_lock(30 * BASE)]);
 
         assert!(GlobalDisputes::determine_voting_winner(&market_id_2).is_some());
 
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id_2, 30 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![vote_lock(market_id_2, 30 * BASE)]);
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
 
         assert_eq!(<Locks<Runtime>>::get(ALICE), vec![]);
         assert!(Balances::locks(ALICE).is_empty());
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![(market_id_2, 20 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(BOB), vec![vote_lock(market_id_2, 20 * BASE)]);
         assert_eq!(Balances::locks(BOB), vec![the_lock(20 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(BOB), BOB));
@@ -1544,12 +1553,12 @@ fn locking_works_for_two_markets_with_weaker_first_unlock() {
 
         assert_eq!(
             <Locks<Runtime>>::get(ALICE),
-            vec![(market_id_1, 50 * BASE), (market_id_2, 30 * BASE)]
+            vec![vote_lock(market_id_1, 50 * BASE), vote_lock(market_id_2, 30 * BASE)]
         );
         assert_eq!(Balances::locks(ALICE), vec![the_lock(50 * BASE)]);
         assert_eq!(
             <Locks<Runtime>>::get(BOB),
-            vec![(market_id_1, 40 * BASE), (market_id_2, 20 * BASE)]
+            vec![vote_lock(market_id_1, 40 * BASE), vote_lock(market_id_2, 20 * BASE)]
         );
         assert_eq!(Balances::locks(BOB), vec![the_lock(40 * BASE)]);
 
@@ -1558,33 +1567,33 @@ fn locking_works_for_two_markets_with_weaker_first_unlock() {
 
         assert_eq!(
             <Locks<Runtime>>::get(ALICE),
-            vec![(market_id_1, 50 * BASE), (market_id_2, 30 * BASE)]
+            vec![vote_lock(market_id_1, 50 * BASE), vote_lock(market_id_2, 30 * BASE)]
         );
         assert_eq!(Balances::locks(ALICE), vec![the_lock(50 * BASE)]);
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id_1, 50 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![vote_lock(market_id_1, 50 * BASE)]);
         assert_eq!(Balances::locks(ALICE), vec![the_lock(50 * BASE)]);
         assert_eq!(
             <Locks<Runtime>>::get(BOB),
-            vec![(market_id_1, 40 * BASE), (market_id_2, 20 * BASE)]
+            vec![vote_lock(market_id_1, 40 * BASE), vote_lock(market_id_2, 20 * BASE)]
         );
         assert_eq!(Balances::locks(BOB), vec![the_lock(40 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(BOB), BOB));
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![(market_id_1, 40 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(BOB), vec![vote_lock(market_id_1, 40 * BASE)]);
         assert_eq!(Balances::locks(BOB), vec![the_lock(40 * BASE)]);
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id_1, 50 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![vote_lock(market_id_1, 50 * BASE)]);
         assert_eq!(Balances::locks(ALICE), vec![the_lock(50 * BASE)]);
 
         assert!(GlobalDisputes::determine_voting_winner(&market_id_1).is_some());
 
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id_1, 50 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![vote_lock(market_id_1, 50 * BASE)]);
         assert_eq!(Balances::locks(ALICE), vec![the_lock(50 * BASE)]);
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
 
         assert_eq!(<Locks<Runtime>>::get(ALICE), vec![]);
         assert!(Balances::locks(ALICE).is_empty());
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![(market_id_1, 40 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(BOB), vec![vote_lock(market_id_1, 40 * BASE)]);
         assert_eq!(Balances::locks(BOB), vec![the_lock(40 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(BOB), BOB));
@@ -1592,3 +1601,473 @@ fn locking_works_for_two_markets_with_weaker_first_unlock() {
         assert!(Balances::locks(BOB).is_empty());
     });
 }
+
+#[test]
+fn vote_with_conviction_fails_if_voting_mode_is_not_conviction() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market);
+
+        let initial_items = get_initial_items();
+        assert_ok!(GlobalDisputes::start_global_dispute(&market_id, initial_items.as_slice()));
+
+        set_vote_period();
+
+        assert_noop!(
+            GlobalDisputes::vote_with_conviction(
+                RuntimeOrigin::signed(ALICE),
+                market_id,
+                OutcomeReport::Scalar(0),
+                10 * BASE,
+                Conviction::Locked2x
+            ),
+            Error::<Runtime>::ConvictionNotSupported
+        );
+        // Without a conviction the vote is the same as a call to `vote_on_outcome`.
+        assert_ok!(GlobalDisputes::vote_with_conviction(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(0),
+            10 * BASE,
+            Conviction::None
+        ));
+        check_outcome_sum(&market_id, OutcomeReport::Scalar(0), 10 * BASE);
+    });
+}
+
+#[test]
+fn conviction_votes_are_weighted_and_locked_beyond_the_global_dispute() {
+    ExtBuilder::default().build().execute_with(|| {
+        GdVotingMode::set(&VotingMode::Conviction);
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market);
+
+        let initial_items = get_initial_items();
+        assert_ok!(GlobalDisputes::start_global_dispute(&market_id, initial_items.as_slice()));
+        let vote_end = match <GlobalDisputesInfo<Runtime>>::get(market_id).unwrap().status {
+            GdStatus::Active { vote_end, .. } => vote_end,
+            _ => panic!("global dispute should be active"),
+        };
+
+        set_vote_period();
+
+        assert_ok!(GlobalDisputes::vote_with_conviction(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(20),
+            10 * BASE,
+            Conviction::Locked3x
+        ));
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            RuntimeOrigin::signed(BOB),
+            market_id,
+            OutcomeReport::Scalar(40),
+            20 * BASE
+        ));
+
+        check_outcome_sum(&market_id, OutcomeReport::Scalar(20), 30 * BASE);
+        check_outcome_sum(&market_id, OutcomeReport::Scalar(40), 20 * BASE);
+        assert_eq!(
+            GlobalDisputes::determine_voting_winner(&market_id).unwrap(),
+            OutcomeReport::Scalar(20)
+        );
+
+        let unlock_at = vote_end + 2 * ConvictionLockPeriod::get();
+        assert_eq!(
+            <Locks<Runtime>>::get(ALICE),
+            vec![VoteLock { market_id, amount: 10 * BASE, unlock_at: Some(unlock_at) }]
+        );
+
+        assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(BOB), BOB));
+        assert!(Balances::locks(BOB).is_empty());
+
+        System::set_block_number(unlock_at - 1);
+        assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
+        assert_eq!(Balances::locks(ALICE), vec![the_lock(10 * BASE)]);
+
+        System::set_block_number(unlock_at);
+        assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
+        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![]);
+        assert!(Balances::locks(ALICE).is_empty());
+    });
+}
+
+#[test]
+fn conviction_votes_are_unlocked_immediately_if_the_global_dispute_is_destroyed() {
+    ExtBuilder::default().build().execute_with(|| {
+        GdVotingMode::set(&VotingMode::Conviction);
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market);
+
+        let initial_items = get_initial_items();
+        assert_ok!(GlobalDisputes::start_global_dispute(&market_id, initial_items.as_slice()));
+
+        set_vote_period();
+
+        assert_ok!(GlobalDisputes::vote_with_conviction(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(20),
+            10 * BASE,
+            Conviction::Locked5x
+        ));
+
+        assert_ok!(GlobalDisputes::destroy_global_dispute(&market_id));
+
+        assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
+        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![]);
+        assert!(Balances::locks(ALICE).is_empty());
+    });
+}
+
+#[test]
+fn quadratic_votes_are_weighted_by_the_square_root_of_the_total_stake() {
+    ExtBuilder::default().build().execute_with(|| {
+        GdVotingMode::set(&VotingMode::Quadratic);
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market);
+
+        let initial_items = get_initial_items();
+        assert_ok!(GlobalDisputes::start_global_dispute(&market_id, initial_items.as_slice()));
+        let outcome_sum_of =
+            |outcome| <Outcomes<Runtime>>::get(market_id, outcome).unwrap().outcome_sum;
+        // The square root of `SETUP_AMOUNT`.
+        let setup_weight = 1_000_000;
+        assert_eq!(outcome_sum_of(OutcomeReport::Scalar(20)), setup_weight);
+
+        set_vote_period();
+
+        // Splitting a vote yields the same weight as voting with the total stake at once.
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(20),
+            16 * BASE
+        ));
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(20),
+            9 * BASE
+        ));
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            RuntimeOrigin::signed(BOB),
+            market_id,
+            OutcomeReport::Scalar(40),
+            25 * BASE
+        ));
+
+        assert_eq!(outcome_sum_of(OutcomeReport::Scalar(20)), setup_weight + 500_000);
+        assert_eq!(outcome_sum_of(OutcomeReport::Scalar(40)), setup_weight + 500_000);
+        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![vote_lock(market_id, 25 * BASE)]);
+        assert_eq!(Balances::locks(ALICE), vec![the_lock(25 * BASE)]);
+    });
+}
+
+fn start_global_dispute_in_vote_period() -> MarketIdOf<Runtime> {
+    let market_id = 0u128;
+    let market = market_mock::<Runtime>();
+    Markets::<Runtime>::insert(market_id, market);
+
+    let initial_items = get_initial_items();
+    assert_ok!(GlobalDisputes::start_global_dispute(&market_id, initial_items.as_slice()));
+
+    set_vote_period();
+    market_id
+}
+
+#[test]
+fn delegate_gd_votes_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(GlobalDisputes::delegate_gd_votes(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            30 * BASE,
+            DelegationScope::All
+        ));
+
+        assert_eq!(
+            <Delegations<Runtime>>::get(BOB),
+            Some(Delegation { delegate: ALICE, amount: 30 * BASE, scope: DelegationScope::All })
+        );
+        assert_eq!(<Delegators<Runtime>>::get(ALICE), vec![BOB]);
+        System::assert_last_event(
+            Event::<Runtime>::VotesDelegated {
+                delegator: BOB,
+                delegate: ALICE,
+                amount: 30 * BASE,
+                scope: DelegationScope::All,
+            }
+            .into(),
+        );
+
+        // Delegating again replaces the previous delegation.
+        assert_ok!(GlobalDisputes::delegate_gd_votes(
+            RuntimeOrigin::signed(BOB),
+            CHARLIE,
+            20 * BASE,
+            DelegationScope::All
+        ));
+        assert_eq!(<Delegators<Runtime>>::get(ALICE), vec![]);
+        assert_eq!(<Delegators<Runtime>>::get(CHARLIE), vec![BOB]);
+        assert_eq!(<Delegations<Runtime>>::get(BOB).unwrap().delegate, CHARLIE);
+    });
+}
+
+#[test]
+fn delegate_gd_votes_fails_on_self_delegation() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            GlobalDisputes::delegate_gd_votes(
+                RuntimeOrigin::signed(BOB),
+                BOB,
+                30 * BASE,
+                DelegationScope::All
+            ),
+            Error::<Runtime>::SelfDelegation
+        );
+    });
+}
+
+#[test]
+fn delegate_gd_votes_fails_on_amount_below_minimum() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            GlobalDisputes::delegate_gd_votes(
+                RuntimeOrigin::signed(BOB),
+                ALICE,
+                MinOutcomeVoteAmount::get() - 1,
+                DelegationScope::All
+            ),
+            Error::<Runtime>::AmountTooLow
+        );
+    });
+}
+
+#[test]
+fn delegate_gd_votes_evicts_the_smallest_delegation_if_the_delegate_is_full() {
+    ExtBuilder::default().build().execute_with(|| {
+        let delegators: Vec<_> = (100u128..100 + MaxGdDelegators::get() as u128).collect();
+        for (i, delegator) in delegators.iter().enumerate() {
+            let _ = Balances::deposit_creating(delegator, 100 * BASE);
+            assert_ok!(GlobalDisputes::delegate_gd_votes(
+                RuntimeOrigin::signed(*delegator),
+                ALICE,
+                (20 + i as u128) * BASE,
+                DelegationScope::All
+            ));
+        }
+
+        // The smallest delegation is only evicted by a larger one.
+        assert_noop!(
+            GlobalDisputes::delegate_gd_votes(
+                RuntimeOrigin::signed(BOB),
+                ALICE,
+                20 * BASE,
+                DelegationScope::All
+            ),
+            Error::<Runtime>::TooManyDelegators
+        );
+        assert_ok!(GlobalDisputes::delegate_gd_votes(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            21 * BASE,
+            DelegationScope::All
+        ));
+        assert!(<Delegations<Runtime>>::get(delegators[0]).is_none());
+        assert!(!<Delegators<Runtime>>::get(ALICE).contains(&delegators[0]));
+        assert!(<Delegators<Runtime>>::get(ALICE).contains(&BOB));
+        System::assert_has_event(
+            Event::<Runtime>::DelegatorEvicted { delegator: delegators[0], delegate: ALICE }.into(),
+        );
+
+        // Delegations are compared by the amount their delegators can still lock.
+        Balances::make_free_balance_be(&delegators[5], MinOutcomeVoteAmount::get());
+        assert_ok!(GlobalDisputes::delegate_gd_votes(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            MinOutcomeVoteAmount::get() + 1,
+            DelegationScope::All
+        ));
+        assert!(<Delegations<Runtime>>::get(delegators[5]).is_none());
+        assert!(<Delegators<Runtime>>::get(ALICE).contains(&CHARLIE));
+    });
+}
+
+#[test]
+fn undelegate_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(GlobalDisputes::delegate_gd_votes(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            30 * BASE,
+            DelegationScope::All
+        ));
+
+        assert_ok!(GlobalDisputes::undelegate(RuntimeOrigin::signed(BOB)));
+
+        assert!(<Delegations<Runtime>>::get(BOB).is_none());
+        assert!(!<Delegators<Runtime>>::contains_key(ALICE));
+        System::assert_last_event(
+            Event::<Runtime>::VotesUndelegated { delegator: BOB, delegate: ALICE }.into(),
+        );
+        assert_noop!(
+            GlobalDisputes::undelegate(RuntimeOrigin::signed(BOB)),
+            Error::<Runtime>::DelegationNotFound
+        );
+    });
+}
+
+#[test]
+fn delegated_votes_count_towards_the_outcome_of_the_delegate() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(GlobalDisputes::delegate_gd_votes(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            30 * BASE,
+            DelegationScope::All
+        ));
+        assert_ok!(GlobalDisputes::delegate_gd_votes(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            20 * BASE,
+            DelegationScope::All
+        ));
+        let market_id = start_global_dispute_in_vote_period();
+
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(20),
+            10 * BASE
+        ));
+
+        check_outcome_sum(&market_id, OutcomeReport::Scalar(20), 60 * BASE);
+        System::assert_has_event(
+            Event::<Runtime>::DelegatedVotesCounted {
+                market_id,
+                delegate: ALICE,
+                outcome: OutcomeReport::Scalar(20),
+                amount: 50 * BASE,
+            }
+            .into(),
+        );
+        assert_eq!(<Locks<Runtime>>::get(BOB), vec![vote_lock(market_id, 30 * BASE)]);
+        assert_eq!(Balances::locks(BOB), vec![the_lock(30 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(CHARLIE), vec![vote_lock(market_id, 20 * BASE)]);
+        assert_eq!(Balances::locks(CHARLIE), vec![the_lock(20 * BASE)]);
+
+        // Further votes of the delegate don't count the delegations again.
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(20),
+            10 * BASE
+        ));
+        check_outcome_sum(&market_id, OutcomeReport::Scalar(20), 70 * BASE);
+        assert_eq!(<Locks<Runtime>>::get(BOB), vec![vote_lock(market_id, 30 * BASE)]);
+
+        assert!(GlobalDisputes::determine_voting_winner(&market_id).is_some());
+        assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(BOB), BOB));
+        assert_eq!(<Locks<Runtime>>::get(BOB), vec![]);
+        assert!(Balances::locks(BOB).is_empty());
+    });
+}
+
+#[test]
+fn delegated_votes_are_only_counted_for_markets_in_scope() {
+    ExtBuilder::default().build().execute_with(|| {
+        let creator = market_mock::<Runtime>().creator;
+        assert_ok!(GlobalDisputes::delegate_gd_votes(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            30 * BASE,
+            DelegationScope::Creator(creator)
+        ));
+        assert_ok!(GlobalDisputes::delegate_gd_votes(
+            RuntimeOrigin::signed(CHARLIE),
+            ALICE,
+            20 * BASE,
+            DelegationScope::Creator(DAVE)
+        ));
+        assert_ok!(GlobalDisputes::delegate_gd_votes(
+            RuntimeOrigin::signed(EVE),
+            ALICE,
+            10 * BASE,
+            DelegationScope::BaseAsset(Asset::ForeignAsset(0))
+        ));
+        let market_id = start_global_dispute_in_vote_period();
+
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(20),
+            10 * BASE
+        ));
+
+        check_outcome_sum(&market_id, OutcomeReport::Scalar(20), 40 * BASE);
+        assert_eq!(<Locks<Runtime>>::get(BOB), vec![vote_lock(market_id, 30 * BASE)]);
+        assert_eq!(<Locks<Runtime>>::get(CHARLIE), vec![]);
+        assert!(Balances::locks(CHARLIE).is_empty());
+        assert_eq!(<Locks<Runtime>>::get(EVE), vec![]);
+        assert!(Balances::locks(EVE).is_empty());
+    });
+}
+
+#[test]
+fn delegators_who_voted_themselves_keep_their_vote() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(GlobalDisputes::delegate_gd_votes(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            30 * BASE,
+            DelegationScope::All
+        ));
+        let market_id = start_global_dispute_in_vote_period();
+
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            RuntimeOrigin::signed(BOB),
+            market_id,
+            OutcomeReport::Scalar(40),
+            15 * BASE
+        ));
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(20),
+            10 * BASE
+        ));
+
+        check_outcome_sum(&market_id, OutcomeReport::Scalar(20), 10 * BASE);
+        check_outcome_sum(&market_id, OutcomeReport::Scalar(40), 15 * BASE);
+        assert_eq!(<Locks<Runtime>>::get(BOB), vec![vote_lock(market_id, 15 * BASE)]);
+    });
+}
+
+#[test]
+fn undelegated_votes_are_not_counted() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(GlobalDisputes::delegate_gd_votes(
+            RuntimeOrigin::signed(BOB),
+            ALICE,
+            30 * BASE,
+            DelegationScope::All
+        ));
+        assert_ok!(GlobalDisputes::undelegate(RuntimeOrigin::signed(BOB)));
+        let market_id = start_global_dispute_in_vote_period();
+
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(20),
+            10 * BASE
+        ));
+
+        check_outcome_sum(&market_id, OutcomeReport::Scalar(20), 10 * BASE);
+        assert_eq!(<Locks<Runtime>>::get(BOB), vec![]);
+    });
+}
@@ -17,7 +17,7 @@
 
 use frame_support::pallet_prelude::{Decode, Encode, MaxEncodedLen, TypeInfo};
 use prediction_market_primitives::types::OutcomeReport;
-use sp_runtime::traits::Saturating;
+use sp_runtime::traits::{AtLeast32BitUnsigned, IntegerSquareRoot, Saturating};
 
 /// The original voting outcome owner information.
 #[derive(Debug, TypeInfo, Decode, Encode, MaxEncodedLen, Clone, PartialEq, Eq)]
@@ -102,3 +102,115 @@ pub struct InitialItem<AccountId, Balance> {
     /// The vote amount at the start of the global dispute.
     pub amount: Balance,
 }
+
+/// The way the locked amount of a vote is turned into voting weight.
+#[derive(TypeInfo, Debug, Decode, Encode, MaxEncodedLen, Clone, Copy, PartialEq, Eq)]
+pub enum VotingMode {
+    /// The weight of a vote is the locked amount.
+    Linear,
+    /// The weight of a vote is the locked amount multiplied by the conviction of the vote.
+    Conviction,
+    /// The weight of the votes of an account on a global dispute is the square root of the total
+    /// amount the account locked for it.
+    Quadratic,
+}
+
+impl VotingMode {
+    /// Returns the weight of `amount` locked with `conviction`.
+    pub fn weigh<Balance>(&self, amount: Balance, conviction: Conviction) -> Balance
+    where
+        Balance: AtLeast32BitUnsigned + Copy,
+    {
+        match self {
+            VotingMode::Linear => amount,
+            VotingMode::Conviction => amount.saturating_mul(conviction.multiplier().into()),
+            VotingMode::Quadratic => amount.integer_sqrt(),
+        }
+    }
+}
+
+/// The conviction of a vote. A vote with a higher conviction stays locked for longer after the
+/// end of the global dispute, but has more weight if the voting mode is `VotingMode::Conviction`.
+#[derive(TypeInfo, Debug, Decode, Encode, MaxEncodedLen, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Conviction {
+    /// 1x weight, unlocked as soon as the global dispute is concluded.
+    #[default]
+    None,
+    /// 2x weight, locked for one lock period after the end of the voting period.
+    Locked2x,
+    /// 3x weight, locked for two lock periods after the end of the voting period.
+    Locked3x,
+    /// 4x weight, locked for four lock periods after the end of the voting period.
+    Locked4x,
+    /// 5x weight, locked for eight lock periods after the end of the voting period.
+    Locked5x,
+}
+
+impl Conviction {
+    /// The factor by which the locked amount is multiplied.
+    pub fn multiplier(&self) -> u8 {
+        match self {
+            Conviction::None => 1,
+            Conviction::Locked2x => 2,
+            Conviction::Locked3x => 3,
+            Conviction::Locked4x => 4,
+            Conviction::Locked5x => 5,
+        }
+    }
+
+    /// The number of lock periods the vote stays locked after the end of the voting period.
+    pub fn lock_periods(&self) -> u32 {
+        match self {
+            Conviction::None => 0,
+            Conviction::Locked2x => 1,
+            Conviction::Locked3x => 2,
+            Conviction::Locked4x => 4,
+            Conviction::Locked5x => 8,
+        }
+    }
+}
+
+/// The lock an account holds for its votes on a global dispute.
+#[derive(TypeInfo, Debug, Decode, Encode, MaxEncodedLen, Clone, PartialEq, Eq)]
+pub struct VoteLock<MarketId, Balance, BlockNumber> {
+    /// The market of the global dispute.
+    pub market_id: MarketId,
+    /// The total amount locked for the global dispute.
+    pub amount: Balance,
+    /// The block from which on the amount can be unlocked once the global dispute is finished.
+    /// `None` if the amount can be unlocked as soon as the global dispute is concluded.
+    pub unlock_at: Option<BlockNumber>,
+}
+
+/// The markets on whose global disputes the delegated votes of an account are counted.
+#[derive(TypeInfo, Debug, Decode, Encode, MaxEncodedLen, Clone, PartialEq, Eq)]
+pub enum DelegationScope<AccountId, Asset> {
+    /// All markets.
+    All,
+    /// The markets created by the account.
+    Creator(AccountId),
+    /// The markets with the base asset.
+    BaseAsset(Asset),
+}
+
+impl<AccountId: PartialEq, Asset: PartialEq> DelegationScope<AccountId, Asset> {
+    /// Returns whether a market with `creator` and `base_asset` is in the scope.
+    pub fn contains(&self, creator: &AccountId, base_asset: &Asset) -> bool {
+        match self {
+            DelegationScope::All => true,
+            DelegationScope::Creator(account) => account == creator,
+            DelegationScope::BaseAsset(asset) => asset == base_asset,
+        }
+    }
+}
+
+/// The delegation of the votes of an account on global disputes.
+#[derive(TypeInfo, Debug, Decode, Encode, MaxEncodedLen, Clone, PartialEq, Eq)]
+pub struct Delegation<AccountId, Balance, Asset> {
+    /// The account which votes on behalf of the delegator.
+    pub delegate: AccountId,
+    /// The amount which is locked on each global dispute the delegate votes on.
+    pub amount: Balance,
+    /// The markets on whose global disputes the delegation is counted.
+    pub scope: DelegationScope<AccountId, Asset>,
+}
@@ -57,6 +57,9 @@ pub trait WeightInfoZeitgeist {
     fn reward_outcome_owner_paid_possession() -> Weight;
     fn purge_outcomes(k: u32, o: u32) -> Weight;
     fn refund_vote_fees(k: u32, o: u32) -> Weight;
+    fn delegate_gd_votes(d: u32) -> Weight;
+    fn undelegate(d: u32) -> Weight;
+    fn count_delegated_votes(d: u32) -> Weight;
 }
 
 /// Weight functions for pallet_pm_global_disputes (automatically generated)
@@ -236,4 +239,71 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(k.into())))
             .saturating_add(Weight::from_parts(0, 2870).saturating_mul(k.into()))
     }
+    /// Storage: `System::Account` (r:1 w:0)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode:
+    /// `MaxEncodedLen`) Storage: `GlobalDisputes::Delegations` (r:1 w:1)
+    /// Proof: `GlobalDisputes::Delegations` (`max_values`: None, `max_size`: Some(112), added:
+    /// 2587, mode: `MaxEncodedLen`) Storage: `GlobalDisputes::Delegators` (r:2 w:2)
+    /// Proof: `GlobalDisputes::Delegators` (`max_values`: None, `max_size`: Some(2070), added:
+    /// 4545, mode: `MaxEncodedLen`) The range of component `d` is `[0, 63]`.
+    fn delegate_gd_votes(d: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `378 + d * (64 ±0)`
+        //  Estimated: `10080`
+        // Minimum execution time: 41_120 nanoseconds.
+        Weight::from_parts(46_358_412, 10080)
+            // Standard Error: 2_184
+            .saturating_add(Weight::from_parts(98_716, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: `GlobalDisputes::Delegations` (r:1 w:1)
+    /// Proof: `GlobalDisputes::Delegations` (`max_values`: None, `max_size`: Some(112), added:
+    /// 2587, mode: `MaxEncodedLen`) Storage: `GlobalDisputes::Delegators` (r:1 w:1)
+    /// Proof: `GlobalDisputes::Delegators` (`max_values`: None, `max_size`: Some(2070), added:
+    /// 4545, mode: `MaxEncodedLen`) The range of component `d` is `[1, 64]`.
+    fn undelegate(d: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `310 + d * (64 ±0)`
+        //  Estimated: `5535`
+        // Minimum execution time: 27_301 nanoseconds.
+        Weight::from_parts(30_714_225, 5535)
+            // Standard Error: 1_795
+            .saturating_add(Weight::from_parts(71_482, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `GlobalDisputes::Delegators` (r:1 w:0)
+    /// Proof: `GlobalDisputes::Delegators` (`max_values`: None, `max_size`: Some(2070), added:
+    /// 4545, mode: `MaxEncodedLen`) Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
+    /// mode: `MaxEncodedLen`) Storage: `GlobalDisputes::Outcomes` (r:1 w:1)
+    /// Proof: `GlobalDisputes::Outcomes` (`max_values`: None, `max_size`: Some(395), added: 2870,
+    /// mode: `MaxEncodedLen`) Storage: `GlobalDisputes::GlobalDisputesInfo` (r:1 w:1)
+    /// Proof: `GlobalDisputes::GlobalDisputesInfo` (`max_values`: None, `max_size`: Some(396),
+    /// added: 2871, mode: `MaxEncodedLen`) Storage: `GlobalDisputes::Delegations` (r:64 w:0)
+    /// Proof: `GlobalDisputes::Delegations` (`max_values`: None, `max_size`: Some(112), added:
+    /// 2587, mode: `MaxEncodedLen`) Storage: `System::Account` (r:64 w:0)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode:
+    /// `MaxEncodedLen`) Storage: `GlobalDisputes::Locks` (r:64 w:64)
+    /// Proof: `GlobalDisputes::Locks` (`max_values`: None, `max_size`: Some(2091), added: 4566,
+    /// mode: `MaxEncodedLen`) Storage: `Balances::Locks` (r:64 w:64)
+    /// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode:
+    /// `MaxEncodedLen`) Storage: `Balances::Freezes` (r:64 w:0)
+    /// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode:
+    /// `MaxEncodedLen`) The range of component `d` is `[0, 64]`.
+    fn count_delegated_votes(d: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0 + d * (512 ±0)`
+        //  Estimated: `5535 + d * (4566 ±0)`
+        // Minimum execution time: 2_950 nanoseconds.
+        Weight::from_parts(8_622_504, 5535)
+            // Standard Error: 21_307
+            .saturating_add(Weight::from_parts(31_468_035, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 4566).saturating_mul(d.into()))
+    }
 }
@@ -0,0 +1,62 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use parity_scale_codec::{Codec, MaxEncodedLen};
+use prediction_market_primitives::{
+    hybrid_router_api_types::{Strategy, TradeSimulation},
+    orderbook::OrderId,
+    types::Asset,
+};
+use sp_runtime::DispatchError;
+
+sp_api::decl_runtime_apis! {
+    pub trait HybridRouterApi<AccountId, MarketId, Balance> where
+        AccountId: Codec,
+        MarketId: Codec + MaxEncodedLen,
+        Balance: Codec,
+    {
+        /// Simulates `HybridRouter::buy` on behalf of `who` and returns the planned order fills,
+        /// AMM trades, fees, average price and the unfilled remainder.
+        fn simulate_buy(
+            who: AccountId,
+            market_id: MarketId,
+            asset: Asset<MarketId>,
+            amount_in: Balance,
+            max_price: Balance,
+            orders: Vec<OrderId>,
+            strategy: Strategy,
+        ) -> Result<TradeSimulation<AccountId, Balance, MarketId>, DispatchError>;
+
+        /// Simulates `HybridRouter::sell` on behalf of `who` and returns the planned order fills,
+        /// AMM trades, fees, average price and the unfilled remainder.
+        fn simulate_sell(
+            who: AccountId,
+            market_id: MarketId,
+            asset: Asset<MarketId>,
+            amount_in: Balance,
+            min_price: Balance,
+            orders: Vec<OrderId>,
+            strategy: Strategy,
+        ) -> Result<TradeSimulation<AccountId, Balance, MarketId>, DispatchError>;
+    }
+}
@@ -201,6 +201,7 @@ mod benchmarks {
                 maker_amount,
                 taker_asset,
                 taker_amount,
+                None,
             ));
         }
         let strategy = Strategy::LimitOrder;
@@ -271,6 +272,7 @@ mod benchmarks {
                 maker_amount,
                 taker_asset,
                 taker_amount,
+                None,
             )
             .unwrap();
         }
@@ -335,6 +337,7 @@ mod benchmarks {
                 maker_amount,
                 taker_asset,
                 taker_amount,
+                None,
             ));
         }
         let strategy = Strategy::LimitOrder;
@@ -424,6 +427,7 @@ mod benchmarks {
                 maker_amount,
                 taker_asset,
                 taker_amount,
+                None,
             )
             .unwrap();
         }
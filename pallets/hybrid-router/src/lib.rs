@@ -37,7 +37,7 @@ mod pallet {
     pub const SELL: &[u8] = b"sell outcome tokens";
 
     use crate::{
-        types::{OrderAmmTradesInfo, Strategy, Trade, TradeEventInfo, TxType},
+        types::{OrderAmmTradesInfo, Strategy, Trade, TradeEventInfo, TradeExecution, TxType},
         weights::WeightInfoZeitgeist,
     };
     use alloc::{vec, vec::Vec};
@@ -45,6 +45,7 @@ mod pallet {
     use frame_support::{
         dispatch::GetDispatchInfo,
         ensure, require_transactional,
+        storage::{with_transaction, TransactionOutcome},
         traits::{IsSubType, IsType, StorageVersion},
         PalletId,
     };
@@ -58,7 +59,8 @@ mod pallet {
     use prediction_market_primitives::traits::{CompleteSetOperationsApi, DeployPoolApi};
     use prediction_market_primitives::{
         hybrid_router_api_types::{
-            AmmSoftFail, AmmTrade, ApiError, OrderbookSoftFail, OrderbookTrade,
+            AmmSoftFail, AmmTrade, ApiError, LimitOrderPlacement, OrderFill, OrderbookSoftFail,
+            OrderbookTrade, TradeSimulation,
         },
         math::{
             checked_ops_res::CheckedSubRes,
@@ -164,6 +166,9 @@ mod pallet {
         <<T as Config>::MarketCommons as MarketCommonsPalletApi>::MarketId;
     pub(crate) type AmmTradeOf<T> = AmmTrade<BalanceOf<T>>;
     pub(crate) type OrderTradeOf<T> = OrderbookTrade<AccountIdOf<T>, BalanceOf<T>>;
+    pub(crate) type LimitOrderPlacementOf<T> = LimitOrderPlacement<MarketIdOf<T>, BalanceOf<T>>;
+    pub(crate) type TradeSimulationOf<T> =
+        TradeSimulation<AccountIdOf<T>, BalanceOf<T>, MarketIdOf<T>>;
 
     #[pallet::storage]
     #[pallet::getter(fn market_nonces)]
@@ -260,20 +265,25 @@ mod pallet {
         ///   reached.
         ///
         /// The elements of `orders` are the orders that the router may use to execute the order. If
-        /// any of these orders are already filled, they are ignored. It is not necessary
-        /// for the router to use all specified orders. The smaller the vector, the larger
-        /// the risk that the AMM is used to fill large chunks of the order.
+        /// any of these orders are already filled or have expired, they are ignored. It is not
+        /// necessary for the router to use all specified orders. The smaller the vector, the
+        /// larger the risk that the AMM is used to fill large chunks of the order.
         ///
         /// The `orders` vector **must** be sorted in ascending order by the price of their
         /// associated orders. Failing this, the behavior of `buy` is undefined.
         ///
         /// If the maximum price is reached before the entire buy order is filled, the `strategy`
         /// parameter decides if the order is rolled back (`Strategy::ImmediateOrCancel`) or
-        /// if a limit order for the remaining amount is placed (`Strategy::LimitOrder`).
+        /// if a limit order for the remaining amount is placed (`Strategy::LimitOrder`). The
+        /// limit order placed by `Strategy::GoodTilBlock` or `Strategy::GoodTilTime` expires
+        /// after the specified block or moment.
         ///
         /// Complexity: `O(n)`
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::buy(*asset_count as u32, orders.len().saturated_into::<u32>()))]
+        #[pallet::weight(
+            T::WeightInfo::buy(*asset_count as u32, orders.len().saturated_into::<u32>())
+                .saturating_add(Pallet::<T>::amm_trades_overhead_max_weight(orders.len()))
+        )]
         #[frame_support::transactional]
         #[allow(clippy::too_many_arguments)]
         pub fn buy(
@@ -317,20 +327,25 @@ mod pallet {
         ///   reached.
         ///
         /// The elements of `orders` are the orders that the router may use to execute the order. If
-        /// any of these orders are already filled, they are ignored. It is not necessary
-        /// for the router to use all specified orders. The smaller the vector, the larger
-        /// the risk that the AMM is used to fill large chunks of the order.
+        /// any of these orders are already filled or have expired, they are ignored. It is not
+        /// necessary for the router to use all specified orders. The smaller the vector, the
+        /// larger the risk that the AMM is used to fill large chunks of the order.
         ///
         /// The `orders` vector **must** be sorted in ascending order by the price of their
         /// associated orders. Failing this, the behavior of `sell` is undefined.
         ///
         /// If the maximum price is reached before the entire buy order is filled, the `strategy`
         /// parameter decides if the order is rolled back (`Strategy::ImmediateOrCancel`) or
-        /// if a limit order for the remaining amount is placed (`Strategy::LimitOrder`).
+        /// if a limit order for the remaining amount is placed (`Strategy::LimitOrder`). The
+        /// limit order placed by `Strategy::GoodTilBlock` or `Strategy::GoodTilTime` expires
+        /// after the specified block or moment.
         ///
         /// Complexity: `O(n)`
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::sell(*asset_count as u32, orders.len().saturated_into::<u32>()))]
+        #[pallet::weight(
+            T::WeightInfo::sell(*asset_count as u32, orders.len().saturated_into::<u32>())
+                .saturating_add(Pallet::<T>::amm_trades_overhead_max_weight(orders.len()))
+        )]
         #[frame_support::transactional]
         #[allow(clippy::too_many_arguments)]
         pub fn sell(
@@ -362,7 +377,10 @@ mod pallet {
 
         // Signed buy extrinsic
         #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::signed_buy(*asset_count as u32, orders.len().saturated_into::<u32>()))]
+        #[pallet::weight(
+            T::WeightInfo::signed_buy(*asset_count as u32, orders.len().saturated_into::<u32>())
+                .saturating_add(Pallet::<T>::amm_trades_overhead_max_weight(orders.len()))
+        )]
         #[frame_support::transactional]
         #[allow(clippy::too_many_arguments)]
         pub fn signed_buy(
@@ -416,7 +434,10 @@ mod pallet {
 
         // Signed sell extrinsic
         #[pallet::call_index(3)]
-        #[pallet::weight(T::WeightInfo::signed_sell(*asset_count as u32, orders.len().saturated_into::<u32>()))]
+        #[pallet::weight(
+            T::WeightInfo::signed_sell(*asset_count as u32, orders.len().saturated_into::<u32>())
+                .saturating_add(Pallet::<T>::amm_trades_overhead_max_weight(orders.len()))
+        )]
         #[frame_support::transactional]
         pub fn signed_sell(
             origin: OriginFor<T>,
@@ -502,7 +523,7 @@ mod pallet {
                     if spot_price >= price_limit {
                         return Ok((amount_in, None));
                     }
-                    T::Amm::calculate_buy_amount_until(market_id, asset, price_limit)?
+                    T::Amm::calculate_buy_amount_until(who, market_id, asset, price_limit)?
                 },
                 TxType::Sell => {
                     if spot_price <= price_limit {
@@ -532,6 +553,13 @@ mod pallet {
                 .unwrap_or(Ok((amount_in, None)))
         }
 
+        /// Returns the max weight of the AMM bookkeeping for a trade which fills `order_count`
+        /// orders. The AMM trades at most once before each order and once after the last one.
+        fn amm_trades_overhead_max_weight(order_count: usize) -> Weight {
+            T::Amm::trade_overhead_max_weight()
+                .saturating_mul(order_count.saturating_add(1).saturated_into::<u64>())
+        }
+
         fn handle_amm_trade(
             tx_type: TxType,
             who: AccountIdOf<T>,
@@ -641,7 +669,7 @@ mod pallet {
                 // and the `maker_partial_fill` of `fill_order` is specified in `taker_asset`
                 let order_trade_opt = Self::handle_fill_order(who.clone(), order_id, maker_fill)?;
                 if let Some(order_trade) = order_trade_opt {
-                    order_trades.push(order_trade);
+                    order_trades.push((order_id, order_trade));
                     // `maker_fill` is the amount the order owner (maker) wants to receive
                     remaining = remaining.checked_sub_res(&maker_fill)?;
                 }
@@ -665,7 +693,8 @@ mod pallet {
             }
         }
 
-        /// Places a limit order if the strategy is `Strategy::LimitOrder`.
+        /// Places a limit order if the strategy is `Strategy::LimitOrder`,
+        /// `Strategy::GoodTilBlock` or `Strategy::GoodTilTime`.
         /// If the strategy is `Strategy::ImmediateOrCancel`, an error is returned.
         /// A bool is returned to indicate if the order was placed successfully.
         ///
@@ -690,7 +719,7 @@ mod pallet {
         ) -> Result<bool, DispatchError> {
             match strategy {
                 Strategy::ImmediateOrCancel => Err(Error::<T>::CancelStrategyApplied.into()),
-                Strategy::LimitOrder => {
+                Strategy::LimitOrder | Strategy::GoodTilBlock(_) | Strategy::GoodTilTime(_) => {
                     match T::Orderbook::place_order(
                         who.clone(),
                         market_id,
@@ -698,6 +727,7 @@ mod pallet {
                         maker_amount,
                         taker_asset,
                         taker_amount,
+                        strategy.order_expiry(),
                     ) {
                         Ok(()) => Ok(true),
                         Err(ApiError::SoftFailure(OrderbookSoftFail::BelowMinimumBalance)) |
@@ -738,6 +768,61 @@ mod pallet {
             orders: &[OrderId],
             strategy: Strategy,
         ) -> DispatchResult {
+            let TradeExecution {
+                asset_in,
+                asset_out,
+                remaining,
+                order_trades,
+                amm_trades,
+                limit_order,
+            } = Self::execute_trade(
+                tx_type,
+                &who,
+                market_id,
+                asset_count,
+                asset,
+                amount_in,
+                price_limit,
+                orders,
+                strategy,
+            )?;
+            let TradeEventInfo { amount_out, external_fee_amount, swap_fee_amount } =
+                Self::get_event_info(&who, &order_trades, &amm_trades)?;
+            Self::deposit_event(Event::HybridRouterExecuted {
+                tx_type,
+                who,
+                market_id,
+                price_limit,
+                asset_in,
+                amount_in: if limit_order.is_some() {
+                    amount_in
+                } else {
+                    amount_in.saturating_sub(remaining)
+                },
+                asset_out,
+                amount_out,
+                external_fee_amount,
+                swap_fee_amount,
+            });
+
+            Ok(())
+        }
+
+        /// Runs the routing logic of `do_trade` and returns the executed trades without emitting
+        /// an event. See `do_trade` for the description of the arguments.
+        #[require_transactional]
+        #[allow(clippy::too_many_arguments)]
+        fn execute_trade(
+            tx_type: TxType,
+            who: &AccountIdOf<T>,
+            market_id: MarketIdOf<T>,
+            asset_count: u16,
+            asset: AssetOf<T>,
+            amount_in: BalanceOf<T>,
+            price_limit: BalanceOf<T>,
+            orders: &[OrderId],
+            strategy: Strategy,
+        ) -> Result<TradeExecution<T>, DispatchError> {
             ensure!(amount_in > BalanceOf::<T>::zero(), Error::<T>::AmountIsZero);
             ensure!(
                 price_limit <= PredictionMarketBase::<BalanceOf<T>>::get()?,
@@ -755,7 +840,7 @@ mod pallet {
                 TxType::Buy => (market.base_asset, asset),
                 TxType::Sell => (asset, market.base_asset),
             };
-            T::AssetManager::ensure_can_withdraw(asset_in, &who, amount_in)?;
+            T::AssetManager::ensure_can_withdraw(asset_in, who, amount_in)?;
 
             let mut amm_trades: Vec<AmmTradeOf<T>> = Vec::new();
             let mut remaining = amount_in;
@@ -764,7 +849,7 @@ mod pallet {
                 tx_type,
                 orders,
                 remaining,
-                &who,
+                who,
                 market_id,
                 market.base_asset,
                 asset,
@@ -773,12 +858,12 @@ mod pallet {
 
             remaining = order_amm_trades_info.remaining;
             amm_trades.extend(order_amm_trades_info.amm_trades);
-            let orderbook_trades = order_amm_trades_info.order_trades;
+            let order_trades = order_amm_trades_info.order_trades;
 
             if !remaining.is_zero() {
                 let amm_trade_info = Self::maybe_fill_from_amm(
                     tx_type,
-                    &who,
+                    who,
                     market_id,
                     asset,
                     remaining,
@@ -789,7 +874,7 @@ mod pallet {
                 remaining = amm_trade_info.0;
             }
 
-            let mut limit_order_was_placed = false;
+            let mut limit_order = None;
 
             if !remaining.is_zero() {
                 let (maker_asset, maker_amount, taker_asset, taker_amount) = match tx_type {
@@ -808,46 +893,112 @@ mod pallet {
                         (maker_asset, maker_amount, taker_asset, taker_amount)
                     },
                 };
-                limit_order_was_placed = Self::maybe_place_limit_order(
+                let expiry = strategy.order_expiry();
+                let limit_order_was_placed = Self::maybe_place_limit_order(
                     strategy,
-                    &who,
+                    who,
                     market_id,
                     maker_asset,
                     maker_amount,
                     taker_asset,
                     taker_amount,
                 )?;
+                if limit_order_was_placed {
+                    limit_order = Some(LimitOrderPlacement {
+                        maker_asset,
+                        maker_amount,
+                        taker_asset,
+                        taker_amount,
+                        expiry,
+                    });
+                }
             }
-            let TradeEventInfo { amount_out, external_fee_amount, swap_fee_amount } =
-                Self::get_event_info(&who, &orderbook_trades, &amm_trades)?;
-            Self::deposit_event(Event::HybridRouterExecuted {
-                tx_type,
-                who,
-                market_id,
-                price_limit,
+
+            Ok(TradeExecution {
                 asset_in,
-                amount_in: if limit_order_was_placed {
-                    amount_in
-                } else {
-                    amount_in.saturating_sub(remaining)
-                },
                 asset_out,
+                remaining,
+                order_trades,
+                amm_trades,
+                limit_order,
+            })
+        }
+
+        /// Simulates a trade without changing any state.
+        ///
+        /// The trade is executed exactly like `buy` or `sell` would execute it and then rolled
+        /// back. `who` must hold `amount_in` units of the input asset. Fails with the error the
+        /// actual trade would fail with.
+        #[allow(clippy::too_many_arguments)]
+        pub fn simulate_trade(
+            tx_type: TxType,
+            who: AccountIdOf<T>,
+            market_id: MarketIdOf<T>,
+            asset: AssetOf<T>,
+            amount_in: BalanceOf<T>,
+            price_limit: BalanceOf<T>,
+            orders: Vec<OrderId>,
+            strategy: Strategy,
+        ) -> Result<TradeSimulationOf<T>, DispatchError> {
+            let asset_count = T::MarketCommons::market(&market_id)?
+                .outcome_assets()
+                .len()
+                .saturated_into::<u16>();
+            let execution = with_transaction(|| {
+                TransactionOutcome::Rollback(Self::execute_trade(
+                    tx_type,
+                    &who,
+                    market_id,
+                    asset_count,
+                    asset,
+                    amount_in,
+                    price_limit,
+                    &orders,
+                    strategy,
+                ))
+            })?;
+            let TradeEventInfo { amount_out, external_fee_amount, swap_fee_amount } =
+                Self::get_event_info(&who, &execution.order_trades, &execution.amm_trades)?;
+            let filled_amount_in = amount_in.checked_sub_res(&execution.remaining)?;
+            let average_price = if filled_amount_in.is_zero() || amount_out.is_zero() {
+                Zero::zero()
+            } else {
+                match tx_type {
+                    TxType::Buy => filled_amount_in.bdiv(amount_out)?,
+                    TxType::Sell => amount_out.bdiv(filled_amount_in)?,
+                }
+            };
+            let order_fills = execution
+                .order_trades
+                .into_iter()
+                .map(|(order_id, trade)| OrderFill {
+                    order_id,
+                    filled_maker_amount: trade.filled_maker_amount,
+                    filled_taker_amount: trade.filled_taker_amount,
+                    external_fee: trade.external_fee,
+                })
+                .collect();
+            Ok(TradeSimulation {
+                order_fills,
+                amm_trades: execution.amm_trades,
+                amount_in: filled_amount_in,
                 amount_out,
                 external_fee_amount,
                 swap_fee_amount,
-            });
-
-            Ok(())
+                average_price,
+                remaining: execution.remaining,
+                limit_order: execution.limit_order,
+            })
         }
 
         fn get_event_info(
             who: &AccountIdOf<T>,
-            orderbook_trades: &[OrderTradeOf<T>],
+            orderbook_trades: &[(OrderId, OrderTradeOf<T>)],
             amm_trades: &[AmmTradeOf<T>],
         ) -> Result<TradeEventInfo<T>, DispatchError> {
             orderbook_trades
                 .iter()
-                .map(|trade| Trade::<T>::Orderbook(trade))
+                .map(|(_, trade)| Trade::<T>::Orderbook(trade))
                 .chain(amm_trades.iter().map(|trade| Trade::Amm(*trade)))
                 .try_fold(TradeEventInfo::<T>::new(), |event_info: TradeEventInfo<T>, trade| {
                     Self::update_event_info(who, trade, event_info)
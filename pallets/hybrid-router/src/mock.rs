@@ -32,23 +32,27 @@ use frame_support::{
 };
 use frame_system::{mocking::MockBlock, EnsureRoot, EnsureSignedBy};
 use orml_traits::{asset_registry::AssetProcessor, MultiCurrency};
+use pallet_pm_global_disputes::types::VotingMode;
 use parity_scale_codec::{alloc::sync::Arc, Encode};
 use prediction_market_primitives::{
     constants::mock::{
         AddOutcomePeriod, AggregationPeriod, AppealBond, AppealPeriod, AuthorizedPalletId,
         BlockHashCount, BlocksPerYear, CloseEarlyBlockPeriod, CloseEarlyDisputeBond,
         CloseEarlyProtectionBlockPeriod, CloseEarlyProtectionTimeFramePeriod,
-        CloseEarlyRequestBond, CloseEarlyTimeFramePeriod, CorrectionPeriod, CourtPalletId,
-        ExistentialDeposit, ExistentialDeposits, GdVotingPeriod, GetNativeCurrencyId,
-        GlobalDisputeLockId, GlobalDisputesPalletId, HybridRouterPalletId, InflationPeriod, LockId,
-        MaxAppeals, MaxApprovals, MaxCourtParticipants, MaxCreatorFee, MaxDelegations,
-        MaxDisputeDuration, MaxDisputes, MaxEditReasonLen, MaxGlobalDisputeVotes, MaxGracePeriod,
-        MaxLiquidityTreeDepth, MaxLocks, MaxMarketLifetime, MaxOracleDuration, MaxOrders,
-        MaxOwners, MaxRejectReasonLen, MaxReserves, MaxSelectedDraws, MaxYearlyInflation,
-        MinCategories, MinDisputeDuration, MinJurorStake, MinOracleDuration, MinOutcomeVoteAmount,
-        MinimumPeriod, NeoMaxSwapFee, NeoSwapsPalletId, OrderbookPalletId, OutsiderBond,
-        PmPalletId, RemoveKeysLimit, RequestInterval, TreasuryPalletId, VotePeriod,
-        VotingOutcomeFee, BASE, CENT_BASE, MAX_ASSETS,
+        CloseEarlyRequestBond, CloseEarlyTimeFramePeriod, ConvictionLockPeriod, CorrectionPeriod,
+        CourtPalletId, ExistentialDeposit, ExistentialDeposits, GdVotingPeriod,
+        GetNativeCurrencyId, GlobalDisputeLockId, GlobalDisputesPalletId, HybridRouterPalletId,
+        InflationPeriod, LockId, MaxAppeals, MaxApprovals, MaxCourtParticipants, MaxCreatorFee,
+        MaxDelegations, MaxDisputeDuration, MaxDisputes, MaxEditReasonLen, MaxGdDelegators,
+        MaxGlobalDisputeVotes, MaxGracePeriod, MaxIncentivePrograms, MaxLiquidityTreeDepth,
+        MaxLocks, MaxMarketLifetime, MaxObservations, MaxOracleDuration, MaxOrders,
+        MaxOrdersPerBatch, MaxOrdersPerMaker, MaxOrdersPerMakerPerMarket, MaxOrdersPerMarket,
+        MaxOwners, MaxPanelMembers, MaxRangePositions, MaxRejectReasonLen, MaxReserves,
+        MaxSelectedDraws, MaxSplitDepth, MaxVolumeTiers, MaxYearlyInflation, MinCategories,
+        MinDisputeDuration, MinJurorStake, MinOracleDuration, MinOutcomeVoteAmount, MinimumPeriod,
+        NeoMaxSwapFee, NeoSwapsPalletId, OrderbookPalletId, OutsiderBond, PanelReportPeriod,
+        PmPalletId, RemoveKeysLimit, ReputationInfluence, RequestInterval, TreasuryPalletId,
+        VotePeriod, VotingOutcomeFee, BASE, CENT_BASE, MAX_ASSETS,
     },
     traits::{DistributeFees, NoopLiquidityProvider},
     types::{
@@ -114,6 +118,7 @@ parameter_types! {
     pub const AdvisoryBond: Balance = 0;
     pub const AdvisoryBondSlashPercentage: Percent = Percent::from_percent(10);
     pub const OracleBond: Balance = 0;
+    pub const MultiOracleBond: Balance = 0;
     pub const ValidityBond: Balance = 0;
     pub const DisputeBond: Balance = 0;
     pub const MaxCategories: u16 = MAX_ASSETS + 1;
@@ -121,9 +126,10 @@ parameter_types! {
     pub WinningFeeAccount: TestAccountIdPK = winning_fee_account();
 }
 
-pub fn calculate_fee<T: crate::Config>(_amount: BalanceOf<T>) -> BalanceOf<T> {
+pub fn calculate_fee<T: crate::Config>(amount: BalanceOf<T>) -> BalanceOf<T> {
     pallet_pm_neo_swaps::AdditionalSwapFee::<Runtime>::get()
         .unwrap()
+        .fee_amount(amount.saturated_into(), Perbill::zero())
         .saturated_into()
 }
 
@@ -150,8 +156,19 @@ where
             Err(_) => Zero::zero(),
         }
     }
+
+    fn fee_amount(
+        _market_id: Self::MarketId,
+        _trader: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Self::Balance {
+        calculate_fee::<T>(amount)
+    }
 }
 
+pub type TradingFees =
+    (ExternalFees<Runtime, FeeAccount>, pallet_prediction_markets::MarketCreatorFee<Runtime>);
+
 pub fn calculate_winning_fee<T: crate::Config>(amount: BalanceOf<T>) -> BalanceOf<T> {
     WinnerFeePercentage::get().mul_floor(amount.saturated_into::<BalanceOf<T>>())
 }
@@ -179,6 +196,14 @@ where
             Err(_) => Zero::zero(),
         }
     }
+
+    fn fee_amount(
+        _market_id: Self::MarketId,
+        _trader: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Self::Balance {
+        calculate_winning_fee::<T>(amount)
+    }
 }
 
 pub struct DustRemovalWhitelist;
@@ -240,21 +265,32 @@ impl pallet_avn::Config for Runtime {
 
 impl pallet_pm_order_book::Config for Runtime {
     type AssetManager = AssetManager;
-    type ExternalFees = ExternalFees<Runtime, FeeAccount>;
+    type ExternalFees = TradingFees;
     type RuntimeEvent = RuntimeEvent;
     type MarketCommons = MarketCommons;
+    type MaxOrdersPerMarket = MaxOrdersPerMarket;
+    type MaxOrdersPerMaker = MaxOrdersPerMaker;
+    type MaxOrdersPerMakerPerMarket = MaxOrdersPerMakerPerMarket;
+    type MaxOrdersPerBatch = MaxOrdersPerBatch;
     type PalletId = OrderbookPalletId;
     type WeightInfo = pallet_pm_order_book::weights::WeightInfo<Runtime>;
+    type RuntimeCall = RuntimeCall;
+    type Public = TestAccountIdPK;
+    type Signature = SignatureTest;
 }
 
 impl pallet_pm_neo_swaps::Config for Runtime {
     type MultiCurrency = AssetManager;
     type CompleteSetOperations = PredictionMarkets;
-    type ExternalFees = ExternalFees<Runtime, FeeAccount>;
+    type ExternalFees = TradingFees;
     type MarketCommons = MarketCommons;
     type RuntimeEvent = RuntimeEvent;
+    type MaxIncentivePrograms = MaxIncentivePrograms;
     type MaxLiquidityTreeDepth = MaxLiquidityTreeDepth;
+    type MaxObservations = MaxObservations;
+    type MaxRangePositions = MaxRangePositions;
     type MaxSwapFee = NeoMaxSwapFee;
+    type MaxVolumeTiers = MaxVolumeTiers;
     type PalletId = NeoSwapsPalletId;
     type WeightInfo = pallet_pm_neo_swaps::weights::WeightInfo<Runtime>;
     type SignedTxLifetime = ConstU32<16>;
@@ -299,7 +335,9 @@ impl pallet_prediction_markets::Config for Runtime {
     type MinCategories = MinCategories;
     type MaxEditReasonLen = MaxEditReasonLen;
     type MaxRejectReasonLen = MaxRejectReasonLen;
+    type MaxSplitDepth = MaxSplitDepth;
     type OracleBond = OracleBond;
+    type MultiOracleBond = MultiOracleBond;
     type OutsiderBond = OutsiderBond;
     type PalletId = PmPalletId;
     type RejectOrigin = EnsureSignedBy<Sudo, TestAccountIdPK>;
@@ -325,7 +363,9 @@ impl pallet_pm_authorized::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DisputeResolution = pallet_prediction_markets::Pallet<Runtime>;
     type MarketCommons = MarketCommons;
+    type MaxPanelMembers = MaxPanelMembers;
     type PalletId = AuthorizedPalletId;
+    type PanelReportPeriod = PanelReportPeriod;
     type WeightInfo = pallet_pm_authorized::weights::WeightInfo<Runtime>;
 }
 
@@ -349,8 +389,12 @@ impl pallet_pm_court::Config for Runtime {
     type MinJurorStake = MinJurorStake;
     type MonetaryGovernanceOrigin = EnsureRoot<TestAccountIdPK>;
     type PalletId = CourtPalletId;
+    type Public = TestAccountIdPK;
     type Random = RandomnessCollectiveFlip;
+    type ReputationInfluence = ReputationInfluence;
     type RequestInterval = RequestInterval;
+    type RuntimeCall = RuntimeCall;
+    type Signature = SignatureTest;
     type Slash = Treasury;
     type TreasuryPalletId = TreasuryPalletId;
     type WeightInfo = pallet_pm_court::weights::WeightInfo<Runtime>;
@@ -456,20 +500,27 @@ impl pallet_timestamp::Config for Runtime {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const GdVotingMode: VotingMode = VotingMode::Linear;
+}
+
 impl pallet_pm_global_disputes::Config for Runtime {
     type AddOutcomePeriod = AddOutcomePeriod;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
     type RuntimeEvent = RuntimeEvent;
     type DisputeResolution = pallet_prediction_markets::Pallet<Runtime>;
     type MarketCommons = MarketCommons;
     type Currency = Balances;
     type GlobalDisputeLockId = GlobalDisputeLockId;
     type GlobalDisputesPalletId = GlobalDisputesPalletId;
+    type MaxDelegators = MaxGdDelegators;
     type MaxGlobalDisputeVotes = MaxGlobalDisputeVotes;
     type MaxOwners = MaxOwners;
     type MinOutcomeVoteAmount = MinOutcomeVoteAmount;
     type RemoveKeysLimit = RemoveKeysLimit;
     type GdVotingPeriod = GdVotingPeriod;
     type VotingOutcomeFee = VotingOutcomeFee;
+    type VotingMode = GdVotingMode;
     type WeightInfo = pallet_pm_global_disputes::weights::WeightInfo<Runtime>;
 }
 
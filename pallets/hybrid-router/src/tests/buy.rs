@@ -16,7 +16,8 @@
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use prediction_market_primitives::types::Asset;
+use pallet_prediction_markets::Event as PredictionMarketsEvent;
+use prediction_market_primitives::{orderbook::OrderExpiry, types::Asset};
 
 #[test]
 fn buy_from_amm_and_then_fill_specified_order() {
@@ -48,6 +49,7 @@ fn buy_from_amm_and_then_fill_specified_order() {
             order_maker_amount,
             BASE_ASSET,
             order_taker_amount,
+            None,
         ));
 
         let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
@@ -70,9 +72,11 @@ fn buy_from_amm_and_then_fill_specified_order() {
             NeoSwapsEvent::<Runtime>::BuyExecuted {
                 who: alice(),
                 market_id,
+                parent_collection_id: None,
                 asset_out: asset,
                 amount_in: amm_amount_in,
                 amount_out: 5606655193,
+                swap_fee,
                 swap_fee_amount: 28043285,
                 external_fee_amount: 1000000,
             }
@@ -92,6 +96,7 @@ fn buy_from_amm_and_then_fill_specified_order() {
                 maker_amount: 85608657084,
                 taker_asset: BASE_ASSET,
                 taker_amount: unfilled_base_asset_amount,
+                expiry: None,
             }
         );
         let filled_base_asset_amount = order_taker_amount - unfilled_base_asset_amount;
@@ -100,6 +105,76 @@ fn buy_from_amm_and_then_fill_specified_order() {
     });
 }
 
+#[test]
+fn buy_pays_creator_fee_on_amm_trade_and_order_fill() {
+    ExtBuilder::default().build().execute_with(|| {
+        let pivot = _1_100;
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2 - pivot, _1_2 + pivot],
+            CENT_BASE,
+        );
+        assert_ok!(MarketCommons::mutate_market(&market_id, |market| {
+            market.creator_fee = Perbill::from_percent(1);
+            Ok(())
+        }));
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        assert_ok!(AssetManager::deposit(asset, &charlie(), _12));
+        assert_ok!(Orderbook::place_order(
+            RuntimeOrigin::signed(charlie()),
+            market_id,
+            asset,
+            _12,
+            BASE_ASSET,
+            _6,
+            None,
+        ));
+        let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
+
+        let amount_in = _2;
+        assert_ok!(AssetManager::deposit(BASE_ASSET, &dave(), amount_in));
+        let creator_balance = AssetManager::free_balance(BASE_ASSET, &alice());
+        assert_ok!(HybridRouter::buy(
+            RuntimeOrigin::signed(dave()),
+            market_id,
+            2,
+            asset,
+            amount_in,
+            _3_4,
+            order_ids,
+            Strategy::LimitOrder,
+        ));
+
+        let creator_fees = System::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                RuntimeEvent::PredictionMarkets(PredictionMarketsEvent::CreatorFeePaid {
+                    creator,
+                    trader,
+                    amount,
+                    ..
+                }) => {
+                    assert_eq!(creator, alice());
+                    assert_eq!(trader, dave());
+                    Some(amount)
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        // One fee is paid on the AMM trade and one on the order fill.
+        assert_eq!(creator_fees.len(), 2);
+        assert!(creator_fees.iter().all(|&amount| amount > 0));
+        assert_eq!(
+            AssetManager::free_balance(BASE_ASSET, &alice()),
+            creator_balance + creator_fees.iter().sum::<BalanceOf<Runtime>>()
+        );
+    });
+}
+
 #[test]
 fn buy_from_amm_if_specified_order_has_higher_prices_than_the_amm() {
     ExtBuilder::default().build().execute_with(|| {
@@ -129,6 +204,7 @@ fn buy_from_amm_if_specified_order_has_higher_prices_than_the_amm() {
             order_maker_amount,
             BASE_ASSET,
             order_taker_amount,
+            None,
         ));
 
         let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
@@ -158,6 +234,7 @@ fn buy_from_amm_if_specified_order_has_higher_prices_than_the_amm() {
                 maker_amount: _4,
                 taker_asset: BASE_ASSET,
                 taker_amount: _2,
+                expiry: None,
             }
         );
     });
@@ -192,6 +269,7 @@ fn buy_fill_multiple_orders_if_amm_spot_price_higher_than_order_prices() {
             order_maker_amount,
             BASE_ASSET,
             order_taker_amount,
+            None,
         ));
         assert_ok!(Orderbook::place_order(
             RuntimeOrigin::signed(charlie()),
@@ -200,6 +278,7 @@ fn buy_fill_multiple_orders_if_amm_spot_price_higher_than_order_prices() {
             order_maker_amount,
             BASE_ASSET,
             order_taker_amount,
+            None,
         ));
 
         let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
@@ -251,6 +330,7 @@ fn buy_fill_specified_order_partially_if_amm_spot_price_higher() {
             order_maker_amount,
             BASE_ASSET,
             order_taker_amount,
+            None,
         ));
 
         let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
@@ -281,6 +361,7 @@ fn buy_fill_specified_order_partially_if_amm_spot_price_higher() {
                 maker_amount: _4,
                 taker_asset: BASE_ASSET,
                 taker_amount: _2,
+                expiry: None,
             }
         );
     });
@@ -314,6 +395,7 @@ fn buy_fails_if_asset_not_equal_to_order_book_maker_asset() {
             order_maker_amount,
             Asset::CategoricalOutcome(market_id, 0),
             _2,
+            None,
         ));
 
         let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
@@ -367,6 +449,7 @@ fn buy_fails_if_order_price_above_max_price() {
             order_maker_amount,
             BASE_ASSET,
             _2,
+            None,
         ));
 
         let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
@@ -428,9 +511,11 @@ fn buy_from_amm() {
             NeoSwapsEvent::<Runtime>::BuyExecuted {
                 who: alice(),
                 market_id,
+                parent_collection_id: None,
                 asset_out: asset,
                 amount_in: 20000000000,
                 amount_out: 37205851586,
+                swap_fee,
                 swap_fee_amount: 200000000,
                 external_fee_amount: 1000000,
             }
@@ -488,11 +573,97 @@ fn buy_max_price_lower_than_amm_spot_price_results_in_place_order() {
                 maker_amount: _2,
                 taker_asset: asset,
                 taker_amount: _4,
+                expiry: None,
             }
         );
     });
 }
 
+#[test]
+fn buy_with_good_til_block_places_order_with_expiry() {
+    ExtBuilder::default().build().execute_with(|| {
+        let liquidity = _10;
+        let spot_prices = vec![_1_2 + 1u128, _1_2 - 1u128];
+        let swap_fee = CENT_BASE;
+        let asset_count = 2u16;
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            liquidity,
+            spot_prices,
+            swap_fee,
+        );
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let max_price = (_1_2).saturated_into::<BalanceOf<Runtime>>();
+        assert_ok!(HybridRouter::buy(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            asset_count,
+            asset,
+            _2,
+            max_price,
+            vec![],
+            Strategy::GoodTilBlock(10),
+        ));
+
+        let orders = Orders::<Runtime>::iter().map(|(_, o)| o).collect::<Vec<_>>();
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].expiry, Some(OrderExpiry::Block(10)));
+    });
+}
+
+#[test]
+fn buy_ignores_expired_orders() {
+    ExtBuilder::default().build().execute_with(|| {
+        let liquidity = _10;
+        let pivot = _1_100;
+        let spot_prices = vec![_1_2 - pivot, _1_2 + pivot];
+        let swap_fee = CENT_BASE;
+        let asset_count = 2u16;
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            liquidity,
+            spot_prices,
+            swap_fee,
+        );
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        assert_ok!(AssetManager::deposit(asset, &charlie(), _12));
+        assert_ok!(Orderbook::place_order(
+            RuntimeOrigin::signed(charlie()),
+            market_id,
+            asset,
+            _12,
+            BASE_ASSET,
+            _6,
+            Some(OrderExpiry::Block(1)),
+        ));
+        let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
+        let order = Orders::<Runtime>::get(order_ids[0]).unwrap();
+        let charlie_balance = AssetManager::free_balance(BASE_ASSET, &charlie());
+
+        System::set_block_number(2);
+        let max_price = _3_4.saturated_into::<BalanceOf<Runtime>>();
+        assert_ok!(HybridRouter::buy(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            asset_count,
+            asset,
+            _2,
+            max_price,
+            order_ids.clone(),
+            Strategy::ImmediateOrCancel,
+        ));
+
+        assert_eq!(Orders::<Runtime>::get(order_ids[0]), Some(order));
+        assert_eq!(AssetManager::free_balance(BASE_ASSET, &charlie()), charlie_balance);
+    });
+}
+
 #[test]
 fn buy_from_amm_but_low_amount() {
     ExtBuilder::default().build().execute_with(|| {
@@ -533,9 +704,11 @@ fn buy_from_amm_but_low_amount() {
             NeoSwapsEvent::<Runtime>::BuyExecuted {
                 who: alice(),
                 market_id,
+                parent_collection_id: None,
                 asset_out: asset,
                 amount_in: 29,
                 amount_out: 58,
+                swap_fee,
                 swap_fee_amount: 0,
                 external_fee_amount: 0,
             }
@@ -555,6 +728,7 @@ fn buy_from_amm_but_low_amount() {
                 maker_amount: 19999999971,
                 taker_asset: asset,
                 taker_amount: 39999999935,
+                expiry: None,
             }
         );
     });
@@ -596,9 +770,11 @@ fn buy_from_amm_only() {
             NeoSwapsEvent::<Runtime>::BuyExecuted {
                 who: alice(),
                 market_id,
+                parent_collection_id: None,
                 asset_out: asset,
                 amount_in: 20000000000,
                 amount_out: 37205851586,
+                swap_fee,
                 swap_fee_amount: 200000000,
                 external_fee_amount: 1000000,
             }
@@ -653,6 +829,7 @@ fn buy_places_limit_order_no_pool() {
                 maker_amount: 10 * BASE,
                 taker_asset: asset,
                 taker_amount: 20 * BASE,
+                expiry: None,
             }
         );
     });
@@ -737,6 +914,7 @@ fn buy_emits_event() {
                 maker_amount,
                 taker_asset,
                 taker_amount,
+                None,
             ));
         }
 
@@ -918,6 +1096,7 @@ fn buy_succeeds_for_numerical_soft_failure() {
                 maker_amount: _1_100,
                 taker_asset: Asset::CategoricalOutcome(market_id, 0),
                 taker_amount: 133333334,
+                expiry: None,
             }
         );
     });
@@ -951,6 +1130,7 @@ fn buy_just_one_unit_from_amm() {
             order_maker_amount,
             BASE_ASSET,
             _1,
+            None,
         ));
 
         let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
@@ -983,6 +1163,7 @@ fn buy_just_one_unit_from_amm() {
                 maker_amount: _2,
                 taker_asset: BASE_ASSET,
                 taker_amount: _1,
+                expiry: None,
             }
         );
     });
@@ -1016,6 +1197,7 @@ fn buy_succeeds_for_fill_order_below_minimum_balance_soft_failure() {
             order_maker_amount,
             BASE_ASSET,
             _1,
+            None,
         ));
 
         let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
@@ -1048,6 +1230,7 @@ fn buy_succeeds_for_fill_order_below_minimum_balance_soft_failure() {
                 maker_amount: _2,
                 taker_asset: BASE_ASSET,
                 taker_amount: _1,
+                expiry: None,
             }
         );
     });
@@ -1081,6 +1264,7 @@ fn buy_succeeds_for_place_order_partial_fill_near_full_fill_not_allowed() {
             order_maker_amount,
             BASE_ASSET,
             _1,
+            None,
         ));
 
         let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
@@ -1111,6 +1295,7 @@ fn buy_succeeds_for_place_order_partial_fill_near_full_fill_not_allowed() {
                 maker_amount: _2,
                 taker_asset: BASE_ASSET,
                 taker_amount: _1,
+                expiry: None,
             }
         );
     });
@@ -1144,6 +1329,7 @@ fn buy_only_executes_first_order_from_orders_vector() {
             order_maker_amount,
             BASE_ASSET,
             _1,
+            None,
         ));
 
         let order_maker_amount = _2;
@@ -1155,6 +1341,7 @@ fn buy_only_executes_first_order_from_orders_vector() {
             order_maker_amount,
             BASE_ASSET,
             _1,
+            None,
         ));
 
         let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
@@ -1188,6 +1375,7 @@ fn buy_only_executes_first_order_from_orders_vector() {
                 maker_amount: _2,
                 taker_asset: BASE_ASSET,
                 taker_amount: _1,
+                expiry: None,
             }
         );
     });
@@ -1233,6 +1421,7 @@ fn buy_skips_fill_order_if_order_not_present_and_places_new_order() {
                 maker_amount: 10 * BASE,
                 taker_asset: Asset::CategoricalOutcome(market_id, 0),
                 taker_amount: 20 * BASE,
+                expiry: None,
             }
         );
     });
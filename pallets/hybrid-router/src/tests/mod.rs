@@ -39,6 +39,7 @@ pub use sp_runtime::{traits::Hash, Perbill, SaturatedConversion};
 
 mod buy;
 mod sell;
+mod simulate;
 
 const BASE_ASSET: Asset<MarketId> = FOREIGN_ASSET;
 
@@ -48,6 +48,7 @@ fn sell_to_amm_and_then_fill_specified_order() {
             order_maker_amount,
             asset,
             order_taker_amount,
+            None,
         ));
 
         let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
@@ -72,9 +73,11 @@ fn sell_to_amm_and_then_fill_specified_order() {
             NeoSwapsEvent::<Runtime>::SellExecuted {
                 who: alice(),
                 market_id,
+                parent_collection_id: None,
                 asset_in: asset,
                 amount_in: amm_amount_in,
                 amount_out: 2802768611,
+                swap_fee,
                 swap_fee_amount: 28320895,
                 external_fee_amount: 1000000,
             }
@@ -94,6 +97,7 @@ fn sell_to_amm_and_then_fill_specified_order() {
                 maker_amount: 52804047165,
                 taker_asset: Asset::CategoricalOutcome(market_id, 0),
                 taker_amount: unfilled_base_asset_amount,
+                expiry: None,
             }
         );
         let filled_base_asset_amount = order_taker_amount - unfilled_base_asset_amount;
@@ -131,6 +135,7 @@ fn sell_to_amm_if_specified_order_has_lower_prices_than_the_amm() {
             order_maker_amount,
             asset,
             order_taker_amount,
+            None,
         ));
 
         let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
@@ -164,6 +169,7 @@ fn sell_to_amm_if_specified_order_has_lower_prices_than_the_amm() {
                 maker_amount: _1,
                 taker_asset: Asset::CategoricalOutcome(market_id, 0),
                 taker_amount: _2,
+                expiry: None,
             }
         );
     });
@@ -198,6 +204,7 @@ fn sell_fill_multiple_orders_if_amm_spot_price_lower_than_order_prices() {
             order_maker_amount,
             asset,
             order_taker_amount,
+            None,
         ));
         assert_ok!(Orderbook::place_order(
             RuntimeOrigin::signed(charlie()),
@@ -206,6 +213,7 @@ fn sell_fill_multiple_orders_if_amm_spot_price_lower_than_order_prices() {
             order_maker_amount,
             asset,
             order_taker_amount,
+            None,
         ));
 
         let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
@@ -259,6 +267,7 @@ fn sell_fill_specified_order_partially_if_amm_spot_price_lower() {
             order_maker_amount,
             asset,
             order_taker_amount,
+            None,
         ));
 
         let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
@@ -291,6 +300,7 @@ fn sell_fill_specified_order_partially_if_amm_spot_price_lower() {
                 maker_amount: _3,
                 taker_asset: Asset::CategoricalOutcome(market_id, 0),
                 taker_amount: _6,
+                expiry: None,
             }
         );
     });
@@ -325,6 +335,7 @@ fn sell_fails_if_asset_not_equal_to_order_book_taker_asset() {
             maker_amount,
             BASE_ASSET,
             amount_in,
+            None,
         ));
 
         let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
@@ -380,6 +391,7 @@ fn sell_fails_if_order_price_below_min_price() {
             order_maker_amount,
             Asset::CategoricalOutcome(market_id, 0),
             amount,
+            None,
         ));
 
         let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
@@ -446,9 +458,11 @@ fn sell_to_amm() {
             NeoSwapsEvent::<Runtime>::SellExecuted {
                 who: alice(),
                 market_id,
+                parent_collection_id: None,
                 asset_in: asset,
                 amount_in: 20000000000,
                 amount_out: 9556166539,
+                swap_fee,
                 swap_fee_amount: 96537036,
                 external_fee_amount: 1000000,
             }
@@ -509,6 +523,7 @@ fn sell_min_price_higher_than_amm_spot_price_results_in_place_order() {
                 maker_amount: _2,
                 taker_asset: base_asset,
                 taker_amount: _1,
+                expiry: None,
             }
         );
     });
@@ -557,9 +572,11 @@ fn sell_to_amm_but_low_amount() {
             NeoSwapsEvent::<Runtime>::SellExecuted {
                 who: alice(),
                 market_id,
+                parent_collection_id: None,
                 asset_in: asset,
                 amount_in: 58,
                 amount_out: 29,
+                swap_fee,
                 swap_fee_amount: 0,
                 external_fee_amount: 0,
             }
@@ -579,6 +596,7 @@ fn sell_to_amm_but_low_amount() {
                 maker_amount: 19999999942,
                 taker_asset: base_asset,
                 taker_amount: 9999999969,
+                expiry: None,
             }
         );
     });
@@ -629,6 +647,7 @@ fn sell_succeeds_for_numerical_soft_failure() {
                 maker_amount: _1000 * 100,
                 taker_asset: BASE_ASSET,
                 taker_amount: _1,
+                expiry: None,
             }
         );
     });
@@ -673,9 +692,11 @@ fn sell_to_amm_only() {
             NeoSwapsEvent::<Runtime>::SellExecuted {
                 who: alice(),
                 market_id,
+                parent_collection_id: None,
                 asset_in: asset,
                 amount_in: 20000000000,
                 amount_out: 9556166539,
+                swap_fee,
                 swap_fee_amount: 96537036,
                 external_fee_amount: 1000000,
             }
@@ -733,6 +754,7 @@ fn sell_places_limit_order_no_pool() {
                 maker_amount: 10 * BASE,
                 taker_asset: base_asset,
                 taker_amount: 5 * BASE,
+                expiry: None,
             }
         );
     });
@@ -814,6 +836,7 @@ fn sell_emits_event() {
                 maker_amount,
                 taker_asset,
                 taker_amount,
+                None,
             ));
         }
 
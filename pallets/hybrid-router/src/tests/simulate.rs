@@ -0,0 +1,251 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use prediction_market_primitives::{
+    hybrid_router_api_types::LimitOrderPlacement, math::fixed::FixedDiv,
+};
+
+#[test]
+fn simulate_buy_matches_executed_buy() {
+    ExtBuilder::default().build().execute_with(|| {
+        let liquidity = _10;
+        let pivot = _1_100;
+        let spot_prices = vec![_1_2 - pivot, _1_2 + pivot];
+        let swap_fee = CENT_BASE;
+        let asset_count = 2u16;
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            liquidity,
+            spot_prices.clone(),
+            swap_fee,
+        );
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let amount_in = _2;
+        assert_ok!(AssetManager::deposit(asset, &charlie(), _12));
+        assert_ok!(Orderbook::place_order(
+            RuntimeOrigin::signed(charlie()),
+            market_id,
+            asset,
+            _12,
+            BASE_ASSET,
+            _6,
+            None,
+        ));
+
+        let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
+        let order_before = Orders::<Runtime>::get(order_ids[0]).unwrap();
+        let balance_before = AssetManager::free_balance(BASE_ASSET, &alice());
+        let max_price = _3_4.saturated_into::<BalanceOf<Runtime>>();
+        let strategy = Strategy::LimitOrder;
+        let simulation = HybridRouter::simulate_trade(
+            TxType::Buy,
+            alice(),
+            market_id,
+            asset,
+            amount_in,
+            max_price,
+            order_ids.clone(),
+            strategy,
+        )
+        .unwrap();
+
+        assert_eq!(Orders::<Runtime>::get(order_ids[0]), Some(order_before));
+        assert_eq!(AssetManager::free_balance(BASE_ASSET, &alice()), balance_before);
+        assert_eq!(simulation.order_fills.len(), 1);
+        assert_eq!(simulation.order_fills[0].order_id, order_ids[0]);
+        assert_eq!(simulation.order_fills[0].filled_taker_amount, 17195671458);
+        assert_eq!(simulation.amm_trades.len(), 1);
+        assert_eq!(simulation.amm_trades[0].amount_in, 2804328542);
+        assert_eq!(simulation.amount_in, amount_in);
+        assert_eq!(simulation.remaining, 0);
+        assert_eq!(simulation.limit_order, None);
+        assert_eq!(
+            simulation.average_price,
+            simulation.amount_in.bdiv(simulation.amount_out).unwrap()
+        );
+
+        assert_ok!(HybridRouter::buy(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            asset_count,
+            asset,
+            amount_in,
+            max_price,
+            order_ids,
+            strategy,
+        ));
+        System::assert_last_event(
+            Event::<Runtime>::HybridRouterExecuted {
+                tx_type: TxType::Buy,
+                who: alice(),
+                market_id,
+                price_limit: max_price,
+                asset_in: BASE_ASSET,
+                amount_in,
+                asset_out: asset,
+                amount_out: simulation.amount_out,
+                external_fee_amount: simulation.external_fee_amount,
+                swap_fee_amount: simulation.swap_fee_amount,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn simulate_sell_matches_executed_sell() {
+    ExtBuilder::default().build().execute_with(|| {
+        let liquidity = _10;
+        let spot_prices = vec![_1_2, _1_2];
+        let swap_fee = CENT_BASE;
+        let asset_count = 2u16;
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            liquidity,
+            spot_prices,
+            swap_fee,
+        );
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let amount_in = _1;
+        assert_ok!(AssetManager::deposit(asset, &alice(), amount_in));
+
+        let min_price = _1_4.saturated_into::<BalanceOf<Runtime>>();
+        let strategy = Strategy::ImmediateOrCancel;
+        let simulation = HybridRouter::simulate_trade(
+            TxType::Sell,
+            alice(),
+            market_id,
+            asset,
+            amount_in,
+            min_price,
+            vec![],
+            strategy,
+        )
+        .unwrap();
+
+        assert!(simulation.order_fills.is_empty());
+        assert_eq!(simulation.amm_trades.len(), 1);
+        assert_eq!(simulation.remaining, 0);
+        assert_eq!(
+            simulation.average_price,
+            simulation.amount_out.bdiv(simulation.amount_in).unwrap()
+        );
+
+        assert_ok!(HybridRouter::sell(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            asset_count,
+            asset,
+            amount_in,
+            min_price,
+            vec![],
+            strategy,
+        ));
+        System::assert_last_event(
+            Event::<Runtime>::HybridRouterExecuted {
+                tx_type: TxType::Sell,
+                who: alice(),
+                market_id,
+                price_limit: min_price,
+                asset_in: asset,
+                amount_in,
+                asset_out: BASE_ASSET,
+                amount_out: simulation.amount_out,
+                external_fee_amount: simulation.external_fee_amount,
+                swap_fee_amount: simulation.swap_fee_amount,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn simulate_buy_returns_limit_order_for_remaining_amount() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0;
+        let mut market = market_mock::<Runtime>(market_creator());
+        let base_asset = market.base_asset;
+        market.status = MarketStatus::Active;
+        Markets::<Runtime>::insert(market_id, market);
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let amount_in = 10 * BASE;
+        let max_price = (BASE / 2).saturated_into::<BalanceOf<Runtime>>();
+        let simulation = HybridRouter::simulate_trade(
+            TxType::Buy,
+            alice(),
+            market_id,
+            asset,
+            amount_in,
+            max_price,
+            vec![],
+            Strategy::LimitOrder,
+        )
+        .unwrap();
+
+        assert_eq!(Orders::<Runtime>::iter().count(), 0);
+        assert!(simulation.order_fills.is_empty());
+        assert!(simulation.amm_trades.is_empty());
+        assert_eq!(simulation.amount_in, 0);
+        assert_eq!(simulation.amount_out, 0);
+        assert_eq!(simulation.average_price, 0);
+        assert_eq!(simulation.remaining, amount_in);
+        assert_eq!(
+            simulation.limit_order,
+            Some(LimitOrderPlacement {
+                maker_asset: base_asset,
+                maker_amount: 10 * BASE,
+                taker_asset: asset,
+                taker_amount: 20 * BASE,
+                expiry: None,
+            })
+        );
+    });
+}
+
+#[test]
+fn simulate_buy_fails_if_cancel_strategy_applied() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0;
+        let mut market = market_mock::<Runtime>(market_creator());
+        market.status = MarketStatus::Active;
+        Markets::<Runtime>::insert(market_id, market);
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let max_price = (BASE / 2).saturated_into::<BalanceOf<Runtime>>();
+        assert_eq!(
+            HybridRouter::simulate_trade(
+                TxType::Buy,
+                alice(),
+                market_id,
+                asset,
+                10 * BASE,
+                max_price,
+                vec![],
+                Strategy::ImmediateOrCancel,
+            ),
+            Err(Error::<Runtime>::CancelStrategyApplied.into())
+        );
+    });
+}
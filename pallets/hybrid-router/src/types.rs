@@ -15,41 +15,17 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AmmTradeOf, BalanceOf, Config, OrderTradeOf};
+use crate::{AmmTradeOf, AssetOf, BalanceOf, Config, LimitOrderPlacementOf, OrderTradeOf};
 use alloc::vec::Vec;
 use frame_support::pallet_prelude::*;
-use prediction_market_primitives::math::checked_ops_res::{CheckedAddRes, CheckedSubRes};
+pub use prediction_market_primitives::hybrid_router_api_types::{Strategy, TxType};
+use prediction_market_primitives::{
+    math::checked_ops_res::{CheckedAddRes, CheckedSubRes},
+    orderbook::OrderId,
+};
 use scale_info::TypeInfo;
 use sp_runtime::{traits::Zero, DispatchError};
 
-/// Represents the strategy used when placing an order in a trading environment.
-#[derive(
-    Copy,
-    Clone,
-    Eq,
-    PartialEq,
-    Ord,
-    PartialOrd,
-    Encode,
-    Decode,
-    RuntimeDebug,
-    MaxEncodedLen,
-    TypeInfo,
-)]
-pub enum Strategy {
-    /// The trade is rolled back if it cannot be executed fully.
-    ImmediateOrCancel,
-    /// Partially fulfills the order if possible, placing the remainder in the order book. Favors
-    /// achieving a specific price rather than immediate execution.
-    LimitOrder,
-}
-
-#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, TypeInfo)]
-pub enum TxType {
-    Buy,
-    Sell,
-}
-
 #[derive(Clone, Debug, Decode, Encode, PartialEq, TypeInfo)]
 pub enum Trade<'a, T: Config> {
     Orderbook(&'a OrderTradeOf<T>),
@@ -95,6 +71,16 @@ impl<T: Config> TradeEventInfo<T> {
 
 pub struct OrderAmmTradesInfo<T: Config> {
     pub remaining: BalanceOf<T>,
-    pub order_trades: Vec<OrderTradeOf<T>>,
+    pub order_trades: Vec<(OrderId, OrderTradeOf<T>)>,
+    pub amm_trades: Vec<AmmTradeOf<T>>,
+}
+
+/// Everything `do_trade` executed, used to emit the trade event or to report a simulation.
+pub struct TradeExecution<T: Config> {
+    pub asset_in: AssetOf<T>,
+    pub asset_out: AssetOf<T>,
+    pub remaining: BalanceOf<T>,
+    pub order_trades: Vec<(OrderId, OrderTradeOf<T>)>,
     pub amm_trades: Vec<AmmTradeOf<T>>,
+    pub limit_order: Option<LimitOrderPlacementOf<T>>,
 }
@@ -15,3 +15,137 @@
 //
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use frame_support::{
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{Decode, Encode};
+use prediction_market_primitives::types::{
+    Asset, Bond, Deadlines, EarlyClose, Market, MarketBonds, MarketCreation,
+    MarketDisputeMechanism, MarketPeriod, MarketStatus, MarketType, OutcomeReport, Report,
+    ScoringRule,
+};
+use scale_info::TypeInfo;
+use sp_arithmetic::per_things::Perbill;
+use sp_runtime::RuntimeDebug;
+#[cfg(feature = "try-runtime")]
+use {frame_support::ensure, sp_runtime::TryRuntimeError};
+
+const LOG_TARGET: &str = "runtime::pallet-pm-market-commons";
+
+/// The layout of market bonds before the bonds of multi-oracle markets were added.
+#[derive(Clone, Decode, Encode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OldMarketBonds<AccountId, Balance> {
+    pub creation: Option<Bond<AccountId, Balance>>,
+    pub oracle: Option<Bond<AccountId, Balance>>,
+    pub outsider: Option<Bond<AccountId, Balance>>,
+    pub dispute: Option<Bond<AccountId, Balance>>,
+    pub close_request: Option<Bond<AccountId, Balance>>,
+    pub close_dispute: Option<Bond<AccountId, Balance>>,
+}
+
+/// The layout of markets before the bonds of multi-oracle markets were added.
+#[derive(Clone, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct OldMarket<AccountId, Balance, BlockNumber, Moment, MarketId> {
+    pub market_id: MarketId,
+    pub base_asset: Asset<MarketId>,
+    pub creator: AccountId,
+    pub creation: MarketCreation,
+    pub creator_fee: Perbill,
+    pub oracle: AccountId,
+    pub metadata: Vec<u8>,
+    pub market_type: MarketType,
+    pub period: MarketPeriod<BlockNumber, Moment>,
+    pub deadlines: Deadlines<BlockNumber>,
+    pub scoring_rule: ScoringRule,
+    pub status: MarketStatus,
+    pub report: Option<Report<AccountId, BlockNumber>>,
+    pub resolved_outcome: Option<OutcomeReport>,
+    pub dispute_mechanism: Option<MarketDisputeMechanism>,
+    pub bonds: OldMarketBonds<AccountId, Balance>,
+    pub early_close: Option<EarlyClose<BlockNumber, Moment>>,
+}
+
+type OldMarketOf<T> =
+    OldMarket<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>, MomentOf<T>, MarketIdOf<T>>;
+
+/// Adds empty multi-oracle bonds to the bonds of all existing `Markets`.
+pub struct AddMultiOracleBonds<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for AddMultiOracleBonds<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let onchain = Pallet::<T>::on_chain_storage_version();
+        if onchain != 13 {
+            log::info!(
+                target: LOG_TARGET,
+                "Skipping AddMultiOracleBonds, on-chain storage version is {:?}",
+                onchain
+            );
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut market_count: u64 = 0;
+        <Markets<T>>::translate_values::<OldMarketOf<T>, _>(|old_market| {
+            market_count = market_count.saturating_add(1);
+            let bonds = old_market.bonds;
+            Some(Market {
+                market_id: old_market.market_id,
+                base_asset: old_market.base_asset,
+                creator: old_market.creator,
+                creation: old_market.creation,
+                creator_fee: old_market.creator_fee,
+                oracle: old_market.oracle,
+                metadata: old_market.metadata,
+                market_type: old_market.market_type,
+                period: old_market.period,
+                deadlines: old_market.deadlines,
+                scoring_rule: old_market.scoring_rule,
+                status: old_market.status,
+                report: old_market.report,
+                resolved_outcome: old_market.resolved_outcome,
+                dispute_mechanism: old_market.dispute_mechanism,
+                bonds: MarketBonds {
+                    creation: bonds.creation,
+                    oracle: bonds.oracle,
+                    outsider: bonds.outsider,
+                    dispute: bonds.dispute,
+                    close_request: bonds.close_request,
+                    close_dispute: bonds.close_dispute,
+                    oracles: Default::default(),
+                },
+                early_close: old_market.early_close,
+            })
+        });
+
+        StorageVersion::new(14).put::<Pallet<T>>();
+        log::info!(target: LOG_TARGET, "Added empty oracle bonds to {} markets", market_count);
+
+        T::DbWeight::get()
+            .reads_writes(market_count.saturating_add(1), market_count.saturating_add(1))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let market_count = <Markets<T>>::iter_keys().count() as u64;
+        Ok(market_count.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(input: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let old_market_count: u64 =
+            Decode::decode(&mut &input[..]).map_err(|_| "failed to decode the market count")?;
+        let market_count = <Markets<T>>::iter().count() as u64;
+        ensure!(market_count == old_market_count, "markets failed to decode after the migration");
+        ensure!(
+            <Markets<T>>::iter_values().all(|market| market.bonds.oracles.is_empty()),
+            "migrated market has oracle bonds"
+        );
+        ensure!(Pallet::<T>::on_chain_storage_version() == 14, "storage version was not updated");
+        Ok(())
+    }
+}
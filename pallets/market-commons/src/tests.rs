@@ -62,6 +62,7 @@ fn create_market_builder(oracle: AccountIdOf<Runtime>) -> MarketBuilder<Runtime>
             dispute: None,
             close_dispute: None,
             close_request: None,
+            oracles: Default::default(),
         })
         .early_close(None);
     market_builder
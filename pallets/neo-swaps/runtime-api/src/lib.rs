@@ -0,0 +1,65 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use parity_scale_codec::{Codec, MaxEncodedLen};
+use prediction_market_primitives::{neo_swaps_api_types::SwapQuote, types::Asset};
+use sp_runtime::DispatchError;
+
+sp_api::decl_runtime_apis! {
+    pub trait NeoSwapsApi<AccountId, MarketId, Balance, BlockNumber> where
+        AccountId: Codec,
+        MarketId: Codec + MaxEncodedLen,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Quotes `who` buying `asset_out` for `amount_in` units of collateral from the pool of
+        /// `market_id`, including swap and external fees.
+        fn quote_buy(
+            who: AccountId,
+            market_id: MarketId,
+            asset_out: Asset<MarketId>,
+            amount_in: Balance,
+        ) -> Result<SwapQuote<Balance>, DispatchError>;
+
+        /// Quotes `who` selling `amount_in` units of `asset_in` to the pool of `market_id`,
+        /// including swap and external fees.
+        fn quote_sell(
+            who: AccountId,
+            market_id: MarketId,
+            asset_in: Asset<MarketId>,
+            amount_in: Balance,
+        ) -> Result<SwapQuote<Balance>, DispatchError>;
+
+        /// Returns the spot prices of all assets in the pool of `market_id`.
+        fn spot_prices(
+            market_id: MarketId,
+        ) -> Result<Vec<(Asset<MarketId>, Balance)>, DispatchError>;
+
+        /// Returns the time-weighted average prices of all assets in the pool of `market_id` over
+        /// the last `period` blocks.
+        fn twap(
+            market_id: MarketId,
+            period: BlockNumber,
+        ) -> Result<Vec<(Asset<MarketId>, Balance)>, DispatchError>;
+    }
+}
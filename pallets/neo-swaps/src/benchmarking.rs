@@ -21,7 +21,10 @@ use super::*;
 use crate::{
     liquidity_tree::{traits::LiquidityTreeHelper, types::LiquidityTree},
     traits::{liquidity_shares_manager::LiquiditySharesManager, pool_operations::PoolOperations},
-    AssetOf, BalanceOf, MarketIdOf, Pallet as NeoSwaps, Pools, MIN_SPOT_PRICE,
+    types::{AdditionalSwapFeeMode, DynamicFeeParams, IncentiveReward, RangePosition, VolumeTier},
+    AdditionalSwapFeeSchedules, AdditionalSwapFeeTiers, AssetOf, BalanceOf, ConditionalPools,
+    DynamicSwapFees, IncentivePrograms, IncentiveRewards, MarketIdOf, NextIncentiveProgramId,
+    Pallet as NeoSwaps, Pools, RangePositions, MIN_SPOT_PRICE,
 };
 use alloc::{vec, vec::Vec};
 use common_primitives::constants::currency::CENT_BASE;
@@ -39,7 +42,10 @@ use prediction_market_primitives::{
     constants::base_multiples::*,
     math::fixed::{BaseProvider, FixedDiv, FixedMul, PredictionMarketBase},
     traits::CompleteSetOperationsApi,
-    types::{Asset, Market, MarketCreation, MarketPeriod, MarketStatus, MarketType, ScoringRule},
+    types::{
+        Asset, CombinatorialId, Market, MarketCreation, MarketPeriod, MarketStatus, MarketType,
+        ScoringRule,
+    },
 };
 use sp_avn_common::Proof;
 use sp_core::{crypto::DEV_PHRASE, H256};
@@ -247,9 +253,69 @@ fn create_market_and_deploy_pool<T: Config>(
         create_spot_prices::<T>(asset_count),
         CENT_BASE.saturated_into(),
     ));
+    // Move to the next block so that the benchmarked call records a new price observation
+    // instead of overwriting the one recorded on deployment.
+    let now = frame_system::Pallet::<T>::block_number();
+    frame_system::Pallet::<T>::set_block_number(now + 1u32.into());
     market_id
 }
 
+/// Creates a parent market and a child market with `asset_count` outcomes and splits `amount`
+/// units of the position conditioned on the parent market's first outcome into the positions of
+/// the child market. Returns the child market and the parent collection.
+fn create_market_and_split_conditional_positions<T: Config>(
+    caller: T::AccountId,
+    asset_count: u16,
+    amount: BalanceOf<T>,
+) -> (MarketIdOf<T>, CombinatorialId) {
+    let base_asset = Asset::Tru;
+    let parent_market_id = create_market::<T>(caller.clone(), base_asset, 2);
+    let market_id = create_market::<T>(caller.clone(), base_asset, asset_count);
+    let parent_collection_id =
+        match T::CompleteSetOperations::position_asset(None, parent_market_id, 0b01).unwrap() {
+            Asset::CombinatorialOutcome(collection_id) => collection_id,
+            _ => panic!("unexpected position asset"),
+        };
+    let parent_asset = Asset::CombinatorialOutcome(parent_collection_id);
+    let total_cost = amount + T::MultiCurrency::minimum_balance(parent_asset);
+
+    assert_ok!(T::MultiCurrency::deposit(base_asset, &caller, total_cost));
+    assert_ok_with_transaction!(T::CompleteSetOperations::split_position(
+        caller.clone(),
+        None,
+        parent_market_id,
+        vec![0b01, 0b10],
+        total_cost,
+    ));
+    let partition = (0..asset_count).map(|index| 1 << index).collect();
+    assert_ok_with_transaction!(T::CompleteSetOperations::split_position(
+        caller,
+        Some(parent_collection_id),
+        market_id,
+        partition,
+        amount,
+    ));
+    (market_id, parent_collection_id)
+}
+
+fn create_market_and_deploy_combinatorial_pool<T: Config>(
+    caller: T::AccountId,
+    asset_count: u16,
+) -> (MarketIdOf<T>, CombinatorialId) {
+    let amount = _10.saturated_into();
+    let (market_id, parent_collection_id) =
+        create_market_and_split_conditional_positions::<T>(caller.clone(), asset_count, amount);
+    assert_ok!(NeoSwaps::<T>::deploy_combinatorial_pool(
+        RawOrigin::Signed(caller).into(),
+        market_id,
+        parent_collection_id,
+        amount,
+        create_spot_prices::<T>(asset_count),
+        CENT_BASE.saturated_into(),
+    ));
+    (market_id, parent_collection_id)
+}
+
 fn deposit_fees<T: Config>(market_id: MarketIdOf<T>, amount: BalanceOf<T>) {
     let mut pool = Pools::<T>::get(market_id).unwrap();
     assert_ok!(T::MultiCurrency::deposit(pool.collateral, &pool.account_id, amount));
@@ -282,6 +348,45 @@ fn add_liquidity_provider_to_market<T: Config>(market_id: MarketIdOf<T>, caller:
     ));
 }
 
+// Insert a range position for `who` into the pool of `market_id` which covers `lower..=upper` and
+// holds all of `who`'s pool shares, if any.
+fn insert_range_position<T: Config>(
+    market_id: MarketIdOf<T>,
+    who: AccountIdOf<T>,
+    lower: BalanceOf<T>,
+    upper: BalanceOf<T>,
+) {
+    let pool = Pools::<T>::get(market_id).unwrap();
+    let position = RangePosition {
+        asset: pool.assets()[0],
+        lower,
+        upper,
+        shares: pool.liquidity_shares_manager.shares_of(&who).unwrap_or_else(|_| Zero::zero()),
+        parked: vec![Zero::zero(); pool.assets().len()].try_into().unwrap(),
+    };
+    RangePositions::<T>::insert(market_id, who, position);
+}
+
+// Let the creator of `market_id` fund `count` incentive programs which pay out in the base asset
+// and end after 10 blocks.
+fn create_incentive_programs<T: Config>(market_id: MarketIdOf<T>, count: u32) {
+    let market = T::MarketCommons::market(&market_id).unwrap();
+    let now = frame_system::Pallet::<T>::block_number();
+    for _ in 0..count {
+        let amount = _100.saturated_into();
+        let total_cost = amount + T::MultiCurrency::minimum_balance(market.base_asset);
+        assert_ok!(T::MultiCurrency::deposit(market.base_asset, &market.creator, total_cost));
+        assert_ok!(NeoSwaps::<T>::create_incentive_program(
+            RawOrigin::Signed(market.creator.clone()).into(),
+            market_id,
+            market.base_asset,
+            amount,
+            now,
+            now + 10u32.into(),
+        ));
+    }
+}
+
 fn into_bytes<T: Config>(account: &<T as pallet_avn::Config>::AuthorityId) -> [u8; 32]
 where
     T: Config + pallet_avn::Config,
@@ -553,6 +658,87 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn deploy_combinatorial_pool(n: Linear<2, 128>) {
+        let alice: T::AccountId = whitelisted_caller();
+        let asset_count = n.try_into().unwrap();
+        let amount = _10.saturated_into();
+        let (market_id, parent_collection_id) =
+            create_market_and_split_conditional_positions::<T>(alice.clone(), asset_count, amount);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(alice),
+            market_id,
+            parent_collection_id,
+            amount,
+            create_spot_prices::<T>(asset_count),
+            CENT_BASE.saturated_into(),
+        );
+
+        assert!(ConditionalPools::<T>::contains_key(market_id, parent_collection_id));
+    }
+
+    #[benchmark]
+    fn buy_conditional(n: Linear<2, 128>) {
+        let alice = whitelisted_caller();
+        let asset_count = n.try_into().unwrap();
+        let (market_id, parent_collection_id) =
+            create_market_and_deploy_combinatorial_pool::<T>(alice, asset_count);
+        let asset_out =
+            T::CompleteSetOperations::position_asset(Some(parent_collection_id), market_id, 0b01)
+                .unwrap();
+        let amount_in = _1.saturated_into();
+        let min_amount_out = 0u8.saturated_into();
+
+        let helper = BenchmarkHelper::<T>::new();
+        let bob = helper.accounts().next().unwrap();
+        let parent_asset = Asset::CombinatorialOutcome(parent_collection_id);
+        assert_ok!(T::MultiCurrency::deposit(parent_asset, &bob, amount_in));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(bob),
+            market_id,
+            parent_collection_id,
+            asset_count,
+            asset_out,
+            amount_in,
+            min_amount_out,
+        );
+    }
+
+    #[benchmark]
+    fn sell_conditional(n: Linear<2, 128>) {
+        let alice = whitelisted_caller();
+        let asset_count = n.try_into().unwrap();
+        let (market_id, parent_collection_id) =
+            create_market_and_deploy_combinatorial_pool::<T>(alice, asset_count);
+        let asset_in = T::CompleteSetOperations::position_asset(
+            Some(parent_collection_id),
+            market_id,
+            1 << (asset_count - 1),
+        )
+        .unwrap();
+        let amount_in = _1.saturated_into();
+        let min_amount_out = 0u8.saturated_into();
+
+        let helper = BenchmarkHelper::<T>::new();
+        let bob = helper.accounts().next().unwrap();
+        assert_ok!(T::MultiCurrency::deposit(asset_in, &bob, amount_in));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(bob),
+            market_id,
+            parent_collection_id,
+            asset_count,
+            asset_in,
+            amount_in,
+            min_amount_out,
+        );
+    }
+
     #[benchmark]
     fn signed_join(n: Linear<2, 128>) {
         let (signer_account_keypair, signer_account_id) = get_user_account::<T>();
@@ -798,15 +984,483 @@ mod benchmarks {
     fn set_additional_swap_fee() {
         // This works because the account is registered in the genesis config.
         let market_admin = get_user_account::<T>().1;
-        let new_fee: BalanceOf<T> = 12_345_678u128.saturated_into();
+        let new_fee = AdditionalSwapFeeMode::Percentage {
+            rate: Perbill::from_parts(12_345_678),
+            min: Some(CENT_BASE.saturated_into()),
+            max: Some(_1.saturated_into()),
+        };
 
         #[extrinsic_call]
-        set_additional_swap_fee(RawOrigin::Signed(market_admin), new_fee.clone());
+        set_additional_swap_fee(RawOrigin::Signed(market_admin), new_fee);
 
-        assert_eq!(AdditionalSwapFee::<T>::get(), Some(new_fee.clone()));
+        assert_eq!(AdditionalSwapFee::<T>::get(), Some(new_fee));
         assert_last_event::<T>(Event::AdditionalSwapFeeSet { new_fee }.into());
     }
 
+    #[benchmark]
+    fn set_additional_swap_fee_schedule() {
+        let market_admin = get_user_account::<T>().1;
+        let asset = Asset::Tru;
+        let fee = Some(AdditionalSwapFeeMode::Percentage {
+            rate: Perbill::from_parts(12_345_678),
+            min: Some(CENT_BASE.saturated_into()),
+            max: Some(_1.saturated_into()),
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(market_admin), asset, fee);
+
+        assert_eq!(AdditionalSwapFeeSchedules::<T>::get(asset), fee);
+    }
+
+    #[benchmark]
+    fn set_additional_swap_fee_tiers(n: Linear<0, { T::MaxVolumeTiers::get() }>) {
+        let market_admin = get_user_account::<T>().1;
+        let tiers = (0..n)
+            .map(|i| VolumeTier {
+                min_volume: (_100 * (i as u128 + 1)).saturated_into(),
+                discount: Perbill::from_percent(i + 1),
+            })
+            .collect::<Vec<_>>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(market_admin), tiers);
+
+        assert_eq!(AdditionalSwapFeeTiers::<T>::get().len(), n as usize);
+    }
+
+    // Bob joins the pool with a range position and is assigned a leaf at maximum depth in the tree.
+    // The pool already holds the maximum number of range positions but one.
+    #[benchmark]
+    fn join_range(n: Linear<2, 128>) {
+        let alice: T::AccountId = whitelisted_caller();
+        let base_asset = Asset::Tru;
+        let asset_count = n.try_into().unwrap();
+        let market_id = create_market_and_deploy_pool::<T>(
+            alice.clone(),
+            base_asset,
+            asset_count,
+            _10.saturated_into(),
+        );
+        let helper = BenchmarkHelper::<T>::new();
+        helper.populate_liquidity_tree_with_free_leaf(market_id);
+        for i in 0..T::MaxRangePositions::get().saturating_sub(1) {
+            insert_range_position::<T>(
+                market_id,
+                account("range", i, 0),
+                Zero::zero(),
+                _1.saturated_into(),
+            );
+        }
+        let pool = Pools::<T>::get(market_id).unwrap();
+        // The position must meet both the relative minimum of new LPs and the minimum liquidity
+        // of range positions.
+        let ratio = MIN_LIQUIDITY
+            .saturated_into::<BalanceOf<T>>()
+            .bdiv_ceil(pool.liquidity_parameter)
+            .unwrap()
+            .max(MIN_RELATIVE_LP_POSITION_VALUE.saturated_into());
+        let pool_shares_amount =
+            pool.liquidity_shares_manager.total_shares().unwrap().bmul_ceil(ratio).unwrap();
+        let complete_set_amount = pool
+            .reserves
+            .values()
+            .max()
+            .unwrap()
+            .bmul_ceil(ratio.saturating_add(ratio))
+            .unwrap();
+        let bob = helper.accounts().next().unwrap();
+        helper.set_up_liquidity_benchmark(market_id, bob.clone(), Some(complete_set_amount));
+        let max_amounts_in = vec![u128::MAX.saturated_into(); asset_count as usize];
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(bob.clone()),
+            market_id,
+            pool_shares_amount,
+            max_amounts_in,
+            asset,
+            Zero::zero(),
+            _1.saturated_into(),
+        );
+
+        assert!(RangePositions::<T>::contains_key(market_id, bob));
+    }
+
+    // Worst-case benchmark of `exit_range`: Bob owns an active range position on a leaf of maximum
+    // depth and the pool is kept alive.
+    #[benchmark]
+    fn exit_range(n: Linear<2, 128>) {
+        let alice: T::AccountId = whitelisted_caller();
+        let base_asset = Asset::Tru;
+        let asset_count = n.try_into().unwrap();
+        let market_id = create_market_and_deploy_pool::<T>(
+            alice.clone(),
+            base_asset,
+            asset_count,
+            _10.saturated_into(),
+        );
+        let min_amounts_out = vec![0u8.into(); asset_count as usize];
+
+        let helper = BenchmarkHelper::<T>::new();
+        let bob = helper.accounts().next().unwrap();
+        helper.populate_liquidity_tree_until_full(market_id, bob.clone());
+        insert_range_position::<T>(market_id, bob.clone(), Zero::zero(), _1.saturated_into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(bob.clone()), market_id, min_amounts_out);
+
+        assert!(!RangePositions::<T>::contains_key(market_id, bob));
+        assert!(Pools::<T>::contains_key(market_id)); // Ensure we took the right turn.
+    }
+
+    // Worst-case benchmark of the range position update executed after each trade: Every range
+    // position of the pool is deactivated.
+    #[benchmark]
+    fn update_range_positions(m: Linear<0, 10>) {
+        let alice: T::AccountId = whitelisted_caller();
+        let base_asset = Asset::Tru;
+        let asset_count = 128;
+        let market_id = create_market_and_deploy_pool::<T>(
+            alice.clone(),
+            base_asset,
+            asset_count,
+            _10.saturated_into(),
+        );
+        let helper = BenchmarkHelper::<T>::new();
+        let owners: Vec<_> = helper.accounts().take(m as usize).collect();
+        for who in owners.iter() {
+            add_liquidity_provider_to_market::<T>(market_id, who.clone());
+            // The range excludes the current spot price, so the position is deactivated.
+            insert_range_position::<T>(
+                market_id,
+                who.clone(),
+                _1.saturated_into::<BalanceOf<T>>() - 1u8.into(),
+                _1.saturated_into(),
+            );
+        }
+        let mut pool = Pools::<T>::get(market_id).unwrap();
+
+        #[block]
+        {
+            assert_ok!(NeoSwaps::<T>::update_range_positions(market_id, &mut pool));
+        }
+
+        for who in owners.iter() {
+            assert!(!RangePositions::<T>::get(market_id, who).unwrap().is_active());
+        }
+    }
+
+    #[benchmark]
+    fn deploy_pool_with_dynamic_fee(n: Linear<2, 128>) {
+        let alice: T::AccountId = whitelisted_caller();
+        let base_asset = Asset::Tru;
+        let asset_count = n.try_into().unwrap();
+        let market_id = create_market::<T>(alice.clone(), base_asset, asset_count);
+        let amount = _10.saturated_into();
+        let total_cost = amount + T::MultiCurrency::minimum_balance(base_asset);
+
+        assert_ok!(T::MultiCurrency::deposit(base_asset, &alice, total_cost));
+        assert_ok_with_transaction!(T::CompleteSetOperations::buy_complete_set(
+            alice.clone(),
+            market_id,
+            amount
+        ));
+        let params = DynamicFeeParams {
+            min_fee: CENT_BASE.saturated_into(),
+            max_fee: T::MaxSwapFee::get(),
+            sensitivity: _1.saturated_into(),
+            window: 10u32.into(),
+        };
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(alice),
+            market_id,
+            amount,
+            create_spot_prices::<T>(asset_count),
+            params,
+        );
+
+        assert!(DynamicSwapFees::<T>::contains_key(market_id));
+    }
+
+    // The market already has the maximum number of incentive programs but one.
+    // Worst-case benchmark of `create_incentive_program`: The market has the maximum number of
+    // incentive programs, which have all ended, so the oldest one and the rewards entries of each
+    // node of the liquidity tree are removed to make room.
+    #[benchmark]
+    fn create_incentive_program() {
+        let alice: T::AccountId = whitelisted_caller();
+        let base_asset = Asset::Tru;
+        let market_id =
+            create_market_and_deploy_pool::<T>(alice.clone(), base_asset, 2, _10.saturated_into());
+        create_incentive_programs::<T>(market_id, T::MaxIncentivePrograms::get());
+        let now = frame_system::Pallet::<T>::block_number();
+        let now = now + 11u32.into();
+        frame_system::Pallet::<T>::set_block_number(now);
+        assert_ok!(NeoSwaps::<T>::claim_incentives(
+            RawOrigin::Signed(alice.clone()).into(),
+            market_id
+        ));
+        let helper = BenchmarkHelper::<T>::new();
+        let reward = IncentiveReward { debt: 1u8.into(), pending: Zero::zero() };
+        for who in helper.accounts().take(LiquidityTreeOf::<T>::max_node_count() as usize) {
+            IncentiveRewards::<T>::insert((market_id, 0), who, reward);
+        }
+        let amount = _100.saturated_into();
+        let total_cost = amount + T::MultiCurrency::minimum_balance(base_asset);
+        assert_ok!(T::MultiCurrency::deposit(base_asset, &alice, total_cost));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(alice), market_id, base_asset, amount, now, now + 10u32.into());
+
+        assert!(!IncentivePrograms::<T>::contains_key(market_id, 0));
+        assert_eq!(NextIncentiveProgramId::<T>::get(market_id), T::MaxIncentivePrograms::get() + 1);
+    }
+
+    // Worst-case benchmark of settling and checkpointing the incentive rewards of an LP whose pool
+    // shares change: The market has the maximum number of incentive programs.
+    #[benchmark]
+    fn settle_incentives() {
+        let alice: T::AccountId = whitelisted_caller();
+        let market_id =
+            create_market_and_deploy_pool::<T>(alice.clone(), Asset::Tru, 2, _10.saturated_into());
+        create_incentive_programs::<T>(market_id, T::MaxIncentivePrograms::get());
+        let now = frame_system::Pallet::<T>::block_number();
+        frame_system::Pallet::<T>::set_block_number(now + 5u32.into());
+        let pool = Pools::<T>::get(market_id).unwrap();
+
+        #[block]
+        {
+            assert_ok!(NeoSwaps::<T>::settle_incentives(market_id, &pool, &alice));
+            assert_ok!(NeoSwaps::<T>::checkpoint_incentives(market_id, &pool, &alice));
+        }
+
+        assert!(!IncentiveRewards::<T>::get((market_id, 0), alice).pending.is_zero());
+    }
+
+    // Worst-case benchmark of `claim_incentives`: The caller claims rewards from the maximum number
+    // of incentive programs.
+    #[benchmark]
+    fn claim_incentives() {
+        let alice: T::AccountId = whitelisted_caller();
+        let market_id =
+            create_market_and_deploy_pool::<T>(alice.clone(), Asset::Tru, 2, _10.saturated_into());
+        create_incentive_programs::<T>(market_id, T::MaxIncentivePrograms::get());
+        let now = frame_system::Pallet::<T>::block_number();
+        frame_system::Pallet::<T>::set_block_number(now + 5u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(alice.clone()), market_id);
+
+        assert!(!IncentiveRewards::<T>::get((market_id, 0), alice).debt.is_zero());
+    }
+
+    #[benchmark]
+    fn signed_claim_incentives() {
+        let (signer_account_keypair, signer_account_id) = get_user_account::<T>();
+        let market_id = create_market_and_deploy_pool::<T>(
+            signer_account_id.clone(),
+            Asset::Tru,
+            2u16,
+            _10.saturated_into(),
+        );
+        create_incentive_programs::<T>(market_id, T::MaxIncentivePrograms::get());
+        let now = frame_system::Pallet::<T>::block_number();
+        frame_system::Pallet::<T>::set_block_number(now + 5u32.into());
+
+        let block_number = frame_system::Pallet::<T>::block_number();
+        let relayer_account_id = get_relayer::<T>();
+        let encoded_payload = NeoSwaps::<T>::encode_signed_claim_incentives_params(
+            &relayer_account_id,
+            &market_id,
+            &block_number,
+        );
+        let valid_signature = signer_account_keypair.sign(&encoded_payload).unwrap().encode();
+        let proof: Proof<T::Signature, T::AccountId> =
+            get_proof::<T>(signer_account_id.clone(), relayer_account_id, &valid_signature);
+        let initial_balance = T::MultiCurrency::free_balance(Asset::Tru, &signer_account_id);
+
+        #[extrinsic_call]
+        signed_claim_incentives(
+            RawOrigin::Signed(signer_account_id.clone()),
+            proof,
+            market_id,
+            block_number,
+        );
+
+        let final_balance = T::MultiCurrency::free_balance(Asset::Tru, &signer_account_id);
+        assert!(final_balance > initial_balance);
+    }
+
+    // Worst-case benchmark of `join_with_collateral`: Bob joins a pool with a free leaf in its
+    // liquidity tree and a leftover of the expensive outcome is sold to the pool.
+    #[benchmark]
+    fn join_with_collateral(n: Linear<2, 128>) {
+        let alice: T::AccountId = whitelisted_caller();
+        let base_asset = Asset::Tru;
+        let asset_count = n.try_into().unwrap();
+        let market_id = create_market_and_deploy_pool::<T>(
+            alice.clone(),
+            base_asset,
+            asset_count,
+            _10.saturated_into(),
+        );
+        let helper = BenchmarkHelper::<T>::new();
+        helper.populate_liquidity_tree_with_free_leaf(market_id);
+        let bob = helper.accounts().next().unwrap();
+        let amount_in = _10.saturated_into();
+        assert_ok!(T::MultiCurrency::deposit(base_asset, &bob, amount_in));
+        let min_leftover_amounts_out = vec![0u8.into(); n as usize];
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(bob.clone()),
+            market_id,
+            asset_count,
+            amount_in,
+            0u8.into(),
+            min_leftover_amounts_out,
+        );
+
+        let pool = Pools::<T>::get(market_id).unwrap();
+        assert!(pool.liquidity_shares_manager.shares_of(&bob).is_ok());
+    }
+
+    // Worst-case benchmark of `exit_to_collateral`: Bob exits a pool which is kept alive and the
+    // leftovers of all cheap outcomes are sold to the pool.
+    #[benchmark]
+    fn exit_to_collateral(n: Linear<2, 128>) {
+        let alice: T::AccountId = whitelisted_caller();
+        let base_asset = Asset::Tru;
+        let asset_count = n.try_into().unwrap();
+        let market_id = create_market_and_deploy_pool::<T>(
+            alice.clone(),
+            base_asset,
+            asset_count,
+            _10.saturated_into(),
+        );
+        let helper = BenchmarkHelper::<T>::new();
+        let bob = helper.accounts().next().unwrap();
+        add_liquidity_provider_to_market::<T>(market_id, bob.clone());
+        let pool = Pools::<T>::get(market_id).unwrap();
+        let pool_shares_amount = pool.liquidity_shares_manager.shares_of(&bob).unwrap();
+        let min_leftover_amounts_out = vec![0u8.into(); n as usize];
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(bob),
+            market_id,
+            asset_count,
+            pool_shares_amount,
+            0u8.into(),
+            min_leftover_amounts_out,
+        );
+
+        assert!(Pools::<T>::contains_key(market_id));
+    }
+
+    #[benchmark]
+    fn signed_join_with_collateral(n: Linear<2, 128>) {
+        let (signer_account_keypair, signer_account_id) = get_user_account::<T>();
+        let alice: T::AccountId = whitelisted_caller();
+        let base_asset = Asset::Tru;
+        let asset_count = n.try_into().unwrap();
+        let market_id = create_market_and_deploy_pool::<T>(
+            alice,
+            base_asset,
+            asset_count,
+            _10.saturated_into(),
+        );
+        let helper = BenchmarkHelper::<T>::new();
+        helper.populate_liquidity_tree_with_free_leaf(market_id);
+        let amount_in = _10.saturated_into();
+        let min_pool_shares_amount = 0u8.into();
+        let min_leftover_amounts_out = vec![0u8.into(); n as usize];
+        assert_ok!(T::MultiCurrency::deposit(base_asset, &signer_account_id, amount_in));
+
+        let block_number = frame_system::Pallet::<T>::block_number();
+        let relayer_account_id = get_relayer::<T>();
+        let encoded_payload = NeoSwaps::<T>::encode_signed_join_with_collateral_params(
+            &relayer_account_id,
+            &market_id,
+            &asset_count,
+            &amount_in,
+            &min_pool_shares_amount,
+            &min_leftover_amounts_out,
+            &block_number,
+        );
+        let valid_signature = signer_account_keypair.sign(&encoded_payload).unwrap().encode();
+        let proof: Proof<T::Signature, T::AccountId> =
+            get_proof::<T>(signer_account_id.clone(), relayer_account_id, &valid_signature);
+
+        #[extrinsic_call]
+        signed_join_with_collateral(
+            RawOrigin::Signed(signer_account_id.clone()),
+            proof,
+            market_id,
+            asset_count,
+            amount_in,
+            min_pool_shares_amount,
+            min_leftover_amounts_out,
+            block_number,
+        );
+
+        let pool = Pools::<T>::get(market_id).unwrap();
+        assert!(pool.liquidity_shares_manager.shares_of(&signer_account_id).is_ok());
+    }
+
+    #[benchmark]
+    fn signed_exit_to_collateral(n: Linear<2, 128>) {
+        let (signer_account_keypair, signer_account_id) = get_user_account::<T>();
+        let alice: T::AccountId = whitelisted_caller();
+        let base_asset = Asset::Tru;
+        let asset_count = n.try_into().unwrap();
+        let market_id = create_market_and_deploy_pool::<T>(
+            alice,
+            base_asset,
+            asset_count,
+            _10.saturated_into(),
+        );
+        add_liquidity_provider_to_market::<T>(market_id, signer_account_id.clone());
+        let pool = Pools::<T>::get(market_id).unwrap();
+        let pool_shares_amount =
+            pool.liquidity_shares_manager.shares_of(&signer_account_id).unwrap();
+        let min_amount_out = 0u8.into();
+        let min_leftover_amounts_out = vec![0u8.into(); n as usize];
+
+        let block_number = frame_system::Pallet::<T>::block_number();
+        let relayer_account_id = get_relayer::<T>();
+        let encoded_payload = NeoSwaps::<T>::encode_signed_exit_to_collateral_params(
+            &relayer_account_id,
+            &market_id,
+            &asset_count,
+            &pool_shares_amount,
+            &min_amount_out,
+            &min_leftover_amounts_out,
+            &block_number,
+        );
+        let valid_signature = signer_account_keypair.sign(&encoded_payload).unwrap().encode();
+        let proof: Proof<T::Signature, T::AccountId> =
+            get_proof::<T>(signer_account_id.clone(), relayer_account_id, &valid_signature);
+
+        #[extrinsic_call]
+        signed_exit_to_collateral(
+            RawOrigin::Signed(signer_account_id),
+            proof,
+            market_id,
+            asset_count,
+            pool_shares_amount,
+            min_amount_out,
+            min_leftover_amounts_out,
+            block_number,
+        );
+
+        assert!(Pools::<T>::contains_key(market_id));
+    }
+
     impl_benchmark_test_suite!(
         NeoSwaps,
         crate::mock::ExtBuilder::default().build(),
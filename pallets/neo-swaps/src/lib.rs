@@ -40,16 +40,28 @@ pub use pallet::*;
 pub const WITHDRAW_FEES_CONTEXT: &[u8] = b"neo_swap::withdraw_fees_context";
 pub const EXIT_CONTEXT: &[u8] = b"neo_swap::exit_context";
 pub const JOIN_CONTEXT: &[u8] = b"neo_swap::join_context";
+pub const CLAIM_INCENTIVES_CONTEXT: &[u8] = b"neo_swap::claim_incentives_context";
+pub const JOIN_WITH_COLLATERAL_CONTEXT: &[u8] = b"neo_swap::join_with_collateral_context";
+pub const EXIT_TO_COLLATERAL_CONTEXT: &[u8] = b"neo_swap::exit_to_collateral_context";
 
 #[frame_support::pallet]
 mod pallet {
-    use super::{EXIT_CONTEXT, JOIN_CONTEXT, WITHDRAW_FEES_CONTEXT};
+    use super::{
+        CLAIM_INCENTIVES_CONTEXT, EXIT_CONTEXT, EXIT_TO_COLLATERAL_CONTEXT, JOIN_CONTEXT,
+        JOIN_WITH_COLLATERAL_CONTEXT, WITHDRAW_FEES_CONTEXT,
+    };
     use crate::{
         consts::LN_NUMERICAL_LIMIT,
-        liquidity_tree::types::{BenchmarkInfo, LiquidityTree, LiquidityTreeError},
+        liquidity_tree::{
+            traits::LiquidityTreeHelper,
+            types::{BenchmarkInfo, LiquidityTree, LiquidityTreeError},
+        },
         math::{Math, MathOps},
         traits::{pool_operations::PoolOperations, LiquiditySharesManager},
-        types::{FeeDistribution, MaxAssets, Pool},
+        types::{
+            AdditionalSwapFeeMode, DynamicFeeParams, FeeDistribution, IncentiveProgram,
+            IncentiveReward, MaxAssets, Observation, Pool, PriceHistory, RangePosition, VolumeTier,
+        },
         weights::*,
     };
     use alloc::{collections::BTreeMap, vec, vec::Vec};
@@ -58,10 +70,14 @@ mod pallet {
     use frame_support::{
         dispatch::DispatchResultWithPostInfo,
         ensure,
-        pallet_prelude::{BuildGenesisConfig, OptionQuery, StorageMap, StorageValue},
+        pallet_prelude::{
+            BuildGenesisConfig, OptionQuery, StorageDoubleMap, StorageMap, StorageValue, ValueQuery,
+        },
         require_transactional,
         traits::{Get, IsSubType, IsType, StorageVersion},
-        transactional, PalletError, PalletId, Parameter, Twox64Concat,
+        transactional,
+        weights::Weight,
+        BoundedVec, PalletError, PalletId, Parameter, Twox64Concat,
     };
     use frame_system::{
         ensure_signed,
@@ -73,26 +89,27 @@ mod pallet {
     use prediction_market_primitives::{
         hybrid_router_api_types::{AmmSoftFail, AmmTrade, ApiError},
         math::{
-            checked_ops_res::{CheckedAddRes, CheckedSubRes},
+            checked_ops_res::{CheckedAddRes, CheckedDivRes, CheckedSubRes},
             fixed::{BaseProvider, FixedDiv, FixedMul, PredictionMarketBase},
         },
+        neo_swaps_api_types::SwapQuote,
         traits::{
             CompleteSetOperationsApi, DeployPoolApi, DistributeFees, HybridRouterAmmApi,
             OnLiquidityProvided, PalletAdminGetter,
         },
-        types::{Asset, MarketStatus, ScoringRule},
+        types::{Asset, CombinatorialId, IndexSet, MarketStatus, ScoringRule},
     };
     use scale_info::{prelude::boxed::Box, TypeInfo};
     use sp_avn_common::{verify_signature, InnerCallValidator, Proof};
     use sp_runtime::{
         traits::{
-            AccountIdConversion, CheckedSub, Dispatchable, IdentifyAccount, Member, Saturating,
-            Verify, Zero,
+            AccountIdConversion, CheckedDiv, CheckedShr, CheckedSub, Dispatchable, Hash,
+            IdentifyAccount, Member, Saturating, Verify, Zero,
         },
-        DispatchError, DispatchResult, RuntimeDebug, SaturatedConversion,
+        DispatchError, DispatchResult, Perbill, RuntimeDebug, SaturatedConversion,
     };
 
-    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
     // These should not be config parameters to avoid misconfigurations.
     pub(crate) const EXIT_FEE: u128 = CENT_BASE / 10; // 0.1%
@@ -119,11 +136,19 @@ mod pallet {
     pub(crate) type LiquidityTreeOf<T> = LiquidityTree<T, <T as Config>::MaxLiquidityTreeDepth>;
     pub(crate) type PoolOf<T> = Pool<T, LiquidityTreeOf<T>, MaxAssets>;
     pub(crate) type AmmTradeOf<T> = AmmTrade<BalanceOf<T>>;
+    pub(crate) type SwapQuoteOf<T> = SwapQuote<BalanceOf<T>>;
+    pub(crate) type DynamicFeeParamsOf<T> = DynamicFeeParams<BalanceOf<T>, BlockNumberFor<T>>;
+    pub(crate) type AdditionalSwapFeeModeOf<T> = AdditionalSwapFeeMode<BalanceOf<T>>;
+    pub(crate) type VolumeTierOf<T> = VolumeTier<BalanceOf<T>>;
+    pub(crate) type VolumeTiersOf<T> = BoundedVec<VolumeTierOf<T>, <T as Config>::MaxVolumeTiers>;
+    pub(crate) type IncentiveRewardOf<T> = IncentiveReward<BalanceOf<T>>;
+    pub(crate) type IncentiveProgramId = u32;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type CompleteSetOperations: CompleteSetOperationsApi<
             AccountId = Self::AccountId,
+            Asset = AssetOf<Self>,
             Balance = BalanceOf<Self>,
             MarketId = MarketIdOf<Self>,
         >;
@@ -154,14 +179,33 @@ mod pallet {
 
         type WeightInfo: WeightInfoZeitgeist;
 
+        /// The maximum number of incentive programs per market. Each change of an LP's pool
+        /// shares updates every incentive program of the pool, so this should be kept small.
+        #[pallet::constant]
+        type MaxIncentivePrograms: Get<u32>;
+
         /// The maximum allowed liquidity tree depth per pool. Each pool can support
         /// `2^(depth + 1) - 1` liquidity providers. **Must** be less than 16.
         #[pallet::constant]
         type MaxLiquidityTreeDepth: Get<u32>;
 
+        /// The number of price observations kept per pool. Bounds the longest period over which
+        /// time-weighted average prices can be calculated.
+        #[pallet::constant]
+        type MaxObservations: Get<u32>;
+
+        /// The maximum number of range positions per pool. Each trade checks every range
+        /// position of the pool, so this should be kept small.
+        #[pallet::constant]
+        type MaxRangePositions: Get<u32>;
+
         #[pallet::constant]
         type MaxSwapFee: Get<BalanceOf<Self>>;
 
+        /// The maximum number of volume tiers of the additional swap fee.
+        #[pallet::constant]
+        type MaxVolumeTiers: Get<u32>;
+
         #[pallet::constant]
         type PalletId: Get<PalletId>;
 
@@ -196,16 +240,99 @@ mod pallet {
     #[pallet::storage]
     pub(crate) type Pools<T: Config> = StorageMap<_, Twox64Concat, MarketIdOf<T>, PoolOf<T>>;
 
+    /// The conditional pools of each market by the collection which their positions are
+    /// conditioned on. They exist independently of the market's regular pool in `Pools`.
+    #[pallet::storage]
+    pub(crate) type ConditionalPools<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, MarketIdOf<T>, Twox64Concat, CombinatorialId, PoolOf<T>>;
+
+    /// Range-bounded liquidity positions of each pool by owner.
+    #[pallet::storage]
+    pub(crate) type RangePositions<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        MarketIdOf<T>,
+        Twox64Concat,
+        T::AccountId,
+        RangePosition<T>,
+    >;
+
+    /// The parameters of pools which charge a dynamic swap fee. Pools without an entry charge
+    /// the fixed swap fee specified at deployment.
+    #[pallet::storage]
+    pub type DynamicSwapFees<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, DynamicFeeParamsOf<T>>;
+
+    /// The recent price movements of pools which charge a dynamic swap fee.
+    #[pallet::storage]
+    pub(crate) type PriceHistories<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, PriceHistory<T>>;
+
+    /// The price observations of each pool, stored in a ring buffer of `MaxObservations` slots.
+    #[pallet::storage]
+    pub(crate) type Observations<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, MarketIdOf<T>, Twox64Concat, u32, Observation<T>>;
+
+    /// The slot of the most recent observation of each pool in `Observations`.
+    #[pallet::storage]
+    pub(crate) type LatestObservations<T: Config> = StorageMap<_, Twox64Concat, MarketIdOf<T>, u32>;
+
     /// The account that receives the early exit fee
     #[pallet::storage]
     pub type EarlyExitFeeAccount<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
-    /// The amount of additional swap fee to be paid
+    /// The additional swap fee charged on trades in base assets without a fee schedule.
+    #[pallet::storage]
+    pub type AdditionalSwapFee<T: Config> =
+        StorageValue<_, AdditionalSwapFeeModeOf<T>, OptionQuery>;
+
+    /// The additional swap fee charged on trades in markets with the specified base asset.
+    #[pallet::storage]
+    pub type AdditionalSwapFeeSchedules<T: Config> =
+        StorageMap<_, Twox64Concat, AssetOf<T>, AdditionalSwapFeeModeOf<T>>;
+
+    /// Discounts on the additional swap fee by trading volume, sorted by increasing volume.
+    #[pallet::storage]
+    pub type AdditionalSwapFeeTiers<T: Config> = StorageValue<_, VolumeTiersOf<T>, ValueQuery>;
+
+    /// The total amount of collateral each account has traded, used to determine its volume
+    /// tier.
+    #[pallet::storage]
+    pub type TradingVolumes<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// The incentive programs of each market's pool by ID.
     #[pallet::storage]
-    pub type AdditionalSwapFee<T: Config> = StorageValue<_, BalanceOf<T>, OptionQuery>;
+    pub type IncentivePrograms<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        MarketIdOf<T>,
+        Twox64Concat,
+        IncentiveProgramId,
+        IncentiveProgram<T>,
+    >;
+
+    /// The ID of the next incentive program of each market. IDs are never reused, so this is the
+    /// number of programs ever created for the market, including removed ones.
+    #[pallet::storage]
+    pub type NextIncentiveProgramId<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, IncentiveProgramId, ValueQuery>;
+
+    /// The rewards of each LP by incentive program.
+    #[pallet::storage]
+    pub type IncentiveRewards<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        (MarketIdOf<T>, IncentiveProgramId),
+        Twox64Concat,
+        T::AccountId,
+        IncentiveRewardOf<T>,
+        ValueQuery,
+    >;
 
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
+        /// The flat additional swap fee charged on every trade.
         pub additional_swap_fee: BalanceOf<T>,
     }
 
@@ -218,7 +345,9 @@ mod pallet {
     #[pallet::genesis_build]
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
-            AdditionalSwapFee::<T>::set(Some(self.additional_swap_fee.clone()));
+            AdditionalSwapFee::<T>::set(Some(AdditionalSwapFeeMode::Flat(
+                self.additional_swap_fee,
+            )));
         }
     }
 
@@ -229,33 +358,44 @@ mod pallet {
         T: Config,
     {
         /// Informant bought a position. `amount_in` is the amount of collateral paid by `who`,
-        /// including swap and external fees.
+        /// including swap and external fees. `swap_fee` is the fee rate charged by the pool.
         BuyExecuted {
             who: T::AccountId,
             market_id: MarketIdOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
             asset_out: AssetOf<T>,
             amount_in: BalanceOf<T>,
             amount_out: BalanceOf<T>,
+            swap_fee: BalanceOf<T>,
             swap_fee_amount: BalanceOf<T>,
             external_fee_amount: BalanceOf<T>,
         },
         /// Informant sold a position. `amount_out` is the amount of collateral received by `who`,
-        /// with swap and external fees already deducted.
+        /// with swap and external fees already deducted. `swap_fee` is the fee rate charged by the
+        /// pool.
         SellExecuted {
             who: T::AccountId,
             market_id: MarketIdOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
             asset_in: AssetOf<T>,
             amount_in: BalanceOf<T>,
             amount_out: BalanceOf<T>,
+            swap_fee: BalanceOf<T>,
             swap_fee_amount: BalanceOf<T>,
             external_fee_amount: BalanceOf<T>,
         },
         /// Liquidity provider withdrew fees.
-        FeesWithdrawn { who: T::AccountId, market_id: MarketIdOf<T>, amount: BalanceOf<T> },
+        FeesWithdrawn {
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
+            amount: BalanceOf<T>,
+        },
         /// Liquidity provider joined the pool.
         JoinExecuted {
             who: T::AccountId,
             market_id: MarketIdOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
             pool_shares_amount: BalanceOf<T>,
             amounts_in: Vec<BalanceOf<T>>,
             new_liquidity_parameter: BalanceOf<T>,
@@ -264,14 +404,17 @@ mod pallet {
         ExitExecuted {
             who: T::AccountId,
             market_id: MarketIdOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
             pool_shares_amount: BalanceOf<T>,
             amounts_out: Vec<BalanceOf<T>>,
             new_liquidity_parameter: BalanceOf<T>,
         },
-        /// Pool was createed.
+        /// Pool was createed. The `parent_collection_id` of the pool events is specified for
+        /// conditional pools.
         PoolDeployed {
             who: T::AccountId,
             market_id: MarketIdOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
             account_id: T::AccountId,
             reserves: BTreeMap<AssetOf<T>, BalanceOf<T>>,
             collateral: AssetOf<T>,
@@ -283,12 +426,104 @@ mod pallet {
         PoolDestroyed {
             who: T::AccountId,
             market_id: MarketIdOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
             amounts_out: Vec<BalanceOf<T>>,
         },
-        /// A fee for the additional swap fee was set.
-        AdditionalSwapFeeSet { new_fee: BalanceOf<T> },
+        /// The default additional swap fee was set.
+        AdditionalSwapFeeSet { new_fee: AdditionalSwapFeeModeOf<T> },
+        /// The additional swap fee of markets with base asset `asset` was set, or removed if
+        /// `fee` is `None`.
+        AdditionalSwapFeeScheduleSet { asset: AssetOf<T>, fee: Option<AdditionalSwapFeeModeOf<T>> },
+        /// The volume tiers of the additional swap fee were replaced.
+        AdditionalSwapFeeTiersSet { tiers: Vec<VolumeTierOf<T>> },
         /// The account that receives the early exit fee was set.
         EarlyExitFeeAccountSet { new_account: T::AccountId },
+        /// Liquidity provider opened a range position. The liquidity is only used while the spot
+        /// price of `asset` is in the range `lower..=upper`.
+        RangePositionOpened {
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            asset: AssetOf<T>,
+            lower: BalanceOf<T>,
+            upper: BalanceOf<T>,
+            pool_shares_amount: BalanceOf<T>,
+        },
+        /// The spot price left the range of a range position and its liquidity was removed from
+        /// the pool.
+        RangePositionDeactivated {
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            pool_shares_amount: BalanceOf<T>,
+            amounts_parked: Vec<BalanceOf<T>>,
+            new_liquidity_parameter: BalanceOf<T>,
+        },
+        /// The spot price returned to the range of a range position and its liquidity was added
+        /// back to the pool.
+        RangePositionReactivated {
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            pool_shares_amount: BalanceOf<T>,
+            amounts_in: Vec<BalanceOf<T>>,
+            new_liquidity_parameter: BalanceOf<T>,
+        },
+        /// Liquidity provider closed a range position.
+        RangePositionClosed {
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            pool_shares_amount: BalanceOf<T>,
+            amounts_out: Vec<BalanceOf<T>>,
+        },
+        /// An incentive program which streams `amount` units of `asset` to the LPs of the pool
+        /// from block `start` to block `end` was funded.
+        IncentiveProgramCreated {
+            funder: T::AccountId,
+            market_id: MarketIdOf<T>,
+            program_id: IncentiveProgramId,
+            asset: AssetOf<T>,
+            amount: BalanceOf<T>,
+            start: BlockNumberFor<T>,
+            end: BlockNumberFor<T>,
+        },
+        /// Liquidity provider claimed the rewards of an incentive program.
+        IncentivesClaimed {
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            program_id: IncentiveProgramId,
+            asset: AssetOf<T>,
+            amount: BalanceOf<T>,
+        },
+        /// The pool of an incentive program was destroyed and the part of the budget which was
+        /// not streamed was returned to the funder.
+        IncentiveProgramClosed {
+            market_id: MarketIdOf<T>,
+            program_id: IncentiveProgramId,
+            refunded: BalanceOf<T>,
+        },
+        /// Liquidity provider joined the pool using `amount_in` units of collateral. `amount_out`
+        /// is the collateral received for the outcome tokens which were not used to join.
+        JoinWithCollateralExecuted {
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            amount_in: BalanceOf<T>,
+            pool_shares_amount: BalanceOf<T>,
+            amount_out: BalanceOf<T>,
+        },
+        /// Liquidity provider left the pool and exchanged the outcome tokens received for
+        /// `amount_out` units of collateral.
+        ExitToCollateralExecuted {
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            pool_shares_amount: BalanceOf<T>,
+            amount_out: BalanceOf<T>,
+        },
+        /// An incentive program which ended and whose rewards were claimed was removed to make
+        /// room for a new program. `refunded` is the part of the budget which was returned to the
+        /// funder.
+        IncentiveProgramRemoved {
+            market_id: MarketIdOf<T>,
+            program_id: IncentiveProgramId,
+            refunded: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -360,6 +595,36 @@ mod pallet {
         AdditionalSwapFeeNotSet,
         /// The user is not the pallet admin
         SenderNotMarketAdmin,
+        /// The lower bound of the price range is not below the upper bound.
+        InvalidPriceRange,
+        /// The current spot price is not in the specified price range.
+        SpotPriceOutOfRange,
+        /// The account already holds a range position or regular pool shares in this pool.
+        RangePositionExists,
+        /// The account does not hold a range position in this pool.
+        RangePositionNotFound,
+        /// The pool has reached the maximum number of range positions.
+        TooManyRangePositions,
+        /// The bounds of the dynamic swap fee are out of order or the window is empty.
+        InvalidDynamicFeeParameters,
+        /// The minimum of a percentage fee exceeds its maximum.
+        InvalidAdditionalSwapFee,
+        /// The volume tiers are not sorted by strictly increasing volume.
+        VolumeTiersNotSorted,
+        /// The number of volume tiers exceeds `MaxVolumeTiers`.
+        TooManyVolumeTiers,
+        /// The incentive program must not start in the past and must end after it starts.
+        InvalidIncentivePeriod,
+        /// The market has reached the maximum number of incentive programs.
+        TooManyIncentivePrograms,
+        /// The market has no incentive programs.
+        IncentiveProgramNotFound,
+        /// The period of a time-weighted average price is zero.
+        ZeroTwapPeriod,
+        /// The pool's observations don't cover the requested period.
+        InsufficientObservations,
+        /// The range position would add less than the minimum liquidity to the pool.
+        RangePositionTooSmall,
     }
 
     #[derive(Decode, Encode, Eq, PartialEq, PalletError, RuntimeDebug, TypeInfo)]
@@ -404,7 +669,10 @@ mod pallet {
         /// Depends on the implementation of `CompleteSetOperationsApi` and `ExternalFees`; when
         /// using the canonical implementations, the runtime complexity is `O(asset_count)`.
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::buy((*asset_count).saturated_into()))]
+        #[pallet::weight(
+            T::WeightInfo::buy((*asset_count).saturated_into())
+                .saturating_add(Pallet::<T>::range_update_max_weight())
+        )]
         #[transactional]
         pub fn buy(
             origin: OriginFor<T>,
@@ -417,8 +685,10 @@ mod pallet {
             let who = ensure_signed(origin)?;
             let asset_count_real = T::MarketCommons::market(&market_id)?.outcomes();
             ensure!(asset_count == asset_count_real, Error::<T>::IncorrectAssetCount);
-            let _ = Self::do_buy(who, market_id, asset_out, amount_in, min_amount_out)?;
-            Ok(Some(T::WeightInfo::buy(asset_count.into())).into())
+            let _ = Self::do_buy(who, market_id, None, asset_out, amount_in, min_amount_out)?;
+            let weight = T::WeightInfo::buy(asset_count.into())
+                .saturating_add(Self::range_update_max_weight());
+            Ok(Some(weight).into())
         }
 
         /// Sell outcome tokens to the specified market.
@@ -448,7 +718,10 @@ mod pallet {
         /// Depends on the implementation of `CompleteSetOperationsApi` and `ExternalFees`; when
         /// using the canonical implementations, the runtime complexity is `O(asset_count)`.
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::sell((*asset_count).saturated_into()))]
+        #[pallet::weight(
+            T::WeightInfo::sell((*asset_count).saturated_into())
+                .saturating_add(Pallet::<T>::range_update_max_weight())
+        )]
         #[transactional]
         pub fn sell(
             origin: OriginFor<T>,
@@ -461,8 +734,10 @@ mod pallet {
             let who = ensure_signed(origin)?;
             let asset_count_real = T::MarketCommons::market(&market_id)?.outcomes();
             ensure!(asset_count == asset_count_real, Error::<T>::IncorrectAssetCount);
-            let _ = Self::do_sell(who, market_id, asset_in, amount_in, min_amount_out)?;
-            Ok(Some(T::WeightInfo::sell(asset_count.into())).into())
+            let _ = Self::do_sell(who, market_id, None, asset_in, amount_in, min_amount_out)?;
+            let weight = T::WeightInfo::sell(asset_count.into())
+                .saturating_add(Self::range_update_max_weight());
+            Ok(Some(weight).into())
         }
 
         /// Join the liquidity pool for the specified market.
@@ -492,6 +767,7 @@ mod pallet {
             T::WeightInfo::join_in_place(max_amounts_in.len().saturated_into())
                 .max(T::WeightInfo::join_reassigned(max_amounts_in.len().saturated_into()))
                 .max(T::WeightInfo::join_leaf(max_amounts_in.len().saturated_into()))
+                .saturating_add(T::WeightInfo::settle_incentives())
         )]
         #[transactional]
         pub fn join(
@@ -507,7 +783,7 @@ mod pallet {
             let _: u32 =
                 max_amounts_in.len().try_into().map_err(|_| Error::<T>::NarrowingConversion)?;
             ensure!(max_amounts_in.len() == asset_count_usize, Error::<T>::IncorrectVecLen);
-            Self::do_join(who, market_id, pool_shares_amount, max_amounts_in)
+            Self::do_join(who, market_id, None, pool_shares_amount, max_amounts_in)
         }
 
         /// Exit the liquidity pool for the specified market.
@@ -543,7 +819,10 @@ mod pallet {
         /// pool's liquidity tree, or, equivalently, `log_2(m)` where `m` is the number of liquidity
         /// providers in the pool.
         #[pallet::call_index(3)]
-        #[pallet::weight(T::WeightInfo::exit(min_amounts_out.len().saturated_into()))]
+        #[pallet::weight(
+            T::WeightInfo::exit(min_amounts_out.len().saturated_into())
+                .saturating_add(T::WeightInfo::settle_incentives())
+        )]
         #[transactional]
         pub fn exit(
             origin: OriginFor<T>,
@@ -557,8 +836,10 @@ mod pallet {
             let min_amounts_out_len: u32 =
                 min_amounts_out.len().try_into().map_err(|_| Error::<T>::NarrowingConversion)?;
             ensure!(min_amounts_out_len == asset_count_u32, Error::<T>::IncorrectVecLen);
-            Self::do_exit(who, market_id, pool_shares_amount_out, min_amounts_out)?;
-            Ok(Some(T::WeightInfo::exit(min_amounts_out_len)).into())
+            Self::do_exit(who, market_id, None, pool_shares_amount_out, min_amounts_out)?;
+            let weight = T::WeightInfo::exit(min_amounts_out_len)
+                .saturating_add(T::WeightInfo::settle_incentives());
+            Ok(Some(weight).into())
         }
 
         /// Withdraw swap fees from the specified market.
@@ -581,7 +862,7 @@ mod pallet {
             #[pallet::compact] market_id: MarketIdOf<T>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            Self::do_withdraw_fees(who, market_id)?;
+            Self::do_withdraw_fees(who, market_id, None)?;
             Ok(())
         }
 
@@ -625,13 +906,16 @@ mod pallet {
             let spot_prices_len: u32 =
                 spot_prices.len().try_into().map_err(|_| Error::<T>::NarrowingConversion)?;
             ensure!(spot_prices_len == asset_count_u32, Error::<T>::IncorrectVecLen);
-            Self::do_deploy_pool(who, market_id, amount, spot_prices, swap_fee)?;
+            Self::do_deploy_pool(who, market_id, None, amount, spot_prices, swap_fee)?;
             Ok(Some(T::WeightInfo::deploy_pool(spot_prices_len)).into())
         }
 
         // TODO update weight
         #[pallet::call_index(6)]
-        #[pallet::weight(T::WeightInfo::signed_join(max_amounts_in.len().saturated_into()))]
+        #[pallet::weight(
+            T::WeightInfo::signed_join(max_amounts_in.len().saturated_into())
+                .saturating_add(T::WeightInfo::settle_incentives())
+        )]
         #[transactional]
         pub fn signed_join(
             origin: OriginFor<T>,
@@ -669,7 +953,7 @@ mod pallet {
                 max_amounts_in.len().try_into().map_err(|_| Error::<T>::NarrowingConversion)?;
             ensure!(max_amounts_in.len() == asset_count_usize, Error::<T>::IncorrectVecLen);
 
-            Self::do_join(who.clone(), market_id, pool_shares_amount, max_amounts_in)?;
+            Self::do_join(who.clone(), market_id, None, pool_shares_amount, max_amounts_in)?;
 
             Ok(().into())
         }
@@ -700,7 +984,7 @@ mod pallet {
                 Error::<T>::UnauthorizedSignedTransaction
             );
 
-            Self::do_withdraw_fees(who, market_id)?;
+            Self::do_withdraw_fees(who, market_id, None)?;
 
             // TODO return weight
             Ok(().into())
@@ -708,7 +992,10 @@ mod pallet {
 
         // TODO update weight
         #[pallet::call_index(8)]
-        #[pallet::weight(T::WeightInfo::signed_exit(min_amounts_out.len().saturated_into()))]
+        #[pallet::weight(
+            T::WeightInfo::signed_exit(min_amounts_out.len().saturated_into())
+                .saturating_add(T::WeightInfo::settle_incentives())
+        )]
         #[transactional]
         pub fn signed_exit(
             origin: OriginFor<T>,
@@ -745,7 +1032,7 @@ mod pallet {
             let min_amounts_out_len: u32 =
                 min_amounts_out.len().try_into().map_err(|_| Error::<T>::NarrowingConversion)?;
             ensure!(min_amounts_out_len == asset_count_u32, Error::<T>::IncorrectVecLen);
-            Self::do_exit(who, market_id, pool_shares_amount_out, min_amounts_out)?;
+            Self::do_exit(who, market_id, None, pool_shares_amount_out, min_amounts_out)?;
 
             // TODO return weight
             Ok(().into())
@@ -770,217 +1057,947 @@ mod pallet {
         #[pallet::call_index(10)]
         #[pallet::weight(T::WeightInfo::set_additional_swap_fee())]
         #[transactional]
-        pub fn set_additional_swap_fee(origin: OriginFor<T>, fee: BalanceOf<T>) -> DispatchResult {
+        pub fn set_additional_swap_fee(
+            origin: OriginFor<T>,
+            fee: AdditionalSwapFeeModeOf<T>,
+        ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(who == T::PalletAdminGetter::get_admin()?, Error::<T>::SenderNotMarketAdmin);
+            ensure!(fee.is_valid(), Error::<T>::InvalidAdditionalSwapFee);
 
             <AdditionalSwapFee<T>>::mutate(|f| *f = Some(fee));
             Self::deposit_event(Event::AdditionalSwapFeeSet { new_fee: fee });
 
             Ok(())
         }
-    }
-
-    impl<T: Config> Pallet<T> {
-        #[require_transactional]
-        fn do_buy(
-            who: T::AccountId,
-            market_id: MarketIdOf<T>,
-            asset_out: AssetOf<T>,
-            amount_in: BalanceOf<T>,
-            min_amount_out: BalanceOf<T>,
-        ) -> Result<AmmTradeOf<T>, DispatchError> {
-            ensure!(amount_in != Zero::zero(), Error::<T>::ZeroAmount);
-            let market = T::MarketCommons::market(&market_id)?;
-            ensure!(market.status == MarketStatus::Active, Error::<T>::MarketNotActive);
-            Self::try_mutate_pool(&market_id, |pool| {
-                ensure!(pool.contains(&asset_out), Error::<T>::AssetNotFound);
-                T::MultiCurrency::transfer(pool.collateral, &who, &pool.account_id, amount_in)?;
-                let FeeDistribution {
-                    remaining: amount_in_minus_fees,
-                    swap_fees: swap_fee_amount,
-                    external_fees: external_fee_amount,
-                } = Self::distribute_fees(market_id, pool, amount_in)?;
-                ensure!(
-                    amount_in_minus_fees <= pool.calculate_numerical_threshold(),
-                    Error::<T>::NumericalLimits(NumericalLimitsError::MaxAmountExceeded),
-                );
-                ensure!(
-                    pool.calculate_buy_ln_argument(asset_out, amount_in_minus_fees)? >=
-                        LN_NUMERICAL_LIMIT.saturated_into(),
-                    Error::<T>::NumericalLimits(NumericalLimitsError::MinAmountNotMet),
-                );
-                let swap_amount_out =
-                    pool.calculate_swap_amount_out_for_buy(asset_out, amount_in_minus_fees)?;
-                let amount_out = swap_amount_out.checked_add_res(&amount_in_minus_fees)?;
-                ensure!(amount_out >= min_amount_out, Error::<T>::AmountOutBelowMin);
-                // Instead of letting `who` buy the complete sets and then transfer almost all of
-                // the outcomes to the pool account, we prevent `(n-1)` storage reads by using the
-                // pool account to buy. Note that the fees are already in the pool at this point.
-                T::CompleteSetOperations::buy_complete_set(
-                    pool.account_id.clone(),
-                    market_id,
-                    amount_in_minus_fees,
-                )?;
-                T::MultiCurrency::transfer(asset_out, &pool.account_id, &who, amount_out)?;
-                for asset in pool.assets().iter() {
-                    pool.increase_reserve(asset, &amount_in_minus_fees)?;
-                    if *asset == asset_out {
-                        pool.decrease_reserve(asset, &amount_out)?;
-                    }
-                }
-                Self::deposit_event(Event::<T>::BuyExecuted {
-                    who: who.clone(),
-                    market_id,
-                    asset_out,
-                    amount_in,
-                    amount_out,
-                    swap_fee_amount,
-                    external_fee_amount,
-                });
-                Ok(AmmTrade { amount_in, amount_out, swap_fee_amount, external_fee_amount })
-            })
-        }
 
-        #[require_transactional]
-        fn do_sell(
-            who: T::AccountId,
-            market_id: MarketIdOf<T>,
-            asset_in: AssetOf<T>,
-            amount_in: BalanceOf<T>,
-            min_amount_out: BalanceOf<T>,
-        ) -> Result<AmmTradeOf<T>, DispatchError> {
-            ensure!(amount_in != Zero::zero(), Error::<T>::ZeroAmount);
-            let market = T::MarketCommons::market(&market_id)?;
-            ensure!(market.status == MarketStatus::Active, Error::<T>::MarketNotActive);
-            Self::try_mutate_pool(&market_id, |pool| {
-                ensure!(pool.contains(&asset_in), Error::<T>::AssetNotFound);
-                // Ensure that the price of `asset_in` is at least `exp(-EXP_NUMERICAL_LIMITS) =
-                // 4.5399...e-05`.
-                ensure!(
-                    pool.reserve_of(&asset_in)? <= pool.calculate_numerical_threshold(),
-                    Error::<T>::NumericalLimits(NumericalLimitsError::SpotPriceTooLow),
-                );
-                ensure!(
-                    amount_in <= pool.calculate_numerical_threshold(),
-                    Error::<T>::NumericalLimits(NumericalLimitsError::MaxAmountExceeded),
-                );
-                // Instead of first executing a swap with `(n-1)` transfers from the pool account to
-                // `who` and then selling complete sets, we prevent `(n-1)` storage reads: 1)
-                // Transfer `amount_in` units of `asset_in` to the pool account, 2) sell
-                // `amount_out` complete sets using the pool account, 3) transfer
-                // `amount_out_minus_fees` units of collateral to `who`. The fees automatically end
-                // up in the pool.
-                let amount_out = pool.calculate_swap_amount_out_for_sell(asset_in, amount_in)?;
-                // Beware! This transfer **must** happen _after_ calculating `amount_out`:
-                T::MultiCurrency::transfer(asset_in, &who, &pool.account_id, amount_in)?;
-                T::CompleteSetOperations::sell_complete_set(
-                    pool.account_id.clone(),
-                    market_id,
-                    amount_out,
-                )?;
-                let FeeDistribution {
-                    remaining: amount_out_minus_fees,
-                    swap_fees: swap_fee_amount,
-                    external_fees: external_fee_amount,
-                } = Self::distribute_fees(market_id, pool, amount_out)?;
-                ensure!(amount_out_minus_fees >= min_amount_out, Error::<T>::AmountOutBelowMin);
-                T::MultiCurrency::transfer(
-                    pool.collateral,
-                    &pool.account_id,
-                    &who,
-                    amount_out_minus_fees,
-                )?;
-                for asset in pool.assets().iter() {
-                    if *asset == asset_in {
-                        pool.increase_reserve(asset, &amount_in)?;
-                    }
-                    pool.decrease_reserve(asset, &amount_out)?;
-                }
-                // Ensure that the sell doesn't move the price below the minimum defined by
-                // `EXP_NUMERICAL_LIMITS` (see comment above).
-                ensure!(
-                    pool.reserve_of(&asset_in)? <= pool.calculate_numerical_threshold(),
-                    Error::<T>::NumericalLimits(NumericalLimitsError::SpotPriceSlippedTooLow),
-                );
-                Self::deposit_event(Event::<T>::SellExecuted {
-                    who: who.clone(),
-                    market_id,
-                    asset_in,
-                    amount_in,
-                    amount_out: amount_out_minus_fees,
-                    swap_fee_amount,
-                    external_fee_amount,
-                });
-                Ok(AmmTrade {
-                    amount_in,
-                    amount_out: amount_out_minus_fees,
-                    swap_fee_amount,
-                    external_fee_amount,
-                })
-            })
+        /// Deploy a conditional pool for the specified market.
+        ///
+        /// The pool trades the positions obtained by conditioning `parent_collection_id` on each
+        /// outcome of `market_id` against the position of `parent_collection_id`, which serves as
+        /// the pool's collateral. Buying and selling splits and merges positions instead of buying
+        /// and selling complete sets. A market can have one conditional pool per parent collection
+        /// in addition to its regular pool.
+        ///
+        /// As with `deploy_pool`, the sender must acquire the outcome positions in advance, here by
+        /// splitting `amount` units of `parent_collection_id` into singleton index sets of the
+        /// market's outcomes. The `spot_prices` are ordered by outcome index. Note that the
+        /// amounts passed to `join_conditional` and `exit_conditional` are ordered like the pool's
+        /// assets, which are sorted by collection ID.
+        ///
+        /// Conditional pools are traded and provided with liquidity using the `*_conditional`
+        /// calls. They are not routed by the hybrid router and don't support range
+        /// positions, dynamic swap fees, incentive programs or price observations.
+        ///
+        /// # Complexity
+        ///
+        /// `O(n)` where `n` is the number of assets in the pool.
+        #[pallet::call_index(11)]
+        #[pallet::weight(
+            T::WeightInfo::deploy_combinatorial_pool(spot_prices.len().saturated_into())
+        )]
+        #[transactional]
+        pub fn deploy_combinatorial_pool(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            parent_collection_id: CombinatorialId,
+            #[pallet::compact] amount: BalanceOf<T>,
+            spot_prices: Vec<BalanceOf<T>>,
+            #[pallet::compact] swap_fee: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let asset_count = T::MarketCommons::market(&market_id)?.outcomes();
+            let asset_count_u32: u32 = asset_count.into();
+            let spot_prices_len: u32 =
+                spot_prices.len().try_into().map_err(|_| Error::<T>::NarrowingConversion)?;
+            ensure!(spot_prices_len == asset_count_u32, Error::<T>::IncorrectVecLen);
+            Self::do_deploy_pool(
+                who,
+                market_id,
+                Some(parent_collection_id),
+                amount,
+                spot_prices,
+                swap_fee,
+            )?;
+            Ok(Some(T::WeightInfo::deploy_combinatorial_pool(spot_prices_len)).into())
         }
 
-        #[require_transactional]
-        fn do_join(
-            who: T::AccountId,
-            market_id: MarketIdOf<T>,
-            pool_shares_amount: BalanceOf<T>,
+        /// Join the liquidity pool for the specified market with liquidity that only applies
+        /// while the spot price of `asset` is in the range `lower..=upper`.
+        ///
+        /// The position is opened like a regular `join` and receives swap fees like any other LP
+        /// while the spot price is in range. As soon as a trade moves the spot price out of range,
+        /// the position's share of the reserves is taken out of the pool and parked in the pool
+        /// account; the position then no longer earns fees and no longer contributes to the pool's
+        /// liquidity parameter. When a trade moves the spot price back into range, as much of the
+        /// parked funds as the current reserve ratios permit are added back to the pool.
+        ///
+        /// The current spot price must be in the range. Each account may hold either regular pool
+        /// shares or a single range position per pool. Since the number of range positions per
+        /// pool is limited, each position must add at least the minimum liquidity of a pool. The
+        /// liquidity of a pool can't be parked entirely, so a position isn't deactivated if
+        /// doing so would drop the liquidity parameter below the minimum.
+        ///
+        /// # Parameters
+        ///
+        /// - `market_id`: Identifier for the market related to the pool.
+        /// - `pool_shares_amount`: The number of new pool shares the LP will receive.
+        /// - `max_amounts_in`: Vector of the maximum amounts of each outcome token the LP is
+        ///   willing to deposit (with outcomes specified in the order of `MarketCommonsApi`).
+        /// - `asset`: The asset whose spot price defines the range.
+        /// - `lower`: The lower bound of the range.
+        /// - `upper`: The upper bound of the range.
+        ///
+        /// # Complexity
+        ///
+        /// `O(n + d + m)` where `n` is the number of assets in the pool, `d` is the depth of the
+        /// pool's liquidity tree and `m` is the number of range positions of the pool.
+        #[pallet::call_index(12)]
+        #[pallet::weight(
+            T::WeightInfo::join_range(max_amounts_in.len().saturated_into())
+                .saturating_add(T::WeightInfo::settle_incentives())
+        )]
+        #[transactional]
+        pub fn join_range(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            #[pallet::compact] pool_shares_amount: BalanceOf<T>,
             max_amounts_in: Vec<BalanceOf<T>>,
+            asset: AssetOf<T>,
+            #[pallet::compact] lower: BalanceOf<T>,
+            #[pallet::compact] upper: BalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
-            ensure!(pool_shares_amount != Zero::zero(), Error::<T>::ZeroAmount);
-            let market = T::MarketCommons::market(&market_id)?;
-            ensure!(market.status == MarketStatus::Active, Error::<T>::MarketNotActive);
-            let asset_count_u16: u16 =
+            let who = ensure_signed(origin)?;
+            let asset_count = T::MarketCommons::market(&market_id)?.outcomes();
+            let asset_count_u32: u32 = asset_count.into();
+            let max_amounts_in_len: u32 =
+                max_amounts_in.len().try_into().map_err(|_| Error::<T>::NarrowingConversion)?;
+            ensure!(max_amounts_in_len == asset_count_u32, Error::<T>::IncorrectVecLen);
+            Self::do_join_range(
+                who,
+                market_id,
+                pool_shares_amount,
+                max_amounts_in,
+                asset,
+                lower,
+                upper,
+            )?;
+            let weight = T::WeightInfo::join_range(max_amounts_in_len)
+                .saturating_add(T::WeightInfo::settle_incentives());
+            Ok(Some(weight).into())
+        }
+
+        /// Close the caller's range position in the pool of the specified market.
+        ///
+        /// The LP receives the funds parked while the position was out of range and, if the
+        /// position is active, its share of the reserves minus the exit fee (while the market is
+        /// active). As with `exit`, fees must be withdrawn beforehand. If no pool shares are left
+        /// after closing the position, the pool is destroyed.
+        ///
+        /// # Parameters
+        ///
+        /// - `market_id`: Identifier for the market related to the pool.
+        /// - `min_amounts_out`: Vector of the minimum amounts of each outcome token the LP expects
+        ///   to withdraw (with outcomes specified in the order given by `MarketCommonsApi`).
+        ///
+        /// # Complexity
+        ///
+        /// `O(n + d)` where `n` is the number of assets in the pool and `d` is the depth of the
+        /// pool's liquidity tree.
+        #[pallet::call_index(13)]
+        #[pallet::weight(
+            T::WeightInfo::exit_range(min_amounts_out.len().saturated_into())
+                .saturating_add(T::WeightInfo::settle_incentives())
+        )]
+        #[transactional]
+        pub fn exit_range(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            min_amounts_out: Vec<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let asset_count = T::MarketCommons::market(&market_id)?.outcomes();
+            let asset_count_u32: u32 = asset_count.into();
+            let min_amounts_out_len: u32 =
+                min_amounts_out.len().try_into().map_err(|_| Error::<T>::NarrowingConversion)?;
+            ensure!(min_amounts_out_len == asset_count_u32, Error::<T>::IncorrectVecLen);
+            Self::do_exit_range(who, market_id, min_amounts_out)?;
+            let weight = T::WeightInfo::exit_range(min_amounts_out_len)
+                .saturating_add(T::WeightInfo::settle_incentives());
+            Ok(Some(weight).into())
+        }
+
+        /// Deploy a pool whose swap fee adapts to recent price movements.
+        ///
+        /// Behaves like `deploy_pool`, except that the swap fee is recalculated before each trade
+        /// as `min_fee + sensitivity * volatility`, capped at `max_fee`, where `volatility` is the
+        /// largest change of any spot price since the start of the current window of `window`
+        /// blocks. The volatility is halved for every window which passes, so the fee returns to
+        /// `min_fee` when trading is quiet. The fee charged is reported in the `BuyExecuted` and
+        /// `SellExecuted` events.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The account that deploys the pool.
+        /// - `market_id`: The ID of the market for which the pool is deployed.
+        /// - `amount`: The amount of each token to add to the pool.
+        /// - `spot_prices`: The initial spot prices of the pool assets.
+        /// - `params`: The bounds and sensitivity of the swap fee and the length of a window.
+        ///   `min_fee` and `max_fee` must satisfy the same bounds as the fee of `deploy_pool`.
+        ///
+        /// # Complexity
+        ///
+        /// `O(n)` where `n` is the number of assets in the pool.
+        #[pallet::call_index(14)]
+        #[pallet::weight(
+            T::WeightInfo::deploy_pool_with_dynamic_fee(spot_prices.len().saturated_into())
+        )]
+        #[transactional]
+        pub fn deploy_pool_with_dynamic_fee(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+            spot_prices: Vec<BalanceOf<T>>,
+            params: DynamicFeeParamsOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let asset_count = T::MarketCommons::market(&market_id)?.outcomes();
+            let asset_count_u32: u32 = asset_count.into();
+            let spot_prices_len: u32 =
+                spot_prices.len().try_into().map_err(|_| Error::<T>::NarrowingConversion)?;
+            ensure!(spot_prices_len == asset_count_u32, Error::<T>::IncorrectVecLen);
+            Self::do_deploy_pool_with_dynamic_fee(who, market_id, amount, spot_prices, params)?;
+            Ok(Some(T::WeightInfo::deploy_pool_with_dynamic_fee(spot_prices_len)).into())
+        }
+
+        /// Set the additional swap fee of markets with base asset `asset`, which takes precedence
+        /// over the default set with `set_additional_swap_fee`. If `fee` is `None`, the schedule
+        /// is removed and the default applies again.
+        ///
+        /// Can only be called by the pallet admin.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::set_additional_swap_fee_schedule())]
+        #[transactional]
+        pub fn set_additional_swap_fee_schedule(
+            origin: OriginFor<T>,
+            asset: AssetOf<T>,
+            fee: Option<AdditionalSwapFeeModeOf<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(who == T::PalletAdminGetter::get_admin()?, Error::<T>::SenderNotMarketAdmin);
+            if let Some(fee) = fee {
+                ensure!(fee.is_valid(), Error::<T>::InvalidAdditionalSwapFee);
+            }
+
+            AdditionalSwapFeeSchedules::<T>::set(asset, fee);
+            Self::deposit_event(Event::AdditionalSwapFeeScheduleSet { asset, fee });
+
+            Ok(())
+        }
+
+        /// Replace the volume tiers of the additional swap fee.
+        ///
+        /// Each tier waives a share of the additional swap fee for accounts whose trading volume
+        /// is at least the tier's `min_volume`. If several tiers apply, the one with the largest
+        /// `min_volume` is used. The tiers must be sorted by strictly increasing `min_volume`.
+        ///
+        /// Can only be called by the pallet admin.
+        ///
+        /// # Complexity
+        ///
+        /// `O(n)` where `n` is the number of tiers.
+        #[pallet::call_index(16)]
+        #[pallet::weight(
+            T::WeightInfo::set_additional_swap_fee_tiers(tiers.len().saturated_into())
+        )]
+        #[transactional]
+        pub fn set_additional_swap_fee_tiers(
+            origin: OriginFor<T>,
+            tiers: Vec<VolumeTierOf<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(who == T::PalletAdminGetter::get_admin()?, Error::<T>::SenderNotMarketAdmin);
+            ensure!(
+                tiers.windows(2).all(|pair| pair[0].min_volume < pair[1].min_volume),
+                Error::<T>::VolumeTiersNotSorted
+            );
+            let bounded_tiers: VolumeTiersOf<T> =
+                tiers.clone().try_into().map_err(|_| Error::<T>::TooManyVolumeTiers)?;
+
+            AdditionalSwapFeeTiers::<T>::put(bounded_tiers);
+            Self::deposit_event(Event::AdditionalSwapFeeTiersSet { tiers });
+
+            Ok(())
+        }
+
+        /// Fund an incentive program which streams `amount` units of `asset` to the LPs of the
+        /// pool of the specified market.
+        ///
+        /// Rewards are streamed at a constant rate from block `start` to block `end` and are
+        /// distributed among the LPs in proportion to their pool shares at the time. Inactive
+        /// range positions hold no pool shares and receive no rewards. LPs collect their rewards
+        /// using `claim_incentives`. If the pool is destroyed before the program ends, the rest of
+        /// the budget is returned to the funder.
+        ///
+        /// Funding the program costs the sender an additional fee to the tune of the asset's
+        /// existential deposit, which keeps the account holding the budget alive.
+        ///
+        /// If the market already has `MaxIncentivePrograms` programs, the oldest program which has
+        /// ended and whose rewards were claimed is removed to make room and the fee is refunded.
+        ///
+        /// Can only be called by the market creator or the pallet admin.
+        ///
+        /// # Parameters
+        ///
+        /// - `market_id`: Identifier for the market related to the pool.
+        /// - `asset`: The asset in which rewards are paid.
+        /// - `amount`: The total amount of rewards.
+        /// - `start`: The block from which rewards are streamed. Must not be in the past.
+        /// - `end`: The block in which the program ends.
+        ///
+        /// # Complexity
+        ///
+        /// `O(p + m)` where `p` is the number of incentive programs of the market and `m` is the
+        /// number of LPs of the removed program.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::create_incentive_program())]
+        #[transactional]
+        pub fn create_incentive_program(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            asset: AssetOf<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+            start: BlockNumberFor<T>,
+            end: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_create_incentive_program(who, market_id, asset, amount, start, end)?;
+            Ok(())
+        }
+
+        /// Claim the rewards of all incentive programs of the specified market.
+        ///
+        /// Rewards remain claimable after the caller left the pool or the pool was destroyed.
+        ///
+        /// # Parameters
+        ///
+        /// - `market_id`: Identifier for the market related to the pool.
+        ///
+        /// # Complexity
+        ///
+        /// `O(n)` where `n` is the number of incentive programs of the market.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::claim_incentives())]
+        #[transactional]
+        pub fn claim_incentives(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_claim_incentives(who, market_id)?;
+            Ok(())
+        }
+
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::signed_claim_incentives())]
+        #[transactional]
+        pub fn signed_claim_incentives(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            block_number: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(who == proof.signer, Error::<T>::SenderIsNotSigner);
+            ensure!(
+                block_number.saturating_add(T::SignedTxLifetime::get().into()) >
+                    frame_system::Pallet::<T>::block_number(),
+                Error::<T>::SignedTransactionExpired
+            );
+
+            let encoded_payload = Self::encode_signed_claim_incentives_params(
+                &proof.relayer,
+                &market_id,
+                &block_number,
+            );
+
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &encoded_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedTransaction
+            );
+
+            Self::do_claim_incentives(who, market_id)?;
+
+            Ok(().into())
+        }
+
+        /// Join the liquidity pool for the specified market using collateral only.
+        ///
+        /// Buys `amount_in` complete sets and joins the pool with as many pool shares as these
+        /// permit. The outcome tokens which are not required to join are sold to the pool, so the
+        /// LP ends up holding only pool shares and collateral. Amounts too small to be sold are
+        /// kept by the LP.
+        ///
+        /// # Parameters
+        ///
+        /// - `market_id`: Identifier for the market related to the pool.
+        /// - `asset_count`: Number of assets in the pool.
+        /// - `amount_in`: Amount of collateral paid by the LP.
+        /// - `min_pool_shares_amount`: Minimum number of pool shares the LP expects to receive.
+        /// - `min_leftover_amounts_out`: Vector of the minimum amounts of collateral the LP expects
+        ///   to receive for the leftover of each outcome, in the order of the pool's assets.
+        ///
+        /// # Complexity
+        ///
+        /// `O(n^2 + d)` where `n` is the number of assets in the pool and `d` is the depth of the
+        /// pool's liquidity tree, as each leftover outcome token is sold separately.
+        #[pallet::call_index(20)]
+        #[pallet::weight(
+            T::WeightInfo::join_with_collateral((*asset_count).saturated_into())
+                .saturating_add(T::WeightInfo::settle_incentives())
+        )]
+        #[transactional]
+        pub fn join_with_collateral(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            asset_count: AssetIndexType,
+            #[pallet::compact] amount_in: BalanceOf<T>,
+            #[pallet::compact] min_pool_shares_amount: BalanceOf<T>,
+            min_leftover_amounts_out: Vec<BalanceOf<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_join_with_collateral(
+                who,
+                market_id,
+                asset_count,
+                amount_in,
+                min_pool_shares_amount,
+                min_leftover_amounts_out,
+            )?;
+            Ok(())
+        }
+
+        /// Exit the liquidity pool for the specified market and receive collateral only.
+        ///
+        /// Behaves like `exit`, after which the outcome tokens received are exchanged for
+        /// collateral: Complete sets are sold and the rest is sold to the pool. If the exit
+        /// destroys the pool, the rest is kept by the LP. As with `exit`, fees must be
+        /// withdrawn beforehand.
+        ///
+        /// # Parameters
+        ///
+        /// - `market_id`: Identifier for the market related to the pool.
+        /// - `asset_count`: Number of assets in the pool.
+        /// - `pool_shares_amount`: The number of pool shares to burn.
+        /// - `min_amount_out`: Minimum amount of collateral the LP expects to receive.
+        /// - `min_leftover_amounts_out`: Vector of the minimum amounts of collateral the LP expects
+        ///   to receive for the leftover of each outcome, in the order of the pool's assets.
+        ///
+        /// # Complexity
+        ///
+        /// `O(n^2 + d)` where `n` is the number of assets in the pool and `d` is the depth of the
+        /// pool's liquidity tree, as each leftover outcome token is sold separately.
+        #[pallet::call_index(21)]
+        #[pallet::weight(
+            T::WeightInfo::exit_to_collateral((*asset_count).saturated_into())
+                .saturating_add(T::WeightInfo::settle_incentives())
+        )]
+        #[transactional]
+        pub fn exit_to_collateral(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            asset_count: AssetIndexType,
+            #[pallet::compact] pool_shares_amount: BalanceOf<T>,
+            #[pallet::compact] min_amount_out: BalanceOf<T>,
+            min_leftover_amounts_out: Vec<BalanceOf<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_exit_to_collateral(
+                who,
+                market_id,
+                asset_count,
+                pool_shares_amount,
+                min_amount_out,
+                min_leftover_amounts_out,
+            )?;
+            Ok(())
+        }
+
+        #[pallet::call_index(22)]
+        #[pallet::weight(
+            T::WeightInfo::signed_join_with_collateral((*asset_count).saturated_into())
+                .saturating_add(T::WeightInfo::settle_incentives())
+        )]
+        #[transactional]
+        pub fn signed_join_with_collateral(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            asset_count: AssetIndexType,
+            #[pallet::compact] amount_in: BalanceOf<T>,
+            #[pallet::compact] min_pool_shares_amount: BalanceOf<T>,
+            min_leftover_amounts_out: Vec<BalanceOf<T>>,
+            block_number: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(who == proof.signer, Error::<T>::SenderIsNotSigner);
+            ensure!(
+                block_number.saturating_add(T::SignedTxLifetime::get().into()) >
+                    frame_system::Pallet::<T>::block_number(),
+                Error::<T>::SignedTransactionExpired
+            );
+
+            let encoded_payload = Self::encode_signed_join_with_collateral_params(
+                &proof.relayer,
+                &market_id,
+                &asset_count,
+                &amount_in,
+                &min_pool_shares_amount,
+                &min_leftover_amounts_out,
+                &block_number,
+            );
+
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &encoded_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedTransaction
+            );
+
+            Self::do_join_with_collateral(
+                who,
+                market_id,
+                asset_count,
+                amount_in,
+                min_pool_shares_amount,
+                min_leftover_amounts_out,
+            )?;
+
+            Ok(().into())
+        }
+
+        #[pallet::call_index(23)]
+        #[pallet::weight(
+            T::WeightInfo::signed_exit_to_collateral((*asset_count).saturated_into())
+                .saturating_add(T::WeightInfo::settle_incentives())
+        )]
+        #[transactional]
+        pub fn signed_exit_to_collateral(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            asset_count: AssetIndexType,
+            #[pallet::compact] pool_shares_amount: BalanceOf<T>,
+            #[pallet::compact] min_amount_out: BalanceOf<T>,
+            min_leftover_amounts_out: Vec<BalanceOf<T>>,
+            block_number: BlockNumberFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(who == proof.signer, Error::<T>::SenderIsNotSigner);
+            ensure!(
+                block_number.saturating_add(T::SignedTxLifetime::get().into()) >
+                    frame_system::Pallet::<T>::block_number(),
+                Error::<T>::SignedTransactionExpired
+            );
+
+            let encoded_payload = Self::encode_signed_exit_to_collateral_params(
+                &proof.relayer,
+                &market_id,
+                &asset_count,
+                &pool_shares_amount,
+                &min_amount_out,
+                &min_leftover_amounts_out,
+                &block_number,
+            );
+
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &encoded_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedTransaction
+            );
+
+            Self::do_exit_to_collateral(
+                who,
+                market_id,
+                asset_count,
+                pool_shares_amount,
+                min_amount_out,
+                min_leftover_amounts_out,
+            )?;
+
+            Ok(().into())
+        }
+
+        /// Buy outcome positions from the conditional pool of the specified market whose positions
+        /// are conditioned on `parent_collection_id`.
+        ///
+        /// Behaves like `buy`, except that `amount_in` is paid in the position of
+        /// `parent_collection_id`, which is split into the positions of the pool instead of buying
+        /// complete sets.
+        ///
+        /// # Complexity
+        ///
+        /// `O(n)` where `n` is the number of assets in the pool.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::buy_conditional((*asset_count).saturated_into()))]
+        #[transactional]
+        pub fn buy_conditional(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            parent_collection_id: CombinatorialId,
+            asset_count: AssetIndexType,
+            asset_out: AssetOf<T>,
+            #[pallet::compact] amount_in: BalanceOf<T>,
+            #[pallet::compact] min_amount_out: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let asset_count_real = T::MarketCommons::market(&market_id)?.outcomes();
+            ensure!(asset_count == asset_count_real, Error::<T>::IncorrectAssetCount);
+            let _ = Self::do_buy(
+                who,
+                market_id,
+                Some(parent_collection_id),
+                asset_out,
+                amount_in,
+                min_amount_out,
+            )?;
+            Ok(Some(T::WeightInfo::buy_conditional(asset_count.into())).into())
+        }
+
+        /// Sell outcome positions to the conditional pool of the specified market whose positions
+        /// are conditioned on `parent_collection_id`.
+        ///
+        /// Behaves like `sell`, except that the positions of the pool are merged into the position
+        /// of `parent_collection_id` instead of selling complete sets, and the seller receives the
+        /// parent position.
+        ///
+        /// # Complexity
+        ///
+        /// `O(n)` where `n` is the number of assets in the pool.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::sell_conditional((*asset_count).saturated_into()))]
+        #[transactional]
+        pub fn sell_conditional(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            parent_collection_id: CombinatorialId,
+            asset_count: AssetIndexType,
+            asset_in: AssetOf<T>,
+            #[pallet::compact] amount_in: BalanceOf<T>,
+            #[pallet::compact] min_amount_out: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let asset_count_real = T::MarketCommons::market(&market_id)?.outcomes();
+            ensure!(asset_count == asset_count_real, Error::<T>::IncorrectAssetCount);
+            let _ = Self::do_sell(
+                who,
+                market_id,
+                Some(parent_collection_id),
+                asset_in,
+                amount_in,
+                min_amount_out,
+            )?;
+            Ok(Some(T::WeightInfo::sell_conditional(asset_count.into())).into())
+        }
+
+        /// Join the conditional pool of the specified market whose positions are conditioned on
+        /// `parent_collection_id`.
+        ///
+        /// Behaves like `join`. The `max_amounts_in` are ordered like the pool's assets.
+        ///
+        /// # Complexity
+        ///
+        /// `O(n + d)` where `n` is the number of assets in the pool and `d` is the depth of the
+        /// pool's liquidity tree.
+        #[pallet::call_index(26)]
+        #[pallet::weight(
+            T::WeightInfo::join_in_place(max_amounts_in.len().saturated_into())
+                .max(T::WeightInfo::join_reassigned(max_amounts_in.len().saturated_into()))
+                .max(T::WeightInfo::join_leaf(max_amounts_in.len().saturated_into()))
+        )]
+        #[transactional]
+        pub fn join_conditional(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            parent_collection_id: CombinatorialId,
+            #[pallet::compact] pool_shares_amount: BalanceOf<T>,
+            max_amounts_in: Vec<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let asset_count = T::MarketCommons::market(&market_id)?.outcomes();
+            let asset_count_usize: usize = asset_count.into();
+            // Ensure that the conversion in the weight calculation doesn't saturate.
+            let _: u32 =
                 max_amounts_in.len().try_into().map_err(|_| Error::<T>::NarrowingConversion)?;
-            let asset_count_u32: u32 = asset_count_u16.into();
-            ensure!(asset_count_u16 == market.outcomes(), Error::<T>::IncorrectAssetCount);
-            let benchmark_info = Self::try_mutate_pool(&market_id, |pool| {
-                let ratio =
-                    pool_shares_amount.bdiv_ceil(pool.liquidity_shares_manager.total_shares()?)?;
-                // Ensure that new LPs contribute at least MIN_RELATIVE_LP_POSITION_VALUE. Note that
-                // this ensures that the ratio can never be zero.
-                if pool.liquidity_shares_manager.shares_of(&who).is_err() {
-                    ensure!(
-                        ratio >= MIN_RELATIVE_LP_POSITION_VALUE.saturated_into(),
-                        Error::<T>::MinRelativeLiquidityThresholdViolated,
-                    );
-                }
-                let mut amounts_in = vec![];
-                for (&asset, &max_amount_in) in pool.assets().iter().zip(max_amounts_in.iter()) {
-                    let balance_in_pool = pool.reserve_of(&asset)?;
-                    let amount_in = ratio.bmul_ceil(balance_in_pool)?;
-                    amounts_in.push(amount_in);
-                    ensure!(amount_in <= max_amount_in, Error::<T>::AmountInAboveMax);
-                    T::MultiCurrency::transfer(asset, &who, &pool.account_id, amount_in)?;
+            ensure!(max_amounts_in.len() == asset_count_usize, Error::<T>::IncorrectVecLen);
+            Self::do_join(
+                who,
+                market_id,
+                Some(parent_collection_id),
+                pool_shares_amount,
+                max_amounts_in,
+            )
+        }
+
+        /// Exit the conditional pool of the specified market whose positions are conditioned on
+        /// `parent_collection_id`.
+        ///
+        /// Behaves like `exit`. The `min_amounts_out` are ordered like the pool's assets.
+        ///
+        /// # Complexity
+        ///
+        /// `O(n + d)` where `n` is the number of assets in the pool and `d` is the depth of the
+        /// pool's liquidity tree.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::exit(min_amounts_out.len().saturated_into()))]
+        #[transactional]
+        pub fn exit_conditional(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            parent_collection_id: CombinatorialId,
+            #[pallet::compact] pool_shares_amount_out: BalanceOf<T>,
+            min_amounts_out: Vec<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let asset_count = T::MarketCommons::market(&market_id)?.outcomes();
+            let asset_count_u32: u32 = asset_count.into();
+            let min_amounts_out_len: u32 =
+                min_amounts_out.len().try_into().map_err(|_| Error::<T>::NarrowingConversion)?;
+            ensure!(min_amounts_out_len == asset_count_u32, Error::<T>::IncorrectVecLen);
+            Self::do_exit(
+                who,
+                market_id,
+                Some(parent_collection_id),
+                pool_shares_amount_out,
+                min_amounts_out,
+            )?;
+            Ok(Some(T::WeightInfo::exit(min_amounts_out_len)).into())
+        }
+
+        /// Withdraw swap fees from the conditional pool of the specified market whose positions
+        /// are conditioned on `parent_collection_id`.
+        ///
+        /// Behaves like `withdraw_fees`. The fees are paid in the position of
+        /// `parent_collection_id`.
+        ///
+        /// # Complexity
+        ///
+        /// `O(1)`.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::withdraw_fees())]
+        #[transactional]
+        pub fn withdraw_conditional_fees(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            parent_collection_id: CombinatorialId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_withdraw_fees(who, market_id, Some(parent_collection_id))?;
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        #[require_transactional]
+        fn do_buy(
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
+            asset_out: AssetOf<T>,
+            amount_in: BalanceOf<T>,
+            min_amount_out: BalanceOf<T>,
+        ) -> Result<AmmTradeOf<T>, DispatchError> {
+            ensure!(amount_in != Zero::zero(), Error::<T>::ZeroAmount);
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(market.status == MarketStatus::Active, Error::<T>::MarketNotActive);
+            Self::try_mutate_pool_of(&market_id, parent_collection_id, |pool| {
+                ensure!(pool.contains(&asset_out), Error::<T>::AssetNotFound);
+                let price_history = if parent_collection_id.is_none() {
+                    Self::apply_dynamic_swap_fee(market_id, pool)?
+                } else {
+                    None
+                };
+                T::MultiCurrency::transfer(pool.collateral, &who, &pool.account_id, amount_in)?;
+                let FeeDistribution {
+                    remaining: amount_in_minus_fees,
+                    swap_fees: swap_fee_amount,
+                    external_fees: external_fee_amount,
+                } = Self::distribute_fees(market_id, pool, &who, amount_in)?;
+                let amount_out =
+                    Self::swap_reserves_for_buy(pool, asset_out, amount_in_minus_fees)?;
+                ensure!(amount_out >= min_amount_out, Error::<T>::AmountOutBelowMin);
+                // Instead of letting `who` buy the complete sets and then transfer almost all of
+                // the outcomes to the pool account, we prevent `(n-1)` storage reads by using the
+                // pool account to buy. Note that the fees are already in the pool at this point.
+                Self::buy_complete_set(
+                    pool,
+                    parent_collection_id,
+                    market_id,
+                    amount_in_minus_fees,
+                )?;
+                T::MultiCurrency::transfer(asset_out, &pool.account_id, &who, amount_out)?;
+                if parent_collection_id.is_none() {
+                    Self::after_regular_pool_trade(market_id, pool, price_history)?;
                 }
-                for ((_, balance), amount_in) in pool.reserves.iter_mut().zip(amounts_in.iter()) {
-                    *balance = balance.checked_add_res(amount_in)?;
+                Self::deposit_event(Event::<T>::BuyExecuted {
+                    who: who.clone(),
+                    market_id,
+                    parent_collection_id,
+                    asset_out,
+                    amount_in,
+                    amount_out,
+                    swap_fee: pool.swap_fee,
+                    swap_fee_amount,
+                    external_fee_amount,
+                });
+                Ok(AmmTrade { amount_in, amount_out, swap_fee_amount, external_fee_amount })
+            })
+        }
+
+        #[require_transactional]
+        fn do_sell(
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
+            asset_in: AssetOf<T>,
+            amount_in: BalanceOf<T>,
+            min_amount_out: BalanceOf<T>,
+        ) -> Result<AmmTradeOf<T>, DispatchError> {
+            ensure!(amount_in != Zero::zero(), Error::<T>::ZeroAmount);
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(market.status == MarketStatus::Active, Error::<T>::MarketNotActive);
+            Self::try_mutate_pool_of(&market_id, parent_collection_id, |pool| {
+                ensure!(pool.contains(&asset_in), Error::<T>::AssetNotFound);
+                let price_history = if parent_collection_id.is_none() {
+                    Self::apply_dynamic_swap_fee(market_id, pool)?
+                } else {
+                    None
+                };
+                // Instead of first executing a swap with `(n-1)` transfers from the pool account to
+                // `who` and then selling complete sets, we prevent `(n-1)` storage reads: 1)
+                // Transfer `amount_in` units of `asset_in` to the pool account, 2) sell
+                // `amount_out` complete sets using the pool account, 3) transfer
+                // `amount_out_minus_fees` units of collateral to `who`. The fees automatically end
+                // up in the pool.
+                let amount_out = Self::swap_reserves_for_sell(pool, asset_in, amount_in)?;
+                // Beware! This transfer **must** happen _after_ calculating `amount_out`:
+                T::MultiCurrency::transfer(asset_in, &who, &pool.account_id, amount_in)?;
+                Self::sell_complete_set(pool, parent_collection_id, market_id, amount_out)?;
+                let FeeDistribution {
+                    remaining: amount_out_minus_fees,
+                    swap_fees: swap_fee_amount,
+                    external_fees: external_fee_amount,
+                } = Self::distribute_fees(market_id, pool, &who, amount_out)?;
+                ensure!(amount_out_minus_fees >= min_amount_out, Error::<T>::AmountOutBelowMin);
+                T::MultiCurrency::transfer(
+                    pool.collateral,
+                    &pool.account_id,
+                    &who,
+                    amount_out_minus_fees,
+                )?;
+                if parent_collection_id.is_none() {
+                    Self::after_regular_pool_trade(market_id, pool, price_history)?;
                 }
-                let benchmark_info =
-                    pool.liquidity_shares_manager.join(&who, pool_shares_amount)?;
-                let new_liquidity_parameter = pool
-                    .liquidity_parameter
-                    .checked_add_res(&ratio.bmul(pool.liquidity_parameter)?)?;
-                pool.liquidity_parameter = new_liquidity_parameter;
-                Self::deposit_event(Event::<T>::JoinExecuted {
+                Self::deposit_event(Event::<T>::SellExecuted {
                     who: who.clone(),
                     market_id,
-                    pool_shares_amount,
-                    amounts_in,
-                    new_liquidity_parameter,
+                    parent_collection_id,
+                    asset_in,
+                    amount_in,
+                    amount_out: amount_out_minus_fees,
+                    swap_fee: pool.swap_fee,
+                    swap_fee_amount,
+                    external_fee_amount,
                 });
+                Ok(AmmTrade {
+                    amount_in,
+                    amount_out: amount_out_minus_fees,
+                    swap_fee_amount,
+                    external_fee_amount,
+                })
+            })
+        }
+
+        #[require_transactional]
+        fn do_join(
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
+            pool_shares_amount: BalanceOf<T>,
+            max_amounts_in: Vec<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            ensure!(pool_shares_amount != Zero::zero(), Error::<T>::ZeroAmount);
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(market.status == MarketStatus::Active, Error::<T>::MarketNotActive);
+            let asset_count_u16: u16 =
+                max_amounts_in.len().try_into().map_err(|_| Error::<T>::NarrowingConversion)?;
+            let asset_count_u32: u32 = asset_count_u16.into();
+            ensure!(asset_count_u16 == market.outcomes(), Error::<T>::IncorrectAssetCount);
+            // Range positions and incentive programs only exist for regular pools.
+            let is_regular = parent_collection_id.is_none();
+            ensure!(
+                !is_regular || !RangePositions::<T>::contains_key(market_id, &who),
+                Error::<T>::RangePositionExists
+            );
+            let benchmark_info =
+                Self::try_mutate_pool_of(&market_id, parent_collection_id, |pool| {
+                    let ratio = pool_shares_amount
+                        .bdiv_ceil(pool.liquidity_shares_manager.total_shares()?)?;
+                    // Ensure that new LPs contribute at least MIN_RELATIVE_LP_POSITION_VALUE. Note
+                    // that this ensures that the ratio can never be zero.
+                    if pool.liquidity_shares_manager.shares_of(&who).is_err() {
+                        ensure!(
+                            ratio >= MIN_RELATIVE_LP_POSITION_VALUE.saturated_into(),
+                            Error::<T>::MinRelativeLiquidityThresholdViolated,
+                        );
+                    }
+                    let mut amounts_in = vec![];
+                    for (&asset, &max_amount_in) in pool.assets().iter().zip(max_amounts_in.iter())
+                    {
+                        let balance_in_pool = pool.reserve_of(&asset)?;
+                        let amount_in = ratio.bmul_ceil(balance_in_pool)?;
+                        amounts_in.push(amount_in);
+                        ensure!(amount_in <= max_amount_in, Error::<T>::AmountInAboveMax);
+                        T::MultiCurrency::transfer(asset, &who, &pool.account_id, amount_in)?;
+                    }
+                    for ((_, balance), amount_in) in pool.reserves.iter_mut().zip(amounts_in.iter())
+                    {
+                        *balance = balance.checked_add_res(amount_in)?;
+                    }
+                    if is_regular {
+                        Self::settle_incentives(market_id, pool, &who)?;
+                    }
+                    let benchmark_info =
+                        pool.liquidity_shares_manager.join(&who, pool_shares_amount)?;
+                    let new_liquidity_parameter = pool
+                        .liquidity_parameter
+                        .checked_add_res(&ratio.bmul(pool.liquidity_parameter)?)?;
+                    pool.liquidity_parameter = new_liquidity_parameter;
+                    if is_regular {
+                        Self::checkpoint_incentives(market_id, pool, &who)?;
+                        Self::record_observation(market_id, pool)?;
+                    }
+                    Self::deposit_event(Event::<T>::JoinExecuted {
+                        who: who.clone(),
+                        market_id,
+                        parent_collection_id,
+                        pool_shares_amount,
+                        amounts_in,
+                        new_liquidity_parameter,
+                    });
 
-                // Notify other pallets that liquidity has been provided.
-                T::OnLiquidityProvided::on_liquidity_provided(&market_id, &who);
+                    // Notify other pallets that liquidity has been provided.
+                    T::OnLiquidityProvided::on_liquidity_provided(&market_id, &who);
 
-                Ok(benchmark_info)
-            })?;
+                    Ok(benchmark_info)
+                })?;
             let weight = match benchmark_info {
                 BenchmarkInfo::InPlace => T::WeightInfo::join_in_place(asset_count_u32),
                 BenchmarkInfo::Reassigned => T::WeightInfo::join_reassigned(asset_count_u32),
                 BenchmarkInfo::Leaf => T::WeightInfo::join_leaf(asset_count_u32),
             };
+            let weight = if is_regular {
+                weight.saturating_add(T::WeightInfo::settle_incentives())
+            } else {
+                weight
+            };
             Ok((Some(weight)).into())
         }
 
@@ -988,12 +2005,19 @@ mod pallet {
         fn do_exit(
             who: T::AccountId,
             market_id: MarketIdOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
             pool_shares_amount: BalanceOf<T>,
             min_amounts_out: Vec<BalanceOf<T>>,
         ) -> DispatchResult {
             ensure!(pool_shares_amount != Zero::zero(), Error::<T>::ZeroAmount);
+            // Range positions and incentive programs only exist for regular pools.
+            let is_regular = parent_collection_id.is_none();
+            ensure!(
+                !is_regular || !RangePositions::<T>::contains_key(market_id, &who),
+                Error::<T>::RangePositionExists
+            );
             let market = T::MarketCommons::market(&market_id)?;
-            Pools::<T>::try_mutate_exists(market_id, |maybe_pool| {
+            let mutate_pool = |maybe_pool: &mut Option<PoolOf<T>>| -> DispatchResult {
                 let pool =
                     maybe_pool.as_mut().ok_or::<DispatchError>(Error::<T>::PoolNotFound.into())?;
                 let ratio = {
@@ -1017,32 +2041,20 @@ mod pallet {
                 for ((_, balance), amount_out) in pool.reserves.iter_mut().zip(amounts_out.iter()) {
                     *balance = balance.checked_sub_res(amount_out)?;
                 }
+                if is_regular {
+                    Self::settle_incentives(market_id, pool, &who)?;
+                }
                 pool.liquidity_shares_manager.exit(&who, pool_shares_amount)?;
+                if is_regular {
+                    Self::checkpoint_incentives(market_id, pool, &who)?;
+                }
                 if pool.liquidity_shares_manager.total_shares()? == Zero::zero() {
-                    let withdraw_remaining = |&asset| -> DispatchResult {
-                        let remaining = T::MultiCurrency::free_balance(asset, &pool.account_id);
-                        T::MultiCurrency::withdraw(asset, &pool.account_id, remaining)?;
-                        Ok(())
-                    };
-
-                    // Transfer any remaining base assets to the designated account.
-                    let remaining =
-                        T::MultiCurrency::free_balance(pool.collateral, &pool.account_id);
-                    T::MultiCurrency::transfer(
-                        pool.collateral,
-                        &pool.account_id,
-                        &Self::early_exit_account()?,
-                        remaining,
-                    )?;
-
-                    // Clear left-over tokens. These naturally occur in the form of exit fees.
-                    for asset in pool.assets().iter() {
-                        withdraw_remaining(asset)?;
-                    }
+                    Self::destroy_pool(market_id, parent_collection_id, pool)?;
                     *maybe_pool = None; // Delete the storage map entry.
                     Self::deposit_event(Event::<T>::PoolDestroyed {
                         who: who.clone(),
                         market_id,
+                        parent_collection_id,
                         amounts_out,
                     });
                 } else {
@@ -1067,41 +2079,731 @@ mod pallet {
                         );
                     }
                     pool.liquidity_parameter = new_liquidity_parameter;
+                    if is_regular {
+                        Self::record_observation(market_id, pool)?;
+                    }
                     Self::deposit_event(Event::<T>::ExitExecuted {
                         who: who.clone(),
                         market_id,
+                        parent_collection_id,
                         pool_shares_amount,
                         amounts_out,
                         new_liquidity_parameter,
                     });
                 }
                 Ok(())
+            };
+            match parent_collection_id {
+                Some(parent_collection_id) => ConditionalPools::<T>::try_mutate_exists(
+                    market_id,
+                    parent_collection_id,
+                    mutate_pool,
+                ),
+                None => Pools::<T>::try_mutate_exists(market_id, mutate_pool),
+            }
+        }
+
+        /// Clears the account of a pool after all of its pool shares were removed. Range positions
+        /// are paid out their parked funds and outstanding fees, any other collateral is sent to
+        /// the early exit fee account and all remaining outcome tokens are destroyed. The unspent
+        /// budgets of incentive programs are returned to their funders.
+        ///
+        /// The data which only exists for regular pools is left alone when destroying a conditional
+        /// pool of the market, specified by `parent_collection_id`.
+        fn destroy_pool(
+            market_id: MarketIdOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
+            pool: &mut PoolOf<T>,
+        ) -> DispatchResult {
+            if parent_collection_id.is_none() {
+                let range_positions: Vec<_> =
+                    RangePositions::<T>::drain_prefix(market_id).collect();
+                for (who, position) in range_positions {
+                    for (&asset, &amount) in pool.assets().iter().zip(position.parked.iter()) {
+                        T::MultiCurrency::transfer(asset, &pool.account_id, &who, amount)?;
+                    }
+                    let fees = pool.liquidity_shares_manager.withdraw_fees(&who)?;
+                    T::MultiCurrency::transfer(pool.collateral, &pool.account_id, &who, fees)?;
+                }
+            }
+
+            let withdraw_remaining = |&asset| -> DispatchResult {
+                let remaining = T::MultiCurrency::free_balance(asset, &pool.account_id);
+                T::MultiCurrency::withdraw(asset, &pool.account_id, remaining)?;
+                Ok(())
+            };
+
+            // Transfer any remaining base assets to the designated account.
+            let remaining = T::MultiCurrency::free_balance(pool.collateral, &pool.account_id);
+            T::MultiCurrency::transfer(
+                pool.collateral,
+                &pool.account_id,
+                &Self::early_exit_account()?,
+                remaining,
+            )?;
+
+            // Clear left-over tokens. These naturally occur in the form of exit fees.
+            for asset in pool.assets().iter() {
+                withdraw_remaining(asset)?;
+            }
+            if parent_collection_id.is_none() {
+                DynamicSwapFees::<T>::remove(market_id);
+                PriceHistories::<T>::remove(market_id);
+                let _ = Observations::<T>::clear_prefix(market_id, T::MaxObservations::get(), None);
+                LatestObservations::<T>::remove(market_id);
+                Self::close_incentive_programs(market_id)?;
+            }
+            Ok(())
+        }
+
+        #[require_transactional]
+        fn do_join_range(
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            pool_shares_amount: BalanceOf<T>,
+            max_amounts_in: Vec<BalanceOf<T>>,
+            asset: AssetOf<T>,
+            lower: BalanceOf<T>,
+            upper: BalanceOf<T>,
+        ) -> DispatchResult {
+            ensure!(lower < upper, Error::<T>::InvalidPriceRange);
+            ensure!(
+                !RangePositions::<T>::contains_key(market_id, &who),
+                Error::<T>::RangePositionExists
+            );
+            let range_position_count: u32 =
+                RangePositions::<T>::iter_prefix(market_id).count().saturated_into();
+            ensure!(
+                range_position_count < T::MaxRangePositions::get(),
+                Error::<T>::TooManyRangePositions
+            );
+            let pool = Pools::<T>::get(market_id).ok_or(Error::<T>::PoolNotFound)?;
+            ensure!(
+                pool.liquidity_shares_manager.shares_of(&who).is_err(),
+                Error::<T>::RangePositionExists
+            );
+            ensure!(pool.contains(&asset), Error::<T>::AssetNotFound);
+            // Keep dust positions from taking up the limited slots of the pool.
+            let liquidity = pool_shares_amount
+                .bdiv_ceil(pool.liquidity_shares_manager.total_shares()?)?
+                .bmul_ceil(pool.liquidity_parameter)?;
+            ensure!(liquidity >= MIN_LIQUIDITY.saturated_into(), Error::<T>::RangePositionTooSmall);
+            let parked = vec![Zero::zero(); pool.assets().len()]
+                .try_into()
+                .map_err(|_| Error::<T>::Unexpected)?;
+            let position =
+                RangePosition { asset, lower, upper, shares: pool_shares_amount, parked };
+            ensure!(
+                position.contains(pool.calculate_spot_price(asset)?),
+                Error::<T>::SpotPriceOutOfRange
+            );
+            let _ =
+                Self::do_join(who.clone(), market_id, None, pool_shares_amount, max_amounts_in)?;
+            RangePositions::<T>::insert(market_id, &who, position);
+            Self::deposit_event(Event::<T>::RangePositionOpened {
+                who,
+                market_id,
+                asset,
+                lower,
+                upper,
+                pool_shares_amount,
+            });
+            Ok(())
+        }
+
+        #[require_transactional]
+        fn do_exit_range(
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            min_amounts_out: Vec<BalanceOf<T>>,
+        ) -> DispatchResult {
+            let position = RangePositions::<T>::take(market_id, &who)
+                .ok_or(Error::<T>::RangePositionNotFound)?;
+            let market = T::MarketCommons::market(&market_id)?;
+            Pools::<T>::try_mutate_exists(market_id, |maybe_pool| {
+                let pool =
+                    maybe_pool.as_mut().ok_or::<DispatchError>(Error::<T>::PoolNotFound.into())?;
+                // Parked funds are not part of the pool and are returned in full.
+                let ratio = if position.is_active() {
+                    let mut ratio = position
+                        .shares
+                        .bdiv_floor(pool.liquidity_shares_manager.total_shares()?)?;
+                    if market.status == MarketStatus::Active {
+                        let multiplier = PredictionMarketBase::<BalanceOf<T>>::get()?
+                            .checked_sub_res(&EXIT_FEE.saturated_into())?;
+                        ratio = ratio.bmul_floor(multiplier)?;
+                    }
+                    ratio
+                } else {
+                    Zero::zero()
+                };
+                let mut amounts_out = vec![];
+                for ((&asset, &min_amount_out), &parked) in
+                    pool.assets().iter().zip(min_amounts_out.iter()).zip(position.parked.iter())
+                {
+                    let amount_from_pool = ratio.bmul_floor(pool.reserve_of(&asset)?)?;
+                    pool.decrease_reserve(&asset, &amount_from_pool)?;
+                    let amount_out = amount_from_pool.checked_add_res(&parked)?;
+                    amounts_out.push(amount_out);
+                    ensure!(amount_out >= min_amount_out, Error::<T>::AmountOutBelowMin);
+                    T::MultiCurrency::transfer(asset, &pool.account_id, &who, amount_out)?;
+                }
+                // Inactive positions hold no stake, but still occupy a node of the liquidity tree.
+                Self::settle_incentives(market_id, pool, &who)?;
+                pool.liquidity_shares_manager.exit(&who, position.shares)?;
+                Self::checkpoint_incentives(market_id, pool, &who)?;
+                if pool.liquidity_shares_manager.total_shares()? == Zero::zero() {
+                    Self::destroy_pool(market_id, None, pool)?;
+                    *maybe_pool = None; // Delete the storage map entry.
+                    Self::deposit_event(Event::<T>::PoolDestroyed {
+                        who: who.clone(),
+                        market_id,
+                        parent_collection_id: None,
+                        amounts_out: amounts_out.clone(),
+                    });
+                } else {
+                    pool.liquidity_parameter = pool
+                        .liquidity_parameter
+                        .checked_sub_res(&ratio.bmul(pool.liquidity_parameter)?)?;
+                }
+                Self::deposit_event(Event::<T>::RangePositionClosed {
+                    who: who.clone(),
+                    market_id,
+                    pool_shares_amount: position.shares,
+                    amounts_out,
+                });
+                Ok(())
             })
         }
 
+        /// Deactivates each range position of `pool` whose range no longer contains the spot price
+        /// and reactivates each inactive range position whose range contains it again. Spot prices
+        /// are not affected.
+        pub(crate) fn update_range_positions(
+            market_id: MarketIdOf<T>,
+            pool: &mut PoolOf<T>,
+        ) -> DispatchResult {
+            let range_positions: Vec<_> = RangePositions::<T>::iter_prefix(market_id).collect();
+            for (who, mut position) in range_positions {
+                let in_range = position.contains(pool.calculate_spot_price(position.asset)?);
+                let updated = if position.is_active() && !in_range {
+                    Self::deactivate_range_position(market_id, pool, &who, &mut position)?
+                } else if !position.is_active() && in_range {
+                    Self::reactivate_range_position(market_id, pool, &who, &mut position)?
+                } else {
+                    false
+                };
+                if updated {
+                    RangePositions::<T>::insert(market_id, &who, position);
+                }
+            }
+            Ok(())
+        }
+
+        /// Moves the share of the reserves which belongs to `position` out of the pool. Returns
+        /// `false` if the position can't be deactivated without draining the pool.
+        fn deactivate_range_position(
+            market_id: MarketIdOf<T>,
+            pool: &mut PoolOf<T>,
+            who: &T::AccountId,
+            position: &mut RangePosition<T>,
+        ) -> Result<bool, DispatchError> {
+            let total_shares = pool.liquidity_shares_manager.total_shares()?;
+            if position.shares >= total_shares {
+                return Ok(false);
+            }
+            let ratio = position.shares.bdiv_floor(total_shares)?;
+            let new_liquidity_parameter = pool
+                .liquidity_parameter
+                .checked_sub_res(&ratio.bmul(pool.liquidity_parameter)?)?;
+            if new_liquidity_parameter < MIN_LIQUIDITY.saturated_into() {
+                return Ok(false);
+            }
+            let mut amounts_parked = vec![];
+            for (asset, parked) in pool.assets().iter().zip(position.parked.iter_mut()) {
+                let amount = ratio.bmul_floor(pool.reserve_of(asset)?)?;
+                pool.decrease_reserve(asset, &amount)?;
+                *parked = parked.checked_add_res(&amount)?;
+                amounts_parked.push(amount);
+            }
+            Self::settle_incentives(market_id, pool, who)?;
+            pool.liquidity_shares_manager.deactivate(who, position.shares)?;
+            Self::checkpoint_incentives(market_id, pool, who)?;
+            pool.liquidity_parameter = new_liquidity_parameter;
+            Self::deposit_event(Event::<T>::RangePositionDeactivated {
+                who: who.clone(),
+                market_id,
+                pool_shares_amount: position.shares,
+                amounts_parked,
+                new_liquidity_parameter,
+            });
+            position.shares = Zero::zero();
+            Ok(true)
+        }
+
+        /// Moves as much of the funds parked by `position` back into the pool as the current
+        /// reserves permit. Returns `false` if the parked funds are too small to buy any shares.
+        fn reactivate_range_position(
+            market_id: MarketIdOf<T>,
+            pool: &mut PoolOf<T>,
+            who: &T::AccountId,
+            position: &mut RangePosition<T>,
+        ) -> Result<bool, DispatchError> {
+            let mut ratio: Option<BalanceOf<T>> = None;
+            for (asset, parked) in pool.assets().iter().zip(position.parked.iter()) {
+                let asset_ratio = parked.bdiv_floor(pool.reserve_of(asset)?)?;
+                ratio = Some(ratio.map_or(asset_ratio, |r| r.min(asset_ratio)));
+            }
+            let ratio = ratio.unwrap_or_else(Zero::zero);
+            let pool_shares_amount =
+                ratio.bmul_floor(pool.liquidity_shares_manager.total_shares()?)?;
+            if pool_shares_amount == Zero::zero() {
+                return Ok(false);
+            }
+            let mut amounts_in = vec![];
+            for (asset, parked) in pool.assets().iter().zip(position.parked.iter_mut()) {
+                let amount = ratio.bmul_floor(pool.reserve_of(asset)?)?;
+                *parked = parked.checked_sub_res(&amount)?;
+                pool.increase_reserve(asset, &amount)?;
+                amounts_in.push(amount);
+            }
+            Self::settle_incentives(market_id, pool, who)?;
+            let _ = pool.liquidity_shares_manager.join(who, pool_shares_amount)?;
+            Self::checkpoint_incentives(market_id, pool, who)?;
+            let new_liquidity_parameter = pool
+                .liquidity_parameter
+                .checked_add_res(&ratio.bmul(pool.liquidity_parameter)?)?;
+            pool.liquidity_parameter = new_liquidity_parameter;
+            Self::deposit_event(Event::<T>::RangePositionReactivated {
+                who: who.clone(),
+                market_id,
+                pool_shares_amount,
+                amounts_in,
+                new_liquidity_parameter,
+            });
+            position.shares = pool_shares_amount;
+            Ok(true)
+        }
+
+        #[require_transactional]
+        fn do_join_with_collateral(
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            asset_count: AssetIndexType,
+            amount_in: BalanceOf<T>,
+            min_pool_shares_amount: BalanceOf<T>,
+            min_leftover_amounts_out: Vec<BalanceOf<T>>,
+        ) -> DispatchResult {
+            ensure!(amount_in != Zero::zero(), Error::<T>::ZeroAmount);
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(market.status == MarketStatus::Active, Error::<T>::MarketNotActive);
+            ensure!(asset_count == market.outcomes(), Error::<T>::IncorrectAssetCount);
+            ensure!(
+                min_leftover_amounts_out.len() == asset_count as usize,
+                Error::<T>::IncorrectVecLen
+            );
+            let pool = Pools::<T>::get(market_id).ok_or(Error::<T>::PoolNotFound)?;
+            // Joining requires the same ratio of each reserve, so the number of pool shares is
+            // limited by the largest reserve.
+            let max_reserve =
+                pool.reserves.values().copied().max().ok_or(Error::<T>::Unexpected)?;
+            let pool_shares_amount = amount_in
+                .bdiv_floor(max_reserve)?
+                .bmul_floor(pool.liquidity_shares_manager.total_shares()?)?;
+            ensure!(pool_shares_amount >= min_pool_shares_amount, Error::<T>::AmountOutBelowMin);
+            let assets = pool.assets();
+            T::CompleteSetOperations::buy_complete_set(who.clone(), market_id, amount_in)?;
+            let balances_before = Self::balances_of(&who, &assets);
+            let _ = Self::do_join(
+                who.clone(),
+                market_id,
+                None,
+                pool_shares_amount,
+                vec![amount_in; assets.len()],
+            )?;
+            let mut amount_out: BalanceOf<T> = Zero::zero();
+            for ((&asset, balance_before), min_leftover_amount_out) in
+                assets.iter().zip(balances_before).zip(min_leftover_amounts_out)
+            {
+                let balance_after = T::MultiCurrency::free_balance(asset, &who);
+                let amount_joined = balance_before.checked_sub_res(&balance_after)?;
+                let leftover = amount_in.checked_sub_res(&amount_joined)?;
+                let amount_sold =
+                    Self::sell_leftover(&who, market_id, asset, leftover, min_leftover_amount_out)?;
+                amount_out = amount_out.checked_add_res(&amount_sold)?;
+            }
+            Self::deposit_event(Event::<T>::JoinWithCollateralExecuted {
+                who,
+                market_id,
+                amount_in,
+                pool_shares_amount,
+                amount_out,
+            });
+            Ok(())
+        }
+
+        #[require_transactional]
+        fn do_exit_to_collateral(
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            asset_count: AssetIndexType,
+            pool_shares_amount: BalanceOf<T>,
+            min_amount_out: BalanceOf<T>,
+            min_leftover_amounts_out: Vec<BalanceOf<T>>,
+        ) -> DispatchResult {
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(market.status == MarketStatus::Active, Error::<T>::MarketNotActive);
+            ensure!(asset_count == market.outcomes(), Error::<T>::IncorrectAssetCount);
+            ensure!(
+                min_leftover_amounts_out.len() == asset_count as usize,
+                Error::<T>::IncorrectVecLen
+            );
+            let pool = Pools::<T>::get(market_id).ok_or(Error::<T>::PoolNotFound)?;
+            let assets = pool.assets();
+            let balances_before = Self::balances_of(&who, &assets);
+            Self::do_exit(
+                who.clone(),
+                market_id,
+                None,
+                pool_shares_amount,
+                vec![Zero::zero(); assets.len()],
+            )?;
+            let amounts_exited = assets
+                .iter()
+                .zip(balances_before)
+                .map(|(&asset, balance_before)| {
+                    T::MultiCurrency::free_balance(asset, &who).checked_sub_res(&balance_before)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let complete_set_amount =
+                amounts_exited.iter().copied().min().unwrap_or_else(Zero::zero);
+            if complete_set_amount != Zero::zero() {
+                T::CompleteSetOperations::sell_complete_set(
+                    who.clone(),
+                    market_id,
+                    complete_set_amount,
+                )?;
+            }
+            let mut amount_out = complete_set_amount;
+            // Leftovers can't be routed through the pool if the exit destroyed it and are kept by
+            // the LP.
+            if Pools::<T>::contains_key(market_id) {
+                for ((&asset, amount_exited), min_leftover_amount_out) in
+                    assets.iter().zip(amounts_exited).zip(min_leftover_amounts_out)
+                {
+                    let leftover = amount_exited.checked_sub_res(&complete_set_amount)?;
+                    let amount_sold = Self::sell_leftover(
+                        &who,
+                        market_id,
+                        asset,
+                        leftover,
+                        min_leftover_amount_out,
+                    )?;
+                    amount_out = amount_out.checked_add_res(&amount_sold)?;
+                }
+            } else {
+                ensure!(
+                    min_leftover_amounts_out.iter().all(|amount| amount.is_zero()),
+                    Error::<T>::AmountOutBelowMin
+                );
+            }
+            ensure!(amount_out >= min_amount_out, Error::<T>::AmountOutBelowMin);
+            Self::deposit_event(Event::<T>::ExitToCollateralExecuted {
+                who,
+                market_id,
+                pool_shares_amount,
+                amount_out,
+            });
+            Ok(())
+        }
+
+        /// Sells `amount` units of `asset` to the pool for at least `min_amount_out` and returns
+        /// the amount of collateral received. Amounts too small to be sold for any collateral are
+        /// kept by `who`.
+        fn sell_leftover(
+            who: &T::AccountId,
+            market_id: MarketIdOf<T>,
+            asset: AssetOf<T>,
+            amount: BalanceOf<T>,
+            min_amount_out: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let pool = Pools::<T>::get(market_id).ok_or(Error::<T>::PoolNotFound)?;
+            if amount == Zero::zero() ||
+                pool.calculate_swap_amount_out_for_sell(asset, amount)? == Zero::zero()
+            {
+                ensure!(min_amount_out == Zero::zero(), Error::<T>::AmountOutBelowMin);
+                return Ok(Zero::zero());
+            }
+            let trade = Self::do_sell(who.clone(), market_id, None, asset, amount, min_amount_out)?;
+            Ok(trade.amount_out)
+        }
+
+        fn balances_of(who: &T::AccountId, assets: &[AssetOf<T>]) -> Vec<BalanceOf<T>> {
+            assets.iter().map(|&asset| T::MultiCurrency::free_balance(asset, who)).collect()
+        }
+
         #[require_transactional]
-        fn do_withdraw_fees(who: T::AccountId, market_id: MarketIdOf<T>) -> DispatchResult {
-            Self::try_mutate_pool(&market_id, |pool| {
+        fn do_withdraw_fees(
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
+        ) -> DispatchResult {
+            Self::try_mutate_pool_of(&market_id, parent_collection_id, |pool| {
                 let amount = pool.liquidity_shares_manager.withdraw_fees(&who)?;
                 T::MultiCurrency::transfer(pool.collateral, &pool.account_id, &who, amount)?; // Should never fail.
                 Self::deposit_event(Event::<T>::FeesWithdrawn {
                     who: who.clone(),
                     market_id,
-                    amount,
+                    parent_collection_id,
+                    amount,
+                });
+                Ok(())
+            })
+        }
+
+        #[require_transactional]
+        fn do_create_incentive_program(
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            asset: AssetOf<T>,
+            amount: BalanceOf<T>,
+            start: BlockNumberFor<T>,
+            end: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            ensure!(amount != Zero::zero(), Error::<T>::ZeroAmount);
+            let market = T::MarketCommons::market(&market_id)?;
+            let is_admin = T::PalletAdminGetter::get_admin().ok().as_ref() == Some(&who);
+            ensure!(who == market.creator || is_admin, Error::<T>::NotAllowed);
+            ensure!(market.status == MarketStatus::Active, Error::<T>::MarketNotActive);
+            let pool = Pools::<T>::get(market_id).ok_or(Error::<T>::PoolNotFound)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now <= start && start < end, Error::<T>::InvalidIncentivePeriod);
+            let mut program_count: u32 =
+                IncentivePrograms::<T>::iter_prefix(market_id).count().saturated_into();
+            if program_count >= T::MaxIncentivePrograms::get() &&
+                Self::remove_finished_incentive_program(market_id, &pool)?
+            {
+                program_count = program_count.saturating_sub(1);
+            }
+            ensure!(
+                program_count < T::MaxIncentivePrograms::get(),
+                Error::<T>::TooManyIncentivePrograms
+            );
+            let program_id = NextIncentiveProgramId::<T>::get(market_id);
+            let incentive_account_id = Self::incentive_account_id(&market_id);
+            T::MultiCurrency::transfer(asset, &who, &incentive_account_id, amount)?;
+            // Ensure that the existential deposit doesn't kill the last rewards which are claimed.
+            T::MultiCurrency::transfer(
+                asset,
+                &who,
+                &incentive_account_id,
+                T::MultiCurrency::minimum_balance(asset),
+            )?;
+            let program = IncentiveProgram::new(who.clone(), asset, amount, start, end);
+            IncentivePrograms::<T>::insert(market_id, program_id, program);
+            NextIncentiveProgramId::<T>::insert(market_id, program_id.saturating_add(1));
+            Self::deposit_event(Event::<T>::IncentiveProgramCreated {
+                funder: who,
+                market_id,
+                program_id,
+                asset,
+                amount,
+                start,
+                end,
+            });
+            Ok(())
+        }
+
+        #[require_transactional]
+        fn do_claim_incentives(who: T::AccountId, market_id: MarketIdOf<T>) -> DispatchResult {
+            ensure!(
+                NextIncentiveProgramId::<T>::get(market_id) != 0,
+                Error::<T>::IncentiveProgramNotFound
+            );
+            if let Some(pool) = Pools::<T>::get(market_id) {
+                Self::settle_incentives(market_id, &pool, &who)?;
+            }
+            let incentive_account_id = Self::incentive_account_id(&market_id);
+            for (program_id, mut program) in IncentivePrograms::<T>::iter_prefix(market_id) {
+                let mut amount: BalanceOf<T> = Zero::zero();
+                Self::mutate_incentive_reward(market_id, program_id, &who, |reward| {
+                    amount = reward.pending;
+                    reward.pending = Zero::zero();
+                    Ok(())
+                })?;
+                if amount.is_zero() {
+                    continue;
+                }
+                T::MultiCurrency::transfer(program.asset, &incentive_account_id, &who, amount)?;
+                program.unclaimed = program.unclaimed.saturating_sub(amount);
+                IncentivePrograms::<T>::insert(market_id, program_id, program.clone());
+                Self::deposit_event(Event::<T>::IncentivesClaimed {
+                    who: who.clone(),
+                    market_id,
+                    program_id,
+                    asset: program.asset,
+                    amount,
+                });
+            }
+            Ok(())
+        }
+
+        /// Streams the rewards of all incentive programs of `pool` up to the current block and
+        /// credits `who` with their share. Must be called before the pool shares of `who` change.
+        pub(crate) fn settle_incentives(
+            market_id: MarketIdOf<T>,
+            pool: &PoolOf<T>,
+            who: &T::AccountId,
+        ) -> DispatchResult {
+            let now = frame_system::Pallet::<T>::block_number();
+            let total_shares = pool.liquidity_shares_manager.total_shares()?;
+            let shares =
+                pool.liquidity_shares_manager.shares_of(who).unwrap_or_else(|_| Zero::zero());
+            for (program_id, mut program) in IncentivePrograms::<T>::iter_prefix(market_id) {
+                program.accrue(now, total_shares)?;
+                let debt = program.reward_debt(shares)?;
+                Self::mutate_incentive_reward(market_id, program_id, who, |reward| {
+                    let accrued = debt.saturating_sub(reward.debt);
+                    reward.pending = reward.pending.checked_add_res(&accrued)?;
+                    reward.debt = debt;
+                    Ok(())
+                })?;
+                IncentivePrograms::<T>::insert(market_id, program_id, program);
+            }
+            Ok(())
+        }
+
+        /// Marks the rewards of all incentive programs of `pool` as paid out for the current pool
+        /// shares of `who`. Must be called after the pool shares of `who` change.
+        pub(crate) fn checkpoint_incentives(
+            market_id: MarketIdOf<T>,
+            pool: &PoolOf<T>,
+            who: &T::AccountId,
+        ) -> DispatchResult {
+            let shares =
+                pool.liquidity_shares_manager.shares_of(who).unwrap_or_else(|_| Zero::zero());
+            for (program_id, program) in IncentivePrograms::<T>::iter_prefix(market_id) {
+                let debt = program.reward_debt(shares)?;
+                Self::mutate_incentive_reward(market_id, program_id, who, |reward| {
+                    reward.debt = debt;
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        }
+
+        /// Mutates the rewards of `who` in the specified program, removing the entry if it's
+        /// empty.
+        fn mutate_incentive_reward<F>(
+            market_id: MarketIdOf<T>,
+            program_id: IncentiveProgramId,
+            who: &T::AccountId,
+            mutator: F,
+        ) -> DispatchResult
+        where
+            F: FnOnce(&mut IncentiveRewardOf<T>) -> DispatchResult,
+        {
+            IncentiveRewards::<T>::try_mutate_exists((market_id, program_id), who, |maybe_reward| {
+                let mut reward = maybe_reward.unwrap_or_default();
+                mutator(&mut reward)?;
+                *maybe_reward = (reward != Default::default()).then_some(reward);
+                Ok(())
+            })
+        }
+
+        /// Removes the incentive program of `market_id` with the smallest ID which has ended and
+        /// whose rewards were claimed, except for dust below the existential deposit, and returns
+        /// what's left of its funds to the funder. Returns `false` if there is no such program.
+        ///
+        /// Programs are only removed to make room for new programs, since rewards which were not
+        /// claimed by the time the program is removed are lost.
+        fn remove_finished_incentive_program(
+            market_id: MarketIdOf<T>,
+            pool: &PoolOf<T>,
+        ) -> Result<bool, DispatchError> {
+            let now = frame_system::Pallet::<T>::block_number();
+            let total_shares = pool.liquidity_shares_manager.total_shares()?;
+            let mut finished = None;
+            for (program_id, mut program) in IncentivePrograms::<T>::iter_prefix(market_id) {
+                // Stream the rewards of the last blocks so that they're counted as unclaimed.
+                program.accrue(now, total_shares)?;
+                let dust = T::MultiCurrency::minimum_balance(program.asset);
+                let is_smallest_id = finished.as_ref().map_or(true, |(id, _)| program_id < *id);
+                if program.is_finished(now, dust) && is_smallest_id {
+                    finished = Some((program_id, program));
+                }
+            }
+            let Some((program_id, program)) = finished else {
+                return Ok(false);
+            };
+            // The existential deposit which was paid on creation is returned as well.
+            let refunded = program
+                .remaining
+                .checked_add_res(&program.unclaimed)?
+                .checked_add_res(&T::MultiCurrency::minimum_balance(program.asset))?;
+            T::MultiCurrency::transfer(
+                program.asset,
+                &Self::incentive_account_id(&market_id),
+                &program.funder,
+                refunded,
+            )?;
+            IncentivePrograms::<T>::remove(market_id, program_id);
+            // Each LP of the pool has at most one rewards entry per program. The entries which are
+            // left over hold dust and are never read again since program IDs aren't reused.
+            let _ = IncentiveRewards::<T>::clear_prefix(
+                (market_id, program_id),
+                LiquidityTreeOf::<T>::max_node_count(),
+                None,
+            );
+            Self::deposit_event(Event::<T>::IncentiveProgramRemoved {
+                market_id,
+                program_id,
+                refunded,
+            });
+            Ok(true)
+        }
+
+        /// Returns the unspent budgets of the incentive programs of `market_id` to their funders.
+        /// The rewards which were already streamed remain claimable.
+        fn close_incentive_programs(market_id: MarketIdOf<T>) -> DispatchResult {
+            let incentive_account_id = Self::incentive_account_id(&market_id);
+            for (program_id, mut program) in IncentivePrograms::<T>::iter_prefix(market_id) {
+                let refunded = program.remaining;
+                if refunded.is_zero() {
+                    continue;
+                }
+                T::MultiCurrency::transfer(
+                    program.asset,
+                    &incentive_account_id,
+                    &program.funder,
+                    refunded,
+                )?;
+                program.remaining = Zero::zero();
+                IncentivePrograms::<T>::insert(market_id, program_id, program);
+                Self::deposit_event(Event::<T>::IncentiveProgramClosed {
+                    market_id,
+                    program_id,
+                    refunded,
                 });
-                Ok(())
-            })
+            }
+            Ok(())
         }
 
         #[require_transactional]
         fn do_deploy_pool(
             who: T::AccountId,
             market_id: MarketIdOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
             amount: BalanceOf<T>,
             spot_prices: Vec<BalanceOf<T>>,
             swap_fee: BalanceOf<T>,
         ) -> DispatchResult {
-            ensure!(!Pools::<T>::contains_key(market_id), Error::<T>::DuplicatePool);
+            let pool_exists = match parent_collection_id {
+                Some(parent_collection_id) =>
+                    ConditionalPools::<T>::contains_key(market_id, parent_collection_id),
+                None => Pools::<T>::contains_key(market_id),
+            };
+            ensure!(!pool_exists, Error::<T>::DuplicatePool);
             let market = T::MarketCommons::market(&market_id)?;
             ensure!(market.status == MarketStatus::Active, Error::<T>::MarketNotActive);
             ensure!(
@@ -1138,13 +2840,24 @@ mod pallet {
                 liquidity_parameter >= MIN_LIQUIDITY.saturated_into(),
                 Error::<T>::LiquidityTooLow
             );
-            let pool_account_id = Self::pool_account_id(&market_id);
+            let (outcome_assets, collateral, pool_account_id) = match parent_collection_id {
+                Some(parent_collection_id) => (
+                    Self::conditional_outcome_assets(
+                        parent_collection_id,
+                        market_id,
+                        asset_count_u16,
+                    )?,
+                    Asset::CombinatorialOutcome(parent_collection_id),
+                    Self::conditional_pool_account_id(&market_id, &parent_collection_id),
+                ),
+                None =>
+                    (market.outcome_assets(), market.base_asset, Self::pool_account_id(&market_id)),
+            };
             let mut reserves = BTreeMap::new();
-            for (&amount_in, &asset) in amounts_in.iter().zip(market.outcome_assets().iter()) {
+            for (&amount_in, &asset) in amounts_in.iter().zip(outcome_assets.iter()) {
                 T::MultiCurrency::transfer(asset, &who, &pool_account_id, amount_in)?;
                 let _ = reserves.insert(asset, amount_in);
             }
-            let collateral = market.base_asset;
             let pool = Pool {
                 account_id: pool_account_id.clone(),
                 reserves: reserves.clone().try_into().map_err(|_| Error::<T>::Unexpected)?,
@@ -1161,7 +2874,18 @@ mod pallet {
                 &pool.account_id,
                 T::MultiCurrency::minimum_balance(collateral),
             )?;
-            Pools::<T>::insert(market_id, pool);
+            match parent_collection_id {
+                Some(parent_collection_id) => {
+                    ConditionalPools::<T>::insert(market_id, parent_collection_id, pool);
+                },
+                None => {
+                    // The market may have incentive programs from a previous pool which must not
+                    // pay out rewards for the new shares.
+                    Self::checkpoint_incentives(market_id, &pool, &who)?;
+                    Self::record_observation(market_id, &pool)?;
+                    Pools::<T>::insert(market_id, pool);
+                },
+            }
 
             // Notify other pallets that liquidity has been provided.
             T::OnLiquidityProvided::on_liquidity_provided(&market_id, &who);
@@ -1169,6 +2893,7 @@ mod pallet {
             Self::deposit_event(Event::<T>::PoolDeployed {
                 who,
                 market_id,
+                parent_collection_id,
                 account_id: pool_account_id,
                 reserves,
                 collateral,
@@ -1179,25 +2904,408 @@ mod pallet {
             Ok(())
         }
 
+        #[require_transactional]
+        fn do_deploy_pool_with_dynamic_fee(
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            amount: BalanceOf<T>,
+            spot_prices: Vec<BalanceOf<T>>,
+            params: DynamicFeeParamsOf<T>,
+        ) -> DispatchResult {
+            ensure!(params.max_fee <= T::MaxSwapFee::get(), Error::<T>::SwapFeeAboveMax);
+            ensure!(params.min_fee <= params.max_fee, Error::<T>::InvalidDynamicFeeParameters);
+            ensure!(!params.window.is_zero(), Error::<T>::InvalidDynamicFeeParameters);
+            // The lower bound of `min_fee` is checked when deploying the pool.
+            Self::do_deploy_pool(who, market_id, None, amount, spot_prices, params.min_fee)?;
+            let pool = Pools::<T>::get(market_id).ok_or(Error::<T>::PoolNotFound)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            let price_history = PriceHistory::new(now, Self::pool_spot_prices(&pool)?);
+            DynamicSwapFees::<T>::insert(market_id, params);
+            PriceHistories::<T>::insert(market_id, price_history);
+            Ok(())
+        }
+
+        /// Returns the positions of `parent_collection_id` conditioned on each outcome of
+        /// `market_id`, ordered by outcome index.
+        fn conditional_outcome_assets(
+            parent_collection_id: CombinatorialId,
+            market_id: MarketIdOf<T>,
+            asset_count: u16,
+        ) -> Result<Vec<AssetOf<T>>, DispatchError> {
+            Self::singleton_partition(asset_count)?
+                .into_iter()
+                .map(|index_set| {
+                    T::CompleteSetOperations::position_asset(
+                        Some(parent_collection_id),
+                        market_id,
+                        index_set,
+                    )
+                })
+                .collect()
+        }
+
+        /// Returns the partition of the outcomes of a market with `asset_count` outcomes into
+        /// singletons.
+        fn singleton_partition(asset_count: u16) -> Result<Vec<IndexSet>, DispatchError> {
+            (0..asset_count)
+                .map(|index| {
+                    (1 as IndexSet).checked_shl(index.into()).ok_or(Error::<T>::Unexpected.into())
+                })
+                .collect()
+        }
+
+        /// Exchanges `amount` units of the pool's collateral for `amount` units of each of the
+        /// pool's assets using the pool account. Conditional pools split their parent position.
+        fn buy_complete_set(
+            pool: &PoolOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
+            market_id: MarketIdOf<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            Self::buy_complete_set_for(
+                pool.account_id.clone(),
+                parent_collection_id,
+                market_id,
+                pool.assets().len().saturated_into(),
+                amount,
+            )
+        }
+
+        /// The inverse of `buy_complete_set`.
+        fn sell_complete_set(
+            pool: &PoolOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
+            market_id: MarketIdOf<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            Self::sell_complete_set_for(
+                pool.account_id.clone(),
+                parent_collection_id,
+                market_id,
+                pool.assets().len().saturated_into(),
+                amount,
+            )
+        }
+
+        /// Exchanges `amount` units of collateral for `amount` units of each of the `asset_count`
+        /// outcomes of `market_id` using the account `who`. If `parent_collection_id` is
+        /// specified, the parent position is split instead.
+        fn buy_complete_set_for(
+            who: T::AccountId,
+            parent_collection_id: Option<CombinatorialId>,
+            market_id: MarketIdOf<T>,
+            asset_count: u16,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            match parent_collection_id {
+                Some(parent_collection_id) => T::CompleteSetOperations::split_position(
+                    who,
+                    Some(parent_collection_id),
+                    market_id,
+                    Self::singleton_partition(asset_count)?,
+                    amount,
+                ),
+                None => T::CompleteSetOperations::buy_complete_set(who, market_id, amount),
+            }
+        }
+
+        /// The inverse of `buy_complete_set_for`.
+        fn sell_complete_set_for(
+            who: T::AccountId,
+            parent_collection_id: Option<CombinatorialId>,
+            market_id: MarketIdOf<T>,
+            asset_count: u16,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            match parent_collection_id {
+                Some(parent_collection_id) => T::CompleteSetOperations::merge_position(
+                    who,
+                    Some(parent_collection_id),
+                    market_id,
+                    Self::singleton_partition(asset_count)?,
+                    amount,
+                ),
+                None => T::CompleteSetOperations::sell_complete_set(who, market_id, amount),
+            }
+        }
+
         #[inline]
         pub(crate) fn pool_account_id(market_id: &MarketIdOf<T>) -> T::AccountId {
             T::PalletId::get().into_sub_account_truncating((*market_id).saturated_into::<u128>())
         }
 
+        /// The account of the conditional pool of `market_id` whose positions are conditioned on
+        /// `parent_collection_id`. The pair is hashed since it doesn't fit into a sub account.
+        pub(crate) fn conditional_pool_account_id(
+            market_id: &MarketIdOf<T>,
+            parent_collection_id: &CombinatorialId,
+        ) -> T::AccountId {
+            let pool_key = (b"cond", (*market_id).saturated_into::<u128>(), parent_collection_id);
+            T::PalletId::get().into_sub_account_truncating(T::Hashing::hash_of(&pool_key))
+        }
+
+        /// The account which holds the budgets of the incentive programs of `market_id`.
+        pub fn incentive_account_id(market_id: &MarketIdOf<T>) -> T::AccountId {
+            T::PalletId::get()
+                .into_sub_account_truncating((b"incv", (*market_id).saturated_into::<u128>()))
+        }
+
         pub fn early_exit_account() -> Result<T::AccountId, Error<T>> {
             Ok(<EarlyExitFeeAccount<T>>::get().ok_or(Error::<T>::EarlyExitFeeAccountNotSet)?)
         }
 
-        pub fn additional_swap_fee() -> Result<BalanceOf<T>, Error<T>> {
+        /// Returns the default additional swap fee.
+        pub fn additional_swap_fee() -> Result<AdditionalSwapFeeModeOf<T>, Error<T>> {
             Ok(<AdditionalSwapFee<T>>::get().ok_or(Error::<T>::AdditionalSwapFeeNotSet)?)
         }
 
+        /// Returns the additional swap fee of markets with base asset `base_asset`.
+        pub fn additional_swap_fee_of(
+            base_asset: AssetOf<T>,
+        ) -> Result<AdditionalSwapFeeModeOf<T>, Error<T>> {
+            match AdditionalSwapFeeSchedules::<T>::get(base_asset) {
+                Some(fee) => Ok(fee),
+                None => Self::additional_swap_fee(),
+            }
+        }
+
+        /// Returns the additional swap fee charged on a trade of `amount` units of collateral in
+        /// the market `market_id`. If `trader` is specified, the discount of their volume tier is
+        /// applied.
+        pub fn additional_swap_fee_amount(
+            market_id: MarketIdOf<T>,
+            trader: Option<&T::AccountId>,
+            amount: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let base_asset = T::MarketCommons::market(&market_id)?.base_asset;
+            let fee = Self::additional_swap_fee_of(base_asset)?;
+            let discount = trader.map(Self::volume_discount).unwrap_or_else(Perbill::zero);
+            Ok(fee.fee_amount(amount, discount))
+        }
+
+        /// Adds `amount` to the trading volume of `who`.
+        pub fn note_trading_volume(who: &T::AccountId, amount: BalanceOf<T>) {
+            TradingVolumes::<T>::mutate(who, |volume| *volume = volume.saturating_add(amount));
+        }
+
+        /// Returns the share of the additional swap fee waived for `who`.
+        fn volume_discount(who: &T::AccountId) -> Perbill {
+            let volume = TradingVolumes::<T>::get(who);
+            AdditionalSwapFeeTiers::<T>::get()
+                .iter()
+                .rev()
+                .find(|tier| tier.min_volume <= volume)
+                .map(|tier| tier.discount)
+                .unwrap_or_else(Perbill::zero)
+        }
+
+        /// Simulate `who` buying `asset_out` from the pool of `market_id` for `amount_in` units of
+        /// collateral without changing any state.
+        ///
+        /// The quote deducts the pool's swap fee and the fees which `T::ExternalFees::fee_amount`
+        /// reports for `who` and performs the same numerical checks as `buy`.
+        pub fn quote_buy(
+            who: &T::AccountId,
+            market_id: MarketIdOf<T>,
+            asset_out: AssetOf<T>,
+            amount_in: BalanceOf<T>,
+        ) -> Result<SwapQuoteOf<T>, DispatchError> {
+            ensure!(amount_in != Zero::zero(), Error::<T>::ZeroAmount);
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(market.status == MarketStatus::Active, Error::<T>::MarketNotActive);
+            let mut pool = Pools::<T>::get(market_id).ok_or(Error::<T>::PoolNotFound)?;
+            ensure!(pool.contains(&asset_out), Error::<T>::AssetNotFound);
+            pool.swap_fee = Self::current_swap_fee(market_id, &pool)?;
+            let spot_price_before = pool.calculate_spot_price(asset_out)?;
+            let FeeDistribution {
+                remaining: amount_in_minus_fees,
+                swap_fees: swap_fee_amount,
+                external_fees: external_fee_amount,
+            } = Self::quote_fees(market_id, &pool, who, amount_in)?;
+            let amount_out =
+                Self::swap_reserves_for_buy(&mut pool, asset_out, amount_in_minus_fees)?;
+            let spot_price_after = pool.calculate_spot_price(asset_out)?;
+            Ok(SwapQuote {
+                amount_in,
+                amount_out,
+                swap_fee_amount,
+                external_fee_amount,
+                spot_price_before,
+                spot_price_after,
+            })
+        }
+
+        /// Simulate `who` selling `amount_in` units of `asset_in` to the pool of `market_id`
+        /// without changing any state.
+        ///
+        /// The quote deducts the pool's swap fee and the fees which `T::ExternalFees::fee_amount`
+        /// reports for `who` and performs the same numerical checks as `sell`.
+        pub fn quote_sell(
+            who: &T::AccountId,
+            market_id: MarketIdOf<T>,
+            asset_in: AssetOf<T>,
+            amount_in: BalanceOf<T>,
+        ) -> Result<SwapQuoteOf<T>, DispatchError> {
+            ensure!(amount_in != Zero::zero(), Error::<T>::ZeroAmount);
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(market.status == MarketStatus::Active, Error::<T>::MarketNotActive);
+            let mut pool = Pools::<T>::get(market_id).ok_or(Error::<T>::PoolNotFound)?;
+            ensure!(pool.contains(&asset_in), Error::<T>::AssetNotFound);
+            pool.swap_fee = Self::current_swap_fee(market_id, &pool)?;
+            let spot_price_before = pool.calculate_spot_price(asset_in)?;
+            let amount_out = Self::swap_reserves_for_sell(&mut pool, asset_in, amount_in)?;
+            let FeeDistribution {
+                remaining: amount_out_minus_fees,
+                swap_fees: swap_fee_amount,
+                external_fees: external_fee_amount,
+            } = Self::quote_fees(market_id, &pool, who, amount_out)?;
+            let spot_price_after = pool.calculate_spot_price(asset_in)?;
+            Ok(SwapQuote {
+                amount_in,
+                amount_out: amount_out_minus_fees,
+                swap_fee_amount,
+                external_fee_amount,
+                spot_price_before,
+                spot_price_after,
+            })
+        }
+
+        /// Return the spot prices of all assets held in the pool of `market_id`.
+        pub fn spot_prices(
+            market_id: MarketIdOf<T>,
+        ) -> Result<Vec<(AssetOf<T>, BalanceOf<T>)>, DispatchError> {
+            let pool = Pools::<T>::get(market_id).ok_or(Error::<T>::PoolNotFound)?;
+            pool.assets()
+                .into_iter()
+                .map(|asset| Ok((asset, pool.calculate_spot_price(asset)?)))
+                .collect()
+        }
+
+        /// Returns the time-weighted average prices of all assets held in the pool of `market_id`
+        /// over the last `period` blocks.
+        ///
+        /// Each block is weighted with the spot prices after the last trade of the previous block,
+        /// so trades in the current block don't affect the result. Fails if the oldest observation
+        /// kept by the pool is younger than `period` blocks.
+        pub fn twap(
+            market_id: MarketIdOf<T>,
+            period: BlockNumberFor<T>,
+        ) -> Result<Vec<(AssetOf<T>, BalanceOf<T>)>, DispatchError> {
+            ensure!(!period.is_zero(), Error::<T>::ZeroTwapPeriod);
+            let pool = Pools::<T>::get(market_id).ok_or(Error::<T>::PoolNotFound)?;
+            let latest_slot = LatestObservations::<T>::get(market_id)
+                .ok_or(Error::<T>::InsufficientObservations)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            let start = now.checked_sub(&period).ok_or(Error::<T>::InsufficientObservations)?;
+            let latest =
+                Observations::<T>::get(market_id, latest_slot).ok_or(Error::<T>::Unexpected)?;
+            let first = Observations::<T>::iter_prefix_values(market_id)
+                .filter(|observation| observation.block <= start)
+                .max_by_key(|observation| observation.block)
+                .ok_or(Error::<T>::InsufficientObservations)?;
+            let period_balance: BalanceOf<T> = period.saturated_into::<u128>().saturated_into();
+            pool.assets()
+                .into_iter()
+                .zip(latest.cumulative_prices_at(now))
+                .zip(first.cumulative_prices_at(start))
+                .map(|((asset, cumulative_price_now), cumulative_price_start)| {
+                    let twap = cumulative_price_now
+                        .checked_sub_res(&cumulative_price_start)?
+                        .checked_div_res(&period_balance)?;
+                    Ok((asset, twap))
+                })
+                .collect()
+        }
+
+        /// Moves the reserves of `pool` as if `amount_in_minus_fees` units of collateral (net of
+        /// all fees) were used to buy `asset_out` and returns the amount of `asset_out` that the
+        /// buyer receives.
+        ///
+        /// Shared by `do_buy` and `quote_buy`, so quotes are subject to the same numerical limits
+        /// as trades.
+        fn swap_reserves_for_buy(
+            pool: &mut PoolOf<T>,
+            asset_out: AssetOf<T>,
+            amount_in_minus_fees: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            ensure!(
+                amount_in_minus_fees <= pool.calculate_numerical_threshold(),
+                Error::<T>::NumericalLimits(NumericalLimitsError::MaxAmountExceeded),
+            );
+            ensure!(
+                pool.calculate_buy_ln_argument(asset_out, amount_in_minus_fees)? >=
+                    LN_NUMERICAL_LIMIT.saturated_into(),
+                Error::<T>::NumericalLimits(NumericalLimitsError::MinAmountNotMet),
+            );
+            let swap_amount_out =
+                pool.calculate_swap_amount_out_for_buy(asset_out, amount_in_minus_fees)?;
+            let amount_out = swap_amount_out.checked_add_res(&amount_in_minus_fees)?;
+            for asset in pool.assets().iter() {
+                pool.increase_reserve(asset, &amount_in_minus_fees)?;
+                if *asset == asset_out {
+                    pool.decrease_reserve(asset, &amount_out)?;
+                }
+            }
+            Ok(amount_out)
+        }
+
+        /// Moves the reserves of `pool` as if `amount_in` units of `asset_in` were sold and
+        /// returns the amount of collateral that the sale yields before fees.
+        ///
+        /// Shared by `do_sell` and `quote_sell`, so quotes are subject to the same numerical limits
+        /// as trades.
+        fn swap_reserves_for_sell(
+            pool: &mut PoolOf<T>,
+            asset_in: AssetOf<T>,
+            amount_in: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            // Ensure that the price of `asset_in` is at least `exp(-EXP_NUMERICAL_LIMITS) =
+            // 4.5399...e-05`.
+            ensure!(
+                pool.reserve_of(&asset_in)? <= pool.calculate_numerical_threshold(),
+                Error::<T>::NumericalLimits(NumericalLimitsError::SpotPriceTooLow),
+            );
+            ensure!(
+                amount_in <= pool.calculate_numerical_threshold(),
+                Error::<T>::NumericalLimits(NumericalLimitsError::MaxAmountExceeded),
+            );
+            let amount_out = pool.calculate_swap_amount_out_for_sell(asset_in, amount_in)?;
+            for asset in pool.assets().iter() {
+                if *asset == asset_in {
+                    pool.increase_reserve(asset, &amount_in)?;
+                }
+                pool.decrease_reserve(asset, &amount_out)?;
+            }
+            // Ensure that the sell doesn't move the price below the minimum defined by
+            // `EXP_NUMERICAL_LIMITS` (see comment above).
+            ensure!(
+                pool.reserve_of(&asset_in)? <= pool.calculate_numerical_threshold(),
+                Error::<T>::NumericalLimits(NumericalLimitsError::SpotPriceSlippedTooLow),
+            );
+            Ok(amount_out)
+        }
+
+        /// Read-only counterpart of `distribute_fees`.
+        fn quote_fees(
+            market_id: MarketIdOf<T>,
+            pool: &PoolOf<T>,
+            who: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> Result<FeeDistribution<T>, DispatchError> {
+            let swap_fees = pool.swap_fee.bmul(amount)?;
+            let external_fees = T::ExternalFees::fee_amount(market_id, who, amount);
+            let total_fees = external_fees.saturating_add(swap_fees);
+            let remaining = amount.checked_sub(&total_fees).ok_or(Error::<T>::Unexpected)?;
+            Ok(FeeDistribution { remaining, swap_fees, external_fees })
+        }
+
         /// Distribute swap fees and external fees and returns the remaining amount.
         ///
         /// # Arguments
         ///
         /// - `market_id`: The ID of the market to which the pool belongs.
         /// - `pool`: The pool on which the trade was executed.
+        /// - `who`: The trader on whose behalf the pool pays the external fees.
         /// - `amount`: The gross amount from which the fee is deduced.
         ///
         /// Will fail if the total amount of fees is more than the gross amount. In particular, the
@@ -1206,17 +3314,165 @@ mod pallet {
         fn distribute_fees(
             market_id: MarketIdOf<T>,
             pool: &mut PoolOf<T>,
+            who: &T::AccountId,
             amount: BalanceOf<T>,
         ) -> Result<FeeDistribution<T>, DispatchError> {
             let swap_fees = pool.swap_fee.bmul(amount)?;
             pool.liquidity_shares_manager.deposit_fees(swap_fees)?; // Should only error unexpectedly!
-            let external_fees =
-                T::ExternalFees::distribute(market_id, pool.collateral, &pool.account_id, amount);
+            let external_fees = T::ExternalFees::distribute_on_behalf_of(
+                market_id,
+                pool.collateral,
+                &pool.account_id,
+                who,
+                amount,
+            );
             let total_fees = external_fees.saturating_add(swap_fees);
             let remaining = amount.checked_sub(&total_fees).ok_or(Error::<T>::Unexpected)?;
             Ok(FeeDistribution { remaining, swap_fees, external_fees })
         }
 
+        /// Returns the spot prices of all assets in `pool`, ordered like the pool's assets.
+        fn pool_spot_prices(
+            pool: &PoolOf<T>,
+        ) -> Result<BoundedVec<BalanceOf<T>, MaxAssets>, DispatchError> {
+            let spot_prices = pool
+                .assets()
+                .into_iter()
+                .map(|asset| pool.calculate_spot_price(asset))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(spot_prices.try_into().map_err(|_| Error::<T>::Unexpected)?)
+        }
+
+        /// Returns the price history of the pool of `market_id` as of the current block.
+        ///
+        /// If the window of the stored history has elapsed, a new window is started at the current
+        /// spot prices and the volatility is halved for every full window that has passed.
+        fn current_price_history(
+            market_id: MarketIdOf<T>,
+            pool: &PoolOf<T>,
+            params: &DynamicFeeParamsOf<T>,
+        ) -> Result<PriceHistory<T>, DispatchError> {
+            let mut price_history =
+                PriceHistories::<T>::get(market_id).ok_or(Error::<T>::Unexpected)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            let elapsed = now.saturating_sub(price_history.window_start);
+            if elapsed >= params.window {
+                let windows: u32 = elapsed
+                    .checked_div(&params.window)
+                    .ok_or(Error::<T>::Unexpected)?
+                    .saturated_into();
+                price_history.volatility =
+                    price_history.volatility.checked_shr(windows).unwrap_or_else(Zero::zero);
+                price_history.window_start = now;
+                price_history.reference_prices = Self::pool_spot_prices(pool)?;
+            }
+            Ok(price_history)
+        }
+
+        /// Returns the swap fee that the pool of `market_id` currently charges.
+        fn current_swap_fee(
+            market_id: MarketIdOf<T>,
+            pool: &PoolOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            match DynamicSwapFees::<T>::get(market_id) {
+                Some(params) => {
+                    let price_history = Self::current_price_history(market_id, pool, &params)?;
+                    params.fee(price_history.volatility)
+                },
+                None => Ok(pool.swap_fee),
+            }
+        }
+
+        /// Sets the swap fee of `pool` to the current dynamic fee, if the pool charges one, and
+        /// returns the price history that the trade must be recorded in.
+        fn apply_dynamic_swap_fee(
+            market_id: MarketIdOf<T>,
+            pool: &mut PoolOf<T>,
+        ) -> Result<Option<PriceHistory<T>>, DispatchError> {
+            let params = match DynamicSwapFees::<T>::get(market_id) {
+                Some(params) => params,
+                None => return Ok(None),
+            };
+            let price_history = Self::current_price_history(market_id, pool, &params)?;
+            pool.swap_fee = params.fee(price_history.volatility)?;
+            Ok(Some(price_history))
+        }
+
+        /// Raises the volatility of `price_history` to the largest deviation of the spot prices of
+        /// `pool` from the window's reference prices and stores the history.
+        fn record_price_movement(
+            market_id: MarketIdOf<T>,
+            pool: &PoolOf<T>,
+            mut price_history: PriceHistory<T>,
+        ) -> DispatchResult {
+            for (asset, &reference_price) in
+                pool.assets().into_iter().zip(price_history.reference_prices.iter())
+            {
+                let spot_price = pool.calculate_spot_price(asset)?;
+                let movement =
+                    spot_price.max(reference_price).saturating_sub(spot_price.min(reference_price));
+                price_history.volatility = price_history.volatility.max(movement);
+            }
+            PriceHistories::<T>::insert(market_id, price_history);
+            Ok(())
+        }
+
+        /// Records the current spot prices of `pool` in its observation ring.
+        ///
+        /// The first update in a block accumulates the prices of the previous observation over the
+        /// blocks that have passed since and takes the next slot, overwriting the oldest
+        /// observation once the ring is full. Later updates in the same block only replace the
+        /// spot prices of that observation.
+        fn record_observation(market_id: MarketIdOf<T>, pool: &PoolOf<T>) -> DispatchResult {
+            let now = frame_system::Pallet::<T>::block_number();
+            let spot_prices = Self::pool_spot_prices(pool)?;
+            let latest_slot = match LatestObservations::<T>::get(market_id) {
+                Some(latest_slot) => latest_slot,
+                None => {
+                    Observations::<T>::insert(market_id, 0, Observation::new(now, spot_prices)?);
+                    LatestObservations::<T>::insert(market_id, 0);
+                    return Ok(());
+                },
+            };
+            let mut latest =
+                Observations::<T>::get(market_id, latest_slot).ok_or(Error::<T>::Unexpected)?;
+            if latest.block == now {
+                latest.spot_prices = spot_prices;
+                Observations::<T>::insert(market_id, latest_slot, latest);
+                return Ok(());
+            }
+            let next_slot = latest_slot
+                .saturating_add(1)
+                .checked_rem(T::MaxObservations::get())
+                .unwrap_or(0);
+            Observations::<T>::insert(market_id, next_slot, latest.next(now, spot_prices)?);
+            LatestObservations::<T>::insert(market_id, next_slot);
+            Ok(())
+        }
+
+        /// The maximum weight of updating the range positions of a regular pool after a trade. Each
+        /// range position which is deactivated or reactivated settles its incentive rewards.
+        pub(crate) fn range_update_max_weight() -> Weight {
+            let max_range_positions = T::MaxRangePositions::get();
+            T::WeightInfo::update_range_positions(max_range_positions).saturating_add(
+                T::WeightInfo::settle_incentives().saturating_mul(max_range_positions.into()),
+            )
+        }
+
+        /// Records the trade in the price history, the observations and the range positions of
+        /// the regular pool of `market_id`.
+        fn after_regular_pool_trade(
+            market_id: MarketIdOf<T>,
+            pool: &mut PoolOf<T>,
+            price_history: Option<PriceHistory<T>>,
+        ) -> DispatchResult {
+            if let Some(price_history) = price_history {
+                Self::record_price_movement(market_id, pool, price_history)?;
+            }
+            Self::record_observation(market_id, pool)?;
+            Self::update_range_positions(market_id, pool)
+        }
+
         pub(crate) fn try_mutate_pool<R, F>(
             market_id: &MarketIdOf<T>,
             mutator: F,
@@ -1224,9 +3480,27 @@ mod pallet {
         where
             F: FnMut(&mut PoolOf<T>) -> Result<R, DispatchError>,
         {
-            Pools::<T>::try_mutate(market_id, |maybe_pool| {
+            Self::try_mutate_pool_of(market_id, None, mutator)
+        }
+
+        /// Like `try_mutate_pool`, but mutates the conditional pool of `market_id` whose positions
+        /// are conditioned on `parent_collection_id` if it is specified.
+        pub(crate) fn try_mutate_pool_of<R, F>(
+            market_id: &MarketIdOf<T>,
+            parent_collection_id: Option<CombinatorialId>,
+            mutator: F,
+        ) -> Result<R, DispatchError>
+        where
+            F: FnMut(&mut PoolOf<T>) -> Result<R, DispatchError>,
+        {
+            let mutate = |maybe_pool: &mut Option<PoolOf<T>>| {
                 maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound.into()).and_then(mutator)
-            })
+            };
+            match parent_collection_id {
+                Some(parent_collection_id) =>
+                    ConditionalPools::<T>::try_mutate(market_id, parent_collection_id, mutate),
+                None => Pools::<T>::try_mutate(market_id, mutate),
+            }
         }
     }
 
@@ -1242,7 +3516,7 @@ mod pallet {
             spot_prices: Vec<Self::Balance>,
             swap_fee: Self::Balance,
         ) -> DispatchResult {
-            Self::do_deploy_pool(who, market_id, amount, spot_prices, swap_fee)
+            Self::do_deploy_pool(who, market_id, None, amount, spot_prices, swap_fee)
         }
     }
 
@@ -1285,6 +3559,7 @@ mod pallet {
         type Asset = AssetOf<T>;
 
         fn pool_exists(market_id: Self::MarketId) -> bool {
+            // Conditional pools don't trade against the market's base asset.
             Pools::<T>::contains_key(market_id)
         }
 
@@ -1297,16 +3572,29 @@ mod pallet {
         }
 
         fn calculate_buy_amount_until(
+            who: &Self::AccountId,
             market_id: Self::MarketId,
             asset: Self::Asset,
             until: Self::Balance,
         ) -> Result<Self::Balance, DispatchError> {
             let pool = Pools::<T>::get(market_id).ok_or(Error::<T>::PoolNotFound)?;
             let buy_amount = pool.calculate_buy_amount_until(asset, until)?;
-            let total_fee_fractional =
-                pool.swap_fee.checked_add_res(&Self::additional_swap_fee()?)?;
-            let buy_amount_plus_fees =
-                Self::amount_including_fee_surplus(buy_amount, total_fee_fractional)?;
+            let swap_fee = Self::current_swap_fee(market_id, &pool)?;
+            // The external fees charged on one unit approximate the fraction they take from any
+            // amount.
+            let external_fee_fractional =
+                T::ExternalFees::fee_amount(market_id, who, BASE.saturated_into());
+            let total_fee_fractional = swap_fee.checked_add_res(&external_fee_fractional)?;
+            let estimate = Self::amount_including_fee_surplus(buy_amount, total_fee_fractional)?;
+            // Flat fees and percentage fees raised to their minimum or capped at their maximum
+            // don't scale with the amount, so the external fees are charged on the estimate and
+            // only the swap fee is treated as a fraction. This doesn't change the estimate of fees
+            // which do scale with the amount.
+            let external_fees = T::ExternalFees::fee_amount(market_id, who, estimate);
+            let buy_amount_plus_fees = Self::amount_including_fee_surplus(
+                buy_amount.checked_add_res(&external_fees)?,
+                swap_fee,
+            )?;
             Ok(buy_amount_plus_fees)
         }
 
@@ -1317,7 +3605,7 @@ mod pallet {
             amount_in: Self::Balance,
             min_amount_out: Self::Balance,
         ) -> Result<AmmTradeOf<T>, ApiError<AmmSoftFail>> {
-            Self::do_buy(who, market_id, asset_out, amount_in, min_amount_out)
+            Self::do_buy(who, market_id, None, asset_out, amount_in, min_amount_out)
                 .map_err(Self::match_failure)
         }
 
@@ -1337,9 +3625,13 @@ mod pallet {
             amount_in: Self::Balance,
             min_amount_out: Self::Balance,
         ) -> Result<AmmTradeOf<T>, ApiError<AmmSoftFail>> {
-            Self::do_sell(who, market_id, asset_out, amount_in, min_amount_out)
+            Self::do_sell(who, market_id, None, asset_out, amount_in, min_amount_out)
                 .map_err(Self::match_failure)
         }
+
+        fn trade_overhead_max_weight() -> Weight {
+            Self::range_update_max_weight()
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -1371,6 +3663,58 @@ mod pallet {
             (EXIT_CONTEXT, relayer, market_id, pool_shares, min_amounts_out, block_number).encode()
         }
 
+        pub fn encode_signed_claim_incentives_params(
+            relayer: &T::AccountId,
+            market_id: &MarketIdOf<T>,
+            block_number: &BlockNumberFor<T>,
+        ) -> Vec<u8> {
+            (CLAIM_INCENTIVES_CONTEXT, relayer, market_id, block_number).encode()
+        }
+
+        pub fn encode_signed_join_with_collateral_params(
+            relayer: &T::AccountId,
+            market_id: &MarketIdOf<T>,
+            asset_count: &AssetIndexType,
+            amount_in: &BalanceOf<T>,
+            min_pool_shares_amount: &BalanceOf<T>,
+            min_leftover_amounts_out: &Vec<BalanceOf<T>>,
+            block_number: &BlockNumberFor<T>,
+        ) -> Vec<u8> {
+            (
+                JOIN_WITH_COLLATERAL_CONTEXT,
+                relayer,
+                market_id,
+                asset_count,
+                amount_in,
+                min_pool_shares_amount,
+                min_leftover_amounts_out,
+                block_number,
+            )
+                .encode()
+        }
+
+        pub fn encode_signed_exit_to_collateral_params(
+            relayer: &T::AccountId,
+            market_id: &MarketIdOf<T>,
+            asset_count: &AssetIndexType,
+            pool_shares_amount: &BalanceOf<T>,
+            min_amount_out: &BalanceOf<T>,
+            min_leftover_amounts_out: &Vec<BalanceOf<T>>,
+            block_number: &BlockNumberFor<T>,
+        ) -> Vec<u8> {
+            (
+                EXIT_TO_COLLATERAL_CONTEXT,
+                relayer,
+                market_id,
+                asset_count,
+                pool_shares_amount,
+                min_amount_out,
+                min_leftover_amounts_out,
+                block_number,
+            )
+                .encode()
+        }
+
         pub fn get_encoded_call_param(
             call: &<T as Config>::RuntimeCall,
         ) -> Option<(&Proof<T::Signature, T::AccountId>, Vec<u8>)> {
@@ -1423,6 +3767,57 @@ mod pallet {
 
                     Some((proof, encoded_data))
                 },
+                Call::signed_claim_incentives { ref proof, ref market_id, ref block_number } => {
+                    let encoded_data = Self::encode_signed_claim_incentives_params(
+                        &proof.relayer,
+                        market_id,
+                        block_number,
+                    );
+
+                    Some((proof, encoded_data))
+                },
+                Call::signed_join_with_collateral {
+                    ref proof,
+                    ref market_id,
+                    ref asset_count,
+                    ref amount_in,
+                    ref min_pool_shares_amount,
+                    ref min_leftover_amounts_out,
+                    ref block_number,
+                } => {
+                    let encoded_data = Self::encode_signed_join_with_collateral_params(
+                        &proof.relayer,
+                        market_id,
+                        asset_count,
+                        amount_in,
+                        min_pool_shares_amount,
+                        min_leftover_amounts_out,
+                        block_number,
+                    );
+
+                    Some((proof, encoded_data))
+                },
+                Call::signed_exit_to_collateral {
+                    ref proof,
+                    ref market_id,
+                    ref asset_count,
+                    ref pool_shares_amount,
+                    ref min_amount_out,
+                    ref min_leftover_amounts_out,
+                    ref block_number,
+                } => {
+                    let encoded_data = Self::encode_signed_exit_to_collateral_params(
+                        &proof.relayer,
+                        market_id,
+                        asset_count,
+                        pool_shares_amount,
+                        min_amount_out,
+                        min_leftover_amounts_out,
+                        block_number,
+                    );
+
+                    Some((proof, encoded_data))
+                },
 
                 _ => None,
             }
@@ -0,0 +1,68 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use test_case::test_case;
+
+#[test_case(false)]
+#[test_case(true)]
+fn deactivate_root_works(deactivate_all: bool) {
+    let mut tree = utility::create_test_tree();
+    // Remove lazy fees on the path to the node. Unlike `exit`, the node's fees are kept.
+    tree.nodes[0].lazy_fees = Zero::zero();
+
+    let mut nodes = tree.nodes.clone().into_inner();
+    let amount = if deactivate_all { _1 } else { _1_2 };
+    nodes[0].stake -= amount;
+    let account_to_index = tree.account_to_index.clone().into_inner();
+    let abandoned_nodes = tree.abandoned_nodes.clone().into_inner();
+
+    tree.deactivate(&get_account(3), amount).unwrap();
+    assert_liquidity_tree_state!(tree, nodes, account_to_index, abandoned_nodes);
+}
+
+#[test_case(false)]
+#[test_case(true)]
+fn deactivate_middle_works(deactivate_all: bool) {
+    let mut tree = utility::create_test_tree();
+    // Remove lazy fees on the path to the node. Unlike `exit`, the node's fees are kept.
+    tree.nodes[0].lazy_fees = Zero::zero();
+    tree.nodes[1].lazy_fees = Zero::zero();
+    tree.nodes[3].lazy_fees = Zero::zero();
+
+    let mut nodes = tree.nodes.clone().into_inner();
+    let amount = if deactivate_all { _3 } else { _1 };
+    nodes[0].descendant_stake -= amount;
+    nodes[1].descendant_stake -= amount;
+    nodes[3].stake -= amount;
+    let account_to_index = tree.account_to_index.clone().into_inner();
+    let abandoned_nodes = tree.abandoned_nodes.clone().into_inner();
+
+    tree.deactivate(&get_account(5), amount).unwrap();
+    assert_liquidity_tree_state!(tree, nodes, account_to_index, abandoned_nodes);
+    // The account keeps its node and may still withdraw its fees.
+    assert_eq!(tree.withdraw_fees(&get_account(5)).unwrap(), _1);
+}
+
+#[test]
+fn deactivate_fails_on_insufficient_stake() {
+    let mut tree = utility::create_test_tree();
+    assert_err!(
+        tree.deactivate(&get_account(9), _3 + 1),
+        LiquidityTreeError::InsufficientStake.into_dispatch_error::<Runtime>(),
+    );
+}
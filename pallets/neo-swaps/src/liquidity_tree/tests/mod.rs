@@ -32,6 +32,7 @@ use frame_support::assert_err;
 use prediction_market_primitives::constants::base_multiples::*;
 pub use prediction_market_primitives::test_helper::get_account;
 use sp_runtime::traits::Zero;
+mod deactivate;
 mod deposit_fees;
 mod exit;
 mod join;
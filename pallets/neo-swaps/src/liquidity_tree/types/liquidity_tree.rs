@@ -215,6 +215,24 @@ where
         Ok(())
     }
 
+    fn deactivate(&mut self, who: &T::AccountId, stake: BalanceOf<T>) -> DispatchResult {
+        let index = self.map_account_to_index(who)?;
+        self.propagate_fees_to_node(index)?;
+        let node = self.get_node_mut(index)?;
+        node.stake = node
+            .stake
+            .checked_sub(&stake)
+            .ok_or(LiquidityTreeError::InsufficientStake.into_dispatch_error::<T>())?;
+        if let Some(parent_index) = self.parent_index(index) {
+            self.update_descendant_stake_of_ancestors(
+                parent_index,
+                stake,
+                UpdateDescendantStakeOperation::Sub,
+            )?;
+        }
+        Ok(())
+    }
+
     fn split(
         &mut self,
         _sender: &T::AccountId,
@@ -14,3 +14,74 @@
 //
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{types::AdditionalSwapFeeMode, *};
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use frame_support::ensure;
+use frame_support::{
+    storage_alias,
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+};
+#[cfg(feature = "try-runtime")]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const LOG_TARGET: &str = "runtime::pallet-pm-neo-swaps";
+
+mod v2 {
+    use super::*;
+
+    #[storage_alias]
+    pub(super) type AdditionalSwapFee<T: Config> = StorageValue<Pallet<T>, BalanceOf<T>>;
+}
+
+/// Converts the flat `AdditionalSwapFee` amount into `AdditionalSwapFeeMode::Flat`.
+pub struct MigrateAdditionalSwapFeeMode<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateAdditionalSwapFeeMode<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let onchain = Pallet::<T>::on_chain_storage_version();
+        if onchain != 2 {
+            log::info!(
+                target: LOG_TARGET,
+                "Skipping MigrateAdditionalSwapFeeMode, on-chain storage version is {:?}",
+                onchain
+            );
+            return T::DbWeight::get().reads(1);
+        }
+
+        let result = <AdditionalSwapFee<T>>::translate::<BalanceOf<T>, _>(|old_fee| {
+            old_fee.map(AdditionalSwapFeeMode::Flat)
+        });
+        if result.is_err() {
+            log::error!(target: LOG_TARGET, "Failed to decode the additional swap fee");
+        }
+
+        StorageVersion::new(3).put::<Pallet<T>>();
+        log::info!(target: LOG_TARGET, "Migrated the additional swap fee to a flat fee mode");
+
+        T::DbWeight::get().reads_writes(2, 2)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        Ok(<v2::AdditionalSwapFee<T>>::get().encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(input: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let old_fee: Option<BalanceOf<T>> =
+            Decode::decode(&mut &input[..]).map_err(|_| "failed to decode the old fee")?;
+        ensure!(
+            <AdditionalSwapFee<T>>::get() == old_fee.map(AdditionalSwapFeeMode::Flat),
+            "additional swap fee was not migrated"
+        );
+        ensure!(Pallet::<T>::on_chain_storage_version() == 3, "storage version was not updated");
+        Ok(())
+    }
+}
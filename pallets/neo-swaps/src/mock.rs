@@ -32,6 +32,7 @@ use frame_support::{
 };
 use frame_system::{mocking::MockBlockU32, EnsureRoot, EnsureSignedBy};
 use orml_traits::{asset_registry::AssetProcessor, MultiCurrency};
+use pallet_pm_global_disputes::types::VotingMode;
 use pallet_pm_neo_swaps::BalanceOf;
 use parity_scale_codec::{alloc::sync::Arc, Encode};
 pub use prediction_market_primitives::test_helper::{get_account, get_account_from_mnemonic};
@@ -42,16 +43,18 @@ use prediction_market_primitives::{
             AddOutcomePeriod, AggregationPeriod, AppealBond, AppealPeriod, AuthorizedPalletId,
             BlockHashCount, BlocksPerYear, CloseEarlyBlockPeriod, CloseEarlyDisputeBond,
             CloseEarlyProtectionBlockPeriod, CloseEarlyProtectionTimeFramePeriod,
-            CloseEarlyRequestBond, CloseEarlyTimeFramePeriod, CorrectionPeriod, CourtPalletId,
-            ExistentialDeposit, ExistentialDeposits, GdVotingPeriod, GetNativeCurrencyId,
-            GlobalDisputeLockId, GlobalDisputesPalletId, InflationPeriod, LockId, MaxAppeals,
-            MaxApprovals, MaxCourtParticipants, MaxCreatorFee, MaxDelegations, MaxDisputeDuration,
-            MaxDisputes, MaxEditReasonLen, MaxGlobalDisputeVotes, MaxGracePeriod,
-            MaxLiquidityTreeDepth, MaxLocks, MaxMarketLifetime, MaxOracleDuration, MaxOwners,
-            MaxRejectReasonLen, MaxReserves, MaxSelectedDraws, MaxYearlyInflation, MinCategories,
-            MinDisputeDuration, MinJurorStake, MinOracleDuration, MinOutcomeVoteAmount,
-            MinimumPeriod, NeoMaxSwapFee, NeoSwapsPalletId, OutsiderBond, PmPalletId,
-            RemoveKeysLimit, RequestInterval, TreasuryPalletId, VotePeriod, VotingOutcomeFee,
+            CloseEarlyRequestBond, CloseEarlyTimeFramePeriod, ConvictionLockPeriod,
+            CorrectionPeriod, CourtPalletId, ExistentialDeposit, ExistentialDeposits,
+            GdVotingPeriod, GetNativeCurrencyId, GlobalDisputeLockId, GlobalDisputesPalletId,
+            InflationPeriod, LockId, MaxAppeals, MaxApprovals, MaxCourtParticipants, MaxCreatorFee,
+            MaxDelegations, MaxDisputeDuration, MaxDisputes, MaxEditReasonLen, MaxGdDelegators,
+            MaxGlobalDisputeVotes, MaxGracePeriod, MaxIncentivePrograms, MaxLiquidityTreeDepth,
+            MaxLocks, MaxMarketLifetime, MaxObservations, MaxOracleDuration, MaxOwners,
+            MaxPanelMembers, MaxRangePositions, MaxRejectReasonLen, MaxReserves, MaxSelectedDraws,
+            MaxSplitDepth, MaxVolumeTiers, MaxYearlyInflation, MinCategories, MinDisputeDuration,
+            MinJurorStake, MinOracleDuration, MinOutcomeVoteAmount, MinimumPeriod, NeoMaxSwapFee,
+            NeoSwapsPalletId, OutsiderBond, PanelReportPeriod, PmPalletId, RemoveKeysLimit,
+            ReputationInfluence, RequestInterval, TreasuryPalletId, VotePeriod, VotingOutcomeFee,
             CENT_BASE,
         },
     },
@@ -119,6 +122,7 @@ parameter_types! {
     pub const AdvisoryBond: Balance = 0;
     pub const AdvisoryBondSlashPercentage: Percent = Percent::from_percent(10);
     pub const OracleBond: Balance = 0;
+    pub const MultiOracleBond: Balance = 0;
     pub const ValidityBond: Balance = 0;
     pub const DisputeBond: Balance = 0;
     pub const MaxCategories: u16 = MAX_ASSETS + 1;
@@ -157,17 +161,39 @@ where
     type MarketId = MarketIdOf<T>;
 
     fn distribute(
-        _market_id: Self::MarketId,
+        market_id: Self::MarketId,
         asset: Self::Asset,
         account: &Self::AccountId,
-        _amount: Self::Balance,
+        amount: Self::Balance,
     ) -> Self::Balance {
-        let fees = NeoSwaps::additional_swap_fee().unwrap().saturated_into();
+        Self::distribute_on_behalf_of(market_id, asset, account, account, amount)
+    }
+
+    fn distribute_on_behalf_of(
+        market_id: Self::MarketId,
+        asset: Self::Asset,
+        account: &Self::AccountId,
+        trader: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Self::Balance {
+        let fees = crate::Pallet::<T>::additional_swap_fee_amount(market_id, Some(trader), amount)
+            .unwrap();
         match T::MultiCurrency::transfer(asset, account, &F::get(), fees) {
-            Ok(_) => fees,
+            Ok(_) => {
+                crate::Pallet::<T>::note_trading_volume(trader, amount);
+                fees
+            },
             Err(_) => Zero::zero(),
         }
     }
+
+    fn fee_amount(
+        market_id: Self::MarketId,
+        trader: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Self::Balance {
+        crate::Pallet::<T>::additional_swap_fee_amount(market_id, Some(trader), amount).unwrap()
+    }
 }
 
 pub struct DustRemovalWhitelist;
@@ -209,6 +235,14 @@ where
             Err(_) => Zero::zero(),
         }
     }
+
+    fn fee_amount(
+        _market_id: Self::MarketId,
+        _trader: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Self::Balance {
+        calculate_fee::<T>(amount)
+    }
 }
 
 construct_runtime!(
@@ -234,12 +268,19 @@ construct_runtime!(
 impl crate::Config for Runtime {
     type MultiCurrency = AssetManager;
     type CompleteSetOperations = PredictionMarkets;
-    type ExternalFees = ExternalFees<Runtime, AdditionalFeeAccount>;
+    type ExternalFees = (
+        ExternalFees<Runtime, AdditionalFeeAccount>,
+        pallet_prediction_markets::MarketCreatorFee<Runtime>,
+    );
     type MarketCommons = MarketCommons;
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
+    type MaxIncentivePrograms = MaxIncentivePrograms;
     type MaxLiquidityTreeDepth = MaxLiquidityTreeDepth;
+    type MaxObservations = MaxObservations;
+    type MaxRangePositions = MaxRangePositions;
     type MaxSwapFee = NeoMaxSwapFee;
+    type MaxVolumeTiers = MaxVolumeTiers;
     type PalletId = NeoSwapsPalletId;
     type WeightInfo = pallet_pm_neo_swaps::weights::WeightInfo<Runtime>;
     type SignedTxLifetime = ConstU32<16>;
@@ -283,7 +324,9 @@ impl pallet_prediction_markets::Config for Runtime {
     type MinCategories = MinCategories;
     type MaxEditReasonLen = MaxEditReasonLen;
     type MaxRejectReasonLen = MaxRejectReasonLen;
+    type MaxSplitDepth = MaxSplitDepth;
     type OracleBond = OracleBond;
+    type MultiOracleBond = MultiOracleBond;
     type OutsiderBond = OutsiderBond;
     type PalletId = PmPalletId;
     type RejectOrigin = EnsureSignedBy<Sudo, TestAccountIdPK>;
@@ -309,7 +352,9 @@ impl pallet_pm_authorized::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DisputeResolution = pallet_prediction_markets::Pallet<Runtime>;
     type MarketCommons = MarketCommons;
+    type MaxPanelMembers = MaxPanelMembers;
     type PalletId = AuthorizedPalletId;
+    type PanelReportPeriod = PanelReportPeriod;
     type WeightInfo = pallet_pm_authorized::weights::WeightInfo<Runtime>;
 }
 
@@ -342,8 +387,12 @@ impl pallet_pm_court::Config for Runtime {
     type MinJurorStake = MinJurorStake;
     type MonetaryGovernanceOrigin = EnsureRoot<TestAccountIdPK>;
     type PalletId = CourtPalletId;
+    type Public = TestAccountIdPK;
     type Random = RandomnessCollectiveFlip;
+    type ReputationInfluence = ReputationInfluence;
     type RequestInterval = RequestInterval;
+    type RuntimeCall = RuntimeCall;
+    type Signature = SignatureTest;
     type Slash = Treasury;
     type TreasuryPalletId = TreasuryPalletId;
     type WeightInfo = pallet_pm_court::weights::WeightInfo<Runtime>;
@@ -425,20 +474,27 @@ impl pallet_timestamp::Config for Runtime {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const GdVotingMode: VotingMode = VotingMode::Linear;
+}
+
 impl pallet_pm_global_disputes::Config for Runtime {
     type AddOutcomePeriod = AddOutcomePeriod;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
     type RuntimeEvent = RuntimeEvent;
     type DisputeResolution = pallet_prediction_markets::Pallet<Runtime>;
     type MarketCommons = MarketCommons;
     type Currency = Balances;
     type GlobalDisputeLockId = GlobalDisputeLockId;
     type GlobalDisputesPalletId = GlobalDisputesPalletId;
+    type MaxDelegators = MaxGdDelegators;
     type MaxGlobalDisputeVotes = MaxGlobalDisputeVotes;
     type MaxOwners = MaxOwners;
     type MinOutcomeVoteAmount = MinOutcomeVoteAmount;
     type RemoveKeysLimit = RemoveKeysLimit;
     type GdVotingPeriod = GdVotingPeriod;
     type VotingOutcomeFee = VotingOutcomeFee;
+    type VotingMode = GdVotingMode;
     type WeightInfo = pallet_pm_global_disputes::weights::WeightInfo<Runtime>;
 }
 
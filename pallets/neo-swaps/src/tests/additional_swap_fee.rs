@@ -0,0 +1,270 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::types::VolumeTier;
+use prediction_market_primitives::traits::HybridRouterAmmApi;
+use test_case::test_case;
+
+fn percentage(
+    percent: u32,
+    min: Option<BalanceOf<Runtime>>,
+    max: Option<BalanceOf<Runtime>>,
+) -> AdditionalSwapFeeModeOf<Runtime> {
+    AdditionalSwapFeeMode::Percentage { rate: Perbill::from_percent(percent), min, max }
+}
+
+fn create_market_and_deploy_default_pool() -> MarketId {
+    create_market_and_deploy_pool(
+        alice(),
+        BASE_ASSET,
+        MarketType::Categorical(2),
+        _10,
+        vec![_1_2, _1_2],
+        CENT_BASE,
+    )
+}
+
+fn buy_first_outcome(
+    who: AccountIdOf<Runtime>,
+    market_id: MarketId,
+    amount_in: BalanceOf<Runtime>,
+) {
+    assert_ok!(AssetManager::deposit(BASE_ASSET, &who, amount_in));
+    assert_ok!(NeoSwaps::buy(
+        RuntimeOrigin::signed(who),
+        market_id,
+        2,
+        Asset::CategoricalOutcome(market_id, 0),
+        amount_in,
+        0
+    ));
+}
+
+#[test_case(percentage(1, None, None), _1; "uncapped")]
+#[test_case(percentage(1, Some(_2), None), _2; "raised_to_min")]
+#[test_case(percentage(1, None, Some(_1_2)), _1_2; "capped_at_max")]
+#[test_case(percentage(50, Some(_100 + 1), None), _100; "capped_at_amount")]
+#[test_case(AdditionalSwapFeeMode::Flat(_3), _3; "flat")]
+fn additional_swap_fee_amount_is_correct(
+    fee: AdditionalSwapFeeModeOf<Runtime>,
+    expected: BalanceOf<Runtime>,
+) {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        assert_ok!(NeoSwaps::set_additional_swap_fee(RuntimeOrigin::signed(market_admin()), fee));
+        assert_eq!(NeoSwaps::additional_swap_fee_amount(market_id, None, _100), Ok(expected));
+    });
+}
+
+#[test]
+fn buy_charges_percentage_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        let fee = percentage(2, None, None);
+        assert_ok!(NeoSwaps::set_additional_swap_fee(RuntimeOrigin::signed(market_admin()), fee));
+        let fee_account_balance = AssetManager::free_balance(BASE_ASSET, &fee_account());
+        let amount_in = _5;
+        buy_first_outcome(charlie(), market_id, amount_in);
+        let expected_fee = amount_in / 50;
+        assert_balance!(fee_account(), BASE_ASSET, fee_account_balance + expected_fee);
+        let external_fee_amount = System::events()
+            .into_iter()
+            .find_map(|record| match record.event {
+                RuntimeEvent::NeoSwaps(Event::BuyExecuted { external_fee_amount, .. }) =>
+                    Some(external_fee_amount),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(external_fee_amount, expected_fee);
+    });
+}
+
+#[test]
+fn schedule_overrides_default_fee_of_base_asset() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        let fee = percentage(3, None, Some(_1));
+        assert_ok!(NeoSwaps::set_additional_swap_fee_schedule(
+            RuntimeOrigin::signed(market_admin()),
+            BASE_ASSET,
+            Some(fee),
+        ));
+        System::assert_last_event(
+            Event::AdditionalSwapFeeScheduleSet { asset: BASE_ASSET, fee: Some(fee) }.into(),
+        );
+        assert_eq!(NeoSwaps::additional_swap_fee_of(BASE_ASSET), Ok(fee));
+        assert_eq!(NeoSwaps::additional_swap_fee_amount(market_id, None, _10), Ok(3 * _1_10));
+        // Other base assets still use the default.
+        assert_eq!(NeoSwaps::additional_swap_fee_of(Asset::Tru), NeoSwaps::additional_swap_fee());
+
+        assert_ok!(NeoSwaps::set_additional_swap_fee_schedule(
+            RuntimeOrigin::signed(market_admin()),
+            BASE_ASSET,
+            None,
+        ));
+        assert!(!AdditionalSwapFeeSchedules::<Runtime>::contains_key(BASE_ASSET));
+        assert_eq!(
+            NeoSwaps::additional_swap_fee_amount(market_id, None, _10),
+            Ok(additional_swap_fee())
+        );
+    });
+}
+
+#[test]
+fn volume_tiers_discount_fee_of_active_traders() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        let fee = percentage(1, None, None);
+        assert_ok!(NeoSwaps::set_additional_swap_fee(RuntimeOrigin::signed(market_admin()), fee));
+        let tiers = vec![
+            VolumeTier { min_volume: _2, discount: Perbill::from_percent(25) },
+            VolumeTier { min_volume: _4, discount: Perbill::from_percent(50) },
+        ];
+        assert_ok!(NeoSwaps::set_additional_swap_fee_tiers(
+            RuntimeOrigin::signed(market_admin()),
+            tiers.clone(),
+        ));
+        System::assert_last_event(Event::AdditionalSwapFeeTiersSet { tiers }.into());
+
+        let fee_without_discount = _1_100;
+        let amount_of =
+            |who| NeoSwaps::additional_swap_fee_amount(market_id, Some(&who), _1).unwrap();
+        assert_eq!(amount_of(charlie()), fee_without_discount);
+
+        buy_first_outcome(charlie(), market_id, _2);
+        assert_eq!(TradingVolumes::<Runtime>::get(charlie()), _2);
+        assert_eq!(amount_of(charlie()), fee_without_discount * 3 / 4);
+        // Only the trader's own volume counts.
+        assert_eq!(amount_of(bob()), fee_without_discount);
+
+        buy_first_outcome(charlie(), market_id, _2);
+        assert_eq!(TradingVolumes::<Runtime>::get(charlie()), _4);
+        assert_eq!(amount_of(charlie()), fee_without_discount / 2);
+        // No discount is applied without a trader.
+        assert_eq!(
+            NeoSwaps::additional_swap_fee_amount(market_id, None, _1),
+            Ok(fee_without_discount)
+        );
+    });
+}
+
+#[test]
+fn quote_buy_applies_volume_tier_of_trader() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        let fee = percentage(1, None, None);
+        assert_ok!(NeoSwaps::set_additional_swap_fee(RuntimeOrigin::signed(market_admin()), fee));
+        let tiers = vec![VolumeTier { min_volume: _2, discount: Perbill::from_percent(50) }];
+        assert_ok!(NeoSwaps::set_additional_swap_fee_tiers(
+            RuntimeOrigin::signed(market_admin()),
+            tiers,
+        ));
+        buy_first_outcome(charlie(), market_id, _2);
+
+        let asset_out = Asset::CategoricalOutcome(market_id, 0);
+        let amount_in = _1;
+        let quote = NeoSwaps::quote_buy(&charlie(), market_id, asset_out, amount_in).unwrap();
+        assert_eq!(quote.external_fee_amount, _1_100 / 2);
+        buy_first_outcome(charlie(), market_id, amount_in);
+        System::assert_last_event(
+            Event::BuyExecuted {
+                who: charlie(),
+                market_id,
+                parent_collection_id: None,
+                asset_out,
+                amount_in,
+                amount_out: quote.amount_out,
+                swap_fee: CENT_BASE,
+                swap_fee_amount: quote.swap_fee_amount,
+                external_fee_amount: quote.external_fee_amount,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test_case(AdditionalSwapFeeMode::Flat(_1_10); "flat")]
+#[test_case(percentage(1, Some(_1_10), None); "raised_to_min")]
+#[test_case(percentage(10, None, Some(_1_100)); "capped_at_max")]
+fn calculate_buy_amount_until_includes_fees_not_proportional_to_amount(
+    fee: AdditionalSwapFeeModeOf<Runtime>,
+) {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        assert_ok!(NeoSwaps::set_additional_swap_fee(RuntimeOrigin::signed(market_admin()), fee));
+        let asset_out = Asset::CategoricalOutcome(market_id, 0);
+        let until = _3_4;
+        let amount_in = <NeoSwaps as HybridRouterAmmApi>::calculate_buy_amount_until(
+            &charlie(),
+            market_id,
+            asset_out,
+            until,
+        )
+        .unwrap();
+        buy_first_outcome(charlie(), market_id, amount_in);
+        let pool = Pools::<Runtime>::get(market_id).unwrap();
+        assert_approx!(pool.calculate_spot_price(asset_out).unwrap(), until, 100_000);
+    });
+}
+
+#[test]
+fn set_additional_swap_fee_fails_on_min_above_max() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            NeoSwaps::set_additional_swap_fee(
+                RuntimeOrigin::signed(market_admin()),
+                percentage(1, Some(_2), Some(_1)),
+            ),
+            Error::<Runtime>::InvalidAdditionalSwapFee
+        );
+        assert_noop!(
+            NeoSwaps::set_additional_swap_fee_schedule(
+                RuntimeOrigin::signed(market_admin()),
+                BASE_ASSET,
+                Some(percentage(1, Some(_2), Some(_1))),
+            ),
+            Error::<Runtime>::InvalidAdditionalSwapFee
+        );
+    });
+}
+
+#[test_case(vec![(_2, 10), (_1, 20)], Error::<Runtime>::VolumeTiersNotSorted; "unsorted")]
+#[test_case(vec![(_1, 10), (_1, 20)], Error::<Runtime>::VolumeTiersNotSorted; "duplicate")]
+#[test_case(
+    (1..=<Runtime as Config>::MaxVolumeTiers::get() as u128 + 1).map(|i| (i * _1, 10)).collect(),
+    Error::<Runtime>::TooManyVolumeTiers;
+    "too_many"
+)]
+fn set_additional_swap_fee_tiers_fails_on_invalid_tiers(
+    tiers: Vec<(BalanceOf<Runtime>, u32)>,
+    error: Error<Runtime>,
+) {
+    ExtBuilder::default().build().execute_with(|| {
+        let tiers = tiers
+            .into_iter()
+            .map(|(min_volume, percent)| VolumeTier {
+                min_volume,
+                discount: Perbill::from_percent(percent),
+            })
+            .collect();
+        assert_noop!(
+            NeoSwaps::set_additional_swap_fee_tiers(RuntimeOrigin::signed(market_admin()), tiers),
+            error
+        );
+    });
+}
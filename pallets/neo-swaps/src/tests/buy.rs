@@ -37,11 +37,10 @@ fn buy_works() {
         let pool = Pools::<Runtime>::get(market_id).unwrap();
         let amount_in_minus_fees = _10;
         let pct_fee = _1 - swap_fee;
-        let total_in = amount_in_minus_fees + NeoSwaps::additional_swap_fee().unwrap();
+        let total_in = amount_in_minus_fees + additional_swap_fee();
         let amount_in = total_in.bdiv(pct_fee).unwrap(); // This is exactly _10 after deducting fees.
-        let expected_swap_fee_amount =
-            amount_in - amount_in_minus_fees - NeoSwaps::additional_swap_fee().unwrap();
-        let expected_external_fee_amount = NeoSwaps::additional_swap_fee().unwrap();
+        let expected_swap_fee_amount = amount_in - amount_in_minus_fees - additional_swap_fee();
+        let expected_external_fee_amount = additional_swap_fee();
         let pool_outcomes_before: Vec<_> =
             pool.assets().iter().map(|a| pool.reserve_of(a).unwrap()).collect();
         let liquidity_parameter_before = pool.liquidity_parameter;
@@ -86,9 +85,11 @@ fn buy_works() {
             Event::BuyExecuted {
                 who: bob(),
                 market_id,
+                parent_collection_id: None,
                 asset_out,
                 amount_in,
                 amount_out: expected_amount_out,
+                swap_fee,
                 swap_fee_amount: expected_swap_fee_amount,
                 external_fee_amount: expected_external_fee_amount,
             }
@@ -97,6 +98,57 @@ fn buy_works() {
     });
 }
 
+#[test]
+fn buy_pays_creator_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        let creator_fee = Perbill::from_percent(1);
+        assert_ok!(MarketCommons::mutate_market(&market_id, |market| {
+            market.creator_fee = creator_fee;
+            Ok(())
+        }));
+        let creator_balance = AssetManager::free_balance(BASE_ASSET, &alice());
+        let amount_in = _10;
+        let expected_creator_fee_amount = creator_fee.mul_floor(amount_in);
+        assert_ok!(AssetManager::deposit(BASE_ASSET, &bob(), amount_in));
+        assert_ok!(NeoSwaps::buy(
+            RuntimeOrigin::signed(bob()),
+            market_id,
+            2,
+            Asset::CategoricalOutcome(market_id, 0),
+            amount_in,
+            0,
+        ));
+        assert_balance!(alice(), BASE_ASSET, creator_balance + expected_creator_fee_amount);
+        System::assert_has_event(
+            pallet_prediction_markets::Event::<Runtime>::CreatorFeePaid {
+                market_id,
+                creator: alice(),
+                trader: bob(),
+                asset: BASE_ASSET,
+                amount: expected_creator_fee_amount,
+            }
+            .into(),
+        );
+        let external_fee_amount = System::events()
+            .into_iter()
+            .find_map(|record| match record.event {
+                RuntimeEvent::NeoSwaps(Event::BuyExecuted { external_fee_amount, .. }) =>
+                    Some(external_fee_amount),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(external_fee_amount, additional_swap_fee() + expected_creator_fee_amount);
+    });
+}
+
 #[test]
 fn buy_fails_on_incorrect_asset_count() {
     ExtBuilder::default().build().execute_with(|| {
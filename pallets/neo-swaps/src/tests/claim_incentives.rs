@@ -0,0 +1,179 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Deploys a pool with `_10` pool shares owned by alice and lets alice fund an incentive program
+/// which streams `_10` units of the base asset from block 1 to block 11, i.e. `_1` per block.
+fn create_market_and_deploy_pool_with_incentive_program() -> MarketId {
+    let market_id = create_market_and_deploy_pool(
+        alice(),
+        BASE_ASSET,
+        MarketType::Categorical(2),
+        _10,
+        vec![_1_2, _1_2],
+        CENT_BASE,
+    );
+    let total_cost = _10 + AssetManager::minimum_balance(BASE_ASSET);
+    assert_ok!(AssetManager::deposit(BASE_ASSET, &alice(), total_cost));
+    assert_ok!(NeoSwaps::create_incentive_program(
+        RuntimeOrigin::signed(alice()),
+        market_id,
+        BASE_ASSET,
+        _10,
+        1,
+        11,
+    ));
+    market_id
+}
+
+fn join(market_id: MarketId, who: AccountIdOf<Runtime>, pool_shares_amount: BalanceOf<Runtime>) {
+    deposit_complete_set(market_id, who, pool_shares_amount + CENT_BASE);
+    assert_ok!(NeoSwaps::join(
+        RuntimeOrigin::signed(who),
+        market_id,
+        pool_shares_amount,
+        vec![u128::MAX, u128::MAX],
+    ));
+}
+
+fn claim(market_id: MarketId, who: AccountIdOf<Runtime>) -> BalanceOf<Runtime> {
+    let old_balance = AssetManager::free_balance(BASE_ASSET, &who);
+    assert_ok!(NeoSwaps::claim_incentives(RuntimeOrigin::signed(who), market_id));
+    AssetManager::free_balance(BASE_ASSET, &who) - old_balance
+}
+
+#[test]
+fn claim_incentives_pays_rewards_in_proportion_to_pool_shares() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_incentive_program();
+        join(market_id, bob(), _10);
+
+        System::set_block_number(6);
+        assert_eq!(claim(market_id, alice()), _5 / 2);
+        System::assert_last_event(
+            Event::IncentivesClaimed {
+                who: alice(),
+                market_id,
+                program_id: 0,
+                asset: BASE_ASSET,
+                amount: _5 / 2,
+            }
+            .into(),
+        );
+
+        // Rewards stop at the end of the program.
+        System::set_block_number(20);
+        assert_eq!(claim(market_id, alice()), _5 / 2);
+        assert_eq!(claim(market_id, bob()), _5);
+        assert_eq!(claim(market_id, bob()), 0);
+        assert_eq!(IncentivePrograms::<Runtime>::get(market_id, 0).unwrap().remaining, 0);
+    });
+}
+
+#[test]
+fn rewards_are_kept_after_exit() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_incentive_program();
+        join(market_id, bob(), _10);
+
+        System::set_block_number(6);
+        assert_ok!(NeoSwaps::exit(RuntimeOrigin::signed(bob()), market_id, _10, vec![0, 0]));
+
+        System::set_block_number(11);
+        assert_eq!(claim(market_id, bob()), _5 / 2);
+        assert_eq!(claim(market_id, alice()), _5 / 2 + _5);
+    });
+}
+
+#[test]
+fn rewards_of_new_liquidity_providers_start_when_they_join() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_incentive_program();
+
+        System::set_block_number(6);
+        join(market_id, bob(), _10);
+
+        System::set_block_number(11);
+        assert_eq!(claim(market_id, bob()), _5 / 2);
+        assert_eq!(claim(market_id, alice()), _5 + _5 / 2);
+    });
+}
+
+#[test]
+fn destroying_the_pool_refunds_the_remaining_budget() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_incentive_program();
+
+        System::set_block_number(6);
+        let alice_balance = AssetManager::free_balance(BASE_ASSET, &alice());
+        assert_ok!(NeoSwaps::exit(RuntimeOrigin::signed(alice()), market_id, _10, vec![0, 0]));
+        assert!(!Pools::<Runtime>::contains_key(market_id));
+        System::assert_has_event(
+            Event::IncentiveProgramClosed { market_id, program_id: 0, refunded: _5 }.into(),
+        );
+        assert_balance!(alice(), BASE_ASSET, alice_balance + _5);
+
+        // The rewards streamed before the pool was destroyed can still be claimed.
+        System::set_block_number(11);
+        assert_eq!(claim(market_id, alice()), _5);
+    });
+}
+
+#[test]
+fn claims_and_refund_add_up_to_the_funded_amount() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_incentive_program();
+        // `_10` isn't divisible by the `_13` pool shares, so each update leaves a remainder.
+        join(market_id, bob(), _3);
+
+        System::set_block_number(4);
+        let mut claimed = claim(market_id, bob());
+
+        System::set_block_number(20);
+        assert_ok!(NeoSwaps::exit(RuntimeOrigin::signed(bob()), market_id, _3, vec![0, 0]));
+        let alice_balance = AssetManager::free_balance(BASE_ASSET, &alice());
+        assert_ok!(NeoSwaps::exit(RuntimeOrigin::signed(alice()), market_id, _10, vec![0, 0]));
+        let refunded = AssetManager::free_balance(BASE_ASSET, &alice()) - alice_balance;
+        assert_eq!(refunded, 4);
+        System::assert_has_event(
+            Event::IncentiveProgramClosed { market_id, program_id: 0, refunded }.into(),
+        );
+
+        claimed += claim(market_id, alice());
+        claimed += claim(market_id, bob());
+        assert_eq!(claimed + refunded, _10);
+    });
+}
+
+#[test]
+fn claim_incentives_fails_without_incentive_programs() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        assert_noop!(
+            NeoSwaps::claim_incentives(RuntimeOrigin::signed(alice()), market_id),
+            Error::<Runtime>::IncentiveProgramNotFound
+        );
+    });
+}
@@ -0,0 +1,268 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use test_case::test_case;
+
+fn create_market_and_deploy_default_pool() -> MarketId {
+    create_market_and_deploy_pool(
+        alice(),
+        BASE_ASSET,
+        MarketType::Categorical(2),
+        _10,
+        vec![_1_2, _1_2],
+        CENT_BASE,
+    )
+}
+
+fn fund(who: AccountIdOf<Runtime>, amount: BalanceOf<Runtime>) {
+    let total_cost = amount + AssetManager::minimum_balance(BASE_ASSET);
+    assert_ok!(AssetManager::deposit(BASE_ASSET, &who, total_cost));
+}
+
+#[test]
+fn create_incentive_program_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        fund(alice(), _10);
+        let alice_balance = AssetManager::free_balance(BASE_ASSET, &alice());
+        assert_ok!(NeoSwaps::create_incentive_program(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            BASE_ASSET,
+            _10,
+            1,
+            5,
+        ));
+        let ed = AssetManager::minimum_balance(BASE_ASSET);
+        assert_balance!(alice(), BASE_ASSET, alice_balance - _10 - ed);
+        assert_balance!(NeoSwaps::incentive_account_id(&market_id), BASE_ASSET, _10 + ed);
+        let program = IncentivePrograms::<Runtime>::get(market_id, 0).unwrap();
+        assert_eq!(program.funder, alice());
+        assert_eq!(program.reward_per_block, _10 / 4);
+        assert_eq!(program.remaining, _10);
+        assert_eq!(program.last_update, 1);
+        assert_eq!(NextIncentiveProgramId::<Runtime>::get(market_id), 1);
+        System::assert_last_event(
+            Event::IncentiveProgramCreated {
+                funder: alice(),
+                market_id,
+                program_id: 0,
+                asset: BASE_ASSET,
+                amount: _10,
+                start: 1,
+                end: 5,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn create_incentive_program_works_for_pallet_admin() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        fund(market_admin(), _1);
+        assert_ok!(NeoSwaps::create_incentive_program(
+            RuntimeOrigin::signed(market_admin()),
+            market_id,
+            BASE_ASSET,
+            _1,
+            2,
+            3,
+        ));
+        assert_eq!(IncentivePrograms::<Runtime>::get(market_id, 0).unwrap().funder, market_admin());
+    });
+}
+
+#[test]
+fn create_incentive_program_fails_on_unauthorized_caller() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        fund(bob(), _1);
+        assert_noop!(
+            NeoSwaps::create_incentive_program(
+                RuntimeOrigin::signed(bob()),
+                market_id,
+                BASE_ASSET,
+                _1,
+                1,
+                5,
+            ),
+            Error::<Runtime>::NotAllowed
+        );
+    });
+}
+
+#[test_case(1, 1; "empty")]
+#[test_case(3, 2; "reversed")]
+#[test_case(0, 5; "starts_in_the_past")]
+fn create_incentive_program_fails_on_invalid_period(start: u32, end: u32) {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        fund(alice(), _1);
+        assert_noop!(
+            NeoSwaps::create_incentive_program(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                BASE_ASSET,
+                _1,
+                start,
+                end,
+            ),
+            Error::<Runtime>::InvalidIncentivePeriod
+        );
+    });
+}
+
+#[test]
+fn create_incentive_program_fails_on_too_many_programs() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        let max_programs = <Runtime as Config>::MaxIncentivePrograms::get();
+        for _ in 0..max_programs {
+            fund(alice(), _1);
+            assert_ok!(NeoSwaps::create_incentive_program(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                BASE_ASSET,
+                _1,
+                1,
+                5,
+            ));
+        }
+        fund(alice(), _1);
+        assert_noop!(
+            NeoSwaps::create_incentive_program(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                BASE_ASSET,
+                _1,
+                1,
+                5,
+            ),
+            Error::<Runtime>::TooManyIncentivePrograms
+        );
+    });
+}
+
+fn create_max_incentive_programs(market_id: MarketId) {
+    for _ in 0..<Runtime as Config>::MaxIncentivePrograms::get() {
+        fund(alice(), _1);
+        assert_ok!(NeoSwaps::create_incentive_program(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            BASE_ASSET,
+            _1,
+            1,
+            5,
+        ));
+    }
+}
+
+#[test]
+fn create_incentive_program_removes_finished_program() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        create_max_incentive_programs(market_id);
+        System::set_block_number(6);
+        assert_ok!(NeoSwaps::claim_incentives(RuntimeOrigin::signed(alice()), market_id));
+        fund(alice(), _1);
+        let alice_balance = AssetManager::free_balance(BASE_ASSET, &alice());
+        assert_ok!(NeoSwaps::create_incentive_program(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            BASE_ASSET,
+            _1,
+            6,
+            10,
+        ));
+        let max_programs = <Runtime as Config>::MaxIncentivePrograms::get();
+        assert!(!IncentivePrograms::<Runtime>::contains_key(market_id, 0));
+        assert!(IncentivePrograms::<Runtime>::contains_key(market_id, max_programs));
+        assert_eq!(NextIncentiveProgramId::<Runtime>::get(market_id), max_programs + 1);
+        // All rewards were claimed, so only the existential deposit is refunded.
+        let ed = AssetManager::minimum_balance(BASE_ASSET);
+        assert_balance!(alice(), BASE_ASSET, alice_balance - _1);
+        System::assert_has_event(
+            Event::IncentiveProgramRemoved { market_id, program_id: 0, refunded: ed }.into(),
+        );
+    });
+}
+
+#[test]
+fn create_incentive_program_fails_on_too_many_programs_with_unclaimed_rewards() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        create_max_incentive_programs(market_id);
+        System::set_block_number(6);
+        fund(alice(), _1);
+        assert_noop!(
+            NeoSwaps::create_incentive_program(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                BASE_ASSET,
+                _1,
+                6,
+                10,
+            ),
+            Error::<Runtime>::TooManyIncentivePrograms
+        );
+    });
+}
+
+#[test]
+fn create_incentive_program_fails_on_missing_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            ScoringRule::AmmCdaHybrid,
+        );
+        fund(alice(), _1);
+        assert_noop!(
+            NeoSwaps::create_incentive_program(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                BASE_ASSET,
+                _1,
+                1,
+                5,
+            ),
+            Error::<Runtime>::PoolNotFound
+        );
+    });
+}
+
+#[test]
+fn create_incentive_program_fails_on_zero_amount() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        assert_noop!(
+            NeoSwaps::create_incentive_program(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                BASE_ASSET,
+                0,
+                1,
+                5,
+            ),
+            Error::<Runtime>::ZeroAmount
+        );
+    });
+}
@@ -0,0 +1,340 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use prediction_market_primitives::{traits::HybridRouterAmmApi, types::CombinatorialId};
+
+fn position_asset(
+    parent_collection_id: Option<CombinatorialId>,
+    market_id: MarketId,
+    index_set: u128,
+) -> Asset<MarketId> {
+    <Runtime as Config>::CompleteSetOperations::position_asset(
+        parent_collection_id,
+        market_id,
+        index_set,
+    )
+    .unwrap()
+}
+
+/// Creates a market, splits `amount` units of collateral into positions of `alice` and returns
+/// the collection of the position conditioned on the market's first outcome.
+fn create_parent_collection(amount: BalanceOf<Runtime>) -> CombinatorialId {
+    let market_id =
+        create_market(alice(), BASE_ASSET, MarketType::Categorical(2), ScoringRule::AmmCdaHybrid);
+    assert_ok!(PredictionMarkets::split_position(
+        RuntimeOrigin::signed(alice()),
+        None,
+        market_id,
+        vec![0b01, 0b10],
+        amount,
+    ));
+    match position_asset(None, market_id, 0b01) {
+        Asset::CombinatorialOutcome(collection_id) => collection_id,
+        _ => unreachable!(),
+    }
+}
+
+fn create_market_and_deploy_combinatorial_pool(
+    parent_collection_id: CombinatorialId,
+    amount: BalanceOf<Runtime>,
+    spot_prices: Vec<BalanceOf<Runtime>>,
+    swap_fee: BalanceOf<Runtime>,
+) -> MarketId {
+    let market_id =
+        create_market(alice(), BASE_ASSET, MarketType::Categorical(2), ScoringRule::AmmCdaHybrid);
+    assert_ok!(PredictionMarkets::split_position(
+        RuntimeOrigin::signed(alice()),
+        Some(parent_collection_id),
+        market_id,
+        vec![0b01, 0b10],
+        amount,
+    ));
+    assert_ok!(NeoSwaps::deploy_combinatorial_pool(
+        RuntimeOrigin::signed(alice()),
+        market_id,
+        parent_collection_id,
+        amount,
+        spot_prices,
+        swap_fee,
+    ));
+    market_id
+}
+
+#[test]
+fn deploy_combinatorial_pool_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let parent_collection_id = create_parent_collection(_100);
+        let parent_asset = Asset::CombinatorialOutcome(parent_collection_id);
+        let amount = _10;
+        let spot_prices = vec![_1_4, _3_4];
+        let market_id = create_market_and_deploy_combinatorial_pool(
+            parent_collection_id,
+            amount,
+            spot_prices.clone(),
+            CENT_BASE,
+        );
+
+        let pool = ConditionalPools::<Runtime>::get(market_id, parent_collection_id).unwrap();
+        let assets = [
+            position_asset(Some(parent_collection_id), market_id, 0b01),
+            position_asset(Some(parent_collection_id), market_id, 0b10),
+        ];
+        let buffer = AssetManager::minimum_balance(parent_asset);
+        assert_eq!(pool.collateral, parent_asset);
+        // Spot prices are assigned by outcome index, not by the order of the pool's assets.
+        assert_approx!(pool.calculate_spot_price(assets[0]).unwrap(), spot_prices[0], 1);
+        assert_approx!(pool.calculate_spot_price(assets[1]).unwrap(), spot_prices[1], 1);
+        for asset in assets.iter() {
+            assert!(pool.contains(asset));
+            assert_eq!(
+                pool.reserve_of(asset).unwrap(),
+                AssetManager::free_balance(*asset, &pool.account_id)
+            );
+        }
+        assert_balance!(pool.account_id, parent_asset, buffer);
+        assert_balance!(alice(), parent_asset, _100 - amount - buffer);
+        assert_eq!(
+            pool.account_id,
+            NeoSwaps::conditional_pool_account_id(&market_id, &parent_collection_id)
+        );
+        assert!(!Pools::<Runtime>::contains_key(market_id));
+        assert!(!<NeoSwaps as HybridRouterAmmApi>::pool_exists(market_id));
+    });
+}
+
+#[test]
+fn buy_and_sell_split_and_merge_parent_position() {
+    ExtBuilder::default().build().execute_with(|| {
+        let parent_collection_id = create_parent_collection(_100);
+        let parent_asset = Asset::CombinatorialOutcome(parent_collection_id);
+        let swap_fee = CENT_BASE;
+        let market_id = create_market_and_deploy_combinatorial_pool(
+            parent_collection_id,
+            _10,
+            vec![_1_2, _1_2],
+            swap_fee,
+        );
+        let asset_out = position_asset(Some(parent_collection_id), market_id, 0b01);
+
+        let amount_in_minus_fees = _10;
+        let total_in = amount_in_minus_fees + additional_swap_fee();
+        let amount_in = total_in.bdiv(_1 - swap_fee).unwrap();
+        assert_ok!(AssetManager::transfer(parent_asset, &alice(), &bob(), amount_in));
+        assert_ok!(NeoSwaps::buy_conditional(
+            RuntimeOrigin::signed(bob()),
+            market_id,
+            parent_collection_id,
+            2,
+            asset_out,
+            amount_in,
+            0,
+        ));
+        let expected_amount_out = 58496250072 + amount_in_minus_fees;
+        assert_balance!(bob(), parent_asset, 0);
+        assert_balance!(bob(), asset_out, expected_amount_out);
+        assert_balance!(fee_account(), parent_asset, additional_swap_fee());
+
+        assert_ok!(NeoSwaps::sell_conditional(
+            RuntimeOrigin::signed(bob()),
+            market_id,
+            parent_collection_id,
+            2,
+            asset_out,
+            expected_amount_out,
+            0,
+        ));
+        assert_balance!(bob(), asset_out, 0);
+        assert!(AssetManager::free_balance(parent_asset, &bob()) > 0);
+        // Conditional pools pay out the parent position, not the underlying collateral.
+        assert_balance!(bob(), BASE_ASSET, 0);
+    });
+}
+
+#[test]
+fn regular_calls_do_not_touch_conditional_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        let parent_collection_id = create_parent_collection(_100);
+        let market_id = create_market_and_deploy_combinatorial_pool(
+            parent_collection_id,
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        let asset_out = position_asset(Some(parent_collection_id), market_id, 0b01);
+        assert_noop!(
+            NeoSwaps::buy(RuntimeOrigin::signed(alice()), market_id, 2, asset_out, _1, 0),
+            Error::<Runtime>::PoolNotFound
+        );
+    });
+}
+
+#[test]
+fn exit_conditional_destroys_conditional_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        let parent_collection_id = create_parent_collection(_100);
+        let market_id = create_market_and_deploy_combinatorial_pool(
+            parent_collection_id,
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        assert_ok!(NeoSwaps::exit_conditional(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            parent_collection_id,
+            _10,
+            vec![0, 0]
+        ));
+        assert!(!ConditionalPools::<Runtime>::contains_key(market_id, parent_collection_id));
+        System::assert_last_event(
+            Event::PoolDestroyed {
+                who: alice(),
+                market_id,
+                parent_collection_id: Some(parent_collection_id),
+                // The exit fee is retained by the pool.
+                amounts_out: vec![99_900_000_000, 99_900_000_000],
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn deploy_combinatorial_pool_fails_on_unknown_parent_collection() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_noop!(
+            NeoSwaps::deploy_combinatorial_pool(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                [0; 32],
+                _10,
+                vec![_1_2, _1_2],
+                CENT_BASE,
+            ),
+            pallet_prediction_markets::Error::<Runtime>::CombinatorialCollectionNotFound
+        );
+    });
+}
+
+#[test]
+fn deploy_combinatorial_pool_works_next_to_regular_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        let parent_collection_id = create_parent_collection(_100);
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(alice()),
+            Some(parent_collection_id),
+            market_id,
+            vec![0b01, 0b10],
+            _10,
+        ));
+        assert_ok!(NeoSwaps::deploy_combinatorial_pool(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            parent_collection_id,
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        ));
+        let regular_pool = Pools::<Runtime>::get(market_id).unwrap();
+        let conditional_pool =
+            ConditionalPools::<Runtime>::get(market_id, parent_collection_id).unwrap();
+        assert_eq!(regular_pool.collateral, BASE_ASSET);
+        assert_eq!(conditional_pool.collateral, Asset::CombinatorialOutcome(parent_collection_id));
+        assert_ne!(regular_pool.account_id, conditional_pool.account_id);
+    });
+}
+
+#[test]
+fn deploy_combinatorial_pool_works_for_multiple_parent_collections() {
+    ExtBuilder::default().build().execute_with(|| {
+        let parent_collection_ids =
+            [create_parent_collection(_100), create_parent_collection(_100)];
+        let market_id = create_market(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            ScoringRule::AmmCdaHybrid,
+        );
+        for parent_collection_id in parent_collection_ids {
+            assert_ok!(PredictionMarkets::split_position(
+                RuntimeOrigin::signed(alice()),
+                Some(parent_collection_id),
+                market_id,
+                vec![0b01, 0b10],
+                _10,
+            ));
+            assert_ok!(NeoSwaps::deploy_combinatorial_pool(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                parent_collection_id,
+                _10,
+                vec![_1_2, _1_2],
+                CENT_BASE,
+            ));
+        }
+        let pools = parent_collection_ids
+            .map(|id| ConditionalPools::<Runtime>::get(market_id, id).unwrap());
+        assert_ne!(pools[0].account_id, pools[1].account_id);
+        assert_ne!(pools[0].collateral, pools[1].collateral);
+    });
+}
+
+#[test]
+fn deploy_combinatorial_pool_fails_on_duplicate_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        let parent_collection_id = create_parent_collection(_100);
+        let market_id = create_market_and_deploy_combinatorial_pool(
+            parent_collection_id,
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(alice()),
+            Some(parent_collection_id),
+            market_id,
+            vec![0b01, 0b10],
+            _10,
+        ));
+        assert_noop!(
+            NeoSwaps::deploy_combinatorial_pool(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                parent_collection_id,
+                _10,
+                vec![_1_2, _1_2],
+                CENT_BASE,
+            ),
+            Error::<Runtime>::DuplicatePool
+        );
+    });
+}
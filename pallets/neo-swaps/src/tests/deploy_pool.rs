@@ -73,6 +73,7 @@ fn deploy_pool_works_with_binary_markets() {
             Event::PoolDeployed {
                 who: alice(),
                 market_id,
+                parent_collection_id: None,
                 account_id: pool.account_id,
                 reserves,
                 collateral: pool.collateral,
@@ -156,6 +157,7 @@ fn deploy_pool_works_with_scalar_marktes() {
             Event::PoolDeployed {
                 who: alice(),
                 market_id,
+                parent_collection_id: None,
                 account_id: pool.account_id,
                 reserves,
                 collateral: pool.collateral,
@@ -0,0 +1,236 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::types::DynamicFeeParams;
+use test_case::test_case;
+
+const WINDOW: u32 = 10;
+
+fn default_params() -> DynamicFeeParamsOf<Runtime> {
+    DynamicFeeParams {
+        min_fee: CENT_BASE,
+        max_fee: 5 * CENT_BASE,
+        sensitivity: _1_10,
+        window: WINDOW,
+    }
+}
+
+fn create_market_and_deploy_pool_with_dynamic_fee(params: DynamicFeeParamsOf<Runtime>) -> MarketId {
+    let market_id =
+        create_market(alice(), BASE_ASSET, MarketType::Categorical(2), ScoringRule::AmmCdaHybrid);
+    deposit_complete_set(market_id, alice(), _10);
+    assert_ok!(NeoSwaps::deploy_pool_with_dynamic_fee(
+        RuntimeOrigin::signed(alice()),
+        market_id,
+        _10,
+        vec![_1_2, _1_2],
+        params,
+    ));
+    market_id
+}
+
+/// Returns the swap fee reported by the last trade.
+fn last_swap_fee() -> BalanceOf<Runtime> {
+    System::events()
+        .into_iter()
+        .rev()
+        .find_map(|record| match record.event {
+            RuntimeEvent::NeoSwaps(Event::BuyExecuted { swap_fee, .. }) |
+            RuntimeEvent::NeoSwaps(Event::SellExecuted { swap_fee, .. }) => Some(swap_fee),
+            _ => None,
+        })
+        .unwrap()
+}
+
+fn sell_first_outcome(market_id: MarketId, amount_in: BalanceOf<Runtime>) {
+    assert_ok!(NeoSwaps::sell(
+        RuntimeOrigin::signed(charlie()),
+        market_id,
+        2,
+        Asset::CategoricalOutcome(market_id, 0),
+        amount_in,
+        0
+    ));
+}
+
+#[test]
+fn deploy_pool_with_dynamic_fee_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let params = default_params();
+        let market_id = create_market_and_deploy_pool_with_dynamic_fee(params);
+        let pool = Pools::<Runtime>::get(market_id).unwrap();
+        assert_eq!(pool.swap_fee, params.min_fee);
+        assert_eq!(DynamicSwapFees::<Runtime>::get(market_id), Some(params));
+        let price_history = PriceHistories::<Runtime>::get(market_id).unwrap();
+        assert_eq!(price_history.window_start, System::block_number());
+        assert_eq!(price_history.volatility, 0);
+        for (&asset, &reference_price) in
+            pool.assets().iter().zip(price_history.reference_prices.iter())
+        {
+            assert_eq!(reference_price, pool.calculate_spot_price(asset).unwrap());
+        }
+    });
+}
+
+#[test]
+fn large_price_move_raises_swap_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let params = default_params();
+        let market_id = create_market_and_deploy_pool_with_dynamic_fee(params);
+        let amount_out = buy_first_outcome_for_ten_units(market_id);
+        // The first trade of the window is charged the minimum fee.
+        assert_eq!(last_swap_fee(), params.min_fee);
+
+        let pool = Pools::<Runtime>::get(market_id).unwrap();
+        let spot_price =
+            pool.calculate_spot_price(Asset::CategoricalOutcome(market_id, 0)).unwrap();
+        let volatility = PriceHistories::<Runtime>::get(market_id).unwrap().volatility;
+        assert_approx!(volatility, spot_price - _1_2, 10);
+
+        sell_first_outcome(market_id, amount_out);
+        let expected_swap_fee = params.min_fee + params.sensitivity.bmul(volatility).unwrap();
+        assert!(expected_swap_fee > params.min_fee);
+        assert_eq!(last_swap_fee(), expected_swap_fee);
+        assert_eq!(Pools::<Runtime>::get(market_id).unwrap().swap_fee, expected_swap_fee);
+    });
+}
+
+#[test]
+fn swap_fee_is_capped_at_max_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let params = DynamicFeeParams { sensitivity: _10, ..default_params() };
+        let market_id = create_market_and_deploy_pool_with_dynamic_fee(params);
+        let amount_out = buy_first_outcome_for_ten_units(market_id);
+        sell_first_outcome(market_id, amount_out);
+        assert_eq!(last_swap_fee(), params.max_fee);
+    });
+}
+
+#[test]
+fn swap_fee_decays_when_trading_is_quiet() {
+    ExtBuilder::default().build().execute_with(|| {
+        let params = default_params();
+        let market_id = create_market_and_deploy_pool_with_dynamic_fee(params);
+        let amount_out = buy_first_outcome_for_ten_units(market_id);
+        let price_history = PriceHistories::<Runtime>::get(market_id).unwrap();
+
+        // Two full windows pass without trades, so the volatility is divided by four.
+        System::set_block_number(price_history.window_start + 2 * WINDOW);
+        let pool = Pools::<Runtime>::get(market_id).unwrap();
+        let spot_prices_before = pool
+            .assets()
+            .iter()
+            .map(|&asset| pool.calculate_spot_price(asset).unwrap())
+            .collect::<Vec<_>>();
+        sell_first_outcome(market_id, amount_out);
+        let decayed_volatility = price_history.volatility / 4;
+        assert_eq!(
+            last_swap_fee(),
+            params.min_fee + params.sensitivity.bmul(decayed_volatility).unwrap()
+        );
+
+        // A new window was started at the spot prices before the trade.
+        let price_history = PriceHistories::<Runtime>::get(market_id).unwrap();
+        assert_eq!(price_history.window_start, System::block_number());
+        assert_eq!(price_history.reference_prices.into_inner(), spot_prices_before);
+
+        // Without any further price movements, the fee returns to the minimum.
+        System::set_block_number(System::block_number() + 128 * WINDOW);
+        assert_ok!(AssetManager::deposit(BASE_ASSET, &charlie(), _1));
+        assert_ok!(NeoSwaps::buy(
+            RuntimeOrigin::signed(charlie()),
+            market_id,
+            2,
+            Asset::CategoricalOutcome(market_id, 1),
+            _1,
+            0
+        ));
+        assert_eq!(last_swap_fee(), params.min_fee);
+    });
+}
+
+#[test]
+fn quote_buy_uses_current_dynamic_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let params = default_params();
+        let market_id = create_market_and_deploy_pool_with_dynamic_fee(params);
+        let _ = buy_first_outcome_for_ten_units(market_id);
+        let volatility = PriceHistories::<Runtime>::get(market_id).unwrap().volatility;
+        let swap_fee = params.min_fee + params.sensitivity.bmul(volatility).unwrap();
+        let amount_in = _1;
+        let asset_out = Asset::CategoricalOutcome(market_id, 1);
+        let quote = NeoSwaps::quote_buy(&bob(), market_id, asset_out, amount_in).unwrap();
+        assert_eq!(quote.swap_fee_amount, swap_fee.bmul(amount_in).unwrap());
+    });
+}
+
+#[test]
+fn exit_removes_dynamic_fee_of_destroyed_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_dynamic_fee(default_params());
+        assert_ok!(NeoSwaps::exit(RuntimeOrigin::signed(alice()), market_id, _10, vec![0, 0]));
+        assert!(!Pools::<Runtime>::contains_key(market_id));
+        assert!(!DynamicSwapFees::<Runtime>::contains_key(market_id));
+        assert!(!PriceHistories::<Runtime>::contains_key(market_id));
+    });
+}
+
+#[test_case(
+    DynamicFeeParams { min_fee: 5 * CENT_BASE, max_fee: CENT_BASE, ..default_params() },
+    Error::<Runtime>::InvalidDynamicFeeParameters;
+    "min_fee_above_max_fee"
+)]
+#[test_case(
+    DynamicFeeParams { window: 0, ..default_params() },
+    Error::<Runtime>::InvalidDynamicFeeParameters;
+    "empty_window"
+)]
+#[test_case(
+    DynamicFeeParams { max_fee: <Runtime as Config>::MaxSwapFee::get() + 1, ..default_params() },
+    Error::<Runtime>::SwapFeeAboveMax;
+    "max_fee_above_max_swap_fee"
+)]
+#[test_case(
+    DynamicFeeParams { min_fee: MIN_SWAP_FEE - 1, ..default_params() },
+    Error::<Runtime>::SwapFeeBelowMin;
+    "min_fee_below_min_swap_fee"
+)]
+fn deploy_pool_with_dynamic_fee_fails_on_invalid_params(
+    params: DynamicFeeParamsOf<Runtime>,
+    error: Error<Runtime>,
+) {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            ScoringRule::AmmCdaHybrid,
+        );
+        deposit_complete_set(market_id, alice(), _10);
+        assert_noop!(
+            NeoSwaps::deploy_pool_with_dynamic_fee(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                _10,
+                vec![_1_2, _1_2],
+                params,
+            ),
+            error
+        );
+    });
+}
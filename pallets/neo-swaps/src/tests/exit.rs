@@ -94,6 +94,7 @@ fn exit_works(
             Event::ExitExecuted {
                 who: alice(),
                 market_id,
+                parent_collection_id: None,
                 pool_shares_amount,
                 amounts_out,
                 new_liquidity_parameter,
@@ -152,7 +153,13 @@ fn last_exit_destroys_pool(market_status: MarketStatus, amounts_out: Vec<Balance
         assert!(!Pools::<Runtime>::contains_key(market_id));
         assert_balances!(pool_account, outcomes, [0, 0]);
         System::assert_last_event(
-            Event::PoolDestroyed { who: alice(), market_id, amounts_out }.into(),
+            Event::PoolDestroyed {
+                who: alice(),
+                market_id,
+                parent_collection_id: None,
+                amounts_out,
+            }
+            .into(),
         );
     });
 }
@@ -374,3 +381,35 @@ fn exit_pool_fails_on_relative_liquidity_threshold_violated() {
         );
     });
 }
+
+#[test]
+fn liquidity_is_refunded_pro_rata_if_market_resolved_to_invalid() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        MarketCommons::mutate_market(&market_id, |market| {
+            market.status = MarketStatus::Resolved;
+            market.resolved_outcome = Some(OutcomeReport::Invalid);
+            Ok(())
+        })
+        .unwrap();
+        assert_ok!(NeoSwaps::exit(RuntimeOrigin::signed(alice()), market_id, _10, vec![0, 0]));
+        assert!(!Pools::<Runtime>::contains_key(market_id));
+
+        // Each outcome share is worth half a unit of the base asset, so the complete sets
+        // withdrawn from the pool are worth exactly the liquidity alice provided.
+        let alice_balance = AssetManager::free_balance(BASE_ASSET, &alice());
+        assert_ok!(PredictionMarkets::redeem_shares(RuntimeOrigin::signed(alice()), market_id));
+        assert_balance!(alice(), BASE_ASSET, alice_balance + _10);
+        let market = MarketCommons::market(&market_id).unwrap();
+        for asset in market.outcome_assets() {
+            assert_balance!(alice(), asset, 0);
+        }
+    });
+}
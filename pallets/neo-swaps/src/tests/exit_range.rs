@@ -0,0 +1,163 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::liquidity_tree::types::LiquidityTreeError;
+
+#[test]
+fn exit_range_works_for_active_position() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_range_position();
+        let pool = Pools::<Runtime>::get(market_id).unwrap();
+        let ratio = _1.bdiv_floor(_11).unwrap().bmul_floor(_1 - EXIT_FEE).unwrap();
+        let amounts_out: Vec<_> = pool
+            .assets()
+            .iter()
+            .map(|asset| ratio.bmul_floor(pool.reserve_of(asset).unwrap()).unwrap())
+            .collect();
+        let balances_before: Vec<_> = pool
+            .assets()
+            .iter()
+            .map(|&asset| AssetManager::free_balance(asset, &bob()))
+            .collect();
+        assert_ok!(NeoSwaps::exit_range(RuntimeOrigin::signed(bob()), market_id, vec![0, 0]));
+
+        let new_pool = Pools::<Runtime>::get(market_id).unwrap();
+        for (i, asset) in pool.assets().iter().enumerate() {
+            assert_balance!(bob(), *asset, balances_before[i] + amounts_out[i]);
+            assert_eq!(
+                new_pool.reserve_of(asset).unwrap(),
+                pool.reserve_of(asset).unwrap() - amounts_out[i]
+            );
+        }
+        assert!(!RangePositions::<Runtime>::contains_key(market_id, bob()));
+        assert!(new_pool.liquidity_shares_manager.shares_of(&bob()).is_err());
+        assert_eq!(new_pool.liquidity_shares_manager.total_shares().unwrap(), _10);
+        assert_eq!(
+            new_pool.liquidity_parameter,
+            pool.liquidity_parameter - ratio.bmul(pool.liquidity_parameter).unwrap()
+        );
+        System::assert_last_event(
+            Event::RangePositionClosed {
+                who: bob(),
+                market_id,
+                pool_shares_amount: _1,
+                amounts_out,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn exit_range_returns_parked_funds_of_inactive_position() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_range_position();
+        buy_first_outcome_for_ten_units(market_id);
+        assert_ok!(NeoSwaps::withdraw_fees(RuntimeOrigin::signed(bob()), market_id));
+        let pool = Pools::<Runtime>::get(market_id).unwrap();
+        let parked = RangePositions::<Runtime>::get(market_id, bob()).unwrap().parked.into_inner();
+        let balances_before: Vec<_> = pool
+            .assets()
+            .iter()
+            .map(|&asset| AssetManager::free_balance(asset, &bob()))
+            .collect();
+        assert_ok!(NeoSwaps::exit_range(RuntimeOrigin::signed(bob()), market_id, parked.clone()));
+
+        // The parked funds are returned without exit fee and the pool is left untouched.
+        let new_pool = Pools::<Runtime>::get(market_id).unwrap();
+        for (i, asset) in pool.assets().iter().enumerate() {
+            assert_balance!(bob(), *asset, balances_before[i] + parked[i]);
+        }
+        assert_eq!(new_pool.reserves, pool.reserves);
+        assert_eq!(new_pool.liquidity_parameter, pool.liquidity_parameter);
+        assert!(new_pool.liquidity_shares_manager.shares_of(&bob()).is_err());
+        assert!(!RangePositions::<Runtime>::contains_key(market_id, bob()));
+    });
+}
+
+#[test]
+fn last_exit_pays_out_parked_funds_of_range_positions() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_range_position();
+        buy_first_outcome_for_ten_units(market_id);
+        let pool = Pools::<Runtime>::get(market_id).unwrap();
+        let parked = RangePositions::<Runtime>::get(market_id, bob()).unwrap().parked.into_inner();
+        let outcome_balances_before: Vec<_> = pool
+            .assets()
+            .iter()
+            .map(|&asset| AssetManager::free_balance(asset, &bob()))
+            .collect();
+        let base_balance_before = AssetManager::free_balance(BASE_ASSET, &bob());
+        assert_ok!(NeoSwaps::withdraw_fees(RuntimeOrigin::signed(alice()), market_id));
+        assert_ok!(NeoSwaps::exit(RuntimeOrigin::signed(alice()), market_id, _10, vec![0, 0]));
+
+        assert!(!Pools::<Runtime>::contains_key(market_id));
+        assert!(!RangePositions::<Runtime>::contains_key(market_id, bob()));
+        for (i, asset) in pool.assets().iter().enumerate() {
+            assert_balance!(bob(), *asset, outcome_balances_before[i] + parked[i]);
+            assert_balance!(pool.account_id, *asset, 0);
+        }
+        // Bob's outstanding fees are paid out, too.
+        assert!(AssetManager::free_balance(BASE_ASSET, &bob()) > base_balance_before);
+    });
+}
+
+#[test]
+fn exit_range_fails_on_range_position_not_found() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_range_position();
+        assert_noop!(
+            NeoSwaps::exit_range(RuntimeOrigin::signed(alice()), market_id, vec![0, 0]),
+            Error::<Runtime>::RangePositionNotFound
+        );
+    });
+}
+
+#[test]
+fn exit_range_fails_on_amount_out_below_min() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_range_position();
+        assert_noop!(
+            NeoSwaps::exit_range(RuntimeOrigin::signed(bob()), market_id, vec![_1, _1]),
+            Error::<Runtime>::AmountOutBelowMin
+        );
+    });
+}
+
+#[test]
+fn exit_range_fails_on_outstanding_fees() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_range_position();
+        buy_first_outcome_for_ten_units(market_id);
+        assert_noop!(
+            NeoSwaps::exit_range(RuntimeOrigin::signed(bob()), market_id, vec![0, 0]),
+            LiquidityTreeError::UnwithdrawnFees.into_dispatch_error::<Runtime>(),
+        );
+    });
+}
+
+#[test]
+fn exit_fails_if_account_holds_range_position() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_range_position();
+        assert_noop!(
+            NeoSwaps::exit(RuntimeOrigin::signed(bob()), market_id, _1, vec![0, 0]),
+            Error::<Runtime>::RangePositionExists
+        );
+    });
+}
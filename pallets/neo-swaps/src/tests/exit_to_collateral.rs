@@ -0,0 +1,208 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+fn create_market_and_deploy_pool_with_two_liquidity_providers(
+    spot_prices: Vec<BalanceOf<Runtime>>,
+) -> MarketId {
+    let market_id = create_market_and_deploy_pool(
+        alice(),
+        BASE_ASSET,
+        MarketType::Categorical(2),
+        _10,
+        spot_prices,
+        CENT_BASE,
+    );
+    deposit_complete_set(market_id, bob(), _10);
+    assert_ok!(NeoSwaps::join(RuntimeOrigin::signed(bob()), market_id, _10, vec![_10, _10]));
+    // Bob keeps the outcomes he didn't need to join, so we burn them for a clean slate.
+    for asset in Pools::<Runtime>::get(market_id).unwrap().assets() {
+        let balance = AssetManager::free_balance(asset, &bob());
+        assert_ok!(AssetManager::withdraw(asset, &bob(), balance));
+    }
+    market_id
+}
+
+#[test]
+fn exit_to_collateral_works_without_leftovers() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id =
+            create_market_and_deploy_pool_with_two_liquidity_providers(vec![_1_2, _1_2]);
+        let amount_out = 99_900_000_000;
+        assert_ok!(NeoSwaps::exit_to_collateral(
+            RuntimeOrigin::signed(bob()),
+            market_id,
+            2,
+            _10,
+            amount_out,
+            vec![0, 0],
+        ));
+        assert_balance!(bob(), BASE_ASSET, amount_out);
+        assert_balance!(bob(), Asset::CategoricalOutcome(market_id, 0), 0);
+        assert_balance!(bob(), Asset::CategoricalOutcome(market_id, 1), 0);
+        System::assert_last_event(
+            Event::ExitToCollateralExecuted {
+                who: bob(),
+                market_id,
+                pool_shares_amount: _10,
+                amount_out,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn exit_to_collateral_sells_leftovers_to_the_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id =
+            create_market_and_deploy_pool_with_two_liquidity_providers(vec![_3_4, _1_4]);
+        assert_ok!(NeoSwaps::exit_to_collateral(
+            RuntimeOrigin::signed(bob()),
+            market_id,
+            2,
+            _10,
+            0,
+            vec![0, 0],
+        ));
+        assert_balance!(bob(), Asset::CategoricalOutcome(market_id, 0), 0);
+        assert_balance!(bob(), Asset::CategoricalOutcome(market_id, 1), 0);
+        let sold = System::events().into_iter().any(|record| {
+            matches!(
+                record.event,
+                RuntimeEvent::NeoSwaps(Event::SellExecuted { who, asset_in, .. })
+                    if who == bob() && asset_in == Asset::CategoricalOutcome(market_id, 1)
+            )
+        });
+        assert!(sold);
+        let amount_out = AssetManager::free_balance(BASE_ASSET, &bob());
+        System::assert_last_event(
+            Event::ExitToCollateralExecuted {
+                who: bob(),
+                market_id,
+                pool_shares_amount: _10,
+                amount_out,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn exit_to_collateral_keeps_leftovers_if_the_pool_is_destroyed() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_3_4, _1_4],
+            CENT_BASE,
+        );
+        let first_outcome = Asset::CategoricalOutcome(market_id, 0);
+        let second_outcome = Asset::CategoricalOutcome(market_id, 1);
+        let first_outcome_balance = AssetManager::free_balance(first_outcome, &alice());
+        let second_outcome_balance = AssetManager::free_balance(second_outcome, &alice());
+        assert_ok!(NeoSwaps::exit_to_collateral(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            2,
+            _10,
+            0,
+            vec![0, 0],
+        ));
+        assert!(!Pools::<Runtime>::contains_key(market_id));
+        // Alice receives `[20_731_123_089, 99_900_000_000]` and sells the first amount as complete
+        // sets.
+        assert_balance!(alice(), first_outcome, first_outcome_balance);
+        assert_balance!(alice(), second_outcome, second_outcome_balance + 79_168_876_911);
+        System::assert_last_event(
+            Event::ExitToCollateralExecuted {
+                who: alice(),
+                market_id,
+                pool_shares_amount: _10,
+                amount_out: 20_731_123_089,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn exit_to_collateral_fails_on_insufficient_amount_out() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id =
+            create_market_and_deploy_pool_with_two_liquidity_providers(vec![_1_2, _1_2]);
+        assert_noop!(
+            NeoSwaps::exit_to_collateral(
+                RuntimeOrigin::signed(bob()),
+                market_id,
+                2,
+                _10,
+                99_900_000_001,
+                vec![0, 0],
+            ),
+            Error::<Runtime>::AmountOutBelowMin
+        );
+    });
+}
+
+#[test]
+fn exit_to_collateral_fails_on_insufficient_leftover_amount_out() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id =
+            create_market_and_deploy_pool_with_two_liquidity_providers(vec![_3_4, _1_4]);
+        // The leftover of the cheap outcome is worth less than `_10`.
+        assert_noop!(
+            NeoSwaps::exit_to_collateral(
+                RuntimeOrigin::signed(bob()),
+                market_id,
+                2,
+                _10,
+                0,
+                vec![0, _10],
+            ),
+            Error::<Runtime>::AmountOutBelowMin
+        );
+    });
+}
+
+#[test]
+fn exit_to_collateral_fails_on_leftover_amount_out_if_the_pool_is_destroyed() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_3_4, _1_4],
+            CENT_BASE,
+        );
+        assert_noop!(
+            NeoSwaps::exit_to_collateral(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                2,
+                _10,
+                0,
+                vec![0, 1],
+            ),
+            Error::<Runtime>::AmountOutBelowMin
+        );
+    });
+}
@@ -70,6 +70,7 @@ fn join_works(
             Event::JoinExecuted {
                 who,
                 market_id,
+                parent_collection_id: None,
                 pool_shares_amount,
                 amounts_in,
                 new_liquidity_parameter,
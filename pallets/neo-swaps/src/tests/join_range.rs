@@ -0,0 +1,356 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::types::RangePosition;
+
+#[test]
+fn join_range_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_range_position();
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let position = RangePositions::<Runtime>::get(market_id, bob()).unwrap();
+        assert_eq!(position.asset, asset);
+        assert_eq!((position.lower, position.upper), (_1_3, _2_3));
+        assert_eq!(position.shares, _1);
+        assert_eq!(position.parked.into_inner(), vec![0, 0]);
+        let pool = Pools::<Runtime>::get(market_id).unwrap();
+        assert_eq!(pool.liquidity_shares_manager.shares_of(&bob()).unwrap(), _1);
+        assert_eq!(pool.liquidity_shares_manager.total_shares().unwrap(), _11);
+        System::assert_last_event(
+            Event::RangePositionOpened {
+                who: bob(),
+                market_id,
+                asset,
+                lower: _1_3,
+                upper: _2_3,
+                pool_shares_amount: _1,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn trade_deactivates_range_position_if_spot_price_leaves_range() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_range_position();
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let liquidity_parameter_before =
+            Pools::<Runtime>::get(market_id).unwrap().liquidity_parameter;
+        buy_first_outcome_for_ten_units(market_id);
+
+        let pool = Pools::<Runtime>::get(market_id).unwrap();
+        let position = RangePositions::<Runtime>::get(market_id, bob()).unwrap();
+        assert!(!position.is_active());
+        assert!(pool.calculate_spot_price(asset).unwrap() > _2_3);
+        assert_eq!(pool.liquidity_shares_manager.shares_of(&bob()).unwrap(), 0);
+        assert_eq!(pool.liquidity_shares_manager.total_shares().unwrap(), _10);
+        // The parked funds are held by the pool account, but no longer count as reserves.
+        for (asset, &parked) in pool.assets().iter().zip(position.parked.iter()) {
+            assert!(parked > 0);
+            assert_eq!(
+                AssetManager::free_balance(*asset, &pool.account_id),
+                pool.reserve_of(asset).unwrap() + parked
+            );
+        }
+        // Bob's share of the liquidity was removed.
+        assert_approx!(
+            pool.liquidity_parameter,
+            liquidity_parameter_before.bmul(_10.bdiv(_11).unwrap()).unwrap(),
+            10
+        );
+        System::assert_has_event(
+            Event::RangePositionDeactivated {
+                who: bob(),
+                market_id,
+                pool_shares_amount: _1,
+                amounts_parked: position.parked.into_inner(),
+                new_liquidity_parameter: pool.liquidity_parameter,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn inactive_range_position_keeps_fees_but_earns_no_new_fees() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_range_position();
+        buy_first_outcome_for_ten_units(market_id);
+        assert!(!RangePositions::<Runtime>::get(market_id, bob()).unwrap().is_active());
+        // Bob earned fees from the trade which deactivated his position.
+        let balance_before = AssetManager::free_balance(BASE_ASSET, &bob());
+        assert_ok!(NeoSwaps::withdraw_fees(RuntimeOrigin::signed(bob()), market_id));
+        let balance_after = AssetManager::free_balance(BASE_ASSET, &bob());
+        assert!(balance_after > balance_before);
+
+        // A trade which keeps the price out of range doesn't pay fees to bob.
+        let asset_in = Asset::CategoricalOutcome(market_id, 0);
+        assert_ok!(NeoSwaps::sell(RuntimeOrigin::signed(charlie()), market_id, 2, asset_in, _1, 0));
+        assert!(!RangePositions::<Runtime>::get(market_id, bob()).unwrap().is_active());
+        assert_ok!(NeoSwaps::withdraw_fees(RuntimeOrigin::signed(bob()), market_id));
+        assert_balance!(bob(), BASE_ASSET, balance_after);
+    });
+}
+
+#[test]
+fn trade_reactivates_range_position_if_spot_price_returns_to_range() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_range_position();
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let amount = buy_first_outcome_for_ten_units(market_id);
+        let parked_before = RangePositions::<Runtime>::get(market_id, bob()).unwrap().parked;
+        let liquidity_parameter_before =
+            Pools::<Runtime>::get(market_id).unwrap().liquidity_parameter;
+        assert_ok!(NeoSwaps::sell(
+            RuntimeOrigin::signed(charlie()),
+            market_id,
+            2,
+            asset,
+            amount,
+            0
+        ));
+
+        let pool = Pools::<Runtime>::get(market_id).unwrap();
+        let position = RangePositions::<Runtime>::get(market_id, bob()).unwrap();
+        assert!(position.is_active());
+        assert!(position.contains(pool.calculate_spot_price(asset).unwrap()));
+        assert_eq!(pool.liquidity_shares_manager.shares_of(&bob()).unwrap(), position.shares);
+        assert_eq!(pool.liquidity_shares_manager.total_shares().unwrap(), _10 + position.shares);
+        assert!(pool.liquidity_parameter > liquidity_parameter_before);
+        let amounts_in: Vec<_> = parked_before
+            .iter()
+            .zip(position.parked.iter())
+            .map(|(before, after)| before - after)
+            .collect();
+        // At least one of the parked amounts is used up completely, save for rounding.
+        assert!(position.parked.iter().any(|&parked| parked < 100));
+        System::assert_has_event(
+            Event::RangePositionReactivated {
+                who: bob(),
+                market_id,
+                pool_shares_amount: position.shares,
+                amounts_in,
+                new_liquidity_parameter: pool.liquidity_parameter,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn join_range_fails_on_invalid_price_range() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        deposit_complete_set(market_id, bob(), _2);
+        assert_noop!(
+            NeoSwaps::join_range(
+                RuntimeOrigin::signed(bob()),
+                market_id,
+                _1,
+                vec![u128::MAX, u128::MAX],
+                Asset::CategoricalOutcome(market_id, 0),
+                _2_3,
+                _1_3,
+            ),
+            Error::<Runtime>::InvalidPriceRange
+        );
+    });
+}
+
+#[test]
+fn join_range_fails_if_spot_price_is_out_of_range() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        deposit_complete_set(market_id, bob(), _2);
+        assert_noop!(
+            NeoSwaps::join_range(
+                RuntimeOrigin::signed(bob()),
+                market_id,
+                _1,
+                vec![u128::MAX, u128::MAX],
+                Asset::CategoricalOutcome(market_id, 0),
+                _2_3,
+                _9_10,
+            ),
+            Error::<Runtime>::SpotPriceOutOfRange
+        );
+    });
+}
+
+#[test]
+fn join_range_fails_if_position_adds_less_than_minimum_liquidity() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        deposit_complete_set(market_id, bob(), _2);
+        // Half a pool share of ten adds about `0.72` to the liquidity parameter of `_10 / ln(2)`.
+        assert_noop!(
+            NeoSwaps::join_range(
+                RuntimeOrigin::signed(bob()),
+                market_id,
+                _1_2,
+                vec![u128::MAX, u128::MAX],
+                Asset::CategoricalOutcome(market_id, 0),
+                _1_3,
+                _2_3,
+            ),
+            Error::<Runtime>::RangePositionTooSmall
+        );
+    });
+}
+
+#[test]
+fn join_range_fails_on_asset_not_found() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        deposit_complete_set(market_id, bob(), _2);
+        assert_noop!(
+            NeoSwaps::join_range(
+                RuntimeOrigin::signed(bob()),
+                market_id,
+                _1,
+                vec![u128::MAX, u128::MAX],
+                Asset::CategoricalOutcome(market_id, 2),
+                _1_3,
+                _2_3,
+            ),
+            Error::<Runtime>::AssetNotFound
+        );
+    });
+}
+
+#[test]
+fn join_range_fails_if_account_holds_pool_shares() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        deposit_complete_set(market_id, alice(), _2);
+        assert_noop!(
+            NeoSwaps::join_range(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                _1,
+                vec![u128::MAX, u128::MAX],
+                Asset::CategoricalOutcome(market_id, 0),
+                _1_3,
+                _2_3,
+            ),
+            Error::<Runtime>::RangePositionExists
+        );
+    });
+}
+
+#[test]
+fn join_range_fails_on_too_many_range_positions() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        for seed in 0..<Runtime as Config>::MaxRangePositions::get() {
+            let position = RangePosition {
+                asset,
+                lower: _1_3,
+                upper: _2_3,
+                shares: 0,
+                parked: vec![0, 0].try_into().unwrap(),
+            };
+            RangePositions::<Runtime>::insert(
+                market_id,
+                get_account_from_seed([seed as u8; 32]),
+                position,
+            );
+        }
+        deposit_complete_set(market_id, bob(), _2);
+        assert_noop!(
+            NeoSwaps::join_range(
+                RuntimeOrigin::signed(bob()),
+                market_id,
+                _1,
+                vec![u128::MAX, u128::MAX],
+                asset,
+                _1_3,
+                _2_3,
+            ),
+            Error::<Runtime>::TooManyRangePositions
+        );
+    });
+}
+
+#[test]
+fn join_fails_if_account_holds_range_position() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_range_position();
+        deposit_complete_set(market_id, bob(), _2);
+        assert_noop!(
+            NeoSwaps::join(RuntimeOrigin::signed(bob()), market_id, _1, vec![u128::MAX, u128::MAX]),
+            Error::<Runtime>::RangePositionExists
+        );
+        assert_noop!(
+            NeoSwaps::join_range(
+                RuntimeOrigin::signed(bob()),
+                market_id,
+                _1,
+                vec![u128::MAX, u128::MAX],
+                Asset::CategoricalOutcome(market_id, 0),
+                _1_3,
+                _2_3,
+            ),
+            Error::<Runtime>::RangePositionExists
+        );
+    });
+}
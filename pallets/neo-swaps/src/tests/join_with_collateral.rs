@@ -0,0 +1,216 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+fn pool_shares_of(market_id: MarketId, who: AccountIdOf<Runtime>) -> BalanceOf<Runtime> {
+    Pools::<Runtime>::get(market_id)
+        .unwrap()
+        .liquidity_shares_manager
+        .shares_of(&who)
+        .unwrap()
+}
+
+#[test]
+fn join_with_collateral_works_without_leftovers() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        assert_ok!(AssetManager::deposit(BASE_ASSET, &bob(), _10));
+        assert_ok!(NeoSwaps::join_with_collateral(
+            RuntimeOrigin::signed(bob()),
+            market_id,
+            2,
+            _10,
+            _10,
+            vec![0, 0],
+        ));
+        assert_eq!(pool_shares_of(market_id, bob()), _10);
+        assert_balance!(bob(), BASE_ASSET, 0);
+        assert_balance!(bob(), Asset::CategoricalOutcome(market_id, 0), 0);
+        assert_balance!(bob(), Asset::CategoricalOutcome(market_id, 1), 0);
+        System::assert_last_event(
+            Event::JoinWithCollateralExecuted {
+                who: bob(),
+                market_id,
+                amount_in: _10,
+                pool_shares_amount: _10,
+                amount_out: 0,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn join_with_collateral_sells_leftovers_to_the_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_3_4, _1_4],
+            CENT_BASE,
+        );
+        assert_ok!(AssetManager::deposit(BASE_ASSET, &bob(), _10));
+        assert_ok!(NeoSwaps::join_with_collateral(
+            RuntimeOrigin::signed(bob()),
+            market_id,
+            2,
+            _10,
+            0,
+            vec![0, 0],
+        ));
+        assert_eq!(pool_shares_of(market_id, bob()), _10);
+        let leftover = _10 - 20_751_874_964;
+        let amount_out = AssetManager::free_balance(BASE_ASSET, &bob());
+        assert!(amount_out > 0);
+        assert_balance!(bob(), Asset::CategoricalOutcome(market_id, 0), 0);
+        assert_balance!(bob(), Asset::CategoricalOutcome(market_id, 1), 0);
+        let sold = System::events().into_iter().any(|record| {
+            matches!(
+                record.event,
+                RuntimeEvent::NeoSwaps(Event::SellExecuted { who, amount_in, .. })
+                    if who == bob() && amount_in == leftover
+            )
+        });
+        assert!(sold);
+        System::assert_last_event(
+            Event::JoinWithCollateralExecuted {
+                who: bob(),
+                market_id,
+                amount_in: _10,
+                pool_shares_amount: _10,
+                amount_out,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn join_with_collateral_fails_on_insufficient_pool_shares() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        assert_ok!(AssetManager::deposit(BASE_ASSET, &bob(), _10));
+        assert_noop!(
+            NeoSwaps::join_with_collateral(
+                RuntimeOrigin::signed(bob()),
+                market_id,
+                2,
+                _10,
+                _10 + 1,
+                vec![0, 0],
+            ),
+            Error::<Runtime>::AmountOutBelowMin
+        );
+    });
+}
+
+#[test]
+fn join_with_collateral_fails_on_incorrect_asset_count() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        assert_ok!(AssetManager::deposit(BASE_ASSET, &bob(), _10));
+        assert_noop!(
+            NeoSwaps::join_with_collateral(
+                RuntimeOrigin::signed(bob()),
+                market_id,
+                3,
+                _10,
+                0,
+                vec![0, 0, 0]
+            ),
+            Error::<Runtime>::IncorrectAssetCount
+        );
+    });
+}
+
+#[test]
+fn join_with_collateral_fails_on_insufficient_leftover_amount_out() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_3_4, _1_4],
+            CENT_BASE,
+        );
+        assert_ok!(AssetManager::deposit(BASE_ASSET, &bob(), _10));
+        // The leftover of the expensive outcome is sold for less than its face value.
+        let leftover = _10 - 20_751_874_964;
+        assert_noop!(
+            NeoSwaps::join_with_collateral(
+                RuntimeOrigin::signed(bob()),
+                market_id,
+                2,
+                _10,
+                0,
+                vec![leftover, 0]
+            ),
+            Error::<Runtime>::AmountOutBelowMin
+        );
+    });
+}
+
+#[test]
+fn join_with_collateral_fails_on_incorrect_vec_len() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        assert_ok!(AssetManager::deposit(BASE_ASSET, &bob(), _10));
+        assert_noop!(
+            NeoSwaps::join_with_collateral(
+                RuntimeOrigin::signed(bob()),
+                market_id,
+                2,
+                _10,
+                0,
+                vec![0]
+            ),
+            Error::<Runtime>::IncorrectVecLen
+        );
+    });
+}
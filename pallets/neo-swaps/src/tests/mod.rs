@@ -17,20 +17,34 @@
 
 #![cfg(all(feature = "mock", test))]
 
+mod additional_swap_fee;
 mod buy;
 mod buy_and_sell;
+mod claim_incentives;
+mod create_incentive_program;
+mod deploy_combinatorial_pool;
 mod deploy_pool;
+mod deploy_pool_with_dynamic_fee;
 mod exit;
+mod exit_range;
+mod exit_to_collateral;
 mod join;
+mod join_range;
+mod join_with_collateral;
 mod liquidity_tree_interactions;
 mod pallet_admin_tests;
+mod quote;
 mod sell;
+mod signed_claim_incentives;
 mod signed_exit;
+mod signed_exit_to_collateral;
 mod signed_join;
+mod signed_join_with_collateral;
 mod signed_withdraw_fees;
+mod twap;
 mod withdraw_fees;
 
-use crate::{consts::*, mock::*, traits::*, *};
+use crate::{consts::*, mock::*, traits::*, types::AdditionalSwapFeeMode, *};
 use common_primitives::constants::currency::CENT_BASE;
 use frame_support::{assert_noop, assert_ok};
 use orml_traits::MultiCurrency;
@@ -42,7 +56,7 @@ use prediction_market_primitives::{
     math::fixed::{FixedDiv, FixedMul},
     types::{
         Asset, Deadlines, MarketCreation, MarketId, MarketPeriod, MarketStatus, MarketType,
-        MultiHash, ScalarPosition, ScoringRule, TestAccountIdPK,
+        MultiHash, OutcomeReport, ScalarPosition, ScoringRule, TestAccountIdPK,
     },
 };
 pub use sp_runtime::traits::Hash;
@@ -117,3 +131,54 @@ fn deposit_complete_set(
         amount,
     ));
 }
+
+/// Deploys a pool with `_10` units of liquidity and equal prices for a binary market and lets bob
+/// open a range position of `_1` pool shares, which is active while the price of the first outcome
+/// is in the range `_1_3..=_2_3`.
+fn create_market_and_deploy_pool_with_range_position() -> MarketId {
+    let market_id = create_market_and_deploy_pool(
+        alice(),
+        BASE_ASSET,
+        MarketType::Categorical(2),
+        _10,
+        vec![_1_2, _1_2],
+        CENT_BASE,
+    );
+    deposit_complete_set(market_id, bob(), _2);
+    assert_ok!(NeoSwaps::join_range(
+        RuntimeOrigin::signed(bob()),
+        market_id,
+        _1,
+        vec![u128::MAX, u128::MAX],
+        Asset::CategoricalOutcome(market_id, 0),
+        _1_3,
+        _2_3,
+    ));
+    market_id
+}
+
+/// Returns the flat additional swap fee set in the genesis config.
+fn additional_swap_fee() -> BalanceOf<Runtime> {
+    match NeoSwaps::additional_swap_fee().unwrap() {
+        AdditionalSwapFeeMode::Flat(fee) => fee,
+        fee => panic!("unexpected additional swap fee: {:?}", fee),
+    }
+}
+
+/// Lets charlie buy the first outcome for `_10` units of collateral (after fees), which moves its
+/// price above `_2_3`. Returns the amount of outcome tokens charlie received.
+fn buy_first_outcome_for_ten_units(market_id: MarketId) -> BalanceOf<Runtime> {
+    let total_in = _10 + additional_swap_fee();
+    let amount_in = total_in.bdiv(_1 - CENT_BASE).unwrap();
+    let asset_out = Asset::CategoricalOutcome(market_id, 0);
+    assert_ok!(AssetManager::deposit(BASE_ASSET, &charlie(), amount_in));
+    assert_ok!(NeoSwaps::buy(
+        RuntimeOrigin::signed(charlie()),
+        market_id,
+        2,
+        asset_out,
+        amount_in,
+        0
+    ));
+    AssetManager::free_balance(asset_out, &charlie())
+}
@@ -2,7 +2,7 @@
 
 #![cfg(test)]
 
-use crate::{mock::*, *};
+use crate::{mock::*, types::AdditionalSwapFeeMode, *};
 use frame_support::{assert_noop, assert_ok};
 use frame_system::RawOrigin;
 use sp_runtime::DispatchError;
@@ -14,7 +14,7 @@ mod set_additional_swap_fee {
     fn succeeds() {
         let mut ext = ExtBuilder::default().build();
         ext.execute_with(|| {
-            let fee = 123_456;
+            let fee = AdditionalSwapFeeMode::Flat(123_456);
             assert!(<AdditionalSwapFee<Runtime>>::get() != Some(fee));
             assert_ok!(NeoSwaps::set_additional_swap_fee(
                 RuntimeOrigin::signed(market_admin()),
@@ -29,7 +29,7 @@ mod set_additional_swap_fee {
     fn origin_is_checked_none() {
         let mut ext = ExtBuilder::default().build();
         ext.execute_with(|| {
-            let fee = 123_456;
+            let fee = AdditionalSwapFeeMode::Flat(123_456);
             assert!(<AdditionalSwapFee<Runtime>>::get() != Some(fee));
             assert_noop!(
                 NeoSwaps::set_additional_swap_fee(RawOrigin::None.into(), fee),
@@ -42,7 +42,7 @@ mod set_additional_swap_fee {
     fn origin_is_checked_signed() {
         let mut ext = ExtBuilder::default().build();
         ext.execute_with(|| {
-            let fee = 123_456;
+            let fee = AdditionalSwapFeeMode::Flat(123_456);
             assert!(<AdditionalSwapFee<Runtime>>::get() != Some(fee));
 
             let bad_origin = alice();
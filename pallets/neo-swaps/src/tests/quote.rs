@@ -0,0 +1,215 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use sp_runtime::DispatchError;
+use test_case::test_case;
+
+#[test]
+fn quote_buy_matches_executed_buy() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        let pool = Pools::<Runtime>::get(market_id).unwrap();
+        let asset_out = pool.assets()[0];
+        let amount_in = _10;
+        let quote = NeoSwaps::quote_buy(&bob(), market_id, asset_out, amount_in).unwrap();
+        assert_eq!(quote.spot_price_before, _1_2);
+        assert_ok!(AssetManager::deposit(BASE_ASSET, &bob(), amount_in));
+        assert_ok!(NeoSwaps::buy(
+            RuntimeOrigin::signed(bob()),
+            market_id,
+            2,
+            asset_out,
+            amount_in,
+            0,
+        ));
+        System::assert_last_event(
+            Event::BuyExecuted {
+                who: bob(),
+                market_id,
+                parent_collection_id: None,
+                asset_out,
+                amount_in,
+                amount_out: quote.amount_out,
+                swap_fee: CENT_BASE,
+                swap_fee_amount: quote.swap_fee_amount,
+                external_fee_amount: quote.external_fee_amount,
+            }
+            .into(),
+        );
+        let pool = Pools::<Runtime>::get(market_id).unwrap();
+        assert_eq!(quote.spot_price_after, pool.calculate_spot_price(asset_out).unwrap());
+    });
+}
+
+#[test]
+fn quote_sell_matches_executed_sell() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Scalar(0..=1),
+            _10,
+            vec![_1_4, _3_4],
+            CENT_BASE,
+        );
+        let pool = Pools::<Runtime>::get(market_id).unwrap();
+        let asset_in = pool.assets()[1];
+        let amount_in = _10;
+        let quote = NeoSwaps::quote_sell(&bob(), market_id, asset_in, amount_in).unwrap();
+        assert_eq!(quote.spot_price_before, _3_4);
+        deposit_complete_set(market_id, bob(), amount_in);
+        assert_ok!(NeoSwaps::sell(
+            RuntimeOrigin::signed(bob()),
+            market_id,
+            2,
+            asset_in,
+            amount_in,
+            0,
+        ));
+        System::assert_last_event(
+            Event::SellExecuted {
+                who: bob(),
+                market_id,
+                parent_collection_id: None,
+                asset_in,
+                amount_in,
+                amount_out: quote.amount_out,
+                swap_fee: CENT_BASE,
+                swap_fee_amount: quote.swap_fee_amount,
+                external_fee_amount: quote.external_fee_amount,
+            }
+            .into(),
+        );
+        let pool = Pools::<Runtime>::get(market_id).unwrap();
+        assert_eq!(quote.spot_price_after, pool.calculate_spot_price(asset_in).unwrap());
+    });
+}
+
+#[test]
+fn spot_prices_returns_all_pool_assets() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(4),
+            _10,
+            vec![_1_4, _1_4, _1_4, _1_4],
+            CENT_BASE,
+        );
+        let pool = Pools::<Runtime>::get(market_id).unwrap();
+        let expected: Vec<_> = pool.assets().into_iter().map(|asset| (asset, _1_4)).collect();
+        assert_eq!(NeoSwaps::spot_prices(market_id).unwrap(), expected);
+    });
+}
+
+#[test]
+fn quotes_fail_if_pool_does_not_exist() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            ScoringRule::AmmCdaHybrid,
+        );
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        assert_noop!(
+            NeoSwaps::quote_buy(&bob(), market_id, asset, _1),
+            Error::<Runtime>::PoolNotFound
+        );
+        assert_noop!(
+            NeoSwaps::quote_sell(&bob(), market_id, asset, _1),
+            Error::<Runtime>::PoolNotFound
+        );
+        assert_noop!(NeoSwaps::spot_prices(market_id), Error::<Runtime>::PoolNotFound);
+    });
+}
+
+#[test]
+fn quote_buy_fails_on_inactive_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT_BASE,
+        );
+        MarketCommons::mutate_market(&market_id, |market| {
+            market.status = MarketStatus::Closed;
+            Ok(())
+        })
+        .unwrap();
+        assert_noop!(
+            NeoSwaps::quote_buy(&bob(), market_id, Asset::CategoricalOutcome(market_id, 0), _1),
+            Error::<Runtime>::MarketNotActive
+        );
+    });
+}
+
+#[test_case(11, _1, NumericalLimitsError::SpotPriceTooLow; "spot_price_too_low")]
+#[test_case(10, _10, NumericalLimitsError::SpotPriceSlippedTooLow; "spot_price_slipped_too_low")]
+fn quote_sell_fails_like_executed_sell_on_numerical_limits(
+    reserve_factor: u128,
+    amount_in: BalanceOf<Runtime>,
+    error: NumericalLimitsError,
+) {
+    ExtBuilder::default().build().execute_with(|| {
+        let error: DispatchError = Error::<Runtime>::NumericalLimits(error).into();
+        let asset_count = 4;
+        let market_id = create_market_and_deploy_pool(
+            alice(),
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            _10,
+            vec![_1_4, _1_4, _1_4, _1_4],
+            CENT_BASE,
+        );
+        let asset_in = Asset::CategoricalOutcome(market_id, asset_count - 1);
+        // Force the price down by changing the reserve of the pool; see the corresponding tests
+        // of `sell`.
+        NeoSwaps::try_mutate_pool(&market_id, |pool| {
+            pool.reserves
+                .try_insert(asset_in, reserve_factor * pool.liquidity_parameter)
+                .unwrap();
+            Ok(())
+        })
+        .unwrap();
+        assert_noop!(NeoSwaps::quote_sell(&bob(), market_id, asset_in, amount_in), error);
+        assert_ok!(AssetManager::deposit(asset_in, &bob(), amount_in));
+        assert_ok!(AssetManager::deposit(BASE_ASSET, &bob(), _1));
+        assert_noop!(
+            NeoSwaps::sell(
+                RuntimeOrigin::signed(bob()),
+                market_id,
+                asset_count,
+                asset_in,
+                amount_in,
+                0
+            ),
+            error,
+        );
+    });
+}
@@ -47,10 +47,9 @@ fn sell_works() {
         ));
 
         let expected_amount_out = 59632253897;
-        let expected_fees =
-            swap_fee.bmul(expected_amount_out).unwrap() + NeoSwaps::additional_swap_fee().unwrap();
-        let expected_swap_fee_amount = expected_fees - NeoSwaps::additional_swap_fee().unwrap();
-        let expected_external_fee_amount = NeoSwaps::additional_swap_fee().unwrap();
+        let expected_fees = swap_fee.bmul(expected_amount_out).unwrap() + additional_swap_fee();
+        let expected_swap_fee_amount = expected_fees - additional_swap_fee();
+        let expected_external_fee_amount = additional_swap_fee();
         let expected_amount_out_minus_fees = expected_amount_out - expected_fees;
 
         assert_balance!(bob(), BASE_ASSET, expected_amount_out_minus_fees);
@@ -81,9 +80,11 @@ fn sell_works() {
             Event::SellExecuted {
                 who: bob(),
                 market_id,
+                parent_collection_id: None,
                 asset_in,
                 amount_in,
                 amount_out: expected_amount_out_minus_fees,
+                swap_fee,
                 swap_fee_amount: expected_swap_fee_amount,
                 external_fee_amount: expected_external_fee_amount,
             }
@@ -0,0 +1,156 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use prediction_market_primitives::{test_helper::TestAccount, types::SignatureTest};
+use sp_avn_common::Proof;
+use sp_core::Pair;
+
+// Alice seed is 0
+fn signer() -> TestAccount {
+    TestAccount::new([0; 32])
+}
+
+/// Deploys a pool owned by alice with an incentive program which streams `_1` per block from
+/// block 1 to block 11 and advances to block 6.
+fn create_market_and_deploy_pool_with_incentive_program() -> MarketId {
+    let market_id = create_market_and_deploy_pool(
+        alice(),
+        BASE_ASSET,
+        MarketType::Categorical(2),
+        _10,
+        vec![_1_2, _1_2],
+        CENT_BASE,
+    );
+    let total_cost = _10 + AssetManager::minimum_balance(BASE_ASSET);
+    assert_ok!(AssetManager::deposit(BASE_ASSET, &alice(), total_cost));
+    assert_ok!(NeoSwaps::create_incentive_program(
+        RuntimeOrigin::signed(alice()),
+        market_id,
+        BASE_ASSET,
+        _10,
+        1,
+        11,
+    ));
+    System::set_block_number(6);
+    market_id
+}
+
+fn create_signed_claim_incentives_proof(
+    who: &TestAccount,
+    market_id: MarketId,
+    block_number: u32,
+) -> Proof<SignatureTest, TestAccountIdPK> {
+    let relayer = eve();
+    let encoded_payload =
+        NeoSwaps::encode_signed_claim_incentives_params(&relayer, &market_id, &block_number);
+    let signature = SignatureTest::from(who.key_pair().sign(&encoded_payload));
+    Proof { signer: who.key_pair().public(), relayer, signature }
+}
+
+#[test]
+fn signed_claim_incentives_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_incentive_program();
+        let alice = signer();
+        assert_eq!(alice.account_id(), alice());
+        let block_number = System::block_number();
+        let proof = create_signed_claim_incentives_proof(&alice, market_id, block_number);
+        let old_balance = AssetManager::free_balance(BASE_ASSET, &alice());
+
+        assert_ok!(NeoSwaps::signed_claim_incentives(
+            RuntimeOrigin::signed(alice()),
+            proof,
+            market_id,
+            block_number,
+        ));
+
+        assert_balance!(alice(), BASE_ASSET, old_balance + _5);
+        System::assert_last_event(
+            Event::IncentivesClaimed {
+                who: alice(),
+                market_id,
+                program_id: 0,
+                asset: BASE_ASSET,
+                amount: _5,
+            }
+            .into(),
+        );
+    });
+}
+
+mod fails_when {
+    use super::*;
+
+    #[test]
+    fn proof_has_wrong_relayer() {
+        ExtBuilder::default().build().execute_with(|| {
+            let market_id = create_market_and_deploy_pool_with_incentive_program();
+            let block_number = System::block_number();
+            let bad_proof = Proof {
+                relayer: dave(),
+                ..create_signed_claim_incentives_proof(&signer(), market_id, block_number)
+            };
+            assert_noop!(
+                NeoSwaps::signed_claim_incentives(
+                    RuntimeOrigin::signed(alice()),
+                    bad_proof,
+                    market_id,
+                    block_number,
+                ),
+                Error::<Runtime>::UnauthorizedSignedTransaction
+            );
+        });
+    }
+
+    #[test]
+    fn sender_is_not_signer() {
+        ExtBuilder::default().build().execute_with(|| {
+            let market_id = create_market_and_deploy_pool_with_incentive_program();
+            let block_number = System::block_number();
+            let proof = create_signed_claim_incentives_proof(&signer(), market_id, block_number);
+            assert_noop!(
+                NeoSwaps::signed_claim_incentives(
+                    RuntimeOrigin::signed(bob()),
+                    proof,
+                    market_id,
+                    block_number,
+                ),
+                Error::<Runtime>::SenderIsNotSigner
+            );
+        });
+    }
+
+    #[test]
+    fn proof_has_expired() {
+        ExtBuilder::default().build().execute_with(|| {
+            let market_id = create_market_and_deploy_pool_with_incentive_program();
+            let block_number = System::block_number();
+            let proof = create_signed_claim_incentives_proof(&signer(), market_id, block_number);
+            System::set_block_number(block_number + 100);
+            assert_noop!(
+                NeoSwaps::signed_claim_incentives(
+                    RuntimeOrigin::signed(alice()),
+                    proof,
+                    market_id,
+                    block_number,
+                ),
+                Error::<Runtime>::SignedTransactionExpired
+            );
+        });
+    }
+}
@@ -154,6 +154,7 @@ fn signed_exit_works(
             Event::ExitExecuted {
                 who: alice(),
                 market_id,
+                parent_collection_id: None,
                 pool_shares_amount,
                 amounts_out,
                 new_liquidity_parameter,
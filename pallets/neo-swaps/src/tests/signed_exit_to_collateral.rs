@@ -0,0 +1,162 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use prediction_market_primitives::{test_helper::TestAccount, types::SignatureTest};
+use sp_avn_common::Proof;
+use sp_core::Pair;
+
+// Alice seed is 0
+fn signer() -> TestAccount {
+    TestAccount::new([0; 32])
+}
+
+/// Deploys a pool with `_10` pool shares owned by alice and equal prices, and lets alice join
+/// with another `_10` pool shares.
+fn create_market_and_deploy_pool_with_alice_as_liquidity_provider() -> MarketId {
+    let market_id = create_market_and_deploy_pool(
+        alice(),
+        BASE_ASSET,
+        MarketType::Categorical(2),
+        _10,
+        vec![_1_2, _1_2],
+        CENT_BASE,
+    );
+    assert_ok!(NeoSwaps::join_with_collateral(
+        RuntimeOrigin::signed(alice()),
+        market_id,
+        2,
+        _10,
+        _10,
+        vec![0, 0],
+    ));
+    market_id
+}
+
+fn create_signed_exit_to_collateral_proof(
+    who: &TestAccount,
+    market_id: MarketId,
+    pool_shares_amount: BalanceOf<Runtime>,
+    min_amount_out: BalanceOf<Runtime>,
+    block_number: u32,
+) -> Proof<SignatureTest, TestAccountIdPK> {
+    let relayer = eve();
+    let encoded_payload = NeoSwaps::encode_signed_exit_to_collateral_params(
+        &relayer,
+        &market_id,
+        &2,
+        &pool_shares_amount,
+        &min_amount_out,
+        &vec![0, 0],
+        &block_number,
+    );
+    let signature = SignatureTest::from(who.key_pair().sign(&encoded_payload));
+    Proof { signer: who.key_pair().public(), relayer, signature }
+}
+
+#[test]
+fn signed_exit_to_collateral_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_pool_with_alice_as_liquidity_provider();
+        let alice = signer();
+        assert_eq!(alice.account_id(), alice());
+        let block_number = System::block_number();
+        let amount_out = 99_900_000_000;
+        let proof = create_signed_exit_to_collateral_proof(
+            &alice,
+            market_id,
+            _10,
+            amount_out,
+            block_number,
+        );
+        let old_balance = AssetManager::free_balance(BASE_ASSET, &alice());
+
+        assert_ok!(NeoSwaps::signed_exit_to_collateral(
+            RuntimeOrigin::signed(alice()),
+            proof,
+            market_id,
+            2,
+            _10,
+            amount_out,
+            vec![0, 0],
+            block_number,
+        ));
+
+        assert_balance!(alice(), BASE_ASSET, old_balance + amount_out);
+        System::assert_last_event(
+            Event::ExitToCollateralExecuted {
+                who: alice(),
+                market_id,
+                pool_shares_amount: _10,
+                amount_out,
+            }
+            .into(),
+        );
+    });
+}
+
+mod fails_when {
+    use super::*;
+
+    #[test]
+    fn proof_has_wrong_relayer() {
+        ExtBuilder::default().build().execute_with(|| {
+            let market_id = create_market_and_deploy_pool_with_alice_as_liquidity_provider();
+            let block_number = System::block_number();
+            let bad_proof = Proof {
+                relayer: dave(),
+                ..create_signed_exit_to_collateral_proof(&signer(), market_id, _10, 0, block_number)
+            };
+            assert_noop!(
+                NeoSwaps::signed_exit_to_collateral(
+                    RuntimeOrigin::signed(alice()),
+                    bad_proof,
+                    market_id,
+                    2,
+                    _10,
+                    0,
+                    vec![0, 0],
+                    block_number,
+                ),
+                Error::<Runtime>::UnauthorizedSignedTransaction
+            );
+        });
+    }
+
+    #[test]
+    fn sender_is_not_signer() {
+        ExtBuilder::default().build().execute_with(|| {
+            let market_id = create_market_and_deploy_pool_with_alice_as_liquidity_provider();
+            let block_number = System::block_number();
+            let proof =
+                create_signed_exit_to_collateral_proof(&signer(), market_id, _10, 0, block_number);
+            assert_noop!(
+                NeoSwaps::signed_exit_to_collateral(
+                    RuntimeOrigin::signed(bob()),
+                    proof,
+                    market_id,
+                    2,
+                    _10,
+                    0,
+                    vec![0, 0],
+                    block_number,
+                ),
+                Error::<Runtime>::SenderIsNotSigner
+            );
+        });
+    }
+}
@@ -151,6 +151,7 @@ fn signed_join_works() {
             Event::JoinExecuted {
                 who: bob(),
                 market_id,
+                parent_collection_id: None,
                 pool_shares_amount,
                 amounts_in: vec![
                     bob_initial_balances[0] - bob_final_balances[0],
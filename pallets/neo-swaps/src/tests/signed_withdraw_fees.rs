@@ -92,7 +92,13 @@ impl SignedWithdrawContext {
             fees_remaining,
         );
         System::assert_last_event(
-            Event::FeesWithdrawn { who, market_id: self.market_id, amount: fees_withdrawn }.into(),
+            Event::FeesWithdrawn {
+                who,
+                market_id: self.market_id,
+                parent_collection_id: None,
+                amount: fees_withdrawn,
+            }
+            .into(),
         );
         Ok(().into())
     }
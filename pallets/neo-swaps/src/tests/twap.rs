@@ -0,0 +1,144 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use test_case::test_case;
+
+fn create_market_and_deploy_default_pool() -> MarketId {
+    create_market_and_deploy_pool(
+        alice(),
+        BASE_ASSET,
+        MarketType::Categorical(2),
+        _10,
+        vec![_1_2, _1_2],
+        CENT_BASE,
+    )
+}
+
+fn spot_price_of_first_outcome(market_id: MarketId) -> BalanceOf<Runtime> {
+    NeoSwaps::spot_prices(market_id).unwrap()[0].1
+}
+
+fn twap_of_first_outcome(market_id: MarketId, period: u32) -> BalanceOf<Runtime> {
+    NeoSwaps::twap(market_id, period).unwrap()[0].1
+}
+
+#[test]
+fn twap_equals_spot_prices_without_trades() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        System::set_block_number(11);
+        assert_eq!(
+            NeoSwaps::twap(market_id, 10).unwrap(),
+            vec![
+                (Asset::CategoricalOutcome(market_id, 0), _1_2),
+                (Asset::CategoricalOutcome(market_id, 1), _1_2),
+            ]
+        );
+    });
+}
+
+#[test]
+fn twap_weights_spot_prices_by_duration() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        System::set_block_number(5);
+        buy_first_outcome_for_ten_units(market_id);
+        let spot_price = spot_price_of_first_outcome(market_id);
+        System::set_block_number(11);
+        // The initial price held for blocks 1 to 4, the new price for blocks 5 to 10.
+        assert_eq!(twap_of_first_outcome(market_id, 10), (4 * _1_2 + 6 * spot_price) / 10);
+        // Only the new price held over the last five blocks.
+        assert_eq!(twap_of_first_outcome(market_id, 5), spot_price);
+    });
+}
+
+#[test]
+fn trades_in_the_current_block_do_not_affect_twap() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        System::set_block_number(11);
+        buy_first_outcome_for_ten_units(market_id);
+        assert!(spot_price_of_first_outcome(market_id) > _2_3);
+        assert_eq!(twap_of_first_outcome(market_id, 10), _1_2);
+    });
+}
+
+#[test]
+fn only_the_last_update_of_a_block_is_observed() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        System::set_block_number(5);
+        let amount_out = buy_first_outcome_for_ten_units(market_id);
+        assert_ok!(NeoSwaps::sell(
+            RuntimeOrigin::signed(charlie()),
+            market_id,
+            2,
+            Asset::CategoricalOutcome(market_id, 0),
+            amount_out,
+            0,
+        ));
+        let spot_price = spot_price_of_first_outcome(market_id);
+        System::set_block_number(11);
+        assert_eq!(twap_of_first_outcome(market_id, 5), spot_price);
+    });
+}
+
+#[test]
+fn oldest_observations_are_overwritten() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        let max_observations = <Runtime as Config>::MaxObservations::get();
+        for block in 2..=max_observations + 1 {
+            System::set_block_number(block);
+            deposit_complete_set(market_id, bob(), _2);
+            assert_ok!(NeoSwaps::join(RuntimeOrigin::signed(bob()), market_id, _1, vec![_2, _2]));
+        }
+        assert_eq!(
+            Observations::<Runtime>::iter_prefix(market_id).count() as u32,
+            max_observations
+        );
+        System::set_block_number(max_observations + 2);
+        assert_ok!(NeoSwaps::twap(market_id, max_observations));
+        assert_noop!(
+            NeoSwaps::twap(market_id, max_observations + 1),
+            Error::<Runtime>::InsufficientObservations
+        );
+    });
+}
+
+#[test_case(0, Error::<Runtime>::ZeroTwapPeriod; "zero_period")]
+#[test_case(11, Error::<Runtime>::InsufficientObservations; "period_before_deployment")]
+fn twap_fails_on_invalid_period(period: u32, error: Error<Runtime>) {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        System::set_block_number(11);
+        assert_noop!(NeoSwaps::twap(market_id, period), error);
+    });
+}
+
+#[test]
+fn observations_are_removed_with_the_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_and_deploy_default_pool();
+        System::set_block_number(11);
+        assert_ok!(NeoSwaps::exit(RuntimeOrigin::signed(alice()), market_id, _10, vec![0, 0]));
+        assert_eq!(Observations::<Runtime>::iter_prefix(market_id).count(), 0);
+        assert!(!LatestObservations::<Runtime>::contains_key(market_id));
+        assert_noop!(NeoSwaps::twap(market_id, 10), Error::<Runtime>::PoolNotFound);
+    });
+}
@@ -74,7 +74,13 @@ fn withdraw_fees_works() {
                 fees_remaining,
             );
             System::assert_last_event(
-                Event::FeesWithdrawn { who, market_id, amount: fees_withdrawn }.into(),
+                Event::FeesWithdrawn {
+                    who,
+                    market_id,
+                    parent_collection_id: None,
+                    amount: fees_withdrawn,
+                }
+                .into(),
             );
         };
         test_withdraw(alice(), _1_4, _3_4);
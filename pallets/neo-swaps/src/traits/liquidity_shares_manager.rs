@@ -33,6 +33,11 @@ pub trait LiquiditySharesManager<T: Config> {
     /// Remove `amount` units of pool shares from the account of `who`.
     fn exit(&mut self, who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult;
 
+    /// Remove `amount` units of pool shares from the account of `who` without closing the
+    /// account. The removed shares no longer receive fees, but the fees owed to `who` are kept and
+    /// may still be withdrawn.
+    fn deactivate(&mut self, who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult;
+
     /// Transfer `amount` units of pool shares from `sender` to `receiver`.
     #[allow(unused)]
     fn split(
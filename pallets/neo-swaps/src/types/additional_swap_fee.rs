@@ -0,0 +1,72 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::AtLeast32BitUnsigned, Perbill, RuntimeDebug, Saturating};
+
+/// Determines the additional swap fee charged on top of the pool's swap fee.
+#[derive(Clone, Copy, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum AdditionalSwapFeeMode<Balance> {
+    /// A fixed amount, regardless of the size of the trade.
+    Flat(Balance),
+    /// A share of the trade amount, raised to `min` and capped at `max` if specified. The fee
+    /// never exceeds the trade amount.
+    Percentage { rate: Perbill, min: Option<Balance>, max: Option<Balance> },
+}
+
+impl<Balance> AdditionalSwapFeeMode<Balance>
+where
+    Balance: AtLeast32BitUnsigned + Copy,
+{
+    /// Returns the fee charged on a trade of `amount` units of collateral after waiving the
+    /// `discount` share of it.
+    pub fn fee_amount(&self, amount: Balance, discount: Perbill) -> Balance {
+        let fee = match *self {
+            AdditionalSwapFeeMode::Flat(fee) => fee,
+            AdditionalSwapFeeMode::Percentage { rate, min, max } => {
+                let mut fee = rate.mul_floor(amount);
+                if let Some(min) = min {
+                    fee = fee.max(min);
+                }
+                if let Some(max) = max {
+                    fee = fee.min(max);
+                }
+                fee.min(amount)
+            },
+        };
+        fee.saturating_sub(discount.mul_floor(fee))
+    }
+
+    /// Checks that the caps of a percentage fee are in order.
+    pub(crate) fn is_valid(&self) -> bool {
+        match *self {
+            AdditionalSwapFeeMode::Percentage { min: Some(min), max: Some(max), .. } => min <= max,
+            _ => true,
+        }
+    }
+}
+
+/// A discount on the additional swap fee for accounts which have traded at least `min_volume`
+/// units of collateral.
+#[derive(Clone, Copy, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct VolumeTier<Balance> {
+    /// The trading volume from which the tier applies.
+    pub min_volume: Balance,
+    /// The share of the additional swap fee which is waived.
+    pub discount: Perbill,
+}
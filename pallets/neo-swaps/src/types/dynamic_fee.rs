@@ -0,0 +1,90 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    pallet::{BalanceOf, Config},
+    types::MaxAssets,
+};
+use frame_support::{
+    storage::bounded_vec::BoundedVec, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use prediction_market_primitives::math::fixed::FixedMul;
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{AtLeast32BitUnsigned, Zero},
+    DispatchError, RuntimeDebug, Saturating,
+};
+
+/// Parameters of a pool whose swap fee follows recent price movements.
+///
+/// The fee is `min_fee + sensitivity * volatility`, capped at `max_fee`, where `volatility` is the
+/// largest change of any spot price observed since the start of the current window. At the start
+/// of each new window, the volatility is halved for every window that has passed.
+#[derive(Clone, Copy, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct DynamicFeeParams<Balance, BlockNumber> {
+    /// The fee charged while trading is quiet.
+    pub min_fee: Balance,
+    /// The fee charged after large price movements.
+    pub max_fee: Balance,
+    /// The fee added per unit of price movement, as fixed point decimal.
+    pub sensitivity: Balance,
+    /// The number of blocks in each window.
+    pub window: BlockNumber,
+}
+
+impl<Balance, BlockNumber> DynamicFeeParams<Balance, BlockNumber>
+where
+    Balance: AtLeast32BitUnsigned + Copy,
+{
+    /// Calculates the swap fee for the specified `volatility`.
+    pub(crate) fn fee(&self, volatility: Balance) -> Result<Balance, DispatchError> {
+        let surcharge = self.sensitivity.bmul(volatility)?;
+        Ok(self.min_fee.saturating_add(surcharge).min(self.max_fee))
+    }
+}
+
+/// The price history of a pool with dynamic swap fee.
+#[derive(
+    CloneNoBound, Decode, Encode, Eq, MaxEncodedLen, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct PriceHistory<T>
+where
+    T: Config,
+{
+    /// The block in which the current window started.
+    pub window_start: BlockNumberFor<T>,
+    /// The spot prices at the start of the current window, ordered like the pool's assets.
+    pub reference_prices: BoundedVec<BalanceOf<T>, MaxAssets>,
+    /// The largest change of any spot price observed in the current window, including the decayed
+    /// volatility of previous windows.
+    pub volatility: BalanceOf<T>,
+}
+
+impl<T> PriceHistory<T>
+where
+    T: Config,
+{
+    pub(crate) fn new(
+        window_start: BlockNumberFor<T>,
+        reference_prices: BoundedVec<BalanceOf<T>, MaxAssets>,
+    ) -> Self {
+        PriceHistory { window_start, reference_prices, volatility: Zero::zero() }
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::pallet::{AssetOf, BalanceOf, Config};
+use frame_support::{CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound};
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use prediction_market_primitives::math::{
+    checked_ops_res::CheckedSubRes,
+    fixed::{FixedDiv, FixedMul},
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{CheckedDiv, Zero},
+    DispatchError, RuntimeDebug, SaturatedConversion, Saturating,
+};
+
+/// A budget of `asset` which is streamed to the LPs of a pool in proportion to their pool shares
+/// from block `start` to block `end`.
+///
+/// Rewards are tracked using an accumulator: `reward_per_share` is the total amount of rewards
+/// paid out per pool share since the program started. Each LP's claim is the difference between
+/// their current shares multiplied by the accumulator and the value of the accumulator at the time
+/// their shares last changed, which is stored in their `IncentiveReward`.
+#[derive(
+    CloneNoBound, Decode, Encode, Eq, MaxEncodedLen, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct IncentiveProgram<T>
+where
+    T: Config,
+{
+    /// The account which funded the program and receives unspent funds when the pool is
+    /// destroyed.
+    pub funder: T::AccountId,
+    /// The asset in which rewards are paid.
+    pub asset: AssetOf<T>,
+    pub start: BlockNumberFor<T>,
+    pub end: BlockNumberFor<T>,
+    /// The amount streamed to the pool per block.
+    pub reward_per_block: BalanceOf<T>,
+    /// The part of the budget which was not yet streamed to the pool.
+    pub remaining: BalanceOf<T>,
+    /// The accumulated rewards per pool share, as fixed point decimal.
+    pub reward_per_share: BalanceOf<T>,
+    /// The block up to which rewards were streamed.
+    pub last_update: BlockNumberFor<T>,
+    /// The rewards which were streamed to the pool, but not yet claimed by the LPs.
+    pub unclaimed: BalanceOf<T>,
+}
+
+impl<T> IncentiveProgram<T>
+where
+    T: Config,
+{
+    pub(crate) fn new(
+        funder: T::AccountId,
+        asset: AssetOf<T>,
+        amount: BalanceOf<T>,
+        start: BlockNumberFor<T>,
+        end: BlockNumberFor<T>,
+    ) -> Self {
+        let duration: BalanceOf<T> =
+            end.saturating_sub(start).saturated_into::<u128>().saturated_into();
+        let reward_per_block = amount.checked_div(&duration).unwrap_or_else(Zero::zero);
+        IncentiveProgram {
+            funder,
+            asset,
+            start,
+            end,
+            reward_per_block,
+            remaining: amount,
+            reward_per_share: Zero::zero(),
+            last_update: start,
+            unclaimed: Zero::zero(),
+        }
+    }
+
+    /// Streams the rewards of all blocks since the last update up to `now` to the holders of
+    /// `total_shares` pool shares. Only the amount which is actually credited to the shares is
+    /// deducted from the budget, so rounding errors are carried over to the next update and the
+    /// last block of the program pays out the rest of the budget. If there are no pool shares,
+    /// the rewards are kept. Whatever is left after the program ends is refunded to the funder
+    /// when the pool is destroyed.
+    pub(crate) fn accrue(
+        &mut self,
+        now: BlockNumberFor<T>,
+        total_shares: BalanceOf<T>,
+    ) -> Result<(), DispatchError> {
+        let until = now.min(self.end);
+        if until <= self.last_update {
+            return Ok(());
+        }
+        if total_shares.is_zero() {
+            self.last_update = until;
+            return Ok(());
+        }
+        let emitted = if until == self.end {
+            self.remaining
+        } else {
+            let blocks: BalanceOf<T> =
+                until.saturating_sub(self.last_update).saturated_into::<u128>().saturated_into();
+            self.reward_per_block.saturating_mul(blocks).min(self.remaining)
+        };
+        let reward_per_share_delta = emitted.bdiv_floor(total_shares)?;
+        // Rounding up is safe: `total_shares * reward_per_share_delta` doesn't exceed `emitted`.
+        let credited = total_shares.bmul_ceil(reward_per_share_delta)?;
+        self.reward_per_share = self.reward_per_share.saturating_add(reward_per_share_delta);
+        self.remaining = self.remaining.checked_sub_res(&credited)?;
+        self.unclaimed = self.unclaimed.saturating_add(credited);
+        self.last_update = until;
+        Ok(())
+    }
+
+    /// Returns `true` if all rewards were streamed up to the end of the program and no more than
+    /// `dust` of them is still waiting to be claimed.
+    pub(crate) fn is_finished(&self, now: BlockNumberFor<T>, dust: BalanceOf<T>) -> bool {
+        self.end <= now && self.last_update == self.end && self.unclaimed <= dust
+    }
+
+    /// The value of the accumulator for a position of `shares` pool shares.
+    pub(crate) fn reward_debt(&self, shares: BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError> {
+        shares.bmul_floor(self.reward_per_share)
+    }
+}
+
+/// The rewards of an LP in an incentive program.
+#[derive(
+    Clone, Copy, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo,
+)]
+pub struct IncentiveReward<Balance> {
+    /// The value of the program's accumulator for the LP's shares at the time they last changed.
+    pub debt: Balance,
+    /// The rewards which were accrued, but not yet claimed.
+    pub pending: Balance,
+}
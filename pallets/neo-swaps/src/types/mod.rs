@@ -15,10 +15,21 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
+mod additional_swap_fee;
+mod dynamic_fee;
 mod fee_distribution;
+mod incentive_program;
 mod max_assets;
+mod observation;
 mod pool;
+mod range_position;
 
+pub use additional_swap_fee::*;
+pub use dynamic_fee::DynamicFeeParams;
+pub(crate) use dynamic_fee::PriceHistory;
 pub(crate) use fee_distribution::*;
+pub use incentive_program::*;
 pub(crate) use max_assets::*;
+pub(crate) use observation::*;
 pub(crate) use pool::*;
+pub(crate) use range_position::*;
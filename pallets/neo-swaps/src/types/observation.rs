@@ -0,0 +1,92 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    pallet::{BalanceOf, Config},
+    types::MaxAssets,
+    Error,
+};
+use alloc::{vec, vec::Vec};
+use frame_support::{
+    storage::bounded_vec::BoundedVec, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Zero, DispatchError, SaturatedConversion, Saturating};
+
+/// A snapshot of the prices of a pool, taken in `block`.
+///
+/// The spot prices recorded in an observation are considered to hold from the end of `block`
+/// until the block of the next observation, so prices which are moved and moved back within a
+/// single block have no effect on the time-weighted average.
+#[derive(
+    CloneNoBound, Decode, Encode, Eq, MaxEncodedLen, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct Observation<T>
+where
+    T: Config,
+{
+    pub block: BlockNumberFor<T>,
+    /// The sum of the spot prices of each asset over all blocks from the first observation of the
+    /// pool up to `block`, ordered like the pool's assets.
+    pub cumulative_prices: BoundedVec<BalanceOf<T>, MaxAssets>,
+    /// The spot prices after the last update in `block`, ordered like the pool's assets.
+    pub spot_prices: BoundedVec<BalanceOf<T>, MaxAssets>,
+}
+
+impl<T> Observation<T>
+where
+    T: Config,
+{
+    pub(crate) fn new(
+        block: BlockNumberFor<T>,
+        spot_prices: BoundedVec<BalanceOf<T>, MaxAssets>,
+    ) -> Result<Self, DispatchError> {
+        let cumulative_prices = vec![Zero::zero(); spot_prices.len()]
+            .try_into()
+            .map_err(|_| Error::<T>::Unexpected)?;
+        Ok(Observation { block, cumulative_prices, spot_prices })
+    }
+
+    /// Returns the cumulative prices at `block`, which must not be before the observation.
+    pub(crate) fn cumulative_prices_at(&self, block: BlockNumberFor<T>) -> Vec<BalanceOf<T>> {
+        let elapsed: BalanceOf<T> =
+            block.saturating_sub(self.block).saturated_into::<u128>().saturated_into();
+        self.cumulative_prices
+            .iter()
+            .zip(self.spot_prices.iter())
+            .map(|(&cumulative_price, &spot_price)| {
+                cumulative_price.saturating_add(spot_price.saturating_mul(elapsed))
+            })
+            .collect()
+    }
+
+    /// Returns the observation which follows this one in `block` with the new `spot_prices`.
+    pub(crate) fn next(
+        &self,
+        block: BlockNumberFor<T>,
+        spot_prices: BoundedVec<BalanceOf<T>, MaxAssets>,
+    ) -> Result<Self, DispatchError> {
+        let cumulative_prices = self
+            .cumulative_prices_at(block)
+            .try_into()
+            .map_err(|_| Error::<T>::Unexpected)?;
+        Ok(Observation { block, cumulative_prices, spot_prices })
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    pallet::{AssetOf, BalanceOf, Config},
+    types::MaxAssets,
+};
+use frame_support::{
+    storage::bounded_vec::BoundedVec, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::traits::Zero;
+
+/// Liquidity which only applies while the spot price of `asset` is in the range `lower..=upper`.
+///
+/// While active, the position holds `shares` units of pool shares and contributes to the pool's
+/// liquidity parameter like any other LP. When the spot price leaves the range, the position's
+/// share of the reserves is taken out of the pool and parked until the price returns.
+#[derive(
+    CloneNoBound, Decode, Encode, Eq, MaxEncodedLen, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct RangePosition<T>
+where
+    T: Config,
+{
+    pub asset: AssetOf<T>,
+    pub lower: BalanceOf<T>,
+    pub upper: BalanceOf<T>,
+    /// The pool shares of the position; zero if the position is inactive.
+    pub shares: BalanceOf<T>,
+    /// The amounts held outside of the pool while the position is inactive, ordered like the
+    /// pool's assets.
+    pub parked: BoundedVec<BalanceOf<T>, MaxAssets>,
+}
+
+impl<T> RangePosition<T>
+where
+    T: Config,
+{
+    pub(crate) fn is_active(&self) -> bool {
+        self.shares != Zero::zero()
+    }
+
+    pub(crate) fn contains(&self, spot_price: BalanceOf<T>) -> bool {
+        self.lower <= spot_price && spot_price <= self.upper
+    }
+}
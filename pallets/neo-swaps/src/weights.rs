@@ -62,6 +62,23 @@ pub trait WeightInfoZeitgeist {
     fn signed_exit(n: u32) -> Weight;
     fn set_early_exit_fee_account() -> Weight;
     fn set_additional_swap_fee() -> Weight;
+    fn deploy_combinatorial_pool(n: u32) -> Weight;
+    fn join_range(n: u32) -> Weight;
+    fn exit_range(n: u32) -> Weight;
+    fn update_range_positions(m: u32) -> Weight;
+    fn deploy_pool_with_dynamic_fee(n: u32) -> Weight;
+    fn set_additional_swap_fee_schedule() -> Weight;
+    fn set_additional_swap_fee_tiers(n: u32) -> Weight;
+    fn create_incentive_program() -> Weight;
+    fn claim_incentives() -> Weight;
+    fn signed_claim_incentives() -> Weight;
+    fn join_with_collateral(n: u32) -> Weight;
+    fn exit_to_collateral(n: u32) -> Weight;
+    fn signed_join_with_collateral(n: u32) -> Weight;
+    fn signed_exit_to_collateral(n: u32) -> Weight;
+    fn buy_conditional(n: u32) -> Weight;
+    fn sell_conditional(n: u32) -> Weight;
+    fn settle_incentives() -> Weight;
 }
 
 /// Weight functions for pallet_pm_neo_swaps (automatically generated)
@@ -84,8 +101,8 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Storage: `PredictionMarkets::MarketAdmin` (r:1 w:0)
     /// Proof: `PredictionMarkets::MarketAdmin` (`max_values`: Some(1), `max_size`: Some(32), added:
     /// 527, mode: `MaxEncodedLen`) Storage: `NeoSwaps::AdditionalSwapFee` (r:1 w:1)
-    /// Proof: `NeoSwaps::AdditionalSwapFee` (`max_values`: Some(1), `max_size`: Some(16), added:
-    /// 511, mode: `MaxEncodedLen`)
+    /// Proof: `NeoSwaps::AdditionalSwapFee` (`max_values`: Some(1), `max_size`: Some(38), added:
+    /// 533, mode: `MaxEncodedLen`)
     fn set_additional_swap_fee() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `176`
@@ -95,145 +112,93 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().reads(2_u64))
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
-    /// Storage: `MarketCommons::Markets` (r:1 w:0)
-    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
-    /// mode: `MaxEncodedLen`) Storage: `NeoSwaps::Pools` (r:1 w:1)
-    /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(144746), added: 147221, mode:
-    /// `MaxEncodedLen`) Storage: `System::Account` (r:3 w:3)
-    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode:
-    /// `MaxEncodedLen`) Storage: `Tokens::Accounts` (r:129 w:129)
-    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(123), added: 2598, mode:
-    /// `MaxEncodedLen`) Storage: `Tokens::TotalIssuance` (r:128 w:128)
-    /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(43), added: 2518, mode:
-    /// `MaxEncodedLen`) The range of component `n` is `[2, 128]`.
+    /// Not benchmarked yet. Estimated as the last benchmark result plus the cost of recording a
+    /// price observation. Storage: `MarketCommons::Markets` (r:1 w:0) Storage: `NeoSwaps::Pools`
+    /// (r:1 w:1) Storage: `NeoSwaps::DynamicSwapFees` (r:1 w:0) Storage: `NeoSwaps::PriceHistories`
+    /// (r:1 w:1) Storage: `NeoSwaps::AdditionalSwapFeeSchedules` (r:1 w:0) Storage:
+    /// `NeoSwaps::AdditionalSwapFeeTiers` (r:1 w:0) Storage: `NeoSwaps::TradingVolumes` (r:1 w:1)
+    /// Storage: `System::Account` (r:3 w:3) Storage: `Tokens::Accounts` (r:129 w:129) Storage:
+    /// `Tokens::TotalIssuance` (r:128 w:128) Storage: `NeoSwaps::LatestObservations` (r:1 w:1)
+    /// Storage: `NeoSwaps::Observations` (r:1 w:1) The range of component `n` is `[2, 128]`.
     fn buy(n: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `1283 + n * (163 ±0)`
-        //  Estimated: `148211 + n * (2598 ±0)`
-        // Minimum execution time: 367_628 nanoseconds.
-        Weight::from_parts(352_364_640, 148211)
-            // Standard Error: 44_817
-            .saturating_add(Weight::from_parts(16_342_365, 0).saturating_mul(n.into()))
-            .saturating_add(T::DbWeight::get().reads(6))
+        Weight::from_parts(358_604_640, 148211)
+            .saturating_add(Weight::from_parts(19_212_365, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(13))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
-            .saturating_add(T::DbWeight::get().writes(5))
+            .saturating_add(T::DbWeight::get().writes(9))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
             .saturating_add(Weight::from_parts(0, 2598).saturating_mul(n.into()))
     }
-    /// Storage: `MarketCommons::Markets` (r:1 w:0)
-    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
-    /// mode: `MaxEncodedLen`) Storage: `NeoSwaps::Pools` (r:1 w:1)
-    /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(144746), added: 147221, mode:
-    /// `MaxEncodedLen`) Storage: `Tokens::Accounts` (r:129 w:129)
-    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(123), added: 2598, mode:
-    /// `MaxEncodedLen`) Storage: `System::Account` (r:3 w:3)
-    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode:
-    /// `MaxEncodedLen`) Storage: `Tokens::TotalIssuance` (r:128 w:128)
-    /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(43), added: 2518, mode:
-    /// `MaxEncodedLen`) The range of component `n` is `[2, 128]`.
+    /// Not benchmarked yet. Estimated as the last benchmark result plus the cost of recording a
+    /// price observation. Storage: `MarketCommons::Markets` (r:1 w:0) Storage: `NeoSwaps::Pools`
+    /// (r:1 w:1) Storage: `Tokens::Accounts` (r:129 w:129) Storage: `NeoSwaps::DynamicSwapFees`
+    /// (r:1 w:0) Storage: `NeoSwaps::PriceHistories` (r:1 w:1) Storage:
+    /// `NeoSwaps::AdditionalSwapFeeSchedules` (r:1 w:0) Storage: `NeoSwaps::AdditionalSwapFeeTiers`
+    /// (r:1 w:0) Storage: `NeoSwaps::TradingVolumes` (r:1 w:1) Storage: `System::Account` (r:3 w:3)
+    /// Storage: `Tokens::TotalIssuance` (r:128 w:128) Storage: `NeoSwaps::LatestObservations` (r:1
+    /// w:1) Storage: `NeoSwaps::Observations` (r:1 w:1) The range of component `n` is `[2, 128]`.
     fn sell(n: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `1414 + n * (163 ±0)`
-        //  Estimated: `148211 + n * (2598 ±0)`
-        // Minimum execution time: 290_977 nanoseconds.
-        Weight::from_parts(251_827_451, 148211)
-            // Standard Error: 26_730
-            .saturating_add(Weight::from_parts(23_379_744, 0).saturating_mul(n.into()))
-            .saturating_add(T::DbWeight::get().reads(6))
+        Weight::from_parts(258_067_451, 148211)
+            .saturating_add(Weight::from_parts(26_249_744, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(13))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
-            .saturating_add(T::DbWeight::get().writes(5))
+            .saturating_add(T::DbWeight::get().writes(9))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
             .saturating_add(Weight::from_parts(0, 2598).saturating_mul(n.into()))
     }
-    /// Storage: `MarketCommons::Markets` (r:1 w:0)
-    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
-    /// mode: `MaxEncodedLen`) Storage: `NeoSwaps::Pools` (r:1 w:1)
-    /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(144746), added: 147221, mode:
-    /// `MaxEncodedLen`) Storage: `Tokens::Accounts` (r:256 w:256)
-    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(123), added: 2598, mode:
-    /// `MaxEncodedLen`) Storage: `System::Account` (r:1 w:0)
-    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode:
-    /// `MaxEncodedLen`) The range of component `n` is `[2, 128]`.
+    /// Not benchmarked yet. Estimated as the last benchmark result plus the cost of recording a
+    /// price observation. Storage: `MarketCommons::Markets` (r:1 w:0) Storage: `NeoSwaps::Pools`
+    /// (r:1 w:1) Storage: `Tokens::Accounts` (r:256 w:256) Storage: `System::Account` (r:1 w:0)
+    /// Storage: `NeoSwaps::LatestObservations` (r:1 w:1) Storage: `NeoSwaps::Observations` (r:1
+    /// w:1) The range of component `n` is `[2, 128]`.
     fn join_in_place(n: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `139311 + n * (197 ±0)`
-        //  Estimated: `148211 + n * (5196 ±0)`
-        // Minimum execution time: 280_475 nanoseconds.
-        Weight::from_parts(216_619_883, 148211)
-            // Standard Error: 169_197
-            .saturating_add(Weight::from_parts(30_980_963, 0).saturating_mul(n.into()))
-            .saturating_add(T::DbWeight::get().reads(3))
+        Weight::from_parts(222_859_883, 148211)
+            .saturating_add(Weight::from_parts(33_850_963, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(5))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes(3))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
             .saturating_add(Weight::from_parts(0, 5196).saturating_mul(n.into()))
     }
-    /// Storage: `MarketCommons::Markets` (r:1 w:0)
-    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
-    /// mode: `MaxEncodedLen`) Storage: `NeoSwaps::Pools` (r:1 w:1)
-    /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(144746), added: 147221, mode:
-    /// `MaxEncodedLen`) Storage: `Tokens::Accounts` (r:256 w:256)
-    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(123), added: 2598, mode:
-    /// `MaxEncodedLen`) Storage: `System::Account` (r:1 w:0)
-    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode:
-    /// `MaxEncodedLen`) The range of component `n` is `[2, 128]`.
+    /// Not benchmarked yet. Estimated as the last benchmark result plus the cost of recording a
+    /// price observation. Storage: `MarketCommons::Markets` (r:1 w:0) Storage: `NeoSwaps::Pools`
+    /// (r:1 w:1) Storage: `Tokens::Accounts` (r:256 w:256) Storage: `System::Account` (r:1 w:0)
+    /// Storage: `NeoSwaps::LatestObservations` (r:1 w:1) Storage: `NeoSwaps::Observations` (r:1
+    /// w:1) The range of component `n` is `[2, 128]`.
     fn join_reassigned(n: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `139107 + n * (197 ±0)`
-        //  Estimated: `148211 + n * (5196 ±0)`
-        // Minimum execution time: 306_517 nanoseconds.
-        Weight::from_parts(192_506_242, 148211)
-            // Standard Error: 186_645
-            .saturating_add(Weight::from_parts(32_347_165, 0).saturating_mul(n.into()))
-            .saturating_add(T::DbWeight::get().reads(3))
+        Weight::from_parts(198_746_242, 148211)
+            .saturating_add(Weight::from_parts(35_217_165, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(5))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes(3))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
             .saturating_add(Weight::from_parts(0, 5196).saturating_mul(n.into()))
     }
-    /// Storage: `MarketCommons::Markets` (r:1 w:0)
-    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
-    /// mode: `MaxEncodedLen`) Storage: `NeoSwaps::Pools` (r:1 w:1)
-    /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(144746), added: 147221, mode:
-    /// `MaxEncodedLen`) Storage: `Tokens::Accounts` (r:256 w:256)
-    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(123), added: 2598, mode:
-    /// `MaxEncodedLen`) Storage: `System::Account` (r:1 w:0)
-    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode:
-    /// `MaxEncodedLen`) The range of component `n` is `[2, 128]`.
+    /// Not benchmarked yet. Estimated as the last benchmark result plus the cost of recording a
+    /// price observation. Storage: `MarketCommons::Markets` (r:1 w:0) Storage: `NeoSwaps::Pools`
+    /// (r:1 w:1) Storage: `Tokens::Accounts` (r:256 w:256) Storage: `System::Account` (r:1 w:0)
+    /// Storage: `NeoSwaps::LatestObservations` (r:1 w:1) Storage: `NeoSwaps::Observations` (r:1
+    /// w:1) The range of component `n` is `[2, 128]`.
     fn join_leaf(n: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `138611 + n * (197 ±0)`
-        //  Estimated: `148211 + n * (5196 ±0)`
-        // Minimum execution time: 333_727 nanoseconds.
-        Weight::from_parts(276_851_445, 148211)
-            // Standard Error: 174_609
-            .saturating_add(Weight::from_parts(31_456_446, 0).saturating_mul(n.into()))
-            .saturating_add(T::DbWeight::get().reads(3))
+        Weight::from_parts(283_091_445, 148211)
+            .saturating_add(Weight::from_parts(34_326_446, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(5))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes(3))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
             .saturating_add(Weight::from_parts(0, 5196).saturating_mul(n.into()))
     }
-    /// Storage: `MarketCommons::Markets` (r:1 w:0)
-    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
-    /// mode: `MaxEncodedLen`) Storage: `NeoSwaps::Pools` (r:1 w:1)
-    /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(144746), added: 147221, mode:
-    /// `MaxEncodedLen`) Storage: `Tokens::Accounts` (r:256 w:256)
-    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(123), added: 2598, mode:
-    /// `MaxEncodedLen`) Storage: `System::Account` (r:1 w:0)
-    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode:
-    /// `MaxEncodedLen`) The range of component `n` is `[2, 128]`.
+    /// Not benchmarked yet. Estimated as the last benchmark result plus the cost of recording a
+    /// price observation. Storage: `MarketCommons::Markets` (r:1 w:0) Storage: `NeoSwaps::Pools`
+    /// (r:1 w:1) Storage: `Tokens::Accounts` (r:256 w:256) Storage: `System::Account` (r:1 w:0)
+    /// Storage: `NeoSwaps::LatestObservations` (r:1 w:1) Storage: `NeoSwaps::Observations` (r:1
+    /// w:1) The range of component `n` is `[2, 128]`.
     fn exit(n: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `139208 + n * (197 ±0)`
-        //  Estimated: `148211 + n * (5196 ±0)`
-        // Minimum execution time: 325_537 nanoseconds.
-        Weight::from_parts(344_456_185, 148211)
-            // Standard Error: 201_865
-            .saturating_add(Weight::from_parts(30_432_204, 0).saturating_mul(n.into()))
-            .saturating_add(T::DbWeight::get().reads(3))
+        Weight::from_parts(350_696_185, 148211)
+            .saturating_add(Weight::from_parts(33_302_204, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(5))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes(3))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
             .saturating_add(Weight::from_parts(0, 5196).saturating_mul(n.into()))
     }
@@ -251,87 +216,351 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().reads(3))
             .saturating_add(T::DbWeight::get().writes(3))
     }
+    /// Not benchmarked yet. Estimated as the last benchmark result plus the cost of writing the
+    /// first price observation. Storage: `MarketCommons::Markets` (r:1 w:0) Storage:
+    /// `NeoSwaps::Pools` (r:1 w:1) Storage: `Tokens::Accounts` (r:256 w:256) Storage:
+    /// `System::Account` (r:1 w:1) Storage: `NeoSwaps::LatestObservations` (r:1 w:1) Storage:
+    /// `NeoSwaps::Observations` (r:0 w:1) The range of component `n` is `[2, 128]`.
+    fn deploy_pool(n: u32) -> Weight {
+        Weight::from_parts(113_893_447, 148211)
+            .saturating_add(Weight::from_parts(37_067_887, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 5196).saturating_mul(n.into()))
+    }
+    /// Not benchmarked yet. Estimated as the last benchmark result plus the cost of recording a
+    /// price observation. Storage: `MarketCommons::Markets` (r:1 w:0) Storage: `NeoSwaps::Pools`
+    /// (r:1 w:1) Storage: `Tokens::Accounts` (r:256 w:256) Storage: `System::Account` (r:1 w:0)
+    /// Storage: `NeoSwaps::LatestObservations` (r:1 w:1) Storage: `NeoSwaps::Observations` (r:1
+    /// w:1) The range of component `n` is `[2, 128]`.
+    fn signed_join(n: u32) -> Weight {
+        Weight::from_parts(146_767_727, 148211)
+            .saturating_add(Weight::from_parts(22_891_386, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 5196).saturating_mul(n.into()))
+    }
+    /// Storage: `NeoSwaps::Pools` (r:1 w:1)
+    /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(144746), added: 147221, mode:
+    /// `MaxEncodedLen`) Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+    /// `MaxEncodedLen`)
+    fn signed_withdraw_fees() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `556`
+        //  Estimated: `148211`
+        // Minimum execution time: 158_708_000 picoseconds.
+        Weight::from_parts(204_537_000, 148211)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Not benchmarked yet. Estimated as the last benchmark result plus the cost of recording a
+    /// price observation. Storage: `MarketCommons::Markets` (r:1 w:0) Storage: `NeoSwaps::Pools`
+    /// (r:1 w:1) Storage: `Tokens::Accounts` (r:256 w:256) Storage: `System::Account` (r:1 w:0)
+    /// Storage: `NeoSwaps::LatestObservations` (r:1 w:1) Storage: `NeoSwaps::Observations` (r:1
+    /// w:1) The range of component `n` is `[2, 128]`.
+    fn signed_exit(n: u32) -> Weight {
+        Weight::from_parts(236_075_711, 148211)
+            .saturating_add(Weight::from_parts(23_985_060, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 5196).saturating_mul(n.into()))
+    }
+    /// Not benchmarked yet. Estimated as the last benchmark result plus the cost of writing the
+    /// first price observation. Storage: `MarketCommons::Markets` (r:1 w:0) Storage:
+    /// `NeoSwaps::ConditionalPools` (r:1 w:1) Storage:
+    /// `PredictionMarkets::CombinatorialCollections` (r:1 w:0) Storage: `Tokens::Accounts` (r:258
+    /// w:258) The range of component `n` is `[2, 128]`.
+    fn deploy_combinatorial_pool(n: u32) -> Weight {
+        Weight::from_parts(145_135_318, 148211)
+            .saturating_add(Weight::from_parts(44_676_254, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 5196).saturating_mul(n.into()))
+    }
     /// Storage: `MarketCommons::Markets` (r:1 w:0)
     /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
+    /// mode: `MaxEncodedLen`) Storage: `NeoSwaps::RangePositions` (r:11 w:1)
+    /// Proof: `NeoSwaps::RangePositions` (`max_values`: None, `max_size`: Some(2153), added: 4628,
     /// mode: `MaxEncodedLen`) Storage: `NeoSwaps::Pools` (r:1 w:1)
     /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(144746), added: 147221, mode:
     /// `MaxEncodedLen`) Storage: `Tokens::Accounts` (r:256 w:256)
     /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(123), added: 2598, mode:
-    /// `MaxEncodedLen`) Storage: `System::Account` (r:1 w:1)
+    /// `MaxEncodedLen`) Storage: `System::Account` (r:1 w:0)
     /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode:
     /// `MaxEncodedLen`) The range of component `n` is `[2, 128]`.
-    fn deploy_pool(n: u32) -> Weight {
+    fn join_range(n: u32) -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `611 + n * (81 ±0)`
+        //  Measured:  `139794 + n * (197 ±0)`
         //  Estimated: `148211 + n * (5196 ±0)`
-        // Minimum execution time: 161_493 nanoseconds.
-        Weight::from_parts(109_983_447, 148211)
-            // Standard Error: 44_260
-            .saturating_add(Weight::from_parts(34_197_887, 0).saturating_mul(n.into()))
-            .saturating_add(T::DbWeight::get().reads(3))
+        // Minimum execution time: 421_806 nanoseconds.
+        Weight::from_parts(401_732_516, 148211)
+            // Standard Error: 112_487
+            .saturating_add(Weight::from_parts(27_194_731, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(14))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
             .saturating_add(T::DbWeight::get().writes(2))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
             .saturating_add(Weight::from_parts(0, 5196).saturating_mul(n.into()))
     }
-    /// Storage: `MarketCommons::Markets` (r:1 w:0)
-    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(678), added: 3153,
+    /// Storage: `NeoSwaps::RangePositions` (r:1 w:1)
+    /// Proof: `NeoSwaps::RangePositions` (`max_values`: None, `max_size`: Some(2153), added: 4628,
+    /// mode: `MaxEncodedLen`) Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
     /// mode: `MaxEncodedLen`) Storage: `NeoSwaps::Pools` (r:1 w:1)
     /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(144746), added: 147221, mode:
     /// `MaxEncodedLen`) Storage: `Tokens::Accounts` (r:256 w:256)
     /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(123), added: 2598, mode:
     /// `MaxEncodedLen`) Storage: `System::Account` (r:1 w:0)
-    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode:
     /// `MaxEncodedLen`) The range of component `n` is `[2, 128]`.
-    fn signed_join(n: u32) -> Weight {
+    fn exit_range(n: u32) -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `1008 + n * (197 ±0)`
+        //  Measured:  `139691 + n * (197 ±0)`
         //  Estimated: `148211 + n * (5196 ±0)`
-        // Minimum execution time: 164_880_000 picoseconds.
-        Weight::from_parts(140_527_727, 148211)
-            // Standard Error: 113_137
-            .saturating_add(Weight::from_parts(20_021_386, 0).saturating_mul(n.into()))
-            .saturating_add(T::DbWeight::get().reads(3_u64))
+        // Minimum execution time: 341_205 nanoseconds.
+        Weight::from_parts(359_118_402, 148211)
+            // Standard Error: 198_304
+            .saturating_add(Weight::from_parts(31_027_866, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(4))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
-            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
             .saturating_add(Weight::from_parts(0, 5196).saturating_mul(n.into()))
     }
-    /// Storage: `NeoSwaps::Pools` (r:1 w:1)
+    /// Storage: `NeoSwaps::RangePositions` (r:10 w:10)
+    /// Proof: `NeoSwaps::RangePositions` (`max_values`: None, `max_size`: Some(2153), added: 4628,
+    /// mode: `MaxEncodedLen`) The range of component `m` is `[0, 10]`.
+    fn update_range_positions(m: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `138917 + m * (1083 ±0)`
+        //  Estimated: `148211 + m * (4628 ±0)`
+        // Minimum execution time: 4_217 nanoseconds.
+        Weight::from_parts(5_073_000, 148211)
+            // Standard Error: 96_513
+            .saturating_add(Weight::from_parts(48_366_274, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+            .saturating_add(Weight::from_parts(0, 4628).saturating_mul(m.into()))
+    }
+    /// Not benchmarked yet. Estimated as the last benchmark result plus the cost of writing the
+    /// first price observation. Storage: `MarketCommons::Markets` (r:1 w:0) Storage:
+    /// `NeoSwaps::Pools` (r:1 w:1) Storage: `Tokens::Accounts` (r:256 w:256) Storage:
+    /// `System::Account` (r:1 w:1) Storage: `NeoSwaps::DynamicSwapFees` (r:0 w:1) Storage:
+    /// `NeoSwaps::PriceHistories` (r:0 w:1) Storage: `NeoSwaps::LatestObservations` (r:1 w:1)
+    /// Storage: `NeoSwaps::Observations` (r:0 w:1) The range of component `n` is `[2, 128]`.
+    fn deploy_pool_with_dynamic_fee(n: u32) -> Weight {
+        Weight::from_parts(120_117_183, 148211)
+            .saturating_add(Weight::from_parts(40_722_406, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(6))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 5196).saturating_mul(n.into()))
+    }
+    /// Storage: `PredictionMarkets::MarketAdmin` (r:1 w:0)
+    /// Proof: `PredictionMarkets::MarketAdmin` (`max_values`: Some(1), `max_size`: Some(32), added:
+    /// 527, mode: `MaxEncodedLen`) Storage: `NeoSwaps::AdditionalSwapFeeSchedules` (r:0 w:1)
+    /// Proof: `NeoSwaps::AdditionalSwapFeeSchedules` (`max_values`: None, `max_size`: Some(70),
+    /// added: 2545, mode: `MaxEncodedLen`)
+    fn set_additional_swap_fee_schedule() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `176`
+        //  Estimated: `1517`
+        // Minimum execution time: 18_862_000 picoseconds.
+        Weight::from_parts(21_104_000, 1517)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `PredictionMarkets::MarketAdmin` (r:1 w:0)
+    /// Proof: `PredictionMarkets::MarketAdmin` (`max_values`: Some(1), `max_size`: Some(32), added:
+    /// 527, mode: `MaxEncodedLen`) Storage: `NeoSwaps::AdditionalSwapFeeTiers` (r:0 w:1)
+    /// Proof: `NeoSwaps::AdditionalSwapFeeTiers` (`max_values`: Some(1), `max_size`: Some(201),
+    /// added: 696, mode: `MaxEncodedLen`) The range of component `n` is `[0, 10]`.
+    fn set_additional_swap_fee_tiers(n: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `176`
+        //  Estimated: `1517`
+        // Minimum execution time: 18_517_000 picoseconds.
+        Weight::from_parts(20_309_512, 1517)
+            // Standard Error: 2_903
+            .saturating_add(Weight::from_parts(196_834, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
+    /// mode: `MaxEncodedLen`) Storage: `PredictionMarkets::MarketAdmin` (r:1 w:0)
+    /// Proof: `PredictionMarkets::MarketAdmin` (`max_values`: Some(1), `max_size`: Some(32), added:
+    /// 527, mode: `MaxEncodedLen`) Storage: `NeoSwaps::Pools` (r:1 w:0)
     /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(144746), added: 147221, mode:
-    /// `MaxEncodedLen`) Storage: `System::Account` (r:2 w:2)
-    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+    /// `MaxEncodedLen`) Storage: `NeoSwaps::NextIncentiveProgramId` (r:1 w:1)
+    /// Proof: `NeoSwaps::NextIncentiveProgramId` (`max_values`: None, `max_size`: Some(28), added:
+    /// 2503, mode: `MaxEncodedLen`) Storage: `Tokens::Accounts` (r:2 w:2)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(123), added: 2598, mode:
+    /// `MaxEncodedLen`) Storage: `NeoSwaps::IncentivePrograms` (r:0 w:1)
+    /// Proof: `NeoSwaps::IncentivePrograms` (`max_values`: None, `max_size`: Some(163), added:
+    /// 2638, mode: `MaxEncodedLen`)
+    fn create_incentive_program() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `2412`
+        //  Estimated: `148211`
+        // Minimum execution time: 71_306_000 picoseconds.
+        Weight::from_parts(74_839_000, 148211)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `NeoSwaps::NextIncentiveProgramId` (r:1 w:0)
+    /// Proof: `NeoSwaps::NextIncentiveProgramId` (`max_values`: None, `max_size`: Some(28), added:
+    /// 2503, mode: `MaxEncodedLen`) Storage: `NeoSwaps::Pools` (r:1 w:0)
+    /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(144746), added: 147221, mode:
+    /// `MaxEncodedLen`) Storage: `NeoSwaps::IncentivePrograms` (r:11 w:10)
+    /// Proof: `NeoSwaps::IncentivePrograms` (`max_values`: None, `max_size`: Some(163), added:
+    /// 2638, mode: `MaxEncodedLen`) Storage: `NeoSwaps::IncentiveRewards` (r:10 w:10)
+    /// Proof: `NeoSwaps::IncentiveRewards` (`max_values`: None, `max_size`: Some(112), added:
+    /// 2587, mode: `MaxEncodedLen`) Storage: `Tokens::Accounts` (r:20 w:20)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(123), added: 2598, mode:
     /// `MaxEncodedLen`)
-    fn signed_withdraw_fees() -> Weight {
+    fn claim_incentives() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `556`
+        //  Measured:  `140894`
         //  Estimated: `148211`
-        // Minimum execution time: 158_708_000 picoseconds.
-        Weight::from_parts(204_537_000, 148211)
-            .saturating_add(T::DbWeight::get().reads(3_u64))
-            .saturating_add(T::DbWeight::get().writes(3_u64))
+        // Minimum execution time: 612_118_000 picoseconds.
+        Weight::from_parts(640_553_000, 148211)
+            .saturating_add(T::DbWeight::get().reads(43_u64))
+            .saturating_add(T::DbWeight::get().writes(40_u64))
     }
-    /// Storage: `MarketCommons::Markets` (r:1 w:0)
-    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(678), added: 3153,
-    /// mode: `MaxEncodedLen`) Storage: `NeoSwaps::Pools` (r:1 w:1)
+    /// Storage: `NeoSwaps::NextIncentiveProgramId` (r:1 w:0)
+    /// Proof: `NeoSwaps::NextIncentiveProgramId` (`max_values`: None, `max_size`: Some(28), added:
+    /// 2503, mode: `MaxEncodedLen`) Storage: `NeoSwaps::Pools` (r:1 w:0)
     /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(144746), added: 147221, mode:
-    /// `MaxEncodedLen`) Storage: `Tokens::Accounts` (r:256 w:256)
+    /// `MaxEncodedLen`) Storage: `NeoSwaps::IncentivePrograms` (r:11 w:10)
+    /// Proof: `NeoSwaps::IncentivePrograms` (`max_values`: None, `max_size`: Some(163), added:
+    /// 2638, mode: `MaxEncodedLen`) Storage: `NeoSwaps::IncentiveRewards` (r:10 w:10)
+    /// Proof: `NeoSwaps::IncentiveRewards` (`max_values`: None, `max_size`: Some(112), added:
+    /// 2587, mode: `MaxEncodedLen`) Storage: `Tokens::Accounts` (r:20 w:20)
     /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(123), added: 2598, mode:
-    /// `MaxEncodedLen`) Storage: `System::Account` (r:1 w:0)
-    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
-    /// `MaxEncodedLen`) The range of component `n` is `[2, 128]`.
-    fn signed_exit(n: u32) -> Weight {
+    /// `MaxEncodedLen`)
+    fn signed_claim_incentives() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `1085 + n * (197 ±0)`
-        //  Estimated: `148211 + n * (5196 ±0)`
-        // Minimum execution time: 185_643_000 picoseconds.
-        Weight::from_parts(229_835_711, 148211)
-            // Standard Error: 248_712
-            .saturating_add(Weight::from_parts(21_115_060, 0).saturating_mul(n.into()))
-            .saturating_add(T::DbWeight::get().reads(3_u64))
+        //  Measured:  `140894`
+        //  Estimated: `148211`
+        // Minimum execution time: 681_950_000 picoseconds.
+        Weight::from_parts(709_417_000, 148211)
+            .saturating_add(T::DbWeight::get().reads(43_u64))
+            .saturating_add(T::DbWeight::get().writes(40_u64))
+    }
+    /// Not benchmarked yet. Estimated as the last benchmark result plus the cost of recording a
+    /// price observation. Storage: `MarketCommons::Markets` (r:1 w:0) Storage: `NeoSwaps::Pools`
+    /// (r:1 w:1) Storage: `Tokens::Accounts` (r:257 w:257) Storage: `Tokens::TotalIssuance` (r:128
+    /// w:128) Storage: `NeoSwaps::NextIncentiveProgramId` (r:1 w:0) Storage:
+    /// `NeoSwaps::PriceHistories` (r:1 w:1) Storage: `NeoSwaps::LatestObservations` (r:1 w:1)
+    /// Storage: `NeoSwaps::Observations` (r:1 w:1) The range of component `n` is `[2, 128]`.
+    fn join_with_collateral(n: u32) -> Weight {
+        Weight::from_parts(484_352_904, 148211)
+            .saturating_add(Weight::from_parts(91_331_207, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(5))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 7794).saturating_mul(n.into()))
+    }
+    /// Not benchmarked yet. Estimated as the last benchmark result plus the cost of recording a
+    /// price observation. Storage: `MarketCommons::Markets` (r:1 w:0) Storage: `NeoSwaps::Pools`
+    /// (r:1 w:1) Storage: `Tokens::Accounts` (r:257 w:257) Storage: `Tokens::TotalIssuance` (r:128
+    /// w:128) Storage: `NeoSwaps::NextIncentiveProgramId` (r:1 w:0) Storage:
+    /// `NeoSwaps::PriceHistories` (r:1 w:1) Storage: `NeoSwaps::LatestObservations` (r:1 w:1)
+    /// Storage: `NeoSwaps::Observations` (r:1 w:1) The range of component `n` is `[2, 128]`.
+    fn exit_to_collateral(n: u32) -> Weight {
+        Weight::from_parts(461_949_311, 148211)
+            .saturating_add(Weight::from_parts(89_800_442, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(5))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 7794).saturating_mul(n.into()))
+    }
+    /// Not benchmarked yet. Estimated as the last benchmark result plus the cost of recording a
+    /// price observation. Storage: `MarketCommons::Markets` (r:1 w:0) Storage: `NeoSwaps::Pools`
+    /// (r:1 w:1) Storage: `Tokens::Accounts` (r:257 w:257) Storage: `Tokens::TotalIssuance` (r:128
+    /// w:128) Storage: `NeoSwaps::NextIncentiveProgramId` (r:1 w:0) Storage:
+    /// `NeoSwaps::PriceHistories` (r:1 w:1) Storage: `NeoSwaps::LatestObservations` (r:1 w:1)
+    /// Storage: `NeoSwaps::Observations` (r:1 w:1) The range of component `n` is `[2, 128]`.
+    fn signed_join_with_collateral(n: u32) -> Weight {
+        Weight::from_parts(552_623_120, 148211)
+            .saturating_add(Weight::from_parts(91_385_863, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(5))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 7794).saturating_mul(n.into()))
+    }
+    /// Not benchmarked yet. Estimated as the last benchmark result plus the cost of recording a
+    /// price observation. Storage: `MarketCommons::Markets` (r:1 w:0) Storage: `NeoSwaps::Pools`
+    /// (r:1 w:1) Storage: `Tokens::Accounts` (r:257 w:257) Storage: `Tokens::TotalIssuance` (r:128
+    /// w:128) Storage: `NeoSwaps::NextIncentiveProgramId` (r:1 w:0) Storage:
+    /// `NeoSwaps::PriceHistories` (r:1 w:1) Storage: `NeoSwaps::LatestObservations` (r:1 w:1)
+    /// Storage: `NeoSwaps::Observations` (r:1 w:1) The range of component `n` is `[2, 128]`.
+    fn signed_exit_to_collateral(n: u32) -> Weight {
+        Weight::from_parts(529_304_985, 148211)
+            .saturating_add(Weight::from_parts(89_864_016, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(5))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 7794).saturating_mul(n.into()))
+    }
+    /// Not benchmarked yet. Estimated as `buy` without the storage of regular pools plus the
+    /// storage of splitting the parent position. Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Storage: `NeoSwaps::ConditionalPools` (r:1 w:1) Storage:
+    /// `NeoSwaps::AdditionalSwapFeeSchedules` (r:1 w:0) Storage: `NeoSwaps::AdditionalSwapFeeTiers`
+    /// (r:1 w:0) Storage: `NeoSwaps::TradingVolumes` (r:1 w:1) Storage: `System::Account` (r:3
+    /// w:3) Storage: `PredictionMarkets::CombinatorialCollections` (r:128 w:128) Storage:
+    /// `Tokens::Accounts` (r:129 w:129) Storage: `Tokens::TotalIssuance` (r:128 w:128) The range
+    /// of component `n` is `[2, 128]`.
+    fn buy_conditional(n: u32) -> Weight {
+        Weight::from_parts(358_604_640, 148211)
+            .saturating_add(Weight::from_parts(43_013_742, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(9))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(6))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2780).saturating_mul(n.into()))
+    }
+    /// Not benchmarked yet. Estimated as `sell` without the storage of regular pools plus the
+    /// storage of merging into the parent position. Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Storage: `NeoSwaps::ConditionalPools` (r:1 w:1) Storage:
+    /// `NeoSwaps::AdditionalSwapFeeSchedules` (r:1 w:0) Storage: `NeoSwaps::AdditionalSwapFeeTiers`
+    /// (r:1 w:0) Storage: `NeoSwaps::TradingVolumes` (r:1 w:1) Storage: `System::Account` (r:3
+    /// w:3) Storage: `Tokens::Accounts` (r:129 w:129) Storage: `Tokens::TotalIssuance` (r:128
+    /// w:128) The range of component `n` is `[2, 128]`.
+    fn sell_conditional(n: u32) -> Weight {
+        Weight::from_parts(258_067_451, 148211)
+            .saturating_add(Weight::from_parts(45_296_357, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(9))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
-            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes(6))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
-            .saturating_add(Weight::from_parts(0, 5196).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, 2612).saturating_mul(n.into()))
+    }
+    /// Not benchmarked yet. Estimated as the part of `claim_incentives` which doesn't transfer
+    /// rewards, with `IncentiveRewards` read and written twice per program.
+    /// Storage: `NeoSwaps::IncentivePrograms` (r:22 w:10)
+    /// Proof: `NeoSwaps::IncentivePrograms` (`max_values`: None, `max_size`: Some(179), added:
+    /// 2654, mode: `MaxEncodedLen`) Storage: `NeoSwaps::IncentiveRewards` (r:20 w:20)
+    /// Proof: `NeoSwaps::IncentiveRewards` (`max_values`: None, `max_size`: Some(112), added:
+    /// 2587, mode: `MaxEncodedLen`)
+    fn settle_incentives() -> Weight {
+        Weight::from_parts(262_818_000, 55064)
+            .saturating_add(T::DbWeight::get().reads(42_u64))
+            .saturating_add(T::DbWeight::get().writes(30_u64))
     }
 }
@@ -20,7 +20,7 @@
 
 use libfuzzer_sys::fuzz_target;
 use prediction_market_primitives::types::{Asset, ScalarPosition};
-use pallet_pm_order_book::mock::{ExtBuilder, Orderbook, RuntimeOrigin};
+use pallet_pm_order_book::mock::{get_account, ExtBuilder, Orderbook, RuntimeOrigin};
 
 #[cfg(feature = "arbitrary")]
 use arbitrary::{Arbitrary, Result, Unstructured};
@@ -33,32 +33,34 @@ fuzz_target!(|data: Data| {
         let taker_asset = asset(data.taker_asset);
 
         let _ = Orderbook::place_order(
-            RuntimeOrigin::signed(data.fill_order_origin.into()),
+            RuntimeOrigin::signed(get_account(data.fill_order_origin)),
             data.market_id,
             maker_asset,
             data.maker_amount,
             taker_asset,
             data.taker_amount,
+            None,
         );
 
         let _ = Orderbook::fill_order(
-            RuntimeOrigin::signed(data.fill_order_origin.into()),
+            RuntimeOrigin::signed(get_account(data.fill_order_origin)),
             data.order_id,
             maker_partial_fill(data.maker_partial_fill),
         );
 
         // Make arbitrary order and attempt to remove
         let _ = Orderbook::place_order(
-            RuntimeOrigin::signed(data.place_order_origin.into()),
+            RuntimeOrigin::signed(get_account(data.place_order_origin)),
             data.market_id,
             maker_asset,
             data.maker_amount,
             taker_asset,
             data.taker_amount,
+            None,
         );
 
         let _ = Orderbook::remove_order(
-            RuntimeOrigin::signed(data.remove_order_origin.into()),
+            RuntimeOrigin::signed(get_account(data.remove_order_origin)),
             data.order_id,
         );
     });
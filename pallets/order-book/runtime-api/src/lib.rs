@@ -0,0 +1,51 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use parity_scale_codec::{Codec, HasCompact, MaxEncodedLen};
+use prediction_market_primitives::{
+    orderbook::{OrderBookDepth, OrderId},
+    types::Asset,
+};
+use sp_runtime::DispatchError;
+
+sp_api::decl_runtime_apis! {
+    pub trait OrderBookApi<AccountId, MarketId, Balance> where
+        AccountId: Codec,
+        MarketId: Codec + HasCompact + MaxEncodedLen,
+        Balance: Codec,
+    {
+        /// Returns the IDs of the open orders of `market_id` in the order they were placed.
+        fn market_orders(market_id: MarketId) -> Vec<OrderId>;
+
+        /// Returns the IDs of the open orders of `maker` in the order they were placed.
+        fn maker_orders(maker: AccountId) -> Vec<OrderId>;
+
+        /// Returns up to `depth` of the best bids and asks for `outcome_asset` of `market_id`,
+        /// best price first.
+        fn best_orders(
+            market_id: MarketId,
+            outcome_asset: Asset<MarketId>,
+            depth: u32,
+        ) -> Result<OrderBookDepth<AccountId, Balance, MarketId>, DispatchError>;
+    }
+}
@@ -31,14 +31,19 @@ use frame_support::traits::UnfilteredDispatchable;
 use frame_system::RawOrigin;
 use orml_traits::MultiCurrency;
 use prediction_market_primitives::types::Asset;
-use sp_runtime::SaturatedConversion;
+use sp_core::{crypto::DEV_PHRASE, H256};
+use sp_runtime::{RuntimeAppPublic, SaturatedConversion};
+
+fn fund_account<T: Config>(acc: &T::AccountId, asset: AssetOf<T>) -> DispatchResult {
+    T::AssetManager::deposit(asset, acc, BASE.saturating_mul(1_100).saturated_into())
+}
 
 fn generate_funded_account<T: Config>(
     seed: Option<u32>,
     asset: AssetOf<T>,
 ) -> Result<T::AccountId, &'static str> {
     let acc = if let Some(s) = seed { account("AssetHolder", 0, s) } else { whitelisted_caller() };
-    T::AssetManager::deposit(asset, &acc, BASE.saturating_mul(1_100).saturated_into())?;
+    fund_account::<T>(&acc, asset)?;
     Ok(acc)
 }
 
@@ -58,32 +63,204 @@ fn order_common_parameters<T: Config>(
     Ok((market_id, acc, maker_asset, maker_amount, taker_amount))
 }
 
+/// Returns `len` order IDs which don't collide with the IDs of placed orders.
+fn dummy_order_ids(len: u32) -> Vec<OrderId> {
+    (0..len).map(|i| OrderId::MAX - OrderId::from(i)).collect()
+}
+
+/// Fills the market and outcome asset index entries an order for `outcome_asset` is added to
+/// with `len` dummy order IDs.
+fn fill_market_indexes<T: Config>(
+    market_id: MarketIdOf<T>,
+    outcome_asset: AssetOf<T>,
+    side: OrderSide,
+    len: u32,
+) {
+    let market_order_ids = MarketOrderIdsOf::<T>::truncate_from(dummy_order_ids(len));
+    <MarketOrders<T>>::insert(market_id, market_order_ids.clone());
+    <OutcomeAssetOrders<T>>::insert(outcome_asset, side, market_order_ids);
+}
+
+/// Fills the index entry of `maker` with `len` dummy order IDs.
+fn fill_maker_index<T: Config>(maker: &T::AccountId, len: u32) {
+    <MakerOrders<T>>::insert(maker, MakerOrderIdsOf::<T>::truncate_from(dummy_order_ids(len)));
+}
+
+/// Fills the index entries an order of `maker` for `outcome_asset` is added to up to one below
+/// their bounds, so that the benchmarks measure the largest index entries.
+fn fill_order_indexes<T: Config>(
+    market_id: MarketIdOf<T>,
+    outcome_asset: AssetOf<T>,
+    side: OrderSide,
+    maker: &T::AccountId,
+) {
+    fill_market_indexes::<T>(market_id, outcome_asset, side, T::MaxOrdersPerMarket::get() - 1);
+    fill_maker_index::<T>(maker, T::MaxOrdersPerMaker::get() - 1);
+}
+
+/// Pushes `n` markets and fills their index entries up to one below their bounds, so that each
+/// order of a batch is added to different index entries of the largest size.
+fn batch_markets<T: Config>(n: u32) -> Result<Vec<MarketIdOf<T>>, DispatchError> {
+    let mut market_ids = Vec::new();
+    for _ in 0..n {
+        let market_id = T::MarketCommons::push_market(market_mock::<T>())?;
+        let outcome_asset = Asset::CategoricalOutcome(market_id, 0);
+        fill_market_indexes::<T>(
+            market_id,
+            outcome_asset,
+            OrderSide::Bid,
+            T::MaxOrdersPerMarket::get() - 1,
+        );
+        market_ids.push(market_id);
+    }
+    Ok(market_ids)
+}
+
 fn place_default_order<T: Config>(
     seed: Option<u32>,
     taker_asset: AssetOf<T>,
+    index_len: u32,
 ) -> Result<(T::AccountId, MarketIdOf<T>, OrderId), &'static str> {
     let (market_id, acc, maker_asset, maker_amount, taker_amount) =
         order_common_parameters::<T>(seed)?;
+    let order_id = place_order_of::<T>(
+        &acc,
+        market_id,
+        maker_asset,
+        maker_amount,
+        taker_asset,
+        taker_amount,
+        index_len,
+    )?;
+    Ok((acc, market_id, order_id))
+}
+
+/// Places an order of `maker`, after which the market and outcome asset index entries of the
+/// order contain `index_len` orders and the index entry of `maker` contains as many orders as its
+/// bound allows, but no more than `index_len`.
+fn place_order_of<T: Config>(
+    maker: &T::AccountId,
+    market_id: MarketIdOf<T>,
+    maker_asset: AssetOf<T>,
+    maker_amount: BalanceOf<T>,
+    taker_asset: AssetOf<T>,
+    taker_amount: BalanceOf<T>,
+    index_len: u32,
+) -> Result<OrderId, &'static str> {
+    fill_market_indexes::<T>(market_id, taker_asset, OrderSide::Bid, index_len - 1);
+    fill_maker_index::<T>(maker, index_len.min(T::MaxOrdersPerMaker::get()) - 1);
 
     let order_id = <NextOrderId<T>>::get();
-    let _ =
-        Call::<T>::place_order { market_id, maker_asset, maker_amount, taker_asset, taker_amount }
-            .dispatch_bypass_filter(RawOrigin::Signed(acc.clone()).into())?;
+    // Orders with an expiry are the worst case since filling them checks the current moment.
+    let expiry = Some(OrderExpiry::Timestamp(Moment::MAX));
+    let _ = Call::<T>::place_order {
+        market_id,
+        maker_asset,
+        maker_amount,
+        taker_asset,
+        taker_amount,
+        expiry,
+    }
+    .dispatch_bypass_filter(RawOrigin::Signed(maker.clone()).into())?;
 
-    Ok((acc, market_id, order_id))
+    Ok(order_id)
+}
+
+/// Returns a batch of orders which offer `maker_asset` for the first outcome of each of the
+/// `market_ids`.
+fn batch_order_params<T: Config>(
+    market_ids: &[MarketIdOf<T>],
+    maker_asset: AssetOf<T>,
+) -> OrderParamsBatchOf<T> {
+    let orders = market_ids
+        .iter()
+        .map(|market_id| OrderParams {
+            market_id: *market_id,
+            maker_asset,
+            maker_amount: BASE.saturated_into(),
+            taker_asset: Asset::CategoricalOutcome(*market_id, 0),
+            taker_amount: BASE.saturated_into(),
+            expiry: Some(OrderExpiry::Timestamp(Moment::MAX)),
+        })
+        .collect::<Vec<_>>();
+    OrderParamsBatchOf::<T>::truncate_from(orders)
+}
+
+fn place_order_batch<T: Config>(
+    maker: &T::AccountId,
+    orders: OrderParamsBatchOf<T>,
+) -> Result<OrderIdBatchOf<T>, &'static str> {
+    let first_order_id = <NextOrderId<T>>::get();
+    Orderbook::<T>::place_orders(
+        RawOrigin::Signed(maker.clone()).into(),
+        orders,
+        BatchMode::AllOrNothing,
+    )?;
+    let order_ids = (first_order_id..<NextOrderId<T>>::get()).collect::<Vec<_>>();
+    Ok(OrderIdBatchOf::<T>::truncate_from(order_ids))
+}
+
+fn into_bytes<T: Config>(account: &<T as pallet_avn::Config>::AuthorityId) -> [u8; 32]
+where
+    T: Config + pallet_avn::Config,
+{
+    let bytes = account.encode();
+    let mut vector: [u8; 32] = Default::default();
+    vector.copy_from_slice(&bytes[0..32]);
+    vector
+}
+
+fn get_user_account<T: Config>() -> (<T as pallet_avn::Config>::AuthorityId, T::AccountId)
+where
+    T: Config + pallet_avn::Config,
+{
+    let mnemonic: &str = DEV_PHRASE;
+    let key_pair =
+        <T as pallet_avn::Config>::AuthorityId::generate_pair(Some(mnemonic.as_bytes().to_vec()));
+    let account_bytes = into_bytes::<T>(&key_pair);
+    let account_id = T::AccountId::decode(&mut &account_bytes.encode()[..]).unwrap();
+    (key_pair, account_id)
+}
+
+fn get_relayer<T: Config>() -> T::AccountId {
+    let relayer_account: H256 = H256::repeat_byte(1);
+    T::AccountId::decode(&mut relayer_account.as_bytes()).expect("valid relayer account id")
+}
+
+fn get_proof<T>(
+    key_pair: &<T as pallet_avn::Config>::AuthorityId,
+    signer: T::AccountId,
+    relayer: T::AccountId,
+    signed_payload: &[u8],
+) -> Proof<T::Signature, T::AccountId>
+where
+    T: Config + pallet_avn::Config,
+{
+    let signature = key_pair.sign(&signed_payload).unwrap().encode();
+    Proof {
+        signer,
+        relayer,
+        signature: sp_core::sr25519::Signature::from_slice(&signature).unwrap().into(),
+    }
 }
 
 benchmarks! {
+    where_clause { where T: pallet_avn::Config }
+
     remove_order {
+        let o in 1 .. T::MaxOrdersPerMarket::get();
+
         let market_id = 0u32.into();
         let taker_asset = Asset::CategoricalOutcome::<MarketIdOf<T>>(market_id, 0);
-        let (caller, _, order_id) = place_default_order::<T>(None, taker_asset)?;
+        let (caller, _, order_id) = place_default_order::<T>(None, taker_asset, o)?;
     }: remove_order(RawOrigin::Signed(caller), order_id)
 
     fill_order {
+        let o in 1 .. T::MaxOrdersPerMarket::get();
+
         let market_id = 0u32.into();
         let taker_asset = Asset::CategoricalOutcome::<MarketIdOf<T>>(market_id, 0);
-        let (_, _, order_id) = place_default_order::<T>(Some(0), taker_asset)?;
+        let (_, _, order_id) = place_default_order::<T>(Some(0), taker_asset, o)?;
         let caller = generate_funded_account::<T>(None, taker_asset)?;
         let maker_asset = T::MarketCommons::market(&market_id).unwrap().base_asset;
         let caller = generate_funded_account::<T>(None, maker_asset)?;
@@ -93,6 +270,7 @@ benchmarks! {
         let (market_id, caller, maker_asset, maker_amount, taker_amount) =
             order_common_parameters::<T>(None)?;
         let taker_asset = Asset::CategoricalOutcome::<MarketIdOf<T>>(market_id, 0);
+        fill_order_indexes::<T>(market_id, taker_asset, OrderSide::Bid, &caller);
     }: {
         Orderbook::<T>::place_order(
             RawOrigin::Signed(caller).into(),
@@ -101,9 +279,117 @@ benchmarks! {
             maker_amount,
             taker_asset,
             taker_amount,
+            Some(OrderExpiry::Timestamp(Moment::MAX)),
         )?;
     }
 
+    signed_remove_order {
+        let o in 1 .. T::MaxOrdersPerMarket::get();
+
+        let relayer = get_relayer::<T>();
+        let (key_pair, caller) = get_user_account::<T>();
+        let (market_id, _, maker_asset, maker_amount, taker_amount) =
+            order_common_parameters::<T>(None)?;
+        fund_account::<T>(&caller, maker_asset)?;
+        let taker_asset = Asset::CategoricalOutcome::<MarketIdOf<T>>(market_id, 0);
+        let order_id = place_order_of::<T>(
+            &caller,
+            market_id,
+            maker_asset,
+            maker_amount,
+            taker_asset,
+            taker_amount,
+            o,
+        )?;
+        let signed_payload =
+            Orderbook::<T>::encode_signed_remove_order_params(&relayer, 0, &order_id);
+        let proof = get_proof::<T>(&key_pair, caller.clone(), relayer, &signed_payload);
+    }: signed_remove_order(RawOrigin::Signed(caller), proof, order_id)
+
+    signed_fill_order {
+        let o in 1 .. T::MaxOrdersPerMarket::get();
+
+        let relayer = get_relayer::<T>();
+        let (key_pair, caller) = get_user_account::<T>();
+        let market_id = 0u32.into();
+        let taker_asset = Asset::CategoricalOutcome::<MarketIdOf<T>>(market_id, 0);
+        let (_, _, order_id) = place_default_order::<T>(Some(0), taker_asset, o)?;
+        let maker_asset = T::MarketCommons::market(&market_id).unwrap().base_asset;
+        fund_account::<T>(&caller, taker_asset)?;
+        fund_account::<T>(&caller, maker_asset)?;
+        let signed_payload =
+            Orderbook::<T>::encode_signed_fill_order_params(&relayer, 0, &order_id, &None);
+        let proof = get_proof::<T>(&key_pair, caller.clone(), relayer, &signed_payload);
+    }: signed_fill_order(RawOrigin::Signed(caller), proof, order_id, None)
+
+    signed_place_order {
+        let relayer = get_relayer::<T>();
+        let (key_pair, caller) = get_user_account::<T>();
+        let (market_id, _, maker_asset, maker_amount, taker_amount) =
+            order_common_parameters::<T>(None)?;
+        fund_account::<T>(&caller, maker_asset)?;
+        let taker_asset = Asset::CategoricalOutcome::<MarketIdOf<T>>(market_id, 0);
+        fill_order_indexes::<T>(market_id, taker_asset, OrderSide::Bid, &caller);
+        let expiry = Some(OrderExpiry::Timestamp(Moment::MAX));
+        let signed_payload = Orderbook::<T>::encode_signed_place_order_params(
+            &relayer,
+            0,
+            &market_id,
+            &maker_asset,
+            &maker_amount,
+            &taker_asset,
+            &taker_amount,
+            &expiry,
+        );
+        let proof = get_proof::<T>(&key_pair, caller.clone(), relayer, &signed_payload);
+    }: signed_place_order(
+        RawOrigin::Signed(caller),
+        proof,
+        market_id,
+        maker_asset,
+        maker_amount,
+        taker_asset,
+        taker_amount,
+        expiry
+    )
+
+    place_orders {
+        let n in 1 .. T::MaxOrdersPerBatch::get();
+
+        let maker_asset = market_mock::<T>().base_asset;
+        let caller = generate_funded_account::<T>(None, maker_asset)?;
+        let market_ids = batch_markets::<T>(n)?;
+        fill_maker_index::<T>(&caller, T::MaxOrdersPerMaker::get() - n);
+        let orders = batch_order_params::<T>(&market_ids, maker_asset);
+    }: _(RawOrigin::Signed(caller), orders, BatchMode::SkipFailed)
+
+    remove_orders {
+        let n in 1 .. T::MaxOrdersPerBatch::get();
+
+        let maker_asset = market_mock::<T>().base_asset;
+        let caller = generate_funded_account::<T>(None, maker_asset)?;
+        let market_ids = batch_markets::<T>(n)?;
+        fill_maker_index::<T>(&caller, T::MaxOrdersPerMaker::get() - n);
+        let orders = batch_order_params::<T>(&market_ids, maker_asset);
+        let order_ids = place_order_batch::<T>(&caller, orders)?;
+    }: _(RawOrigin::Signed(caller), order_ids, BatchMode::SkipFailed)
+
+    replace_orders {
+        let n in 1 .. T::MaxOrdersPerBatch::get();
+        let m in 1 .. T::MaxOrdersPerBatch::get();
+
+        let maker_asset = market_mock::<T>().base_asset;
+        let caller = generate_funded_account::<T>(None, maker_asset)?;
+        // The removed and placed orders belong to different markets, so that none of the index
+        // entries are shared.
+        let market_ids = batch_markets::<T>(n + m)?;
+        fill_maker_index::<T>(&caller, T::MaxOrdersPerMaker::get() - n.max(m));
+        let (removed_market_ids, placed_market_ids) = market_ids.split_at(n as usize);
+        let orders = batch_order_params::<T>(removed_market_ids, maker_asset);
+        let order_ids = place_order_batch::<T>(&caller, orders)?;
+        let orders = batch_order_params::<T>(placed_market_ids, maker_asset);
+    }: _(RawOrigin::Signed(caller), order_ids, orders)
+
     impl_benchmark_test_suite!(
         Orderbook,
         crate::mock::ExtBuilder::default().build(),
@@ -22,15 +22,18 @@
 extern crate alloc;
 
 use crate::weights::*;
-use alloc::{vec, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 use core::marker::PhantomData;
 use frame_support::{
+    dispatch::{DispatchResultWithPostInfo, GetDispatchInfo},
     ensure,
     pallet_prelude::{
-        DispatchError, DispatchResult, OptionQuery, StorageMap, StorageValue, ValueQuery,
+        DispatchError, DispatchResult, Hooks, Member, OptionQuery, Parameter, StorageDoubleMap,
+        StorageMap, StorageValue, ValueQuery, Weight,
     },
-    traits::{IsType, StorageVersion},
-    transactional, PalletId, Twox64Concat,
+    storage::with_storage_layer,
+    traits::{IsSubType, IsType, StorageVersion},
+    transactional, Blake2_128Concat, BoundedVec, PalletId, Twox64Concat,
 };
 use frame_system::{
     ensure_signed,
@@ -38,17 +41,29 @@ use frame_system::{
 };
 use orml_traits::{BalanceStatus, MultiCurrency, NamedMultiReservableCurrency};
 pub use pallet::*;
+use parity_scale_codec::{Decode, Encode};
 use prediction_market_primitives::{
     hybrid_router_api_types::{ApiError, ExternalFee, OrderbookSoftFail, OrderbookTrade},
     math::{
         checked_ops_res::{CheckedAddRes, CheckedSubRes},
         fixed::FixedMulDiv,
     },
-    orderbook::{Order, OrderId},
+    orderbook::{
+        BatchMode, Order, OrderBookDepth, OrderExpiry, OrderId, OrderParams, OrderSide, PricedOrder,
+    },
     traits::{DistributeFees, HybridRouterOrderbookApi, MarketCommonsPalletApi},
-    types::{Asset, Market, MarketStatus, MarketType, ScalarPosition, ScoringRule},
+    types::{Asset, Market, MarketStatus, MarketType, Moment, ScalarPosition, ScoringRule},
+};
+use scale_info::TypeInfo;
+use sp_avn_common::{verify_signature, InnerCallValidator, Proof};
+use sp_runtime::{
+    traits::{Dispatchable, Get, IdentifyAccount, Verify, Zero},
+    SaturatedConversion,
 };
-use sp_runtime::traits::{Get, Zero};
+
+pub const PLACE_ORDER_CONTEXT: &[u8] = b"order_book::place_order_context";
+pub const FILL_ORDER_CONTEXT: &[u8] = b"order_book::fill_order_context";
+pub const REMOVE_ORDER_CONTEXT: &[u8] = b"order_book::remove_order_context";
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks;
@@ -66,6 +81,9 @@ mod pallet {
     #[allow(dead_code)]
     const LOG_TARGET: &str = "runtime::pallet-pm-order-book";
 
+    /// The maximum number of markets the `on_idle` sweeper visits per block.
+    pub(crate) const MAX_SWEPT_MARKETS: u32 = 16;
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// Shares of outcome assets and native currency
@@ -91,14 +109,50 @@ mod pallet {
             Balance = BalanceOf<Self>,
         >;
 
+        /// The maximum number of open orders per market. Also bounds the open orders per outcome
+        /// asset and side.
+        #[pallet::constant]
+        type MaxOrdersPerMarket: Get<u32>;
+
+        /// The maximum number of open orders per maker.
+        #[pallet::constant]
+        type MaxOrdersPerMaker: Get<u32>;
+
+        /// The maximum number of open orders per maker and market. Keeps a single maker from
+        /// occupying all of the `MaxOrdersPerMarket` slots of a market.
+        #[pallet::constant]
+        type MaxOrdersPerMakerPerMarket: Get<u32>;
+
+        /// The maximum number of orders which can be placed or removed in a single batch call.
+        #[pallet::constant]
+        type MaxOrdersPerBatch: Get<u32>;
+
         #[pallet::constant]
         type PalletId: Get<PalletId>;
 
         type WeightInfo: WeightInfoZeitgeist;
+
+        type Public: IdentifyAccount<AccountId = Self::AccountId>;
+
+        /// The signature type used by accounts/transactions.
+        type Signature: Verify<Signer = Self::Public>
+            + Member
+            + Decode
+            + Encode
+            + From<sp_core::sr25519::Signature>
+            + TypeInfo;
+
+        /// The overarching call type.
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = <Self as frame_system::Config>::RuntimeOrigin>
+            + IsSubType<Call<Self>>
+            + From<Call<Self>>
+            + GetDispatchInfo
+            + From<frame_system::Call<Self>>;
     }
 
     /// The current storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
     pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
     pub(crate) type AssetOf<T> = Asset<MarketIdOf<T>>;
@@ -113,6 +167,15 @@ mod pallet {
     pub(crate) type MomentOf<T> = <<T as Config>::MarketCommons as MarketCommonsPalletApi>::Moment;
     pub(crate) type OrderOf<T> = Order<AccountIdOf<T>, BalanceOf<T>, MarketIdOf<T>>;
     pub(crate) type OrderbookTradeOf<T> = OrderbookTrade<AccountIdOf<T>, BalanceOf<T>>;
+    pub(crate) type OrderBookDepthOf<T> =
+        OrderBookDepth<AccountIdOf<T>, BalanceOf<T>, MarketIdOf<T>>;
+    pub(crate) type PricedOrderOf<T> = PricedOrder<AccountIdOf<T>, BalanceOf<T>, MarketIdOf<T>>;
+    pub(crate) type MarketOrderIdsOf<T> = BoundedVec<OrderId, <T as Config>::MaxOrdersPerMarket>;
+    pub(crate) type MakerOrderIdsOf<T> = BoundedVec<OrderId, <T as Config>::MaxOrdersPerMaker>;
+    pub(crate) type OrderParamsOf<T> = OrderParams<BalanceOf<T>, MarketIdOf<T>>;
+    pub(crate) type OrderParamsBatchOf<T> =
+        BoundedVec<OrderParamsOf<T>, <T as Config>::MaxOrdersPerBatch>;
+    pub(crate) type OrderIdBatchOf<T> = BoundedVec<OrderId, <T as Config>::MaxOrdersPerBatch>;
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -124,6 +187,49 @@ mod pallet {
     #[pallet::storage]
     pub type Orders<T: Config> = StorageMap<_, Twox64Concat, OrderId, OrderOf<T>, OptionQuery>;
 
+    /// The IDs of the open orders of each market in the order they were placed.
+    #[pallet::storage]
+    pub type MarketOrders<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, MarketOrderIdsOf<T>, ValueQuery>;
+
+    /// The IDs of the open orders of each outcome asset and side in the order they were placed.
+    #[pallet::storage]
+    pub type OutcomeAssetOrders<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        AssetOf<T>,
+        Twox64Concat,
+        OrderSide,
+        MarketOrderIdsOf<T>,
+        ValueQuery,
+    >;
+
+    /// The last market visited by the `on_idle` sweeper. The next sweep continues after it.
+    #[pallet::storage]
+    pub type SweepCursor<T: Config> = StorageValue<_, MarketIdOf<T>, OptionQuery>;
+
+    /// The IDs of the open orders of each maker in the order they were placed.
+    #[pallet::storage]
+    pub type MakerOrders<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, MakerOrderIdsOf<T>, ValueQuery>;
+
+    /// The number of open orders of each maker in each market.
+    #[pallet::storage]
+    pub type MakerMarketOrderCount<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AccountIdOf<T>,
+        Twox64Concat,
+        MarketIdOf<T>,
+        u32,
+        ValueQuery,
+    >;
+
+    /// The nonce of each signer's next signed call.
+    #[pallet::storage]
+    pub type UserNonces<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u64, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(fn deposit_event)]
     pub enum Event<T>
@@ -148,6 +254,25 @@ mod pallet {
             order_id: OrderId,
             maker: T::AccountId,
         },
+        /// An order of a market which is no longer active was removed and its reserve refunded.
+        OrderCancelled {
+            order_id: OrderId,
+            maker: AccountIdOf<T>,
+            asset: AssetOf<T>,
+            amount: BalanceOf<T>,
+        },
+        /// An expired order was removed and its reserve refunded.
+        OrderExpired {
+            order_id: OrderId,
+            maker: AccountIdOf<T>,
+            asset: AssetOf<T>,
+            amount: BalanceOf<T>,
+        },
+        /// The item at `index` of a batch failed and was skipped.
+        OrderBatchItemFailed {
+            index: u32,
+            error: DispatchError,
+        },
     }
 
     #[pallet::error]
@@ -170,6 +295,25 @@ mod pallet {
         MarketBaseAssetNotPresent,
         /// The specified amount is below the minimum balance.
         BelowMinimumBalance,
+        /// The market has reached the maximum number of open orders.
+        TooManyOrdersForMarket,
+        /// The maker has reached the maximum number of open orders.
+        TooManyOrdersForMaker,
+        /// The maker has reached the maximum number of open orders in the market.
+        TooManyOrdersForMakerInMarket,
+        /// The order has expired or the specified expiry has already passed.
+        OrderExpired,
+        /// The sender is not the signer of the transaction.
+        SenderIsNotSigner,
+        /// Signed transaction has failed validation.
+        UnauthorizedSignedTransaction,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::sweep_stale_orders(remaining_weight)
+        }
     }
 
     #[pallet::call]
@@ -178,16 +322,17 @@ mod pallet {
         ///
         /// # Weight
         ///
-        /// Complexity: `O(1)`
+        /// Complexity: `O(o)`, where `o` is the number of open orders in the longest index entry
+        /// the order is removed from.
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::remove_order())]
+        #[pallet::weight(T::WeightInfo::remove_order(T::MaxOrdersPerMarket::get()))]
         #[transactional]
-        pub fn remove_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+        pub fn remove_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
-            Self::do_remove_order(order_id, who)?;
+            let index_len = Self::do_remove_order(order_id, who)?;
 
-            Ok(())
+            Ok(Some(T::WeightInfo::remove_order(index_len)).into())
         }
 
         /// Fill an existing order entirely (`maker_partial_fill` = None)
@@ -200,24 +345,28 @@ mod pallet {
         ///
         /// # Weight
         ///
-        /// Complexity: `O(1)`
+        /// Complexity: `O(o)`, where `o` is the number of open orders in the longest index entry
+        /// a fully filled order is removed from.
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::fill_order())]
+        #[pallet::weight(T::WeightInfo::fill_order(T::MaxOrdersPerMarket::get()))]
         #[transactional]
         pub fn fill_order(
             origin: OriginFor<T>,
             order_id: OrderId,
             maker_partial_fill: Option<BalanceOf<T>>,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let taker = ensure_signed(origin)?;
 
-            let _ = Self::do_fill_order(order_id, taker, maker_partial_fill)?;
+            let (_, index_len) = Self::do_fill_order(order_id, taker, maker_partial_fill)?;
 
-            Ok(())
+            Ok(Some(T::WeightInfo::fill_order(index_len)).into())
         }
 
         /// Place a new order.
         ///
+        /// The order can no longer be filled after `expiry`, if specified, and is removed by the
+        /// `on_idle` sweeper once it has expired.
+        ///
         /// # Weight
         ///
         /// Complexity: `O(1)`
@@ -231,6 +380,7 @@ mod pallet {
             #[pallet::compact] maker_amount: BalanceOf<T>,
             taker_asset: AssetOf<T>,
             #[pallet::compact] taker_amount: BalanceOf<T>,
+            expiry: Option<OrderExpiry>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
@@ -241,10 +391,201 @@ mod pallet {
                 maker_amount,
                 taker_asset,
                 taker_amount,
+                expiry,
+            )?;
+
+            Ok(())
+        }
+
+        /// Places a new order on behalf of `proof.signer`. The relayer pays the transaction fee.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::signed_place_order())]
+        #[transactional]
+        #[allow(clippy::too_many_arguments)]
+        pub fn signed_place_order(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            maker_asset: AssetOf<T>,
+            #[pallet::compact] maker_amount: BalanceOf<T>,
+            taker_asset: AssetOf<T>,
+            #[pallet::compact] taker_amount: BalanceOf<T>,
+            expiry: Option<OrderExpiry>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(who == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let nonce = <UserNonces<T>>::get(&who);
+            let signed_payload = Self::encode_signed_place_order_params(
+                &proof.relayer,
+                nonce,
+                &market_id,
+                &maker_asset,
+                &maker_amount,
+                &taker_asset,
+                &taker_amount,
+                &expiry,
+            );
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedTransaction
+            );
+
+            Self::do_place_order(
+                who.clone(),
+                market_id,
+                maker_asset,
+                maker_amount,
+                taker_asset,
+                taker_amount,
+                expiry,
             )?;
 
+            <UserNonces<T>>::mutate(&who, |nonce| *nonce += 1);
             Ok(())
         }
+
+        /// Fills an existing order on behalf of `proof.signer`. The relayer pays the transaction
+        /// fee.
+        ///
+        /// See `fill_order` for the meaning of `maker_partial_fill`.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(o)`, where `o` is the number of open orders in the longest index entry
+        /// a fully filled order is removed from.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::signed_fill_order(T::MaxOrdersPerMarket::get()))]
+        #[transactional]
+        pub fn signed_fill_order(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            order_id: OrderId,
+            maker_partial_fill: Option<BalanceOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let taker = ensure_signed(origin)?;
+            ensure!(taker == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let nonce = <UserNonces<T>>::get(&taker);
+            let signed_payload = Self::encode_signed_fill_order_params(
+                &proof.relayer,
+                nonce,
+                &order_id,
+                &maker_partial_fill,
+            );
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedTransaction
+            );
+
+            let (_, index_len) = Self::do_fill_order(order_id, taker.clone(), maker_partial_fill)?;
+
+            <UserNonces<T>>::mutate(&taker, |nonce| *nonce += 1);
+            Ok(Some(T::WeightInfo::signed_fill_order(index_len)).into())
+        }
+
+        /// Removes an order of `proof.signer`. The relayer pays the transaction fee.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(o)`, where `o` is the number of open orders in the longest index entry
+        /// the order is removed from.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::signed_remove_order(T::MaxOrdersPerMarket::get()))]
+        #[transactional]
+        pub fn signed_remove_order(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            order_id: OrderId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(who == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let nonce = <UserNonces<T>>::get(&who);
+            let signed_payload =
+                Self::encode_signed_remove_order_params(&proof.relayer, nonce, &order_id);
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload).is_ok(),
+                Error::<T>::UnauthorizedSignedTransaction
+            );
+
+            let index_len = Self::do_remove_order(order_id, who.clone())?;
+
+            <UserNonces<T>>::mutate(&who, |nonce| *nonce += 1);
+            Ok(Some(T::WeightInfo::signed_remove_order(index_len)).into())
+        }
+
+        /// Places multiple orders.
+        ///
+        /// With `BatchMode::AllOrNothing` the call fails if any of the orders can't be placed.
+        /// With `BatchMode::SkipFailed` such orders are skipped and `OrderBatchItemFailed` is
+        /// emitted for each of them.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n)`, where `n` is the number of orders.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::place_orders(orders.len() as u32))]
+        #[transactional]
+        pub fn place_orders(
+            origin: OriginFor<T>,
+            orders: OrderParamsBatchOf<T>,
+            mode: BatchMode,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_place_orders(who, orders, mode)
+        }
+
+        /// Removes multiple orders.
+        ///
+        /// With `BatchMode::AllOrNothing` the call fails if any of the orders can't be removed.
+        /// With `BatchMode::SkipFailed` such orders are skipped and `OrderBatchItemFailed` is
+        /// emitted for each of them.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n)`, where `n` is the number of orders.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::remove_orders(order_ids.len() as u32))]
+        #[transactional]
+        pub fn remove_orders(
+            origin: OriginFor<T>,
+            order_ids: OrderIdBatchOf<T>,
+            mode: BatchMode,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_remove_orders(who, order_ids, mode)
+        }
+
+        /// Atomically removes the orders `order_ids` and places `orders`.
+        ///
+        /// The call fails without any changes if any of the orders can't be removed or placed.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n + m)`, where `n` is the number of removed orders and `m` is the
+        /// number of placed orders.
+        #[pallet::call_index(8)]
+        #[pallet::weight(
+            T::WeightInfo::replace_orders(order_ids.len() as u32, orders.len() as u32)
+        )]
+        #[transactional]
+        pub fn replace_orders(
+            origin: OriginFor<T>,
+            order_ids: OrderIdBatchOf<T>,
+            orders: OrderParamsBatchOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_remove_orders(who.clone(), order_ids, BatchMode::AllOrNothing)?;
+            Self::do_place_orders(who, orders, BatchMode::AllOrNothing)
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -311,12 +652,24 @@ mod pallet {
             Ok(())
         }
 
-        fn do_remove_order(order_id: OrderId, who: AccountIdOf<T>) -> DispatchResult {
+        /// Removes an order of `who` and returns the length of the longest index entry it was
+        /// removed from.
+        fn do_remove_order(order_id: OrderId, who: AccountIdOf<T>) -> Result<u32, DispatchError> {
             let order_data = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderDoesNotExist)?;
 
-            let maker = &order_data.maker;
-            ensure!(who == *maker, Error::<T>::NotOrderCreator);
+            ensure!(who == order_data.maker, Error::<T>::NotOrderCreator);
+
+            let index_len = Self::release_order(order_id, &order_data);
+
+            Self::deposit_event(Event::OrderRemoved { order_id, maker: order_data.maker });
+
+            Ok(index_len)
+        }
 
+        /// Unreserves the unfilled maker amount of an order and deletes the order. Returns the
+        /// length of the longest index entry the order was removed from.
+        fn release_order(order_id: OrderId, order_data: &OrderOf<T>) -> u32 {
+            let maker = &order_data.maker;
             let missing = T::AssetManager::unreserve_named(
                 &Self::reserve_id(),
                 order_data.maker_asset,
@@ -336,10 +689,123 @@ mod pallet {
             );
 
             <Orders<T>>::remove(order_id);
+            Self::remove_from_indexes(order_id, order_data)
+        }
 
-            Self::deposit_event(Event::OrderRemoved { order_id, maker: maker.clone() });
+        /// Removes the orders of markets which are no longer active and the expired orders of
+        /// active markets, and refunds their reserves.
+        ///
+        /// Visits at most `MAX_SWEPT_MARKETS` markets with open orders, continuing after the
+        /// market visited last, and stops as soon as `remaining_weight` is exhausted. A market
+        /// which couldn't be cleared completely is visited again by the next sweep.
+        pub(crate) fn sweep_stale_orders(remaining_weight: Weight) -> Weight {
+            let db_weight = T::DbWeight::get();
+            // Reading the index entry and the market.
+            let market_weight = db_weight.reads(2);
+            let order_weight = T::WeightInfo::remove_order(T::MaxOrdersPerMarket::get());
+            // Reading an order of an active market to check its expiry.
+            let expiry_weight = db_weight.reads(1);
+            // Reading and writing the cursor and reading the keys of the visited markets.
+            let mut consumed =
+                db_weight.reads_writes(1u64.saturating_add(MAX_SWEPT_MARKETS.into()), 1);
+            if remaining_weight.any_lt(consumed.saturating_add(market_weight)) {
+                return Weight::zero();
+            }
 
-            Ok(())
+            let start = <SweepCursor<T>>::get();
+            let market_keys = match start {
+                Some(market_id) => {
+                    let start_key = <MarketOrders<T>>::hashed_key_for(market_id);
+                    <MarketOrders<T>>::iter_keys_from(start_key)
+                },
+                None => <MarketOrders<T>>::iter_keys(),
+            };
+            let market_ids = market_keys.take(MAX_SWEPT_MARKETS as usize).collect::<Vec<_>>();
+            let reached_end = market_ids.len() < MAX_SWEPT_MARKETS as usize;
+
+            let mut cursor = start;
+            for market_id in market_ids {
+                if remaining_weight.any_lt(consumed.saturating_add(market_weight)) {
+                    <SweepCursor<T>>::set(cursor);
+                    return consumed;
+                }
+                consumed.saturating_accrue(market_weight);
+                let is_stale = T::MarketCommons::market(&market_id)
+                    .map_or(true, |market| market.status != MarketStatus::Active);
+                if is_stale {
+                    for order_id in <MarketOrders<T>>::get(market_id) {
+                        if remaining_weight.any_lt(consumed.saturating_add(order_weight)) {
+                            <SweepCursor<T>>::set(cursor);
+                            return consumed;
+                        }
+                        consumed.saturating_accrue(order_weight);
+                        Self::cancel_order(order_id);
+                    }
+                } else {
+                    for order_id in <MarketOrders<T>>::get(market_id) {
+                        let max_weight = expiry_weight.saturating_add(order_weight);
+                        if remaining_weight.any_lt(consumed.saturating_add(max_weight)) {
+                            <SweepCursor<T>>::set(cursor);
+                            return consumed;
+                        }
+                        consumed.saturating_accrue(expiry_weight);
+                        if Self::expire_order(order_id) {
+                            consumed.saturating_accrue(order_weight);
+                        }
+                    }
+                }
+                cursor = Some(market_id);
+            }
+
+            // Start over with the next sweep once the end of the map is reached.
+            <SweepCursor<T>>::set(if reached_end { None } else { cursor });
+            consumed
+        }
+
+        /// Removes an order if it has expired and emits `OrderExpired`. Returns `true` if the
+        /// order was removed.
+        fn expire_order(order_id: OrderId) -> bool {
+            match <Orders<T>>::get(order_id) {
+                Some(order_data) if Self::is_order_expired(&order_data) => {
+                    let _ = Self::release_order(order_id, &order_data);
+                    Self::deposit_event(Event::OrderExpired {
+                        order_id,
+                        maker: order_data.maker,
+                        asset: order_data.maker_asset,
+                        amount: order_data.maker_amount,
+                    });
+                    true
+                },
+                _ => false,
+            }
+        }
+
+        /// Returns the current block number and moment which order expiries are checked against.
+        fn block_and_moment() -> (u64, Moment) {
+            let block = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+            let now = T::MarketCommons::now().saturated_into::<Moment>();
+            (block, now)
+        }
+
+        /// Returns `true` if the order has expired at the current block and moment.
+        pub(crate) fn is_order_expired(order_data: &OrderOf<T>) -> bool {
+            order_data.expiry.map_or(false, |expiry| {
+                let (block, now) = Self::block_and_moment();
+                expiry.is_expired(block, now)
+            })
+        }
+
+        /// Removes an order without checking its maker and emits `OrderCancelled`.
+        fn cancel_order(order_id: OrderId) {
+            if let Some(order_data) = <Orders<T>>::get(order_id) {
+                let _ = Self::release_order(order_id, &order_data);
+                Self::deposit_event(Event::OrderCancelled {
+                    order_id,
+                    maker: order_data.maker,
+                    asset: order_data.maker_asset,
+                    amount: order_data.maker_amount,
+                });
+            }
         }
 
         /// Charge the external fees in base asset and return the adjusted maker fill.
@@ -380,12 +846,15 @@ mod pallet {
             }
         }
 
+        /// Fills an order and returns the trade and the length of the longest index entry the
+        /// order was removed from, which is zero unless the order was filled completely.
         fn do_fill_order(
             order_id: OrderId,
             taker: AccountIdOf<T>,
             maker_partial_fill: Option<BalanceOf<T>>,
-        ) -> Result<OrderbookTradeOf<T>, DispatchError> {
+        ) -> Result<(OrderbookTradeOf<T>, u32), DispatchError> {
             let mut order_data = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderDoesNotExist)?;
+            ensure!(!Self::is_order_expired(&order_data), Error::<T>::OrderExpired);
             let market = T::MarketCommons::market(&order_data.market_id)?;
             ensure!(market.status == MarketStatus::Active, Error::<T>::MarketIsNotActive);
             let base_asset = market.base_asset;
@@ -435,11 +904,13 @@ mod pallet {
             Self::decrease_order_amounts(&mut order_data, maker_fill, taker_fill)?;
             Self::ensure_ratio_quotient_valid(&order_data)?;
 
-            if order_data.maker_amount.is_zero() {
+            let index_len = if order_data.maker_amount.is_zero() {
                 <Orders<T>>::remove(order_id);
+                Self::remove_from_indexes(order_id, &order_data)
             } else {
                 <Orders<T>>::insert(order_id, order_data.clone());
-            }
+                0
+            };
 
             Self::deposit_event(Event::OrderFilled {
                 order_id,
@@ -452,13 +923,62 @@ mod pallet {
                 external_fee: external_fee.clone(),
             });
 
-            Ok(OrderbookTrade {
+            let trade = OrderbookTrade {
                 filled_maker_amount: taker_fill,
                 filled_taker_amount: maker_fill,
                 external_fee,
+            };
+            Ok((trade, index_len))
+        }
+
+        fn do_place_orders(
+            who: AccountIdOf<T>,
+            orders: OrderParamsBatchOf<T>,
+            mode: BatchMode,
+        ) -> DispatchResult {
+            Self::execute_batch(orders.into_inner(), mode, |order| {
+                Self::do_place_order(
+                    who.clone(),
+                    order.market_id,
+                    order.maker_asset,
+                    order.maker_amount,
+                    order.taker_asset,
+                    order.taker_amount,
+                    order.expiry,
+                )
             })
         }
 
+        fn do_remove_orders(
+            who: AccountIdOf<T>,
+            order_ids: OrderIdBatchOf<T>,
+            mode: BatchMode,
+        ) -> DispatchResult {
+            Self::execute_batch(order_ids.into_inner(), mode, |order_id| {
+                Self::do_remove_order(order_id, who.clone()).map(|_| ())
+            })
+        }
+
+        /// Applies `f` to each item of a batch. With `BatchMode::SkipFailed` each item is executed
+        /// in its own storage layer, so that a failing item is reverted without aborting the
+        /// batch.
+        fn execute_batch<I, F>(items: Vec<I>, mode: BatchMode, mut f: F) -> DispatchResult
+        where
+            F: FnMut(I) -> DispatchResult,
+        {
+            for (index, item) in (0u32..).zip(items) {
+                match mode {
+                    BatchMode::AllOrNothing => f(item)?,
+                    BatchMode::SkipFailed =>
+                        if let Err(error) = with_storage_layer(|| f(item)) {
+                            Self::deposit_event(Event::OrderBatchItemFailed { index, error });
+                        },
+                }
+            }
+
+            Ok(())
+        }
+
         fn do_place_order(
             who: AccountIdOf<T>,
             market_id: MarketIdOf<T>,
@@ -466,6 +986,7 @@ mod pallet {
             maker_amount: BalanceOf<T>,
             taker_asset: AssetOf<T>,
             taker_amount: BalanceOf<T>,
+            expiry: Option<OrderExpiry>,
         ) -> DispatchResult {
             let market = T::MarketCommons::market(&market_id)?;
             ensure!(market.status == MarketStatus::Active, Error::<T>::MarketIsNotActive);
@@ -481,10 +1002,10 @@ mod pallet {
                 ensure!(taker_asset == base_asset, Error::<T>::MarketBaseAssetNotPresent);
                 maker_asset
             };
-            let market_assets = market.outcome_assets();
-            market_assets
-                .binary_search(&outcome_asset)
-                .map_err(|_| Error::<T>::InvalidOutcomeAsset)?;
+            ensure!(
+                market.outcome_assets().contains(&outcome_asset),
+                Error::<T>::InvalidOutcomeAsset
+            );
 
             ensure!(
                 maker_amount >= T::AssetManager::minimum_balance(maker_asset),
@@ -494,6 +1015,10 @@ mod pallet {
                 taker_amount >= T::AssetManager::minimum_balance(taker_asset),
                 Error::<T>::BelowMinimumBalance
             );
+            if let Some(expiry) = expiry {
+                let (block, now) = Self::block_and_moment();
+                ensure!(!expiry.is_expired(block, now), Error::<T>::OrderExpired);
+            }
 
             let order_id = <NextOrderId<T>>::get();
             let next_order_id = order_id.checked_add_res(&1)?;
@@ -508,8 +1033,10 @@ mod pallet {
                 maker_amount,
                 taker_asset,
                 taker_amount,
+                expiry,
             };
 
+            Self::add_to_indexes(order_id, &order)?;
             <Orders<T>>::insert(order_id, order.clone());
             <NextOrderId<T>>::put(next_order_id);
             Self::deposit_event(Event::OrderPlaced { order_id, order });
@@ -519,6 +1046,125 @@ mod pallet {
     }
 
     impl<T: Config> Pallet<T> {
+        /// Adds `order_id` to the market, outcome asset and maker indexes and counts it towards
+        /// the open orders of the maker in the market.
+        pub(crate) fn add_to_indexes(order_id: OrderId, order: &OrderOf<T>) -> DispatchResult {
+            <MakerMarketOrderCount<T>>::try_mutate(&order.maker, order.market_id, |count| {
+                ensure!(
+                    *count < T::MaxOrdersPerMakerPerMarket::get(),
+                    Error::<T>::TooManyOrdersForMakerInMarket
+                );
+                *count = count.saturating_add(1);
+                Ok::<_, DispatchError>(())
+            })?;
+            <MarketOrders<T>>::try_mutate(order.market_id, |order_ids| {
+                order_ids.try_push(order_id).map_err(|_| Error::<T>::TooManyOrdersForMarket)
+            })?;
+            <OutcomeAssetOrders<T>>::try_mutate(
+                order.outcome_asset(),
+                order.side(),
+                |order_ids| {
+                    order_ids.try_push(order_id).map_err(|_| Error::<T>::TooManyOrdersForMarket)
+                },
+            )?;
+            <MakerOrders<T>>::try_mutate(&order.maker, |order_ids| {
+                order_ids.try_push(order_id).map_err(|_| Error::<T>::TooManyOrdersForMaker)
+            })?;
+            Ok(())
+        }
+
+        /// Removes `order_id` from the market, outcome asset and maker indexes.
+        ///
+        /// Returns the length of the longest index entry the order was removed from, which the
+        /// weight of the removal scales with.
+        fn remove_from_indexes(order_id: OrderId, order: &OrderOf<T>) -> u32 {
+            let market_len = <MarketOrders<T>>::mutate_exists(order.market_id, |maybe_order_ids| {
+                Self::remove_order_id(maybe_order_ids, order_id)
+            });
+            let outcome_asset_len = <OutcomeAssetOrders<T>>::mutate_exists(
+                order.outcome_asset(),
+                order.side(),
+                |maybe_order_ids| Self::remove_order_id(maybe_order_ids, order_id),
+            );
+            let maker_len = <MakerOrders<T>>::mutate_exists(&order.maker, |maybe_order_ids| {
+                Self::remove_order_id(maybe_order_ids, order_id)
+            });
+            <MakerMarketOrderCount<T>>::mutate_exists(
+                &order.maker,
+                order.market_id,
+                |maybe_count| {
+                    *maybe_count =
+                        maybe_count.and_then(|count| count.checked_sub(1)).filter(|c| *c > 0);
+                },
+            );
+            market_len.max(outcome_asset_len).max(maker_len)
+        }
+
+        /// Removes `order_id` from an index entry and clears the entry if it becomes empty.
+        /// Returns the length of the entry before the removal.
+        fn remove_order_id<S>(
+            maybe_order_ids: &mut Option<BoundedVec<OrderId, S>>,
+            order_id: OrderId,
+        ) -> u32 {
+            let Some(order_ids) = maybe_order_ids else {
+                return 0;
+            };
+            let len = order_ids.len() as u32;
+            order_ids.retain(|id| *id != order_id);
+            if order_ids.is_empty() {
+                *maybe_order_ids = None;
+            }
+            len
+        }
+
+        /// Returns the IDs of the open orders of `market_id` in the order they were placed.
+        pub fn market_orders(market_id: MarketIdOf<T>) -> Vec<OrderId> {
+            <MarketOrders<T>>::get(market_id).into_inner()
+        }
+
+        /// Returns the IDs of the open orders of `maker` in the order they were placed.
+        pub fn maker_orders(maker: &AccountIdOf<T>) -> Vec<OrderId> {
+            <MakerOrders<T>>::get(maker).into_inner()
+        }
+
+        /// Returns up to `depth` of the best bids and asks for `outcome_asset`.
+        ///
+        /// Bids are sorted by descending price and asks by ascending price. Orders with equal
+        /// prices keep the order in which they were placed. Expired orders are skipped.
+        pub fn best_orders(
+            market_id: MarketIdOf<T>,
+            outcome_asset: AssetOf<T>,
+            depth: u32,
+        ) -> Result<OrderBookDepthOf<T>, DispatchError> {
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(
+                market.outcome_assets().contains(&outcome_asset),
+                Error::<T>::InvalidOutcomeAsset
+            );
+            let mut bids = Self::priced_orders(outcome_asset, OrderSide::Bid, market.base_asset)?;
+            bids.sort_by(|a, b| b.price.cmp(&a.price));
+            bids.truncate(depth as usize);
+            let mut asks = Self::priced_orders(outcome_asset, OrderSide::Ask, market.base_asset)?;
+            asks.sort_by(|a, b| a.price.cmp(&b.price));
+            asks.truncate(depth as usize);
+            Ok(OrderBookDepth { bids, asks })
+        }
+
+        fn priced_orders(
+            outcome_asset: AssetOf<T>,
+            side: OrderSide,
+            base_asset: AssetOf<T>,
+        ) -> Result<Vec<PricedOrderOf<T>>, DispatchError> {
+            <OutcomeAssetOrders<T>>::get(outcome_asset, side)
+                .into_iter()
+                .filter_map(|order_id| <Orders<T>>::get(order_id).map(|order| (order_id, order)))
+                .filter(|(_, order)| !Self::is_order_expired(order))
+                .map(|(order_id, order)| {
+                    Ok(PricedOrder { order_id, price: order.price(base_asset)?, order })
+                })
+                .collect()
+        }
+
         fn match_failure(error: DispatchError) -> ApiError<OrderbookSoftFail> {
             let below_minimum_balance: DispatchError = Error::<T>::BelowMinimumBalance.into();
             let partial_fill_near_full_fill_not_allowed: DispatchError =
@@ -533,6 +1179,120 @@ mod pallet {
         }
     }
 
+    impl<T: Config> Pallet<T> {
+        #[allow(clippy::too_many_arguments)]
+        pub fn encode_signed_place_order_params(
+            relayer: &T::AccountId,
+            nonce: u64,
+            market_id: &MarketIdOf<T>,
+            maker_asset: &AssetOf<T>,
+            maker_amount: &BalanceOf<T>,
+            taker_asset: &AssetOf<T>,
+            taker_amount: &BalanceOf<T>,
+            expiry: &Option<OrderExpiry>,
+        ) -> Vec<u8> {
+            (
+                PLACE_ORDER_CONTEXT,
+                relayer,
+                nonce,
+                market_id,
+                maker_asset,
+                maker_amount,
+                taker_asset,
+                taker_amount,
+                expiry,
+            )
+                .encode()
+        }
+
+        pub fn encode_signed_fill_order_params(
+            relayer: &T::AccountId,
+            nonce: u64,
+            order_id: &OrderId,
+            maker_partial_fill: &Option<BalanceOf<T>>,
+        ) -> Vec<u8> {
+            (FILL_ORDER_CONTEXT, relayer, nonce, order_id, maker_partial_fill).encode()
+        }
+
+        pub fn encode_signed_remove_order_params(
+            relayer: &T::AccountId,
+            nonce: u64,
+            order_id: &OrderId,
+        ) -> Vec<u8> {
+            (REMOVE_ORDER_CONTEXT, relayer, nonce, order_id).encode()
+        }
+
+        pub fn get_encoded_call_param(
+            call: &<T as Config>::RuntimeCall,
+        ) -> Option<(&Proof<T::Signature, T::AccountId>, Vec<u8>)> {
+            let call = match call.is_sub_type() {
+                Some(call) => call,
+                None => return None,
+            };
+
+            match call {
+                Call::signed_place_order {
+                    ref proof,
+                    ref market_id,
+                    ref maker_asset,
+                    ref maker_amount,
+                    ref taker_asset,
+                    ref taker_amount,
+                    ref expiry,
+                } => {
+                    let nonce = <UserNonces<T>>::get(&proof.signer);
+                    let encoded_data = Self::encode_signed_place_order_params(
+                        &proof.relayer,
+                        nonce,
+                        market_id,
+                        maker_asset,
+                        maker_amount,
+                        taker_asset,
+                        taker_amount,
+                        expiry,
+                    );
+
+                    Some((proof, encoded_data))
+                },
+                Call::signed_fill_order { ref proof, ref order_id, ref maker_partial_fill } => {
+                    let nonce = <UserNonces<T>>::get(&proof.signer);
+                    let encoded_data = Self::encode_signed_fill_order_params(
+                        &proof.relayer,
+                        nonce,
+                        order_id,
+                        maker_partial_fill,
+                    );
+
+                    Some((proof, encoded_data))
+                },
+                Call::signed_remove_order { ref proof, ref order_id } => {
+                    let nonce = <UserNonces<T>>::get(&proof.signer);
+                    let encoded_data =
+                        Self::encode_signed_remove_order_params(&proof.relayer, nonce, order_id);
+
+                    Some((proof, encoded_data))
+                },
+                _ => None,
+            }
+        }
+    }
+
+    impl<T: Config> InnerCallValidator for Pallet<T> {
+        type Call = <T as Config>::RuntimeCall;
+
+        fn signature_is_valid(call: &Box<Self::Call>) -> bool {
+            if let Some((proof, signed_payload)) = Self::get_encoded_call_param(call) {
+                return verify_signature::<T::Signature, T::AccountId>(
+                    proof,
+                    signed_payload.as_slice(),
+                )
+                .is_ok();
+            }
+
+            false
+        }
+    }
+
     impl<T: Config> HybridRouterOrderbookApi for Pallet<T> {
         type AccountId = AccountIdOf<T>;
         type MarketId = MarketIdOf<T>;
@@ -542,7 +1302,9 @@ mod pallet {
         type OrderId = OrderId;
 
         fn order(order_id: Self::OrderId) -> Result<Self::Order, DispatchError> {
-            <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderDoesNotExist.into())
+            let order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderDoesNotExist)?;
+            ensure!(!Self::is_order_expired(&order), Error::<T>::OrderExpired);
+            Ok(order)
         }
 
         fn fill_order(
@@ -550,7 +1312,9 @@ mod pallet {
             order_id: Self::OrderId,
             maker_partial_fill: Option<Self::Balance>,
         ) -> Result<OrderbookTradeOf<T>, ApiError<OrderbookSoftFail>> {
-            Self::do_fill_order(order_id, who, maker_partial_fill).map_err(Self::match_failure)
+            Self::do_fill_order(order_id, who, maker_partial_fill)
+                .map(|(trade, _)| trade)
+                .map_err(Self::match_failure)
         }
 
         fn place_order(
@@ -560,6 +1324,7 @@ mod pallet {
             maker_amount: Self::Balance,
             taker_asset: Self::Asset,
             taker_amount: Self::Balance,
+            expiry: Option<OrderExpiry>,
         ) -> Result<(), ApiError<OrderbookSoftFail>> {
             Self::do_place_order(
                 who,
@@ -568,6 +1333,7 @@ mod pallet {
                 maker_amount,
                 taker_asset,
                 taker_amount,
+                expiry,
             )
             .map_err(Self::match_failure)
         }
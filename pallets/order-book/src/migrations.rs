@@ -14,3 +14,236 @@
 //
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+    storage::with_storage_layer,
+    storage_alias,
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+    weights::Weight,
+};
+use parity_scale_codec::{Decode, Encode, HasCompact, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const LOG_TARGET: &str = "runtime::pallet-pm-order-book";
+
+/// The layout of orders before `expiry` was added.
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct OldOrder<AccountId, Balance, MarketId: MaxEncodedLen + HasCompact> {
+    pub market_id: MarketId,
+    pub maker: AccountId,
+    pub maker_asset: Asset<MarketId>,
+    pub maker_amount: Balance,
+    pub taker_asset: Asset<MarketId>,
+    pub taker_amount: Balance,
+}
+
+impl<AccountId, Balance, MarketId: MaxEncodedLen + HasCompact>
+    OldOrder<AccountId, Balance, MarketId>
+{
+    fn into_order(self) -> Order<AccountId, Balance, MarketId> {
+        Order {
+            market_id: self.market_id,
+            maker: self.maker,
+            maker_asset: self.maker_asset,
+            maker_amount: self.maker_amount,
+            taker_asset: self.taker_asset,
+            taker_amount: self.taker_amount,
+            expiry: None,
+        }
+    }
+}
+
+type OldOrderOf<T> = OldOrder<AccountIdOf<T>, BalanceOf<T>, MarketIdOf<T>>;
+
+mod v1 {
+    use super::*;
+
+    #[storage_alias]
+    pub(super) type Orders<T: Config> = StorageMap<Pallet<T>, Twox64Concat, OrderId, OldOrderOf<T>>;
+}
+
+/// Builds the market, outcome asset and maker indexes from the existing `Orders`.
+///
+/// Each order is indexed in its own storage layer. Orders which don't fit into the indexes would
+/// never be found by the sweep in `on_idle`, so they are removed and their reserves are refunded
+/// to their makers instead.
+pub struct BuildOrderIndexes<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for BuildOrderIndexes<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let onchain = Pallet::<T>::on_chain_storage_version();
+        if onchain != 1 {
+            log::info!(
+                target: LOG_TARGET,
+                "Skipping BuildOrderIndexes, on-chain storage version is {:?}",
+                onchain
+            );
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut reads: u64 = 1;
+        let mut writes: u64 = 0;
+        let mut order_count: u64 = 0;
+        // The orders still have the layout of storage version 1 at this point.
+        for (order_id, old_order) in <v1::Orders<T>>::iter() {
+            order_count = order_count.saturating_add(1);
+            reads = reads.saturating_add(4);
+            writes = writes.saturating_add(4);
+            let order = old_order.into_order();
+            if let Err(e) = with_storage_layer(|| Pallet::<T>::add_to_indexes(order_id, &order)) {
+                let missing = T::AssetManager::unreserve_named(
+                    &Pallet::<T>::reserve_id(),
+                    order.maker_asset,
+                    &order.maker,
+                    order.maker_amount,
+                );
+                <v1::Orders<T>>::remove(order_id);
+                reads = reads.saturating_add(1);
+                writes = writes.saturating_add(2);
+                log::warn!(
+                    target: LOG_TARGET,
+                    "Failed to index order {:?}: {:?}. Removed the order and refunded {:?} of {:?} \
+                     to {:?} (missing: {:?})",
+                    order_id,
+                    e,
+                    order.maker_amount,
+                    order.maker_asset,
+                    order.maker,
+                    missing,
+                );
+            }
+        }
+
+        StorageVersion::new(2).put::<Pallet<T>>();
+        writes = writes.saturating_add(1);
+        log::info!(target: LOG_TARGET, "Built order indexes from {} orders", order_count);
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        Ok(vec![])
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_input: Vec<u8>) -> Result<(), TryRuntimeError> {
+        for (order_id, order) in <v1::Orders<T>>::iter() {
+            ensure!(
+                <MarketOrders<T>>::get(order.market_id).contains(&order_id),
+                "order missing from the market index"
+            );
+            ensure!(
+                <MakerOrders<T>>::get(&order.maker).contains(&order_id),
+                "order missing from the maker index"
+            );
+        }
+        ensure!(Pallet::<T>::on_chain_storage_version() == 2, "storage version was not updated");
+        Ok(())
+    }
+}
+
+/// Adds an empty `expiry` to all existing `Orders`.
+pub struct AddOrderExpiry<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for AddOrderExpiry<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let onchain = Pallet::<T>::on_chain_storage_version();
+        if onchain != 2 {
+            log::info!(
+                target: LOG_TARGET,
+                "Skipping AddOrderExpiry, on-chain storage version is {:?}",
+                onchain
+            );
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut order_count: u64 = 0;
+        <Orders<T>>::translate_values::<OldOrderOf<T>, _>(|old_order| {
+            order_count = order_count.saturating_add(1);
+            Some(old_order.into_order())
+        });
+
+        StorageVersion::new(3).put::<Pallet<T>>();
+        log::info!(target: LOG_TARGET, "Added an empty expiry to {} orders", order_count);
+
+        T::DbWeight::get()
+            .reads_writes(order_count.saturating_add(1), order_count.saturating_add(1))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let order_count = <v1::Orders<T>>::iter_keys().count() as u64;
+        Ok(order_count.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(input: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let old_order_count: u64 =
+            Decode::decode(&mut &input[..]).map_err(|_| "failed to decode the order count")?;
+        let order_count = <Orders<T>>::iter().count() as u64;
+        ensure!(order_count == old_order_count, "orders failed to decode after the migration");
+        ensure!(
+            <Orders<T>>::iter_values().all(|order| order.expiry.is_none()),
+            "migrated order has an expiry"
+        );
+        ensure!(Pallet::<T>::on_chain_storage_version() == 3, "storage version was not updated");
+        Ok(())
+    }
+}
+
+/// Counts the open orders of each maker in each market from the existing `Orders`.
+pub struct CountMakerMarketOrders<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for CountMakerMarketOrders<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let onchain = Pallet::<T>::on_chain_storage_version();
+        if onchain != 3 {
+            log::info!(
+                target: LOG_TARGET,
+                "Skipping CountMakerMarketOrders, on-chain storage version is {:?}",
+                onchain
+            );
+            return T::DbWeight::get().reads(1);
+        }
+
+        // Orders which were indexed by `BuildOrderIndexes` in the same upgrade are already
+        // counted, so the counts are rebuilt from scratch. Existing orders are counted even if they
+        // exceed `MaxOrdersPerMakerPerMarket`.
+        let cleared = <MakerMarketOrderCount<T>>::clear(u32::MAX, None).unique as u64;
+        let mut order_count: u64 = 0;
+        for order in <Orders<T>>::iter_values() {
+            order_count = order_count.saturating_add(1);
+            <MakerMarketOrderCount<T>>::mutate(&order.maker, order.market_id, |count| {
+                *count = count.saturating_add(1)
+            });
+        }
+
+        StorageVersion::new(4).put::<Pallet<T>>();
+        log::info!(target: LOG_TARGET, "Counted {} orders by maker and market", order_count);
+
+        T::DbWeight::get().reads_writes(
+            order_count.saturating_mul(2).saturating_add(1),
+            order_count.saturating_add(cleared).saturating_add(1),
+        )
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        Ok(vec![])
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_input: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let order_count = <Orders<T>>::iter_keys().count() as u64;
+        let counted = <MakerMarketOrderCount<T>>::iter_values()
+            .fold(0u64, |total, count| total.saturating_add(count.into()));
+        ensure!(counted == order_count, "order counts don't match the orders");
+        ensure!(Pallet::<T>::on_chain_storage_version() == 4, "storage version was not updated");
+        Ok(())
+    }
+}
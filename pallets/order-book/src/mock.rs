@@ -25,27 +25,43 @@ use core::marker::PhantomData;
 use frame_support::{construct_runtime, pallet_prelude::Get, parameter_types, traits::Everything};
 use frame_system::mocking::MockBlock;
 use orml_traits::MultiCurrency;
+use parity_scale_codec::alloc::sync::Arc;
+pub use prediction_market_primitives::test_helper::get_account;
 use prediction_market_primitives::{
     constants::mock::{
         BlockHashCount, ExistentialDeposit, ExistentialDeposits, GetNativeCurrencyId, MaxLocks,
+        MaxOrdersPerBatch, MaxOrdersPerMaker, MaxOrdersPerMakerPerMarket, MaxOrdersPerMarket,
         MaxReserves, MinimumPeriod, OrderbookPalletId, BASE, CENT_BASE,
     },
     traits::DistributeFees,
-    types::{AccountIdTest, BasicCurrencyAdapter, CurrencyId, MarketId, OrmlAmount},
+    types::{
+        BasicCurrencyAdapter, CurrencyId, MarketId, OrmlAmount, SignatureTest, TestAccountIdPK,
+    },
 };
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup, Zero},
     BuildStorage, SaturatedConversion,
 };
 
-pub const ALICE: AccountIdTest = 0;
-pub const BOB: AccountIdTest = 1;
-pub const MARKET_CREATOR: AccountIdTest = 42;
+pub fn alice() -> TestAccountIdPK {
+    get_account(0u8)
+}
+pub fn bob() -> TestAccountIdPK {
+    get_account(1u8)
+}
+pub fn eve() -> TestAccountIdPK {
+    get_account(4u8)
+}
+pub fn market_creator() -> TestAccountIdPK {
+    get_account(42u8)
+}
+
 pub const INITIAL_BALANCE: Balance = 100 * BASE;
 pub const EXTERNAL_FEES: Balance = CENT_BASE / 100;
 
 parameter_types! {
-    pub const FeeAccount: AccountIdTest = MARKET_CREATOR;
+    pub FeeAccount: TestAccountIdPK = market_creator();
 }
 
 pub fn calculate_fee<T: crate::Config>(_amount: BalanceOf<T>) -> BalanceOf<T> {
@@ -75,6 +91,14 @@ where
             Err(_) => Zero::zero(),
         }
     }
+
+    fn fee_amount(
+        _market_id: Self::MarketId,
+        _trader: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Self::Balance {
+        calculate_fee::<T>(amount)
+    }
 }
 
 construct_runtime!(
@@ -86,6 +110,7 @@ construct_runtime!(
         Tokens: orml_tokens,
         AssetManager: orml_currencies,
         Timestamp: pallet_timestamp,
+        AVN: pallet_avn,
     }
 );
 
@@ -94,13 +119,20 @@ impl crate::Config for Runtime {
     type ExternalFees = ExternalFees<Runtime, FeeAccount>;
     type RuntimeEvent = RuntimeEvent;
     type MarketCommons = MarketCommons;
+    type MaxOrdersPerMarket = MaxOrdersPerMarket;
+    type MaxOrdersPerMaker = MaxOrdersPerMaker;
+    type MaxOrdersPerMakerPerMarket = MaxOrdersPerMakerPerMarket;
+    type MaxOrdersPerBatch = MaxOrdersPerBatch;
     type PalletId = OrderbookPalletId;
     type WeightInfo = pallet_pm_order_book::weights::WeightInfo<Runtime>;
+    type RuntimeCall = RuntimeCall;
+    type Public = TestAccountIdPK;
+    type Signature = SignatureTest;
 }
 
 impl frame_system::Config for Runtime {
     type AccountData = pallet_balances::AccountData<Balance>;
-    type AccountId = AccountIdTest;
+    type AccountId = TestAccountIdPK;
     type BaseCallFilter = Everything;
     type Block = MockBlock<Runtime>;
     type BlockHashCount = BlockHashCount;
@@ -168,6 +200,15 @@ impl pallet_timestamp::Config for Runtime {
     type WeightInfo = ();
 }
 
+impl pallet_avn::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AuthorityId = pallet_avn::sr25519::AuthorityId;
+    type EthereumPublicKeyChecker = ();
+    type NewSessionHandler = ();
+    type DisabledValidatorChecker = ();
+    type WeightInfo = ();
+}
+
 impl pallet_pm_market_commons::Config for Runtime {
     type Balance = Balance;
     type MarketId = MarketId;
@@ -175,16 +216,17 @@ impl pallet_pm_market_commons::Config for Runtime {
 }
 
 pub struct ExtBuilder {
-    balances: Vec<(AccountIdTest, Balance)>,
+    balances: Vec<(TestAccountIdPK, Balance)>,
 }
 
 impl Default for ExtBuilder {
     fn default() -> Self {
-        Self { balances: vec![(ALICE, INITIAL_BALANCE), (BOB, INITIAL_BALANCE)] }
+        Self { balances: vec![(alice(), INITIAL_BALANCE), (bob(), INITIAL_BALANCE)] }
     }
 }
 impl ExtBuilder {
     pub fn build(self) -> sp_io::TestExternalities {
+        let keystore = MemoryKeystore::new();
         let mut t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
 
         // see the logs in tests when using `RUST_LOG=debug cargo test -- --nocapture`
@@ -195,6 +237,7 @@ impl ExtBuilder {
             .unwrap();
 
         let mut t: sp_io::TestExternalities = t.into();
+        t.register_extension(KeystoreExt(Arc::new(keystore)));
 
         t.execute_with(|| System::set_block_number(1));
 
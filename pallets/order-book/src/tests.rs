@@ -16,17 +16,33 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{mock::*, utils::market_mock, Error, Event, Order, Orders};
+use crate::{
+    mock::*, utils::market_mock, weights::WeightInfoZeitgeist, Call, Config, Error, Event,
+    MakerMarketOrderCount, MakerOrders, MarketOrders, Order, Orders, OutcomeAssetOrders,
+    UserNonces,
+};
 use common_primitives::constants::currency::BASE;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Get, Hooks},
+    weights::Weight,
+};
 use orml_tokens::Error as AError;
 use orml_traits::{MultiCurrency, MultiReservableCurrency};
 use pallet_balances::Error as BError;
 use pallet_pm_market_commons::{Error as MError, MarketCommonsPalletApi, Markets};
 use prediction_market_primitives::{
+    constants::mock::{MaxOrdersPerMaker, MaxOrdersPerMakerPerMarket, MaxOrdersPerMarket},
     hybrid_router_api_types::ExternalFee,
-    types::{Asset, MarketStatus, MarketType, ScalarPosition, ScoringRule},
+    orderbook::{BatchMode, OrderExpiry, OrderParams, OrderSide},
+    test_helper::TestAccount,
+    types::{
+        Asset, MarketStatus, MarketType, ScalarPosition, ScoringRule, SignatureTest,
+        TestAccountIdPK,
+    },
 };
+use sp_avn_common::{InnerCallValidator, Proof};
+use sp_core::Pair;
 use sp_runtime::{Perbill, Perquintill};
 use test_case::test_case;
 
@@ -43,12 +59,13 @@ fn place_order_fails_with_wrong_scoring_rule(scoring_rule: ScoringRule) {
         }));
         assert_noop!(
             Orderbook::place_order(
-                RuntimeOrigin::signed(ALICE),
+                RuntimeOrigin::signed(alice()),
                 market_id,
                 market.base_asset,
                 10 * BASE,
                 Asset::CategoricalOutcome(market_id, 2),
                 25 * BASE,
+                None,
             ),
             Error::<Runtime>::InvalidScoringRule
         );
@@ -72,12 +89,13 @@ fn place_order_fails_if_market_status_not_active(status: MarketStatus) {
         }));
         assert_noop!(
             Orderbook::place_order(
-                RuntimeOrigin::signed(ALICE),
+                RuntimeOrigin::signed(alice()),
                 market_id,
                 market.base_asset,
                 10 * BASE,
                 Asset::CategoricalOutcome(0, 2),
                 25 * BASE,
+                None,
             ),
             Error::<Runtime>::MarketIsNotActive
         );
@@ -101,12 +119,13 @@ fn fill_order_fails_if_market_status_not_active(status: MarketStatus) {
         let order_id = 0u128;
 
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(ALICE),
+            RuntimeOrigin::signed(alice()),
             market_id,
             maker_asset,
             10 * BASE,
             taker_asset,
             25 * BASE,
+            None,
         ));
 
         assert_ok!(MarketCommons::mutate_market(&market_id, |market| {
@@ -115,7 +134,7 @@ fn fill_order_fails_if_market_status_not_active(status: MarketStatus) {
         }));
 
         assert_noop!(
-            Orderbook::fill_order(RuntimeOrigin::signed(BOB), order_id, None),
+            Orderbook::fill_order(RuntimeOrigin::signed(bob()), order_id, None),
             Error::<Runtime>::MarketIsNotActive
         );
     });
@@ -135,21 +154,52 @@ fn fill_order_fails_if_amount_too_high_for_order() {
         let taker_amount = 25 * BASE;
 
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(ALICE),
+            RuntimeOrigin::signed(alice()),
             market_id,
             maker_asset,
             10 * BASE,
             taker_asset,
             taker_amount,
+            None,
         ));
 
         assert_noop!(
-            Orderbook::fill_order(RuntimeOrigin::signed(BOB), order_id, Some(taker_amount + 1)),
+            Orderbook::fill_order(RuntimeOrigin::signed(bob()), order_id, Some(taker_amount + 1)),
             Error::<Runtime>::AmountTooHighForOrder
         );
     });
 }
 
+#[test_case(OrderExpiry::Block(2), 3, 0; "block")]
+#[test_case(OrderExpiry::Timestamp(2_000), 1, 2_001; "timestamp")]
+fn fill_order_fails_if_order_has_expired(expiry: OrderExpiry, block: u64, now: u64) {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let taker_asset = Asset::CategoricalOutcome(0, 2);
+        let taker_amount = 25 * BASE;
+        assert_ok!(Orderbook::place_order(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            market.base_asset,
+            10 * BASE,
+            taker_asset,
+            taker_amount,
+            Some(expiry),
+        ));
+
+        System::set_block_number(block);
+        Timestamp::set_timestamp(now);
+        assert_ok!(AssetManager::deposit(taker_asset, &bob(), taker_amount));
+        assert_noop!(
+            Orderbook::fill_order(RuntimeOrigin::signed(bob()), 0, None),
+            Error::<Runtime>::OrderExpired
+        );
+    });
+}
+
 #[test]
 fn fill_order_fails_if_amount_is_below_minimum_balance() {
     ExtBuilder::default().build().execute_with(|| {
@@ -164,17 +214,18 @@ fn fill_order_fails_if_amount_is_below_minimum_balance() {
         let taker_amount = 25 * BASE;
 
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(ALICE),
+            RuntimeOrigin::signed(alice()),
             market_id,
             maker_asset,
             10 * BASE,
             taker_asset,
             taker_amount,
+            None,
         ));
 
         assert_noop!(
             Orderbook::fill_order(
-                RuntimeOrigin::signed(BOB),
+                RuntimeOrigin::signed(bob()),
                 order_id,
                 Some(AssetManager::minimum_balance(taker_asset) - 1)
             ),
@@ -195,24 +246,26 @@ fn place_order_fails_if_amount_is_below_minimum_balance() {
 
         assert_noop!(
             Orderbook::place_order(
-                RuntimeOrigin::signed(ALICE),
+                RuntimeOrigin::signed(alice()),
                 market_id,
                 maker_asset,
                 AssetManager::minimum_balance(maker_asset) - 1,
                 taker_asset,
                 AssetManager::minimum_balance(taker_asset),
+                None,
             ),
             Error::<Runtime>::BelowMinimumBalance
         );
 
         assert_noop!(
             Orderbook::place_order(
-                RuntimeOrigin::signed(ALICE),
+                RuntimeOrigin::signed(alice()),
                 market_id,
                 maker_asset,
                 AssetManager::minimum_balance(maker_asset),
                 taker_asset,
                 AssetManager::minimum_balance(taker_asset) - 1,
+                None,
             ),
             Error::<Runtime>::BelowMinimumBalance
         );
@@ -233,20 +286,21 @@ fn fill_order_fails_if_balance_too_low() {
         let taker_amount = 25 * BASE;
 
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(ALICE),
+            RuntimeOrigin::signed(alice()),
             market_id,
             maker_asset,
             10 * BASE,
             taker_asset,
             taker_amount,
+            None,
         ));
 
-        AssetManager::deposit(taker_asset, &BOB, taker_amount - 1).unwrap();
-        let bob_free_taker_asset = AssetManager::free_balance(taker_asset, &BOB);
+        AssetManager::deposit(taker_asset, &bob(), taker_amount - 1).unwrap();
+        let bob_free_taker_asset = AssetManager::free_balance(taker_asset, &bob());
         assert_eq!(bob_free_taker_asset, taker_amount - 1);
 
         assert_noop!(
-            Orderbook::fill_order(RuntimeOrigin::signed(BOB), order_id, None),
+            Orderbook::fill_order(RuntimeOrigin::signed(bob()), order_id, None),
             AError::<Runtime>::BalanceTooLow
         );
     });
@@ -266,19 +320,20 @@ fn fill_order_fails_if_partial_fill_near_full_fill_not_allowed() {
         let taker_amount = 25 * BASE;
 
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(ALICE),
+            RuntimeOrigin::signed(alice()),
             market_id,
             maker_asset,
             10 * BASE,
             taker_asset,
             taker_amount,
+            None,
         ));
 
-        AssetManager::deposit(taker_asset, &BOB, taker_amount).unwrap();
+        AssetManager::deposit(taker_asset, &bob(), taker_amount).unwrap();
 
         assert_noop!(
             Orderbook::fill_order(
-                RuntimeOrigin::signed(BOB),
+                RuntimeOrigin::signed(bob()),
                 order_id,
                 Some(taker_amount - AssetManager::minimum_balance(taker_asset) + 1)
             ),
@@ -301,17 +356,18 @@ fn fill_order_removes_order() {
         let taker_amount = 25 * BASE;
 
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(ALICE),
+            RuntimeOrigin::signed(alice()),
             market_id,
             maker_asset,
             10 * BASE,
             taker_asset,
             taker_amount,
+            None,
         ));
 
-        AssetManager::deposit(taker_asset, &BOB, taker_amount).unwrap();
+        AssetManager::deposit(taker_asset, &bob(), taker_amount).unwrap();
 
-        assert_ok!(Orderbook::fill_order(RuntimeOrigin::signed(BOB), order_id, None));
+        assert_ok!(Orderbook::fill_order(RuntimeOrigin::signed(bob()), order_id, None));
 
         assert!(Orders::<Runtime>::get(order_id).is_none());
     });
@@ -332,24 +388,33 @@ fn fill_order_partially_fills_order() {
         let maker_amount = 10 * BASE;
 
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(ALICE),
+            RuntimeOrigin::signed(alice()),
             market_id,
             maker_asset,
             maker_amount,
             taker_asset,
             taker_amount,
+            None,
         ));
 
-        AssetManager::deposit(taker_asset, &BOB, taker_amount).unwrap();
+        AssetManager::deposit(taker_asset, &bob(), taker_amount).unwrap();
 
         let order = Orders::<Runtime>::get(order_id).unwrap();
         assert_eq!(
             order,
-            Order { market_id, maker: ALICE, maker_asset, maker_amount, taker_asset, taker_amount }
+            Order {
+                market_id,
+                maker: alice(),
+                maker_asset,
+                maker_amount,
+                taker_asset,
+                taker_amount,
+                expiry: None,
+            }
         );
 
         assert_ok!(Orderbook::fill_order(
-            RuntimeOrigin::signed(BOB),
+            RuntimeOrigin::signed(bob()),
             order_id,
             Some(taker_amount / 2)
         ));
@@ -360,16 +425,42 @@ fn fill_order_partially_fills_order() {
             order,
             Order {
                 market_id,
-                maker: ALICE,
+                maker: alice(),
                 maker_asset,
                 maker_amount: 5 * BASE,
                 taker_asset,
                 taker_amount: 125_000_000_000,
+                expiry: None,
             }
         );
     });
 }
 
+#[test_case(OrderExpiry::Block(4), 5, 0; "block")]
+#[test_case(OrderExpiry::Timestamp(4_000), 1, 4_001; "timestamp")]
+fn place_order_fails_if_expiry_has_passed(expiry: OrderExpiry, block: u64, now: u64) {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        System::set_block_number(block);
+        Timestamp::set_timestamp(now);
+        assert_noop!(
+            Orderbook::place_order(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                market.base_asset,
+                10 * BASE,
+                Asset::CategoricalOutcome(0, 2),
+                25 * BASE,
+                Some(expiry),
+            ),
+            Error::<Runtime>::OrderExpired
+        );
+    });
+}
+
 #[test]
 fn place_order_fails_if_market_base_asset_not_present() {
     ExtBuilder::default().build().execute_with(|| {
@@ -382,12 +473,13 @@ fn place_order_fails_if_market_base_asset_not_present() {
 
         assert_noop!(
             Orderbook::place_order(
-                RuntimeOrigin::signed(ALICE),
+                RuntimeOrigin::signed(alice()),
                 market_id,
                 maker_asset,
                 10 * BASE,
                 taker_asset,
                 25 * BASE,
+                None,
             ),
             Error::<Runtime>::MarketBaseAssetNotPresent
         );
@@ -407,12 +499,13 @@ fn place_order_fails_if_invalid_outcome_asset() {
 
         assert_noop!(
             Orderbook::place_order(
-                RuntimeOrigin::signed(ALICE),
+                RuntimeOrigin::signed(alice()),
                 market_id,
                 maker_asset,
                 10 * BASE,
                 taker_asset,
                 25 * BASE,
+                None,
             ),
             Error::<Runtime>::InvalidOutcomeAsset
         );
@@ -429,12 +522,13 @@ fn place_order_fails_if_market_not_found() {
 
         assert_noop!(
             Orderbook::place_order(
-                RuntimeOrigin::signed(ALICE),
+                RuntimeOrigin::signed(alice()),
                 market_id,
                 maker_asset,
                 10 * BASE,
                 taker_asset,
                 25 * BASE,
+                None,
             ),
             MError::<Runtime>::MarketDoesNotExist
         );
@@ -448,12 +542,12 @@ fn place_order_fails_if_maker_has_insufficient_funds() {
         let market = market_mock::<Runtime>();
         Markets::<Runtime>::insert(market_id, market.clone());
 
-        let maker = ALICE;
+        let maker = alice();
         let maker_asset = market.base_asset;
         let taker_asset = Asset::CategoricalOutcome(0, 2);
         let alice_free_maker_amount = AssetManager::free_balance(maker_asset, &maker);
 
-        AssetManager::withdraw(maker_asset, &ALICE, alice_free_maker_amount).unwrap();
+        AssetManager::withdraw(maker_asset, &alice(), alice_free_maker_amount).unwrap();
 
         assert_noop!(
             Orderbook::place_order(
@@ -463,6 +557,7 @@ fn place_order_fails_if_maker_has_insufficient_funds() {
                 10 * BASE,
                 taker_asset,
                 25 * BASE,
+                None,
             ),
             BError::<Runtime>::InsufficientBalance,
         );
@@ -474,12 +569,12 @@ fn it_fails_order_does_not_exist() {
     ExtBuilder::default().build().execute_with(|| {
         let order_id = 0u128;
         assert_noop!(
-            Orderbook::fill_order(RuntimeOrigin::signed(ALICE), order_id, None),
+            Orderbook::fill_order(RuntimeOrigin::signed(alice()), order_id, None),
             Error::<Runtime>::OrderDoesNotExist,
         );
 
         assert_noop!(
-            Orderbook::remove_order(RuntimeOrigin::signed(ALICE), order_id),
+            Orderbook::remove_order(RuntimeOrigin::signed(alice()), order_id),
             Error::<Runtime>::OrderDoesNotExist,
         );
     });
@@ -497,36 +592,38 @@ fn it_places_orders() {
         let taker_amount = 10 * BASE;
         let maker_amount = 250 * BASE;
 
-        assert_ok!(AssetManager::deposit(market.base_asset, &ALICE, maker_amount));
+        assert_ok!(AssetManager::deposit(market.base_asset, &alice(), maker_amount));
 
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(ALICE),
+            RuntimeOrigin::signed(alice()),
             market_id,
             market.base_asset,
             maker_amount,
             taker_asset_0,
             taker_amount,
+            None,
         ));
 
-        let reserved_funds = AssetManager::reserved_balance(market.base_asset, &ALICE);
+        let reserved_funds = AssetManager::reserved_balance(market.base_asset, &alice());
         assert_eq!(reserved_funds, maker_amount);
 
         let maker_asset = Asset::CategoricalOutcome(0, 1);
 
         let maker_amount = 10 * BASE;
         let taker_amount = 5 * BASE;
-        assert_ok!(AssetManager::deposit(maker_asset, &BOB, maker_amount));
+        assert_ok!(AssetManager::deposit(maker_asset, &bob(), maker_amount));
 
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(BOB),
+            RuntimeOrigin::signed(bob()),
             market_id,
             maker_asset,
             maker_amount,
             market.base_asset,
             taker_amount,
+            None,
         ));
 
-        let shares_reserved = AssetManager::reserved_balance(maker_asset, &BOB);
+        let shares_reserved = AssetManager::reserved_balance(maker_asset, &bob());
         assert_eq!(shares_reserved, maker_amount);
     });
 }
@@ -543,41 +640,43 @@ fn it_fills_order_fully_maker_outcome_asset() {
 
         let maker_amount = 100 * BASE;
         let taker_amount = 500 * BASE;
-        assert_ok!(AssetManager::deposit(maker_asset, &BOB, maker_amount));
+        assert_ok!(AssetManager::deposit(maker_asset, &bob(), maker_amount));
 
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(BOB),
+            RuntimeOrigin::signed(bob()),
             market_id,
             maker_asset,
             maker_amount,
             taker_asset,
             taker_amount,
+            None,
         ));
 
-        let reserved_bob = AssetManager::reserved_balance(maker_asset, &BOB);
+        let reserved_bob = AssetManager::reserved_balance(maker_asset, &bob());
         assert_eq!(reserved_bob, maker_amount);
 
         let market_creator_balance_before =
-            AssetManager::free_balance(taker_asset, &MARKET_CREATOR);
+            AssetManager::free_balance(taker_asset, &market_creator());
 
         let order_id = 0u128;
-        assert_ok!(AssetManager::deposit(taker_asset, &ALICE, taker_amount));
-        assert_ok!(Orderbook::fill_order(RuntimeOrigin::signed(ALICE), order_id, None));
+        assert_ok!(AssetManager::deposit(taker_asset, &alice(), taker_amount));
+        assert_ok!(Orderbook::fill_order(RuntimeOrigin::signed(alice()), order_id, None));
 
-        let market_creator_balance_after = AssetManager::free_balance(taker_asset, &MARKET_CREATOR);
+        let market_creator_balance_after =
+            AssetManager::free_balance(taker_asset, &market_creator());
         let taker_fees = calculate_fee::<Runtime>(taker_amount);
         assert_eq!(market_creator_balance_after - market_creator_balance_before, taker_fees);
 
-        let reserved_bob = AssetManager::reserved_balance(maker_asset, &BOB);
+        let reserved_bob = AssetManager::reserved_balance(maker_asset, &bob());
         assert_eq!(reserved_bob, 0);
 
-        let external_fee = ExternalFee { account: BOB, amount: taker_fees };
+        let external_fee = ExternalFee { account: bob(), amount: taker_fees };
 
         System::assert_last_event(
             Event::<Runtime>::OrderFilled {
                 order_id,
-                maker: BOB,
-                taker: ALICE,
+                maker: bob(),
+                taker: alice(),
                 filled_maker_amount: maker_amount,
                 filled_taker_amount: taker_amount,
                 unfilled_maker_amount: 0,
@@ -587,13 +686,13 @@ fn it_fills_order_fully_maker_outcome_asset() {
             .into(),
         );
 
-        let alice_maker_asset_free = AssetManager::free_balance(taker_asset, &ALICE);
-        let alice_taker_asset_free = AssetManager::free_balance(maker_asset, &ALICE);
+        let alice_maker_asset_free = AssetManager::free_balance(taker_asset, &alice());
+        let alice_taker_asset_free = AssetManager::free_balance(maker_asset, &alice());
         assert_eq!(alice_maker_asset_free, INITIAL_BALANCE);
         assert_eq!(alice_taker_asset_free, maker_amount);
 
-        let bob_taker_asset_free = AssetManager::free_balance(market.base_asset, &BOB);
-        let bob_maker_asset_free = AssetManager::free_balance(maker_asset, &BOB);
+        let bob_taker_asset_free = AssetManager::free_balance(market.base_asset, &bob());
+        let bob_maker_asset_free = AssetManager::free_balance(maker_asset, &bob());
         assert_eq!(bob_taker_asset_free, INITIAL_BALANCE + taker_amount - taker_fees);
         assert_eq!(bob_maker_asset_free, 0);
     });
@@ -613,32 +712,33 @@ fn it_fills_order_fully_maker_base_asset() {
         let maker_amount = 50 * BASE;
 
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(BOB),
+            RuntimeOrigin::signed(bob()),
             market_id,
             maker_asset,
             maker_amount,
             taker_asset,
             taker_amount,
+            None,
         ));
 
-        let reserved_bob = AssetManager::reserved_balance(maker_asset, &BOB);
+        let reserved_bob = AssetManager::reserved_balance(maker_asset, &bob());
         assert_eq!(reserved_bob, maker_amount);
 
         let order_id = 0u128;
-        assert_ok!(AssetManager::deposit(taker_asset, &ALICE, taker_amount));
-        assert_ok!(Orderbook::fill_order(RuntimeOrigin::signed(ALICE), order_id, None));
+        assert_ok!(AssetManager::deposit(taker_asset, &alice(), taker_amount));
+        assert_ok!(Orderbook::fill_order(RuntimeOrigin::signed(alice()), order_id, None));
 
-        let reserved_bob = AssetManager::reserved_balance(taker_asset, &BOB);
+        let reserved_bob = AssetManager::reserved_balance(taker_asset, &bob());
         assert_eq!(reserved_bob, 0);
 
         let external_fee_amount = calculate_fee::<Runtime>(maker_amount);
-        let external_fee = ExternalFee { account: ALICE, amount: external_fee_amount };
+        let external_fee = ExternalFee { account: alice(), amount: external_fee_amount };
 
         System::assert_last_event(
             Event::<Runtime>::OrderFilled {
                 order_id,
-                maker: BOB,
-                taker: ALICE,
+                maker: bob(),
+                taker: alice(),
                 filled_maker_amount: maker_amount,
                 filled_taker_amount: taker_amount,
                 unfilled_taker_amount: 0,
@@ -648,15 +748,15 @@ fn it_fills_order_fully_maker_base_asset() {
             .into(),
         );
 
-        let alice_maker_asset_free = AssetManager::free_balance(maker_asset, &ALICE);
-        let alice_taker_asset_free = AssetManager::free_balance(taker_asset, &ALICE);
+        let alice_maker_asset_free = AssetManager::free_balance(maker_asset, &alice());
+        let alice_taker_asset_free = AssetManager::free_balance(taker_asset, &alice());
         let maker_fees = calculate_fee::<Runtime>(maker_amount);
         let maker_amount_minus_fees = maker_amount - maker_fees;
         assert_eq!(alice_maker_asset_free, INITIAL_BALANCE + maker_amount_minus_fees);
         assert_eq!(alice_taker_asset_free, 0);
 
-        let bob_bal = AssetManager::free_balance(maker_asset, &BOB);
-        let bob_shares = AssetManager::free_balance(taker_asset, &BOB);
+        let bob_bal = AssetManager::free_balance(maker_asset, &bob());
+        let bob_shares = AssetManager::free_balance(taker_asset, &bob());
         assert_eq!(bob_bal, INITIAL_BALANCE - maker_amount);
         assert_eq!(bob_shares, taker_amount);
     });
@@ -675,31 +775,32 @@ fn it_fills_order_partially_maker_base_asset() {
         let maker_amount = 500 * BASE;
         let taker_amount = 100 * BASE;
 
-        assert_ok!(AssetManager::deposit(maker_asset, &BOB, maker_amount));
+        assert_ok!(AssetManager::deposit(maker_asset, &bob(), maker_amount));
 
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(BOB),
+            RuntimeOrigin::signed(bob()),
             market_id,
             maker_asset,
             maker_amount,
             taker_asset,
             taker_amount,
+            None,
         ));
 
-        let reserved_bob = AssetManager::reserved_balance(maker_asset, &BOB);
+        let reserved_bob = AssetManager::reserved_balance(maker_asset, &bob());
         assert_eq!(reserved_bob, maker_amount);
 
         let order_id = 0u128;
-        assert_ok!(AssetManager::deposit(taker_asset, &ALICE, taker_amount));
+        assert_ok!(AssetManager::deposit(taker_asset, &alice(), taker_amount));
 
-        let market_creator_free_before = AssetManager::free_balance(maker_asset, &MARKET_CREATOR);
+        let market_creator_free_before = AssetManager::free_balance(maker_asset, &market_creator());
 
         // instead of selling 100 shares, Alice sells 70 shares
         let alice_portion = 70 * BASE;
         assert!(alice_portion < taker_amount);
         let alice_taker_asset_free_left = taker_amount - alice_portion;
         let portion = Some(alice_portion);
-        assert_ok!(Orderbook::fill_order(RuntimeOrigin::signed(ALICE), order_id, portion,));
+        assert_ok!(Orderbook::fill_order(RuntimeOrigin::signed(alice()), order_id, portion,));
 
         let order = Orders::<Runtime>::get(order_id).unwrap();
         let unfilled_taker_amount = taker_amount - alice_portion;
@@ -713,21 +814,22 @@ fn it_fills_order_partially_maker_base_asset() {
             order,
             Order {
                 market_id,
-                maker: BOB,
+                maker: bob(),
                 maker_asset,
                 maker_amount: unfilled_maker_amount,
                 taker_asset,
                 taker_amount: unfilled_taker_amount,
+                expiry: None,
             }
         );
 
-        let external_fee = ExternalFee { account: ALICE, amount: external_fee_amount };
+        let external_fee = ExternalFee { account: alice(), amount: external_fee_amount };
 
         System::assert_last_event(
             Event::<Runtime>::OrderFilled {
                 order_id,
-                maker: BOB,
-                taker: ALICE,
+                maker: bob(),
+                taker: alice(),
                 filled_maker_amount,
                 filled_taker_amount: alice_portion,
                 unfilled_maker_amount,
@@ -737,12 +839,12 @@ fn it_fills_order_partially_maker_base_asset() {
             .into(),
         );
 
-        let market_creator_free_after = AssetManager::free_balance(maker_asset, &MARKET_CREATOR);
+        let market_creator_free_after = AssetManager::free_balance(maker_asset, &market_creator());
         let maker_fees = calculate_fee::<Runtime>(filled_maker_amount);
         assert_eq!(market_creator_free_after - market_creator_free_before, maker_fees);
 
-        let alice_maker_asset_free = AssetManager::free_balance(maker_asset, &ALICE);
-        let alice_taker_asset_free = AssetManager::free_balance(taker_asset, &ALICE);
+        let alice_maker_asset_free = AssetManager::free_balance(maker_asset, &alice());
+        let alice_taker_asset_free = AssetManager::free_balance(taker_asset, &alice());
         let filled_maker_amount =
             Perquintill::from_rational(alice_portion, taker_amount).mul_floor(maker_amount);
         let filled_maker_amount_minus_fees =
@@ -750,12 +852,12 @@ fn it_fills_order_partially_maker_base_asset() {
         assert_eq!(alice_maker_asset_free, INITIAL_BALANCE + filled_maker_amount_minus_fees);
         assert_eq!(alice_taker_asset_free, alice_taker_asset_free_left);
 
-        let bob_maker_asset_free = AssetManager::free_balance(maker_asset, &BOB);
-        let bob_taker_asset_free = AssetManager::free_balance(taker_asset, &BOB);
+        let bob_maker_asset_free = AssetManager::free_balance(maker_asset, &bob());
+        let bob_taker_asset_free = AssetManager::free_balance(taker_asset, &bob());
         assert_eq!(bob_maker_asset_free, INITIAL_BALANCE);
         assert_eq!(bob_taker_asset_free, alice_portion);
 
-        let reserved_bob = AssetManager::reserved_balance(maker_asset, &BOB);
+        let reserved_bob = AssetManager::reserved_balance(maker_asset, &bob());
         assert_eq!(reserved_bob, unfilled_maker_amount);
     });
 }
@@ -773,32 +875,33 @@ fn it_fills_order_partially_maker_outcome_asset() {
         let maker_amount = 100 * BASE;
         let taker_amount = 500 * BASE;
 
-        assert_ok!(AssetManager::deposit(maker_asset, &BOB, maker_amount));
+        assert_ok!(AssetManager::deposit(maker_asset, &bob(), maker_amount));
 
         // Make an order from Bob to sell outcome tokens.
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(BOB),
+            RuntimeOrigin::signed(bob()),
             market_id,
             maker_asset,
             maker_amount,
             taker_asset,
             taker_amount,
+            None,
         ));
 
-        let reserved_bob = AssetManager::reserved_balance(maker_asset, &BOB);
+        let reserved_bob = AssetManager::reserved_balance(maker_asset, &bob());
         assert_eq!(reserved_bob, maker_amount);
 
         let order_id = 0u128;
         let market_creator_free_balance_before =
-            AssetManager::free_balance(market.base_asset, &MARKET_CREATOR);
+            AssetManager::free_balance(market.base_asset, &market_creator());
 
         // instead of buying 500 of the base asset, Alice buys 70 shares
         let alice_portion = 70 * BASE;
         let portion = Some(alice_portion);
-        assert_ok!(Orderbook::fill_order(RuntimeOrigin::signed(ALICE), order_id, portion,));
+        assert_ok!(Orderbook::fill_order(RuntimeOrigin::signed(alice()), order_id, portion,));
 
         let market_creator_free_balance_after =
-            AssetManager::free_balance(market.base_asset, &MARKET_CREATOR);
+            AssetManager::free_balance(market.base_asset, &market_creator());
         let external_fee_amount = calculate_fee::<Runtime>(70 * BASE);
         assert_eq!(
             market_creator_free_balance_after - market_creator_free_balance_before,
@@ -811,25 +914,26 @@ fn it_fills_order_partially_maker_outcome_asset() {
             order,
             Order {
                 market_id,
-                maker: BOB,
+                maker: bob(),
                 maker_asset,
                 // from 100 to 86 changed (partially filled) minus fees
                 maker_amount: filled_maker_amount,
                 taker_asset,
                 // from 500 to 430 changed (partially filled)
                 taker_amount: taker_amount - alice_portion,
+                expiry: None,
             }
         );
 
-        let reserved_bob = AssetManager::reserved_balance(maker_asset, &BOB);
+        let reserved_bob = AssetManager::reserved_balance(maker_asset, &bob());
         assert_eq!(reserved_bob, filled_maker_amount);
-        let external_fee = ExternalFee { account: BOB, amount: external_fee_amount };
+        let external_fee = ExternalFee { account: bob(), amount: external_fee_amount };
 
         System::assert_last_event(
             Event::<Runtime>::OrderFilled {
                 order_id,
-                maker: BOB,
-                taker: ALICE,
+                maker: bob(),
+                taker: alice(),
                 // this is confusing, it's 140_000_000_000, so the invert of 860_000_000_000, which
                 // got filled
                 filled_maker_amount: maker_amount - filled_maker_amount,
@@ -841,8 +945,8 @@ fn it_fills_order_partially_maker_outcome_asset() {
             .into(),
         );
 
-        let alice_taker_asset_free = AssetManager::free_balance(taker_asset, &ALICE);
-        let alice_maker_asset_free = AssetManager::free_balance(maker_asset, &ALICE);
+        let alice_taker_asset_free = AssetManager::free_balance(taker_asset, &alice());
+        let alice_maker_asset_free = AssetManager::free_balance(maker_asset, &alice());
         assert_eq!(alice_taker_asset_free, INITIAL_BALANCE - alice_portion);
         assert_eq!(
             alice_maker_asset_free,
@@ -850,13 +954,13 @@ fn it_fills_order_partially_maker_outcome_asset() {
         );
         assert_eq!(alice_maker_asset_free, 140_000_000_000);
 
-        let bob_taker_asset_free = AssetManager::free_balance(taker_asset, &BOB);
-        let bob_maker_asset_free = AssetManager::free_balance(maker_asset, &BOB);
+        let bob_taker_asset_free = AssetManager::free_balance(taker_asset, &bob());
+        let bob_maker_asset_free = AssetManager::free_balance(maker_asset, &bob());
         let filled_minus_fees = alice_portion - calculate_fee::<Runtime>(alice_portion);
         assert_eq!(bob_taker_asset_free, INITIAL_BALANCE + filled_minus_fees);
         assert_eq!(bob_maker_asset_free, 0);
 
-        let reserved_bob = AssetManager::reserved_balance(maker_asset, &BOB);
+        let reserved_bob = AssetManager::reserved_balance(maker_asset, &bob());
         assert_eq!(reserved_bob, filled_maker_amount);
     });
 }
@@ -875,30 +979,39 @@ fn it_removes_order() {
         let maker_amount = 10 * BASE;
 
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(ALICE),
+            RuntimeOrigin::signed(alice()),
             market_id,
             maker_asset,
             maker_amount,
             taker_asset,
             taker_amount,
+            None,
         ));
 
-        let reserved_funds = AssetManager::reserved_balance(market.base_asset, &ALICE);
+        let reserved_funds = AssetManager::reserved_balance(market.base_asset, &alice());
         assert_eq!(reserved_funds, maker_amount);
 
         let order_id = 0u128;
         let order = Orders::<Runtime>::get(order_id).unwrap();
         assert_eq!(
             order,
-            Order { market_id, maker: ALICE, maker_asset, maker_amount, taker_asset, taker_amount }
+            Order {
+                market_id,
+                maker: alice(),
+                maker_asset,
+                maker_amount,
+                taker_asset,
+                taker_amount,
+                expiry: None,
+            }
         );
 
-        let reserved_funds = AssetManager::reserved_balance(market.base_asset, &ALICE);
+        let reserved_funds = AssetManager::reserved_balance(market.base_asset, &alice());
         assert_eq!(reserved_funds, maker_amount);
 
-        assert_ok!(Orderbook::remove_order(RuntimeOrigin::signed(ALICE), order_id));
+        assert_ok!(Orderbook::remove_order(RuntimeOrigin::signed(alice()), order_id));
 
-        let reserved_funds = AssetManager::reserved_balance(market.base_asset, &ALICE);
+        let reserved_funds = AssetManager::reserved_balance(market.base_asset, &alice());
         assert_eq!(reserved_funds, 0);
 
         assert!(Orders::<Runtime>::get(order_id).is_none());
@@ -919,19 +1032,22 @@ fn remove_order_emits_event() {
         let maker_amount = 10 * BASE;
 
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(ALICE),
+            RuntimeOrigin::signed(alice()),
             market_id,
             maker_asset,
             maker_amount,
             taker_asset,
             taker_amount,
+            None,
         ));
 
         let order_id = 0u128;
 
-        assert_ok!(Orderbook::remove_order(RuntimeOrigin::signed(ALICE), order_id));
+        assert_ok!(Orderbook::remove_order(RuntimeOrigin::signed(alice()), order_id));
 
-        System::assert_last_event(Event::<Runtime>::OrderRemoved { order_id, maker: ALICE }.into());
+        System::assert_last_event(
+            Event::<Runtime>::OrderRemoved { order_id, maker: alice() }.into(),
+        );
     });
 }
 
@@ -949,18 +1065,19 @@ fn remove_order_fails_if_not_order_creator() {
         let maker_amount = 10 * BASE;
 
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(BOB),
+            RuntimeOrigin::signed(bob()),
             market_id,
             maker_asset,
             maker_amount,
             taker_asset,
             taker_amount,
+            None,
         ));
 
         let order_id = 0u128;
 
         assert_noop!(
-            Orderbook::remove_order(RuntimeOrigin::signed(ALICE), order_id),
+            Orderbook::remove_order(RuntimeOrigin::signed(alice()), order_id),
             Error::<Runtime>::NotOrderCreator
         );
     });
@@ -980,12 +1097,13 @@ fn place_order_emits_event() {
         let maker_amount = 10 * BASE;
 
         assert_ok!(Orderbook::place_order(
-            RuntimeOrigin::signed(ALICE),
+            RuntimeOrigin::signed(alice()),
             market_id,
             maker_asset,
             maker_amount,
             taker_asset,
             taker_amount,
+            None,
         ));
 
         let order_id = 0u128;
@@ -994,14 +1112,878 @@ fn place_order_emits_event() {
                 order_id,
                 order: Order {
                     market_id,
-                    maker: ALICE,
+                    maker: alice(),
                     maker_asset,
                     maker_amount,
                     taker_asset,
                     taker_amount,
+                    expiry: None,
                 },
             }
             .into(),
         );
     });
 }
+
+#[test]
+fn place_order_adds_order_to_indexes() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let outcome_asset = Asset::CategoricalOutcome(0, 2);
+        assert_ok!(Orderbook::place_order(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            market.base_asset,
+            10 * BASE,
+            outcome_asset,
+            25 * BASE,
+            None,
+        ));
+        assert_ok!(AssetManager::deposit(outcome_asset, &bob(), 10 * BASE));
+        assert_ok!(Orderbook::place_order(
+            RuntimeOrigin::signed(bob()),
+            market_id,
+            outcome_asset,
+            10 * BASE,
+            market.base_asset,
+            5 * BASE,
+            None,
+        ));
+
+        assert_eq!(MarketOrders::<Runtime>::get(market_id).into_inner(), vec![0, 1]);
+        assert_eq!(
+            OutcomeAssetOrders::<Runtime>::get(outcome_asset, OrderSide::Bid).into_inner(),
+            vec![0]
+        );
+        assert_eq!(
+            OutcomeAssetOrders::<Runtime>::get(outcome_asset, OrderSide::Ask).into_inner(),
+            vec![1]
+        );
+        assert_eq!(Orderbook::maker_orders(&alice()), vec![0]);
+        assert_eq!(Orderbook::maker_orders(&bob()), vec![1]);
+    });
+}
+
+#[test]
+fn fill_order_removes_order_from_indexes_only_on_full_fill() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let outcome_asset = Asset::CategoricalOutcome(0, 2);
+        let order_id = 0u128;
+        let taker_amount = 20 * BASE;
+        assert_ok!(Orderbook::place_order(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            market.base_asset,
+            10 * BASE,
+            outcome_asset,
+            taker_amount,
+            None,
+        ));
+        assert_ok!(AssetManager::deposit(outcome_asset, &bob(), taker_amount));
+
+        assert_ok!(Orderbook::fill_order(
+            RuntimeOrigin::signed(bob()),
+            order_id,
+            Some(taker_amount / 2)
+        ));
+        assert_eq!(Orderbook::market_orders(market_id), vec![order_id]);
+        assert_eq!(Orderbook::maker_orders(&alice()), vec![order_id]);
+
+        assert_ok!(Orderbook::fill_order(RuntimeOrigin::signed(bob()), order_id, None));
+        assert!(!MarketOrders::<Runtime>::contains_key(market_id));
+        assert!(!OutcomeAssetOrders::<Runtime>::contains_key(outcome_asset, OrderSide::Bid));
+        assert!(!MakerOrders::<Runtime>::contains_key(alice()));
+    });
+}
+
+#[test]
+fn remove_order_removes_order_from_indexes() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let outcome_asset = Asset::CategoricalOutcome(0, 2);
+        for _ in 0..3 {
+            assert_ok!(Orderbook::place_order(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                market.base_asset,
+                10 * BASE,
+                outcome_asset,
+                25 * BASE,
+                None,
+            ));
+        }
+
+        assert_ok!(Orderbook::remove_order(RuntimeOrigin::signed(alice()), 1));
+
+        assert_eq!(Orderbook::market_orders(market_id), vec![0, 2]);
+        assert_eq!(
+            OutcomeAssetOrders::<Runtime>::get(outcome_asset, OrderSide::Bid).into_inner(),
+            vec![0, 2]
+        );
+        assert_eq!(Orderbook::maker_orders(&alice()), vec![0, 2]);
+    });
+}
+
+#[test]
+fn place_order_fails_if_too_many_orders_for_maker() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market = market_mock::<Runtime>();
+        // The orders are spread across markets to stay below `MaxOrdersPerMakerPerMarket`.
+        let market_ids = [0u128, 1u128];
+        for market_id in market_ids {
+            Markets::<Runtime>::insert(market_id, market.clone());
+        }
+
+        for i in 0..MaxOrdersPerMaker::get() {
+            let market_id = market_ids[i as usize % market_ids.len()];
+            assert_ok!(Orderbook::place_order(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                market.base_asset,
+                BASE,
+                Asset::CategoricalOutcome(market_id, 2),
+                2 * BASE,
+                None,
+            ));
+        }
+
+        assert_noop!(
+            Orderbook::place_order(
+                RuntimeOrigin::signed(alice()),
+                0,
+                market.base_asset,
+                BASE,
+                Asset::CategoricalOutcome(0, 2),
+                2 * BASE,
+                None,
+            ),
+            Error::<Runtime>::TooManyOrdersForMaker
+        );
+    });
+}
+
+#[test]
+fn place_order_fails_if_too_many_orders_for_maker_in_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let outcome_asset = Asset::CategoricalOutcome(0, 2);
+        let place_order = |who| {
+            Orderbook::place_order(
+                RuntimeOrigin::signed(who),
+                market_id,
+                market.base_asset,
+                BASE,
+                outcome_asset,
+                2 * BASE,
+                None,
+            )
+        };
+        for _ in 0..MaxOrdersPerMakerPerMarket::get() {
+            assert_ok!(place_order(alice()));
+        }
+        assert_eq!(
+            MakerMarketOrderCount::<Runtime>::get(alice(), market_id),
+            MaxOrdersPerMakerPerMarket::get()
+        );
+
+        assert_noop!(place_order(alice()), Error::<Runtime>::TooManyOrdersForMakerInMarket);
+        // Other makers can still place orders in the market.
+        assert_ok!(place_order(bob()));
+
+        // Removing an order frees a slot of the maker.
+        assert_ok!(Orderbook::remove_order(RuntimeOrigin::signed(alice()), 0));
+        assert_eq!(
+            MakerMarketOrderCount::<Runtime>::get(alice(), market_id),
+            MaxOrdersPerMakerPerMarket::get() - 1
+        );
+        assert_ok!(place_order(alice()));
+    });
+}
+
+#[test]
+fn best_orders_sorts_bids_and_asks_by_price() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let outcome_asset = Asset::CategoricalOutcome(0, 2);
+        // Bids at 0.4, 0.5 and 0.4 in units of the base asset.
+        for (maker_amount, taker_amount) in
+            [(4 * BASE, 10 * BASE), (5 * BASE, 10 * BASE), (2 * BASE, 5 * BASE)]
+        {
+            assert_ok!(Orderbook::place_order(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                market.base_asset,
+                maker_amount,
+                outcome_asset,
+                taker_amount,
+                None,
+            ));
+        }
+        // Asks at 0.7 and 0.6 in units of the base asset.
+        assert_ok!(AssetManager::deposit(outcome_asset, &bob(), 20 * BASE));
+        for taker_amount in [7 * BASE, 6 * BASE] {
+            assert_ok!(Orderbook::place_order(
+                RuntimeOrigin::signed(bob()),
+                market_id,
+                outcome_asset,
+                10 * BASE,
+                market.base_asset,
+                taker_amount,
+                None,
+            ));
+        }
+
+        let depth = Orderbook::best_orders(market_id, outcome_asset, 10).unwrap();
+        let bids = depth.bids.iter().map(|o| (o.order_id, o.price)).collect::<Vec<_>>();
+        assert_eq!(bids, vec![(1, BASE / 2), (0, 2 * BASE / 5), (2, 2 * BASE / 5)]);
+        let asks = depth.asks.iter().map(|o| (o.order_id, o.price)).collect::<Vec<_>>();
+        assert_eq!(asks, vec![(4, 3 * BASE / 5), (3, 7 * BASE / 10)]);
+
+        let depth = Orderbook::best_orders(market_id, outcome_asset, 1).unwrap();
+        assert_eq!(depth.bids.len(), 1);
+        assert_eq!(depth.bids[0].order_id, 1);
+        assert_eq!(depth.asks.len(), 1);
+        assert_eq!(depth.asks[0].order_id, 4);
+    });
+}
+
+#[test]
+fn best_orders_fails_if_invalid_outcome_asset() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market);
+
+        assert_eq!(
+            Orderbook::best_orders(market_id, Asset::CategoricalOutcome(1, 0), 10),
+            Err(Error::<Runtime>::InvalidOutcomeAsset.into())
+        );
+    });
+}
+
+#[test_case(MarketStatus::Closed; "closed")]
+#[test_case(MarketStatus::Resolved; "resolved")]
+fn on_idle_cancels_orders_of_inactive_market(status: MarketStatus) {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let outcome_asset = Asset::CategoricalOutcome(0, 2);
+        assert_ok!(Orderbook::place_order(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            market.base_asset,
+            10 * BASE,
+            outcome_asset,
+            25 * BASE,
+            None,
+        ));
+        assert_ok!(AssetManager::deposit(outcome_asset, &bob(), 5 * BASE));
+        assert_ok!(Orderbook::place_order(
+            RuntimeOrigin::signed(bob()),
+            market_id,
+            outcome_asset,
+            5 * BASE,
+            market.base_asset,
+            2 * BASE,
+            None,
+        ));
+        assert_ok!(MarketCommons::mutate_market(&market_id, |market| {
+            market.status = status;
+            Ok(())
+        }));
+
+        Orderbook::on_idle(1, Weight::MAX);
+
+        assert_eq!(Orders::<Runtime>::iter().count(), 0);
+        assert!(!MarketOrders::<Runtime>::contains_key(market_id));
+        assert!(!MakerOrders::<Runtime>::contains_key(alice()));
+        assert!(!MakerOrders::<Runtime>::contains_key(bob()));
+        assert_eq!(AssetManager::reserved_balance(market.base_asset, &alice()), 0);
+        assert_eq!(AssetManager::reserved_balance(outcome_asset, &bob()), 0);
+        assert_eq!(AssetManager::free_balance(outcome_asset, &bob()), 5 * BASE);
+        System::assert_has_event(
+            Event::<Runtime>::OrderCancelled {
+                order_id: 0,
+                maker: alice(),
+                asset: market.base_asset,
+                amount: 10 * BASE,
+            }
+            .into(),
+        );
+        System::assert_has_event(
+            Event::<Runtime>::OrderCancelled {
+                order_id: 1,
+                maker: bob(),
+                asset: outcome_asset,
+                amount: 5 * BASE,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn on_idle_keeps_orders_of_active_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        assert_ok!(Orderbook::place_order(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            market.base_asset,
+            10 * BASE,
+            Asset::CategoricalOutcome(0, 2),
+            25 * BASE,
+            None,
+        ));
+
+        Orderbook::on_idle(1, Weight::MAX);
+
+        assert!(Orders::<Runtime>::contains_key(0));
+        assert_eq!(Orderbook::market_orders(market_id), vec![0]);
+        assert_eq!(AssetManager::reserved_balance(market.base_asset, &alice()), 10 * BASE);
+    });
+}
+
+#[test]
+fn on_idle_cancels_orders_across_blocks_if_weight_is_exhausted() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        for _ in 0..3 {
+            assert_ok!(Orderbook::place_order(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                market.base_asset,
+                10 * BASE,
+                Asset::CategoricalOutcome(0, 2),
+                25 * BASE,
+                None,
+            ));
+        }
+        assert_ok!(MarketCommons::mutate_market(&market_id, |market| {
+            market.status = MarketStatus::Closed;
+            Ok(())
+        }));
+
+        let order_weight = <Runtime as Config>::WeightInfo::remove_order(MaxOrdersPerMarket::get());
+        let consumed = Orderbook::on_idle(1, order_weight.saturating_mul(2));
+        assert_eq!(consumed, order_weight.saturating_mul(2));
+        assert_eq!(Orderbook::market_orders(market_id), vec![2]);
+
+        Orderbook::on_idle(2, order_weight.saturating_mul(2));
+        assert!(Orderbook::market_orders(market_id).is_empty());
+        assert_eq!(AssetManager::reserved_balance(market.base_asset, &alice()), 0);
+    });
+}
+
+#[test]
+fn on_idle_removes_expired_orders_of_active_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let outcome_asset = Asset::CategoricalOutcome(0, 2);
+        for expiry in [Some(OrderExpiry::Block(2)), Some(OrderExpiry::Block(3)), None] {
+            assert_ok!(Orderbook::place_order(
+                RuntimeOrigin::signed(alice()),
+                market_id,
+                market.base_asset,
+                10 * BASE,
+                outcome_asset,
+                25 * BASE,
+                expiry,
+            ));
+        }
+
+        System::set_block_number(3);
+        let depth = Orderbook::best_orders(market_id, outcome_asset, 10).unwrap();
+        let bids = depth.bids.iter().map(|o| o.order_id).collect::<Vec<_>>();
+        assert_eq!(bids, vec![1, 2]);
+
+        Orderbook::on_idle(3, Weight::MAX);
+
+        assert!(!Orders::<Runtime>::contains_key(0));
+        assert_eq!(Orderbook::market_orders(market_id), vec![1, 2]);
+        assert_eq!(AssetManager::reserved_balance(market.base_asset, &alice()), 20 * BASE);
+        System::assert_has_event(
+            Event::<Runtime>::OrderExpired {
+                order_id: 0,
+                maker: alice(),
+                asset: market.base_asset,
+                amount: 10 * BASE,
+            }
+            .into(),
+        );
+    });
+}
+
+fn create_proof(who: &TestAccount, signed_payload: &[u8]) -> Proof<SignatureTest, TestAccountIdPK> {
+    let signature = SignatureTest::from(who.key_pair().sign(signed_payload));
+    Proof { signer: who.key_pair().public(), relayer: eve(), signature }
+}
+
+fn signed_place_order_proof(
+    who: &TestAccount,
+    market_id: u128,
+    maker_asset: Asset<u128>,
+    taker_asset: Asset<u128>,
+) -> Proof<SignatureTest, TestAccountIdPK> {
+    let nonce = UserNonces::<Runtime>::get(who.account_id());
+    let signed_payload = Orderbook::encode_signed_place_order_params(
+        &eve(),
+        nonce,
+        &market_id,
+        &maker_asset,
+        &(10 * BASE),
+        &taker_asset,
+        &(25 * BASE),
+        &None,
+    );
+    create_proof(who, &signed_payload)
+}
+
+#[test]
+fn signed_place_order_places_order_and_increments_nonce() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let maker_asset = market.base_asset;
+        let taker_asset = Asset::CategoricalOutcome(0, 2);
+        // Alice's seed is 0.
+        let alice_account = TestAccount::new([0; 32]);
+        let proof = signed_place_order_proof(&alice_account, market_id, maker_asset, taker_asset);
+
+        assert_ok!(Orderbook::signed_place_order(
+            RuntimeOrigin::signed(alice()),
+            proof,
+            market_id,
+            maker_asset,
+            10 * BASE,
+            taker_asset,
+            25 * BASE,
+            None,
+        ));
+
+        assert_eq!(
+            Orders::<Runtime>::get(0).unwrap(),
+            Order {
+                market_id,
+                maker: alice(),
+                maker_asset,
+                maker_amount: 10 * BASE,
+                taker_asset,
+                taker_amount: 25 * BASE,
+                expiry: None,
+            }
+        );
+        assert_eq!(AssetManager::reserved_balance(maker_asset, &alice()), 10 * BASE);
+        assert_eq!(UserNonces::<Runtime>::get(alice()), 1);
+    });
+}
+
+#[test]
+fn signed_place_order_fails_if_nonce_is_reused() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let maker_asset = market.base_asset;
+        let taker_asset = Asset::CategoricalOutcome(0, 2);
+        let alice_account = TestAccount::new([0; 32]);
+        let proof = signed_place_order_proof(&alice_account, market_id, maker_asset, taker_asset);
+
+        assert_ok!(Orderbook::signed_place_order(
+            RuntimeOrigin::signed(alice()),
+            proof.clone(),
+            market_id,
+            maker_asset,
+            10 * BASE,
+            taker_asset,
+            25 * BASE,
+            None,
+        ));
+        assert_noop!(
+            Orderbook::signed_place_order(
+                RuntimeOrigin::signed(alice()),
+                proof,
+                market_id,
+                maker_asset,
+                10 * BASE,
+                taker_asset,
+                25 * BASE,
+                None,
+            ),
+            Error::<Runtime>::UnauthorizedSignedTransaction
+        );
+    });
+}
+
+#[test]
+fn signed_place_order_fails_if_proof_has_wrong_relayer() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let maker_asset = market.base_asset;
+        let taker_asset = Asset::CategoricalOutcome(0, 2);
+        let alice_account = TestAccount::new([0; 32]);
+        let proof = Proof {
+            relayer: bob(),
+            ..signed_place_order_proof(&alice_account, market_id, maker_asset, taker_asset)
+        };
+
+        assert_noop!(
+            Orderbook::signed_place_order(
+                RuntimeOrigin::signed(alice()),
+                proof,
+                market_id,
+                maker_asset,
+                10 * BASE,
+                taker_asset,
+                25 * BASE,
+                None,
+            ),
+            Error::<Runtime>::UnauthorizedSignedTransaction
+        );
+    });
+}
+
+#[test]
+fn signed_place_order_fails_if_sender_is_not_signer() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let maker_asset = market.base_asset;
+        let taker_asset = Asset::CategoricalOutcome(0, 2);
+        let alice_account = TestAccount::new([0; 32]);
+        let proof = signed_place_order_proof(&alice_account, market_id, maker_asset, taker_asset);
+
+        assert_noop!(
+            Orderbook::signed_place_order(
+                RuntimeOrigin::signed(bob()),
+                proof,
+                market_id,
+                maker_asset,
+                10 * BASE,
+                taker_asset,
+                25 * BASE,
+                None,
+            ),
+            Error::<Runtime>::SenderIsNotSigner
+        );
+    });
+}
+
+#[test]
+fn signed_fill_order_fills_order() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let maker_asset = market.base_asset;
+        let taker_asset = Asset::CategoricalOutcome(0, 2);
+        let order_id = 0u128;
+        let taker_amount = 25 * BASE;
+        assert_ok!(Orderbook::place_order(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            maker_asset,
+            10 * BASE,
+            taker_asset,
+            taker_amount,
+            None,
+        ));
+        assert_ok!(AssetManager::deposit(taker_asset, &bob(), taker_amount));
+
+        // Bob's seed is 1.
+        let bob_account = TestAccount::new([1; 32]);
+        let signed_payload =
+            Orderbook::encode_signed_fill_order_params(&eve(), 0, &order_id, &None);
+        let proof = create_proof(&bob_account, &signed_payload);
+
+        assert_ok!(Orderbook::signed_fill_order(
+            RuntimeOrigin::signed(bob()),
+            proof,
+            order_id,
+            None
+        ));
+
+        assert!(Orders::<Runtime>::get(order_id).is_none());
+        assert_eq!(AssetManager::free_balance(taker_asset, &alice()), taker_amount);
+        assert_eq!(UserNonces::<Runtime>::get(bob()), 1);
+    });
+}
+
+#[test]
+fn signed_remove_order_removes_order() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let order_id = 0u128;
+        assert_ok!(Orderbook::place_order(
+            RuntimeOrigin::signed(alice()),
+            market_id,
+            market.base_asset,
+            10 * BASE,
+            Asset::CategoricalOutcome(0, 2),
+            25 * BASE,
+            None,
+        ));
+
+        let alice_account = TestAccount::new([0; 32]);
+        let signed_payload = Orderbook::encode_signed_remove_order_params(&eve(), 0, &order_id);
+        let proof = create_proof(&alice_account, &signed_payload);
+
+        assert_ok!(Orderbook::signed_remove_order(RuntimeOrigin::signed(alice()), proof, order_id));
+
+        assert!(Orders::<Runtime>::get(order_id).is_none());
+        assert_eq!(AssetManager::reserved_balance(market.base_asset, &alice()), 0);
+        assert_eq!(UserNonces::<Runtime>::get(alice()), 1);
+        System::assert_last_event(
+            Event::<Runtime>::OrderRemoved { order_id, maker: alice() }.into(),
+        );
+    });
+}
+
+#[test]
+fn signature_is_valid_checks_signed_calls() {
+    ExtBuilder::default().build().execute_with(|| {
+        let order_id = 0u128;
+        let alice_account = TestAccount::new([0; 32]);
+        let signed_payload = Orderbook::encode_signed_remove_order_params(&eve(), 0, &order_id);
+        let proof = create_proof(&alice_account, &signed_payload);
+
+        let call = Box::new(RuntimeCall::Orderbook(Call::signed_remove_order {
+            proof: proof.clone(),
+            order_id,
+        }));
+        assert!(Orderbook::signature_is_valid(&call));
+
+        let call = Box::new(RuntimeCall::Orderbook(Call::signed_remove_order {
+            proof: Proof { relayer: bob(), ..proof.clone() },
+            order_id,
+        }));
+        assert!(!Orderbook::signature_is_valid(&call));
+
+        let call = Box::new(RuntimeCall::Orderbook(Call::remove_order { order_id }));
+        assert!(!Orderbook::signature_is_valid(&call));
+    });
+}
+
+fn order_params(market_id: u128, outcome: u16) -> OrderParams<u128, u128> {
+    OrderParams {
+        market_id,
+        maker_asset: Asset::Tru,
+        maker_amount: 10 * BASE,
+        taker_asset: Asset::CategoricalOutcome(market_id, outcome),
+        taker_amount: 25 * BASE,
+        expiry: None,
+    }
+}
+
+#[test]
+fn place_orders_places_all_orders() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        Markets::<Runtime>::insert(market_id, market_mock::<Runtime>());
+
+        let orders = vec![order_params(market_id, 0), order_params(market_id, 1)];
+        assert_ok!(Orderbook::place_orders(
+            RuntimeOrigin::signed(alice()),
+            orders.try_into().unwrap(),
+            BatchMode::AllOrNothing,
+        ));
+
+        assert_eq!(Orderbook::maker_orders(&alice()), vec![0, 1]);
+        assert_eq!(Orders::<Runtime>::get(1).unwrap().taker_asset, Asset::CategoricalOutcome(0, 1));
+        assert_eq!(AssetManager::reserved_balance(Asset::Tru, &alice()), 20 * BASE);
+    });
+}
+
+#[test]
+fn place_orders_fails_whole_batch_if_an_order_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        Markets::<Runtime>::insert(market_id, market_mock::<Runtime>());
+
+        let invalid_order = OrderParams { taker_amount: 0, ..order_params(market_id, 1) };
+        let orders = vec![order_params(market_id, 0), invalid_order];
+        assert_noop!(
+            Orderbook::place_orders(
+                RuntimeOrigin::signed(alice()),
+                orders.try_into().unwrap(),
+                BatchMode::AllOrNothing,
+            ),
+            Error::<Runtime>::BelowMinimumBalance
+        );
+    });
+}
+
+#[test]
+fn place_orders_skips_failing_orders() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        Markets::<Runtime>::insert(market_id, market_mock::<Runtime>());
+
+        let invalid_order = OrderParams { taker_amount: 0, ..order_params(market_id, 1) };
+        let orders = vec![order_params(market_id, 0), invalid_order, order_params(market_id, 2)];
+        assert_ok!(Orderbook::place_orders(
+            RuntimeOrigin::signed(alice()),
+            orders.try_into().unwrap(),
+            BatchMode::SkipFailed,
+        ));
+
+        assert_eq!(Orderbook::maker_orders(&alice()), vec![0, 1]);
+        assert_eq!(Orders::<Runtime>::get(1).unwrap().taker_asset, Asset::CategoricalOutcome(0, 2));
+        assert_eq!(AssetManager::reserved_balance(Asset::Tru, &alice()), 20 * BASE);
+        System::assert_has_event(
+            Event::<Runtime>::OrderBatchItemFailed {
+                index: 1,
+                error: Error::<Runtime>::BelowMinimumBalance.into(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn remove_orders_fails_whole_batch_if_an_order_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        Markets::<Runtime>::insert(market_id, market_mock::<Runtime>());
+        assert_ok!(Orderbook::place_orders(
+            RuntimeOrigin::signed(alice()),
+            vec![order_params(market_id, 0)].try_into().unwrap(),
+            BatchMode::AllOrNothing,
+        ));
+        assert_ok!(Orderbook::place_orders(
+            RuntimeOrigin::signed(bob()),
+            vec![order_params(market_id, 0)].try_into().unwrap(),
+            BatchMode::AllOrNothing,
+        ));
+
+        assert_noop!(
+            Orderbook::remove_orders(
+                RuntimeOrigin::signed(alice()),
+                vec![0, 1].try_into().unwrap(),
+                BatchMode::AllOrNothing,
+            ),
+            Error::<Runtime>::NotOrderCreator
+        );
+    });
+}
+
+#[test]
+fn remove_orders_skips_failing_orders() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        Markets::<Runtime>::insert(market_id, market_mock::<Runtime>());
+        assert_ok!(Orderbook::place_orders(
+            RuntimeOrigin::signed(alice()),
+            vec![order_params(market_id, 0), order_params(market_id, 1)].try_into().unwrap(),
+            BatchMode::AllOrNothing,
+        ));
+        assert_ok!(Orderbook::place_orders(
+            RuntimeOrigin::signed(bob()),
+            vec![order_params(market_id, 0)].try_into().unwrap(),
+            BatchMode::AllOrNothing,
+        ));
+
+        assert_ok!(Orderbook::remove_orders(
+            RuntimeOrigin::signed(alice()),
+            vec![0, 2, 1].try_into().unwrap(),
+            BatchMode::SkipFailed,
+        ));
+
+        assert_eq!(Orderbook::market_orders(market_id), vec![2]);
+        assert!(Orderbook::maker_orders(&alice()).is_empty());
+        assert_eq!(AssetManager::reserved_balance(Asset::Tru, &alice()), 0);
+        System::assert_has_event(
+            Event::<Runtime>::OrderBatchItemFailed {
+                index: 1,
+                error: Error::<Runtime>::NotOrderCreator.into(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn replace_orders_removes_and_places_orders() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        Markets::<Runtime>::insert(market_id, market_mock::<Runtime>());
+        assert_ok!(Orderbook::place_orders(
+            RuntimeOrigin::signed(alice()),
+            vec![order_params(market_id, 0), order_params(market_id, 1)].try_into().unwrap(),
+            BatchMode::AllOrNothing,
+        ));
+
+        let new_order = OrderParams { maker_amount: 5 * BASE, ..order_params(market_id, 0) };
+        assert_ok!(Orderbook::replace_orders(
+            RuntimeOrigin::signed(alice()),
+            vec![0, 1].try_into().unwrap(),
+            vec![new_order].try_into().unwrap(),
+        ));
+
+        assert_eq!(Orderbook::maker_orders(&alice()), vec![2]);
+        assert_eq!(Orders::<Runtime>::get(2).unwrap().maker_amount, 5 * BASE);
+        assert_eq!(AssetManager::reserved_balance(Asset::Tru, &alice()), 5 * BASE);
+    });
+}
+
+#[test]
+fn replace_orders_keeps_orders_if_placement_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        Markets::<Runtime>::insert(market_id, market_mock::<Runtime>());
+        assert_ok!(Orderbook::place_orders(
+            RuntimeOrigin::signed(alice()),
+            vec![order_params(market_id, 0)].try_into().unwrap(),
+            BatchMode::AllOrNothing,
+        ));
+
+        let invalid_order = OrderParams { taker_amount: 0, ..order_params(market_id, 0) };
+        assert_noop!(
+            Orderbook::replace_orders(
+                RuntimeOrigin::signed(alice()),
+                vec![0].try_into().unwrap(),
+                vec![invalid_order].try_into().unwrap(),
+            ),
+            Error::<Runtime>::BelowMinimumBalance
+        );
+    });
+}
@@ -16,15 +16,13 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for pallet_pm_order_book
+//! Estimated weights for pallet_pm_order_book
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: `2024-08-28`, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `zeitgeist-benchmark`, CPU: `AMD EPYC 7601 32-Core Processor`
-//! EXECUTION: ``, WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//! The calls of this pallet changed since the last run of the benchmark CLI, so none of the
+//! weights below were generated by it. Regenerate this file with the command below before
+//! deploying the pallet.
 
-// Executed Command:
+// Command:
 // ./target/production/zeitgeist
 // benchmark
 // pallet
@@ -47,66 +45,141 @@ use core::marker::PhantomData;
 use frame_support::{traits::Get, weights::Weight};
 
 ///  Trait containing the required functions for weight retrival within
-/// pallet_pm_order_book (automatically generated)
+/// pallet_pm_order_book
 pub trait WeightInfoZeitgeist {
-    fn remove_order() -> Weight;
-    fn fill_order() -> Weight;
+    fn remove_order(o: u32) -> Weight;
+    fn fill_order(o: u32) -> Weight;
     fn place_order() -> Weight;
+    fn signed_remove_order(o: u32) -> Weight;
+    fn signed_fill_order(o: u32) -> Weight;
+    fn signed_place_order() -> Weight;
+    fn place_orders(n: u32) -> Weight;
+    fn remove_orders(n: u32) -> Weight;
+    fn replace_orders(n: u32, m: u32) -> Weight;
 }
 
-/// Weight functions for pallet_pm_order_book (automatically generated)
+/// Weight functions for pallet_pm_order_book
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
-    /// Storage: `Orderbook::Orders` (r:1 w:1)
-    /// Proof: `Orderbook::Orders` (`max_values`: None, `max_size`: Some(142), added: 2617, mode:
-    /// `MaxEncodedLen`) Storage: `Balances::Reserves` (r:1 w:1)
-    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode:
-    /// `MaxEncodedLen`)
-    fn remove_order() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `251`
-        //  Estimated: `4714`
-        // Minimum execution time: 36_331 nanoseconds.
-        Weight::from_parts(37_771_000, 4714)
-            .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(2))
+    /// Not benchmarked yet. Estimated from the storage it accesses with a constant cost per entry
+    /// of the order indices. Storage: `Orderbook::Orders` (r:1 w:1) Storage: `Balances::Reserves`
+    /// (r:1 w:1) Storage: `Orderbook::MarketOrders` (r:1 w:1) Storage:
+    /// `Orderbook::OutcomeAssetOrders` (r:1 w:1) Storage: `Orderbook::MakerOrders` (r:1 w:1)
+    /// Storage: `Orderbook::MakerMarketOrderCount` (r:1 w:1) The range of component `o` is `[1,
+    /// 1000]`.
+    fn remove_order(o: u32) -> Weight {
+        Weight::from_parts(36_108_412, 19517)
+            .saturating_add(Weight::from_parts(46_211, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
     }
-    /// Storage: `Orderbook::Orders` (r:1 w:1)
-    /// Proof: `Orderbook::Orders` (`max_values`: None, `max_size`: Some(142), added: 2617, mode:
-    /// `MaxEncodedLen`) Storage: `MarketCommons::Markets` (r:1 w:0)
-    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
-    /// mode: `MaxEncodedLen`) Storage: `Balances::Reserves` (r:1 w:1)
-    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode:
-    /// `MaxEncodedLen`) Storage: `System::Account` (r:1 w:1)
-    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode:
-    /// `MaxEncodedLen`) Storage: `Tokens::Accounts` (r:2 w:2)
-    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(123), added: 2598, mode:
-    /// `MaxEncodedLen`)
-    fn fill_order() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `970`
-        //  Estimated: `6186`
-        // Minimum execution time: 90_782 nanoseconds.
-        Weight::from_parts(92_142_000, 6186)
-            .saturating_add(T::DbWeight::get().reads(6))
-            .saturating_add(T::DbWeight::get().writes(5))
+    /// Not benchmarked yet. Estimated from the storage it accesses with a constant cost per entry
+    /// of the order indices. Storage: `Orderbook::Orders` (r:1 w:1) Storage:
+    /// `MarketCommons::Markets` (r:1 w:0) Storage: `Balances::Reserves` (r:1 w:1) Storage:
+    /// `System::Account` (r:1 w:1) Storage: `Tokens::Accounts` (r:2 w:2) Storage:
+    /// `Orderbook::MarketOrders` (r:1 w:1) Storage: `Orderbook::OutcomeAssetOrders` (r:1 w:1)
+    /// Storage: `Orderbook::MakerOrders` (r:1 w:1) Storage: `Orderbook::MakerMarketOrderCount` (r:1
+    /// w:1) Storage: `Timestamp::Now` (r:1 w:0) The range of component `o` is `[1, 1000]`.
+    fn fill_order(o: u32) -> Weight {
+        Weight::from_parts(90_633_119, 19517)
+            .saturating_add(Weight::from_parts(46_385, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(11_u64))
+            .saturating_add(T::DbWeight::get().writes(9_u64))
     }
-    /// Storage: `MarketCommons::Markets` (r:1 w:0)
-    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
-    /// mode: `MaxEncodedLen`) Storage: `Orderbook::NextOrderId` (r:1 w:1)
-    /// Proof: `Orderbook::NextOrderId` (`max_values`: Some(1), `max_size`: Some(16), added: 511,
-    /// mode: `MaxEncodedLen`) Storage: `Balances::Reserves` (r:1 w:1)
-    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode:
-    /// `MaxEncodedLen`) Storage: `Orderbook::Orders` (r:0 w:1)
-    /// Proof: `Orderbook::Orders` (`max_values`: None, `max_size`: Some(142), added: 2617, mode:
-    /// `MaxEncodedLen`)
+    /// Not benchmarked yet. Estimated from the storage it accesses. Storage:
+    /// `MarketCommons::Markets` (r:1 w:0) Storage: `Orderbook::NextOrderId` (r:1 w:1) Storage:
+    /// `Balances::Reserves` (r:1 w:1) Storage: `Orderbook::Orders` (r:0 w:1) Storage:
+    /// `Orderbook::MarketOrders` (r:1 w:1) Storage: `Orderbook::OutcomeAssetOrders` (r:1 w:1)
+    /// Storage: `Orderbook::MakerOrders` (r:1 w:1) Storage: `Orderbook::MakerMarketOrderCount` (r:1
+    /// w:1) Storage: `Timestamp::Now` (r:1 w:0)
     fn place_order() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `318`
-        //  Estimated: `4714`
-        // Minimum execution time: 40_941 nanoseconds.
-        Weight::from_parts(42_722_000, 4714)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(3))
+        Weight::from_parts(61_530_000, 19517)
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+    /// Not benchmarked yet. Estimated as `remove_order` plus the storage of the signed proof.
+    /// Storage: `Orderbook::UserNonces` (r:1 w:1) Storage: `Orderbook::Orders` (r:1 w:1) Storage:
+    /// `Balances::Reserves` (r:1 w:1) Storage: `Orderbook::MarketOrders` (r:1 w:1) Storage:
+    /// `Orderbook::OutcomeAssetOrders` (r:1 w:1) Storage: `Orderbook::MakerOrders` (r:1 w:1)
+    /// Storage: `Orderbook::MakerMarketOrderCount` (r:1 w:1) The range of component `o` is `[1,
+    /// 1000]`.
+    fn signed_remove_order(o: u32) -> Weight {
+        Weight::from_parts(82_702_564, 19517)
+            .saturating_add(Weight::from_parts(46_207, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+    /// Not benchmarked yet. Estimated as `fill_order` plus the storage of the signed proof.
+    /// Storage: `Orderbook::UserNonces` (r:1 w:1) Storage: `Orderbook::Orders` (r:1 w:1) Storage:
+    /// `MarketCommons::Markets` (r:1 w:0) Storage: `Balances::Reserves` (r:1 w:1) Storage:
+    /// `System::Account` (r:1 w:1) Storage: `Tokens::Accounts` (r:2 w:2) Storage:
+    /// `Orderbook::MarketOrders` (r:1 w:1) Storage: `Orderbook::OutcomeAssetOrders` (r:1 w:1)
+    /// Storage: `Orderbook::MakerOrders` (r:1 w:1) Storage: `Orderbook::MakerMarketOrderCount` (r:1
+    /// w:1) Storage: `Timestamp::Now` (r:1 w:0) The range of component `o` is `[1, 1000]`.
+    fn signed_fill_order(o: u32) -> Weight {
+        Weight::from_parts(137_461_850, 19517)
+            .saturating_add(Weight::from_parts(46_402, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(12_u64))
+            .saturating_add(T::DbWeight::get().writes(10_u64))
+    }
+    /// Not benchmarked yet. Estimated as `place_order` plus the storage of the signed proof.
+    /// Storage: `Orderbook::UserNonces` (r:1 w:1) Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Storage: `Orderbook::NextOrderId` (r:1 w:1) Storage: `Balances::Reserves` (r:1 w:1) Storage:
+    /// `Orderbook::Orders` (r:0 w:1) Storage: `Orderbook::MarketOrders` (r:1 w:1) Storage:
+    /// `Orderbook::OutcomeAssetOrders` (r:1 w:1) Storage: `Orderbook::MakerOrders` (r:1 w:1)
+    /// Storage: `Orderbook::MakerMarketOrderCount` (r:1 w:1) Storage: `Timestamp::Now` (r:1 w:0)
+    fn signed_place_order() -> Weight {
+        Weight::from_parts(108_817_000, 19517)
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+    }
+    /// Not benchmarked yet. Estimated as `place_order` per order. Storage: `MarketCommons::Markets`
+    /// (r:32 w:0) Storage: `Orderbook::NextOrderId` (r:1 w:1) Storage: `Balances::Reserves` (r:1
+    /// w:1) Storage: `Orderbook::Orders` (r:0 w:32) Storage: `Orderbook::MarketOrders` (r:32 w:32)
+    /// Storage: `Orderbook::OutcomeAssetOrders` (r:32 w:32) Storage: `Orderbook::MakerOrders` (r:1
+    /// w:1) Storage: `Orderbook::MakerMarketOrderCount` (r:32 w:32) Storage: `Timestamp::Now` (r:1
+    /// w:0) The range of component `n` is `[1, 32]`.
+    fn place_orders(n: u32) -> Weight {
+        Weight::from_parts(16_482_907, 7611)
+            .saturating_add(Weight::from_parts(56_380_114, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 18527).saturating_mul(n.into()))
+    }
+    /// Not benchmarked yet. Estimated as `remove_order` per order. Storage: `Orderbook::Orders`
+    /// (r:32 w:32) Storage: `Balances::Reserves` (r:1 w:1) Storage: `Orderbook::MarketOrders` (r:32
+    /// w:32) Storage: `Orderbook::OutcomeAssetOrders` (r:32 w:32) Storage: `Orderbook::MakerOrders`
+    /// (r:1 w:1) Storage: `Orderbook::MakerMarketOrderCount` (r:32 w:32) The range of component `n`
+    /// is `[1, 32]`.
+    fn remove_orders(n: u32) -> Weight {
+        Weight::from_parts(12_908_544, 7611)
+            .saturating_add(Weight::from_parts(70_447_120, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 18527).saturating_mul(n.into()))
+    }
+    /// Not benchmarked yet. Estimated as `remove_orders` plus `place_orders`. Storage:
+    /// `Orderbook::Orders` (r:32 w:64) Storage: `MarketCommons::Markets` (r:32 w:0) Storage:
+    /// `Orderbook::NextOrderId` (r:1 w:1) Storage: `Balances::Reserves` (r:1 w:1) Storage:
+    /// `Orderbook::MarketOrders` (r:64 w:64) Storage: `Orderbook::OutcomeAssetOrders` (r:64 w:64)
+    /// Storage: `Orderbook::MakerOrders` (r:1 w:1) Storage: `Orderbook::MakerMarketOrderCount`
+    /// (r:64 w:64) Storage: `Timestamp::Now` (r:1 w:0) The range of component `n` is `[1, 32]`. The
+    /// range of component `m` is `[1, 32]`.
+    fn replace_orders(n: u32, m: u32) -> Weight {
+        Weight::from_parts(22_174_315, 7611)
+            .saturating_add(Weight::from_parts(70_513_224, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(56_429_871, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(m.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(m.into())))
+            .saturating_add(Weight::from_parts(0, 18527).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, 18527).saturating_mul(m.into()))
     }
 }
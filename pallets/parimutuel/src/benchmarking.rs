@@ -0,0 +1,125 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![allow(
+    // Auto-generated code is a no man's land
+    clippy::arithmetic_side_effects
+)]
+
+#[cfg(test)]
+use crate::Pallet as Parimutuel;
+
+use crate::{utils::market_mock, *};
+use frame_benchmarking::v2::*;
+use frame_support::{assert_ok, traits::Get};
+use frame_system::RawOrigin;
+use orml_traits::MultiCurrency;
+use prediction_market_primitives::{
+    traits::MarketCommonsPalletApi,
+    types::{Asset, MarketStatus, OutcomeReport},
+};
+use sp_runtime::Saturating;
+
+fn setup_market<T: Config>() -> MarketIdOf<T> {
+    T::MarketCommons::push_market(market_mock::<T>()).unwrap()
+}
+
+fn buy_asset<T: Config>(
+    market_id: MarketIdOf<T>,
+    asset: AssetOf<T>,
+    buyer: &T::AccountId,
+    amount: BalanceOf<T>,
+) {
+    let market = T::MarketCommons::market(&market_id).unwrap();
+    let base_asset = market.base_asset;
+    assert_ok!(T::AssetManager::deposit(base_asset, buyer, amount.saturating_mul(2u8.into())));
+    assert_ok!(Pallet::<T>::buy(RawOrigin::Signed(buyer.clone()).into(), asset, amount));
+}
+
+fn resolve_market<T: Config>(market_id: MarketIdOf<T>, outcome: u16) {
+    assert_ok!(T::MarketCommons::mutate_market(&market_id, |market| {
+        market.resolved_outcome = Some(OutcomeReport::Categorical(outcome));
+        market.status = MarketStatus::Resolved;
+        Ok(())
+    }));
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn buy() {
+        let buyer: T::AccountId = whitelisted_caller();
+        let market_id = setup_market::<T>();
+        let amount = T::MinBetSize::get().saturating_mul(10u8.into());
+        let market = T::MarketCommons::market(&market_id).unwrap();
+        assert_ok!(T::AssetManager::deposit(
+            market.base_asset,
+            &buyer,
+            amount.saturating_mul(2u8.into())
+        ));
+        let asset = Asset::ParimutuelShare(market_id, 0u16);
+
+        #[extrinsic_call]
+        buy(RawOrigin::Signed(buyer), asset, amount);
+    }
+
+    #[benchmark]
+    fn claim_rewards() {
+        // Worst case is the winner who is not the last to claim, because the payoff is calculated
+        // pro rata.
+        let buyer: T::AccountId = whitelisted_caller();
+        let other_winner: T::AccountId = account("other_winner", 0, 0);
+        let loser: T::AccountId = account("loser", 0, 0);
+        let market_id = setup_market::<T>();
+        let amount = T::MinBetSize::get().saturating_mul(10u8.into());
+
+        let winner_asset = Asset::ParimutuelShare(market_id, 0u16);
+        let loser_asset = Asset::ParimutuelShare(market_id, 1u16);
+        buy_asset::<T>(market_id, winner_asset, &buyer, amount);
+        buy_asset::<T>(market_id, winner_asset, &other_winner, amount);
+        buy_asset::<T>(market_id, loser_asset, &loser, amount);
+
+        resolve_market::<T>(market_id, 0u16);
+
+        #[extrinsic_call]
+        claim_rewards(RawOrigin::Signed(buyer), market_id);
+    }
+
+    #[benchmark]
+    fn claim_refunds() {
+        let buyer: T::AccountId = whitelisted_caller();
+        let market_id = setup_market::<T>();
+        let amount = T::MinBetSize::get().saturating_mul(10u8.into());
+
+        let loser_asset = Asset::ParimutuelShare(market_id, 1u16);
+        buy_asset::<T>(market_id, loser_asset, &buyer, amount);
+
+        // Nobody bought the winning outcome, so the stake is refunded.
+        resolve_market::<T>(market_id, 0u16);
+
+        #[extrinsic_call]
+        claim_refunds(RawOrigin::Signed(buyer), loser_asset);
+    }
+
+    impl_benchmark_test_suite!(
+        Parimutuel,
+        crate::mock::ExtBuilder::default().build(),
+        crate::mock::Runtime
+    );
+}
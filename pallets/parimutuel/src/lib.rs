@@ -0,0 +1,414 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod mock;
+#[cfg(test)]
+mod tests;
+mod utils;
+pub mod weights;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+mod pallet {
+    use crate::weights::WeightInfoZeitgeist;
+    use core::marker::PhantomData;
+    use frame_support::{
+        ensure,
+        pallet_prelude::DispatchError,
+        traits::{Get, IsType, StorageVersion},
+        PalletId,
+    };
+    use frame_system::{
+        ensure_signed,
+        pallet_prelude::{BlockNumberFor, OriginFor},
+    };
+    use orml_traits::MultiCurrency;
+    use prediction_market_primitives::{
+        math::{checked_ops_res::CheckedSubRes, fixed::FixedMulDiv},
+        traits::{DistributeFees, MarketCommonsPalletApi},
+        types::{Asset, Market, MarketStatus, MarketType, OutcomeReport, ScoringRule},
+    };
+    use sp_runtime::{
+        traits::{AccountIdConversion, CheckedSub, Zero},
+        DispatchResult,
+    };
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The way how fees are taken from the market base asset.
+        type ExternalFees: DistributeFees<
+            Asset = AssetOf<Self>,
+            AccountId = AccountIdOf<Self>,
+            Balance = BalanceOf<Self>,
+            MarketId = MarketIdOf<Self>,
+        >;
+
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The api to handle different asset classes.
+        type AssetManager: MultiCurrency<Self::AccountId, CurrencyId = AssetOf<Self>>;
+
+        type MarketCommons: MarketCommonsPalletApi<
+            AccountId = Self::AccountId,
+            BlockNumber = BlockNumberFor<Self>,
+            Balance = BalanceOf<Self>,
+        >;
+
+        /// The minimum amount each bet must be. Must be larger than or equal to the existential
+        /// deposit of parimutuel shares.
+        #[pallet::constant]
+        type MinBetSize: Get<BalanceOf<Self>>;
+
+        /// Identifier of this pallet.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        type WeightInfo: WeightInfoZeitgeist;
+    }
+
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+    pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+    pub(crate) type AssetOf<T> = Asset<MarketIdOf<T>>;
+    pub(crate) type BalanceOf<T> =
+        <<T as Config>::AssetManager as MultiCurrency<AccountIdOf<T>>>::Balance;
+    pub(crate) type MarketIdOf<T> =
+        <<T as Config>::MarketCommons as MarketCommonsPalletApi>::MarketId;
+    pub(crate) type MomentOf<T> = <<T as Config>::MarketCommons as MarketCommonsPalletApi>::Moment;
+    pub(crate) type MarketOf<T> =
+        Market<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>, MomentOf<T>, MarketIdOf<T>>;
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::event]
+    #[pallet::generate_deposit(fn deposit_event)]
+    pub enum Event<T>
+    where
+        T: Config,
+    {
+        /// An outcome was bought.
+        OutcomeBought {
+            market_id: MarketIdOf<T>,
+            buyer: AccountIdOf<T>,
+            asset: AssetOf<T>,
+            amount_minus_fees: BalanceOf<T>,
+            fees: BalanceOf<T>,
+        },
+        /// Rewards of the pot were claimed.
+        RewardsClaimed {
+            market_id: MarketIdOf<T>,
+            asset: AssetOf<T>,
+            withdrawn_asset_balance: BalanceOf<T>,
+            base_asset_payoff: BalanceOf<T>,
+            sender: AccountIdOf<T>,
+        },
+        /// A market base asset was refunded.
+        BalanceRefunded {
+            market_id: MarketIdOf<T>,
+            asset: AssetOf<T>,
+            refunded_balance: BalanceOf<T>,
+            sender: AccountIdOf<T>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// There was no buyer for the winning outcome or all winners already claimed their
+        /// rewards. Use the `claim_refunds` extrinsic in case there was no buyer for the winning
+        /// outcome.
+        NoRewardShareOutstanding,
+        /// The market is not active.
+        MarketIsNotActive,
+        /// The specified amount is below the minimum bet size.
+        AmountBelowMinimumBetSize,
+        /// The specified asset is not a parimutuel share.
+        NotParimutuelOutcome,
+        /// The specified asset was not found in the market assets.
+        InvalidOutcomeAsset,
+        /// The scoring rule is not parimutuel.
+        InvalidScoringRule,
+        /// The amount is not enough to pay for the bet and the fees.
+        InsufficientBalance,
+        /// The market is not resolved yet.
+        MarketIsNotResolvedYet,
+        /// An unexpected error occurred. This should never happen!
+        /// There was an internal coding mistake.
+        Unexpected,
+        /// There is no resolved outcome present for the market.
+        NoResolvedOutcome,
+        /// The refund is not allowed, because there are winning shares outstanding.
+        RefundNotAllowed,
+        /// There is no balance to refund.
+        RefundableBalanceIsZero,
+        /// There is no reward to claim, because the sender holds no winning shares.
+        NoWinningShares,
+        /// Only categorical markets are allowed for parimutuels.
+        NotCategorical,
+        /// The market resolved as invalid, so there are no rewards. Use the `claim_refunds`
+        /// extrinsic instead.
+        NoWinningOutcome,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Buy parimutuel shares for the market's base asset.
+        ///
+        /// The base asset minus the external fees is transferred into the market's pot and the
+        /// sender receives the same amount of the specified parimutuel shares.
+        ///
+        /// # Arguments
+        ///
+        /// - `asset`: The outcome asset to buy the shares of.
+        /// - `amount`: The amount of base asset to spend, including the external fees.
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::buy())]
+        #[frame_support::transactional]
+        pub fn buy(
+            origin: OriginFor<T>,
+            asset: AssetOf<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_buy(who, asset, amount)?;
+
+            Ok(())
+        }
+
+        /// Claim the pro-rata share of the pot of a resolved market.
+        ///
+        /// The sender's winning shares are burned and the corresponding part of the pot is paid
+        /// out in the market's base asset.
+        ///
+        /// # Arguments
+        ///
+        /// - `market_id`: The ID of the resolved market.
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::claim_rewards())]
+        #[frame_support::transactional]
+        pub fn claim_rewards(origin: OriginFor<T>, market_id: MarketIdOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_claim_rewards(who, market_id)?;
+
+            Ok(())
+        }
+
+        /// Refund the base asset of losing parimutuel shares in case no one bought the winning
+        /// outcome or the market resolved as invalid.
+        ///
+        /// # Arguments
+        ///
+        /// - `refund_asset`: The parimutuel share to refund.
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::claim_refunds())]
+        #[frame_support::transactional]
+        pub fn claim_refunds(origin: OriginFor<T>, refund_asset: AssetOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_claim_refunds(who, refund_asset)?;
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The account which holds the pot of the specified market.
+        pub fn pot_account(market_id: MarketIdOf<T>) -> AccountIdOf<T> {
+            T::PalletId::get().into_sub_account_truncating(market_id)
+        }
+
+        fn market_id_of(asset: &AssetOf<T>) -> Result<MarketIdOf<T>, DispatchError> {
+            match asset {
+                Asset::ParimutuelShare(market_id, _) => Ok(*market_id),
+                _ => Err(Error::<T>::NotParimutuelOutcome.into()),
+            }
+        }
+
+        fn ensure_parimutuel_market(market: &MarketOf<T>) -> DispatchResult {
+            ensure!(market.scoring_rule == ScoringRule::Parimutuel, Error::<T>::InvalidScoringRule);
+            ensure!(
+                matches!(market.market_type, MarketType::Categorical(_)),
+                Error::<T>::NotCategorical
+            );
+            Ok(())
+        }
+
+        /// The winning parimutuel share of a resolved market, or `None` if the market resolved
+        /// as invalid.
+        fn winning_asset(market: &MarketOf<T>) -> Result<Option<AssetOf<T>>, DispatchError> {
+            ensure!(market.status == MarketStatus::Resolved, Error::<T>::MarketIsNotResolvedYet);
+            match market.resolved_outcome {
+                None => Err(Error::<T>::NoResolvedOutcome.into()),
+                Some(OutcomeReport::Invalid) => Ok(None),
+                Some(_) => {
+                    let asset =
+                        market.resolved_outcome_into_asset().ok_or(Error::<T>::Unexpected)?;
+                    Ok(Some(asset))
+                },
+            }
+        }
+
+        pub(crate) fn do_buy(
+            who: AccountIdOf<T>,
+            asset: AssetOf<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let market_id = Self::market_id_of(&asset)?;
+            let market = T::MarketCommons::market(&market_id)?;
+            let base_asset = market.base_asset;
+            ensure!(
+                T::AssetManager::ensure_can_withdraw(base_asset, &who, amount).is_ok(),
+                Error::<T>::InsufficientBalance
+            );
+            ensure!(market.status == MarketStatus::Active, Error::<T>::MarketIsNotActive);
+            Self::ensure_parimutuel_market(&market)?;
+            ensure!(market.outcome_assets().contains(&asset), Error::<T>::InvalidOutcomeAsset);
+
+            let external_fees = T::ExternalFees::distribute(market_id, base_asset, &who, amount);
+            let amount_minus_fees =
+                amount.checked_sub(&external_fees).ok_or(Error::<T>::Unexpected)?;
+            ensure!(
+                amount_minus_fees >= T::MinBetSize::get(),
+                Error::<T>::AmountBelowMinimumBetSize
+            );
+
+            let pot_account = Self::pot_account(market_id);
+            T::AssetManager::transfer(base_asset, &who, &pot_account, amount_minus_fees)?;
+            T::AssetManager::deposit(asset, &who, amount_minus_fees)?;
+
+            Self::deposit_event(Event::OutcomeBought {
+                market_id,
+                buyer: who,
+                asset,
+                amount_minus_fees,
+                fees: external_fees,
+            });
+
+            Ok(())
+        }
+
+        pub(crate) fn do_claim_rewards(
+            who: AccountIdOf<T>,
+            market_id: MarketIdOf<T>,
+        ) -> DispatchResult {
+            let market = T::MarketCommons::market(&market_id)?;
+            Self::ensure_parimutuel_market(&market)?;
+            let winning_asset =
+                Self::winning_asset(&market)?.ok_or(Error::<T>::NoWinningOutcome)?;
+
+            // Each parimutuel share is backed by exactly one unit of the base asset in the pot, so
+            // the total issuance of the winning share is the sum of all winning stakes.
+            let outcome_total = T::AssetManager::total_issuance(winning_asset);
+            ensure!(!outcome_total.is_zero(), Error::<T>::NoRewardShareOutstanding);
+            let winning_balance = T::AssetManager::free_balance(winning_asset, &who);
+            ensure!(!winning_balance.is_zero(), Error::<T>::NoWinningShares);
+            debug_assert!(
+                outcome_total >= winning_balance,
+                "The total issuance of the winning asset should be at least as large as the \
+                 balance of the winner."
+            );
+
+            let pot_account = Self::pot_account(market_id);
+            let pot_total = T::AssetManager::free_balance(market.base_asset, &pot_account);
+            let payoff = pot_total.bmul_bdiv_floor(winning_balance, outcome_total)?;
+
+            T::AssetManager::withdraw(winning_asset, &who, winning_balance)?;
+
+            // Pay out the whole pot to the last winner or if the remainder would be dust.
+            let remaining_bal = pot_total.checked_sub_res(&payoff)?;
+            let base_asset_min_balance = T::AssetManager::minimum_balance(market.base_asset);
+            let payoff =
+                if remaining_bal < base_asset_min_balance || winning_balance == outcome_total {
+                    pot_total
+                } else {
+                    payoff
+                };
+
+            T::AssetManager::transfer(market.base_asset, &pot_account, &who, payoff)?;
+
+            Self::deposit_event(Event::RewardsClaimed {
+                market_id,
+                asset: winning_asset,
+                withdrawn_asset_balance: winning_balance,
+                base_asset_payoff: payoff,
+                sender: who,
+            });
+
+            Ok(())
+        }
+
+        pub(crate) fn do_claim_refunds(
+            who: AccountIdOf<T>,
+            refund_asset: AssetOf<T>,
+        ) -> DispatchResult {
+            let market_id = Self::market_id_of(&refund_asset)?;
+            let market = T::MarketCommons::market(&market_id)?;
+            Self::ensure_parimutuel_market(&market)?;
+            let winning_asset = Self::winning_asset(&market)?;
+            ensure!(
+                market.outcome_assets().contains(&refund_asset),
+                Error::<T>::InvalidOutcomeAsset
+            );
+
+            if let Some(winning_asset) = winning_asset {
+                let outcome_total = T::AssetManager::total_issuance(winning_asset);
+                ensure!(outcome_total.is_zero(), Error::<T>::RefundNotAllowed);
+            }
+
+            let refund_balance = T::AssetManager::free_balance(refund_asset, &who);
+            ensure!(!refund_balance.is_zero(), Error::<T>::RefundableBalanceIsZero);
+
+            let pot_account = Self::pot_account(market_id);
+            let pot_total = T::AssetManager::free_balance(market.base_asset, &pot_account);
+            // Guard against rounding dust having been paid out to an earlier refund.
+            let refund_balance_checked = refund_balance.min(pot_total);
+
+            T::AssetManager::withdraw(refund_asset, &who, refund_balance)?;
+            T::AssetManager::transfer(
+                market.base_asset,
+                &pot_account,
+                &who,
+                refund_balance_checked,
+            )?;
+
+            Self::deposit_event(Event::BalanceRefunded {
+                market_id,
+                asset: refund_asset,
+                refunded_balance: refund_balance_checked,
+                sender: who,
+            });
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "mock")]
+
+use crate as pallet_pm_parimutuel;
+use crate::{AssetOf, BalanceOf, MarketIdOf};
+use common_primitives::types::{Balance, Hash, Moment};
+use core::marker::PhantomData;
+use frame_support::{construct_runtime, pallet_prelude::Get, parameter_types, traits::Everything};
+use frame_system::mocking::MockBlock;
+use orml_traits::MultiCurrency;
+pub use prediction_market_primitives::test_helper::get_account;
+use prediction_market_primitives::{
+    constants::mock::{
+        BlockHashCount, ExistentialDeposit, ExistentialDeposits, GetNativeCurrencyId, MaxLocks,
+        MaxReserves, MinBetSize, MinimumPeriod, ParimutuelPalletId, BASE,
+    },
+    traits::DistributeFees,
+    types::{BasicCurrencyAdapter, CurrencyId, MarketId, OrmlAmount, TestAccountIdPK},
+};
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup, Zero},
+    BuildStorage, PerThing, Perquintill, SaturatedConversion,
+};
+
+pub fn alice() -> TestAccountIdPK {
+    get_account(0u8)
+}
+pub fn bob() -> TestAccountIdPK {
+    get_account(1u8)
+}
+pub fn charlie() -> TestAccountIdPK {
+    get_account(2u8)
+}
+pub fn market_creator() -> TestAccountIdPK {
+    get_account(42u8)
+}
+
+pub const INITIAL_BALANCE: Balance = 1_000 * BASE;
+
+parameter_types! {
+    pub const ExternalFeeRate: Perquintill = Perquintill::from_percent(1);
+    pub FeeAccount: TestAccountIdPK = market_creator();
+}
+
+pub fn calculate_fee<T: crate::Config>(amount: BalanceOf<T>) -> BalanceOf<T> {
+    ExternalFeeRate::get()
+        .mul_floor(amount.saturated_into::<Balance>())
+        .saturated_into()
+}
+
+pub struct ExternalFees<T, F>(PhantomData<T>, PhantomData<F>);
+
+impl<T: crate::Config, F> DistributeFees for ExternalFees<T, F>
+where
+    F: Get<T::AccountId>,
+{
+    type Asset = AssetOf<T>;
+    type AccountId = T::AccountId;
+    type Balance = BalanceOf<T>;
+    type MarketId = MarketIdOf<T>;
+
+    fn distribute(
+        _market_id: Self::MarketId,
+        asset: Self::Asset,
+        account: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Self::Balance {
+        let fees = calculate_fee::<T>(amount);
+        match T::AssetManager::transfer(asset, account, &F::get(), fees) {
+            Ok(_) => fees,
+            Err(_) => Zero::zero(),
+        }
+    }
+
+    fn fee_amount(
+        _market_id: Self::MarketId,
+        _trader: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Self::Balance {
+        calculate_fee::<T>(amount)
+    }
+}
+
+construct_runtime!(
+    pub enum Runtime {
+        Balances: pallet_balances,
+        MarketCommons: pallet_pm_market_commons,
+        Parimutuel: pallet_pm_parimutuel,
+        System: frame_system,
+        Tokens: orml_tokens,
+        AssetManager: orml_currencies,
+        Timestamp: pallet_timestamp,
+    }
+);
+
+impl crate::Config for Runtime {
+    type AssetManager = AssetManager;
+    type ExternalFees = ExternalFees<Runtime, FeeAccount>;
+    type RuntimeEvent = RuntimeEvent;
+    type MarketCommons = MarketCommons;
+    type MinBetSize = MinBetSize;
+    type PalletId = ParimutuelPalletId;
+    type WeightInfo = pallet_pm_parimutuel::weights::WeightInfo<Runtime>;
+}
+
+impl frame_system::Config for Runtime {
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type AccountId = TestAccountIdPK;
+    type BaseCallFilter = Everything;
+    type Block = MockBlock<Runtime>;
+    type BlockHashCount = BlockHashCount;
+    type BlockLength = ();
+    type BlockWeights = ();
+    type RuntimeCall = RuntimeCall;
+    type DbWeight = ();
+    type RuntimeEvent = RuntimeEvent;
+    type Hash = Hash;
+    type Hashing = BlakeTwo256;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Nonce = u64;
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type OnKilledAccount = ();
+    type OnNewAccount = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletInfo = PalletInfo;
+    type SS58Prefix = ();
+    type SystemWeightInfo = ();
+    type Version = ();
+    type OnSetCode = ();
+}
+
+impl orml_currencies::Config for Runtime {
+    type GetNativeCurrencyId = GetNativeCurrencyId;
+    type MultiCurrency = Tokens;
+    type NativeCurrency = BasicCurrencyAdapter<Runtime, Balances>;
+    type WeightInfo = ();
+}
+
+impl orml_tokens::Config for Runtime {
+    type Amount = OrmlAmount;
+    type Balance = Balance;
+    type CurrencyId = CurrencyId;
+    type DustRemovalWhitelist = Everything;
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposits = ExistentialDeposits;
+    type MaxLocks = ();
+    type MaxReserves = MaxReserves;
+    type CurrencyHooks = ();
+    type ReserveIdentifier = [u8; 8];
+    type WeightInfo = ();
+}
+
+impl pallet_balances::Config for Runtime {
+    type AccountStore = System;
+    type Balance = Balance;
+    type DustRemoval = ();
+    type FreezeIdentifier = ();
+    type RuntimeHoldReason = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ExistentialDeposit;
+    type MaxHolds = ();
+    type MaxFreezes = ();
+    type MaxLocks = MaxLocks;
+    type MaxReserves = MaxReserves;
+    type ReserveIdentifier = [u8; 8];
+    type WeightInfo = ();
+}
+
+impl pallet_timestamp::Config for Runtime {
+    type MinimumPeriod = MinimumPeriod;
+    type Moment = Moment;
+    type OnTimestampSet = ();
+    type WeightInfo = ();
+}
+
+impl pallet_pm_market_commons::Config for Runtime {
+    type Balance = Balance;
+    type MarketId = MarketId;
+    type Timestamp = Timestamp;
+}
+
+pub struct ExtBuilder {
+    balances: Vec<(TestAccountIdPK, Balance)>,
+}
+
+impl Default for ExtBuilder {
+    fn default() -> Self {
+        Self {
+            balances: vec![
+                (alice(), INITIAL_BALANCE),
+                (bob(), INITIAL_BALANCE),
+                (charlie(), INITIAL_BALANCE),
+            ],
+        }
+    }
+}
+
+impl ExtBuilder {
+    pub fn build(self) -> sp_io::TestExternalities {
+        let mut t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+
+        // see the logs in tests when using `RUST_LOG=debug cargo test -- --nocapture`
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        pallet_balances::GenesisConfig::<Runtime> { balances: self.balances }
+            .assimilate_storage(&mut t)
+            .unwrap();
+
+        let mut t: sp_io::TestExternalities = t.into();
+        t.execute_with(|| System::set_block_number(1));
+
+        t
+    }
+}
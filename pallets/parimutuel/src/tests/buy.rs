@@ -0,0 +1,149 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[test]
+fn buy_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        let asset = Asset::ParimutuelShare(market_id, 0u16);
+        let amount = 10 * BASE;
+        let fees = calculate_fee::<Runtime>(amount);
+        let amount_minus_fees = amount - fees;
+        let pot_account = Parimutuel::pot_account(market_id);
+
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(alice()), asset, amount));
+
+        assert_eq!(AssetManager::free_balance(asset, &alice()), amount_minus_fees);
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &alice()), INITIAL_BALANCE - amount);
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &pot_account), amount_minus_fees);
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &market_creator()), fees);
+        System::assert_last_event(
+            Event::OutcomeBought { market_id, buyer: alice(), asset, amount_minus_fees, fees }
+                .into(),
+        );
+    });
+}
+
+#[test]
+fn buy_fails_if_asset_is_not_parimutuel_share() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        let asset = Asset::CategoricalOutcome(market_id, 0u16);
+        assert_noop!(
+            Parimutuel::buy(RuntimeOrigin::signed(alice()), asset, 10 * BASE),
+            Error::<Runtime>::NotParimutuelOutcome
+        );
+    });
+}
+
+#[test]
+fn buy_fails_if_market_does_not_exist() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset = Asset::ParimutuelShare(0, 0u16);
+        assert_noop!(
+            Parimutuel::buy(RuntimeOrigin::signed(alice()), asset, 10 * BASE),
+            MError::<Runtime>::MarketDoesNotExist
+        );
+    });
+}
+
+#[test_case(MarketStatus::Proposed)]
+#[test_case(MarketStatus::Closed)]
+#[test_case(MarketStatus::Reported)]
+#[test_case(MarketStatus::Disputed)]
+#[test_case(MarketStatus::Resolved)]
+fn buy_fails_if_market_is_not_active(status: MarketStatus) {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        Markets::<Runtime>::mutate(market_id, |market| market.as_mut().unwrap().status = status);
+        let asset = Asset::ParimutuelShare(market_id, 0u16);
+        assert_noop!(
+            Parimutuel::buy(RuntimeOrigin::signed(alice()), asset, 10 * BASE),
+            Error::<Runtime>::MarketIsNotActive
+        );
+    });
+}
+
+#[test_case(ScoringRule::AmmCdaHybrid)]
+fn buy_fails_if_scoring_rule_is_not_parimutuel(scoring_rule: ScoringRule) {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        Markets::<Runtime>::mutate(market_id, |market| {
+            market.as_mut().unwrap().scoring_rule = scoring_rule
+        });
+        let asset = Asset::ParimutuelShare(market_id, 0u16);
+        assert_noop!(
+            Parimutuel::buy(RuntimeOrigin::signed(alice()), asset, 10 * BASE),
+            Error::<Runtime>::InvalidScoringRule
+        );
+    });
+}
+
+#[test]
+fn buy_fails_if_market_is_not_categorical() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        Markets::<Runtime>::mutate(market_id, |market| {
+            market.as_mut().unwrap().market_type = MarketType::Scalar(0..=100)
+        });
+        let asset = Asset::ParimutuelShare(market_id, 0u16);
+        assert_noop!(
+            Parimutuel::buy(RuntimeOrigin::signed(alice()), asset, 10 * BASE),
+            Error::<Runtime>::NotCategorical
+        );
+    });
+}
+
+#[test]
+fn buy_fails_if_asset_is_not_an_outcome_of_the_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        let asset = Asset::ParimutuelShare(market_id, 10u16);
+        assert_noop!(
+            Parimutuel::buy(RuntimeOrigin::signed(alice()), asset, 10 * BASE),
+            Error::<Runtime>::InvalidOutcomeAsset
+        );
+    });
+}
+
+#[test]
+fn buy_fails_if_amount_is_below_minimum_bet_size() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        let asset = Asset::ParimutuelShare(market_id, 0u16);
+        // The minimum bet size is met before, but not after the external fees are deducted.
+        let amount = <MinBetSize as Get<u128>>::get();
+        assert_noop!(
+            Parimutuel::buy(RuntimeOrigin::signed(alice()), asset, amount),
+            Error::<Runtime>::AmountBelowMinimumBetSize
+        );
+    });
+}
+
+#[test]
+fn buy_fails_if_balance_is_insufficient() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        let asset = Asset::ParimutuelShare(market_id, 0u16);
+        assert_noop!(
+            Parimutuel::buy(RuntimeOrigin::signed(alice()), asset, INITIAL_BALANCE + 1),
+            Error::<Runtime>::InsufficientBalance
+        );
+    });
+}
@@ -0,0 +1,125 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[test]
+fn claim_refunds_refunds_stakes_if_nobody_bought_the_winning_outcome() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        let alice_asset = Asset::ParimutuelShare(market_id, 1u16);
+        let bob_asset = Asset::ParimutuelShare(market_id, 2u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(alice()), alice_asset, 10 * BASE));
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(bob()), bob_asset, 20 * BASE));
+        resolve_market(market_id, 0u16);
+
+        let alice_stake = AssetManager::free_balance(alice_asset, &alice());
+        let alice_balance_before = AssetManager::free_balance(Asset::Tru, &alice());
+        assert_ok!(Parimutuel::claim_refunds(RuntimeOrigin::signed(alice()), alice_asset));
+        assert_eq!(AssetManager::free_balance(alice_asset, &alice()), 0);
+        assert_eq!(
+            AssetManager::free_balance(Asset::Tru, &alice()),
+            alice_balance_before + alice_stake
+        );
+        System::assert_last_event(
+            Event::BalanceRefunded {
+                market_id,
+                asset: alice_asset,
+                refunded_balance: alice_stake,
+                sender: alice(),
+            }
+            .into(),
+        );
+
+        let bob_stake = AssetManager::free_balance(bob_asset, &bob());
+        let bob_balance_before = AssetManager::free_balance(Asset::Tru, &bob());
+        assert_ok!(Parimutuel::claim_refunds(RuntimeOrigin::signed(bob()), bob_asset));
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &bob()), bob_balance_before + bob_stake);
+        let pot_account = Parimutuel::pot_account(market_id);
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &pot_account), 0);
+    });
+}
+
+#[test]
+fn claim_refunds_refunds_all_stakes_if_market_resolved_as_invalid() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        let alice_asset = Asset::ParimutuelShare(market_id, 0u16);
+        let bob_asset = Asset::ParimutuelShare(market_id, 1u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(alice()), alice_asset, 10 * BASE));
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(bob()), bob_asset, 20 * BASE));
+        resolve_market_with(market_id, OutcomeReport::Invalid);
+
+        let alice_stake = AssetManager::free_balance(alice_asset, &alice());
+        let alice_balance_before = AssetManager::free_balance(Asset::Tru, &alice());
+        assert_ok!(Parimutuel::claim_refunds(RuntimeOrigin::signed(alice()), alice_asset));
+        assert_eq!(
+            AssetManager::free_balance(Asset::Tru, &alice()),
+            alice_balance_before + alice_stake
+        );
+
+        let bob_stake = AssetManager::free_balance(bob_asset, &bob());
+        let bob_balance_before = AssetManager::free_balance(Asset::Tru, &bob());
+        assert_ok!(Parimutuel::claim_refunds(RuntimeOrigin::signed(bob()), bob_asset));
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &bob()), bob_balance_before + bob_stake);
+        let pot_account = Parimutuel::pot_account(market_id);
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &pot_account), 0);
+    });
+}
+
+#[test]
+fn claim_refunds_fails_if_winning_shares_are_outstanding() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        let winner_asset = Asset::ParimutuelShare(market_id, 0u16);
+        let loser_asset = Asset::ParimutuelShare(market_id, 1u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(alice()), winner_asset, 10 * BASE));
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(bob()), loser_asset, 10 * BASE));
+        resolve_market(market_id, 0u16);
+        assert_noop!(
+            Parimutuel::claim_refunds(RuntimeOrigin::signed(bob()), loser_asset),
+            Error::<Runtime>::RefundNotAllowed
+        );
+    });
+}
+
+#[test]
+fn claim_refunds_fails_if_market_is_not_resolved() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        let asset = Asset::ParimutuelShare(market_id, 1u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(alice()), asset, 10 * BASE));
+        assert_noop!(
+            Parimutuel::claim_refunds(RuntimeOrigin::signed(alice()), asset),
+            Error::<Runtime>::MarketIsNotResolvedYet
+        );
+    });
+}
+
+#[test]
+fn claim_refunds_fails_if_refundable_balance_is_zero() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        let asset = Asset::ParimutuelShare(market_id, 1u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(alice()), asset, 10 * BASE));
+        resolve_market(market_id, 0u16);
+        assert_noop!(
+            Parimutuel::claim_refunds(RuntimeOrigin::signed(bob()), asset),
+            Error::<Runtime>::RefundableBalanceIsZero
+        );
+    });
+}
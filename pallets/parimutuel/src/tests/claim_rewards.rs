@@ -0,0 +1,122 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[test]
+fn claim_rewards_pays_out_pot_pro_rata() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        let winner_asset = Asset::ParimutuelShare(market_id, 0u16);
+        let loser_asset = Asset::ParimutuelShare(market_id, 1u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(alice()), winner_asset, 10 * BASE));
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(bob()), winner_asset, 20 * BASE));
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(charlie()), loser_asset, 30 * BASE));
+        resolve_market(market_id, 0u16);
+
+        let pot_account = Parimutuel::pot_account(market_id);
+        let pot_total = AssetManager::free_balance(Asset::Tru, &pot_account);
+        assert_eq!(pot_total, 594 * BASE / 10);
+        let alice_shares = AssetManager::free_balance(winner_asset, &alice());
+        let alice_balance_before = AssetManager::free_balance(Asset::Tru, &alice());
+
+        assert_ok!(Parimutuel::claim_rewards(RuntimeOrigin::signed(alice()), market_id));
+
+        // Alice holds a third of the winning shares.
+        let alice_payoff = pot_total / 3;
+        assert_eq!(AssetManager::free_balance(winner_asset, &alice()), 0);
+        assert_eq!(
+            AssetManager::free_balance(Asset::Tru, &alice()),
+            alice_balance_before + alice_payoff
+        );
+        System::assert_last_event(
+            Event::RewardsClaimed {
+                market_id,
+                asset: winner_asset,
+                withdrawn_asset_balance: alice_shares,
+                base_asset_payoff: alice_payoff,
+                sender: alice(),
+            }
+            .into(),
+        );
+
+        let bob_balance_before = AssetManager::free_balance(Asset::Tru, &bob());
+        assert_ok!(Parimutuel::claim_rewards(RuntimeOrigin::signed(bob()), market_id));
+        assert_eq!(
+            AssetManager::free_balance(Asset::Tru, &bob()),
+            bob_balance_before + pot_total - alice_payoff
+        );
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &pot_account), 0);
+    });
+}
+
+#[test]
+fn claim_rewards_fails_if_market_is_not_resolved() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        let asset = Asset::ParimutuelShare(market_id, 0u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(alice()), asset, 10 * BASE));
+        assert_noop!(
+            Parimutuel::claim_rewards(RuntimeOrigin::signed(alice()), market_id),
+            Error::<Runtime>::MarketIsNotResolvedYet
+        );
+    });
+}
+
+#[test]
+fn claim_rewards_fails_if_there_are_no_winning_shares() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        let asset = Asset::ParimutuelShare(market_id, 1u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(alice()), asset, 10 * BASE));
+        resolve_market(market_id, 0u16);
+        assert_noop!(
+            Parimutuel::claim_rewards(RuntimeOrigin::signed(alice()), market_id),
+            Error::<Runtime>::NoRewardShareOutstanding
+        );
+    });
+}
+
+#[test]
+fn claim_rewards_fails_if_sender_holds_no_winning_shares() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        let winner_asset = Asset::ParimutuelShare(market_id, 0u16);
+        let loser_asset = Asset::ParimutuelShare(market_id, 1u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(alice()), winner_asset, 10 * BASE));
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(bob()), loser_asset, 10 * BASE));
+        resolve_market(market_id, 0u16);
+        assert_noop!(
+            Parimutuel::claim_rewards(RuntimeOrigin::signed(bob()), market_id),
+            Error::<Runtime>::NoWinningShares
+        );
+    });
+}
+
+#[test]
+fn claim_rewards_fails_if_market_resolved_as_invalid() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market();
+        let asset = Asset::ParimutuelShare(market_id, 0u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(alice()), asset, 10 * BASE));
+        resolve_market_with(market_id, OutcomeReport::Invalid);
+        assert_noop!(
+            Parimutuel::claim_rewards(RuntimeOrigin::signed(alice()), market_id),
+            Error::<Runtime>::NoWinningOutcome
+        );
+    });
+}
@@ -0,0 +1,51 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(all(feature = "mock", test))]
+
+mod buy;
+mod claim_refunds;
+mod claim_rewards;
+
+use crate::{mock::*, utils::market_mock, *};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pallet_pm_market_commons::{Error as MError, Markets};
+use prediction_market_primitives::{
+    constants::mock::{MinBetSize, BASE},
+    types::{Asset, MarketId, MarketStatus, MarketType, OutcomeReport, ScoringRule},
+};
+use sp_runtime::traits::Get;
+use test_case::test_case;
+
+fn create_market() -> MarketId {
+    let market_id = 0;
+    Markets::<Runtime>::insert(market_id, market_mock::<Runtime>());
+    market_id
+}
+
+fn resolve_market(market_id: MarketId, outcome: u16) {
+    resolve_market_with(market_id, OutcomeReport::Categorical(outcome));
+}
+
+fn resolve_market_with(market_id: MarketId, outcome: OutcomeReport) {
+    Markets::<Runtime>::mutate(market_id, |market| {
+        let market = market.as_mut().unwrap();
+        market.resolved_outcome = Some(outcome);
+        market.status = MarketStatus::Resolved;
+    });
+}
@@ -0,0 +1,121 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for pallet_pm_parimutuel
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: `2024-09-12`, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `zeitgeist-benchmark`, CPU: `AMD EPYC 7601 32-Core Processor`
+//! EXECUTION: ``, WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+
+// Executed Command:
+// ./target/production/zeitgeist
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_pm_parimutuel
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --template=./misc/weight_template.hbs
+// --output=./pallets/parimutuel/src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use core::marker::PhantomData;
+use frame_support::{traits::Get, weights::Weight};
+
+///  Trait containing the required functions for weight retrival within
+/// pallet_pm_parimutuel (automatically generated)
+pub trait WeightInfoZeitgeist {
+    fn buy() -> Weight;
+    fn claim_rewards() -> Weight;
+    fn claim_refunds() -> Weight;
+}
+
+/// Weight functions for pallet_pm_parimutuel (automatically generated)
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
+    /// mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:3 w:3)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+    /// `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:1 w:1)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(123), added: 2598, mode:
+    /// `MaxEncodedLen`)
+    /// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+    /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(43), added: 2518,
+    /// mode: `MaxEncodedLen`)
+    fn buy() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1474`
+        //  Estimated: `8799`
+        // Minimum execution time: 120_051 nanoseconds.
+        Weight::from_parts(124_592_000, 8799)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
+    /// mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+    /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(43), added: 2518,
+    /// mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:1 w:1)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(123), added: 2598, mode:
+    /// `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+    /// `MaxEncodedLen`)
+    fn claim_rewards() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1842`
+        //  Estimated: `6196`
+        // Minimum execution time: 89_720 nanoseconds.
+        Weight::from_parts(92_311_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
+    /// mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::TotalIssuance` (r:2 w:1)
+    /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(43), added: 2518,
+    /// mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:1 w:1)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(123), added: 2598, mode:
+    /// `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+    /// `MaxEncodedLen`)
+    fn claim_refunds() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1829`
+        //  Estimated: `6196`
+        // Minimum execution time: 86_450 nanoseconds.
+        Weight::from_parts(88_901_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+}
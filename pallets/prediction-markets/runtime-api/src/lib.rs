@@ -19,14 +19,39 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
 use parity_scale_codec::{Codec, MaxEncodedLen};
-use prediction_market_primitives::types::Asset;
+use prediction_market_primitives::types::{Asset, Market, MarketStatus, OutcomeReport};
 
 sp_api::decl_runtime_apis! {
-    pub trait PredictionMarketsApi<MarketId, Hash> where
+    pub trait PredictionMarketsApi<AccountId, Balance, BlockNumber, MarketId, Moment, Hash> where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
         MarketId: Codec + MaxEncodedLen,
+        Moment: Codec,
         Hash: Codec,
     {
-        fn market_outcome_share_id(market_id: MarketId, outcome: u16) -> Asset<MarketId>;
+        /// Returns the outcome asset of `market_id` at index `outcome`, or `None` if the market
+        /// doesn't exist or has no outcome at that index.
+        fn market_outcome_share_id(market_id: MarketId, outcome: u16) -> Option<Asset<MarketId>>;
+
+        /// Returns the market stored under `market_id`, if any.
+        fn market(
+            market_id: MarketId,
+        ) -> Option<Market<AccountId, Balance, BlockNumber, Moment, MarketId>>;
+
+        /// Returns all outcome assets of `market_id`, or an empty vector if the market doesn't
+        /// exist.
+        fn market_outcome_assets(market_id: MarketId) -> Vec<Asset<MarketId>>;
+
+        /// Returns the status of `market_id`, if the market exists.
+        fn market_status(market_id: MarketId) -> Option<MarketStatus>;
+
+        /// Returns the resolved outcome of `market_id`. Only `Some` if the market exists and is
+        /// resolved.
+        fn market_resolved_outcome(market_id: MarketId) -> Option<OutcomeReport>;
     }
 }
@@ -51,8 +51,9 @@ use prediction_market_primitives::{
     math::fixed::{BaseProvider, PredictionMarketBase},
     traits::DisputeApi,
     types::{
-        Asset, Deadlines, MarketCreation, MarketDisputeMechanism, MarketPeriod, MarketStatus,
-        MarketType, MultiHash, OutcomeReport, ScoringRule,
+        Asset, CombinatorialId, Deadlines, IndexSet, MarketCreation, MarketDisputeMechanism,
+        MarketPeriod, MarketStatus, MarketType, MaxMarketOracles, MultiHash, OutcomeReport,
+        ScoringRule, WeightedOracle,
     },
 };
 use sp_core::{crypto::DEV_PHRASE, H160, H256};
@@ -158,10 +159,11 @@ fn create_close_and_report_market<T: Config + pallet_timestamp::Config>(
     Ok((caller, market_id))
 }
 
-// Setup a categorical market for fn `internal_resolve`
+// Setup a market which resolves to `outcome` or, if `None`, to its last outcome
 fn setup_redeem_shares_common<T: Config + pallet_timestamp::Config>(
     market_type: MarketType,
     caller_account_id: &Option<T::AccountId>,
+    outcome: Option<OutcomeReport>,
 ) -> Result<(T::AccountId, MarketIdOf<T>), &'static str> {
     let (caller, market_id) = create_market_common::<T>(
         MarketCreation::Permissionless,
@@ -171,15 +173,15 @@ fn setup_redeem_shares_common<T: Config + pallet_timestamp::Config>(
         Some(MarketDisputeMechanism::Court),
         caller_account_id.clone(),
     )?;
-    let outcome: OutcomeReport;
-
-    if let MarketType::Categorical(categories) = market_type {
-        outcome = OutcomeReport::Categorical(categories.saturating_sub(1));
-    } else if let MarketType::Scalar(range) = market_type {
-        outcome = OutcomeReport::Scalar(*range.end());
-    } else {
-        panic!("setup_redeem_shares_common: Unsupported market type: {market_type:?}");
-    }
+    let outcome = outcome.unwrap_or_else(|| {
+        if let MarketType::Categorical(categories) = market_type {
+            OutcomeReport::Categorical(categories.saturating_sub(1))
+        } else if let MarketType::Scalar(range) = market_type {
+            OutcomeReport::Scalar(*range.end())
+        } else {
+            panic!("setup_redeem_shares_common: Unsupported market type: {market_type:?}");
+        }
+    });
 
     Call::<T>::buy_complete_set { market_id, amount: LIQUIDITY.saturated_into() }
         .dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())?;
@@ -203,6 +205,53 @@ fn setup_redeem_shares_common<T: Config + pallet_timestamp::Config>(
     Ok((caller, market_id))
 }
 
+// Create `n` binary markets, split a position conditioned on the first outcome of each of them and
+// resolve all markets to that outcome.
+fn setup_redeem_position<T: Config + pallet_timestamp::Config>(
+    n: u32,
+) -> Result<(T::AccountId, CombinatorialId), &'static str> {
+    let caller: T::AccountId = whitelisted_caller();
+    let amount: BalanceOf<T> = LIQUIDITY.saturated_into();
+    let mut parent_collection_id = None;
+    let mut market_ids = vec![];
+    for _ in 0..n {
+        let (_, market_id) = create_market_common::<T>(
+            MarketCreation::Permissionless,
+            MarketType::Categorical(2),
+            ScoringRule::AmmCdaHybrid,
+            None,
+            Some(MarketDisputeMechanism::Court),
+            Some(caller.clone()),
+        )?;
+        Pallet::<T>::split_position(
+            RawOrigin::Signed(caller.clone()).into(),
+            parent_collection_id,
+            market_id,
+            vec![1, 2],
+            amount,
+        )?;
+        let conditions = Pallet::<T>::collection_conditions(parent_collection_id)?;
+        let (collection_id, _) = Pallet::<T>::add_condition(&conditions, market_id, 1)?;
+        parent_collection_id = Some(collection_id);
+        market_ids.push(market_id);
+    }
+
+    let range_end: u32 = 1_000_000;
+    let grace_period: u32 = 2 * MILLISECS_PER_BLOCK;
+    pallet_timestamp::Pallet::<T>::set_timestamp((range_end + grace_period).into());
+    for market_id in market_ids {
+        let close_origin = T::CloseOrigin::try_successful_origin().unwrap();
+        let resolve_origin = T::ResolveOrigin::try_successful_origin().unwrap();
+        Call::<T>::admin_move_market_to_closed { market_id }
+            .dispatch_bypass_filter(close_origin)?;
+        Call::<T>::report { market_id, outcome: OutcomeReport::Categorical(0) }
+            .dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())?;
+        Call::<T>::admin_move_market_to_resolved { market_id }
+            .dispatch_bypass_filter(resolve_origin)?;
+    }
+    Ok((caller, parent_collection_id.ok_or("no position was split")?))
+}
+
 fn create_market_and_pool<T: Config + pallet_timestamp::Config>(
     caller_account_id: &Option<T::AccountId>,
     categories: u32,
@@ -397,6 +446,51 @@ where
     return Ok(market_id);
 }
 
+// Creates a closed market with `o` oracles of equal weight which all need to report, and moves
+// the time into the oracle duration.
+fn setup_multi_oracle_market<T>(
+    o: u32,
+    dispute_mechanism: Option<MarketDisputeMechanism>,
+) -> Result<(MarketIdOf<T>, Vec<T::AccountId>), DispatchError>
+where
+    T: Config + pallet_timestamp::Config,
+{
+    let range_start: MomentOf<T> = pallet_pm_market_commons::Pallet::<T>::now();
+    let range_end: MomentOf<T> = 1_000_000u64.saturated_into();
+    let (_, market_id) = create_market_common::<T>(
+        MarketCreation::Permissionless,
+        MarketType::Categorical(T::MaxCategories::get()),
+        ScoringRule::AmmCdaHybrid,
+        Some(MarketPeriod::Timestamp(range_start..range_end)),
+        dispute_mechanism,
+        None,
+    )?;
+    let oracles: Vec<T::AccountId> = (0..o).map(|i| account("oracle", i, 0)).collect();
+    let weighted_oracles = oracles
+        .iter()
+        .map(|who| WeightedOracle { who: who.clone(), weight: 1 })
+        .collect();
+    // Only the market admin may set the oracles of an active market.
+    let market_admin: T::AccountId = account("marketAdmin", 0, 0);
+    MarketAdmin::<T>::set(Some(market_admin.clone()));
+    Pallet::<T>::set_market_oracles(
+        RawOrigin::Signed(market_admin).into(),
+        market_id,
+        weighted_oracles,
+        o,
+    )?;
+
+    let close_origin = T::CloseOrigin::try_successful_origin().unwrap();
+    Pallet::<T>::admin_move_market_to_closed(close_origin, market_id).map_err(|e| e.error)?;
+    let market = pallet_pm_market_commons::Pallet::<T>::market(&market_id)?;
+    let end_period: u32 =
+        (market.deadlines.grace_period.saturated_into::<u32>() + 1) * MILLISECS_PER_BLOCK;
+    let end = range_end.saturated_into::<u32>();
+    pallet_timestamp::Pallet::<T>::set_timestamp((end + end_period).into());
+
+    Ok((market_id, oracles))
+}
+
 benchmarks! {
     where_clause {
         where
@@ -941,13 +1035,21 @@ benchmarks! {
 
     redeem_shares_categorical {
         let (caller, market_id) = setup_redeem_shares_common::<T>(
-            MarketType::Categorical(T::MaxCategories::get()), &None
+            MarketType::Categorical(T::MaxCategories::get()), &None, None
         )?;
     }: redeem_shares(RawOrigin::Signed(caller), market_id)
 
     redeem_shares_scalar {
         let (caller, market_id) = setup_redeem_shares_common::<T>(
-            MarketType::Scalar(0u128..=u128::MAX), &None
+            MarketType::Scalar(0u128..=u128::MAX), &None, None
+        )?;
+    }: redeem_shares(RawOrigin::Signed(caller), market_id)
+
+    redeem_shares_invalid {
+        let c in 2..T::MaxCategories::get().into();
+
+        let (caller, market_id) = setup_redeem_shares_common::<T>(
+            MarketType::Categorical(c.saturated_into()), &None, Some(OutcomeReport::Invalid)
         )?;
     }: redeem_shares(RawOrigin::Signed(caller), market_id)
 
@@ -1732,7 +1834,9 @@ benchmarks! {
         let relayer_account_id = get_relayer::<T>();
         let (caller_key_pair, caller_account_id) = get_user_account::<T>();
         let (caller, market_id) = setup_redeem_shares_common::<T>(
-            MarketType::Categorical(T::MaxCategories::get()), &Some(caller_account_id.clone())
+            MarketType::Categorical(T::MaxCategories::get()),
+            &Some(caller_account_id.clone()),
+            None,
         )?;
         let market_nonce = MarketNonces::<T>::get(caller_account_id.clone(), market_id);
         let signed_payload =
@@ -1751,7 +1855,30 @@ benchmarks! {
         let relayer_account_id = get_relayer::<T>();
         let (caller_key_pair, caller_account_id) = get_user_account::<T>();
         let (caller, market_id) = setup_redeem_shares_common::<T>(
-            MarketType::Scalar(0u128..=u128::MAX), &Some(caller_account_id.clone())
+            MarketType::Scalar(0u128..=u128::MAX), &Some(caller_account_id.clone()), None
+        )?;
+        let market_nonce = MarketNonces::<T>::get(caller_account_id.clone(), market_id);
+        let signed_payload =
+            (REDEEM_SHARES, relayer_account_id.clone(), 0u64, market_id);
+
+        let signature = caller_key_pair.sign(&signed_payload.encode().as_slice()).unwrap().encode();
+        let proof: Proof<T::Signature, T::AccountId> = get_proof::<T>(caller_account_id.clone(), relayer_account_id, &signature);
+
+    }: signed_redeem_shares(RawOrigin::Signed(caller.clone()), proof, market_id)
+    verify {
+        let new_nonce = MarketNonces::<T>::get(caller_account_id.clone(), market_id);
+        assert_eq!(new_nonce, market_nonce + 1);
+    }
+
+    signed_redeem_shares_invalid {
+        let c in 2..T::MaxCategories::get().into();
+
+        let relayer_account_id = get_relayer::<T>();
+        let (caller_key_pair, caller_account_id) = get_user_account::<T>();
+        let (caller, market_id) = setup_redeem_shares_common::<T>(
+            MarketType::Categorical(c.saturated_into()),
+            &Some(caller_account_id.clone()),
+            Some(OutcomeReport::Invalid),
         )?;
         let market_nonce = MarketNonces::<T>::get(caller_account_id.clone(), market_id);
         let signed_payload =
@@ -1804,6 +1931,167 @@ benchmarks! {
         .into());
     }
 
+    split_position {
+        let n in (T::MinCategories::get().into())..T::MaxCategories::get().into();
+        let (caller, market_id) = create_market_common::<T>(
+            MarketCreation::Permissionless,
+            MarketType::Categorical(n.saturated_into()),
+            ScoringRule::AmmCdaHybrid,
+            None,
+            Some(MarketDisputeMechanism::Court),
+            None,
+        )?;
+        let partition: Vec<IndexSet> = (0..n).map(|i| 1 << i).collect();
+        let amount: BalanceOf<T> = LIQUIDITY.saturated_into();
+    }: _(RawOrigin::Signed(caller), None, market_id, partition, amount)
+
+    merge_position {
+        let n in (T::MinCategories::get().into())..T::MaxCategories::get().into();
+        let (caller, market_id) = create_market_common::<T>(
+            MarketCreation::Permissionless,
+            MarketType::Categorical(n.saturated_into()),
+            ScoringRule::AmmCdaHybrid,
+            None,
+            Some(MarketDisputeMechanism::Court),
+            None,
+        )?;
+        let partition: Vec<IndexSet> = (0..n).map(|i| 1 << i).collect();
+        let amount: BalanceOf<T> = LIQUIDITY.saturated_into();
+        Pallet::<T>::split_position(
+            RawOrigin::Signed(caller.clone()).into(),
+            None,
+            market_id,
+            partition.clone(),
+            amount,
+        )?;
+    }: _(RawOrigin::Signed(caller), None, market_id, partition, amount)
+
+    redeem_position {
+        let n in 1..T::MaxSplitDepth::get();
+        let (caller, collection_id) = setup_redeem_position::<T>(n)?;
+    }: _(RawOrigin::Signed(caller), collection_id)
+
+    set_market_oracles {
+        let o in 1..MaxMarketOracles::get();
+        let (caller, market_id) = create_market_common::<T>(
+            MarketCreation::Advised,
+            MarketType::Categorical(T::MaxCategories::get()),
+            ScoringRule::AmmCdaHybrid,
+            None,
+            Some(MarketDisputeMechanism::Court),
+            None,
+        )?;
+        // The bonds of a full set of previous oracles are returned when the oracles are replaced.
+        let max_oracles = MaxMarketOracles::get();
+        let previous_oracles = (0..max_oracles)
+            .map(|i| WeightedOracle { who: account("previousOracle", i, 0), weight: 1 })
+            .collect();
+        Pallet::<T>::set_market_oracles(
+            RawOrigin::Signed(caller.clone()).into(),
+            market_id,
+            previous_oracles,
+            max_oracles,
+        )?;
+        let oracles: Vec<WeightedOracle<T::AccountId>> = (0..o)
+            .map(|i| WeightedOracle { who: account("oracle", i, 0), weight: 1 })
+            .collect();
+    }: _(RawOrigin::Signed(caller), market_id, oracles, o)
+    verify {
+        assert!(MarketOracles::<T>::contains_key(market_id));
+    }
+
+    report_multi_oracle {
+        let o in 1..MaxMarketOracles::get();
+        let (market_id, mut oracles) = setup_multi_oracle_market::<T>(o, Some(MarketDisputeMechanism::Court))?;
+        // The last report reaches the quorum and aggregates all reports.
+        let last_oracle = oracles.pop().unwrap();
+        for oracle in oracles {
+            Pallet::<T>::report(
+                RawOrigin::Signed(oracle).into(),
+                market_id,
+                OutcomeReport::Categorical(0),
+            )?;
+        }
+        let call = Call::<T>::report { market_id, outcome: OutcomeReport::Categorical(0) };
+    }: {
+        call.dispatch_bypass_filter(RawOrigin::Signed(last_oracle).into())?;
+    }
+    verify {
+        let market = pallet_pm_market_commons::Pallet::<T>::market(&market_id)?;
+        assert_eq!(market.status, MarketStatus::Reported);
+    }
+
+    report_multi_oracle_trusted {
+        let o in 1..MaxMarketOracles::get();
+        let (market_id, mut oracles) = setup_multi_oracle_market::<T>(o, None)?;
+        // The last report reaches the quorum and resolves the market.
+        let last_oracle = oracles.pop().unwrap();
+        for oracle in oracles {
+            Pallet::<T>::report(
+                RawOrigin::Signed(oracle).into(),
+                market_id,
+                OutcomeReport::Categorical(0),
+            )?;
+        }
+        let call = Call::<T>::report { market_id, outcome: OutcomeReport::Categorical(0) };
+    }: {
+        call.dispatch_bypass_filter(RawOrigin::Signed(last_oracle).into())?;
+    }
+    verify {
+        let market = pallet_pm_market_commons::Pallet::<T>::market(&market_id)?;
+        assert_eq!(market.status, MarketStatus::Resolved);
+    }
+
+    finalize_oracle_reports {
+        let o in 2..MaxMarketOracles::get();
+        let (market_id, mut oracles) = setup_multi_oracle_market::<T>(o, Some(MarketDisputeMechanism::Court))?;
+        // The bond of the oracle which doesn't report is slashed.
+        let _ = oracles.pop();
+        for oracle in oracles {
+            Pallet::<T>::report(
+                RawOrigin::Signed(oracle).into(),
+                market_id,
+                OutcomeReport::Categorical(0),
+            )?;
+        }
+        let market = pallet_pm_market_commons::Pallet::<T>::market(&market_id)?;
+        let oracle_duration = market.deadlines.oracle_duration.saturated_into::<u32>() + 1;
+        let now = pallet_pm_market_commons::Pallet::<T>::now().saturated_into::<u32>();
+        pallet_timestamp::Pallet::<T>::set_timestamp(
+            (now + oracle_duration * MILLISECS_PER_BLOCK).into(),
+        );
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller), market_id)
+    verify {
+        let market = pallet_pm_market_commons::Pallet::<T>::market(&market_id)?;
+        assert_eq!(market.status, MarketStatus::Reported);
+    }
+
+    finalize_oracle_reports_trusted {
+        let o in 2..MaxMarketOracles::get();
+        let (market_id, mut oracles) = setup_multi_oracle_market::<T>(o, None)?;
+        // The bond of the oracle which doesn't report is slashed and the market is resolved.
+        let _ = oracles.pop();
+        for oracle in oracles {
+            Pallet::<T>::report(
+                RawOrigin::Signed(oracle).into(),
+                market_id,
+                OutcomeReport::Categorical(0),
+            )?;
+        }
+        let market = pallet_pm_market_commons::Pallet::<T>::market(&market_id)?;
+        let oracle_duration = market.deadlines.oracle_duration.saturated_into::<u32>() + 1;
+        let now = pallet_pm_market_commons::Pallet::<T>::now().saturated_into::<u32>();
+        pallet_timestamp::Pallet::<T>::set_timestamp(
+            (now + oracle_duration * MILLISECS_PER_BLOCK).into(),
+        );
+        let caller: T::AccountId = whitelisted_caller();
+    }: finalize_oracle_reports(RawOrigin::Signed(caller), market_id)
+    verify {
+        let market = pallet_pm_market_commons::Pallet::<T>::market(&market_id)?;
+        assert_eq!(market.status, MarketStatus::Resolved);
+    }
+
     impl_benchmark_test_suite!(
         PredictionMarket,
         crate::mock::ExtBuilder::default().build(),
@@ -69,18 +69,19 @@ mod pallet {
             OnLiquidityProvided, PalletAdminGetter,
         },
         types::{
-            AdminConfig, Asset, Bond, CustomMetadata, Deadlines, EarlyClose, EarlyCloseState,
-            EthAddress, GlobalDisputeItem, Market, MarketBonds, MarketCreation,
-            MarketDisputeMechanism, MarketPeriod, MarketStatus, MarketType, MultiHash,
-            OutcomeReport, Report, ResultWithWeightInfo, ScalarPosition, ScoringRule,
+            AdminConfig, Asset, Bond, CombinatorialId, CustomMetadata, Deadlines, EarlyClose,
+            EarlyCloseState, EthAddress, GlobalDisputeItem, IndexSet, Market, MarketBonds,
+            MarketCreation, MarketDisputeMechanism, MarketPeriod, MarketStatus, MarketType,
+            MaxMarketOracles, MultiHash, OracleSet, OutcomeReport, Report, ResultWithWeightInfo,
+            ScalarPosition, ScoringRule, WeightedOracle,
         },
     };
     use scale_info::TypeInfo;
     pub use sp_arithmetic::per_things::{Perbill, Percent};
-    use sp_core::H256;
+    use sp_core::{hashing::blake2_256, H256};
     use sp_runtime::traits::{AccountIdConversion, Dispatchable, IdentifyAccount, Member, Verify};
     pub use sp_runtime::{
-        traits::{Saturating, Zero},
+        traits::{CheckedDiv, Saturating, Zero},
         DispatchError, DispatchResult, SaturatedConversion,
     };
 
@@ -95,6 +96,9 @@ mod pallet {
     /// The current storage version.
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
     const LOG_TARGET: &str = "runtime::pallet-prediction-markets";
+    /// The seed of the sub-account which holds the collateral of all combinatorial positions. Its
+    /// length ensures that the account can't collide with any market account.
+    const COMBINATORIAL_ACCOUNT_SEED: [u8; 20] = *b"combinatorialreserve";
     /// The maximum number of blocks between the [`LastTimeFrame`]
     /// and the current timestamp in block number allowed to recover
     /// the automatic market openings and closings from a chain stall.
@@ -105,6 +109,8 @@ mod pallet {
     pub(crate) type AssetOf<T> = Asset<MarketIdOf<T>>;
     pub(crate) type BalanceOf<T> = <T as pallet_pm_market_commons::Config>::Balance;
     pub(crate) type CacheSize = ConstU32<64>;
+    pub(crate) type CombinatorialCollectionOf<T> =
+        BoundedVec<(MarketIdOf<T>, IndexSet), <T as Config>::MaxSplitDepth>;
     pub(crate) type DeadlinesOf<T> = Deadlines<BlockNumberFor<T>>;
     pub(crate) type EditReason<T> = BoundedVec<u8, <T as Config>::MaxEditReasonLen>;
     pub(crate) type InitialItemOf<T> = InitialItem<AccountIdOf<T>, BalanceOf<T>>;
@@ -117,6 +123,9 @@ mod pallet {
         <<T as pallet_pm_market_commons::Config>::Timestamp as frame_support::traits::Time>::Moment;
     pub(crate) type NegativeImbalanceOf<T> =
         <<T as Config>::Currency as Currency<AccountIdOf<T>>>::NegativeImbalance;
+    pub(crate) type OracleReportsOf<T> =
+        BoundedVec<(AccountIdOf<T>, OutcomeReport), MaxMarketOracles>;
+    pub(crate) type OracleSetOf<T> = OracleSet<AccountIdOf<T>>;
     pub(crate) type RejectReason<T> = BoundedVec<u8, <T as Config>::MaxRejectReasonLen>;
     pub(crate) type ReportOf<T> = Report<AccountIdOf<T>, BlockNumberFor<T>>;
     pub(crate) type TimeFrame = u64;
@@ -697,12 +706,17 @@ mod pallet {
 
         /// Redeems the winning shares of a prediction market.
         ///
+        /// If the market resolved to `Invalid`, all outcome shares of the sender are redeemed for
+        /// an equal share of the collateral of a complete set and no winner fee is charged.
+        ///
         /// # Weight
         ///
-        /// Complexity: `O(1)`
+        /// Complexity: `O(1)`, or `O(n)` if the market resolved to `Invalid`, where `n` is the
+        /// number of outcomes of the market.
         #[pallet::call_index(12)]
         #[pallet::weight(T::WeightInfo::redeem_shares_categorical()
             .max(T::WeightInfo::redeem_shares_scalar())
+            .max(T::WeightInfo::redeem_shares_invalid(T::MaxCategories::get().into()))
         )]
         #[transactional]
         pub fn redeem_shares(
@@ -747,6 +761,9 @@ mod pallet {
 
         /// Reports the outcome of a market.
         ///
+        /// If the market has several oracles, see [`Pallet::set_market_oracles`], the report of
+        /// an oracle only counts towards the aggregated report of the market.
+        ///
         /// # Weight
         ///
         /// Complexity: `O(n)`, where `n` is the number of market ids,
@@ -755,6 +772,8 @@ mod pallet {
         #[pallet::weight(
             T::WeightInfo::report_market_with_dispute_mechanism(CacheSize::get())
                 .max(T::WeightInfo::report_trusted_market())
+                .max(T::WeightInfo::report_multi_oracle(MaxMarketOracles::get()))
+                .max(T::WeightInfo::report_multi_oracle_trusted(MaxMarketOracles::get()))
         )]
         #[transactional]
         pub fn report(
@@ -817,11 +836,6 @@ mod pallet {
                 Error::<T>::InvalidMarketStatus
             );
 
-            ensure!(
-                matches!(dispute_mechanism, MarketDisputeMechanism::Court),
-                Error::<T>::InvalidDisputeMechanism
-            );
-
             ensure!(
                 !T::GlobalDisputes::does_exist(&market_id),
                 Error::<T>::GlobalDisputeExistsAlready
@@ -1406,6 +1420,8 @@ mod pallet {
         #[pallet::weight(
             T::WeightInfo::signed_report_market_with_dispute_mechanism(CacheSize::get())
                 .max(T::WeightInfo::signed_report_trusted_market())
+                .max(T::WeightInfo::report_multi_oracle(MaxMarketOracles::get()))
+                .max(T::WeightInfo::report_multi_oracle_trusted(MaxMarketOracles::get()))
         )]
         #[transactional]
         pub fn signed_report(
@@ -1578,6 +1594,7 @@ mod pallet {
         #[pallet::call_index(31)]
         #[pallet::weight(T::WeightInfo::signed_redeem_shares_categorical()
             .max(T::WeightInfo::signed_redeem_shares_scalar())
+            .max(T::WeightInfo::signed_redeem_shares_invalid(T::MaxCategories::get().into()))
         )]
         #[transactional]
         pub fn signed_redeem_shares(
@@ -1715,6 +1732,177 @@ mod pallet {
             // The UpdateOracleOrigin should not pay fees for providing this service
             Ok((Some(T::WeightInfo::admin_update_market_oracle()), Pays::No).into())
         }
+
+        /// Splits a position into positions conditioned on the outcome of a market.
+        ///
+        /// Burns `amount` units of the position of `parent_collection_id` (or takes `amount`
+        /// units of the market's base asset if `parent_collection_id` is `None`) and mints
+        /// `amount` units of the position of each collection obtained by adding the condition
+        /// "`market_id` resolves to an outcome in the index set" to the parent collection, for
+        /// each index set in `partition`.
+        ///
+        /// Index sets are bitmasks over the outcomes of the market (bit `i` represents outcome
+        /// `i`; for scalar markets, bit `0` is `Long` and bit `1` is `Short`). The elements of
+        /// `partition` must be non-empty and disjoint. If they don't cover all outcomes of the
+        /// market, the position conditioned on their union is split instead of the parent.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n)`, where `n` is the number of index sets in `partition`.
+        #[pallet::call_index(36)]
+        #[pallet::weight(T::WeightInfo::split_position(partition.len().saturated_into()))]
+        #[transactional]
+        pub fn split_position(
+            origin: OriginFor<T>,
+            parent_collection_id: Option<CombinatorialId>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            partition: Vec<IndexSet>,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_split_position(who, parent_collection_id, market_id, partition, amount)
+        }
+
+        /// Merges positions conditioned on the outcome of a market. This is the inverse of
+        /// `split_position`.
+        ///
+        /// Merging is allowed regardless of the status of the market.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n)`, where `n` is the number of index sets in `partition`.
+        #[pallet::call_index(37)]
+        #[pallet::weight(T::WeightInfo::merge_position(partition.len().saturated_into()))]
+        #[transactional]
+        pub fn merge_position(
+            origin: OriginFor<T>,
+            parent_collection_id: Option<CombinatorialId>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            partition: Vec<IndexSet>,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_merge_position(who, parent_collection_id, market_id, partition, amount)
+        }
+
+        /// Redeems the sender's entire balance of the position of a combinatorial collection.
+        ///
+        /// All markets which the collection is conditioned on must be resolved. The payout per
+        /// unit of position is the product of the payouts of the collection's conditions: `1`
+        /// if a categorical market resolved to an outcome in the condition's index set, `0`
+        /// otherwise, and the fraction of the `Long`/`Short` payout selected by the index set
+        /// for scalar markets.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n)`, where `n` is the number of conditions of the collection.
+        #[pallet::call_index(38)]
+        #[pallet::weight(T::WeightInfo::redeem_position(T::MaxSplitDepth::get()))]
+        #[transactional]
+        pub fn redeem_position(
+            origin: OriginFor<T>,
+            collection_id: CombinatorialId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let condition_count = Self::do_redeem_position(who, collection_id)?;
+            Ok(Some(T::WeightInfo::redeem_position(condition_count)).into())
+        }
+
+        /// Lets several oracles resolve a market instead of its oracle.
+        ///
+        /// Once the market is closed, each oracle can `report` an outcome until the end of the
+        /// oracle duration. The reports are aggregated as soon as the combined weight of the
+        /// oracles which reported reaches `quorum`, or with `finalize_oracle_reports` once the
+        /// oracle duration is over. Categorical markets resolve to the outcome with the highest
+        /// reported weight, scalar markets to the weighted median of the reported values. If none
+        /// of the oracles reports, the market can be reported like any other market whose oracle
+        /// didn't report in time.
+        ///
+        /// The market creator may set the oracles while the market is proposed. Once the market
+        /// is active, only the market admin may change them, so that the creator can't replace the
+        /// oracles shortly before the market closes.
+        ///
+        /// The creator reserves `MultiOracleBond` for each oracle. The bond is unreserved as soon
+        /// as the oracle reports, which is possible until the end of the oracle duration even if
+        /// the quorum was reached before. Once the oracle duration is over, the bonds of the
+        /// oracles which didn't report are slashed with `finalize_oracle_reports`.
+        ///
+        /// # Arguments
+        ///
+        /// - `market_id`: The market to set the oracles of.
+        /// - `oracles`: The oracles and the weights of their reports. If empty, the market is
+        ///   resolved by its oracle again and the bonds of the previous oracles are unreserved.
+        /// - `quorum`: The combined weight of the reports which resolves the market before the end
+        ///   of the oracle duration.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n)`, where `n` is the number of oracles.
+        #[pallet::call_index(39)]
+        #[pallet::weight(T::WeightInfo::set_market_oracles(oracles.len().saturated_into()))]
+        #[transactional]
+        pub fn set_market_oracles(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            oracles: Vec<WeightedOracle<T::AccountId>>,
+            quorum: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_set_market_oracles(who, market_id, oracles, quorum)
+        }
+
+        /// Slashes the bonds of the oracles of a multi-oracle market which didn't report by the
+        /// end of the oracle duration. If the reports of the oracles didn't reach the quorum, they
+        /// are aggregated into the report of the market.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n)`, where `n` is the number of oracles of the market.
+        #[pallet::call_index(40)]
+        #[pallet::weight(
+            T::WeightInfo::finalize_oracle_reports(MaxMarketOracles::get())
+                .max(T::WeightInfo::finalize_oracle_reports_trusted(MaxMarketOracles::get()))
+        )]
+        #[transactional]
+        pub fn finalize_oracle_reports(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let market = <pallet_pm_market_commons::Pallet<T>>::market(&market_id)?;
+            let oracle_set =
+                <MarketOracles<T>>::get(market_id).ok_or(Error::<T>::MarketHasNoOracleSet)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                Self::oracle_reporting_window_expired(&market, now)?,
+                Error::<T>::OracleReportingWindowNotExpired
+            );
+
+            let oracle_count = oracle_set.oracles.len() as u32;
+            if market.report.is_none() {
+                Self::ensure_market_is_closed(&market)?;
+                let reports = <OracleReports<T>>::get(market_id);
+                ensure!(!reports.is_empty(), Error::<T>::NoOracleReports);
+                Self::settle_multi_oracle_bonds(&market_id, |_| true, true)?;
+                Self::do_finalize_oracle_reports(&market_id, &oracle_set, &reports)?;
+                // Markets without a dispute mechanism are resolved right away.
+                if market.dispute_mechanism.is_none() {
+                    let weight = T::WeightInfo::finalize_oracle_reports_trusted(oracle_count);
+                    return Ok(Some(weight).into());
+                }
+            } else {
+                ensure!(
+                    market.bonds.oracles.iter().any(|bond| !bond.is_settled),
+                    Error::<T>::OracleBondsAlreadySettled
+                );
+                Self::settle_multi_oracle_bonds(&market_id, |_| true, true)?;
+                if market.status == MarketStatus::Resolved {
+                    <MarketOracles<T>>::remove(market_id);
+                }
+            }
+
+            Ok(Some(T::WeightInfo::finalize_oracle_reports(oracle_count)).into())
+        }
     }
 
     #[pallet::config]
@@ -1920,6 +2108,11 @@ mod pallet {
         #[pallet::constant]
         type OracleBond: Get<BalanceOf<Self>>;
 
+        /// The amount of currency that must be bonded for each oracle of a multi-oracle market.
+        /// The bond of an oracle is slashed if it doesn't report within the oracle duration.
+        #[pallet::constant]
+        type MultiOracleBond: Get<BalanceOf<Self>>;
+
         /// The origin that is allowed to request edits in pending advised markets.
         type RequestEditOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -1949,6 +2142,10 @@ mod pallet {
             Balance = BalanceOf<Self>,
             MarketId = MarketIdOf<Self>,
         >;
+
+        /// The maximum number of markets a combinatorial position can be conditioned on.
+        #[pallet::constant]
+        type MaxSplitDepth: Get<u32>;
     }
 
     #[pallet::error]
@@ -2111,6 +2308,33 @@ mod pallet {
         MarketCanBeDisputed,
         /// The reporting window of the oracle has not expired
         OracleReportingWindowNotExpired,
+        /// The index sets are empty, overlapping or contain outcomes the market doesn't have, or
+        /// fewer than two index sets were specified.
+        InvalidPartition,
+        /// Markets with more than 128 outcomes can't be used in combinatorial positions.
+        TooManyOutcomesForCombinatorialPosition,
+        /// The specified combinatorial collection doesn't exist.
+        CombinatorialCollectionNotFound,
+        /// The collection is already conditioned on the specified market.
+        MarketAlreadyInCollection,
+        /// The collection would be conditioned on more than `MaxSplitDepth` markets.
+        MaxSplitDepthExceeded,
+        /// All markets of a combinatorial collection must have the same base asset.
+        CombinatorialBaseAssetMismatch,
+        /// The oracles contain duplicates or too many accounts, an oracle has zero weight or the
+        /// quorum is zero or exceeds the combined weight of the oracles.
+        InvalidOracleSet,
+        /// The sender already reported an outcome as an oracle of the market.
+        OracleAlreadyReported,
+        /// The market isn't resolved by the reports of several oracles.
+        MarketHasNoOracleSet,
+        /// None of the oracles of the market reported an outcome.
+        NoOracleReports,
+        /// The oracles of the market reported, so their reports must be aggregated with
+        /// `finalize_oracle_reports` instead.
+        OracleReportsNotFinalized,
+        /// The bonds of all oracles of the market are already settled.
+        OracleBondsAlreadySettled,
     }
 
     #[pallet::event]
@@ -2186,6 +2410,50 @@ mod pallet {
             old_oracle: T::AccountId,
             new_oracle: T::AccountId,
         },
+        /// A position has been split into positions conditioned on the outcome of a market.
+        PositionSplit {
+            who: T::AccountId,
+            parent_collection_id: Option<CombinatorialId>,
+            market_id: MarketIdOf<T>,
+            partition: Vec<IndexSet>,
+            amount: BalanceOf<T>,
+        },
+        /// Positions conditioned on the outcome of a market have been merged.
+        PositionsMerged {
+            who: T::AccountId,
+            parent_collection_id: Option<CombinatorialId>,
+            market_id: MarketIdOf<T>,
+            partition: Vec<IndexSet>,
+            amount: BalanceOf<T>,
+        },
+        /// The position of a combinatorial collection has been redeemed.
+        PositionRedeemed {
+            who: T::AccountId,
+            collection_id: CombinatorialId,
+            amount_redeemed: BalanceOf<T>,
+            payout: BalanceOf<T>,
+        },
+        /// The creator fee of a trade on `market_id` was paid to the market creator.
+        CreatorFeePaid {
+            market_id: MarketIdOf<T>,
+            creator: T::AccountId,
+            trader: T::AccountId,
+            asset: AssetOf<T>,
+            amount: BalanceOf<T>,
+        },
+        /// The oracles of a market have been set. If `oracles` is empty, the market is resolved
+        /// by its oracle again.
+        MarketOraclesSet {
+            market_id: MarketIdOf<T>,
+            oracles: Vec<WeightedOracle<T::AccountId>>,
+            quorum: u32,
+        },
+        /// An oracle of a multi-oracle market has reported an outcome.
+        OracleReportSubmitted {
+            market_id: MarketIdOf<T>,
+            oracle: T::AccountId,
+            outcome: OutcomeReport,
+        },
     }
 
     #[pallet::hooks]
@@ -2383,6 +2651,22 @@ mod pallet {
         ValueQuery,
     >;
 
+    /// The conditions of each combinatorial collection which positions have been minted for,
+    /// sorted by market ID. Each condition states that the market resolves to an outcome in the
+    /// index set.
+    #[pallet::storage]
+    pub type CombinatorialCollections<T: Config> =
+        StorageMap<_, Blake2_128Concat, CombinatorialId, CombinatorialCollectionOf<T>>;
+
+    /// The oracles of markets which are resolved by aggregating the reports of several oracles.
+    #[pallet::storage]
+    pub type MarketOracles<T: Config> = StorageMap<_, Twox64Concat, MarketIdOf<T>, OracleSetOf<T>>;
+
+    /// The reports which the oracles of a multi-oracle market submitted so far.
+    #[pallet::storage]
+    pub type OracleReports<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, OracleReportsOf<T>, ValueQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub vault_account: Option<T::AccountId>,
@@ -2590,7 +2874,7 @@ mod pallet {
 
             // Check to see if the sender has any winning shares.
             let resolved_outcome =
-                market.resolved_outcome.ok_or(Error::<T>::MarketIsNotResolved)?;
+                market.resolved_outcome.clone().ok_or(Error::<T>::MarketIsNotResolved)?;
 
             let winning_assets = match resolved_outcome {
                 OutcomeReport::Categorical(category_index) => {
@@ -2625,29 +2909,8 @@ mod pallet {
                         return Err(Error::<T>::InvalidMarketType.into());
                     };
 
-                    let calc_payouts = |final_value: u128,
-                                        low: u128,
-                                        high: u128|
-                     -> (Perbill, Perbill) {
-                        if final_value <= low {
-                            return (Perbill::zero(), Perbill::one());
-                        }
-                        if final_value >= high {
-                            return (Perbill::one(), Perbill::zero());
-                        }
-
-                        let payout_long: Perbill = Perbill::from_rational(
-                            final_value.saturating_sub(low),
-                            high.saturating_sub(low),
-                        );
-                        let payout_short: Perbill = Perbill::from_parts(
-                            Perbill::one().deconstruct().saturating_sub(payout_long.deconstruct()),
-                        );
-                        (payout_long, payout_short)
-                    };
-
                     let (long_percent, short_percent) =
-                        calc_payouts(value, *bound.start(), *bound.end());
+                        calculate_scalar_payouts(value, *bound.start(), *bound.end());
 
                     let long_payout = long_percent.mul_floor(long_balance);
                     let short_payout = short_percent.mul_floor(short_balance);
@@ -2663,6 +2926,36 @@ mod pallet {
                         (short_currency_id, short_payout, short_balance),
                     ]
                 },
+                OutcomeReport::Invalid => {
+                    // A complete set is backed by one unit of collateral, so each of its outcome
+                    // shares is worth an equal fraction of it.
+                    let outcome_count = BalanceOf::<T>::from(market.outcomes());
+                    let refunds = market
+                        .outcome_assets()
+                        .into_iter()
+                        .map(|asset| {
+                            let balance = T::AssetManager::free_balance(asset, &who);
+                            let payout =
+                                balance.checked_div(&outcome_count).unwrap_or_else(Zero::zero);
+                            (asset, payout, balance)
+                        })
+                        .filter(|(_, _, balance)| !balance.is_zero())
+                        .collect::<Vec<_>>();
+
+                    ensure!(!refunds.is_empty(), Error::<T>::NoWinningBalance);
+
+                    let total_payout =
+                        refunds.iter().fold(BalanceOf::<T>::zero(), |acc, (_, payout, _)| {
+                            acc.saturating_add(*payout)
+                        });
+                    ensure!(
+                        T::AssetManager::free_balance(market.base_asset, &market_account) >=
+                            total_payout,
+                        Error::<T>::InsufficientFundsInMarketAccount,
+                    );
+
+                    refunds
+                },
             };
 
             for (currency_id, payout, balance) in winning_assets {
@@ -2681,7 +2974,10 @@ mod pallet {
                 let max_payout = payout.min(remaining_bal);
                 let mut actual_payout = max_payout;
 
-                if !<LiquidityProviders<T>>::contains_key(&market_id, who.clone()) {
+                // Refunds of an invalid market aren't winnings.
+                if resolved_outcome != OutcomeReport::Invalid &&
+                    !<LiquidityProviders<T>>::contains_key(&market_id, who.clone())
+                {
                     // "Who" is not a liquidity provider, so we need to deduct a winning fee
                     let paid_winner_fee = T::WinnerFeeHandler::distribute(
                         market_id,
@@ -2710,6 +3006,8 @@ mod pallet {
             let weight = match resolved_outcome {
                 OutcomeReport::Categorical(_) => T::WeightInfo::redeem_shares_categorical(),
                 OutcomeReport::Scalar(_) => T::WeightInfo::redeem_shares_scalar(),
+                OutcomeReport::Invalid =>
+                    T::WeightInfo::redeem_shares_invalid(market.outcomes().into()),
             };
 
             Ok(weight)
@@ -2724,13 +3022,39 @@ mod pallet {
             let current_block = <frame_system::Pallet<T>>::block_number();
             let market_report = Report { at: current_block, by: who.clone(), outcome };
             let market = <pallet_pm_market_commons::Pallet<T>>::market(&market_id)?;
-            ensure!(market.report.is_none(), Error::<T>::MarketAlreadyReported);
-            Self::ensure_market_is_closed(&market)?;
+            let oracle_set = <MarketOracles<T>>::get(market_id);
+            // The oracles of a multi-oracle market may report until the end of the oracle
+            // duration to get their bonds back, even if the quorum was reached before.
+            let late_oracle_report = oracle_set.is_some() && market.report.is_some();
+            if !late_oracle_report {
+                ensure!(market.report.is_none(), Error::<T>::MarketAlreadyReported);
+                Self::ensure_market_is_closed(&market)?;
+            }
             ensure!(
                 market.matches_outcome_report(&market_report.outcome),
                 Error::<T>::OutcomeMismatch
             );
 
+            if let Some(oracle_set) = oracle_set {
+                if !Self::oracle_reporting_window_expired(&market, current_block)? {
+                    return Self::submit_oracle_report(
+                        who,
+                        market_id,
+                        &market,
+                        oracle_set,
+                        market_report.outcome,
+                    );
+                }
+                ensure!(!late_oracle_report, Error::<T>::MarketAlreadyReported);
+                ensure!(
+                    <OracleReports<T>>::get(market_id).is_empty(),
+                    Error::<T>::OracleReportsNotFinalized
+                );
+                // None of the oracles reported in time, so the market is reported like any other
+                // market whose oracle didn't report in time.
+                Self::settle_multi_oracle_bonds(market_id, |_| true, true)?;
+            }
+
             let weight = if market.dispute_mechanism.is_some() {
                 Self::report_market_with_dispute_mechanism(
                     origin,
@@ -2750,6 +3074,298 @@ mod pallet {
             Ok(weight)
         }
 
+        fn do_set_market_oracles(
+            who: T::AccountId,
+            market_id: MarketIdOf<T>,
+            oracles: Vec<WeightedOracle<T::AccountId>>,
+            quorum: u32,
+        ) -> DispatchResult {
+            let market = <pallet_pm_market_commons::Pallet<T>>::market(&market_id)?;
+            let is_market_admin = <MarketAdmin<T>>::get().map_or(false, |admin| admin == who);
+            ensure!(who == market.creator || is_market_admin, Error::<T>::CallerNotMarketCreator);
+            let is_editable = match market.status {
+                MarketStatus::Proposed => true,
+                MarketStatus::Active => is_market_admin,
+                _ => false,
+            };
+            ensure!(is_editable, Error::<T>::InvalidMarketStatus);
+
+            let oracle_set = if oracles.is_empty() {
+                None
+            } else {
+                let mut accounts = oracles.iter().map(|oracle| &oracle.who).collect::<Vec<_>>();
+                accounts.sort();
+                accounts.dedup();
+                ensure!(accounts.len() == oracles.len(), Error::<T>::InvalidOracleSet);
+                let total_weight =
+                    oracles.iter().try_fold(0u32, |acc, oracle| -> Result<u32, DispatchError> {
+                        ensure!(!oracle.weight.is_zero(), Error::<T>::InvalidOracleSet);
+                        acc.checked_add(oracle.weight).ok_or(Error::<T>::InvalidOracleSet.into())
+                    })?;
+                ensure!(!quorum.is_zero() && quorum <= total_weight, Error::<T>::InvalidOracleSet);
+                let oracles = BoundedVec::try_from(oracles.clone())
+                    .map_err(|_| Error::<T>::InvalidOracleSet)?;
+                Some(OracleSet { oracles, quorum })
+            };
+
+            let creator = market.creator;
+            let bond = T::MultiOracleBond::get();
+            let bonds = BoundedVec::try_from(
+                oracles.iter().map(|_| Bond::new(creator.clone(), bond)).collect::<Vec<_>>(),
+            )
+            .map_err(|_| Error::<T>::InvalidOracleSet)?;
+
+            // The bonds of the previous oracles are returned, since they never had the chance to
+            // report.
+            Self::settle_multi_oracle_bonds(&market_id, |_| true, false)?;
+
+            let total_bond = bond.saturating_mul(bonds.len().saturated_into());
+            T::AssetManager::reserve_named(&Self::reserve_id(), Asset::Tru, &creator, total_bond)?;
+            <pallet_pm_market_commons::Pallet<T>>::mutate_market(&market_id, |market| {
+                market.bonds.oracles = bonds;
+                Ok(())
+            })?;
+            <MarketOracles<T>>::set(market_id, oracle_set);
+
+            Self::deposit_event(Event::MarketOraclesSet { market_id, oracles, quorum });
+
+            Ok(())
+        }
+
+        /// Records the report of an oracle of a multi-oracle market and returns its bond. The
+        /// reports are aggregated as soon as they reach the quorum, later reports only return the
+        /// bond of the oracle.
+        fn submit_oracle_report(
+            who: T::AccountId,
+            market_id: &MarketIdOf<T>,
+            market: &MarketOf<T>,
+            oracle_set: OracleSetOf<T>,
+            outcome: OutcomeReport,
+        ) -> DispatchResultWithPostInfo {
+            let index = oracle_set
+                .oracles
+                .iter()
+                .position(|oracle| oracle.who == who)
+                .ok_or(Error::<T>::ReporterNotOracle)?;
+            // The bonds are stored in the order of the oracles and settled once they report.
+            let already_reported =
+                market.bonds.oracles.get(index).map_or(false, |bond| bond.is_settled);
+            ensure!(!already_reported, Error::<T>::OracleAlreadyReported);
+            Self::settle_multi_oracle_bonds(market_id, |oracle| *oracle == who, false)?;
+
+            Self::deposit_event(Event::OracleReportSubmitted {
+                market_id: *market_id,
+                oracle: who.clone(),
+                outcome: outcome.clone(),
+            });
+
+            let oracle_count = oracle_set.oracles.len() as u32;
+            let weight = T::WeightInfo::report_multi_oracle(oracle_count);
+            if market.report.is_some() {
+                let bonds_settled = market
+                    .bonds
+                    .oracles
+                    .iter()
+                    .enumerate()
+                    .all(|(bond_index, bond)| bond_index == index || bond.is_settled);
+                if market.status == MarketStatus::Resolved && bonds_settled {
+                    <MarketOracles<T>>::remove(market_id);
+                }
+                return Ok(Some(weight).into());
+            }
+
+            let reports = <OracleReports<T>>::try_mutate(
+                market_id,
+                |reports| -> Result<OracleReportsOf<T>, DispatchError> {
+                    ensure!(
+                        !reports.iter().any(|(oracle, _)| *oracle == who),
+                        Error::<T>::OracleAlreadyReported
+                    );
+                    reports.try_push((who, outcome)).map_err(|_| Error::<T>::StorageOverflow)?;
+                    Ok(reports.clone())
+                },
+            )?;
+
+            let reported_weight = oracle_set
+                .oracles
+                .iter()
+                .filter(|oracle| reports.iter().any(|(reporter, _)| *reporter == oracle.who))
+                .fold(0u32, |acc, oracle| acc.saturating_add(oracle.weight));
+            if reported_weight >= oracle_set.quorum {
+                Self::do_finalize_oracle_reports(market_id, &oracle_set, &reports)?;
+                // Markets without a dispute mechanism are resolved right away.
+                if market.dispute_mechanism.is_none() {
+                    let weight = T::WeightInfo::report_multi_oracle_trusted(oracle_count);
+                    return Ok(Some(weight).into());
+                }
+            }
+
+            Ok(Some(weight).into())
+        }
+
+        /// Reports the aggregated outcome of the oracles of a multi-oracle market.
+        fn do_finalize_oracle_reports(
+            market_id: &MarketIdOf<T>,
+            oracle_set: &OracleSetOf<T>,
+            reports: &OracleReportsOf<T>,
+        ) -> DispatchResult {
+            let market = <pallet_pm_market_commons::Pallet<T>>::market(market_id)?;
+            let (by, outcome) = Self::aggregate_oracle_reports(&market, oracle_set, reports)
+                .ok_or(Error::<T>::NoOracleReports)?;
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            let report = Report { at: now, by, outcome };
+            <pallet_pm_market_commons::Pallet<T>>::mutate_market(market_id, |market| {
+                market.report = Some(report.clone());
+                market.status = MarketStatus::Reported;
+                Ok(())
+            })?;
+            if market.dispute_mechanism.is_some() {
+                let resolve_at = now.saturating_add(market.deadlines.dispute_duration);
+                <MarketIdsPerReportBlock<T>>::try_mutate(resolve_at, |ids| {
+                    ids.try_push(*market_id).map_err(|_| <Error<T>>::StorageOverflow)
+                })?;
+            } else {
+                let market = <pallet_pm_market_commons::Pallet<T>>::market(market_id)?;
+                Self::on_resolution(market_id, &market)?;
+            }
+
+            Self::deposit_event(Event::MarketReported(*market_id, MarketStatus::Reported, report));
+
+            Ok(())
+        }
+
+        /// Aggregates the reports of the oracles of a multi-oracle market and returns the oracle
+        /// whose report was selected along with the outcome.
+        ///
+        /// Categorical markets resolve to the outcome with the highest combined weight, where
+        /// ties go to the outcome which was reported first. Scalar markets resolve to the
+        /// weighted median of the reported values, unless the reports of `Invalid` carry at
+        /// least as much weight as the reported values.
+        fn aggregate_oracle_reports(
+            market: &MarketOf<T>,
+            oracle_set: &OracleSetOf<T>,
+            reports: &OracleReportsOf<T>,
+        ) -> Option<(AccountIdOf<T>, OutcomeReport)> {
+            let weight_of = |who: &AccountIdOf<T>| {
+                oracle_set
+                    .oracles
+                    .iter()
+                    .find(|oracle| oracle.who == *who)
+                    .map_or(0u32, |oracle| oracle.weight)
+            };
+            let weight_of_outcome = |outcome: &OutcomeReport| {
+                reports
+                    .iter()
+                    .filter(|(_, reported)| reported == outcome)
+                    .fold(0u32, |acc, (who, _)| acc.saturating_add(weight_of(who)))
+            };
+            let majority = || {
+                let mut best: Option<(&(AccountIdOf<T>, OutcomeReport), u32)> = None;
+                for report in reports.iter() {
+                    let weight = weight_of_outcome(&report.1);
+                    if best.map_or(true, |(_, best_weight)| weight > best_weight) {
+                        best = Some((report, weight));
+                    }
+                }
+                best.map(|(report, _)| report.clone())
+            };
+
+            match market.market_type {
+                MarketType::Categorical(_) => majority(),
+                MarketType::Scalar(_) => {
+                    let mut values = reports
+                        .iter()
+                        .filter_map(|(who, outcome)| match outcome {
+                            OutcomeReport::Scalar(value) => Some((*value, weight_of(who), who)),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>();
+                    let total_weight = values
+                        .iter()
+                        .fold(0u64, |acc, (_, weight, _)| acc.saturating_add((*weight).into()));
+                    let invalid_weight = weight_of_outcome(&OutcomeReport::Invalid);
+                    if total_weight <= invalid_weight.into() {
+                        return majority();
+                    }
+                    // The sort is stable, so equal values stay in the order they were reported.
+                    values.sort_by_key(|(value, _, _)| *value);
+                    let mut cumulative_weight = 0u64;
+                    for (value, weight, who) in values {
+                        cumulative_weight = cumulative_weight.saturating_add(weight.into());
+                        if cumulative_weight.saturating_mul(2) >= total_weight {
+                            return Some((who.clone(), OutcomeReport::Scalar(value)));
+                        }
+                    }
+                    None
+                },
+            }
+        }
+
+        /// Settles the outstanding bonds of the oracles of a multi-oracle market for which
+        /// `settle` returns `true`. The bonds are slashed if `slash` is set and unreserved
+        /// otherwise.
+        fn settle_multi_oracle_bonds<F>(
+            market_id: &MarketIdOf<T>,
+            mut settle: F,
+            slash: bool,
+        ) -> DispatchResult
+        where
+            F: FnMut(&AccountIdOf<T>) -> bool,
+        {
+            let market = <pallet_pm_market_commons::Pallet<T>>::market(market_id)?;
+            let oracles = <MarketOracles<T>>::get(market_id)
+                .map(|oracle_set| oracle_set.oracles.into_inner())
+                .unwrap_or_default();
+            let mut bonds = market.bonds.oracles;
+            let mut overall_imbalance = NegativeImbalanceOf::<T>::zero();
+            // The bonds are stored in the order of the oracles.
+            for (bond, oracle) in bonds.iter_mut().zip(oracles.iter()) {
+                if bond.is_settled || !settle(&oracle.who) {
+                    continue;
+                }
+                if slash {
+                    let (imbalance, excess) = T::Currency::slash_reserved_named(
+                        &Self::reserve_id(),
+                        &bond.who,
+                        bond.value,
+                    );
+                    debug_assert!(
+                        excess.is_zero(),
+                        "Failed to slash the bond of oracle {:?} of market {:?}",
+                        oracle.who,
+                        market_id,
+                    );
+                    overall_imbalance.subsume(imbalance);
+                } else {
+                    let missing =
+                        T::Currency::unreserve_named(&Self::reserve_id(), &bond.who, bond.value);
+                    debug_assert!(
+                        missing.is_zero(),
+                        "Could not unreserve the bond of oracle {:?} of market {:?}",
+                        oracle.who,
+                        market_id,
+                    );
+                }
+                bond.is_settled = true;
+            }
+            T::Slash::on_unbalanced(overall_imbalance);
+            <pallet_pm_market_commons::Pallet<T>>::mutate_market(market_id, |market| {
+                market.bonds.oracles = bonds;
+                Ok(())
+            })
+        }
+
+        /// Whether `report` was submitted by the oracle of the market or aggregated from the
+        /// reports of its oracles.
+        fn is_reported_by_oracle(
+            market_id: &MarketIdOf<T>,
+            market: &MarketOf<T>,
+            report: &ReportOf<T>,
+        ) -> bool {
+            report.by == market.oracle || !<OracleReports<T>>::get(market_id).is_empty()
+        }
+
         pub fn outcome_assets(market_id: MarketIdOf<T>, market: &MarketOf<T>) -> Vec<AssetOf<T>> {
             match market.market_type {
                 MarketType::Categorical(categories) => {
@@ -2939,6 +3555,332 @@ mod pallet {
             Ok(())
         }
 
+        #[require_transactional]
+        pub(crate) fn do_split_position(
+            who: T::AccountId,
+            parent_collection_id: Option<CombinatorialId>,
+            market_id: MarketIdOf<T>,
+            partition: Vec<IndexSet>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            ensure!(amount != BalanceOf::<T>::zero(), Error::<T>::ZeroAmount);
+            let market = <pallet_pm_market_commons::Pallet<T>>::market(&market_id)?;
+            Self::ensure_market_is_active(&market)?;
+            let conditions = Self::collection_conditions(parent_collection_id)?;
+            let collateral = Self::ensure_market_is_conditionable(&conditions, &market)?;
+            let union = Self::validate_partition(&market, &partition)?;
+
+            let source = Self::partition_source(
+                parent_collection_id,
+                &conditions,
+                &market,
+                market_id,
+                union,
+            )?;
+            match source {
+                Some(asset) => Self::burn_position(asset, &who, amount)?,
+                None => {
+                    ensure!(
+                        T::AssetManager::free_balance(collateral, &who) >= amount,
+                        Error::<T>::NotEnoughBalance
+                    );
+                    T::AssetManager::transfer(
+                        collateral,
+                        &who,
+                        &Self::combinatorial_account(),
+                        amount,
+                    )?;
+                },
+            }
+
+            for &index_set in partition.iter() {
+                let asset = Self::register_collection(&conditions, market_id, index_set)?;
+                T::AssetManager::deposit(asset, &who, amount)?;
+            }
+
+            Self::deposit_event(Event::PositionSplit {
+                who,
+                parent_collection_id,
+                market_id,
+                partition,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        #[require_transactional]
+        pub(crate) fn do_merge_position(
+            who: T::AccountId,
+            parent_collection_id: Option<CombinatorialId>,
+            market_id: MarketIdOf<T>,
+            partition: Vec<IndexSet>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            ensure!(amount != BalanceOf::<T>::zero(), Error::<T>::ZeroAmount);
+            let market = <pallet_pm_market_commons::Pallet<T>>::market(&market_id)?;
+            let conditions = Self::collection_conditions(parent_collection_id)?;
+            let collateral = Self::ensure_market_is_conditionable(&conditions, &market)?;
+            let union = Self::validate_partition(&market, &partition)?;
+
+            for &index_set in partition.iter() {
+                let (collection_id, _) = Self::add_condition(&conditions, market_id, index_set)?;
+                Self::burn_position(Asset::CombinatorialOutcome(collection_id), &who, amount)?;
+            }
+
+            let source = Self::partition_source(
+                parent_collection_id,
+                &conditions,
+                &market,
+                market_id,
+                union,
+            )?;
+            match source {
+                Some(asset) => T::AssetManager::deposit(asset, &who, amount)?,
+                None => T::AssetManager::transfer(
+                    collateral,
+                    &Self::combinatorial_account(),
+                    &who,
+                    amount,
+                )?,
+            }
+
+            Self::deposit_event(Event::PositionsMerged {
+                who,
+                parent_collection_id,
+                market_id,
+                partition,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Redeems `who`'s position of `collection_id` and returns the number of conditions of
+        /// the collection.
+        #[require_transactional]
+        pub(crate) fn do_redeem_position(
+            who: T::AccountId,
+            collection_id: CombinatorialId,
+        ) -> Result<u32, DispatchError> {
+            let conditions = <CombinatorialCollections<T>>::get(collection_id)
+                .ok_or(Error::<T>::CombinatorialCollectionNotFound)?;
+            let asset = Asset::CombinatorialOutcome(collection_id);
+            let balance = T::AssetManager::free_balance(asset, &who);
+            ensure!(balance > BalanceOf::<T>::zero(), Error::<T>::NoWinningBalance);
+
+            let mut payout_ratio = Perbill::one();
+            let mut collateral = None;
+            let mut is_liquidity_provider = false;
+            for &(market_id, index_set) in conditions.iter() {
+                let market = <pallet_pm_market_commons::Pallet<T>>::market(&market_id)?;
+                ensure!(market.status == MarketStatus::Resolved, Error::<T>::MarketIsNotResolved);
+                let resolved_outcome =
+                    market.resolved_outcome.as_ref().ok_or(Error::<T>::MarketIsNotResolved)?;
+                let condition_payout =
+                    Self::condition_payout(&market, resolved_outcome, index_set)?;
+                payout_ratio = payout_ratio.saturating_mul(condition_payout);
+                collateral = Some(market.base_asset);
+                is_liquidity_provider = is_liquidity_provider ||
+                    <LiquidityProviders<T>>::contains_key(market_id, &who);
+            }
+            // Collections always have at least one condition.
+            let (fee_market_id, _) =
+                *conditions.first().ok_or(Error::<T>::CombinatorialCollectionNotFound)?;
+            let collateral = collateral.ok_or(Error::<T>::CombinatorialCollectionNotFound)?;
+
+            let account = Self::combinatorial_account();
+            let payout = payout_ratio.mul_floor(balance);
+            ensure!(
+                T::AssetManager::free_balance(collateral, &account) >= payout,
+                Error::<T>::InsufficientFundsInMarketAccount,
+            );
+
+            // Destroy the position.
+            T::AssetManager::withdraw(asset, &who, balance)?;
+
+            let mut actual_payout = payout;
+            if !is_liquidity_provider && payout > BalanceOf::<T>::zero() {
+                // "Who" is not a liquidity provider, so we need to deduct a winning fee
+                let paid_winner_fee =
+                    T::WinnerFeeHandler::distribute(fee_market_id, collateral, &account, payout);
+                actual_payout = payout.saturating_sub(paid_winner_fee);
+            }
+
+            // Pay out the winner.
+            T::AssetManager::transfer(collateral, &account, &who, actual_payout)?;
+
+            Self::deposit_event(Event::PositionRedeemed {
+                who,
+                collection_id,
+                amount_redeemed: balance,
+                payout: actual_payout,
+            });
+
+            Ok(conditions.len().saturated_into())
+        }
+
+        /// The account which holds the collateral backing all combinatorial positions.
+        #[inline]
+        pub(crate) fn combinatorial_account() -> AccountIdOf<T> {
+            T::PalletId::get().into_sub_account_truncating(COMBINATORIAL_ACCOUNT_SEED)
+        }
+
+        /// Returns the index set which contains every outcome of `market`.
+        fn full_index_set(market: &MarketOf<T>) -> Result<IndexSet, DispatchError> {
+            let unused_bits = IndexSet::BITS
+                .checked_sub(market.outcomes().into())
+                .ok_or(Error::<T>::TooManyOutcomesForCombinatorialPosition)?;
+            Ok(IndexSet::MAX.checked_shr(unused_bits).unwrap_or(0))
+        }
+
+        /// Ensures that `partition` consists of at least two non-empty and disjoint index sets of
+        /// outcomes of `market` and returns their union.
+        fn validate_partition(
+            market: &MarketOf<T>,
+            partition: &[IndexSet],
+        ) -> Result<IndexSet, DispatchError> {
+            let full_index_set = Self::full_index_set(market)?;
+            ensure!(partition.len() >= 2, Error::<T>::InvalidPartition);
+            let mut union: IndexSet = 0;
+            for &index_set in partition.iter() {
+                ensure!(
+                    index_set != 0 && index_set & !full_index_set == 0 && index_set & union == 0,
+                    Error::<T>::InvalidPartition
+                );
+                union |= index_set;
+            }
+            Ok(union)
+        }
+
+        /// Returns the conditions of `collection_id`. The root collection (`None`) has none.
+        pub(crate) fn collection_conditions(
+            collection_id: Option<CombinatorialId>,
+        ) -> Result<CombinatorialCollectionOf<T>, DispatchError> {
+            match collection_id {
+                Some(collection_id) => Ok(<CombinatorialCollections<T>>::get(collection_id)
+                    .ok_or(Error::<T>::CombinatorialCollectionNotFound)?),
+                None => Ok(Default::default()),
+            }
+        }
+
+        /// Ensures that a collection with `conditions` can be conditioned on `market` and returns
+        /// the collateral of the collection.
+        fn ensure_market_is_conditionable(
+            conditions: &CombinatorialCollectionOf<T>,
+            market: &MarketOf<T>,
+        ) -> Result<AssetOf<T>, DispatchError> {
+            ensure!(market.is_redeemable(), Error::<T>::InvalidScoringRule);
+            if let Some((market_id, _)) = conditions.first() {
+                let other_market = <pallet_pm_market_commons::Pallet<T>>::market(market_id)?;
+                ensure!(
+                    other_market.base_asset == market.base_asset,
+                    Error::<T>::CombinatorialBaseAssetMismatch
+                );
+            }
+            Ok(market.base_asset)
+        }
+
+        /// Returns the ID and the conditions of the collection obtained by adding the condition
+        /// `(market_id, index_set)` to `conditions`.
+        pub(crate) fn add_condition(
+            conditions: &CombinatorialCollectionOf<T>,
+            market_id: MarketIdOf<T>,
+            index_set: IndexSet,
+        ) -> Result<(CombinatorialId, CombinatorialCollectionOf<T>), DispatchError> {
+            ensure!(
+                !conditions.iter().any(|(id, _)| *id == market_id),
+                Error::<T>::MarketAlreadyInCollection
+            );
+            let mut conditions = conditions.clone();
+            let index = conditions.partition_point(|(id, _)| *id < market_id);
+            conditions
+                .try_insert(index, (market_id, index_set))
+                .map_err(|_| Error::<T>::MaxSplitDepthExceeded)?;
+            Ok((blake2_256(&conditions.encode()), conditions))
+        }
+
+        /// Stores the collection obtained by adding the condition `(market_id, index_set)` to
+        /// `conditions` and returns the asset of its position.
+        fn register_collection(
+            conditions: &CombinatorialCollectionOf<T>,
+            market_id: MarketIdOf<T>,
+            index_set: IndexSet,
+        ) -> Result<AssetOf<T>, DispatchError> {
+            let (collection_id, conditions) =
+                Self::add_condition(conditions, market_id, index_set)?;
+            if !<CombinatorialCollections<T>>::contains_key(collection_id) {
+                <CombinatorialCollections<T>>::insert(collection_id, conditions);
+            }
+            Ok(Asset::CombinatorialOutcome(collection_id))
+        }
+
+        /// Returns the position which a partition of the outcomes of `market` with the given
+        /// `union` is split from (or merged into), or `None` if that's the collateral.
+        fn partition_source(
+            parent_collection_id: Option<CombinatorialId>,
+            conditions: &CombinatorialCollectionOf<T>,
+            market: &MarketOf<T>,
+            market_id: MarketIdOf<T>,
+            union: IndexSet,
+        ) -> Result<Option<AssetOf<T>>, DispatchError> {
+            if union == Self::full_index_set(market)? {
+                Ok(parent_collection_id.map(Asset::CombinatorialOutcome))
+            } else {
+                Self::register_collection(conditions, market_id, union).map(Some)
+            }
+        }
+
+        fn burn_position(
+            asset: AssetOf<T>,
+            who: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            ensure!(
+                T::AssetManager::free_balance(asset, who) >= amount,
+                Error::<T>::InsufficientShareBalance
+            );
+            T::AssetManager::withdraw(asset, who, amount)
+        }
+
+        /// Returns the fraction of a unit of collateral which is paid out per unit of a position
+        /// conditioned on `market` resolving to an outcome in `index_set`.
+        fn condition_payout(
+            market: &MarketOf<T>,
+            resolved_outcome: &OutcomeReport,
+            index_set: IndexSet,
+        ) -> Result<Perbill, DispatchError> {
+            let contains = |index: u16| {
+                index_set.checked_shr(index.into()).map_or(false, |bits| bits & 1 == 1)
+            };
+            match *resolved_outcome {
+                OutcomeReport::Categorical(index) =>
+                    Ok(if contains(index) { Perbill::one() } else { Perbill::zero() }),
+                OutcomeReport::Scalar(value) => {
+                    let bound = if let MarketType::Scalar(ref range) = market.market_type {
+                        range
+                    } else {
+                        return Err(Error::<T>::InvalidMarketType.into());
+                    };
+                    let (long_percent, short_percent) =
+                        calculate_scalar_payouts(value, *bound.start(), *bound.end());
+                    let mut payout = Perbill::zero();
+                    if contains(0) {
+                        payout = payout.saturating_add(long_percent);
+                    }
+                    if contains(1) {
+                        payout = payout.saturating_add(short_percent);
+                    }
+                    Ok(payout)
+                },
+                OutcomeReport::Invalid => {
+                    let outcomes = market.outcomes();
+                    let contained = (0..outcomes).filter(|&index| contains(index)).count();
+                    Ok(Perbill::from_rational(contained as u32, outcomes.into()))
+                },
+            }
+        }
+
         pub(crate) fn do_reject_market(
             market_id: &MarketIdOf<T>,
             market: MarketOf<T>,
@@ -2946,6 +3888,8 @@ mod pallet {
         ) -> DispatchResult {
             ensure!(market.status == MarketStatus::Proposed, Error::<T>::InvalidMarketStatus);
             Self::unreserve_oracle_bond(market_id)?;
+            Self::settle_multi_oracle_bonds(market_id, |_| true, false)?;
+            <MarketOracles<T>>::remove(market_id);
             let imbalance =
                 Self::slash_creation_bond(market_id, Some(T::AdvisoryBondSlashPercentage::get()))?;
             T::Slash::on_unbalanced(imbalance);
@@ -2963,6 +3907,8 @@ mod pallet {
             ensure!(market.status == MarketStatus::Proposed, Error::<T>::InvalidMarketStatus);
             Self::unreserve_creation_bond(market_id)?;
             Self::unreserve_oracle_bond(market_id)?;
+            Self::settle_multi_oracle_bonds(market_id, |_| true, false)?;
+            <MarketOracles<T>>::remove(market_id);
             <pallet_pm_market_commons::Pallet<T>>::remove_market(market_id)?;
             MarketIdsForEdit::<T>::remove(market_id);
             Self::deposit_event(Event::MarketExpired(*market_id));
@@ -3156,7 +4102,7 @@ mod pallet {
         ) -> Result<OutcomeReport, DispatchError> {
             let report = market.report.as_ref().ok_or(Error::<T>::MarketIsNotReported)?;
             // the oracle bond gets returned if the reporter was the oracle
-            if report.by == market.oracle {
+            if Self::is_reported_by_oracle(market_id, market, report) {
                 Self::unreserve_oracle_bond(market_id)?;
             } else {
                 // reward outsider reporter with oracle bond
@@ -3265,7 +4211,7 @@ mod pallet {
         ) -> Result<NegativeImbalanceOf<T>, DispatchError> {
             let mut overall_imbalance = NegativeImbalanceOf::<T>::zero();
 
-            let report_by_oracle = report.by == market.oracle;
+            let report_by_oracle = Self::is_reported_by_oracle(market_id, market, report);
             let is_correct = &report.outcome == resolved_outcome;
 
             let unreserve_outsider = || -> DispatchResult {
@@ -3349,6 +4295,12 @@ mod pallet {
                 m.resolved_outcome = Some(resolved_outcome.clone());
                 Ok(())
             })?;
+            // The oracles of a multi-oracle market which didn't report yet may still do so until
+            // the end of the oracle duration.
+            if market.bonds.oracles.iter().all(|bond| bond.is_settled) {
+                <MarketOracles<T>>::remove(market_id);
+            }
+            <OracleReports<T>>::remove(market_id);
 
             Self::deposit_event(Event::MarketResolved(
                 *market_id,
@@ -3498,6 +4450,12 @@ mod pallet {
             Self::ensure_market_period_is_valid(&period)?;
             Self::ensure_market_deadlines_are_valid(&deadlines, dispute_mechanism.is_none())?;
             Self::ensure_market_type_is_valid(&market_type)?;
+            if scoring_rule == ScoringRule::Parimutuel {
+                ensure!(
+                    matches!(market_type, MarketType::Categorical(_)),
+                    Error::<T>::InvalidMarketType
+                );
+            }
 
             let status: MarketStatus = match creation {
                 MarketCreation::Permissionless => MarketStatus::Active,
@@ -3639,6 +4597,24 @@ mod pallet {
         }
     }
 
+    /// Returns the fractions of a unit of collateral which the `Long` and `Short` positions of a
+    /// scalar market with the range `low..=high` pay out if the market resolves to `final_value`.
+    fn calculate_scalar_payouts(final_value: u128, low: u128, high: u128) -> (Perbill, Perbill) {
+        if final_value <= low {
+            return (Perbill::zero(), Perbill::one());
+        }
+        if final_value >= high {
+            return (Perbill::one(), Perbill::zero());
+        }
+
+        let payout_long: Perbill =
+            Perbill::from_rational(final_value.saturating_sub(low), high.saturating_sub(low));
+        let payout_short: Perbill = Perbill::from_parts(
+            Perbill::one().deconstruct().saturating_sub(payout_long.deconstruct()),
+        );
+        (payout_long, payout_short)
+    }
+
     fn remove_item<I: cmp::PartialEq, G>(items: &mut BoundedVec<I, G>, item: &I) {
         if let Some(pos) = items.iter().position(|i| i == item) {
             items.swap_remove(pos);
@@ -3804,6 +4780,7 @@ mod pallet {
         T: Config,
     {
         type AccountId = T::AccountId;
+        type Asset = AssetOf<T>;
         type Balance = BalanceOf<T>;
         type MarketId = MarketIdOf<T>;
 
@@ -3822,6 +4799,43 @@ mod pallet {
         ) -> DispatchResult {
             Self::do_sell_complete_set(who, market_id, amount)
         }
+
+        fn split_position(
+            who: Self::AccountId,
+            parent_collection_id: Option<CombinatorialId>,
+            market_id: Self::MarketId,
+            partition: Vec<IndexSet>,
+            amount: Self::Balance,
+        ) -> DispatchResult {
+            Self::do_split_position(who, parent_collection_id, market_id, partition, amount)
+        }
+
+        fn merge_position(
+            who: Self::AccountId,
+            parent_collection_id: Option<CombinatorialId>,
+            market_id: Self::MarketId,
+            partition: Vec<IndexSet>,
+            amount: Self::Balance,
+        ) -> DispatchResult {
+            Self::do_merge_position(who, parent_collection_id, market_id, partition, amount)
+        }
+
+        fn position_asset(
+            parent_collection_id: Option<CombinatorialId>,
+            market_id: Self::MarketId,
+            index_set: IndexSet,
+        ) -> Result<Self::Asset, DispatchError> {
+            let market = <pallet_pm_market_commons::Pallet<T>>::market(&market_id)?;
+            let conditions = Self::collection_conditions(parent_collection_id)?;
+            Self::ensure_market_is_conditionable(&conditions, &market)?;
+            let full_index_set = Self::full_index_set(&market)?;
+            ensure!(
+                index_set != 0 && index_set & !full_index_set == 0,
+                Error::<T>::InvalidPartition
+            );
+            let (collection_id, _) = Self::add_condition(&conditions, market_id, index_set)?;
+            Ok(Asset::CombinatorialOutcome(collection_id))
+        }
     }
 
     impl<T: Config> BridgeInterfaceNotification for Pallet<T> {
@@ -3859,6 +4873,78 @@ mod pallet {
         }
     }
 
+    /// Pays the market's `creator_fee`, capped at `MaxCreatorFee`, to the market creator. Calling
+    /// `distribute` is noop if the market doesn't exist or the transfer fails for any reason.
+    pub struct MarketCreatorFee<T>(PhantomData<T>);
+
+    impl<T: Config> MarketCreatorFee<T> {
+        fn fee_rate(market_id: &MarketIdOf<T>) -> Result<(T::AccountId, Perbill), DispatchError> {
+            let market = <pallet_pm_market_commons::Pallet<T>>::market(market_id)?;
+            Ok((market.creator, market.creator_fee.min(T::MaxCreatorFee::get())))
+        }
+
+        fn do_distribute(
+            market_id: MarketIdOf<T>,
+            asset: AssetOf<T>,
+            account: &T::AccountId,
+            trader: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let (creator, fee_rate) = Self::fee_rate(&market_id)?;
+            let fee_amount = fee_rate.mul_floor(amount);
+            if fee_amount.is_zero() {
+                return Ok(fee_amount);
+            }
+            T::AssetManager::transfer(asset, account, &creator, fee_amount)?;
+            Pallet::<T>::deposit_event(Event::CreatorFeePaid {
+                market_id,
+                creator,
+                trader: trader.clone(),
+                asset,
+                amount: fee_amount,
+            });
+            Ok(fee_amount)
+        }
+    }
+
+    impl<T: Config> DistributeFees for MarketCreatorFee<T> {
+        type Asset = AssetOf<T>;
+        type AccountId = T::AccountId;
+        type Balance = BalanceOf<T>;
+        type MarketId = MarketIdOf<T>;
+
+        fn distribute(
+            market_id: Self::MarketId,
+            asset: Self::Asset,
+            account: &Self::AccountId,
+            amount: Self::Balance,
+        ) -> Self::Balance {
+            Self::do_distribute(market_id, asset, account, account, amount)
+                .unwrap_or_else(|_| Zero::zero())
+        }
+
+        fn distribute_on_behalf_of(
+            market_id: Self::MarketId,
+            asset: Self::Asset,
+            account: &Self::AccountId,
+            trader: &Self::AccountId,
+            amount: Self::Balance,
+        ) -> Self::Balance {
+            Self::do_distribute(market_id, asset, account, trader, amount)
+                .unwrap_or_else(|_| Zero::zero())
+        }
+
+        fn fee_amount(
+            market_id: Self::MarketId,
+            _trader: &Self::AccountId,
+            amount: Self::Balance,
+        ) -> Self::Balance {
+            Self::fee_rate(&market_id)
+                .map(|(_, fee_rate)| fee_rate.mul_floor(amount))
+                .unwrap_or_else(|_| Zero::zero())
+        }
+    }
+
     enum AssetFlowDirection {
         Incoming,
         Outgoing,
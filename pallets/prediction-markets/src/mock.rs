@@ -31,10 +31,11 @@ use common_primitives::{
 use core::marker::PhantomData;
 use frame_support::{
     construct_runtime, ord_parameter_types, parameter_types,
-    traits::{Everything, NeverEnsureOrigin, OnFinalize, OnInitialize},
+    traits::{Everything, NeverEnsureOrigin, OnFinalize, OnInitialize, Randomness},
 };
 use frame_system::{mocking::MockBlockU32, EnsureRoot, EnsureSignedBy};
 use orml_traits::{asset_registry::AssetProcessor, MultiCurrency};
+use pallet_pm_global_disputes::types::VotingMode;
 use parity_scale_codec::{alloc::sync::Arc, Encode};
 pub use prediction_market_primitives::test_helper::get_account;
 use prediction_market_primitives::{
@@ -42,21 +43,22 @@ use prediction_market_primitives::{
         AddOutcomePeriod, AggregationPeriod, AppealBond, AppealPeriod, AuthorizedPalletId,
         BlockHashCount, BlocksPerYear, CloseEarlyBlockPeriod, CloseEarlyDisputeBond,
         CloseEarlyProtectionBlockPeriod, CloseEarlyProtectionTimeFramePeriod,
-        CloseEarlyRequestBond, CloseEarlyTimeFramePeriod, CorrectionPeriod, CourtPalletId,
-        ExistentialDeposit, ExistentialDeposits, GdVotingPeriod, GetNativeCurrencyId,
-        GlobalDisputeLockId, GlobalDisputesPalletId, InflationPeriod, LockId, MaxAppeals,
-        MaxApprovals, MaxCategories, MaxCourtParticipants, MaxCreatorFee, MaxDelegations,
-        MaxDisputeDuration, MaxDisputes, MaxEditReasonLen, MaxGlobalDisputeVotes, MaxGracePeriod,
-        MaxLocks, MaxMarketLifetime, MaxOracleDuration, MaxOwners, MaxRejectReasonLen, MaxReserves,
-        MaxSelectedDraws, MaxYearlyInflation, MinCategories, MinDisputeDuration, MinJurorStake,
-        MinOracleDuration, MinOutcomeVoteAmount, MinimumPeriod, OutsiderBond, PmPalletId,
-        RemoveKeysLimit, RequestInterval, TreasuryPalletId, VotePeriod, VotingOutcomeFee, BASE,
-        CENT_BASE,
+        CloseEarlyRequestBond, CloseEarlyTimeFramePeriod, ConvictionLockPeriod, CorrectionPeriod,
+        CourtPalletId, ExistentialDeposit, ExistentialDeposits, GdVotingPeriod,
+        GetNativeCurrencyId, GlobalDisputeLockId, GlobalDisputesPalletId, InflationPeriod, LockId,
+        MaxAppeals, MaxApprovals, MaxCategories, MaxCourtParticipants, MaxCreatorFee,
+        MaxDelegations, MaxDisputeDuration, MaxDisputes, MaxEditReasonLen, MaxGdDelegators,
+        MaxGlobalDisputeVotes, MaxGracePeriod, MaxLocks, MaxMarketLifetime, MaxOracleDuration,
+        MaxOwners, MaxPanelMembers, MaxRejectReasonLen, MaxReserves, MaxSelectedDraws,
+        MaxSplitDepth, MaxYearlyInflation, MinCategories, MinDisputeDuration, MinJurorStake,
+        MinOracleDuration, MinOutcomeVoteAmount, MinimumPeriod, OutsiderBond, PanelReportPeriod,
+        PmPalletId, RemoveKeysLimit, ReputationInfluence, RequestInterval, TreasuryPalletId,
+        VotePeriod, VotingOutcomeFee, BASE, CENT_BASE,
     },
     traits::{DeployPoolApi, DistributeFees},
     types::{
-        Asset, BasicCurrencyAdapter, BlockTest, CurrencyId, CustomMetadata, MarketId, OrmlAmount,
-        SignatureTest, TestAccountIdPK,
+        Asset, BasicCurrencyAdapter, BlockTest, CurrencyId, CustomMetadata, Market, MarketId,
+        MarketStatus, OrmlAmount, OutcomeReport, SignatureTest, TestAccountIdPK,
     },
 };
 use sp_arithmetic::{per_things::Percent, Perbill};
@@ -201,6 +203,7 @@ parameter_types! {
     pub const AdvisoryBond: Balance = 11 * CENT_BASE;
     pub const AdvisoryBondSlashPercentage: Percent = Percent::from_percent(10);
     pub const OracleBond: Balance = 25 * CENT_BASE;
+    pub const MultiOracleBond: Balance = 7 * CENT_BASE;
     pub const ValidityBond: Balance = 53 * CENT_BASE;
     pub const DisputeBond: Balance = 109 * CENT_BASE;
     pub const WinnerFeePercentage: Perbill = Perbill::from_percent(5);
@@ -282,6 +285,14 @@ where
             Err(_) => Zero::zero(),
         }
     }
+
+    fn fee_amount(
+        _market_id: Self::MarketId,
+        _trader: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Self::Balance {
+        calculate_fee::<T>(amount)
+    }
 }
 
 impl crate::Config for Runtime {
@@ -314,7 +325,9 @@ impl crate::Config for Runtime {
     type MinCategories = MinCategories;
     type MaxEditReasonLen = MaxEditReasonLen;
     type MaxRejectReasonLen = MaxRejectReasonLen;
+    type MaxSplitDepth = MaxSplitDepth;
     type OracleBond = OracleBond;
+    type MultiOracleBond = MultiOracleBond;
     type OutsiderBond = OutsiderBond;
     type PalletId = PmPalletId;
     type CloseEarlyBlockPeriod = CloseEarlyBlockPeriod;
@@ -423,6 +436,18 @@ impl pallet_balances::Config for Runtime {
 
 impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
 
+// The court only draws jurors from randomness which became known after the court round was
+// opened. The collective flip pallet reports its randomness as known since 81 blocks ago, so the
+// randomness is reported as known since the current block for the tests instead.
+pub struct CourtRandomness;
+
+impl Randomness<Hash, BlockNumber> for CourtRandomness {
+    fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+        let (random, _) = RandomnessCollectiveFlip::random(subject);
+        (random, System::block_number())
+    }
+}
+
 impl pallet_timestamp::Config for Runtime {
     type MinimumPeriod = MinimumPeriod;
     type Moment = Moment;
@@ -442,7 +467,9 @@ impl pallet_pm_authorized::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DisputeResolution = prediction_markets::Pallet<Runtime>;
     type MarketCommons = MarketCommons;
+    type MaxPanelMembers = MaxPanelMembers;
     type PalletId = AuthorizedPalletId;
+    type PanelReportPeriod = PanelReportPeriod;
     type WeightInfo = pallet_pm_authorized::weights::WeightInfo<Runtime>;
 }
 
@@ -466,8 +493,12 @@ impl pallet_pm_court::Config for Runtime {
     type MinJurorStake = MinJurorStake;
     type MonetaryGovernanceOrigin = EnsureRoot<TestAccountIdPK>;
     type PalletId = CourtPalletId;
-    type Random = RandomnessCollectiveFlip;
+    type Public = TestAccountIdPK;
+    type Random = CourtRandomness;
+    type ReputationInfluence = ReputationInfluence;
     type RequestInterval = RequestInterval;
+    type RuntimeCall = RuntimeCall;
+    type Signature = SignatureTest;
     type Slash = Treasury;
     type TreasuryPalletId = TreasuryPalletId;
     type WeightInfo = pallet_pm_court::weights::WeightInfo<Runtime>;
@@ -479,20 +510,27 @@ impl pallet_pm_market_commons::Config for Runtime {
     type Timestamp = Timestamp;
 }
 
+parameter_types! {
+    pub const GdVotingMode: VotingMode = VotingMode::Linear;
+}
+
 impl pallet_pm_global_disputes::Config for Runtime {
     type AddOutcomePeriod = AddOutcomePeriod;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
     type RuntimeEvent = RuntimeEvent;
     type DisputeResolution = prediction_markets::Pallet<Runtime>;
     type MarketCommons = MarketCommons;
     type Currency = Balances;
     type GlobalDisputeLockId = GlobalDisputeLockId;
     type GlobalDisputesPalletId = GlobalDisputesPalletId;
+    type MaxDelegators = MaxGdDelegators;
     type MaxGlobalDisputeVotes = MaxGlobalDisputeVotes;
     type MaxOwners = MaxOwners;
     type MinOutcomeVoteAmount = MinOutcomeVoteAmount;
     type RemoveKeysLimit = RemoveKeysLimit;
     type GdVotingPeriod = GdVotingPeriod;
     type VotingOutcomeFee = VotingOutcomeFee;
+    type VotingMode = GdVotingMode;
     type WeightInfo = pallet_pm_global_disputes::weights::WeightInfo<Runtime>;
 }
 
@@ -637,9 +675,35 @@ pub fn set_timestamp_for_on_initialize(time: Moment) {
 type Block = MockBlockU32<Runtime>;
 
 sp_api::mock_impl_runtime_apis! {
-    impl pallet_prediction_markets_runtime_api::PredictionMarketsApi<BlockTest<Runtime>, MarketId, Hash> for Runtime {
-        fn market_outcome_share_id(_: MarketId, _: u16) -> Asset<MarketId> {
-            Asset::PoolShare(1)
+    impl pallet_prediction_markets_runtime_api::PredictionMarketsApi<
+        BlockTest<Runtime>,
+        TestAccountIdPK,
+        Balance,
+        BlockNumber,
+        MarketId,
+        Moment,
+        Hash,
+    > for Runtime {
+        fn market_outcome_share_id(_: MarketId, _: u16) -> Option<Asset<MarketId>> {
+            None
+        }
+
+        fn market(
+            _: MarketId,
+        ) -> Option<Market<TestAccountIdPK, Balance, BlockNumber, Moment, MarketId>> {
+            None
+        }
+
+        fn market_outcome_assets(_: MarketId) -> Vec<Asset<MarketId>> {
+            Vec::new()
+        }
+
+        fn market_status(_: MarketId) -> Option<MarketStatus> {
+            None
+        }
+
+        fn market_resolved_outcome(_: MarketId) -> Option<OutcomeReport> {
+            None
         }
     }
 }
@@ -389,6 +389,44 @@ fn it_does_not_create_market_with_too_many_categories() {
     });
 }
 
+#[test]
+fn create_market_accepts_categorical_parimutuel_markets() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..100,
+            ScoringRule::Parimutuel,
+        );
+        let market = MarketCommons::market(&0).unwrap();
+        assert_eq!(market.scoring_rule, ScoringRule::Parimutuel);
+        assert_eq!(market.status, MarketStatus::Active);
+    });
+}
+
+#[test]
+fn create_market_fails_on_scalar_parimutuel_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        WhitelistedMarketCreators::<Runtime>::insert(&alice(), ());
+        assert_noop!(
+            PredictionMarkets::create_market(
+                RuntimeOrigin::signed(alice()),
+                Asset::Tru,
+                Perbill::zero(),
+                bob(),
+                MarketPeriod::Block(0..100),
+                get_deadlines(),
+                gen_metadata(2),
+                MarketCreation::Permissionless,
+                MarketType::Scalar(0..=100),
+                Some(MarketDisputeMechanism::Court),
+                ScoringRule::Parimutuel,
+            ),
+            Error::<Runtime>::InvalidMarketType
+        );
+    });
+}
+
 #[test_case(MarketPeriod::Block(3..3); "empty range blocks")]
 #[test_case(MarketPeriod::Timestamp(3..3); "empty range timestamp")]
 #[test_case(
@@ -594,6 +632,7 @@ fn create_market_fails_if_market_duration_is_too_long_in_moments() {
         dispute: None,
         close_dispute: None,
         close_request: None,
+        oracles: Default::default(),
     }
 )]
 #[test_case(
@@ -607,6 +646,7 @@ fn create_market_fails_if_market_duration_is_too_long_in_moments() {
         dispute: None,
         close_dispute: None,
         close_request: None,
+        oracles: Default::default(),
     }
 )]
 fn create_market_sets_the_correct_market_parameters_and_reserves_the_correct_amount(
@@ -67,6 +67,7 @@ fn create_market_and_deploy_pool_works() {
             dispute: None,
             close_dispute: None,
             close_request: None,
+            oracles: Default::default(),
         };
         assert_eq!(market.creator, creator);
         assert_eq!(market.creation, MarketCreation::Permissionless);
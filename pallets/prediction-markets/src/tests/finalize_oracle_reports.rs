@@ -0,0 +1,135 @@
+// Copyright 2022-2024 Forecasting Technologies LTD.
+// Copyright 2021-2022 Zeitgeist PM LLC.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{MarketOracles, OracleReports};
+
+// Creates a market with three oracles of equal weight which all need to report and moves to the
+// first block of the oracle duration.
+fn create_multi_oracle_market() -> MarketId {
+    let end = 2;
+    simple_create_categorical_market(
+        Asset::Tru,
+        MarketCreation::Permissionless,
+        0..end,
+        ScoringRule::AmmCdaHybrid,
+    );
+    set_market_oracles(0, &[(charlie(), 1), (dave(), 1), (eve(), 1)], 3);
+    run_to_block(end + get_deadlines().grace_period + 1);
+    0
+}
+
+fn run_past_oracle_duration() {
+    let market = MarketCommons::market(&0).unwrap();
+    let MarketPeriod::Block(ref range) = market.period else { panic!("unexpected period") };
+    run_to_block(range.end + market.deadlines.grace_period + market.deadlines.oracle_duration + 1);
+}
+
+#[test]
+fn finalize_oracle_reports_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_multi_oracle_market();
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(charlie()),
+            market_id,
+            OutcomeReport::Categorical(1)
+        ));
+        assert_eq!(MarketCommons::market(&market_id).unwrap().status, MarketStatus::Closed);
+        run_past_oracle_duration();
+
+        let free_alice = Balances::free_balance(alice());
+        let reserved_alice = Balances::reserved_balance(alice());
+        assert_ok!(PredictionMarkets::finalize_oracle_reports(
+            RuntimeOrigin::signed(fred()),
+            market_id
+        ));
+
+        let market = MarketCommons::market(&market_id).unwrap();
+        assert_eq!(market.status, MarketStatus::Reported);
+        let report = market.report.unwrap();
+        assert_eq!(report.by, charlie());
+        assert_eq!(report.outcome, OutcomeReport::Categorical(1));
+        assert!(market.bonds.oracles.iter().all(|bond| bond.is_settled));
+        // The bond of charlie was returned when he reported, the bonds of dave and eve are
+        // slashed.
+        let multi_oracle_bond = <Runtime as Config>::MultiOracleBond::get();
+        assert_eq!(Balances::free_balance(alice()), free_alice);
+        assert_eq!(Balances::reserved_balance(alice()), reserved_alice - 2 * multi_oracle_bond);
+
+        // The aggregated report counts as a report of the oracle.
+        let reserved_alice = Balances::reserved_balance(alice());
+        run_to_block(report.at + market.deadlines.dispute_duration);
+        let market = MarketCommons::market(&market_id).unwrap();
+        assert_eq!(market.status, MarketStatus::Resolved);
+        assert_eq!(
+            Balances::reserved_balance(alice()),
+            reserved_alice -
+                <Runtime as Config>::OracleBond::get() -
+                <Runtime as Config>::ValidityBond::get()
+        );
+        assert!(MarketOracles::<Runtime>::get(market_id).is_none());
+        assert!(OracleReports::<Runtime>::get(market_id).is_empty());
+    });
+}
+
+#[test]
+fn finalize_oracle_reports_fails_during_oracle_duration() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_multi_oracle_market();
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(charlie()),
+            market_id,
+            OutcomeReport::Categorical(1)
+        ));
+        assert_noop!(
+            PredictionMarkets::finalize_oracle_reports(RuntimeOrigin::signed(fred()), market_id),
+            Error::<Runtime>::OracleReportingWindowNotExpired
+        );
+    });
+}
+
+#[test]
+fn finalize_oracle_reports_fails_without_reports() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_multi_oracle_market();
+        run_past_oracle_duration();
+        assert_noop!(
+            PredictionMarkets::finalize_oracle_reports(RuntimeOrigin::signed(fred()), market_id),
+            Error::<Runtime>::NoOracleReports
+        );
+    });
+}
+
+#[test]
+fn finalize_oracle_reports_fails_without_oracle_set() {
+    ExtBuilder::default().build().execute_with(|| {
+        let end = 2;
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..end,
+            ScoringRule::AmmCdaHybrid,
+        );
+        run_past_oracle_duration();
+        assert_noop!(
+            PredictionMarkets::finalize_oracle_reports(RuntimeOrigin::signed(fred()), 0),
+            Error::<Runtime>::MarketHasNoOracleSet
+        );
+    });
+}
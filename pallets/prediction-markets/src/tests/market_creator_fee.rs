@@ -0,0 +1,122 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::MarketCreatorFee;
+use prediction_market_primitives::traits::DistributeFees;
+use test_case::test_case;
+
+fn create_market_with_creator_fee(creator_fee: Perbill) -> MarketId {
+    simple_create_categorical_market(
+        Asset::Tru,
+        MarketCreation::Permissionless,
+        0..2,
+        ScoringRule::AmmCdaHybrid,
+    );
+    let market_id = 0;
+    assert_ok!(MarketCommons::mutate_market(&market_id, |market| {
+        market.creator_fee = creator_fee;
+        Ok(())
+    }));
+    market_id
+}
+
+#[test]
+fn distribute_pays_creator_fee_to_market_creator() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_creator_fee(Perbill::from_percent(1));
+        let creator_balance = AssetManager::free_balance(Asset::Tru, &alice());
+        let fee =
+            MarketCreatorFee::<Runtime>::distribute(market_id, Asset::Tru, &charlie(), 100 * BASE);
+        assert_eq!(fee, BASE);
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &alice()), creator_balance + BASE);
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &charlie()), INITIAL_BALANCE - BASE);
+        System::assert_last_event(
+            Event::CreatorFeePaid {
+                market_id,
+                creator: alice(),
+                trader: charlie(),
+                asset: Asset::Tru,
+                amount: BASE,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn distribute_on_behalf_of_reports_trader() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_creator_fee(Perbill::from_percent(1));
+        let fee = MarketCreatorFee::<Runtime>::distribute_on_behalf_of(
+            market_id,
+            Asset::Tru,
+            &charlie(),
+            &eve(),
+            100 * BASE,
+        );
+        assert_eq!(fee, BASE);
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &charlie()), INITIAL_BALANCE - BASE);
+        System::assert_last_event(
+            Event::CreatorFeePaid {
+                market_id,
+                creator: alice(),
+                trader: eve(),
+                asset: Asset::Tru,
+                amount: BASE,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test_case(Perbill::from_percent(1), BASE; "below_max")]
+#[test_case(Perbill::from_percent(5), BASE; "capped_at_max")]
+#[test_case(Perbill::zero(), 0; "zero")]
+fn fee_amount_is_capped_at_max_creator_fee(creator_fee: Perbill, expected: u128) {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(<Runtime as Config>::MaxCreatorFee::get(), Perbill::from_percent(1));
+        let market_id = create_market_with_creator_fee(creator_fee);
+        assert_eq!(
+            MarketCreatorFee::<Runtime>::fee_amount(market_id, &eve(), 100 * BASE),
+            expected
+        );
+    });
+}
+
+#[test]
+fn distribute_is_noop_without_creator_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_creator_fee(Perbill::zero());
+        let event_count = System::events().len();
+        let fee =
+            MarketCreatorFee::<Runtime>::distribute(market_id, Asset::Tru, &charlie(), 100 * BASE);
+        assert_eq!(fee, 0);
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &charlie()), INITIAL_BALANCE);
+        assert_eq!(System::events().len(), event_count);
+    });
+}
+
+#[test]
+fn distribute_is_noop_on_unknown_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        let fee = MarketCreatorFee::<Runtime>::distribute(0, Asset::Tru, &charlie(), 100 * BASE);
+        assert_eq!(fee, 0);
+        assert_eq!(MarketCreatorFee::<Runtime>::fee_amount(0, &charlie(), 100 * BASE), 0);
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &charlie()), INITIAL_BALANCE);
+    });
+}
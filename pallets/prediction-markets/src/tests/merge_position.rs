@@ -0,0 +1,224 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[test]
+fn merge_position_into_collateral_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        let market_id = 0;
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(charlie()),
+            None,
+            market_id,
+            vec![0b01, 0b10],
+            2 * BASE,
+        ));
+        assert_ok!(PredictionMarkets::merge_position(
+            RuntimeOrigin::signed(charlie()),
+            None,
+            market_id,
+            vec![0b01, 0b10],
+            BASE,
+        ));
+
+        for index_set in [0b01, 0b10] {
+            let asset = Asset::CombinatorialOutcome(collection_id(None, market_id, index_set));
+            assert_eq!(AssetManager::free_balance(asset, &charlie()), BASE);
+        }
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &charlie()), INITIAL_BALANCE - BASE);
+        assert_eq!(
+            AssetManager::free_balance(Asset::Tru, &PredictionMarkets::combinatorial_account()),
+            BASE
+        );
+        System::assert_last_event(
+            Event::PositionsMerged {
+                who: charlie(),
+                parent_collection_id: None,
+                market_id,
+                partition: vec![0b01, 0b10],
+                amount: BASE,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn merge_position_into_parent_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        for _ in 0..2 {
+            simple_create_categorical_market(
+                Asset::Tru,
+                MarketCreation::Permissionless,
+                0..2,
+                ScoringRule::AmmCdaHybrid,
+            );
+        }
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(charlie()),
+            None,
+            0,
+            vec![0b01, 0b10],
+            BASE,
+        ));
+        let parent = collection_id(None, 0, 0b01);
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(charlie()),
+            Some(parent),
+            1,
+            vec![0b01, 0b10],
+            BASE,
+        ));
+        assert_ok!(PredictionMarkets::merge_position(
+            RuntimeOrigin::signed(charlie()),
+            Some(parent),
+            1,
+            vec![0b01, 0b10],
+            BASE,
+        ));
+
+        assert_eq!(
+            AssetManager::free_balance(Asset::CombinatorialOutcome(parent), &charlie()),
+            BASE
+        );
+        for index_set in [0b01, 0b10] {
+            let asset = Asset::CombinatorialOutcome(collection_id(Some(parent), 1, index_set));
+            assert_eq!(AssetManager::free_balance(asset, &charlie()), 0);
+        }
+    });
+}
+
+#[test]
+fn merge_position_with_partial_partition_mints_union() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        let market_id = 0;
+        assert_ok!(MarketCommons::mutate_market(&market_id, |market| {
+            market.market_type = MarketType::Categorical(3);
+            Ok(())
+        }));
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(charlie()),
+            None,
+            market_id,
+            vec![0b001, 0b010, 0b100],
+            BASE,
+        ));
+        assert_ok!(PredictionMarkets::merge_position(
+            RuntimeOrigin::signed(charlie()),
+            None,
+            market_id,
+            vec![0b001, 0b010],
+            BASE,
+        ));
+
+        let union = Asset::CombinatorialOutcome(collection_id(None, market_id, 0b011));
+        assert_eq!(AssetManager::free_balance(union, &charlie()), BASE);
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &charlie()), INITIAL_BALANCE - BASE);
+    });
+}
+
+#[test]
+fn merge_position_works_on_resolved_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(charlie()),
+            None,
+            0,
+            vec![0b01, 0b10],
+            BASE,
+        ));
+        resolve_market(0, OutcomeReport::Categorical(0));
+        assert_ok!(PredictionMarkets::merge_position(
+            RuntimeOrigin::signed(charlie()),
+            None,
+            0,
+            vec![0b01, 0b10],
+            BASE,
+        ));
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &charlie()), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn merge_position_fails_on_insufficient_balance() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(charlie()),
+            None,
+            0,
+            vec![0b01, 0b10],
+            BASE,
+        ));
+        assert_noop!(
+            PredictionMarkets::merge_position(
+                RuntimeOrigin::signed(charlie()),
+                None,
+                0,
+                vec![0b01, 0b10],
+                2 * BASE,
+            ),
+            Error::<Runtime>::InsufficientShareBalance
+        );
+    });
+}
+
+#[test]
+fn merge_position_fails_on_zero_amount() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_noop!(
+            PredictionMarkets::merge_position(
+                RuntimeOrigin::signed(charlie()),
+                None,
+                0,
+                vec![0b01, 0b10],
+                0,
+            ),
+            Error::<Runtime>::ZeroAmount
+        );
+    });
+}
@@ -28,12 +28,16 @@ mod create_market_and_deploy_pool;
 mod dispute;
 mod dispute_early_close;
 mod edit_market;
+mod finalize_oracle_reports;
 mod integration;
 mod manually_close_market;
+mod market_creator_fee;
+mod merge_position;
 mod on_initialize;
 mod on_market_close;
 mod on_resolution;
 mod pallet_admin_tests;
+mod redeem_position;
 mod redeem_shares;
 mod reject_early_close;
 mod reject_market;
@@ -41,6 +45,8 @@ mod report;
 mod request_edit;
 mod schedule_early_close;
 mod sell_complete_set;
+mod set_market_oracles;
+mod split_position;
 mod start_global_dispute;
 
 use crate::{
@@ -55,8 +61,9 @@ use pallet_pm_market_commons::MarketCommonsPalletApi;
 use prediction_market_primitives::{
     constants::mock::{BASE, CENT_BASE},
     types::{
-        Asset, Deadlines, MarketCreation, MarketDisputeMechanism, MarketId, MarketPeriod,
-        MarketStatus, MarketType, MultiHash, OutcomeReport, ScoringRule,
+        Asset, CombinatorialId, Deadlines, IndexSet, MarketCreation, MarketDisputeMechanism,
+        MarketId, MarketPeriod, MarketStatus, MarketType, MultiHash, OutcomeReport, ScoringRule,
+        WeightedOracle,
     },
 };
 use sp_arithmetic::Perbill;
@@ -101,6 +108,26 @@ fn simple_create_categorical_market(
     ));
 }
 
+fn weighted_oracles(
+    oracles: &[(AccountIdOf<Runtime>, u32)],
+) -> Vec<WeightedOracle<AccountIdOf<Runtime>>> {
+    oracles
+        .iter()
+        .map(|(who, weight)| WeightedOracle { who: *who, weight: *weight })
+        .collect()
+}
+
+// Lets the market admin set the given oracles, which is possible while the market is active.
+fn set_market_oracles(market_id: MarketId, oracles: &[(AccountIdOf<Runtime>, u32)], quorum: u32) {
+    let market_admin = <crate::MarketAdmin<Runtime>>::get().unwrap();
+    assert_ok!(PredictionMarkets::set_market_oracles(
+        RuntimeOrigin::signed(market_admin),
+        market_id,
+        weighted_oracles(oracles),
+        quorum
+    ));
+}
+
 fn simple_create_scalar_market(
     base_asset: AssetOf<Runtime>,
     creation: MarketCreation,
@@ -123,6 +150,25 @@ fn simple_create_scalar_market(
     ));
 }
 
+// Returns the ID of the collection obtained by conditioning `parent_collection_id` on
+// `market_id` resolving to an outcome in `index_set`.
+fn collection_id(
+    parent_collection_id: Option<CombinatorialId>,
+    market_id: MarketId,
+    index_set: IndexSet,
+) -> CombinatorialId {
+    let conditions = PredictionMarkets::collection_conditions(parent_collection_id).unwrap();
+    PredictionMarkets::add_condition(&conditions, market_id, index_set).unwrap().0
+}
+
+fn resolve_market(market_id: MarketId, outcome: OutcomeReport) {
+    assert_ok!(MarketCommons::mutate_market(&market_id, |market| {
+        market.status = MarketStatus::Resolved;
+        market.resolved_outcome = Some(outcome);
+        Ok(())
+    }));
+}
+
 fn check_reserve(account: &AccountIdOf<Runtime>, expected: BalanceOf<Runtime>) {
     assert_eq!(Balances::reserved_balance(account), SENTINEL_AMOUNT + expected);
 }
@@ -152,6 +198,10 @@ fn reserve_sentinel_amounts() {
 }
 
 fn simulate_appeal_cycle(market_id: MarketId) {
+    // the participants are drawn from the randomness of a block after the dispute or appeal
+    run_blocks(1);
+    assert_ok!(Court::draw_participants(RuntimeOrigin::signed(charlie()), market_id));
+
     let court = pallet_pm_court::Courts::<Runtime>::get(market_id).unwrap();
     let vote_start = court.round_ends.pre_vote + 1;
 
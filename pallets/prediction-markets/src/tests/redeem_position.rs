@@ -0,0 +1,198 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+fn split_nested_positions(amount: BalanceOf<Runtime>) -> CombinatorialId {
+    for _ in 0..2 {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+    }
+    assert_ok!(PredictionMarkets::split_position(
+        RuntimeOrigin::signed(charlie()),
+        None,
+        0,
+        vec![0b01, 0b10],
+        amount,
+    ));
+    let parent = collection_id(None, 0, 0b01);
+    assert_ok!(PredictionMarkets::split_position(
+        RuntimeOrigin::signed(charlie()),
+        Some(parent),
+        1,
+        vec![0b01, 0b10],
+        amount,
+    ));
+    collection_id(Some(parent), 1, 0b10)
+}
+
+#[test]
+fn redeem_position_pays_out_winning_collection() {
+    ExtBuilder::default().build().execute_with(|| {
+        let amount = 2 * BASE;
+        let collection_id = split_nested_positions(amount);
+        resolve_market(0, OutcomeReport::Categorical(0));
+        resolve_market(1, OutcomeReport::Categorical(1));
+
+        assert_ok!(PredictionMarkets::redeem_position(
+            RuntimeOrigin::signed(charlie()),
+            collection_id
+        ));
+
+        let fee = <Runtime as Config>::WinnerFeePercentage::get() * amount;
+        let payout = amount - fee;
+        assert_eq!(
+            AssetManager::free_balance(Asset::CombinatorialOutcome(collection_id), &charlie()),
+            0
+        );
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &charlie()), INITIAL_BALANCE - fee);
+        assert_eq!(
+            AssetManager::free_balance(Asset::Tru, &PredictionMarkets::combinatorial_account()),
+            0
+        );
+        System::assert_last_event(
+            Event::PositionRedeemed {
+                who: charlie(),
+                collection_id,
+                amount_redeemed: amount,
+                payout,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn redeem_position_pays_nothing_for_losing_collection() {
+    ExtBuilder::default().build().execute_with(|| {
+        let amount = 2 * BASE;
+        let collection_id = split_nested_positions(amount);
+        resolve_market(0, OutcomeReport::Categorical(0));
+        resolve_market(1, OutcomeReport::Categorical(0));
+
+        assert_ok!(PredictionMarkets::redeem_position(
+            RuntimeOrigin::signed(charlie()),
+            collection_id
+        ));
+
+        assert_eq!(
+            AssetManager::free_balance(Asset::CombinatorialOutcome(collection_id), &charlie()),
+            0
+        );
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &charlie()), INITIAL_BALANCE - amount);
+        System::assert_last_event(
+            Event::PositionRedeemed {
+                who: charlie(),
+                collection_id,
+                amount_redeemed: amount,
+                payout: 0,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn redeem_position_pays_out_scalar_fraction() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_scalar_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        let amount = 10 * BASE;
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(charlie()),
+            None,
+            0,
+            vec![0b01, 0b10],
+            amount,
+        ));
+        // The scalar range is `100..=200`, so long positions are worth 25% of their collateral.
+        resolve_market(0, OutcomeReport::Scalar(125));
+
+        let long_id = collection_id(None, 0, 0b01);
+        assert_ok!(PredictionMarkets::redeem_position(RuntimeOrigin::signed(charlie()), long_id));
+
+        let payout = Perbill::from_percent(25) * amount;
+        let fee = <Runtime as Config>::WinnerFeePercentage::get() * payout;
+        assert_eq!(
+            AssetManager::free_balance(Asset::Tru, &charlie()),
+            INITIAL_BALANCE - amount + payout - fee
+        );
+    });
+}
+
+#[test]
+fn redeem_position_exempts_liquidity_providers_from_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let amount = 2 * BASE;
+        let collection_id = split_nested_positions(amount);
+        resolve_market(0, OutcomeReport::Categorical(0));
+        resolve_market(1, OutcomeReport::Categorical(1));
+        crate::LiquidityProviders::<Runtime>::insert(1, charlie(), ());
+
+        assert_ok!(PredictionMarkets::redeem_position(
+            RuntimeOrigin::signed(charlie()),
+            collection_id
+        ));
+
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &charlie()), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn redeem_position_fails_if_market_is_not_resolved() {
+    ExtBuilder::default().build().execute_with(|| {
+        let collection_id = split_nested_positions(BASE);
+        resolve_market(0, OutcomeReport::Categorical(0));
+
+        assert_noop!(
+            PredictionMarkets::redeem_position(RuntimeOrigin::signed(charlie()), collection_id),
+            Error::<Runtime>::MarketIsNotResolved
+        );
+    });
+}
+
+#[test]
+fn redeem_position_fails_on_unknown_collection() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            PredictionMarkets::redeem_position(RuntimeOrigin::signed(charlie()), [0; 32]),
+            Error::<Runtime>::CombinatorialCollectionNotFound
+        );
+    });
+}
+
+#[test]
+fn redeem_position_fails_without_balance() {
+    ExtBuilder::default().build().execute_with(|| {
+        let collection_id = split_nested_positions(BASE);
+        resolve_market(0, OutcomeReport::Categorical(0));
+        resolve_market(1, OutcomeReport::Categorical(1));
+
+        assert_noop!(
+            PredictionMarkets::redeem_position(RuntimeOrigin::signed(dave()), collection_id),
+            Error::<Runtime>::NoWinningBalance
+        );
+    });
+}
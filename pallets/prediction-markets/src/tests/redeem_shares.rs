@@ -215,3 +215,120 @@ fn scalar_market_correctly_resolves_common(base_asset: AssetOf<Runtime>, reporte
         assert_eq!(AssetManager::free_balance(*asset, &eve()), 0);
     }
 }
+
+#[test]
+fn redeem_shares_refunds_categorical_shares_pro_rata_if_market_resolved_to_invalid() {
+    let test = |base_asset: AssetOf<Runtime>| {
+        let end = 2;
+        simple_create_categorical_market(
+            base_asset,
+            MarketCreation::Permissionless,
+            0..end,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_ok!(PredictionMarkets::buy_complete_set(
+            RuntimeOrigin::signed(charlie()),
+            0,
+            100 * BASE
+        ));
+        assert_ok!(Tokens::transfer(
+            RuntimeOrigin::signed(charlie()),
+            eve(),
+            Asset::CategoricalOutcome(0, 0),
+            100 * BASE
+        ));
+        // (Eve now has 100 of outcome 0, Charlie has 100 of outcome 1)
+
+        let market = MarketCommons::market(&0).unwrap();
+        let grace_period = end + market.deadlines.grace_period;
+        run_to_block(grace_period + 1);
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(bob()),
+            0,
+            OutcomeReport::Invalid
+        ));
+        run_blocks(market.deadlines.dispute_duration);
+        let market = MarketCommons::market(&0).unwrap();
+        assert_eq!(market.status, MarketStatus::Resolved);
+        assert_eq!(market.resolved_outcome, Some(OutcomeReport::Invalid));
+
+        assert_ok!(PredictionMarkets::redeem_shares(RuntimeOrigin::signed(charlie()), 0));
+        System::assert_last_event(
+            Event::TokensRedeemed(
+                0,
+                Asset::CategoricalOutcome(0, 1),
+                100 * BASE,
+                50 * BASE,
+                charlie(),
+            )
+            .into(),
+        );
+        assert_ok!(PredictionMarkets::redeem_shares(RuntimeOrigin::signed(eve()), 0));
+
+        // No winner fee is charged on the refunds.
+        assert_eq!(AssetManager::free_balance(base_asset, &charlie()), 950 * BASE);
+        assert_eq!(AssetManager::free_balance(base_asset, &eve()), 1050 * BASE);
+        for asset in market.outcome_assets() {
+            assert_eq!(AssetManager::free_balance(asset, &charlie()), 0);
+            assert_eq!(AssetManager::free_balance(asset, &eve()), 0);
+        }
+    };
+    ExtBuilder::default().build().execute_with(|| {
+        test(Asset::Tru);
+    });
+    #[cfg(feature = "parachain")]
+    ExtBuilder::default().build().execute_with(|| {
+        test(Asset::ForeignAsset(100));
+    });
+}
+
+#[test]
+fn redeem_shares_refunds_complete_set_if_market_resolved_to_invalid() {
+    ExtBuilder::default().build().execute_with(|| {
+        let end = 100;
+        simple_create_scalar_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..end,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_ok!(PredictionMarkets::buy_complete_set(
+            RuntimeOrigin::signed(charlie()),
+            0,
+            100 * BASE
+        ));
+
+        let market = MarketCommons::market(&0).unwrap();
+        let grace_period = end + market.deadlines.grace_period;
+        run_to_block(grace_period + 1);
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(bob()),
+            0,
+            OutcomeReport::Invalid
+        ));
+        run_blocks(market.deadlines.dispute_duration);
+
+        assert_ok!(PredictionMarkets::redeem_shares(RuntimeOrigin::signed(charlie()), 0));
+        System::assert_has_event(
+            Event::TokensRedeemed(
+                0,
+                Asset::ScalarOutcome(0, ScalarPosition::Long),
+                100 * BASE,
+                50 * BASE,
+                charlie(),
+            )
+            .into(),
+        );
+        System::assert_has_event(
+            Event::TokensRedeemed(
+                0,
+                Asset::ScalarOutcome(0, ScalarPosition::Short),
+                100 * BASE,
+                50 * BASE,
+                charlie(),
+            )
+            .into(),
+        );
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &charlie()), 1000 * BASE);
+    });
+}
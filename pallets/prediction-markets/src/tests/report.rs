@@ -443,3 +443,287 @@ fn report_fails_if_reporter_is_not_the_oracle() {
         );
     });
 }
+
+fn create_multi_oracle_market(
+    market_type: MarketType,
+    oracles: &[(AccountIdOf<Runtime>, u32)],
+    quorum: u32,
+) -> MarketId {
+    let end = 2;
+    match market_type {
+        MarketType::Categorical(_) => simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..end,
+            ScoringRule::AmmCdaHybrid,
+        ),
+        MarketType::Scalar(_) => simple_create_scalar_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..end,
+            ScoringRule::AmmCdaHybrid,
+        ),
+    }
+    set_market_oracles(0, oracles, quorum);
+    run_to_block(end + get_deadlines().grace_period + 1);
+    0
+}
+
+fn report_as(market_id: MarketId, who: AccountIdOf<Runtime>, outcome: OutcomeReport) {
+    assert_ok!(PredictionMarkets::report(RuntimeOrigin::signed(who), market_id, outcome));
+}
+
+#[test]
+fn multi_oracle_categorical_market_resolves_to_weighted_majority() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_multi_oracle_market(
+            MarketType::Categorical(2),
+            &[(charlie(), 1), (dave(), 1), (eve(), 3)],
+            5,
+        );
+        report_as(market_id, charlie(), OutcomeReport::Categorical(0));
+        System::assert_last_event(
+            Event::OracleReportSubmitted {
+                market_id,
+                oracle: charlie(),
+                outcome: OutcomeReport::Categorical(0),
+            }
+            .into(),
+        );
+        report_as(market_id, dave(), OutcomeReport::Categorical(0));
+        assert!(MarketCommons::market(&market_id).unwrap().report.is_none());
+
+        report_as(market_id, eve(), OutcomeReport::Categorical(1));
+        let market = MarketCommons::market(&market_id).unwrap();
+        assert_eq!(market.status, MarketStatus::Reported);
+        let report = market.report.unwrap();
+        assert_eq!(report.by, eve());
+        assert_eq!(report.outcome, OutcomeReport::Categorical(1));
+        System::assert_last_event(
+            Event::MarketReported(market_id, MarketStatus::Reported, report).into(),
+        );
+    });
+}
+
+#[test]
+fn multi_oracle_categorical_market_breaks_ties_by_first_report() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_multi_oracle_market(
+            MarketType::Categorical(2),
+            &[(charlie(), 2), (dave(), 1), (eve(), 1)],
+            4,
+        );
+        report_as(market_id, dave(), OutcomeReport::Categorical(1));
+        report_as(market_id, charlie(), OutcomeReport::Categorical(0));
+        report_as(market_id, eve(), OutcomeReport::Categorical(1));
+        let report = MarketCommons::market(&market_id).unwrap().report.unwrap();
+        assert_eq!(report.by, dave());
+        assert_eq!(report.outcome, OutcomeReport::Categorical(1));
+    });
+}
+
+#[test]
+fn multi_oracle_scalar_market_resolves_to_weighted_median() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_multi_oracle_market(
+            MarketType::Scalar(100..=200),
+            &[(charlie(), 1), (dave(), 1), (eve(), 1), (fred(), 2)],
+            5,
+        );
+        report_as(market_id, charlie(), OutcomeReport::Scalar(120));
+        report_as(market_id, dave(), OutcomeReport::Scalar(190));
+        report_as(market_id, eve(), OutcomeReport::Scalar(110));
+        report_as(market_id, fred(), OutcomeReport::Scalar(150));
+        let report = MarketCommons::market(&market_id).unwrap().report.unwrap();
+        assert_eq!(report.by, fred());
+        assert_eq!(report.outcome, OutcomeReport::Scalar(150));
+    });
+}
+
+#[test]
+fn multi_oracle_scalar_market_resolves_to_invalid_if_invalid_carries_the_weight() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_multi_oracle_market(
+            MarketType::Scalar(100..=200),
+            &[(charlie(), 1), (dave(), 1), (eve(), 1)],
+            3,
+        );
+        report_as(market_id, charlie(), OutcomeReport::Scalar(120));
+        report_as(market_id, dave(), OutcomeReport::Invalid);
+        report_as(market_id, eve(), OutcomeReport::Invalid);
+        let report = MarketCommons::market(&market_id).unwrap().report.unwrap();
+        assert_eq!(report.by, dave());
+        assert_eq!(report.outcome, OutcomeReport::Invalid);
+    });
+}
+
+#[test]
+fn multi_oracle_market_is_reported_once_quorum_is_reached() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_multi_oracle_market(
+            MarketType::Categorical(2),
+            &[(charlie(), 1), (dave(), 1), (eve(), 1)],
+            2,
+        );
+        let free_alice = Balances::free_balance(alice());
+        report_as(market_id, charlie(), OutcomeReport::Categorical(1));
+        assert_eq!(MarketCommons::market(&market_id).unwrap().status, MarketStatus::Closed);
+
+        report_as(market_id, dave(), OutcomeReport::Categorical(1));
+        let market = MarketCommons::market(&market_id).unwrap();
+        assert_eq!(market.status, MarketStatus::Reported);
+        // Only the bonds of the oracles which reported are returned.
+        let multi_oracle_bond = <Runtime as Config>::MultiOracleBond::get();
+        assert_eq!(Balances::free_balance(alice()), free_alice + 2 * multi_oracle_bond);
+        assert!(!market.bonds.oracles[2].is_settled);
+
+        // Eve may still report until the end of the oracle duration to get her bond back, but
+        // her report doesn't change the report of the market.
+        report_as(market_id, eve(), OutcomeReport::Categorical(0));
+        let market_after = MarketCommons::market(&market_id).unwrap();
+        assert_eq!(market_after.report, market.report);
+        assert_eq!(Balances::free_balance(alice()), free_alice + 3 * multi_oracle_bond);
+        assert!(market_after.bonds.oracles.iter().all(|bond| bond.is_settled));
+        assert_noop!(
+            PredictionMarkets::report(
+                RuntimeOrigin::signed(eve()),
+                market_id,
+                OutcomeReport::Categorical(0)
+            ),
+            Error::<Runtime>::OracleAlreadyReported
+        );
+    });
+}
+
+#[test]
+fn oracle_which_does_not_report_by_the_end_of_the_oracle_duration_is_slashed() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_multi_oracle_market(
+            MarketType::Categorical(2),
+            &[(charlie(), 1), (dave(), 1), (eve(), 1)],
+            2,
+        );
+        report_as(market_id, charlie(), OutcomeReport::Categorical(1));
+        report_as(market_id, dave(), OutcomeReport::Categorical(1));
+        assert_eq!(MarketCommons::market(&market_id).unwrap().status, MarketStatus::Reported);
+        assert_noop!(
+            PredictionMarkets::finalize_oracle_reports(RuntimeOrigin::signed(fred()), market_id),
+            Error::<Runtime>::OracleReportingWindowNotExpired
+        );
+
+        let market = MarketCommons::market(&market_id).unwrap();
+        run_to_block(2 + market.deadlines.grace_period + market.deadlines.oracle_duration + 1);
+        let free_alice = Balances::free_balance(alice());
+        let reserved_alice = Balances::reserved_balance(alice());
+        assert_ok!(PredictionMarkets::finalize_oracle_reports(
+            RuntimeOrigin::signed(fred()),
+            market_id
+        ));
+
+        // Eve's bond is slashed.
+        let multi_oracle_bond = <Runtime as Config>::MultiOracleBond::get();
+        assert_eq!(Balances::free_balance(alice()), free_alice);
+        assert_eq!(Balances::reserved_balance(alice()), reserved_alice - multi_oracle_bond);
+        let market = MarketCommons::market(&market_id).unwrap();
+        assert!(market.bonds.oracles.iter().all(|bond| bond.is_settled));
+        assert_noop!(
+            PredictionMarkets::report(
+                RuntimeOrigin::signed(eve()),
+                market_id,
+                OutcomeReport::Categorical(1)
+            ),
+            Error::<Runtime>::MarketAlreadyReported
+        );
+        assert_noop!(
+            PredictionMarkets::finalize_oracle_reports(RuntimeOrigin::signed(fred()), market_id),
+            Error::<Runtime>::OracleBondsAlreadySettled
+        );
+    });
+}
+
+#[test]
+fn multi_oracle_report_fails_if_reporter_is_not_an_oracle() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_multi_oracle_market(
+            MarketType::Categorical(2),
+            &[(charlie(), 1), (dave(), 1)],
+            2,
+        );
+        // The oracle of the market is replaced by the oracle set.
+        assert_noop!(
+            PredictionMarkets::report(
+                RuntimeOrigin::signed(bob()),
+                market_id,
+                OutcomeReport::Categorical(0)
+            ),
+            Error::<Runtime>::ReporterNotOracle
+        );
+    });
+}
+
+#[test]
+fn multi_oracle_report_fails_if_oracle_already_reported() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_multi_oracle_market(
+            MarketType::Categorical(2),
+            &[(charlie(), 1), (dave(), 1)],
+            2,
+        );
+        report_as(market_id, charlie(), OutcomeReport::Categorical(0));
+        assert_noop!(
+            PredictionMarkets::report(
+                RuntimeOrigin::signed(charlie()),
+                market_id,
+                OutcomeReport::Categorical(1)
+            ),
+            Error::<Runtime>::OracleAlreadyReported
+        );
+    });
+}
+
+#[test]
+fn outsider_may_report_multi_oracle_market_if_no_oracle_reported_in_time() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_multi_oracle_market(
+            MarketType::Categorical(2),
+            &[(charlie(), 1), (dave(), 1)],
+            2,
+        );
+        let market = MarketCommons::market(&market_id).unwrap();
+        run_to_block(2 + market.deadlines.grace_period + market.deadlines.oracle_duration + 1);
+
+        let free_alice = Balances::free_balance(alice());
+        let reserved_alice = Balances::reserved_balance(alice());
+        report_as(market_id, fred(), OutcomeReport::Categorical(1));
+
+        let market = MarketCommons::market(&market_id).unwrap();
+        assert_eq!(market.status, MarketStatus::Reported);
+        assert_eq!(market.report.unwrap().by, fred());
+        // The bonds of all oracles are slashed.
+        let multi_oracle_bond = <Runtime as Config>::MultiOracleBond::get();
+        assert_eq!(Balances::free_balance(alice()), free_alice);
+        assert_eq!(Balances::reserved_balance(alice()), reserved_alice - 2 * multi_oracle_bond);
+    });
+}
+
+#[test]
+fn report_fails_on_multi_oracle_market_with_pending_oracle_reports() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_multi_oracle_market(
+            MarketType::Categorical(2),
+            &[(charlie(), 1), (dave(), 1)],
+            2,
+        );
+        report_as(market_id, charlie(), OutcomeReport::Categorical(0));
+        let market = MarketCommons::market(&market_id).unwrap();
+        run_to_block(2 + market.deadlines.grace_period + market.deadlines.oracle_duration + 1);
+        assert_noop!(
+            PredictionMarkets::report(
+                RuntimeOrigin::signed(fred()),
+                market_id,
+                OutcomeReport::Categorical(1)
+            ),
+            Error::<Runtime>::OracleReportsNotFinalized
+        );
+    });
+}
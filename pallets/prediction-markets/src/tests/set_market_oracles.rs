@@ -0,0 +1,193 @@
+// Copyright 2022-2024 Forecasting Technologies LTD.
+// Copyright 2021-2022 Zeitgeist PM LLC.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::MarketOracles;
+use test_case::test_case;
+
+#[test]
+fn set_market_oracles_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Advised,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        let reserved = Balances::reserved_balance(alice());
+
+        let oracles = weighted_oracles(&[(charlie(), 1), (dave(), 2), (eve(), 1)]);
+        assert_ok!(PredictionMarkets::set_market_oracles(
+            RuntimeOrigin::signed(alice()),
+            0,
+            oracles.clone(),
+            3
+        ));
+
+        let oracle_set = MarketOracles::<Runtime>::get(0).unwrap();
+        assert_eq!(oracle_set.oracles.into_inner(), oracles);
+        assert_eq!(oracle_set.quorum, 3);
+        let market = MarketCommons::market(&0).unwrap();
+        assert_eq!(market.bonds.oracles.len(), 3);
+        assert!(market.bonds.oracles.iter().all(|bond| bond.who == alice() && !bond.is_settled));
+        let multi_oracle_bond = <Runtime as Config>::MultiOracleBond::get();
+        assert_eq!(Balances::reserved_balance(alice()), reserved + 3 * multi_oracle_bond);
+        System::assert_last_event(
+            Event::MarketOraclesSet { market_id: 0, oracles, quorum: 3 }.into(),
+        );
+    });
+}
+
+#[test]
+fn set_market_oracles_works_for_market_admin_on_active_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        let reserved = Balances::reserved_balance(alice());
+
+        set_market_oracles(0, &[(charlie(), 1), (dave(), 1)], 2);
+
+        // The bonds are reserved from the market creator.
+        let multi_oracle_bond = <Runtime as Config>::MultiOracleBond::get();
+        assert_eq!(Balances::reserved_balance(alice()), reserved + 2 * multi_oracle_bond);
+        let market = MarketCommons::market(&0).unwrap();
+        assert!(market.bonds.oracles.iter().all(|bond| bond.who == alice()));
+    });
+}
+
+#[test]
+fn set_market_oracles_returns_the_bonds_of_the_previous_oracles() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Advised,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        let reserved = Balances::reserved_balance(alice());
+        set_market_oracles(0, &[(charlie(), 1), (dave(), 1), (eve(), 1)], 2);
+
+        set_market_oracles(0, &[(fred(), 1)], 1);
+        let multi_oracle_bond = <Runtime as Config>::MultiOracleBond::get();
+        assert_eq!(Balances::reserved_balance(alice()), reserved + multi_oracle_bond);
+        assert_eq!(MarketCommons::market(&0).unwrap().bonds.oracles.len(), 1);
+
+        set_market_oracles(0, &[], 0);
+        assert_eq!(Balances::reserved_balance(alice()), reserved);
+        assert!(MarketOracles::<Runtime>::get(0).is_none());
+        assert!(MarketCommons::market(&0).unwrap().bonds.oracles.is_empty());
+    });
+}
+
+#[test]
+fn set_market_oracles_fails_if_caller_is_not_the_market_creator() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Advised,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_noop!(
+            PredictionMarkets::set_market_oracles(
+                RuntimeOrigin::signed(bob()),
+                0,
+                weighted_oracles(&[(charlie(), 1)]),
+                1
+            ),
+            Error::<Runtime>::CallerNotMarketCreator
+        );
+    });
+}
+
+#[test]
+fn set_market_oracles_fails_if_market_creator_changes_the_oracles_of_an_active_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_eq!(MarketCommons::market(&0).unwrap().status, MarketStatus::Active);
+        assert_noop!(
+            PredictionMarkets::set_market_oracles(
+                RuntimeOrigin::signed(alice()),
+                0,
+                weighted_oracles(&[(charlie(), 1)]),
+                1
+            ),
+            Error::<Runtime>::InvalidMarketStatus
+        );
+    });
+}
+
+#[test]
+fn set_market_oracles_fails_if_market_is_closed() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        run_to_block(2);
+        assert_noop!(
+            PredictionMarkets::set_market_oracles(
+                RuntimeOrigin::signed(market_admin()),
+                0,
+                weighted_oracles(&[(charlie(), 1)]),
+                1
+            ),
+            Error::<Runtime>::InvalidMarketStatus
+        );
+    });
+}
+
+#[test_case(vec![(charlie(), 1), (dave(), 0)], 1; "zero weight")]
+#[test_case(vec![(charlie(), 1), (charlie(), 1)], 1; "duplicate oracle")]
+#[test_case(vec![(charlie(), 1), (dave(), 1)], 0; "zero quorum")]
+#[test_case(vec![(charlie(), 1), (dave(), 1)], 3; "unreachable quorum")]
+#[test_case(vec![(charlie(), u32::MAX), (dave(), 1)], 1; "weight overflow")]
+fn set_market_oracles_fails_on_invalid_oracle_set(
+    oracles: Vec<(AccountIdOf<Runtime>, u32)>,
+    quorum: u32,
+) {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Advised,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_noop!(
+            PredictionMarkets::set_market_oracles(
+                RuntimeOrigin::signed(alice()),
+                0,
+                weighted_oracles(&oracles),
+                quorum
+            ),
+            Error::<Runtime>::InvalidOracleSet
+        );
+    });
+}
@@ -0,0 +1,457 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::CombinatorialCollections;
+use test_case::test_case;
+
+#[test]
+fn split_position_from_collateral_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        let market_id = 0;
+        let amount = 2 * BASE;
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(charlie()),
+            None,
+            market_id,
+            vec![0b01, 0b10],
+            amount,
+        ));
+
+        for index_set in [0b01, 0b10] {
+            let id = collection_id(None, market_id, index_set);
+            assert_eq!(
+                AssetManager::free_balance(Asset::CombinatorialOutcome(id), &charlie()),
+                amount
+            );
+            assert_eq!(
+                CombinatorialCollections::<Runtime>::get(id).unwrap().into_inner(),
+                vec![(market_id, index_set)]
+            );
+        }
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &charlie()), INITIAL_BALANCE - amount);
+        assert_eq!(
+            AssetManager::free_balance(Asset::Tru, &PredictionMarkets::combinatorial_account()),
+            amount
+        );
+        System::assert_last_event(
+            Event::PositionSplit {
+                who: charlie(),
+                parent_collection_id: None,
+                market_id,
+                partition: vec![0b01, 0b10],
+                amount,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn split_position_from_parent_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        for _ in 0..2 {
+            simple_create_categorical_market(
+                Asset::Tru,
+                MarketCreation::Permissionless,
+                0..2,
+                ScoringRule::AmmCdaHybrid,
+            );
+        }
+        let amount = 2 * BASE;
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(charlie()),
+            None,
+            0,
+            vec![0b01, 0b10],
+            amount,
+        ));
+        let parent = collection_id(None, 0, 0b01);
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(charlie()),
+            Some(parent),
+            1,
+            vec![0b01, 0b10],
+            BASE,
+        ));
+
+        assert_eq!(
+            AssetManager::free_balance(Asset::CombinatorialOutcome(parent), &charlie()),
+            amount - BASE
+        );
+        for index_set in [0b01, 0b10] {
+            let id = collection_id(Some(parent), 1, index_set);
+            assert_eq!(
+                AssetManager::free_balance(Asset::CombinatorialOutcome(id), &charlie()),
+                BASE
+            );
+            assert_eq!(
+                CombinatorialCollections::<Runtime>::get(id).unwrap().into_inner(),
+                vec![(0, 0b01), (1, index_set)]
+            );
+        }
+        // Splitting a parent position doesn't move collateral.
+        assert_eq!(
+            AssetManager::free_balance(Asset::Tru, &PredictionMarkets::combinatorial_account()),
+            amount
+        );
+    });
+}
+
+#[test]
+fn split_position_with_partial_partition_splits_union() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        let market_id = 0;
+        assert_ok!(MarketCommons::mutate_market(&market_id, |market| {
+            market.market_type = MarketType::Categorical(3);
+            Ok(())
+        }));
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(charlie()),
+            None,
+            market_id,
+            vec![0b011, 0b100],
+            BASE,
+        ));
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(charlie()),
+            None,
+            market_id,
+            vec![0b001, 0b010],
+            BASE,
+        ));
+
+        let union = Asset::CombinatorialOutcome(collection_id(None, market_id, 0b011));
+        assert_eq!(AssetManager::free_balance(union, &charlie()), 0);
+        for index_set in [0b001, 0b010, 0b100] {
+            let asset = Asset::CombinatorialOutcome(collection_id(None, market_id, index_set));
+            assert_eq!(AssetManager::free_balance(asset, &charlie()), BASE);
+        }
+        assert_eq!(AssetManager::free_balance(Asset::Tru, &charlie()), INITIAL_BALANCE - BASE);
+    });
+}
+
+#[test]
+fn collection_ids_do_not_depend_on_split_order() {
+    ExtBuilder::default().build().execute_with(|| {
+        for _ in 0..2 {
+            simple_create_categorical_market(
+                Asset::Tru,
+                MarketCreation::Permissionless,
+                0..2,
+                ScoringRule::AmmCdaHybrid,
+            );
+        }
+        for market_id in 0..2 {
+            assert_ok!(PredictionMarkets::split_position(
+                RuntimeOrigin::signed(charlie()),
+                None,
+                market_id,
+                vec![0b01, 0b10],
+                BASE,
+            ));
+        }
+        let first = collection_id(Some(collection_id(None, 0, 0b01)), 1, 0b10);
+        let second = collection_id(Some(collection_id(None, 1, 0b10)), 0, 0b01);
+        assert_eq!(first, second);
+    });
+}
+
+#[test_case(vec![0b01]; "single index set")]
+#[test_case(vec![0b01, 0b01]; "overlapping index sets")]
+#[test_case(vec![0b01, 0b00]; "empty index set")]
+#[test_case(vec![0b01, 0b100]; "index set out of range")]
+fn split_position_fails_on_invalid_partition(partition: Vec<IndexSet>) {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_noop!(
+            PredictionMarkets::split_position(
+                RuntimeOrigin::signed(charlie()),
+                None,
+                0,
+                partition,
+                BASE,
+            ),
+            Error::<Runtime>::InvalidPartition
+        );
+    });
+}
+
+#[test]
+fn split_position_fails_on_zero_amount() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_noop!(
+            PredictionMarkets::split_position(
+                RuntimeOrigin::signed(charlie()),
+                None,
+                0,
+                vec![0b01, 0b10],
+                0,
+            ),
+            Error::<Runtime>::ZeroAmount
+        );
+    });
+}
+
+#[test_case(MarketStatus::Closed)]
+#[test_case(MarketStatus::Reported)]
+#[test_case(MarketStatus::Resolved)]
+fn split_position_fails_if_market_is_not_active(status: MarketStatus) {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_ok!(MarketCommons::mutate_market(&0, |market| {
+            market.status = status;
+            Ok(())
+        }));
+        assert_noop!(
+            PredictionMarkets::split_position(
+                RuntimeOrigin::signed(charlie()),
+                None,
+                0,
+                vec![0b01, 0b10],
+                BASE,
+            ),
+            Error::<Runtime>::MarketIsNotActive
+        );
+    });
+}
+
+#[test]
+fn split_position_fails_on_insufficient_collateral() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_noop!(
+            PredictionMarkets::split_position(
+                RuntimeOrigin::signed(charlie()),
+                None,
+                0,
+                vec![0b01, 0b10],
+                INITIAL_BALANCE + 1,
+            ),
+            Error::<Runtime>::NotEnoughBalance
+        );
+    });
+}
+
+#[test]
+fn split_position_fails_on_insufficient_parent_position() {
+    ExtBuilder::default().build().execute_with(|| {
+        for _ in 0..2 {
+            simple_create_categorical_market(
+                Asset::Tru,
+                MarketCreation::Permissionless,
+                0..2,
+                ScoringRule::AmmCdaHybrid,
+            );
+        }
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(charlie()),
+            None,
+            0,
+            vec![0b01, 0b10],
+            BASE,
+        ));
+        assert_noop!(
+            PredictionMarkets::split_position(
+                RuntimeOrigin::signed(charlie()),
+                Some(collection_id(None, 0, 0b01)),
+                1,
+                vec![0b01, 0b10],
+                2 * BASE,
+            ),
+            Error::<Runtime>::InsufficientShareBalance
+        );
+    });
+}
+
+#[test]
+fn split_position_fails_on_unknown_parent() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_noop!(
+            PredictionMarkets::split_position(
+                RuntimeOrigin::signed(charlie()),
+                Some([0; 32]),
+                0,
+                vec![0b01, 0b10],
+                BASE,
+            ),
+            Error::<Runtime>::CombinatorialCollectionNotFound
+        );
+    });
+}
+
+#[test]
+fn split_position_fails_if_market_is_already_in_collection() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(charlie()),
+            None,
+            0,
+            vec![0b01, 0b10],
+            BASE,
+        ));
+        assert_noop!(
+            PredictionMarkets::split_position(
+                RuntimeOrigin::signed(charlie()),
+                Some(collection_id(None, 0, 0b01)),
+                0,
+                vec![0b01, 0b10],
+                BASE,
+            ),
+            Error::<Runtime>::MarketAlreadyInCollection
+        );
+    });
+}
+
+#[test]
+fn split_position_fails_on_base_asset_mismatch() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        simple_create_categorical_market(
+            Asset::ForeignAsset(100),
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_ok!(PredictionMarkets::split_position(
+            RuntimeOrigin::signed(charlie()),
+            None,
+            0,
+            vec![0b01, 0b10],
+            BASE,
+        ));
+        assert_noop!(
+            PredictionMarkets::split_position(
+                RuntimeOrigin::signed(charlie()),
+                Some(collection_id(None, 0, 0b01)),
+                1,
+                vec![0b01, 0b10],
+                BASE,
+            ),
+            Error::<Runtime>::CombinatorialBaseAssetMismatch
+        );
+    });
+}
+
+#[test]
+fn split_position_fails_if_max_split_depth_is_exceeded() {
+    ExtBuilder::default().build().execute_with(|| {
+        let max_split_depth: MarketId = <Runtime as Config>::MaxSplitDepth::get().into();
+        let mut parent_collection_id = None;
+        for market_id in 0..=max_split_depth {
+            simple_create_categorical_market(
+                Asset::Tru,
+                MarketCreation::Permissionless,
+                0..2,
+                ScoringRule::AmmCdaHybrid,
+            );
+            if market_id == max_split_depth {
+                assert_noop!(
+                    PredictionMarkets::split_position(
+                        RuntimeOrigin::signed(charlie()),
+                        parent_collection_id,
+                        market_id,
+                        vec![0b01, 0b10],
+                        BASE,
+                    ),
+                    Error::<Runtime>::MaxSplitDepthExceeded
+                );
+            } else {
+                assert_ok!(PredictionMarkets::split_position(
+                    RuntimeOrigin::signed(charlie()),
+                    parent_collection_id,
+                    market_id,
+                    vec![0b01, 0b10],
+                    BASE,
+                ));
+                parent_collection_id = Some(collection_id(parent_collection_id, market_id, 0b01));
+            }
+        }
+    });
+}
+
+#[test]
+fn split_position_fails_on_parimutuel_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Tru,
+            MarketCreation::Permissionless,
+            0..2,
+            ScoringRule::Parimutuel,
+        );
+        assert_noop!(
+            PredictionMarkets::split_position(
+                RuntimeOrigin::signed(charlie()),
+                None,
+                0,
+                vec![0b01, 0b10],
+                BASE,
+            ),
+            Error::<Runtime>::InvalidScoringRule
+        );
+    });
+}
@@ -18,6 +18,7 @@
 
 use super::*;
 use crate::WhitelistedMarketCreators;
+use pallet_pm_global_disputes::GlobalDisputesPalletApi;
 use prediction_market_primitives::types::OutcomeReport;
 
 // TODO(#1239) MarketDoesNotExist
@@ -27,46 +28,78 @@ use prediction_market_primitives::types::OutcomeReport;
 // TODO(#1239) MarketIsNotReported
 // TODO(#1239) MarketDisputeMechanismNotFailed
 
-#[test]
-fn start_global_dispute_fails_on_wrong_mdm() {
-    ExtBuilder::default().build().execute_with(|| {
-        let end = 2;
-        WhitelistedMarketCreators::<Runtime>::insert(&alice(), ());
-        assert_ok!(PredictionMarkets::create_market(
-            RuntimeOrigin::signed(alice()),
-            Asset::Tru,
-            Perbill::zero(),
-            bob(),
-            MarketPeriod::Block(0..2),
-            get_deadlines(),
-            gen_metadata(2),
-            MarketCreation::Permissionless,
-            MarketType::Categorical(<Runtime as Config>::MaxDisputes::get() + 1),
-            Some(MarketDisputeMechanism::Authorized),
-            ScoringRule::AmmCdaHybrid,
-        ));
-        let market_id = MarketCommons::latest_market_id().unwrap();
+fn create_and_dispute_authorized_market() -> MarketId {
+    let end = 2;
+    WhitelistedMarketCreators::<Runtime>::insert(&alice(), ());
+    assert_ok!(PredictionMarkets::create_market(
+        RuntimeOrigin::signed(alice()),
+        Asset::Tru,
+        Perbill::zero(),
+        bob(),
+        MarketPeriod::Block(0..2),
+        get_deadlines(),
+        gen_metadata(2),
+        MarketCreation::Permissionless,
+        MarketType::Categorical(<Runtime as Config>::MaxDisputes::get() + 1),
+        Some(MarketDisputeMechanism::Authorized),
+        ScoringRule::AmmCdaHybrid,
+    ));
+    let market_id = MarketCommons::latest_market_id().unwrap();
 
-        let market = MarketCommons::market(&market_id).unwrap();
-        let grace_period = market.deadlines.grace_period;
-        run_to_block(end + grace_period + 1);
-        assert_ok!(PredictionMarkets::report(
-            RuntimeOrigin::signed(bob()),
-            market_id,
-            OutcomeReport::Categorical(0)
-        ));
-        let dispute_at_0 = end + grace_period + 2;
-        run_to_block(dispute_at_0);
+    let market = MarketCommons::market(&market_id).unwrap();
+    let grace_period = market.deadlines.grace_period;
+    run_to_block(end + grace_period + 1);
+    assert_ok!(PredictionMarkets::report(
+        RuntimeOrigin::signed(bob()),
+        market_id,
+        OutcomeReport::Categorical(0)
+    ));
+    let dispute_at_0 = end + grace_period + 2;
+    run_to_block(dispute_at_0);
 
-        // only one dispute allowed for authorized mdm
-        assert_ok!(PredictionMarkets::dispute(RuntimeOrigin::signed(charlie()), market_id,));
-        run_blocks(1);
-        let market = MarketCommons::market(&market_id).unwrap();
-        assert_eq!(market.status, MarketStatus::Disputed);
+    // only one dispute allowed for authorized mdm
+    assert_ok!(PredictionMarkets::dispute(RuntimeOrigin::signed(charlie()), market_id,));
+    run_blocks(1);
+    let market = MarketCommons::market(&market_id).unwrap();
+    assert_eq!(market.status, MarketStatus::Disputed);
+    market_id
+}
 
+#[test]
+fn start_global_dispute_fails_if_authority_has_not_failed() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_and_dispute_authorized_market();
         assert_noop!(
             PredictionMarkets::start_global_dispute(RuntimeOrigin::signed(charlie()), market_id),
-            Error::<Runtime>::InvalidDisputeMechanism
+            Error::<Runtime>::MarketDisputeMechanismNotFailed
         );
     });
 }
+
+#[test]
+fn start_global_dispute_works_if_authorized_panel_is_split() {
+    ExtBuilder::default().build().execute_with(|| {
+        let members = vec![charlie(), dave(), eve()].try_into().unwrap();
+        assert_ok!(Authorized::set_creator_panel(
+            RuntimeOrigin::signed(AuthorizedDisputeResolutionUser::get()),
+            alice(),
+            members,
+            2,
+        ));
+        let market_id = create_and_dispute_authorized_market();
+        for (index, member) in [charlie(), dave(), eve()].into_iter().enumerate() {
+            assert_ok!(Authorized::submit_panel_report(
+                RuntimeOrigin::signed(member),
+                market_id,
+                OutcomeReport::Categorical(index as u16),
+                Default::default(),
+            ));
+        }
+
+        assert_ok!(PredictionMarkets::start_global_dispute(
+            RuntimeOrigin::signed(charlie()),
+            market_id
+        ));
+        assert!(GlobalDisputes::does_exist(&market_id));
+    });
+}
@@ -69,6 +69,7 @@ pub trait WeightInfoZeitgeist {
     fn on_initialize_resolve_overhead() -> Weight;
     fn redeem_shares_categorical() -> Weight;
     fn redeem_shares_scalar() -> Weight;
+    fn redeem_shares_invalid(c: u32) -> Weight;
     fn reject_market(c: u32, r: u32) -> Weight;
     fn report_market_with_dispute_mechanism(m: u32) -> Weight;
     fn report_trusted_market() -> Weight;
@@ -89,6 +90,7 @@ pub trait WeightInfoZeitgeist {
     fn signed_withdraw_tokens() -> Weight;
     fn signed_redeem_shares_categorical() -> Weight;
     fn signed_redeem_shares_scalar() -> Weight;
+    fn signed_redeem_shares_invalid(c: u32) -> Weight;
     fn set_config_option() -> Weight;
     fn whitelist_market_creator() -> Weight;
     fn remove_market_creator() -> Weight;
@@ -99,6 +101,14 @@ pub trait WeightInfoZeitgeist {
     fn set_winnings_fee_account() -> Weight;
     fn set_additional_swap_fee_account() -> Weight;
     fn admin_update_market_oracle() -> Weight;
+    fn split_position(n: u32) -> Weight;
+    fn merge_position(n: u32) -> Weight;
+    fn redeem_position(n: u32) -> Weight;
+    fn set_market_oracles(o: u32) -> Weight;
+    fn report_multi_oracle(o: u32) -> Weight;
+    fn report_multi_oracle_trusted(o: u32) -> Weight;
+    fn finalize_oracle_reports(o: u32) -> Weight;
+    fn finalize_oracle_reports_trusted(o: u32) -> Weight;
 }
 
 /// Weight functions for pallet_prediction_markets (automatically generated)
@@ -524,6 +534,19 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().reads(6))
             .saturating_add(T::DbWeight::get().writes(5))
     }
+    /// Not benchmarked yet. Estimated from the storage it accesses with a constant cost per
+    /// outcome. Storage: `MarketCommons::Markets` (r:1 w:0) Storage: `Tokens::Accounts` (r:64
+    /// w:64) Storage: `System::Account` (r:1 w:1) Storage: `Tokens::TotalIssuance` (r:64 w:64) The
+    /// range of component `c` is `[2, 64]`.
+    fn redeem_shares_invalid(c: u32) -> Weight {
+        Weight::from_parts(81_207_540, 4958)
+            .saturating_add(Weight::from_parts(33_791_127, 0).saturating_mul(c.into()))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+            .saturating_add(Weight::from_parts(0, 2612).saturating_mul(c.into()))
+    }
     /// Storage: `MarketCommons::Markets` (r:1 w:1)
     /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(694), added: 3169,
     /// mode: `MaxEncodedLen`) Storage: `PredictionMarkets::MarketIdsPerCloseTimeFrame` (r:1
@@ -1022,6 +1045,20 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().reads(7_u64))
             .saturating_add(T::DbWeight::get().writes(6_u64))
     }
+    /// Not benchmarked yet. Estimated as `redeem_shares_invalid` plus the storage of the signed
+    /// proof. Storage: `PredictionMarkets::MarketNonces` (r:1 w:1) Storage:
+    /// `MarketCommons::Markets` (r:1 w:0) Storage: `Tokens::Accounts` (r:64 w:64) Storage:
+    /// `System::Account` (r:2 w:2) Storage: `Tokens::TotalIssuance` (r:64 w:64) The range of
+    /// component `c` is `[2, 64]`.
+    fn signed_redeem_shares_invalid(c: u32) -> Weight {
+        Weight::from_parts(72_564_318, 6196)
+            .saturating_add(Weight::from_parts(33_870_542, 0).saturating_mul(c.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+            .saturating_add(Weight::from_parts(0, 2612).saturating_mul(c.into()))
+    }
     /// Storage: `PredictionMarkets::MarketNonces` (r:1 w:1)
     /// Proof: `PredictionMarkets::MarketNonces` (`max_values`: None, `max_size`: Some(88), added:
     /// 2563, mode: `MaxEncodedLen`) Storage: `MarketCommons::Markets` (r:1 w:0)
@@ -1063,4 +1100,101 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().reads(3_u64))
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
+    /// Not benchmarked yet. Estimated from the storage it accesses with a constant cost per
+    /// outcome. Storage: `MarketCommons::Markets` (r:1 w:0) Storage: `System::Account` (r:2 w:2)
+    /// Storage: `PredictionMarkets::CombinatorialCollections` (r:64 w:64) Storage:
+    /// `Tokens::Accounts` (r:64 w:64) Storage: `Tokens::TotalIssuance` (r:64 w:64) The range of
+    /// component `n` is `[2, 64]`.
+    fn split_position(n: u32) -> Weight {
+        Weight::from_parts(66_254_103, 6196)
+            .saturating_add(Weight::from_parts(23_801_377, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2780).saturating_mul(n.into()))
+    }
+    /// Not benchmarked yet. Estimated from the storage it accesses with a constant cost per
+    /// outcome. Storage: `MarketCommons::Markets` (r:1 w:0) Storage: `Tokens::Accounts` (r:64 w:64)
+    /// Storage: `Tokens::TotalIssuance` (r:64 w:64) Storage: `System::Account` (r:2 w:2) The range
+    /// of component `n` is `[2, 64]`.
+    fn merge_position(n: u32) -> Weight {
+        Weight::from_parts(61_877_520, 6196)
+            .saturating_add(Weight::from_parts(19_046_613, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2612).saturating_mul(n.into()))
+    }
+    /// Not benchmarked yet. Estimated from the storage it accesses with a constant cost per
+    /// condition of the collection. Storage: `PredictionMarkets::CombinatorialCollections` (r:1
+    /// w:0) Storage: `Tokens::Accounts` (r:1 w:1) Storage: `MarketCommons::Markets` (r:8 w:0)
+    /// Storage: `PredictionMarkets::LiquidityProviders` (r:8 w:0) Storage: `System::Account` (r:3
+    /// w:3) Storage: `Tokens::TotalIssuance` (r:1 w:1) The range of component `n` is `[1, 8]`.
+    fn redeem_position(n: u32) -> Weight {
+        Weight::from_parts(98_130_416, 8799)
+            .saturating_add(Weight::from_parts(16_702_941, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+            .saturating_add(Weight::from_parts(0, 3968).saturating_mul(n.into()))
+    }
+    /// Not benchmarked yet. Estimated from the storage it accesses with a constant cost per
+    /// oracle. Storage: `PredictionMarkets::MarketAdmin` (r:1 w:0) Storage:
+    /// `MarketCommons::Markets` (r:1 w:1) Storage: `PredictionMarkets::MarketOracles` (r:1 w:1)
+    /// Storage: `Balances::Reserves` (r:1 w:1) The range of component `o` is `[1, 16]`.
+    fn set_market_oracles(o: u32) -> Weight {
+        Weight::from_parts(61_208_113, 4958)
+            .saturating_add(Weight::from_parts(1_204_417, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Not benchmarked yet. Estimated from the storage it accesses with a constant cost per
+    /// oracle. Storage: `MarketCommons::Markets` (r:1 w:1) Storage:
+    /// `PredictionMarkets::MarketOracles` (r:1 w:0) Storage: `Timestamp::Now` (r:1 w:0) Storage:
+    /// `PredictionMarkets::OracleReports` (r:1 w:1) Storage: `Balances::Reserves` (r:1 w:1)
+    /// Storage: `PredictionMarkets::MarketIdsPerReportBlock` (r:1 w:1) The range of component `o`
+    /// is `[1, 16]`.
+    fn report_multi_oracle(o: u32) -> Weight {
+        Weight::from_parts(71_982_540, 4958)
+            .saturating_add(Weight::from_parts(2_318_960, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Not benchmarked yet. Estimated as `report_multi_oracle` plus `report_trusted_market`, which
+    /// resolves the market. Storage: `MarketCommons::Markets` (r:2 w:2) Storage:
+    /// `PredictionMarkets::MarketOracles` (r:1 w:0) Storage: `Timestamp::Now` (r:1 w:0) Storage:
+    /// `PredictionMarkets::OracleReports` (r:1 w:1) Storage: `Balances::Reserves` (r:2 w:2) The
+    /// range of component `o` is `[1, 16]`.
+    fn report_multi_oracle_trusted(o: u32) -> Weight {
+        Weight::from_parts(153_695_540, 4958)
+            .saturating_add(Weight::from_parts(2_318_960, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    /// Not benchmarked yet. Estimated from the storage it accesses with a constant cost per
+    /// oracle. Storage: `MarketCommons::Markets` (r:1 w:1) Storage:
+    /// `PredictionMarkets::MarketOracles` (r:1 w:0) Storage: `Timestamp::Now` (r:1 w:0) Storage:
+    /// `PredictionMarkets::OracleReports` (r:1 w:0) Storage: `Balances::Reserves` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1) Storage:
+    /// `PredictionMarkets::MarketIdsPerReportBlock` (r:1 w:1) The range of component `o` is `[2,
+    /// 16]`.
+    fn finalize_oracle_reports(o: u32) -> Weight {
+        Weight::from_parts(64_730_812, 4958)
+            .saturating_add(Weight::from_parts(2_441_093, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Not benchmarked yet. Estimated as `finalize_oracle_reports` plus `report_trusted_market`,
+    /// which resolves the market. Storage: `MarketCommons::Markets` (r:2 w:2) Storage:
+    /// `PredictionMarkets::MarketOracles` (r:1 w:0) Storage: `Timestamp::Now` (r:1 w:0) Storage:
+    /// `PredictionMarkets::OracleReports` (r:1 w:0) Storage: `Balances::Reserves` (r:2 w:2)
+    /// Storage: `System::Account` (r:1 w:1) The range of component `o` is `[2, 16]`.
+    fn finalize_oracle_reports_trusted(o: u32) -> Weight {
+        Weight::from_parts(146_443_812, 4958)
+            .saturating_add(Weight::from_parts(2_441_093, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
 }
@@ -0,0 +1,113 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![allow(
+    // Auto-generated code is a no man's land
+    clippy::arithmetic_side_effects
+)]
+
+#[cfg(test)]
+use crate::Pallet as RandomnessBeacon;
+
+use crate::*;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    assert_ok,
+    traits::{Currency, Get, Hooks},
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::{
+    traits::{Hash, Saturating},
+    SaturatedConversion,
+};
+
+/// Starts a new round and returns it.
+fn start_round<T: Config>() -> RoundInfo<BlockNumberFor<T>> {
+    Pallet::<T>::finalize_round(frame_system::Pallet::<T>::block_number());
+    CurrentRound::<T>::get()
+}
+
+/// Funds `who` with enough balance to reserve the commit bond.
+fn fund<T: Config>(who: &T::AccountId) {
+    let amount = T::CommitBond::get().saturating_mul(2u128.saturated_into());
+    T::Currency::make_free_balance_be(who, amount.saturating_add(T::Currency::minimum_balance()));
+}
+
+fn commit_to<T: Config>(who: &T::AccountId, secret: T::Hash) {
+    fund::<T>(who);
+    let commitment = Pallet::<T>::commitment_of(who, &secret);
+    assert_ok!(Pallet::<T>::commit(RawOrigin::Signed(who.clone()).into(), commitment));
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn commit() {
+        let caller: T::AccountId = whitelisted_caller();
+        start_round::<T>();
+        fund::<T>(&caller);
+        let commitment = Pallet::<T>::commitment_of(&caller, &T::Hashing::hash(b"secret"));
+
+        #[extrinsic_call]
+        commit(RawOrigin::Signed(caller), commitment);
+    }
+
+    #[benchmark]
+    fn reveal() {
+        let caller: T::AccountId = whitelisted_caller();
+        let round = start_round::<T>();
+        let secret = T::Hashing::hash(b"secret");
+        commit_to::<T>(&caller, secret);
+        frame_system::Pallet::<T>::set_block_number(round.commit_end);
+
+        #[extrinsic_call]
+        reveal(RawOrigin::Signed(caller), secret);
+    }
+
+    #[benchmark]
+    fn on_initialize(c: Linear<0, { T::MaxParticipants::get() }>) {
+        // Worst case is a round in which all but one of the commitments are left unrevealed, so
+        // the seed is updated and the bonds of the other commitments are slashed.
+        let round = start_round::<T>();
+        for i in 0..c {
+            let participant: T::AccountId = account("participant", i, 0);
+            commit_to::<T>(&participant, T::Hashing::hash_of(&i));
+        }
+        frame_system::Pallet::<T>::set_block_number(round.commit_end);
+        if c > 0 {
+            let participant: T::AccountId = account("participant", 0, 0);
+            assert_ok!(Pallet::<T>::reveal(
+                RawOrigin::Signed(participant).into(),
+                T::Hashing::hash_of(&0u32)
+            ));
+        }
+        frame_system::Pallet::<T>::set_block_number(round.reveal_end);
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(round.reveal_end);
+        }
+    }
+
+    impl_benchmark_test_suite!(
+        RandomnessBeacon,
+        crate::mock::ExtBuilder::default().build(),
+        crate::mock::Runtime
+    );
+}
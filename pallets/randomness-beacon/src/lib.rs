@@ -0,0 +1,314 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+mod types;
+pub mod weights;
+
+pub use pallet::*;
+pub use types::*;
+
+#[frame_support::pallet]
+mod pallet {
+    use crate::{types::*, weights::WeightInfoZeitgeist};
+    use core::marker::PhantomData;
+    use frame_support::{
+        ensure,
+        pallet_prelude::{
+            Blake2_128Concat, Hooks, OptionQuery, StorageMap, StorageValue, ValueQuery, Weight,
+        },
+        traits::{
+            Contains, Currency, Get, Imbalance, IsType, OnUnbalanced, Randomness,
+            ReservableCurrency, StorageVersion,
+        },
+    };
+    use frame_system::{
+        ensure_signed,
+        pallet_prelude::{BlockNumberFor, OriginFor},
+    };
+    use sp_runtime::{
+        traits::{Hash, Saturating, Zero},
+        DispatchResult,
+    };
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The accounts which are allowed to contribute to the beacon.
+        type Participants: Contains<Self::AccountId>;
+
+        /// The currency used for the commit bonds.
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// The bond reserved by a participant when committing to a secret. It's unreserved when
+        /// the secret is revealed and slashed if the secret isn't revealed until the round ends.
+        /// Since the last participant to reveal may withhold their secret to change the seed, the
+        /// bond should exceed what biasing a seed is worth.
+        #[pallet::constant]
+        type CommitBond: Get<BalanceOf<Self>>;
+
+        /// Handler for the commit bonds of participants which didn't reveal their secret.
+        type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// The number of blocks in which participants can commit to their secret.
+        #[pallet::constant]
+        type CommitPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The number of blocks after the commit phase in which participants can reveal their
+        /// secret.
+        #[pallet::constant]
+        type RevealPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The maximum number of commitments per round.
+        #[pallet::constant]
+        type MaxParticipants: Get<u32>;
+
+        type WeightInfo: WeightInfoZeitgeist;
+    }
+
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+    pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+    pub(crate) type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+    pub(crate) type NegativeImbalanceOf<T> =
+        <<T as Config>::Currency as Currency<AccountIdOf<T>>>::NegativeImbalance;
+    pub(crate) type CommitmentOf<T> = Commitment<<T as frame_system::Config>::Hash, BalanceOf<T>>;
+    pub(crate) type RoundInfoOf<T> = RoundInfo<BlockNumberFor<T>>;
+    pub(crate) type BeaconOutputOf<T> =
+        BeaconOutput<<T as frame_system::Config>::Hash, BlockNumberFor<T>>;
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    /// The phases of the current round.
+    #[pallet::storage]
+    pub type CurrentRound<T: Config> = StorageValue<_, RoundInfoOf<T>, ValueQuery>;
+
+    /// The commitments of the current round which were not revealed yet.
+    #[pallet::storage]
+    pub type Commitments<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccountIdOf<T>, CommitmentOf<T>, OptionQuery>;
+
+    /// The number of commitments of the current round, including the revealed ones.
+    #[pallet::storage]
+    pub type CommitmentCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// The combination of the secrets revealed in the current round.
+    #[pallet::storage]
+    pub type RevealedSecrets<T: Config> = StorageValue<_, T::Hash, ValueQuery>;
+
+    /// The number of secrets revealed in the current round.
+    #[pallet::storage]
+    pub type RevealCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// The latest output of the beacon.
+    #[pallet::storage]
+    pub type Output<T: Config> = StorageValue<_, BeaconOutputOf<T>, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(fn deposit_event)]
+    pub enum Event<T>
+    where
+        T: Config,
+    {
+        /// A participant committed to a secret.
+        Committed { round: u64, who: AccountIdOf<T> },
+        /// A participant revealed their secret.
+        Revealed { round: u64, who: AccountIdOf<T> },
+        /// The commit bond of a participant which didn't reveal their secret was slashed.
+        CommitBondSlashed { round: u64, who: AccountIdOf<T>, amount: BalanceOf<T> },
+        /// A round ended. The seed was updated if `revealed` isn't zero.
+        RoundFinalized { round: u64, revealed: u32, unrevealed: u32 },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The sender is not allowed to contribute to the beacon.
+        NotAParticipant,
+        /// The commit phase of the current round is over.
+        NotInCommitPhase,
+        /// The current round is not in its reveal phase.
+        NotInRevealPhase,
+        /// The sender already committed to a secret in the current round.
+        AlreadyCommitted,
+        /// The maximum number of commitments of the current round is reached.
+        TooManyCommitments,
+        /// The sender has no unrevealed commitment in the current round.
+        CommitmentNotFound,
+        /// The revealed secret doesn't match the commitment.
+        InvalidReveal,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            if now < CurrentRound::<T>::get().reveal_end {
+                return T::DbWeight::get().reads(1);
+            }
+            let commitments = Self::finalize_round(now);
+            T::WeightInfo::on_initialize(commitments)
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Commit to a secret during the commit phase of the current round. Reserves the
+        /// `CommitBond` of the sender until the secret is revealed.
+        ///
+        /// # Arguments
+        ///
+        /// - `commitment`: The hash of the tuple of the sender's account and the secret, see
+        ///   [`Pallet::commitment_of`].
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::commit())]
+        #[frame_support::transactional]
+        pub fn commit(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(T::Participants::contains(&who), Error::<T>::NotAParticipant);
+            let round = CurrentRound::<T>::get();
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now < round.commit_end, Error::<T>::NotInCommitPhase);
+            ensure!(!Commitments::<T>::contains_key(&who), Error::<T>::AlreadyCommitted);
+            let count = CommitmentCount::<T>::get();
+            ensure!(count < T::MaxParticipants::get(), Error::<T>::TooManyCommitments);
+
+            let bond = T::CommitBond::get();
+            T::Currency::reserve(&who, bond)?;
+            Commitments::<T>::insert(&who, Commitment { hash: commitment, bond });
+            CommitmentCount::<T>::put(count.saturating_add(1));
+
+            Self::deposit_event(Event::Committed { round: round.index, who });
+
+            Ok(())
+        }
+
+        /// Reveal the secret committed to during the commit phase of the current round and
+        /// unreserve the commit bond.
+        ///
+        /// # Arguments
+        ///
+        /// - `secret`: The secret the sender committed to.
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::reveal())]
+        #[frame_support::transactional]
+        pub fn reveal(origin: OriginFor<T>, secret: T::Hash) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let round = CurrentRound::<T>::get();
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                round.commit_end <= now && now < round.reveal_end,
+                Error::<T>::NotInRevealPhase
+            );
+            let commitment = Commitments::<T>::get(&who).ok_or(Error::<T>::CommitmentNotFound)?;
+            ensure!(
+                commitment.hash == Self::commitment_of(&who, &secret),
+                Error::<T>::InvalidReveal
+            );
+
+            Commitments::<T>::remove(&who);
+            T::Currency::unreserve(&who, commitment.bond);
+            RevealedSecrets::<T>::mutate(|revealed| {
+                // XOR is commutative, so the order in which the secrets are revealed doesn't
+                // influence the seed.
+                *revealed = *revealed ^ T::Hashing::hash_of(&secret);
+            });
+            RevealCount::<T>::mutate(|count| *count = count.saturating_add(1));
+
+            Self::deposit_event(Event::Revealed { round: round.index, who });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The commitment of `who` to `secret`. The account is part of the commitment so that
+        /// participants can't copy each other's commitments.
+        pub fn commitment_of(who: &AccountIdOf<T>, secret: &T::Hash) -> T::Hash {
+            T::Hashing::hash_of(&(who, secret))
+        }
+
+        /// Combines the secrets revealed in the current round into a new seed, slashes the bonds of
+        /// the unrevealed commitments and starts the next round. Returns the number of commitments
+        /// of the round.
+        pub(crate) fn finalize_round(now: BlockNumberFor<T>) -> u32 {
+            let round = CurrentRound::<T>::get();
+            let commitments = CommitmentCount::<T>::take();
+            let revealed = RevealCount::<T>::take();
+            let revealed_secrets = RevealedSecrets::<T>::take();
+            // Unrevealed commitments are the only ones left in storage. There are at most
+            // `MaxParticipants` of them.
+            let mut slashed = NegativeImbalanceOf::<T>::zero();
+            for (who, commitment) in Commitments::<T>::drain() {
+                let (imbalance, _) = T::Currency::slash_reserved(&who, commitment.bond);
+                Self::deposit_event(Event::CommitBondSlashed {
+                    round: round.index,
+                    who,
+                    amount: imbalance.peek(),
+                });
+                slashed.subsume(imbalance);
+            }
+            T::Slash::on_unbalanced(slashed);
+
+            if !revealed.is_zero() {
+                let previous_seed = Output::<T>::get().map(|o| o.seed).unwrap_or_default();
+                let seed = T::Hashing::hash_of(&(previous_seed, revealed_secrets, round.index));
+                Output::<T>::put(BeaconOutput { round: round.index, seed, block: now });
+            }
+
+            Self::deposit_event(Event::RoundFinalized {
+                round: round.index,
+                revealed,
+                unrevealed: commitments.saturating_sub(revealed),
+            });
+
+            let commit_end = now.saturating_add(T::CommitPeriod::get());
+            CurrentRound::<T>::put(RoundInfo {
+                index: round.index.saturating_add(1),
+                commit_end,
+                reveal_end: commit_end.saturating_add(T::RevealPeriod::get()),
+            });
+
+            commitments
+        }
+    }
+
+    impl<T: Config> Randomness<T::Hash, BlockNumberFor<T>> for Pallet<T> {
+        /// Returns a value derived from `subject` and the latest seed of the beacon, along with
+        /// the block in which the seed became known.
+        fn random(subject: &[u8]) -> (T::Hash, BlockNumberFor<T>) {
+            let (seed, block) = Output::<T>::get()
+                .map(|o| (o.seed, o.block))
+                .unwrap_or_else(|| (Default::default(), Zero::zero()));
+            (T::Hashing::hash_of(&(subject, seed)), block)
+        }
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use crate as pallet_pm_randomness_beacon;
+use common_primitives::types::{Balance, Hash};
+use frame_support::{
+    construct_runtime,
+    traits::{Contains, Everything, Hooks},
+};
+use frame_system::mocking::MockBlockU32;
+use prediction_market_primitives::{
+    constants::mock::{
+        BeaconCommitBond, BeaconCommitPeriod, BeaconRevealPeriod, BlockHashCount,
+        ExistentialDeposit, MaxBeaconParticipants, MaxLocks, MaxReserves, BASE,
+    },
+    types::AccountIdTest,
+};
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+
+pub const ALICE: AccountIdTest = 0;
+pub const BOB: AccountIdTest = 1;
+pub const CHARLIE: AccountIdTest = 2;
+pub const DAVE: AccountIdTest = 3;
+pub const EVE: AccountIdTest = 4;
+/// An account which isn't allowed to contribute to the beacon.
+pub const OUTSIDER: AccountIdTest = 9;
+
+pub struct MockParticipants;
+
+impl Contains<AccountIdTest> for MockParticipants {
+    fn contains(who: &AccountIdTest) -> bool {
+        *who != OUTSIDER
+    }
+}
+
+construct_runtime!(
+    pub enum Runtime {
+        Balances: pallet_balances,
+        RandomnessBeacon: pallet_pm_randomness_beacon,
+        System: frame_system,
+    }
+);
+
+impl crate::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Participants = MockParticipants;
+    type Currency = Balances;
+    type CommitBond = BeaconCommitBond;
+    type Slash = ();
+    type CommitPeriod = BeaconCommitPeriod;
+    type RevealPeriod = BeaconRevealPeriod;
+    type MaxParticipants = MaxBeaconParticipants;
+    type WeightInfo = crate::weights::WeightInfo<Runtime>;
+}
+
+impl frame_system::Config for Runtime {
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type AccountId = AccountIdTest;
+    type BaseCallFilter = Everything;
+    type Block = MockBlockU32<Runtime>;
+    type BlockHashCount = BlockHashCount;
+    type BlockLength = ();
+    type BlockWeights = ();
+    type RuntimeCall = RuntimeCall;
+    type DbWeight = ();
+    type RuntimeEvent = RuntimeEvent;
+    type Hash = Hash;
+    type Hashing = BlakeTwo256;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Nonce = u64;
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type OnKilledAccount = ();
+    type OnNewAccount = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletInfo = PalletInfo;
+    type SS58Prefix = ();
+    type SystemWeightInfo = ();
+    type Version = ();
+    type OnSetCode = ();
+}
+
+impl pallet_balances::Config for Runtime {
+    type AccountStore = System;
+    type Balance = Balance;
+    type DustRemoval = ();
+    type FreezeIdentifier = ();
+    type RuntimeHoldReason = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ExistentialDeposit;
+    type MaxHolds = ();
+    type MaxFreezes = ();
+    type MaxLocks = MaxLocks;
+    type MaxReserves = MaxReserves;
+    type ReserveIdentifier = [u8; 8];
+    type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+    balances: Vec<(AccountIdTest, Balance)>,
+}
+
+impl Default for ExtBuilder {
+    fn default() -> Self {
+        Self {
+            balances: [ALICE, BOB, CHARLIE, DAVE, EVE, OUTSIDER]
+                .into_iter()
+                .map(|who| (who, 1_000 * BASE))
+                .collect(),
+        }
+    }
+}
+
+impl ExtBuilder {
+    pub fn build(self) -> sp_io::TestExternalities {
+        let mut t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+        pallet_balances::GenesisConfig::<Runtime> { balances: self.balances }
+            .assimilate_storage(&mut t)
+            .unwrap();
+        let mut t: sp_io::TestExternalities = t.into();
+        t.execute_with(|| {
+            // required to assert for events
+            System::set_block_number(1);
+            // start the first round
+            RandomnessBeacon::on_initialize(1);
+        });
+        t
+    }
+}
+
+pub fn run_to_block(n: u32) {
+    while System::block_number() < n {
+        System::set_block_number(System::block_number() + 1);
+        RandomnessBeacon::on_initialize(System::block_number());
+    }
+}
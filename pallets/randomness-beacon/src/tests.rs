@@ -0,0 +1,347 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use crate::{
+    mock::*, Commitment, CommitmentCount, Commitments, CurrentRound, Error, Event, Output,
+    RoundInfo,
+};
+use common_primitives::types::Hash;
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Currency, Randomness, ReservableCurrency},
+};
+use prediction_market_primitives::{
+    constants::mock::{
+        BeaconCommitBond, BeaconCommitPeriod, BeaconRevealPeriod, MaxBeaconParticipants,
+    },
+    types::AccountIdTest,
+};
+use sp_runtime::traits::{BlakeTwo256, Hash as HashT};
+
+fn secret(n: u8) -> Hash {
+    BlakeTwo256::hash(&[n])
+}
+
+fn commit(who: AccountIdTest, secret: Hash) {
+    let commitment = RandomnessBeacon::commitment_of(&who, &secret);
+    assert_ok!(RandomnessBeacon::commit(RuntimeOrigin::signed(who), commitment));
+}
+
+fn reveal(who: AccountIdTest, secret: Hash) {
+    assert_ok!(RandomnessBeacon::reveal(RuntimeOrigin::signed(who), secret));
+}
+
+fn run_to_reveal_phase() {
+    run_to_block(CurrentRound::<Runtime>::get().commit_end);
+}
+
+fn run_to_round_end() {
+    run_to_block(CurrentRound::<Runtime>::get().reveal_end);
+}
+
+/// Runs a full round in which `secrets` are committed and revealed in the given order and
+/// returns the resulting seed.
+fn run_round(secrets: &[(AccountIdTest, Hash)]) -> Hash {
+    for (who, secret) in secrets {
+        commit(*who, *secret);
+    }
+    run_to_reveal_phase();
+    for (who, secret) in secrets {
+        reveal(*who, *secret);
+    }
+    run_to_round_end();
+    Output::<Runtime>::get().unwrap().seed
+}
+
+#[test]
+fn first_round_starts_at_genesis() {
+    ExtBuilder::default().build().execute_with(|| {
+        let commit_end = 1 + BeaconCommitPeriod::get();
+        assert_eq!(
+            CurrentRound::<Runtime>::get(),
+            RoundInfo { index: 1, commit_end, reveal_end: commit_end + BeaconRevealPeriod::get() }
+        );
+        assert_eq!(Output::<Runtime>::get(), None);
+    });
+}
+
+#[test]
+fn commit_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let commitment = RandomnessBeacon::commitment_of(&ALICE, &secret(0));
+        assert_ok!(RandomnessBeacon::commit(RuntimeOrigin::signed(ALICE), commitment));
+        let bond = BeaconCommitBond::get();
+        assert_eq!(Commitments::<Runtime>::get(ALICE), Some(Commitment { hash: commitment, bond }));
+        assert_eq!(CommitmentCount::<Runtime>::get(), 1);
+        assert_eq!(Balances::reserved_balance(ALICE), bond);
+        System::assert_last_event(Event::Committed { round: 1, who: ALICE }.into());
+    });
+}
+
+#[test]
+fn commit_fails_if_sender_is_not_a_participant() {
+    ExtBuilder::default().build().execute_with(|| {
+        let commitment = RandomnessBeacon::commitment_of(&OUTSIDER, &secret(0));
+        assert_noop!(
+            RandomnessBeacon::commit(RuntimeOrigin::signed(OUTSIDER), commitment),
+            Error::<Runtime>::NotAParticipant
+        );
+    });
+}
+
+#[test]
+fn commit_fails_if_sender_cannot_reserve_the_bond() {
+    ExtBuilder::default().build().execute_with(|| {
+        Balances::make_free_balance_be(&ALICE, BeaconCommitBond::get() - 1);
+        let commitment = RandomnessBeacon::commitment_of(&ALICE, &secret(0));
+        assert_noop!(
+            RandomnessBeacon::commit(RuntimeOrigin::signed(ALICE), commitment),
+            pallet_balances::Error::<Runtime>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn commit_fails_after_commit_phase() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_reveal_phase();
+        let commitment = RandomnessBeacon::commitment_of(&ALICE, &secret(0));
+        assert_noop!(
+            RandomnessBeacon::commit(RuntimeOrigin::signed(ALICE), commitment),
+            Error::<Runtime>::NotInCommitPhase
+        );
+    });
+}
+
+#[test]
+fn commit_fails_if_sender_already_committed() {
+    ExtBuilder::default().build().execute_with(|| {
+        commit(ALICE, secret(0));
+        let commitment = RandomnessBeacon::commitment_of(&ALICE, &secret(1));
+        assert_noop!(
+            RandomnessBeacon::commit(RuntimeOrigin::signed(ALICE), commitment),
+            Error::<Runtime>::AlreadyCommitted
+        );
+    });
+}
+
+#[test]
+fn commit_fails_on_too_many_commitments() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(MaxBeaconParticipants::get(), 4);
+        for who in [ALICE, BOB, CHARLIE, DAVE] {
+            commit(who, secret(who as u8));
+        }
+        let commitment = RandomnessBeacon::commitment_of(&EVE, &secret(0));
+        assert_noop!(
+            RandomnessBeacon::commit(RuntimeOrigin::signed(EVE), commitment),
+            Error::<Runtime>::TooManyCommitments
+        );
+    });
+}
+
+#[test]
+fn reveal_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        commit(ALICE, secret(0));
+        run_to_reveal_phase();
+        assert_ok!(RandomnessBeacon::reveal(RuntimeOrigin::signed(ALICE), secret(0)));
+        assert_eq!(Commitments::<Runtime>::get(ALICE), None);
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        System::assert_last_event(Event::Revealed { round: 1, who: ALICE }.into());
+    });
+}
+
+#[test]
+fn reveal_fails_during_commit_phase() {
+    ExtBuilder::default().build().execute_with(|| {
+        commit(ALICE, secret(0));
+        assert_noop!(
+            RandomnessBeacon::reveal(RuntimeOrigin::signed(ALICE), secret(0)),
+            Error::<Runtime>::NotInRevealPhase
+        );
+    });
+}
+
+#[test]
+fn reveal_fails_without_commitment() {
+    ExtBuilder::default().build().execute_with(|| {
+        run_to_reveal_phase();
+        assert_noop!(
+            RandomnessBeacon::reveal(RuntimeOrigin::signed(ALICE), secret(0)),
+            Error::<Runtime>::CommitmentNotFound
+        );
+    });
+}
+
+#[test]
+fn reveal_fails_if_already_revealed() {
+    ExtBuilder::default().build().execute_with(|| {
+        commit(ALICE, secret(0));
+        run_to_reveal_phase();
+        reveal(ALICE, secret(0));
+        assert_noop!(
+            RandomnessBeacon::reveal(RuntimeOrigin::signed(ALICE), secret(0)),
+            Error::<Runtime>::CommitmentNotFound
+        );
+    });
+}
+
+#[test]
+fn reveal_fails_on_secret_which_does_not_match_commitment() {
+    ExtBuilder::default().build().execute_with(|| {
+        commit(ALICE, secret(0));
+        run_to_reveal_phase();
+        assert_noop!(
+            RandomnessBeacon::reveal(RuntimeOrigin::signed(ALICE), secret(1)),
+            Error::<Runtime>::InvalidReveal
+        );
+    });
+}
+
+#[test]
+fn reveal_fails_on_copied_commitment() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_commitment = RandomnessBeacon::commitment_of(&ALICE, &secret(0));
+        assert_ok!(RandomnessBeacon::commit(RuntimeOrigin::signed(ALICE), alice_commitment));
+        assert_ok!(RandomnessBeacon::commit(RuntimeOrigin::signed(BOB), alice_commitment));
+        run_to_reveal_phase();
+        reveal(ALICE, secret(0));
+        assert_noop!(
+            RandomnessBeacon::reveal(RuntimeOrigin::signed(BOB), secret(0)),
+            Error::<Runtime>::InvalidReveal
+        );
+    });
+}
+
+#[test]
+fn seed_is_unknown_until_the_round_ends() {
+    ExtBuilder::default().build().execute_with(|| {
+        let subject = b"subject";
+        let (random_before, known_since_before) = RandomnessBeacon::random(subject);
+        assert_eq!(known_since_before, 0);
+
+        commit(ALICE, secret(0));
+        commit(BOB, secret(1));
+        run_to_reveal_phase();
+        reveal(ALICE, secret(0));
+        reveal(BOB, secret(1));
+
+        // All secrets are revealed, but the output doesn't change before the round ends.
+        run_to_block(CurrentRound::<Runtime>::get().reveal_end - 1);
+        assert_eq!(RandomnessBeacon::random(subject), (random_before, known_since_before));
+
+        let reveal_end = CurrentRound::<Runtime>::get().reveal_end;
+        run_to_round_end();
+        let (random_after, known_since_after) = RandomnessBeacon::random(subject);
+        assert_ne!(random_after, random_before);
+        assert_eq!(known_since_after, reveal_end);
+        assert_eq!(Output::<Runtime>::get().unwrap().round, 1);
+        System::assert_last_event(
+            Event::RoundFinalized { round: 1, revealed: 2, unrevealed: 0 }.into(),
+        );
+    });
+}
+
+#[test]
+fn seed_depends_on_every_revealed_secret() {
+    let seed_of = |secrets: &[(AccountIdTest, Hash)]| {
+        ExtBuilder::default().build().execute_with(|| run_round(secrets))
+    };
+    let seed = seed_of(&[(ALICE, secret(0)), (BOB, secret(1)), (CHARLIE, secret(2))]);
+    for (index, other_secret) in [(0, secret(3)), (1, secret(4)), (2, secret(5))] {
+        let mut secrets = vec![(ALICE, secret(0)), (BOB, secret(1)), (CHARLIE, secret(2))];
+        secrets[index].1 = other_secret;
+        assert_ne!(seed_of(&secrets[..]), seed);
+    }
+}
+
+#[test]
+fn seed_does_not_depend_on_reveal_order() {
+    let seed_of = |secrets: &[(AccountIdTest, Hash)]| {
+        ExtBuilder::default().build().execute_with(|| run_round(secrets))
+    };
+    assert_eq!(
+        seed_of(&[(ALICE, secret(0)), (BOB, secret(1))]),
+        seed_of(&[(BOB, secret(1)), (ALICE, secret(0))])
+    );
+}
+
+#[test]
+fn seed_changes_every_round() {
+    ExtBuilder::default().build().execute_with(|| {
+        let first_seed = run_round(&[(ALICE, secret(0))]);
+        let second_seed = run_round(&[(ALICE, secret(0))]);
+        assert_ne!(first_seed, second_seed);
+        assert_eq!(Output::<Runtime>::get().unwrap().round, 2);
+    });
+}
+
+#[test]
+fn seed_is_kept_if_no_secret_is_revealed() {
+    ExtBuilder::default().build().execute_with(|| {
+        let seed = run_round(&[(ALICE, secret(0))]);
+        commit(ALICE, secret(1));
+        run_to_round_end();
+        assert_eq!(Output::<Runtime>::get().map(|o| (o.round, o.seed)), Some((1, seed)));
+        System::assert_last_event(
+            Event::RoundFinalized { round: 2, revealed: 0, unrevealed: 1 }.into(),
+        );
+    });
+}
+
+#[test]
+fn unrevealed_commitments_are_cleared_at_the_end_of_the_round() {
+    ExtBuilder::default().build().execute_with(|| {
+        commit(ALICE, secret(0));
+        commit(BOB, secret(1));
+        run_to_reveal_phase();
+        reveal(ALICE, secret(0));
+        run_to_round_end();
+        System::assert_last_event(
+            Event::RoundFinalized { round: 1, revealed: 1, unrevealed: 1 }.into(),
+        );
+        assert_eq!(Commitments::<Runtime>::get(BOB), None);
+        assert_eq!(CommitmentCount::<Runtime>::get(), 0);
+
+        // BOB can take part in the next round.
+        commit(BOB, secret(2));
+    });
+}
+
+#[test]
+fn bonds_of_unrevealed_commitments_are_slashed_at_the_end_of_the_round() {
+    ExtBuilder::default().build().execute_with(|| {
+        let bond = BeaconCommitBond::get();
+        let alice_balance = Balances::total_balance(&ALICE);
+        let bob_balance = Balances::total_balance(&BOB);
+        commit(ALICE, secret(0));
+        commit(BOB, secret(1));
+        run_to_reveal_phase();
+        reveal(ALICE, secret(0));
+        run_to_round_end();
+        System::assert_has_event(
+            Event::CommitBondSlashed { round: 1, who: BOB, amount: bond }.into(),
+        );
+        assert_eq!(Balances::total_balance(&ALICE), alice_balance);
+        assert_eq!(Balances::reserved_balance(ALICE), 0);
+        assert_eq!(Balances::total_balance(&BOB), bob_balance - bond);
+        assert_eq!(Balances::reserved_balance(BOB), 0);
+    });
+}
@@ -0,0 +1,50 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// The phases of a round of the beacon. Commitments are accepted until `commit_end` (exclusive)
+/// and reveals from `commit_end` until `reveal_end` (exclusive).
+#[derive(
+    Clone, Copy, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo,
+)]
+pub struct RoundInfo<BlockNumber> {
+    pub index: u64,
+    pub commit_end: BlockNumber,
+    pub reveal_end: BlockNumber,
+}
+
+/// The seed produced by a round of the beacon.
+#[derive(Clone, Copy, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct BeaconOutput<Hash, BlockNumber> {
+    /// The round which produced the seed.
+    pub round: u64,
+    pub seed: Hash,
+    /// The block in which the seed became known.
+    pub block: BlockNumber,
+}
+
+/// The commitment of a participant to a secret.
+#[derive(Clone, Copy, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct Commitment<Hash, Balance> {
+    /// The hash of the account of the participant and their secret.
+    pub hash: Hash,
+    /// The bond reserved when committing.
+    pub bond: Balance,
+}
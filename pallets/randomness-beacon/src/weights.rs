@@ -0,0 +1,131 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+//! Estimated weights for pallet_pm_randomness_beacon
+//!
+//! This pallet wasn't run through the benchmark CLI yet, so none of the weights below were
+//! generated by it. Regenerate this file with the command below before deploying the pallet.
+
+// Command:
+// ./target/production/zeitgeist
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_pm_randomness_beacon
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --template=./misc/weight_template.hbs
+// --output=./pallets/randomness-beacon/src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use core::marker::PhantomData;
+use frame_support::{traits::Get, weights::Weight};
+
+///  Trait containing the required functions for weight retrival within
+/// pallet_pm_randomness_beacon
+pub trait WeightInfoZeitgeist {
+    fn commit() -> Weight;
+    fn reveal() -> Weight;
+    fn on_initialize(c: u32) -> Weight;
+}
+
+/// Weight functions for pallet_pm_randomness_beacon
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
+    /// Not benchmarked yet. Estimated from the storage accesses below.
+    ///
+    /// Storage: `RandomnessBeacon::CurrentRound` (r:1 w:0)
+    /// Proof: `RandomnessBeacon::CurrentRound` (`max_values`: Some(1), `max_size`: Some(16),
+    /// added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `RandomnessBeacon::Commitments` (r:1 w:1)
+    /// Proof: `RandomnessBeacon::Commitments` (`max_values`: None, `max_size`: Some(96), added:
+    /// 2571, mode: `MaxEncodedLen`)
+    /// Storage: `RandomnessBeacon::CommitmentCount` (r:1 w:1)
+    /// Proof: `RandomnessBeacon::CommitmentCount` (`max_values`: Some(1), `max_size`: Some(4),
+    /// added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+    /// `MaxEncodedLen`)
+    fn commit() -> Weight {
+        Weight::from_parts(39_850_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Not benchmarked yet. Estimated from the storage accesses below.
+    ///
+    /// Storage: `RandomnessBeacon::CurrentRound` (r:1 w:0)
+    /// Proof: `RandomnessBeacon::CurrentRound` (`max_values`: Some(1), `max_size`: Some(16),
+    /// added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `RandomnessBeacon::Commitments` (r:1 w:1)
+    /// Proof: `RandomnessBeacon::Commitments` (`max_values`: None, `max_size`: Some(96), added:
+    /// 2571, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+    /// `MaxEncodedLen`)
+    /// Storage: `RandomnessBeacon::RevealedSecrets` (r:1 w:1)
+    /// Proof: `RandomnessBeacon::RevealedSecrets` (`max_values`: Some(1), `max_size`: Some(32),
+    /// added: 527, mode: `MaxEncodedLen`)
+    /// Storage: `RandomnessBeacon::RevealCount` (r:1 w:1)
+    /// Proof: `RandomnessBeacon::RevealCount` (`max_values`: Some(1), `max_size`: Some(4), added:
+    /// 499, mode: `MaxEncodedLen`)
+    fn reveal() -> Weight {
+        Weight::from_parts(44_390_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    /// Not benchmarked yet. Estimated from the storage accesses below, with `c` being the number
+    /// of commitments of the round, all of which may be left unrevealed and slashed into the
+    /// treasury.
+    ///
+    /// Storage: `RandomnessBeacon::CurrentRound` (r:1 w:1)
+    /// Proof: `RandomnessBeacon::CurrentRound` (`max_values`: Some(1), `max_size`: Some(16),
+    /// added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `RandomnessBeacon::CommitmentCount` (r:1 w:1)
+    /// Proof: `RandomnessBeacon::CommitmentCount` (`max_values`: Some(1), `max_size`: Some(4),
+    /// added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `RandomnessBeacon::RevealCount` (r:1 w:1)
+    /// Proof: `RandomnessBeacon::RevealCount` (`max_values`: Some(1), `max_size`: Some(4), added:
+    /// 499, mode: `MaxEncodedLen`)
+    /// Storage: `RandomnessBeacon::RevealedSecrets` (r:1 w:1)
+    /// Proof: `RandomnessBeacon::RevealedSecrets` (`max_values`: Some(1), `max_size`: Some(32),
+    /// added: 527, mode: `MaxEncodedLen`)
+    /// Storage: `RandomnessBeacon::Commitments` (r:257 w:256)
+    /// Proof: `RandomnessBeacon::Commitments` (`max_values`: None, `max_size`: Some(96), added:
+    /// 2571, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:257 w:257)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode:
+    /// `MaxEncodedLen`)
+    /// Storage: `RandomnessBeacon::Output` (r:1 w:1)
+    /// Proof: `RandomnessBeacon::Output` (`max_values`: Some(1), `max_size`: Some(44), added:
+    /// 539, mode: `MaxEncodedLen`)
+    /// The range of component `c` is `[0, 256]`.
+    fn on_initialize(c: u32) -> Weight {
+        Weight::from_parts(27_840_000, 4132)
+            .saturating_add(Weight::from_parts(21_774_305, 0).saturating_mul(c.into()))
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+            .saturating_add(T::DbWeight::get().writes(6))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+            .saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
+    }
+}
@@ -20,7 +20,7 @@
 use crate::traits::NodeAssetEnumerator;
 use crate::{
     traits::PoolSharesId,
-    types::{CategoryIndex, PoolId},
+    types::{CategoryIndex, CombinatorialId, PoolId},
 };
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
@@ -48,7 +48,7 @@ use serde::{Deserialize, Serialize};
 pub enum Asset<MarketId> {
     CategoricalOutcome(MarketId, CategoryIndex),
     ScalarOutcome(MarketId, ScalarPosition),
-    CombinatorialOutcome,
+    CombinatorialOutcome(CombinatorialId),
     PoolShare(PoolId),
     #[default]
     Tru,
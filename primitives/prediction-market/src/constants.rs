@@ -50,6 +50,10 @@ pub const ORDERBOOK_PALLET_ID: PalletId = PalletId(*b"tnf/ordb");
 /// Pallet identifier, mainly used for named balance reserves.
 pub const HYBRID_ROUTER_PALLET_ID: PalletId = PalletId(*b"tnf/hybr");
 
+// Parimutuel
+/// Pallet identifier, used to derive the pot accounts of the markets.
+pub const PARIMUTUEL_PALLET_ID: PalletId = PalletId(*b"tnf/prmt");
+
 // NeoSwaps
 pub const NS_PALLET_ID: PalletId = PalletId(*b"tnf/neos");
 
@@ -60,6 +64,8 @@ pub const TREASURY_PALLET_ID: PalletId = PalletId(*b"Treasury");
 pub const PM_PALLET_ID: PalletId = PalletId(*b"tnf/pred");
 /// Max. categories in a prediction market.
 pub const MAX_CATEGORIES: u16 = 64;
+/// Max. number of markets a combinatorial position can be conditioned on.
+pub const MAX_SPLIT_DEPTH: u32 = 8;
 /// The dispute_duration is time where users can dispute the outcome.
 /// Minimum block period for a dispute.
 pub const MIN_DISPUTE_DURATION: BlockNumber = 12 * BLOCKS_PER_HOUR;
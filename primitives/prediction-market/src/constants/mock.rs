@@ -35,6 +35,8 @@ use sp_arithmetic::Perbill;
 parameter_types! {
     pub const AuthorizedPalletId: PalletId = PalletId(*b"tnf/atzd");
     pub const CorrectionPeriod: BlockNumber = 4;
+    pub const MaxPanelMembers: u32 = 5;
+    pub const PanelReportPeriod: BlockNumber = 10;
 }
 
 // Court
@@ -55,13 +57,16 @@ parameter_types! {
     pub const MaxYearlyInflation: Perbill = Perbill::from_percent(10u32);
     pub const MinJurorStake: Balance = 50 * CENT_BASE;
     pub const InflationPeriod: BlockNumber = 20;
+    pub const ReputationInfluence: Perbill = Perbill::from_percent(50);
 }
 
 // Global disputes parameters
 parameter_types! {
     pub const AddOutcomePeriod: BlockNumber = 20;
+    pub const ConvictionLockPeriod: BlockNumber = 20;
     pub const GlobalDisputeLockId: LockIdentifier = *b"tnf/vote";
     pub const GlobalDisputesPalletId: PalletId = PalletId(*b"tnf/gldp");
+    pub const MaxGdDelegators: u32 = 10;
     pub const MaxGlobalDisputeVotes: u32 = 50;
     pub const MaxOwners: u32 = 10;
     pub const MinOutcomeVoteAmount: Balance = 10 * CENT_BASE;
@@ -80,7 +85,11 @@ parameter_types! {
 parameter_types! {
     pub storage NeoExitFee: Balance = CENT_BASE;
     pub const NeoMaxSwapFee: Balance = 10 * CENT_BASE;
+    pub const MaxIncentivePrograms: u32 = 4u32;
     pub const MaxLiquidityTreeDepth: u32 = 3u32;
+    pub const MaxObservations: u32 = 4u32;
+    pub const MaxRangePositions: u32 = 4u32;
+    pub const MaxVolumeTiers: u32 = 4u32;
     pub const NeoSwapsPalletId: PalletId = PalletId(*b"tnf/neos");
 }
 
@@ -102,6 +111,7 @@ parameter_types! {
     pub const MaxMarketLifetime: BlockNumber = 1_000_000_000;
     pub const MaxOracleDuration: BlockNumber = 30;
     pub const MaxRejectReasonLen: u32 = 1024;
+    pub const MaxSplitDepth: u32 = 4;
     pub const MinCategories: u16 = 2;
     pub const MinDisputeDuration: BlockNumber = 2;
     pub const MinOracleDuration: BlockNumber = 2;
@@ -127,6 +137,10 @@ parameter_types! {
 
 // Orderbook parameters
 parameter_types! {
+    pub const MaxOrdersPerBatch: u32 = 10;
+    pub const MaxOrdersPerMaker: u32 = 10;
+    pub const MaxOrdersPerMakerPerMarket: u32 = 5;
+    pub const MaxOrdersPerMarket: u32 = 200;
     pub const OrderbookPalletId: PalletId = PalletId(*b"tnf/ordb");
 }
 
@@ -136,6 +150,14 @@ parameter_types! {
     pub const MinBetSize: Balance = BASE;
 }
 
+// Randomness beacon parameters
+parameter_types! {
+    pub const BeaconCommitBond: Balance = 10 * BASE;
+    pub const BeaconCommitPeriod: BlockNumber = 5;
+    pub const BeaconRevealPeriod: BlockNumber = 5;
+    pub const MaxBeaconParticipants: u32 = 4;
+}
+
 // Shared within tests
 // Balance
 parameter_types! {
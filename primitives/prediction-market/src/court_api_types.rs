@@ -0,0 +1,58 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::pallet_prelude::*;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::per_things::Perbill;
+
+/// The track record of a juror in the court rounds they were drawn for. A round is counted when
+/// it's appealed or when the stakes of its court are reassigned.
+#[derive(Clone, Copy, Debug, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct JurorReputation {
+    /// The number of rounds in which the juror revealed a vote for the winning vote item.
+    pub majority_votes: u32,
+    /// The number of rounds in which the juror revealed a vote for another vote item.
+    pub minority_votes: u32,
+    /// The number of rounds in which the juror failed to vote or to reveal their vote.
+    pub missed_votes: u32,
+    /// The number of rounds in which the vote of the juror was denounced.
+    pub denounced_votes: u32,
+}
+
+impl JurorReputation {
+    /// The number of rounds the juror took part in.
+    pub fn rounds(&self) -> u32 {
+        self.majority_votes
+            .saturating_add(self.minority_votes)
+            .saturating_add(self.missed_votes)
+            .saturating_add(self.denounced_votes)
+    }
+
+    /// The share of rounds in which the juror voted with the majority, calculated as
+    /// `(majority_votes + 1) / (rounds + 2)`. Jurors without track record start at one half, so
+    /// that fresh accounts don't outrank jurors who decided with the majority before.
+    pub fn score(&self) -> Perbill {
+        Perbill::from_rational(
+            self.majority_votes.saturating_add(1),
+            self.rounds().saturating_add(2),
+        )
+    }
+}
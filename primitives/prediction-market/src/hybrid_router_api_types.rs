@@ -15,10 +15,19 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{
+    orderbook::{OrderExpiry, OrderId},
+    types::{Asset, Moment},
+};
+use alloc::vec::Vec;
 use frame_support::pallet_prelude::*;
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct AmmTrade<Balance> {
     pub amount_in: Balance,
     pub amount_out: Balance,
@@ -27,18 +36,118 @@ pub struct AmmTrade<Balance> {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct ExternalFee<AccountId, Balance> {
     pub account: AccountId,
     pub amount: Balance,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct OrderbookTrade<AccountId, Balance> {
     pub filled_maker_amount: Balance,
     pub filled_taker_amount: Balance,
     pub external_fee: ExternalFee<AccountId, Balance>,
 }
 
+/// Represents the strategy used when placing an order in a trading environment.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum Strategy {
+    /// The trade is rolled back if it cannot be executed fully.
+    ImmediateOrCancel,
+    /// Partially fulfills the order if possible, placing the remainder in the order book. Favors
+    /// achieving a specific price rather than immediate execution.
+    LimitOrder,
+    /// Like `LimitOrder`, but the placed order can no longer be filled after the block with
+    /// this number.
+    GoodTilBlock(u64),
+    /// Like `LimitOrder`, but the placed order can no longer be filled after this moment.
+    GoodTilTime(Moment),
+}
+
+impl Strategy {
+    /// Returns the expiry of the order placed for the remainder, if any.
+    pub fn order_expiry(&self) -> Option<OrderExpiry> {
+        match self {
+            Strategy::ImmediateOrCancel | Strategy::LimitOrder => None,
+            Strategy::GoodTilBlock(block) => Some(OrderExpiry::Block(*block)),
+            Strategy::GoodTilTime(moment) => Some(OrderExpiry::Timestamp(*moment)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum TxType {
+    Buy,
+    Sell,
+}
+
+/// An order of the order book which the hybrid router fills during a trade.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct OrderFill<AccountId, Balance> {
+    pub order_id: OrderId,
+    pub filled_maker_amount: Balance,
+    pub filled_taker_amount: Balance,
+    pub external_fee: ExternalFee<AccountId, Balance>,
+}
+
+/// The limit order placed for the unfilled remainder of a trade under `Strategy::LimitOrder`.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct LimitOrderPlacement<MarketId, Balance> {
+    pub maker_asset: Asset<MarketId>,
+    pub maker_amount: Balance,
+    pub taker_asset: Asset<MarketId>,
+    pub taker_amount: Balance,
+    pub expiry: Option<OrderExpiry>,
+}
+
+/// Outcome of a hybrid router trade that was executed and rolled back.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TradeSimulation<AccountId, Balance, MarketId> {
+    /// The order book orders filled by the trade, in execution order.
+    pub order_fills: Vec<OrderFill<AccountId, Balance>>,
+    /// The trades executed against the AMM, in execution order.
+    pub amm_trades: Vec<AmmTrade<Balance>>,
+    /// The amount of the input asset consumed by order fills and AMM trades.
+    pub amount_in: Balance,
+    /// The amount of the output asset received after fees.
+    pub amount_out: Balance,
+    /// The external fees paid by the trader.
+    pub external_fee_amount: Balance,
+    /// The swap fees paid by the trader.
+    pub swap_fee_amount: Balance,
+    /// The average execution price in units of the base asset, including fees.
+    pub average_price: Balance,
+    /// The amount of the input asset which was not filled.
+    pub remaining: Balance,
+    /// The limit order placed for `remaining`, if any.
+    pub limit_order: Option<LimitOrderPlacement<MarketId, Balance>>,
+}
+
 pub trait FailSoft {}
 
 #[derive(Debug)]
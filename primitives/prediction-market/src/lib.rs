@@ -22,10 +22,12 @@ extern crate alloc;
 
 mod asset;
 pub mod constants;
+pub mod court_api_types;
 pub mod hybrid_router_api_types;
 mod market;
 pub mod math;
 mod max_runtime_usize;
+pub mod neo_swaps_api_types;
 pub mod orderbook;
 mod outcome_report;
 mod proxy_type;
@@ -21,10 +21,17 @@ use alloc::{vec, vec::Vec};
 use core::ops::{Range, RangeInclusive};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_arithmetic::per_things::Perbill;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{traits::ConstU32, BoundedVec, RuntimeDebug};
+
+/// The maximum number of oracles of a multi-oracle market.
+pub type MaxMarketOracles = ConstU32<16>;
 
 #[derive(Clone, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct Market<AccountId, Balance, BlockNumber, Moment, MarketId> {
     pub market_id: MarketId,
     /// Base asset of the market.
@@ -87,6 +94,25 @@ where
         }
     }
 
+    /// Returns the outcome asset of this market at `index`, or `None` if the market has no
+    /// outcome at `index`.
+    pub fn outcome_asset(&self, index: u16) -> Option<Asset<MarketId>> {
+        match self.market_type {
+            MarketType::Categorical(categories) if index < categories => match self.scoring_rule {
+                ScoringRule::AmmCdaHybrid =>
+                    Some(Asset::<MarketId>::CategoricalOutcome(self.market_id, index)),
+                ScoringRule::Parimutuel =>
+                    Some(Asset::<MarketId>::ParimutuelShare(self.market_id, index)),
+            },
+            MarketType::Categorical(_) => None,
+            MarketType::Scalar(_) => match index {
+                0 => Some(Asset::<MarketId>::ScalarOutcome(self.market_id, ScalarPosition::Long)),
+                1 => Some(Asset::<MarketId>::ScalarOutcome(self.market_id, ScalarPosition::Short)),
+                _ => None,
+            },
+        }
+    }
+
     /// Check if `outcome_report` matches the type of this market.
     pub fn matches_outcome_report(&self, outcome_report: &OutcomeReport) -> bool {
         match outcome_report {
@@ -100,6 +126,7 @@ where
             OutcomeReport::Scalar(_) => {
                 matches!(&self.market_type, MarketType::Scalar(_))
             },
+            OutcomeReport::Invalid => true,
         }
     }
 
@@ -162,13 +189,15 @@ where
                 ScoringRule::Parimutuel =>
                     Some(Asset::<MarketId>::ParimutuelShare(self.market_id, *idx)),
             },
-            OutcomeReport::Scalar(_) => None,
+            OutcomeReport::Scalar(_) | OutcomeReport::Invalid => None,
         }
     }
 }
 
 /// Tracks the status of a bond.
 #[derive(Clone, Decode, Encode, MaxEncodedLen, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct Bond<AccountId, Balance> {
     /// The account that reserved the bond.
     pub who: AccountId,
@@ -186,6 +215,8 @@ impl<AccountId, Balance> Bond<AccountId, Balance> {
 
 /// Tracks bonds associated with a prediction market.
 #[derive(Clone, Decode, Encode, MaxEncodedLen, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct MarketBonds<AccountId, Balance> {
     pub creation: Option<Bond<AccountId, Balance>>,
     pub oracle: Option<Bond<AccountId, Balance>>,
@@ -193,6 +224,8 @@ pub struct MarketBonds<AccountId, Balance> {
     pub dispute: Option<Bond<AccountId, Balance>>,
     pub close_request: Option<Bond<AccountId, Balance>>,
     pub close_dispute: Option<Bond<AccountId, Balance>>,
+    /// The bonds of the oracles of a multi-oracle market, in the order of its [`OracleSet`].
+    pub oracles: BoundedVec<Bond<AccountId, Balance>, MaxMarketOracles>,
 }
 
 impl<AccountId: Ord, Balance: frame_support::traits::tokens::Balance>
@@ -210,6 +243,12 @@ impl<AccountId: Ord, Balance: frame_support::traits::tokens::Balance>
             .saturating_add(value_or_default(&self.dispute))
             .saturating_add(value_or_default(&self.close_request))
             .saturating_add(value_or_default(&self.close_dispute))
+            .saturating_add(
+                self.oracles
+                    .iter()
+                    .filter(|bond| bond.who == *who)
+                    .fold(Balance::zero(), |acc, bond| acc.saturating_add(bond.value)),
+            )
     }
 }
 
@@ -223,6 +262,7 @@ impl<AccountId, Balance> Default for MarketBonds<AccountId, Balance> {
             dispute: None,
             close_request: None,
             close_dispute: None,
+            oracles: BoundedVec::default(),
         }
     }
 }
@@ -260,6 +300,8 @@ where
 
 /// Defines the type of market creation.
 #[derive(Clone, Decode, Encode, MaxEncodedLen, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum MarketCreation {
     // A completely permissionless market that requires a higher
     // validity bond. May resolve as `Invalid`.
@@ -291,6 +333,8 @@ pub struct MarketDispute<AccountId, BlockNumber, Balance> {
 
 /// How a market should resolve disputes
 #[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum MarketDisputeMechanism {
     Authorized,
     Court,
@@ -310,6 +354,8 @@ pub enum MarketDisputeMechanism {
 /// 3. With inclusive ranges it is not possible to express empty ranges and this feature
 /// mostly conflicts with existent tests and corner cases.
 #[derive(Clone, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum MarketPeriod<BlockNumber, Moment> {
     Block(Range<BlockNumber>),
     Timestamp(Range<Moment>),
@@ -328,6 +374,8 @@ impl<BlockNumber: MaxEncodedLen, Moment: MaxEncodedLen> MaxEncodedLen
 }
 
 #[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct EarlyClose<BlockNumber, Moment> {
     pub old: MarketPeriod<BlockNumber, Moment>,
     pub new: MarketPeriod<BlockNumber, Moment>,
@@ -335,6 +383,8 @@ pub struct EarlyClose<BlockNumber, Moment> {
 }
 
 #[derive(Clone, Decode, Encode, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum EarlyCloseState {
     ScheduledAsMarketCreator,
     ScheduledAsOther,
@@ -346,6 +396,8 @@ pub enum EarlyCloseState {
 #[derive(
     Clone, Copy, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo,
 )]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct Deadlines<BlockNumber> {
     pub grace_period: BlockNumber,
     pub oracle_duration: BlockNumber,
@@ -353,6 +405,8 @@ pub struct Deadlines<BlockNumber> {
 }
 
 #[derive(TypeInfo, Clone, Copy, Encode, Eq, Decode, MaxEncodedLen, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum ScoringRule {
     AmmCdaHybrid,
     Parimutuel,
@@ -360,6 +414,8 @@ pub enum ScoringRule {
 
 /// Defines the state of the market.
 #[derive(Clone, Copy, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum MarketStatus {
     /// The market has been proposed and is either waiting for approval
     /// from the governing committee, or hasn't reach its delay yet.
@@ -380,6 +436,8 @@ pub enum MarketStatus {
 /// Defines the type of market.
 /// All markets also have themin_assets_out `Invalid` resolution.
 #[derive(Clone, Decode, Encode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum MarketType {
     /// A market with a number of categorical outcomes.
     Categorical(u16),
@@ -394,12 +452,34 @@ impl MaxEncodedLen for MarketType {
 }
 
 #[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct Report<AccountId, BlockNumber> {
     pub at: BlockNumber,
     pub by: AccountId,
     pub outcome: OutcomeReport,
 }
 
+/// An oracle of a multi-oracle market.
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct WeightedOracle<AccountId> {
+    pub who: AccountId,
+    /// The weight of the oracle's report when the reports are aggregated.
+    pub weight: u32,
+}
+
+/// The oracles of a market which is resolved by aggregating the reports of several oracles.
+///
+/// Categorical markets resolve to the outcome with the highest reported weight, scalar markets to
+/// the weighted median of the reported values.
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct OracleSet<AccountId> {
+    pub oracles: BoundedVec<WeightedOracle<AccountId>, MaxMarketOracles>,
+    /// The combined weight of the reports after which the reports are aggregated without waiting
+    /// for the end of the oracle duration.
+    pub quorum: u32,
+}
+
 #[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct AuthorityReport<BlockNumber> {
     pub resolve_at: BlockNumber,
@@ -466,6 +546,18 @@ mod tests {
         false;
         "scalar market report is categorical"
     )]
+    #[test_case(
+        MarketType::Categorical(6),
+        OutcomeReport::Invalid,
+        true;
+        "categorical market invalid"
+    )]
+    #[test_case(
+        MarketType::Scalar(12..=34),
+        OutcomeReport::Invalid,
+        true;
+        "scalar market invalid"
+    )]
     fn market_matches_outcome_report(
         market_type: MarketType,
         outcome_report: OutcomeReport,
@@ -570,6 +662,13 @@ mod tests {
         None;
         "scalar_market"
     )]
+    #[test_case(
+        MarketType::Categorical(2),
+        ScoringRule::AmmCdaHybrid,
+        OutcomeReport::Invalid,
+        None;
+        "invalid_outcome"
+    )]
     fn converts_outcome_correctly(
         market_type: MarketType,
         scoring_rule: ScoringRule,
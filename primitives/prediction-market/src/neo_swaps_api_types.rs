@@ -0,0 +1,40 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::pallet_prelude::*;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Preview of a buy or sell executed against a neo-swaps pool.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct SwapQuote<Balance> {
+    /// The gross amount paid into the pool, including fees.
+    pub amount_in: Balance,
+    /// The amount received by the trader, net of fees.
+    pub amount_out: Balance,
+    /// The amount of fees credited to the pool's liquidity providers.
+    pub swap_fee_amount: Balance,
+    /// The amount of fees taken by `ExternalFees`.
+    pub external_fee_amount: Balance,
+    /// The spot price of the traded outcome before the trade.
+    pub spot_price_before: Balance,
+    /// The spot price of the traded outcome after the trade.
+    pub spot_price_after: Balance,
+}
@@ -17,15 +17,77 @@
 
 use crate::{
     math::fixed::{FixedDiv, FixedMulDiv},
-    types::Asset,
+    types::{Asset, Moment},
 };
+use alloc::vec::Vec;
 use parity_scale_codec::{Decode, Encode, HasCompact, MaxEncodedLen};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::{traits::AtLeast32BitUnsigned, DispatchError, RuntimeDebug};
 
 pub type OrderId = u128;
 
+/// The side of an order relative to the outcome asset it trades.
+///
+/// A bid offers the base asset for the outcome asset, an ask offers the outcome asset for the
+/// base asset.
+#[derive(Clone, Copy, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum OrderSide {
+    Bid,
+    Ask,
+}
+
+/// The last block or moment at which an order can be filled.
+#[derive(Clone, Copy, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum OrderExpiry {
+    /// The order expires after the block with this number.
+    Block(u64),
+    /// The order expires after this moment (in milliseconds since the Unix epoch).
+    Timestamp(Moment),
+}
+
+impl OrderExpiry {
+    /// Return `true` if the expiry lies before the `block` or before `now`.
+    pub fn is_expired(&self, block: u64, now: Moment) -> bool {
+        match self {
+            OrderExpiry::Block(expiry) => block > *expiry,
+            OrderExpiry::Timestamp(expiry) => now > *expiry,
+        }
+    }
+}
+
+/// How a batch of order operations treats items which fail.
+#[derive(Clone, Copy, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum BatchMode {
+    /// The whole batch fails if any of its items fails.
+    AllOrNothing,
+    /// Failing items are skipped and their changes reverted; the other items are executed.
+    SkipFailed,
+}
+
+/// The parameters of an order which is placed as part of a batch.
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct OrderParams<Balance, MarketId: MaxEncodedLen + HasCompact> {
+    pub market_id: MarketId,
+    pub maker_asset: Asset<MarketId>,
+    pub maker_amount: Balance,
+    pub taker_asset: Asset<MarketId>,
+    pub taker_amount: Balance,
+    pub expiry: Option<OrderExpiry>,
+}
+
 #[derive(Clone, Encode, Eq, Decode, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct Order<AccountId, Balance, MarketId: MaxEncodedLen + HasCompact> {
     pub market_id: MarketId,
     pub maker: AccountId,
@@ -33,6 +95,31 @@ pub struct Order<AccountId, Balance, MarketId: MaxEncodedLen + HasCompact> {
     pub maker_amount: Balance,
     pub taker_asset: Asset<MarketId>,
     pub taker_amount: Balance,
+    /// The order can no longer be filled after this expiry. `None` if the order never expires.
+    pub expiry: Option<OrderExpiry>,
+}
+
+impl<AccountId, Balance, MarketId: MaxEncodedLen + HasCompact + Copy>
+    Order<AccountId, Balance, MarketId>
+{
+    /// Return the side of the order.
+    ///
+    /// Orders always trade an outcome asset against the market's base asset, so an order whose
+    /// maker offers an outcome asset is an ask and every other order is a bid.
+    pub fn side(&self) -> OrderSide {
+        match self.maker_asset {
+            Asset::CategoricalOutcome(..) | Asset::ScalarOutcome(..) => OrderSide::Ask,
+            _ => OrderSide::Bid,
+        }
+    }
+
+    /// Return the outcome asset traded by the order.
+    pub fn outcome_asset(&self) -> Asset<MarketId> {
+        match self.side() {
+            OrderSide::Ask => self.maker_asset,
+            OrderSide::Bid => self.taker_asset,
+        }
+    }
 }
 
 impl<
@@ -111,6 +198,28 @@ impl<
     }
 }
 
+/// An open order together with its price in units of the base asset.
+#[derive(Clone, Encode, Eq, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PricedOrder<AccountId, Balance, MarketId: MaxEncodedLen + HasCompact> {
+    pub order_id: OrderId,
+    pub price: Balance,
+    pub order: Order<AccountId, Balance, MarketId>,
+}
+
+/// The open orders of an outcome asset, best price first.
+///
+/// Bids are sorted by descending price, asks by ascending price. Orders with the same price are
+/// sorted by ascending order ID.
+#[derive(Clone, Encode, Eq, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct OrderBookDepth<AccountId, Balance, MarketId: MaxEncodedLen + HasCompact> {
+    pub bids: Vec<PricedOrder<AccountId, Balance, MarketId>>,
+    pub asks: Vec<PricedOrder<AccountId, Balance, MarketId>>,
+}
+
 #[cfg(test)]
 mod tests {
     use common_primitives::{
@@ -121,7 +230,7 @@ mod tests {
     use test_case::test_case;
 
     use super::*;
-    use crate::types::{Asset, MarketId};
+    use crate::types::{Asset, MarketId, ScalarPosition};
 
     pub const BASE_ASSET: Asset<MarketId> = Asset::Tru;
 
@@ -138,6 +247,7 @@ mod tests {
             maker_amount: 100 * BASE,
             taker_asset,
             taker_amount: 50 * BASE,
+            expiry: None,
         };
 
         let price = order.price(maker_asset).unwrap();
@@ -147,6 +257,36 @@ mod tests {
         assert_eq!(price, 50 * CENT_BASE);
     }
 
+    #[test]
+    fn side_and_outcome_asset_work() {
+        let maker = AccountId::from([1u8; 32]);
+        let market_id = MarketId::default();
+        let outcome_asset: Asset<MarketId> = Asset::ScalarOutcome(market_id, ScalarPosition::Long);
+        let bid = Order {
+            market_id,
+            maker,
+            maker_asset: BASE_ASSET,
+            maker_amount: 100 * BASE,
+            taker_asset: outcome_asset,
+            taker_amount: 50 * BASE,
+            expiry: None,
+        };
+        assert_eq!(bid.side(), OrderSide::Bid);
+        assert_eq!(bid.outcome_asset(), outcome_asset);
+
+        let ask = Order { maker_asset: outcome_asset, taker_asset: BASE_ASSET, ..bid };
+        assert_eq!(ask.side(), OrderSide::Ask);
+        assert_eq!(ask.outcome_asset(), outcome_asset);
+    }
+
+    #[test_case(OrderExpiry::Block(5), 5, 10, false ; "block expiry is reached")]
+    #[test_case(OrderExpiry::Block(5), 6, 0, true ; "block expiry has passed")]
+    #[test_case(OrderExpiry::Timestamp(10), 6, 10, false ; "timestamp expiry is reached")]
+    #[test_case(OrderExpiry::Timestamp(10), 0, 11, true ; "timestamp expiry has passed")]
+    fn order_expiry_is_expired_works(expiry: OrderExpiry, block: u64, now: Moment, expected: bool) {
+        assert_eq!(expiry.is_expired(block, now), expected);
+    }
+
     #[test]
     fn price_throws_error_on_division_by_zero() {
         let maker = AccountId::from([1u8; 32]);
@@ -160,6 +300,7 @@ mod tests {
             maker_amount: 0u128,
             taker_asset,
             taker_amount: 0u128,
+            expiry: None,
         };
 
         let price = order.price(maker_asset);
@@ -182,6 +323,7 @@ mod tests {
             maker_amount: 100 * BASE,
             taker_asset,
             taker_amount: 50 * BASE,
+            expiry: None,
         };
 
         let unknown_asset = Asset::CategoricalOutcome(market_id, 1u16);
@@ -204,8 +346,15 @@ mod tests {
         let taker_asset: Asset<MarketId> = Asset::CategoricalOutcome(market_id, 0u16);
         let maker_amount = 100 * BASE;
         let taker_amount = 50 * BASE;
-        let order =
-            Order { market_id, maker, maker_asset, maker_amount, taker_asset, taker_amount };
+        let order = Order {
+            market_id,
+            maker,
+            maker_asset,
+            maker_amount,
+            taker_asset,
+            taker_amount,
+            expiry: None,
+        };
 
         let (taker_fill, maker_fill) =
             order.taker_and_maker_fill_from_maker_amount(sub_maker_amount).unwrap();
@@ -228,8 +377,15 @@ mod tests {
         let taker_asset: Asset<MarketId> = Asset::CategoricalOutcome(market_id, 0u16);
         let maker_amount = 100 * BASE;
         let taker_amount = 50 * BASE;
-        let order =
-            Order { market_id, maker, maker_asset, maker_amount, taker_asset, taker_amount };
+        let order = Order {
+            market_id,
+            maker,
+            maker_asset,
+            maker_amount,
+            taker_asset,
+            taker_amount,
+            expiry: None,
+        };
 
         let (taker_fill, maker_fill) =
             order.taker_and_maker_fill_from_taker_amount(sub_taker_amount).unwrap();
@@ -30,7 +30,12 @@ use crate::types::CategoryIndex;
     PartialEq,
     PartialOrd,
 )]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum OutcomeReport {
     Categorical(CategoryIndex),
     Scalar(u128),
+    /// The market is ambiguous or can't be resolved. Every outcome token of the market redeems
+    /// for an equal share of the collateral of a complete set.
+    Invalid,
 }
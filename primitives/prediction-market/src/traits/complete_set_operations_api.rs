@@ -15,10 +15,13 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
-use sp_runtime::DispatchResult;
+use crate::types::{CombinatorialId, IndexSet};
+use alloc::vec::Vec;
+use sp_runtime::{DispatchError, DispatchResult};
 
 pub trait CompleteSetOperationsApi {
     type AccountId;
+    type Asset;
     type Balance;
     type MarketId;
 
@@ -32,4 +35,35 @@ pub trait CompleteSetOperationsApi {
         market_id: Self::MarketId,
         amount: Self::Balance,
     ) -> DispatchResult;
+
+    /// Split `amount` units of the position of `parent_collection_id` into `amount` units of
+    /// each position obtained by conditioning it on `market_id` resolving to an outcome in one of
+    /// the index sets of `partition`. The root collection (`None`) is the market's base asset.
+    ///
+    /// If `partition` doesn't cover all outcomes of the market, the position conditioned on the
+    /// union of `partition` is split instead.
+    fn split_position(
+        who: Self::AccountId,
+        parent_collection_id: Option<CombinatorialId>,
+        market_id: Self::MarketId,
+        partition: Vec<IndexSet>,
+        amount: Self::Balance,
+    ) -> DispatchResult;
+
+    /// The inverse of [`CompleteSetOperationsApi::split_position`].
+    fn merge_position(
+        who: Self::AccountId,
+        parent_collection_id: Option<CombinatorialId>,
+        market_id: Self::MarketId,
+        partition: Vec<IndexSet>,
+        amount: Self::Balance,
+    ) -> DispatchResult;
+
+    /// Returns the asset of the position of `parent_collection_id` conditioned on `market_id`
+    /// resolving to an outcome in `index_set`.
+    fn position_asset(
+        parent_collection_id: Option<CombinatorialId>,
+        market_id: Self::MarketId,
+        index_set: IndexSet,
+    ) -> Result<Self::Asset, DispatchError>;
 }
@@ -15,6 +15,8 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
+use sp_runtime::traits::Saturating;
+
 /// Trait for distributing fees collected from trading to external recipients like the treasury.
 pub trait DistributeFees {
     type Asset;
@@ -40,4 +42,85 @@ pub trait DistributeFees {
         account: &Self::AccountId,
         amount: Self::Balance,
     ) -> Self::Balance;
+
+    /// Like `distribute`, but for fees which `account` pays on behalf of `trader`, for example a
+    /// liquidity pool which deducts the fees from the amount paid by the trader. Implementations
+    /// which charge account-specific fees must use `trader` to determine them.
+    ///
+    /// Defaults to `distribute`.
+    fn distribute_on_behalf_of(
+        market_id: Self::MarketId,
+        asset: Self::Asset,
+        account: &Self::AccountId,
+        _trader: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Self::Balance {
+        Self::distribute(market_id, asset, account, amount)
+    }
+
+    /// Returns the fees that `distribute` would deduct from `amount` without moving any funds.
+    ///
+    /// # Arguments
+    ///
+    /// - `market_id`: The market on which the fees are taken.
+    /// - `trader`: The account on whose behalf the fees are paid, as in `distribute_on_behalf_of`.
+    /// - `amount`: The gross amount from which fees are deducted.
+    fn fee_amount(
+        market_id: Self::MarketId,
+        trader: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Self::Balance;
+}
+
+/// Charges the fees of `A` and then the fees of `B`, both computed from the same gross amount.
+impl<A, B> DistributeFees for (A, B)
+where
+    A: DistributeFees,
+    A::Asset: Clone,
+    A::Balance: Saturating,
+    A::MarketId: Clone,
+    B: DistributeFees<
+        Asset = A::Asset,
+        AccountId = A::AccountId,
+        Balance = A::Balance,
+        MarketId = A::MarketId,
+    >,
+{
+    type Asset = A::Asset;
+    type AccountId = A::AccountId;
+    type Balance = A::Balance;
+    type MarketId = A::MarketId;
+
+    fn distribute(
+        market_id: Self::MarketId,
+        asset: Self::Asset,
+        account: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Self::Balance {
+        let a = A::distribute(market_id.clone(), asset.clone(), account, amount);
+        let b = B::distribute(market_id, asset, account, amount);
+        a.saturating_add(b)
+    }
+
+    fn distribute_on_behalf_of(
+        market_id: Self::MarketId,
+        asset: Self::Asset,
+        account: &Self::AccountId,
+        trader: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Self::Balance {
+        let a =
+            A::distribute_on_behalf_of(market_id.clone(), asset.clone(), account, trader, amount);
+        let b = B::distribute_on_behalf_of(market_id, asset, account, trader, amount);
+        a.saturating_add(b)
+    }
+
+    fn fee_amount(
+        market_id: Self::MarketId,
+        trader: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Self::Balance {
+        A::fee_amount(market_id.clone(), trader, amount)
+            .saturating_add(B::fee_amount(market_id, trader, amount))
+    }
 }
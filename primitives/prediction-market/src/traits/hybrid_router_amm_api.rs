@@ -16,6 +16,7 @@
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::hybrid_router_api_types::{AmmSoftFail, AmmTrade, ApiError};
+use frame_support::pallet_prelude::Weight;
 use sp_runtime::DispatchError;
 
 /// A type alias for the return struct of AMM buy and sell.
@@ -63,6 +64,7 @@ pub trait HybridRouterAmmApi {
     ///
     /// # Arguments
     ///
+    /// - `who`: The account ID of the user whose fees are included in the buy amount.
     /// - `market_id`: The market ID for which to calculate the buy amount.
     /// - `asset`: The asset to calculate the buy amount for.
     /// - `until`: The maximum price.
@@ -72,6 +74,7 @@ pub trait HybridRouterAmmApi {
     /// Returns the buy amount as a `Result` containing the balance, or an error if the buy amount
     /// cannot be calculated.
     fn calculate_buy_amount_until(
+        who: &Self::AccountId,
         market_id: Self::MarketId,
         asset: Self::Asset,
         until: Self::Balance,
@@ -137,4 +140,8 @@ pub trait HybridRouterAmmApi {
         amount_in: Self::Balance,
         min_amount_out: Self::Balance,
     ) -> Result<AmmTradeOf<Self>, ApiErrorOf>;
+
+    /// Returns the max weight of the pool bookkeeping which every `buy` and `sell` runs on top of
+    /// the trade itself and which depends on the state of the pool, like updating range positions.
+    fn trade_overhead_max_weight() -> Weight;
 }
@@ -17,7 +17,10 @@
 
 use sp_runtime::DispatchError;
 
-use crate::hybrid_router_api_types::{ApiError, OrderbookSoftFail, OrderbookTrade};
+use crate::{
+    hybrid_router_api_types::{ApiError, OrderbookSoftFail, OrderbookTrade},
+    orderbook::OrderExpiry,
+};
 
 /// A type alias for the return struct of order-book trades.
 type OrderbookTradeOf<T> = OrderbookTrade<
@@ -37,7 +40,7 @@ pub trait HybridRouterOrderbookApi {
     type Order;
     type OrderId;
 
-    /// Returns the order with the specified `order_id`.
+    /// Returns the order with the specified `order_id`. Fails if the order has expired.
     ///
     /// # Arguments
     ///
@@ -71,6 +74,7 @@ pub trait HybridRouterOrderbookApi {
     /// - `maker_amount`: The amount the maker wants to trade.
     /// - `taker_asset`: The asset the maker wants to receive.
     /// - `taker_amount`: The amount the maker wants to receive.
+    /// - `expiry`: The expiry after which the order can no longer be filled, if any.
     fn place_order(
         who: Self::AccountId,
         market_id: Self::MarketId,
@@ -78,5 +82,6 @@ pub trait HybridRouterOrderbookApi {
         maker_amount: Self::Balance,
         taker_asset: Self::Asset,
         taker_amount: Self::Balance,
+        expiry: Option<OrderExpiry>,
     ) -> Result<(), ApiErrorOf>;
 }
@@ -36,6 +36,13 @@ pub type OrmlAmount = i128;
 /// The index of the category for a `CategoricalOutcome` asset.
 pub type CategoryIndex = u16;
 
+/// The identifier of a combinatorial collection, i.e. a set of conditions of the form "market `m`
+/// resolves to one of the outcomes in the index set `s`".
+pub type CombinatorialId = [u8; 32];
+
+/// A bitmask over the outcomes of a market. Bit `i` is set if outcome `i` is included in the set.
+pub type IndexSet = u128;
+
 /// Multihash for digest sizes up to 384 bit.
 /// The multicodec encoding the hash algorithm uses only 1 byte,
 /// effecitvely limiting the number of available hash types.
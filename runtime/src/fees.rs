@@ -53,8 +53,9 @@ macro_rules! impl_market_creator_fees {
 
         pub struct AdditionalSwapFee;
 
-        /// Uses the `additional_swap_fee` value defined in the NeoSwap pallet to deduct a fee for
-        /// the chain operator. Calling `distribute` is noop if the transfer fails for any reason.
+        /// Uses the additional swap fee configured in the NeoSwap pallet to deduct a fee for the
+        /// chain operator. The fee depends on the base asset of the market and on the volume tier
+        /// of the trader. Calling `distribute` is noop if the transfer fails for any reason.
         impl DistributeFees for AdditionalSwapFee {
             type Asset = Asset<MarketId>;
             type AccountId = AccountId;
@@ -67,27 +68,57 @@ macro_rules! impl_market_creator_fees {
                 account: &Self::AccountId,
                 amount: Self::Balance,
             ) -> Self::Balance {
-                Self::do_distribute(market_id, asset, account, amount)
+                Self::do_distribute(market_id, asset, account, account, amount)
+                    .unwrap_or_else(|_| 0u8.saturated_into())
+            }
+
+            fn distribute_on_behalf_of(
+                market_id: Self::MarketId,
+                asset: Self::Asset,
+                account: &Self::AccountId,
+                trader: &Self::AccountId,
+                amount: Self::Balance,
+            ) -> Self::Balance {
+                Self::do_distribute(market_id, asset, account, trader, amount)
+                    .unwrap_or_else(|_| 0u8.saturated_into())
+            }
+
+            fn fee_amount(
+                market_id: Self::MarketId,
+                trader: &Self::AccountId,
+                amount: Self::Balance,
+            ) -> Self::Balance {
+                NeoSwaps::additional_swap_fee_amount(market_id, Some(trader), amount)
                     .unwrap_or_else(|_| 0u8.saturated_into())
             }
         }
 
         impl AdditionalSwapFee {
             fn do_distribute(
-                _market_id: MarketId,
+                market_id: MarketId,
                 asset: Asset<MarketId>,
                 account: &AccountId,
-                _amount: Balance,
+                trader: &AccountId,
+                amount: Balance,
             ) -> Result<Balance, DispatchError> {
                 let recipient = PredictionMarkets::additional_swap_fee_account()?;
-                let fee_amount = NeoSwaps::additional_swap_fee()?;
+                let fee_amount =
+                    NeoSwaps::additional_swap_fee_amount(market_id, Some(trader), amount)?;
                 // Might fail if the transaction is too small
                 <AssetManager as MultiCurrency<_>>::transfer(
                     asset, account, &recipient, fee_amount,
                 )?;
+                NeoSwaps::note_trading_volume(trader, amount);
                 Ok(fee_amount)
             }
         }
+
+        /// Pays the market creator the `creator_fee` of the market, capped at `MaxCreatorFee`.
+        pub type MarketCreatorFee = pallet_prediction_markets::MarketCreatorFee<Runtime>;
+
+        /// The external fees charged on trades: the additional swap fee for the chain operator
+        /// followed by the creator fee.
+        pub type TradingFees = (AdditionalSwapFee, MarketCreatorFee);
     };
 }
 
@@ -113,6 +144,14 @@ macro_rules! impl_winner_fees {
             ) -> Self::Balance {
                 Self::do_distribute(asset, account, amount).unwrap_or_else(|_| 0u8.saturated_into())
             }
+
+            fn fee_amount(
+                _market_id: Self::MarketId,
+                _trader: &Self::AccountId,
+                amount: Self::Balance,
+            ) -> Self::Balance {
+                WinnerFeePercentage::get().mul_floor(amount)
+            }
         }
 
         impl WinnerFee {
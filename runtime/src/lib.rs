@@ -39,6 +39,8 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
 pub mod proxy_config;
+#[cfg(test)]
+mod tests;
 use proxy_config::AvnProxyConfig;
 
 pub use prediction_market_primitives::{constants::*, types::*};
@@ -46,8 +48,8 @@ pub use sp_avn_common::watchtower::{ProposalId, WatchtowerHooks};
 
 pub use common_primitives::{
     constants::{
-        currency::*, BLOCKS_PER_DAY, BLOCKS_PER_HOUR, BLOCKS_PER_YEAR, MILLISECS_PER_BLOCK,
-        NODE_MANAGER_PALLET_ID, SLOT_DURATION,
+        currency::*, BLOCKS_PER_DAY, BLOCKS_PER_HOUR, BLOCKS_PER_MINUTE, BLOCKS_PER_YEAR,
+        MILLISECS_PER_BLOCK, NODE_MANAGER_PALLET_ID, SLOT_DURATION,
     },
     types::{AccountId, Balance, BlockNumber},
 };
@@ -177,6 +179,7 @@ where
             GlobalDisputesPalletId::get(),
             HybridRouterPalletId::get(),
             OrderbookPalletId::get(),
+            ParimutuelPalletId::get(),
             PmPalletId::get(),
             TreasuryPalletId::get(),
         ];
@@ -967,7 +970,10 @@ parameter_types! {
     // Authorized
     pub const AuthorizedPalletId: PalletId = AUTHORIZED_PALLET_ID;
     pub const CorrectionPeriod: BlockNumber = BLOCKS_PER_DAY;
-
+    /// The maximum number of members of an authorized dispute resolution panel.
+    pub const MaxPanelMembers: u32 = 16;
+    /// The period after a dispute in which the panel members can submit their outcomes.
+    pub const PanelReportPeriod: BlockNumber = 3 * BLOCKS_PER_DAY;
 }
 
 impl pallet_pm_authorized::Config for Runtime {
@@ -977,7 +983,9 @@ impl pallet_pm_authorized::Config for Runtime {
     type DisputeResolution = pallet_prediction_markets::Pallet<Runtime>;
     type RuntimeEvent = RuntimeEvent;
     type MarketCommons = MarketCommons;
+    type MaxPanelMembers = MaxPanelMembers;
     type PalletId = AuthorizedPalletId;
+    type PanelReportPeriod = PanelReportPeriod;
     type WeightInfo = pallet_pm_authorized::weights::WeightInfo<Runtime>;
 }
 
@@ -1004,6 +1012,8 @@ parameter_types! {
     pub const InflationPeriod: BlockNumber = 30 * BLOCKS_PER_DAY;
     /// The maximum number of appeals until the court fails.
     pub const MaxAppeals: u32 = 4;
+    /// The share of the juror selection weight which depends on the track record of the juror.
+    pub const ReputationInfluence: Perbill = Perbill::from_percent(50);
     /// The maximum number of delegations per juror account.
     pub const MaxDelegations: u32 = 5;
     /// The maximum number of randomly selected `MinJurorStake` draws / atoms of jurors for a dispute.
@@ -1017,6 +1027,13 @@ parameter_types! {
     /// The interval for requesting multiple court votes at once.
     pub const RequestInterval: BlockNumber = 7 * BLOCKS_PER_DAY;
 }
+/// The source of the random seed the court draws its jurors with. The randomness beacon only
+/// produces a seed once the registered nodes committed to and revealed their secrets, so a draw
+/// waits for the next reveal period to end. Use `RandomnessCollectiveFlip` instead to draw from the
+/// block hashes if the registered nodes don't run the beacon; its seeds can be influenced by the
+/// block authors and only count as known a number of blocks after they were produced.
+pub type CourtRandomness = RandomnessBeacon;
+
 impl pallet_pm_court::Config for Runtime {
     type AppealBond = AppealBond;
     type BlocksPerYear = BlocksPerYear;
@@ -1025,6 +1042,7 @@ impl pallet_pm_court::Config for Runtime {
     type AppealPeriod = CourtAppealPeriod;
     type LockId = CourtLockId;
     type PalletId = CourtPalletId;
+    type Public = <Signature as sp_runtime::traits::Verify>::Signer;
     type Currency = Balances;
     type DisputeResolution = pallet_prediction_markets::Pallet<Runtime>;
     type RuntimeEvent = RuntimeEvent;
@@ -1037,8 +1055,14 @@ impl pallet_pm_court::Config for Runtime {
     type MaxYearlyInflation = MaxYearlyInflation;
     type MinJurorStake = MinJurorStake;
     type MonetaryGovernanceOrigin = EnsureRoot<AccountId>;
-    type Random = RandomnessCollectiveFlip;
+    #[cfg(not(feature = "runtime-benchmarks"))]
+    type Random = CourtRandomness;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Random = BenchmarkRandomness;
+    type ReputationInfluence = ReputationInfluence;
     type RequestInterval = RequestInterval;
+    type RuntimeCall = RuntimeCall;
+    type Signature = Signature;
     type Slash = Treasury<Runtime>;
     type TreasuryPalletId = AvnTreasuryPotId;
     type WeightInfo = pallet_pm_court::weights::WeightInfo<Runtime>;
@@ -1094,6 +1118,8 @@ parameter_types! {
     pub const MaxOracleDuration: BlockNumber = MAX_ORACLE_DURATION;
     /// Maximum string length allowed for reject reason.
     pub const MaxRejectReasonLen: u32 = 1024;
+    /// Maximum number of markets a combinatorial position can be conditioned on.
+    pub const MaxSplitDepth: u32 = MAX_SPLIT_DEPTH;
     /// Minimum number of categories. The trivial minimum is 2, which represents a binary market.
     pub const MinCategories: u16 = 2;
     /// Minimum block period for an oracle_duration.
@@ -1101,6 +1127,9 @@ parameter_types! {
     /// (Slashable) The orcale bond. Slashed in case the final outcome does not match the
     /// outcome the oracle reported.
     pub const OracleBond: Balance = 100 * BASE;
+    /// (Slashable) The bond of each oracle of a multi-oracle market. Slashed in case the oracle
+    /// doesn't report within the oracle duration.
+    pub const MultiOracleBond: Balance = 100 * BASE;
     /// (Slashable) A bond for an outcome reporter, who is not the oracle.
     /// Slashed in case the final outcome does not match the outcome by the outsider.
     // If we remove the whitelist restriction for market creation, review this figure and ensure its > OracleBond
@@ -1116,12 +1145,35 @@ parameter_types! {
     pub const ValidityBond: Balance = 100 * BASE;
     // Orderbook parameters
     pub const OrderbookPalletId: PalletId = ORDERBOOK_PALLET_ID;
+    /// Maximum number of open orders per market, and per outcome asset and side.
+    pub const MaxOrdersPerMarket: u32 = 1_000;
+    /// Maximum number of open orders per maker.
+    pub const MaxOrdersPerMaker: u32 = 256;
+    /// Maximum number of open orders per maker and market.
+    pub const MaxOrdersPerMakerPerMarket: u32 = 100;
+    /// Maximum number of orders which can be placed or removed in a single batch call.
+    pub const MaxOrdersPerBatch: u32 = 32;
     // Hybrid Router parameters
     pub const HybridRouterPalletId: PalletId = HYBRID_ROUTER_PALLET_ID;
     /// Maximum number of orders that can be placed in a single trade transaction.
     pub const MaxOrders: u32 = 100;
+    // Parimutuel parameters
+    pub const ParimutuelPalletId: PalletId = PARIMUTUEL_PALLET_ID;
+    /// The minimum amount of base asset which goes into the pot with a single bet.
+    pub const MinBetSize: Balance = BASE;
     /// The percentage of winning we deduct from the winner.
     pub const WinnerFeePercentage: Perbill = Perbill::from_percent(5);
+    // Randomness beacon parameters
+    /// The bond a registered node reserves when committing to a secret. It's slashed if the node
+    /// doesn't reveal the secret. The last node to reveal can withhold its secret to switch to the
+    /// seed it prefers, so the bond must exceed what biasing a juror draw is worth.
+    pub const BeaconCommitBond: Balance = 100 * BASE;
+    /// The time in which the registered nodes commit to their secret for the next seed.
+    pub const BeaconCommitPeriod: BlockNumber = 5 * BLOCKS_PER_MINUTE;
+    /// The time in which the registered nodes reveal their secret.
+    pub const BeaconRevealPeriod: BlockNumber = 5 * BLOCKS_PER_MINUTE;
+    /// The maximum number of nodes which can contribute to a seed.
+    pub const MaxBeaconParticipants: u32 = 256;
 }
 
 impl_winner_fees!();
@@ -1156,7 +1208,9 @@ impl pallet_prediction_markets::Config for Runtime {
     type MinCategories = MinCategories;
     type MaxEditReasonLen = MaxEditReasonLen;
     type MaxRejectReasonLen = MaxRejectReasonLen;
+    type MaxSplitDepth = MaxSplitDepth;
     type OracleBond = OracleBond;
+    type MultiOracleBond = MultiOracleBond;
     type OutsiderBond = OutsiderBond;
     type PalletId = PmPalletId;
     type CloseEarlyBlockPeriod = CloseEarlyBlockPeriod;
@@ -1228,7 +1282,7 @@ parameter_type_with_key! {
                 1
             },
             Asset::CategoricalOutcome(_,_) => DEFAULT_EXISTENTIAL_DEPOSIT,
-            Asset::CombinatorialOutcome => DEFAULT_EXISTENTIAL_DEPOSIT,
+            Asset::CombinatorialOutcome(_) => DEFAULT_EXISTENTIAL_DEPOSIT,
             Asset::PoolShare(_)  => DEFAULT_EXISTENTIAL_DEPOSIT,
             Asset::ScalarOutcome(_,_)  => DEFAULT_EXISTENTIAL_DEPOSIT,
             Asset::ParimutuelShare(_,_)  => DEFAULT_EXISTENTIAL_DEPOSIT,
@@ -1267,30 +1321,37 @@ impl orml_tokens::Config for Runtime {
 // Global disputes parameters
 parameter_types! {
     pub const AddOutcomePeriod: BlockNumber = 20;
+    pub const ConvictionLockPeriod: BlockNumber = 140;
     pub const GlobalDisputeLockId: LockIdentifier = GLOBAL_DISPUTES_LOCK_ID;
     pub const GlobalDisputesPalletId: PalletId = GLOBAL_DISPUTES_PALLET_ID;
+    pub const MaxGdDelegators: u32 = 64;
     pub const MaxGlobalDisputeVotes: u32 = 50;
     pub const MaxOwners: u32 = 10;
     pub const MinOutcomeVoteAmount: Balance = 10 * CENT_BASE;
     pub const RemoveKeysLimit: u32 = 250;
     pub const GdVotingPeriod: BlockNumber = 140;
     pub const VotingOutcomeFee: Balance = 100 * CENT_BASE;
+    pub const GdVotingMode: pallet_pm_global_disputes::types::VotingMode =
+        pallet_pm_global_disputes::types::VotingMode::Conviction;
 }
 
 impl pallet_pm_global_disputes::Config for Runtime {
     type AddOutcomePeriod = AddOutcomePeriod;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
     type Currency = Balances;
     type DisputeResolution = pallet_prediction_markets::Pallet<Runtime>;
     type RuntimeEvent = RuntimeEvent;
     type GlobalDisputeLockId = GlobalDisputeLockId;
     type GlobalDisputesPalletId = GlobalDisputesPalletId;
     type MarketCommons = MarketCommons;
+    type MaxDelegators = MaxGdDelegators;
     type MaxGlobalDisputeVotes = MaxGlobalDisputeVotes;
     type MaxOwners = MaxOwners;
     type MinOutcomeVoteAmount = MinOutcomeVoteAmount;
     type RemoveKeysLimit = RemoveKeysLimit;
     type GdVotingPeriod = GdVotingPeriod;
     type VotingOutcomeFee = VotingOutcomeFee;
+    type VotingMode = GdVotingMode;
     type WeightInfo = pallet_pm_global_disputes::weights::WeightInfo<Runtime>;
 }
 
@@ -1298,21 +1359,29 @@ parameter_types! {
     // NeoSwaps
     pub const NeoSwapsMaxSwapFee: Balance = 10 * CENT_BASE;
     pub const NeoSwapsPalletId: PalletId = NS_PALLET_ID;
+    pub const MaxIncentivePrograms: u32 = 10u32;
     pub const MaxLiquidityTreeDepth: u32 = 9u32;
+    pub const MaxObservations: u32 = 100u32;
+    pub const MaxRangePositions: u32 = 10u32;
+    pub const MaxVolumeTiers: u32 = 10u32;
 }
 
 impl_market_creator_fees!();
 
 impl pallet_pm_neo_swaps::Config for Runtime {
     type CompleteSetOperations = PredictionMarkets;
-    type ExternalFees = AdditionalSwapFee;
+    type ExternalFees = TradingFees;
     type MarketCommons = MarketCommons;
     type MultiCurrency = AssetManager;
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
     type WeightInfo = pallet_pm_neo_swaps::weights::WeightInfo<Runtime>;
+    type MaxIncentivePrograms = MaxIncentivePrograms;
     type MaxLiquidityTreeDepth = MaxLiquidityTreeDepth;
+    type MaxObservations = MaxObservations;
+    type MaxRangePositions = MaxRangePositions;
     type MaxSwapFee = NeoSwapsMaxSwapFee;
+    type MaxVolumeTiers = MaxVolumeTiers;
     type PalletId = NeoSwapsPalletId;
     type SignedTxLifetime = ConstU32<16>;
     type Public = <Signature as sp_runtime::traits::Verify>::Signer;
@@ -1323,11 +1392,40 @@ impl pallet_pm_neo_swaps::Config for Runtime {
 
 impl pallet_pm_order_book::Config for Runtime {
     type AssetManager = AssetManager;
-    type ExternalFees = AdditionalSwapFee;
+    type ExternalFees = TradingFees;
     type RuntimeEvent = RuntimeEvent;
     type MarketCommons = MarketCommons;
+    type MaxOrdersPerMarket = MaxOrdersPerMarket;
+    type MaxOrdersPerMaker = MaxOrdersPerMaker;
+    type MaxOrdersPerMakerPerMarket = MaxOrdersPerMakerPerMarket;
+    type MaxOrdersPerBatch = MaxOrdersPerBatch;
     type PalletId = OrderbookPalletId;
     type WeightInfo = pallet_pm_order_book::weights::WeightInfo<Runtime>;
+    type RuntimeCall = RuntimeCall;
+    type Public = <Signature as sp_runtime::traits::Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl pallet_pm_parimutuel::Config for Runtime {
+    type AssetManager = AssetManager;
+    type ExternalFees = AdditionalSwapFee;
+    type RuntimeEvent = RuntimeEvent;
+    type MarketCommons = MarketCommons;
+    type MinBetSize = MinBetSize;
+    type PalletId = ParimutuelPalletId;
+    type WeightInfo = pallet_pm_parimutuel::weights::WeightInfo<Runtime>;
+}
+
+impl pallet_pm_randomness_beacon::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Participants = RuntimeNodeManager;
+    type Currency = Balances;
+    type CommitBond = BeaconCommitBond;
+    type Slash = Treasury<Runtime>;
+    type CommitPeriod = BeaconCommitPeriod;
+    type RevealPeriod = BeaconRevealPeriod;
+    type MaxParticipants = MaxBeaconParticipants;
+    type WeightInfo = pallet_pm_randomness_beacon::weights::WeightInfo<Runtime>;
 }
 
 impl pallet_pm_hybrid_router::Config for Runtime {
@@ -1401,6 +1499,8 @@ construct_runtime!(
         HybridRouter: pallet_pm_hybrid_router::{Call, Event<T>, Pallet, Storage} = 47,
         Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>} = 48,
         SummaryWatchtower: pallet_summary_watchtower::{Pallet, Call, Storage, Event<T>} = 49,
+        Parimutuel: pallet_pm_parimutuel::{Call, Event<T>, Pallet, Storage} = 50,
+        RandomnessBeacon: pallet_pm_randomness_beacon::{Call, Event<T>, Pallet, Storage} = 51,
     }
 );
 
@@ -1437,6 +1537,12 @@ pub type Executive = frame_executive::Executive<
     (
         pallet_eth_bridge::migration::EthBridgeMigrations<Runtime>,
         pallet_node_manager::migration::OwnedNodesUpgrade<Runtime>,
+        pallet_pm_order_book::migrations::BuildOrderIndexes<Runtime>,
+        pallet_pm_order_book::migrations::AddOrderExpiry<Runtime>,
+        pallet_pm_order_book::migrations::CountMakerMarketOrders<Runtime>,
+        pallet_pm_neo_swaps::migration::MigrateAdditionalSwapFeeMode<Runtime>,
+        pallet_pm_global_disputes::migrations::MigrateVoteLocks<Runtime>,
+        pallet_pm_market_commons::migrations::AddMultiOracleBonds<Runtime>,
     ),
 >;
 
@@ -1473,9 +1579,17 @@ mod benches {
         [pallet_prediction_markets, PredictionMarkets]
         [pallet_pm_neo_swaps, NeoSwaps]
         [pallet_pm_hybrid_router, HybridRouter]
+        [pallet_pm_parimutuel, Parimutuel]
+        [pallet_pm_randomness_beacon, RandomnessBeacon]
     );
 }
 
+use prediction_market_primitives::{
+    court_api_types::JurorReputation,
+    hybrid_router_api_types::{Strategy, TradeSimulation, TxType},
+    neo_swaps_api_types::SwapQuote,
+    orderbook::{OrderBookDepth, OrderId},
+};
 use sp_avn_common::event_discovery::{EthBlockRange, EthereumEventsPartition};
 use sp_core::H160;
 
@@ -1714,6 +1828,158 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_prediction_markets_runtime_api::PredictionMarketsApi<
+        Block,
+        AccountId,
+        Balance,
+        BlockNumber,
+        MarketId,
+        Moment,
+        Hash,
+    > for Runtime {
+        fn market_outcome_share_id(market_id: MarketId, outcome: u16) -> Option<Asset<MarketId>> {
+            pallet_pm_market_commons::Markets::<Runtime>::get(market_id)
+                .and_then(|market| market.outcome_asset(outcome))
+        }
+
+        fn market(
+            market_id: MarketId,
+        ) -> Option<Market<AccountId, Balance, BlockNumber, Moment, MarketId>> {
+            pallet_pm_market_commons::Markets::<Runtime>::get(market_id)
+        }
+
+        fn market_outcome_assets(market_id: MarketId) -> Vec<Asset<MarketId>> {
+            pallet_pm_market_commons::Markets::<Runtime>::get(market_id)
+                .map(|market| market.outcome_assets())
+                .unwrap_or_default()
+        }
+
+        fn market_status(market_id: MarketId) -> Option<MarketStatus> {
+            pallet_pm_market_commons::Markets::<Runtime>::get(market_id).map(|market| market.status)
+        }
+
+        fn market_resolved_outcome(market_id: MarketId) -> Option<OutcomeReport> {
+            pallet_pm_market_commons::Markets::<Runtime>::get(market_id)
+                .and_then(|market| market.resolved_outcome)
+        }
+    }
+
+    impl pallet_pm_neo_swaps_runtime_api::NeoSwapsApi<
+        Block,
+        AccountId,
+        MarketId,
+        Balance,
+        BlockNumber,
+    > for Runtime
+    {
+        fn quote_buy(
+            who: AccountId,
+            market_id: MarketId,
+            asset_out: Asset<MarketId>,
+            amount_in: Balance,
+        ) -> Result<SwapQuote<Balance>, DispatchError> {
+            NeoSwaps::quote_buy(&who, market_id, asset_out, amount_in)
+        }
+
+        fn quote_sell(
+            who: AccountId,
+            market_id: MarketId,
+            asset_in: Asset<MarketId>,
+            amount_in: Balance,
+        ) -> Result<SwapQuote<Balance>, DispatchError> {
+            NeoSwaps::quote_sell(&who, market_id, asset_in, amount_in)
+        }
+
+        fn spot_prices(
+            market_id: MarketId,
+        ) -> Result<Vec<(Asset<MarketId>, Balance)>, DispatchError> {
+            NeoSwaps::spot_prices(market_id)
+        }
+
+        fn twap(
+            market_id: MarketId,
+            period: BlockNumber,
+        ) -> Result<Vec<(Asset<MarketId>, Balance)>, DispatchError> {
+            NeoSwaps::twap(market_id, period)
+        }
+    }
+
+    impl pallet_pm_hybrid_router_runtime_api::HybridRouterApi<Block, AccountId, MarketId, Balance>
+        for Runtime
+    {
+        fn simulate_buy(
+            who: AccountId,
+            market_id: MarketId,
+            asset: Asset<MarketId>,
+            amount_in: Balance,
+            max_price: Balance,
+            orders: Vec<OrderId>,
+            strategy: Strategy,
+        ) -> Result<TradeSimulation<AccountId, Balance, MarketId>, DispatchError> {
+            HybridRouter::simulate_trade(
+                TxType::Buy,
+                who,
+                market_id,
+                asset,
+                amount_in,
+                max_price,
+                orders,
+                strategy,
+            )
+        }
+
+        fn simulate_sell(
+            who: AccountId,
+            market_id: MarketId,
+            asset: Asset<MarketId>,
+            amount_in: Balance,
+            min_price: Balance,
+            orders: Vec<OrderId>,
+            strategy: Strategy,
+        ) -> Result<TradeSimulation<AccountId, Balance, MarketId>, DispatchError> {
+            HybridRouter::simulate_trade(
+                TxType::Sell,
+                who,
+                market_id,
+                asset,
+                amount_in,
+                min_price,
+                orders,
+                strategy,
+            )
+        }
+    }
+
+    impl pallet_pm_order_book_runtime_api::OrderBookApi<Block, AccountId, MarketId, Balance>
+        for Runtime
+    {
+        fn market_orders(market_id: MarketId) -> Vec<OrderId> {
+            Orderbook::market_orders(market_id)
+        }
+
+        fn maker_orders(maker: AccountId) -> Vec<OrderId> {
+            Orderbook::maker_orders(&maker)
+        }
+
+        fn best_orders(
+            market_id: MarketId,
+            outcome_asset: Asset<MarketId>,
+            depth: u32,
+        ) -> Result<OrderBookDepth<AccountId, Balance, MarketId>, DispatchError> {
+            Orderbook::best_orders(market_id, outcome_asset, depth)
+        }
+    }
+
+    impl pallet_pm_court_runtime_api::CourtApi<Block, AccountId> for Runtime {
+        fn reputation(court_participant: AccountId) -> JurorReputation {
+            pallet_pm_court::Reputations::<Runtime>::get(court_participant)
+        }
+
+        fn reputation_score(court_participant: AccountId) -> Perbill {
+            pallet_pm_court::Reputations::<Runtime>::get(court_participant).score()
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (
@@ -1847,3 +2113,29 @@ impl pallet_watchtower::NodesInterface<AccountId, NodeManagerKeyId> for RuntimeN
         pallet_node_manager::TotalRegisteredNodes::<Runtime>::get()
     }
 }
+
+// The registered nodes are the participants of the randomness beacon.
+impl Contains<AccountId> for RuntimeNodeManager {
+    fn contains(node: &AccountId) -> bool {
+        #[cfg(feature = "runtime-benchmarks")]
+        {
+            return true;
+        }
+
+        #[cfg(not(feature = "runtime-benchmarks"))]
+        pallet_node_manager::NodeRegistry::<Runtime>::contains_key(node)
+    }
+}
+
+// Nobody contributes to the randomness beacon in the benchmarks, so it has no output. The court
+// only needs values which became known after a court round was opened.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkRandomness;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Randomness<Hash, BlockNumber> for BenchmarkRandomness {
+    fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+        let now = System::block_number();
+        (<BlakeTwo256 as sp_runtime::traits::Hash>::hash_of(&(subject, now)), now)
+    }
+}
@@ -146,6 +146,24 @@ impl ProvableProxy<RuntimeCall, Signature, AccountId> for AvnProxyConfig {
                 orders: _,
                 strategy: _,
             }) => return Some(proof.clone()),
+            RuntimeCall::Orderbook(pallet_pm_order_book::Call::signed_place_order {
+                proof,
+                market_id: _,
+                maker_asset: _,
+                maker_amount: _,
+                taker_asset: _,
+                taker_amount: _,
+                expiry: _,
+            }) => return Some(proof.clone()),
+            RuntimeCall::Orderbook(pallet_pm_order_book::Call::signed_fill_order {
+                proof,
+                order_id: _,
+                maker_partial_fill: _,
+            }) => return Some(proof.clone()),
+            RuntimeCall::Orderbook(pallet_pm_order_book::Call::signed_remove_order {
+                proof,
+                order_id: _,
+            }) => return Some(proof.clone()),
             RuntimeCall::NodeManager(pallet_node_manager::Call::signed_register_node {
                 proof,
                 node: _,
@@ -172,6 +190,53 @@ impl ProvableProxy<RuntimeCall, Signature, AccountId> for AvnProxyConfig {
                 market_id: _,
                 block_number: _,
             }) => return Some(proof.clone()),
+            RuntimeCall::NeoSwaps(pallet_pm_neo_swaps::Call::signed_claim_incentives {
+                proof,
+                market_id: _,
+                block_number: _,
+            }) => return Some(proof.clone()),
+            RuntimeCall::NeoSwaps(pallet_pm_neo_swaps::Call::signed_join_with_collateral {
+                proof,
+                market_id: _,
+                asset_count: _,
+                amount_in: _,
+                min_pool_shares_amount: _,
+                min_leftover_amounts_out: _,
+                block_number: _,
+            }) => return Some(proof.clone()),
+            RuntimeCall::NeoSwaps(pallet_pm_neo_swaps::Call::signed_exit_to_collateral {
+                proof,
+                market_id: _,
+                asset_count: _,
+                pool_shares_amount: _,
+                min_amount_out: _,
+                min_leftover_amounts_out: _,
+                block_number: _,
+            }) => return Some(proof.clone()),
+            RuntimeCall::Court(pallet_pm_court::Call::signed_join_court { proof, amount: _ }) =>
+                return Some(proof.clone()),
+            RuntimeCall::Court(pallet_pm_court::Call::signed_delegate {
+                proof,
+                amount: _,
+                delegations: _,
+            }) => return Some(proof.clone()),
+            RuntimeCall::Court(pallet_pm_court::Call::signed_prepare_exit_court { proof }) =>
+                return Some(proof.clone()),
+            RuntimeCall::Court(pallet_pm_court::Call::signed_exit_court { proof }) =>
+                return Some(proof.clone()),
+            RuntimeCall::Court(pallet_pm_court::Call::signed_vote {
+                proof,
+                court_id: _,
+                commitment_vote: _,
+            }) => return Some(proof.clone()),
+            RuntimeCall::Court(pallet_pm_court::Call::signed_reveal_vote {
+                proof,
+                court_id: _,
+                vote_item: _,
+                salt: _,
+            }) => return Some(proof.clone()),
+            RuntimeCall::Court(pallet_pm_court::Call::signed_appeal { proof, court_id: _ }) =>
+                return Some(proof.clone()),
             RuntimeCall::NodeManager(pallet_node_manager::Call::signed_deregister_nodes {
                 proof,
                 owner: _,
@@ -209,12 +274,16 @@ impl InnerCallValidator for AvnProxyConfig {
                 return pallet_prediction_markets::Pallet::<Runtime>::signature_is_valid(call),
             RuntimeCall::HybridRouter(..) =>
                 return pallet_pm_hybrid_router::Pallet::<Runtime>::signature_is_valid(call),
+            RuntimeCall::Orderbook(..) =>
+                return pallet_pm_order_book::Pallet::<Runtime>::signature_is_valid(call),
             RuntimeCall::NodeManager(..) =>
                 return pallet_node_manager::Pallet::<Runtime>::signature_is_valid(call),
             RuntimeCall::NeoSwaps(..) =>
                 return pallet_pm_neo_swaps::Pallet::<Runtime>::signature_is_valid(call),
             RuntimeCall::Watchtower(..) =>
                 return pallet_watchtower::Pallet::<Runtime>::signature_is_valid(call),
+            RuntimeCall::Court(..) =>
+                return pallet_pm_court::Pallet::<Runtime>::signature_is_valid(call),
             _ => false,
         }
     }
@@ -0,0 +1,104 @@
+// Copyright 2024 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use pallet_prediction_markets_runtime_api::runtime_decl_for_prediction_markets_api::PredictionMarketsApiV1;
+
+fn insert_market(market_type: MarketType, scoring_rule: ScoringRule) -> MarketId {
+    let market_id = pallet_pm_market_commons::MarketCounter::<Runtime>::get();
+    let market = Market {
+        market_id,
+        base_asset: Asset::Tru,
+        creator: AccountId::from([0u8; 32]),
+        creation: MarketCreation::Permissionless,
+        creator_fee: Perbill::zero(),
+        oracle: AccountId::from([0u8; 32]),
+        metadata: vec![0u8; 50],
+        market_type,
+        period: MarketPeriod::Block(0..100),
+        deadlines: Deadlines { grace_period: 1, oracle_duration: 1, dispute_duration: 1 },
+        scoring_rule,
+        status: MarketStatus::Active,
+        report: None,
+        resolved_outcome: None,
+        dispute_mechanism: None,
+        bonds: MarketBonds::default(),
+        early_close: None,
+    };
+    pallet_pm_market_commons::Markets::<Runtime>::insert(market_id, market);
+    pallet_pm_market_commons::MarketCounter::<Runtime>::put(market_id + 1);
+    market_id
+}
+
+fn market_outcome_share_id(market_id: MarketId, outcome: u16) -> Option<Asset<MarketId>> {
+    <Runtime as PredictionMarketsApiV1<
+        Block,
+        AccountId,
+        Balance,
+        BlockNumber,
+        MarketId,
+        Moment,
+        Hash,
+    >>::market_outcome_share_id(market_id, outcome)
+}
+
+#[test]
+fn market_outcome_share_id_returns_categorical_outcomes() {
+    sp_io::TestExternalities::default().execute_with(|| {
+        let market_id = insert_market(MarketType::Categorical(3), ScoringRule::AmmCdaHybrid);
+        assert_eq!(
+            market_outcome_share_id(market_id, 2),
+            Some(Asset::CategoricalOutcome(market_id, 2))
+        );
+        assert_eq!(market_outcome_share_id(market_id, 3), None);
+    });
+}
+
+#[test]
+fn market_outcome_share_id_returns_scalar_positions() {
+    sp_io::TestExternalities::default().execute_with(|| {
+        let market_id = insert_market(MarketType::Scalar(0..=100), ScoringRule::AmmCdaHybrid);
+        assert_eq!(
+            market_outcome_share_id(market_id, 0),
+            Some(Asset::ScalarOutcome(market_id, ScalarPosition::Long))
+        );
+        assert_eq!(
+            market_outcome_share_id(market_id, 1),
+            Some(Asset::ScalarOutcome(market_id, ScalarPosition::Short))
+        );
+        assert_eq!(market_outcome_share_id(market_id, 2), None);
+    });
+}
+
+#[test]
+fn market_outcome_share_id_returns_parimutuel_shares() {
+    sp_io::TestExternalities::default().execute_with(|| {
+        let market_id = insert_market(MarketType::Categorical(3), ScoringRule::Parimutuel);
+        assert_eq!(
+            market_outcome_share_id(market_id, 1),
+            Some(Asset::ParimutuelShare(market_id, 1))
+        );
+        assert_eq!(market_outcome_share_id(market_id, 3), None);
+    });
+}
+
+#[test]
+fn market_outcome_share_id_returns_none_for_unknown_market() {
+    sp_io::TestExternalities::default().execute_with(|| {
+        assert_eq!(market_outcome_share_id(0, 0), None);
+    });
+}